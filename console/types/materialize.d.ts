@@ -1665,7 +1665,7 @@ export interface MzKafkaSources {
    */
   id: Generated<string>;
   /**
-   * The name of the Kafka topic the source is reading from.
+   * The name of the Kafka topic the source is reading from, the comma-separated topic names for `TOPICS`, or the pattern for `TOPIC PATTERN`.
    */
   topic: Generated<string>;
 }
@@ -0,0 +1,113 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+
+from materialize.mzcompose.service import (
+    Service,
+)
+
+
+class FakeGcs(Service):
+    """A `fake-gcs-server` container that emulates the Google Cloud Storage
+    JSON API.
+
+    Every entry of `buckets` is created as an empty bucket on startup.
+    """
+
+    def __init__(
+        self,
+        name: str = "fake-gcs",
+        image: str = "fsouza/fake-gcs-server:1.52.2",
+        port: int = 4443,
+        buckets: list[str] = [],
+    ) -> None:
+        mkdirs = "".join(f"mkdir -p /data/{bucket} && " for bucket in buckets)
+        super().__init__(
+            name=name,
+            config={
+                "image": image,
+                "init": True,
+                "ports": [port],
+                "entrypoint": ["sh", "-c"],
+                "command": [
+                    f"{mkdirs}exec /bin/fake-gcs-server -backend memory -data /data"
+                    f" -scheme http -port {port} -external-url http://{name}:{port}"
+                ],
+                "healthcheck": {
+                    "test": f"wget -q -O /dev/null http://localhost:{port}/storage/v1/b",
+                    "interval": "1s",
+                    "start_period": "30s",
+                },
+            },
+        )
+
+
+# Answers every POST with a fresh access token, like Google's OAuth 2.0 token
+# endpoint does for a valid signed assertion.
+FAKE_GCP_OAUTH_SERVER = """
+import http.server, json
+
+class Handler(http.server.BaseHTTPRequestHandler):
+    def do_GET(self):
+        self.send_response(200)
+        self.end_headers()
+
+    def do_POST(self):
+        self.rfile.read(int(self.headers.get("Content-Length", 0)))
+        body = json.dumps(
+            {"access_token": "fake-token", "expires_in": 3600, "token_type": "Bearer"}
+        ).encode()
+        self.send_response(200)
+        self.send_header("Content-Type", "application/json")
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        self.wfile.write(body)
+
+http.server.ThreadingHTTPServer(("", PORT), Handler).serve_forever()
+"""
+
+
+class FakeGcpOauth(Service):
+    """A minimal stand-in for Google's OAuth 2.0 token endpoint, at which
+    service accounts mint the access tokens for requests to `FakeGcs`.
+
+    Point Materialize at it with `MZ_GCP_OAUTH_TOKEN_URI`, see `token_uri`.
+    """
+
+    def __init__(
+        self,
+        name: str = "fake-gcp-oauth",
+        image: str = "python:3.12-slim",
+        port: int = 8080,
+    ) -> None:
+        self.token_uri = f"http://{name}:{port}/token"
+        super().__init__(
+            name=name,
+            config={
+                "image": image,
+                "init": True,
+                "ports": [port],
+                "command": [
+                    "python3",
+                    "-u",
+                    "-c",
+                    FAKE_GCP_OAUTH_SERVER.replace("PORT", str(port)),
+                ],
+                "healthcheck": {
+                    "test": [
+                        "CMD",
+                        "python3",
+                        "-c",
+                        f"import urllib.request; urllib.request.urlopen('http://localhost:{port}/health')",
+                    ],
+                    "interval": "1s",
+                    "start_period": "30s",
+                },
+            },
+        )
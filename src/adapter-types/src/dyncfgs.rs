@@ -332,6 +332,15 @@ pub const CATALOG_INFO_METRICS_RECONCILE_INTERVAL: Config<Duration> = Config::ne
     "How frequently to reconcile the catalog `*_info` metrics with the catalog. A zero duration disables reconciliation.",
 );
 
+/// Whether to add the columns that are added to the upstream tables of
+/// PostgreSQL, MySQL and SQL Server source tables with
+/// `SCHEMA CHANGE BEHAVIOR = EVOLVE` to the tables.
+pub const ENABLE_SOURCE_SCHEMA_EVOLUTION: Config<bool> = Config::new(
+    "enable_source_schema_evolution",
+    true,
+    "Whether to add the columns that are added to the upstream tables of source tables that evolve their schema to the tables. Emergency break-glass flag: while disabled, such tables stall once columns are added upstream.",
+);
+
 /// Server-side `statement_timeout` to set on Postgres/CRDB connections used by
 /// the Postgres/CRDB timestamp oracle. A zero value leaves the statement
 /// timeout unset.
@@ -472,6 +481,7 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&ARRANGEMENT_SIZE_HISTORY_COLLECTION_INTERVAL)
         .add(&ARRANGEMENT_SIZE_HISTORY_RETENTION_PERIOD)
         .add(&CATALOG_INFO_METRICS_RECONCILE_INTERVAL)
+        .add(&ENABLE_SOURCE_SCHEMA_EVOLUTION)
         .add(&PG_TIMESTAMP_ORACLE_STATEMENT_TIMEOUT)
         .add(&ENABLE_SCOPED_SYSTEM_PARAMETERS)
 }
@@ -3272,6 +3272,13 @@ mod tests {
                                     ty.name
                                 );
                             }
+                            CatalogType::MultiRange { .. } => {
+                                assert_eq!(
+                                    pg_ty.ty, "m",
+                                    "type {} is not a multirange type as expected",
+                                    ty.name
+                                );
+                            }
                             _ => {
                                 assert_eq!(
                                     pg_ty.ty, "b",
@@ -3813,7 +3820,8 @@ mod tests {
                         | typ @ SqlScalarType::MzTimestamp
                         | typ @ SqlScalarType::List { .. }
                         | typ @ SqlScalarType::Map { .. }
-                        | typ @ SqlScalarType::MzAclItem => {
+                        | typ @ SqlScalarType::MzAclItem
+                        | typ @ SqlScalarType::Enum { .. } => {
                             panic!("{typ:?} type found in {full_name}");
                         }
                         SqlScalarType::AclItem
@@ -3836,6 +3844,9 @@ mod tests {
                         | SqlScalarType::VarChar { .. }
                         | SqlScalarType::Jsonb
                         | SqlScalarType::Uuid
+                        | SqlScalarType::Inet
+                        | SqlScalarType::Cidr
+                        | SqlScalarType::MacAddr
                         | SqlScalarType::Array(_)
                         | SqlScalarType::Record { .. }
                         | SqlScalarType::Oid
@@ -3844,6 +3855,7 @@ mod tests {
                         | SqlScalarType::RegClass
                         | SqlScalarType::Int2Vector
                         | SqlScalarType::Range { .. }
+                        | SqlScalarType::MultiRange { .. }
                         | SqlScalarType::PgLegacyName => {}
                     }
                 }
@@ -52,7 +52,7 @@ use mz_repr::{CatalogItemId, Diff, GlobalId, RelationVersion, Timestamp, Version
 use mz_sql::catalog::CatalogError as SqlCatalogError;
 use mz_sql::catalog::{CatalogItem as SqlCatalogItem, CatalogItemType, CatalogSchema, CatalogType};
 use mz_sql::names::{
-    FullItemName, ItemQualifiers, QualifiedItemName, RawDatabaseSpecifier,
+    FullItemName, ItemQualifiers, ObjectId, QualifiedItemName, RawDatabaseSpecifier,
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaSpecifier,
 };
 use mz_sql::session::user::MZ_SYSTEM_ROLE_ID;
@@ -995,8 +995,10 @@ impl CatalogState {
                     oid,
                     name.clone(),
                     CatalogItem::Func(Func {
-                        inner: func.inner,
+                        create_sql: None,
+                        inner: mz_sql::func::FuncRef::Builtin(func.inner),
                         global_id,
+                        resolved_ids: ResolvedIds::empty(),
                     }),
                     MZ_SYSTEM_ROLE_ID,
                     PrivilegeMap::default(),
@@ -1311,6 +1313,7 @@ impl CatalogState {
                     },
                     item: name.clone(),
                 };
+                let mut type_changed = false;
                 let entry = match retractions.items.remove(&key) {
                     Some(retraction) => {
                         assert_eq!(retraction.id, item.id);
@@ -1326,6 +1329,12 @@ impl CatalogState {
                             .unwrap_or_else(|e| {
                                 panic!("{e:?}: invalid persisted SQL: {create_sql}")
                             });
+                        type_changed = match (&retraction.item, &item) {
+                            (CatalogItem::Type(old), CatalogItem::Type(new)) => {
+                                old.details.typ != new.details.typ
+                            }
+                            _ => false,
+                        };
 
                         CatalogEntry {
                             item,
@@ -1364,6 +1373,9 @@ impl CatalogState {
                 };
 
                 self.insert_entry(entry);
+                if type_changed {
+                    self.reparse_type_dependents(id, local_expression_cache);
+                }
             }
             StateDiff::Retraction => {
                 let entry = self.drop_item(item.id);
@@ -1374,6 +1386,47 @@ impl CatalogState {
         Ok(())
     }
 
+    /// Re-plans every item that transitively depends on the type `id`.
+    ///
+    /// Items bake the definitions of the types they use into their plans, so
+    /// altering a type in place (e.g. `ALTER TYPE ... ADD VALUE`) would
+    /// otherwise leave its dependents describing the old definition. The
+    /// planner only permits such alterations when no dependent has a running
+    /// dataflow that would need to be re-rendered.
+    fn reparse_type_dependents(
+        &mut self,
+        id: CatalogItemId,
+        local_expression_cache: &mut LocalExpressionCache,
+    ) {
+        let mut dependents = self.item_dependents(id, &mut BTreeSet::new());
+        // `item_dependents` returns leaves first, but each item must be
+        // re-planned after the items it depends on.
+        dependents.reverse();
+        for dependent in dependents {
+            let ObjectId::Item(dependent_id) = dependent else {
+                continue;
+            };
+            if dependent_id == id {
+                continue;
+            }
+            let previous_item = self.get_entry(&dependent_id).item().clone();
+            let (create_sql, global_id, extra_versions) = previous_item.to_serialized();
+            let item = self
+                .deserialize_item(
+                    global_id,
+                    &create_sql,
+                    &extra_versions,
+                    local_expression_cache,
+                    Some(previous_item),
+                )
+                .unwrap_or_else(|e| panic!("{e:?}: invalid persisted SQL: {create_sql}"));
+            self.entry_by_id
+                .get_mut(&dependent_id)
+                .expect("catalog out of sync")
+                .item = item;
+        }
+    }
+
     #[instrument(level = "debug")]
     fn apply_comment_update(
         &mut self,
@@ -2127,11 +2180,12 @@ impl CatalogState {
                 .types
                 .remove(&metadata.name().item)
                 .expect("catalog out of sync");
+        } else if metadata.item_type() == CatalogItemType::Func {
+            schema
+                .functions
+                .remove(&metadata.name().item)
+                .expect("catalog out of sync");
         } else {
-            // Functions would need special handling, but we don't yet support
-            // dropping functions.
-            assert_ne!(metadata.item_type(), CatalogItemType::Func);
-
             schema
                 .items
                 .remove(&metadata.name().item)
@@ -2424,8 +2478,6 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         // Partition items into groups s.t. each item in one group has a predefined order with all
         // items in other groups. For example, all sinks are ordered greater than all tables.
         let mut types = Vec::new();
-        // N.B. Functions can depend on system tables, but not user tables.
-        // TODO(udf): This will change when UDFs are supported.
         let mut funcs = Vec::new();
         let mut secrets = Vec::new();
         let mut connections = Vec::new();
@@ -2436,6 +2488,9 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         for update in item_updates {
             match update.0.item_type() {
                 CatalogItemType::Type => types.push(update),
+                // User-defined functions can depend on, and be depended on by,
+                // arbitrary relations, so they are sorted along with views.
+                CatalogItemType::Func if update.0.id.is_user() => derived_items.push(update),
                 CatalogItemType::Func => funcs.push(update),
                 CatalogItemType::Secret => secrets.push(update),
                 CatalogItemType::Connection => connections.push(update),
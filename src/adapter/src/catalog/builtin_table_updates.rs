@@ -199,7 +199,7 @@ impl CatalogState {
                 self.pack_type_update(id, oid, schema_id, name, owner_id, privileges, ty, diff)
             }
             CatalogItem::Func(func) => {
-                self.pack_func_update(id, schema_id, name, owner_id, func, diff)
+                self.pack_func_update(id, oid, schema_id, name, owner_id, func, diff)
             }
             CatalogItem::Log(_) | CatalogItem::Secret(_) => vec![],
             // Connection details (mz_kafka_connections, mz_ssh_tunnel_connections,
@@ -774,6 +774,7 @@ impl CatalogState {
     fn pack_func_update(
         &self,
         id: CatalogItemId,
+        oid: u32,
         schema_id: &SchemaSpecifier,
         name: &str,
         owner_id: &RoleId,
@@ -781,7 +782,13 @@ impl CatalogState {
         diff: Diff,
     ) -> Vec<BuiltinTableUpdate<&'static BuiltinTable>> {
         let mut updates = vec![];
-        for func_impl_details in func.inner.func_impls() {
+        for mut func_impl_details in func.inner.func_impls() {
+            // Builtin functions carry an OID per implementation, while each
+            // user-defined function has exactly one implementation whose OID is
+            // that of the catalog item.
+            if id.is_user() {
+                func_impl_details.oid = oid;
+            }
             let arg_type_ids = func_impl_details
                 .arg_typs
                 .iter()
@@ -828,7 +835,7 @@ impl CatalogState {
                 diff,
             ));
 
-            if let mz_sql::func::Func::Aggregate(_) = func.inner {
+            if let mz_sql::func::Func::Aggregate(_) = &*func.inner {
                 updates.push(BuiltinTableUpdate::row(
                     &*MZ_AGGREGATES,
                     Row::pack_slice(&[
@@ -417,7 +417,9 @@ fn rewrite_sources_to_tables(
         match &mut connection {
             CreateSourceConnection::Postgres { options, .. } => {
                 options.retain(|o| match o.name {
-                    PgConfigOptionName::Details | PgConfigOptionName::Publication => true,
+                    PgConfigOptionName::Details
+                    | PgConfigOptionName::Publication
+                    | PgConfigOptionName::TruncateBehavior => true,
                     PgConfigOptionName::TextColumns | PgConfigOptionName::ExcludeColumns => false,
                 });
             }
@@ -537,13 +539,29 @@ fn rewrite_sources_to_tables(
                             details.into_proto().encode_to_vec(),
                         )))),
                     }];
-                    // The external reference for a kafka source is the just the topic name
+                    // The external reference for a kafka source is the just the topic name, or
+                    // the comma-separated topic names or pattern for multi-topic sources.
                     let topic_option = options
                         .iter()
-                        .find(|o| matches!(o.name, KafkaSourceConfigOptionName::Topic))
+                        .find(|o| {
+                            matches!(
+                                o.name,
+                                KafkaSourceConfigOptionName::Topic
+                                    | KafkaSourceConfigOptionName::Topics
+                                    | KafkaSourceConfigOptionName::TopicPattern
+                            )
+                        })
                         .expect("kafka sources must have a topic");
                     let topic = match &topic_option.value {
-                        Some(WithOptionValue::Value(Value::String(topic))) => topic,
+                        Some(WithOptionValue::Value(Value::String(topic))) => topic.clone(),
+                        Some(WithOptionValue::Sequence(topics)) => topics
+                            .iter()
+                            .map(|topic| match topic {
+                                WithOptionValue::Value(Value::String(topic)) => topic.as_str(),
+                                _ => unreachable!("topic must be a string"),
+                            })
+                            .collect::<Vec<_>>()
+                            .join(","),
                         _ => unreachable!("topic must be a string"),
                     };
                     let external_reference = UnresolvedItemName::qualified(&[Ident::new(topic)?]);
@@ -746,6 +764,10 @@ fn rewrite_sources_to_tables(
                                 name: TableFromSourceOptionName::RetainHistory,
                                 value: option.value,
                             },
+                            CreateSubsourceOptionName::TruncateBehavior => TableFromSourceOption {
+                                name: TableFromSourceOptionName::TruncateBehavior,
+                                value: option.value,
+                            },
                             CreateSubsourceOptionName::Progress => {
                                 panic!("progress option should not exist on this subsource")
                             }
@@ -31,7 +31,7 @@ use mz_catalog::expr_cache::LocalExpressions;
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogCollectionEntry, CatalogEntry, CatalogItem, Cluster, ClusterReplica, CommentsMap,
-    Connection, DataSourceDesc, Database, DefaultPrivileges, Index, MaterializedView,
+    Connection, DataSourceDesc, Database, DefaultPrivileges, Func, Index, MaterializedView,
     NetworkPolicy, Role, RoleAuth, Schema, Secret, Sink, Source, SourceReferences, Table,
     TableDataSource, Type, View,
 };
@@ -74,9 +74,9 @@ use mz_sql::names::{
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaId, SchemaSpecifier, SystemObjectId,
 };
 use mz_sql::plan::{
-    CreateConnectionPlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateSecretPlan,
-    CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, Params,
-    Plan, PlanContext,
+    CreateConnectionPlan, CreateFunctionPlan, CreateIndexPlan, CreateMaterializedViewPlan,
+    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
+    CreateViewPlan, Params, Plan, PlanContext,
 };
 use mz_sql::rbac;
 use mz_sql::session::metadata::SessionMetadata;
@@ -445,7 +445,8 @@ impl CatalogState {
                 CatalogItem::Log(_) => out.push(id),
                 item @ (CatalogItem::View(_)
                 | CatalogItem::MaterializedView(_)
-                | CatalogItem::Connection(_)) => {
+                | CatalogItem::Connection(_)
+                | CatalogItem::Func(_)) => {
                     // TODO Unclear if this table wants to include all uses or only references.
                     for item_id in item.references().items() {
                         if seen.insert(*item_id) {
@@ -468,7 +469,6 @@ impl CatalogState {
                 CatalogItem::Table(_)
                 | CatalogItem::Source(_)
                 | CatalogItem::Type(_)
-                | CatalogItem::Func(_)
                 | CatalogItem::Secret(_) => (),
             }
         }
@@ -1539,6 +1539,12 @@ impl CatalogState {
                     resolved_ids,
                 })
             }
+            Plan::CreateFunction(CreateFunctionPlan { function, .. }) => CatalogItem::Func(Func {
+                create_sql: Some(function.create_sql),
+                inner: mz_sql::func::FuncRef::User(function.inner),
+                global_id,
+                resolved_ids,
+            }),
             Plan::CreateSecret(CreateSecretPlan { secret, .. }) => CatalogItem::Secret(Secret {
                 create_sql: secret.create_sql,
                 global_id,
@@ -1958,6 +1964,9 @@ impl CatalogState {
             CatalogType::Range { element_reference } => CatalogType::Range {
                 element_reference: self.get_system_type(element_reference).id,
             },
+            CatalogType::MultiRange { element_reference } => CatalogType::MultiRange {
+                element_reference: self.get_system_type(element_reference).id,
+            },
             CatalogType::Record { fields } => CatalogType::Record {
                 fields: fields
                     .into_iter()
@@ -1972,6 +1981,9 @@ impl CatalogState {
             CatalogType::Bytes => CatalogType::Bytes,
             CatalogType::Char => CatalogType::Char,
             CatalogType::Date => CatalogType::Date,
+            CatalogType::Enum { labels } => CatalogType::Enum {
+                labels: labels.clone(),
+            },
             CatalogType::Float32 => CatalogType::Float32,
             CatalogType::Float64 => CatalogType::Float64,
             CatalogType::Int16 => CatalogType::Int16,
@@ -1996,6 +2008,9 @@ impl CatalogState {
             CatalogType::Timestamp => CatalogType::Timestamp,
             CatalogType::TimestampTz => CatalogType::TimestampTz,
             CatalogType::Uuid => CatalogType::Uuid,
+            CatalogType::Inet => CatalogType::Inet,
+            CatalogType::Cidr => CatalogType::Cidr,
+            CatalogType::MacAddr => CatalogType::MacAddr,
             CatalogType::VarChar => CatalogType::VarChar,
             CatalogType::Int2Vector => CatalogType::Int2Vector,
             CatalogType::MzAclItem => CatalogType::MzAclItem,
@@ -39,8 +39,8 @@ use mz_catalog::expr_cache::LocalExpressions;
 use mz_catalog::memory::error::{AmbiguousRename, Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogEntry, CatalogItem, ClusterConfig, ClusterVariant, DataSourceDesc, DefaultPrivileges,
-    ReconfigurationState, ReconfigurationStatus, ReconfigurationTarget, SourceReferences,
-    StateDiff, StateUpdate, StateUpdateKind, TemporaryItem,
+    ReconfigurationState, ReconfigurationStatus, ReconfigurationTarget, SourceExportColumn,
+    SourceReferences, StateDiff, StateUpdate, StateUpdateKind, TemporaryItem,
 };
 use mz_cluster_controller::ctx::RefreshWindowDecision;
 use mz_controller::clusters::{ManagedReplicaLocation, ReplicaConfig, ReplicaLocation};
@@ -132,6 +132,8 @@ pub enum Op {
         name: ColumnName,
         typ: SqlColumnType,
         sql: RawDataType,
+        /// Set for tables that are fed by a source.
+        source_export: Option<SourceExportColumn>,
     },
     AlterMaterializedViewApplyReplacement {
         id: CatalogItemId,
@@ -1305,12 +1307,13 @@ impl Catalog {
                 name,
                 typ,
                 sql,
+                source_export,
             } => {
                 let column_name = name.to_string();
                 let column_type = typ.to_string();
                 let nullable = typ.nullable;
                 let mut new_entry = state.get_entry(&id).clone();
-                let version = new_entry.item.add_column(name, typ, sql)?;
+                let version = new_entry.item.add_column(name, typ, sql, source_export)?;
                 // All versions of a table share the same shard, so it shouldn't matter what
                 // GlobalId we use here.
                 let shard_id = state
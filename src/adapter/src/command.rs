@@ -633,6 +633,8 @@ pub enum ExecuteResponse {
     CreatedMaterializedView,
     /// The requested type was created.
     CreatedType,
+    /// The requested function was created.
+    CreatedFunction,
     /// The requested network policy was created.
     CreatedNetworkPolicy,
     /// The requested prepared statement was removed.
@@ -667,6 +669,9 @@ pub enum ExecuteResponse {
     GrantedRole,
     /// The specified number of rows were inserted into the requested table.
     Inserted(usize),
+    /// The specified number of rows were inserted, updated, or deleted by a
+    /// `MERGE` into the requested table.
+    Merged(usize),
     /// The specified prepared statement was created.
     Prepare,
     /// A user-requested warning was raised.
@@ -802,6 +807,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             }
             ExecuteResponseKind::CreatedNetworkPolicy => Ok(ExecuteResponse::CreatedNetworkPolicy),
             ExecuteResponseKind::CreatedType => Ok(ExecuteResponse::CreatedType),
+            ExecuteResponseKind::CreatedFunction => Ok(ExecuteResponse::CreatedFunction),
             ExecuteResponseKind::Deallocate => Err(()),
             ExecuteResponseKind::DeclaredCursor => Ok(ExecuteResponse::DeclaredCursor),
             ExecuteResponseKind::Deleted => Err(()),
@@ -814,6 +820,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::GrantedPrivilege => Ok(ExecuteResponse::GrantedPrivilege),
             ExecuteResponseKind::GrantedRole => Ok(ExecuteResponse::GrantedRole),
             ExecuteResponseKind::Inserted => Err(()),
+            ExecuteResponseKind::Merged => Err(()),
             ExecuteResponseKind::Prepare => Ok(ExecuteResponse::Prepare),
             ExecuteResponseKind::Raised => Ok(ExecuteResponse::Raised),
             ExecuteResponseKind::ReassignOwned => Ok(ExecuteResponse::ReassignOwned),
@@ -863,6 +870,7 @@ impl ExecuteResponse {
             CreatedViews { .. } => Some("CREATE VIEWS".into()),
             CreatedMaterializedView { .. } => Some("CREATE MATERIALIZED VIEW".into()),
             CreatedType => Some("CREATE TYPE".into()),
+            CreatedFunction => Some("CREATE FUNCTION".into()),
             CreatedNetworkPolicy => Some("CREATE NETWORKPOLICY".into()),
             Deallocate { all } => Some(format!("DEALLOCATE{}", if *all { " ALL" } else { "" })),
             DeclaredCursor => Some("DECLARE CURSOR".into()),
@@ -885,6 +893,7 @@ impl ExecuteResponse {
                 // have OIDs.
                 Some(format!("INSERT 0 {}", n))
             }
+            Merged(n) => Some(format!("MERGE {}", n)),
             Prepare => Some("PREPARE".into()),
             Raised => Some("RAISE".into()),
             ReassignOwned => Some("REASSIGN OWNED".into()),
@@ -924,6 +933,7 @@ impl ExecuteResponse {
             | AlterSchemaRename
             | AlterSchemaSwap
             | AlterSecret
+            | AlterTypeAddValue
             | AlterConnection
             | AlterSource
             | AlterSink
@@ -955,6 +965,7 @@ impl ExecuteResponse {
             CreateMaterializedView => &[CreatedMaterializedView],
             CreateIndex => &[CreatedIndex],
             CreateType => &[CreatedType],
+            CreateFunction => &[CreatedFunction],
             PlanKind::Deallocate => &[ExecuteResponseKind::Deallocate],
             CreateNetworkPolicy => &[CreatedNetworkPolicy],
             Declare => &[DeclaredCursor],
@@ -972,6 +983,7 @@ impl ExecuteResponse {
             Execute | ReadThenWrite => &[
                 Deleted,
                 Inserted,
+                Merged,
                 SendingRowsStreaming,
                 SendingRowsImmediate,
                 Updated,
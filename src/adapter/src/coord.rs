@@ -133,9 +133,7 @@ use mz_ore::task::{JoinHandle, spawn};
 use mz_ore::thread::JoinHandleExt;
 use mz_ore::tracing::{OpenTelemetryContext, TracingHandle};
 use mz_ore::url::SensitiveUrl;
-use mz_ore::{
-    assert_none, instrument, soft_assert_eq_or_log, soft_assert_or_log, soft_panic_or_log, stack,
-};
+use mz_ore::{assert_none, instrument, soft_assert_or_log, soft_panic_or_log, stack};
 use mz_persist_client::PersistClient;
 use mz_persist_client::batch::ProtoBatch;
 use mz_persist_client::usage::{ShardsUsageReferenced, StorageUsageClient};
@@ -199,6 +197,7 @@ use crate::coord::cluster_scheduling::SchedulingDecision;
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::introspection::IntrospectionSubscribe;
 use crate::coord::peek::PendingPeek;
+use crate::coord::source_schema_evolution::AddedSourceColumn;
 use crate::coord::statement_logging::StatementLogging;
 use crate::coord::timeline::{TimelineContext, TimelineState};
 use crate::coord::timestamp_selection::{TimestampContext, TimestampDetermination};
@@ -242,6 +241,7 @@ mod info_metrics;
 mod introspection;
 mod message_handler;
 mod privatelink_status;
+mod source_schema_evolution;
 mod sql;
 mod validity;
 
@@ -387,6 +387,7 @@ pub enum Message {
     ArrangementSizesSnapshot,
     ArrangementSizesWrite(Vec<ArrangementSizeRecord>),
     ArrangementSizesPrune(Vec<BuiltinTableUpdate>),
+    SourceSchemaEvolutionApply(Vec<AddedSourceColumn>),
     /// Performs any cleanup and logging actions necessary for
     /// finalizing a statement execution.
     RetireExecute {
@@ -540,6 +541,7 @@ impl Message {
             Message::ArrangementSizesSnapshot => "arrangement_sizes_snapshot",
             Message::ArrangementSizesWrite(_) => "arrangement_sizes_write",
             Message::ArrangementSizesPrune(_) => "arrangement_sizes_prune",
+            Message::SourceSchemaEvolutionApply(_) => "source_schema_evolution_apply",
             Message::RetireExecute { .. } => "retire_execute",
             Message::ExecuteSingleStatementTransaction { .. } => {
                 "execute_single_statement_transaction"
@@ -3262,20 +3264,33 @@ impl Coordinator {
                             desc: data_source_desc,
                             timeline,
                         } => {
-                            // TODO(alter_table): Support versioning tables that read from sources.
-                            soft_assert_eq_or_log!(table.collections.len(), 1);
-                            let collection_descs =
-                                table.collection_descs().map(|(gid, _version, desc)| {
-                                    (
-                                        gid,
-                                        source_desc(
-                                            entry.latest_global_id(),
-                                            data_source_desc,
-                                            &desc,
-                                            timeline,
-                                        ),
-                                    )
-                                });
+                            // Only the latest version of the table is written by its
+                            // source. Earlier versions depend on the next version, like the
+                            // versions of tables we write to.
+                            let versions: BTreeMap<_, _> = table
+                                .collection_descs()
+                                .map(|(gid, version, desc)| (version, (gid, desc)))
+                                .collect();
+                            let collection_descs = versions.iter().map(|(version, (gid, desc))| {
+                                let next_version = version.bump();
+                                let collection_desc = match versions.get(&next_version) {
+                                    Some((primary_collection, _desc)) => CollectionDescription {
+                                        desc: desc.clone(),
+                                        data_source: DataSource::Other,
+                                        since: None,
+                                        timeline: Some(timeline.clone()),
+                                        primary: Some(*primary_collection),
+                                    },
+                                    None => source_desc(
+                                        entry.latest_global_id(),
+                                        data_source_desc,
+                                        desc,
+                                        timeline,
+                                    ),
+                                };
+
+                                (*gid, collection_desc)
+                            });
                             collections.extend(collection_descs);
                         }
                     };
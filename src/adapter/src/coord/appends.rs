@@ -1540,6 +1540,7 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::CreateMaterializedView(_)
         | Plan::CreateIndex(_)
         | Plan::CreateType(_)
+        | Plan::CreateFunction(_)
         | Plan::Comment(_)
         | Plan::DiscardTemp
         | Plan::DiscardAll
@@ -1577,6 +1578,7 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::AlterSchemaRename(_)
         | Plan::AlterSchemaSwap(_)
         | Plan::AlterSecret(_)
+        | Plan::AlterTypeAddValue(_)
         | Plan::AlterSink(_)
         | Plan::AlterSystemSet(_)
         | Plan::AlterSystemReset(_)
@@ -1372,19 +1372,42 @@ impl Coordinator {
             .await
             .unwrap_or_terminate("unable to advance catalog upper");
 
-        self.controller
-            .storage
-            .alter_table_desc(existing_gid, new_gid, new_desc, expected_version)
-            .await
-            .unwrap_or_terminate("failed to alter desc of table");
-
-        // FIFO registration follows all staged writes to the old collection.
-        let registrations = self
-            .controller
-            .storage
-            .table_registrations(vec![new_gid])
-            .unwrap_or_terminate("cannot fail to look up table registrations");
-        self.register_tables_via_committer(registrations).await;
+        match &new_table.data_source {
+            TableDataSource::TableWrites { .. } => {
+                self.controller
+                    .storage
+                    .alter_table_desc(existing_gid, new_gid, new_desc, expected_version)
+                    .await
+                    .unwrap_or_terminate("failed to alter desc of table");
+
+                // FIFO registration follows all staged writes to the old collection.
+                let registrations = self
+                    .controller
+                    .storage
+                    .table_registrations(vec![new_gid])
+                    .unwrap_or_terminate("cannot fail to look up table registrations");
+                self.register_tables_via_committer(registrations).await;
+            }
+            TableDataSource::DataSource {
+                desc: DataSourceDesc::IngestionExport { details, .. },
+                timeline: _,
+            } => {
+                self.controller
+                    .storage
+                    .alter_ingestion_export_desc(
+                        existing_gid,
+                        new_gid,
+                        new_desc,
+                        expected_version,
+                        details.clone(),
+                    )
+                    .await
+                    .unwrap_or_terminate("failed to alter desc of source table");
+            }
+            TableDataSource::DataSource { desc, .. } => {
+                unreachable!("ALTER TABLE data source got {:?}", desc)
+            }
+        }
 
         // Initialize the ReadPolicy which ensures we have the correct read holds.
         let compaction_window = new_table
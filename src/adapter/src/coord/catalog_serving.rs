@@ -92,6 +92,7 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::CreateMaterializedView(_)
         | Plan::CreateIndex(_)
         | Plan::CreateType(_)
+        | Plan::CreateFunction(_)
         | Plan::Comment(_)
         | Plan::DiscardTemp
         | Plan::DiscardAll
@@ -140,6 +141,7 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::AlterSchemaRename(_)
         | Plan::AlterSchemaSwap(_)
         | Plan::AlterSecret(_)
+        | Plan::AlterTypeAddValue(_)
         | Plan::AlterSink(_)
         | Plan::AlterSystemSet(_)
         | Plan::AlterSystemReset(_)
@@ -526,7 +526,7 @@ impl Coordinator {
                     task::spawn(|| "copy_to_preflight", async move {
                         let result = mz_storage_types::sinks::s3_oneshot_sink::preflight(
                             connection_context,
-                            &s3_sink_connection.aws_connection,
+                            &s3_sink_connection.connection,
                             &s3_sink_connection.upload_info,
                             s3_sink_connection.connection_id,
                             sink_id,
@@ -1353,10 +1353,13 @@ impl Coordinator {
                     }
 
                     Statement::Insert(InsertStatement {
-                        source, returning, ..
+                        source,
+                        on_conflict: None,
+                        returning,
+                        ..
                     }) if returning.is_empty() && ConstantVisitor::insert_source(source) => {
                         // Inserting from constant values statements that do not need to execute on
-                        // any cluster (no RETURNING) is always safe.
+                        // any cluster (no RETURNING or ON CONFLICT) is always safe.
                     }
 
                     // These statements must be kept in-sync with `must_serialize_ddl()`.
@@ -1392,6 +1395,7 @@ impl Coordinator {
                     | Statement::AlterRetainHistory(_)
                     | Statement::AlterRole(_)
                     | Statement::AlterSecret(_)
+                    | Statement::AlterTypeAddValue(_)
                     | Statement::AlterSink(_)
                     | Statement::AlterSource(_)
                     | Statement::AlterSystemReset(_)
@@ -1412,6 +1416,7 @@ impl Coordinator {
                     | Statement::CreateSubsource(_)
                     | Statement::CreateTable(_)
                     | Statement::CreateType(_)
+                    | Statement::CreateFunction(_)
                     | Statement::CreateView(_)
                     | Statement::CreateWebhookSource(_)
                     | Statement::CreateNetworkPolicy(_)
@@ -1421,6 +1426,7 @@ impl Coordinator {
                     | Statement::GrantPrivileges(_)
                     | Statement::GrantRole(_)
                     | Statement::Insert(_)
+                    | Statement::Merge(_)
                     | Statement::ReassignOwned(_)
                     | Statement::RevokePrivileges(_)
                     | Statement::RevokeRole(_)
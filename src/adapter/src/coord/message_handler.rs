@@ -185,6 +185,11 @@ impl Coordinator {
             Message::ArrangementSizesPrune(expired) => {
                 self.arrangement_sizes_prune(expired).boxed_local().await;
             }
+            Message::SourceSchemaEvolutionApply(added) => {
+                self.source_schema_evolution_apply(added)
+                    .boxed_local()
+                    .await;
+            }
             Message::RetireExecute {
                 otel_ctx,
                 data,
@@ -769,6 +774,9 @@ impl Coordinator {
                     }
                 }
             }
+            ControllerResponse::SourceSchemaEvolutionPending(export_ids) => {
+                self.source_schema_evolution_check(export_ids);
+            }
         }
     }
 
@@ -12,7 +12,7 @@
 use std::collections::BTreeSet;
 
 use mz_catalog::memory::objects::CatalogItem;
-use mz_repr::CatalogItemId;
+use mz_repr::{CatalogItemId, DatumVec, Diff, RelationDesc, Row};
 use mz_sql::catalog::CatalogItemType;
 
 use crate::catalog::Catalog;
@@ -148,3 +148,56 @@ pub(crate) fn validate_read_then_write_dependencies(
     }
     Ok(())
 }
+
+/// Resolves collisions on any of the table's keys among the rows written by
+/// an `INSERT ... ON CONFLICT`, and returns the rows to write.
+///
+/// Conflicts with rows that are already in the table are resolved when
+/// planning the statement, but the rows proposed by the statement itself are
+/// not checked against one another until their diffs are known. Like in
+/// PostgreSQL, `DO UPDATE` (`update`) cannot affect a row a second time, so
+/// colliding rows are an error. `DO NOTHING` inserts identical proposed rows
+/// once, but distinct colliding rows are an error too: PostgreSQL keeps the
+/// first of them in `VALUES` order, which is not known once the proposed rows
+/// have been consolidated.
+pub(crate) fn resolve_upsert_keys(
+    desc: &RelationDesc,
+    diffs: Vec<(Row, Diff)>,
+    update: bool,
+) -> Result<Vec<(Row, Diff)>, AdapterError> {
+    let keys = &desc.typ().keys;
+    let mut seen = vec![BTreeSet::new(); keys.len()];
+    let mut inserted = BTreeSet::new();
+    let mut datum_vec = DatumVec::new();
+    let mut resolved = Vec::with_capacity(diffs.len());
+    for (row, mut diff) in diffs {
+        if diff.is_positive() {
+            let key_rows: Vec<_> = {
+                let datums = datum_vec.borrow_with(&row);
+                keys.iter()
+                    .map(|key| Row::pack(key.iter().map(|i| datums[*i])))
+                    .collect()
+            };
+            let collides = key_rows
+                .iter()
+                .zip(&seen)
+                .any(|(key, seen)| seen.contains(key));
+            if update && (collides || diff != Diff::ONE) {
+                return Err(AdapterError::OnConflictRowAffectedTwice);
+            }
+            if collides {
+                if inserted.contains(&row) {
+                    continue;
+                }
+                return Err(AdapterError::OnConflictRowAffectedTwice);
+            }
+            diff = Diff::ONE;
+            for (key, seen) in key_rows.into_iter().zip(&mut seen) {
+                seen.insert(key);
+            }
+            inserted.insert(row.clone());
+        }
+        resolved.push((row, diff));
+    }
+    Ok(resolved)
+}
@@ -45,7 +45,8 @@ use mz_sql::session::vars::SessionVars;
 use mz_sql_parser::ast::{Raw, Statement};
 use mz_storage_client::client::TableData;
 use mz_storage_client::storage_collections::StorageCollections;
-use mz_storage_types::connections::inline::IntoInlineConnection;
+use mz_storage_types::connections::Connection;
+use mz_storage_types::connections::inline::{IntoInlineConnection, ReferencedConnection};
 use mz_storage_types::controller::StorageError;
 use mz_storage_types::stats::RelationPartStats;
 use mz_transform::dataflow::DataflowMetainfo;
@@ -299,6 +300,12 @@ impl Coordinator {
                         .await;
                     ctx.retire(result);
                 }
+                Plan::CreateFunction(plan) => {
+                    let result = self
+                        .sequence_create_function(ctx.session(), plan, resolved_ids)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::CreateNetworkPolicy(plan) => {
                     let res = self
                         .sequence_create_network_policy(ctx.session(), plan)
@@ -511,6 +518,12 @@ impl Coordinator {
                 Plan::AlterSecret(plan) => {
                     self.sequence_alter_secret(ctx, plan).await;
                 }
+                Plan::AlterTypeAddValue(plan) => {
+                    let result = self
+                        .sequence_alter_type_add_value(ctx.session(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::AlterSink(plan) => {
                     self.sequence_alter_sink_prepare(ctx, plan).await;
                 }
@@ -877,6 +890,7 @@ impl Coordinator {
                     updates: rows,
                     kind: MutationKind::Insert,
                     returning: Vec::new(),
+                    affected_rows: None,
                     max_result_size: catalog.system_config().max_result_size(),
                 };
                 Self::send_diffs(session, diffs_plan)
@@ -893,7 +907,20 @@ impl Coordinator {
         session: &mut Session,
         mut plan: plan::SendDiffsPlan,
     ) -> Result<ExecuteResponse, AdapterError> {
-        let affected_rows = {
+        let affected_rows = if let Some(affected_rows) = plan.affected_rows {
+            affected_rows
+        } else if let MutationKind::Upsert { .. } = plan.kind {
+            // Every row proposed for insertion is either inserted or replaces
+            // the row it conflicts with, which is also retracted. Like
+            // PostgreSQL, count each of them once.
+            let mut affected_rows = Diff::ZERO;
+            for (_, diff) in plan.updates.iter() {
+                if diff.is_positive() {
+                    affected_rows += diff;
+                }
+            }
+            usize::try_from(affected_rows.into_inner()).expect("positive Diff must fit")
+        } else {
             let mut affected_rows = Diff::from(0);
             let mut all_positive_diffs = true;
             // If all diffs are positive, the number of affected rows is just the
@@ -960,8 +987,11 @@ impl Coordinator {
         }
         Ok(match plan.kind {
             MutationKind::Delete => ExecuteResponse::Deleted(affected_rows),
-            MutationKind::Insert => ExecuteResponse::Inserted(affected_rows),
+            MutationKind::Insert | MutationKind::Upsert { .. } => {
+                ExecuteResponse::Inserted(affected_rows)
+            }
             MutationKind::Update => ExecuteResponse::Updated(affected_rows / 2),
+            MutationKind::Merge { .. } => ExecuteResponse::Merged(affected_rows),
         })
     }
 }
@@ -1065,6 +1095,7 @@ pub(crate) fn emit_optimizer_notices(
 /// and the new frontend peek sequencing to avoid code duplication.
 pub fn eval_copy_to_uri(
     to: HirScalarExpr,
+    connection: &Connection<ReferencedConnection>,
     session: &Session,
     catalog_state: &CatalogState,
 ) -> Result<Uri, AdapterError> {
@@ -1085,6 +1116,11 @@ pub fn eval_copy_to_uri(
             if url.scheme_str() != Some("s3") && url.scheme_str() != Some("gs") {
                 coord_bail!("only 's3://...' and 'gs://...' urls are supported as COPY TO target");
             }
+            if matches!(connection, Connection::Gcp(_)) && url.scheme_str() != Some("gs") {
+                coord_bail!(
+                    "only 'gs://...' urls are supported as COPY TO target with a GCP CONNECTION"
+                );
+            }
             url
         }
         Err(e) => coord_bail!("could not parse COPY TO target url: {}", e),
@@ -23,7 +23,7 @@ use mz_adapter_types::connection::ConnectionId;
 use mz_adapter_types::dyncfgs::{ENABLE_PASSWORD_AUTH, READ_THEN_WRITE_MAX_DEPENDENCIES};
 use mz_catalog::memory::error::ErrorKind;
 use mz_catalog::memory::objects::{
-    CatalogItem, Connection, DataSourceDesc, Sink, Source, Table, TableDataSource, Type,
+    CatalogItem, Connection, DataSourceDesc, Func, Sink, Source, Table, TableDataSource, Type,
 };
 use mz_expr::{
     CollectionPlan, Eval, MapFilterProject, OptimizedMirRelationExpr, ResultSpec, RowSetFinishing,
@@ -81,7 +81,7 @@ use mz_sql::session::vars::{
 use mz_sql::{plan, rbac};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
-    ConnectionOption, ConnectionOptionName, CreateSourceConnection, DeferredItemName,
+    ConnectionOption, ConnectionOptionName, CreateSourceConnection, DeferredItemName, Ident,
     MySqlConfigOption, PgConfigOption, PgConfigOptionName, Statement, TransactionMode,
     WithOptionValue,
 };
@@ -90,6 +90,8 @@ use mz_storage_client::controller::ExportDescription;
 use mz_storage_types::AlterCompatible;
 use mz_storage_types::connections::inline::IntoInlineConnection;
 use mz_storage_types::controller::StorageError;
+use mz_storage_types::sources::postgres::{PostgresSourceConnection, PostgresTruncateBehavior};
+use mz_storage_types::sources::{GenericSourceConnection, SourceDesc};
 use mz_transform::dataflow::DataflowMetainfo;
 use mz_transform::notice::{OptimizerNotice, RawOptimizerNotice};
 use smallvec::SmallVec;
@@ -102,7 +104,7 @@ use crate::command::{ExecuteResponse, Response};
 use crate::coord::appends::{
     BuiltinTableAppendNotify, DeferredOp, DeferredPlan, PendingWriteTxn, UserWriteResponder,
 };
-use crate::coord::read_then_write::validate_read_then_write_dependencies;
+use crate::coord::read_then_write::{resolve_upsert_keys, validate_read_then_write_dependencies};
 use crate::coord::sequencer::emit_optimizer_notices;
 use crate::coord::{
     AlterConnectionValidationReady, AlterMaterializedViewReadyContext, AlterSinkReadyContext,
@@ -441,7 +443,25 @@ impl Coordinator {
 
         let item_id = entry.id();
         let ingestion_id = source.global_id();
-        let subsource_stmts = generate_subsource_statements(&scx, source_name, subsources)?;
+        let mut subsource_stmts = generate_subsource_statements(&scx, source_name, subsources)?;
+
+        // Added subsources take the truncate behavior of their source.
+        if let Ok(Some(SourceDesc {
+            connection:
+                GenericSourceConnection::Postgres(PostgresSourceConnection {
+                    truncate_behavior: PostgresTruncateBehavior::Retract,
+                    ..
+                }),
+            ..
+        })) = entry.source_desc()
+        {
+            for subsource_stmt in subsource_stmts.iter_mut() {
+                subsource_stmt.with_options.push(CreateSubsourceOption {
+                    name: CreateSubsourceOptionName::TruncateBehavior,
+                    value: Some(WithOptionValue::Ident(Ident::new_unchecked("retract"))),
+                });
+            }
+        }
 
         let ids = self
             .allocate_user_ids(u64::cast_from(subsource_stmts.len()))
@@ -538,7 +558,7 @@ impl Coordinator {
         let catalog = self.catalog().for_session(ctx.session());
         let resolved_ids = mz_sql::names::visit_dependencies(&catalog, &source_stmt);
 
-        let propagated_with_options: Vec<_> = source_stmt
+        let mut propagated_with_options: Vec<_> = source_stmt
             .with_options
             .iter()
             .filter_map(|opt| match opt.name {
@@ -550,6 +570,20 @@ impl Coordinator {
             })
             .collect();
 
+        // The source-level `TRUNCATE BEHAVIOR` is the default for the
+        // subsources created alongside the source.
+        if let CreateSourceConnection::Postgres { options, .. } = &source_stmt.connection {
+            propagated_with_options.extend(
+                options
+                    .iter()
+                    .filter(|opt| opt.name == PgConfigOptionName::TruncateBehavior)
+                    .map(|opt| CreateSubsourceOption {
+                        name: CreateSubsourceOptionName::TruncateBehavior,
+                        value: opt.value.clone(),
+                    }),
+            );
+        }
+
         // 2. Then plan the main source.
         let source_plan = match self.plan_statement(
             ctx.session(),
@@ -1248,6 +1282,76 @@ impl Coordinator {
         }
     }
 
+    #[instrument]
+    pub(super) async fn sequence_alter_type_add_value(
+        &mut self,
+        session: &Session,
+        plan::AlterTypeAddValuePlan { id, typ }: plan::AlterTypeAddValuePlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let entry = self.catalog().get_entry(&id);
+        let CatalogItem::Type(cur_type) = entry.item() else {
+            unreachable!("known to be a type");
+        };
+        let mut new_type = cur_type.clone();
+        new_type.create_sql = Some(typ.create_sql);
+        new_type.details.typ = typ.inner;
+
+        // Items that use the type are re-planned against the new labels when
+        // the catalog applies this update, so any cached expressions for them
+        // are stale.
+        let expr_cache_invalidate_ids: BTreeSet<_> = self
+            .catalog()
+            .object_dependents(&vec![ObjectId::Item(id)], session.conn_id())
+            .iter()
+            .filter_map(|id| match id {
+                ObjectId::Item(item_id) => Some(self.catalog().get_entry(item_id).global_ids()),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+
+        let ops = vec![catalog::Op::UpdateItem {
+            id,
+            name: entry.name().clone(),
+            to_item: CatalogItem::Type(new_type),
+        }];
+        self.catalog_transact(Some(session), ops).await?;
+
+        let _fut = self.catalog().update_expression_cache(
+            Default::default(),
+            Default::default(),
+            expr_cache_invalidate_ids,
+        );
+
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Type))
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_create_function(
+        &mut self,
+        session: &Session,
+        plan: plan::CreateFunctionPlan,
+        resolved_ids: ResolvedIds,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let (item_id, global_id) = self.allocate_user_id().await?;
+        let func = Func {
+            create_sql: Some(plan.function.create_sql),
+            inner: mz_sql::func::FuncRef::User(plan.function.inner),
+            global_id,
+            resolved_ids,
+        };
+        let op = catalog::Op::CreateItem {
+            id: item_id,
+            name: plan.name,
+            item: CatalogItem::Func(func),
+            owner_id: *session.current_role_id(),
+        };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(()) => Ok(ExecuteResponse::CreatedFunction),
+            Err(err) => Err(err),
+        }
+    }
+
     #[instrument]
     pub(super) async fn sequence_comment_on(
         &mut self,
@@ -2911,57 +3015,95 @@ impl Coordinator {
                 return_if_err!(style.prep_scalar_expr(expr), ctx);
             }
 
-            let make_diffs = move |mut rows: Box<dyn RowIterator>|
-                  -> Result<(Vec<(Row, Diff)>, u64), AdapterError> {
-                    let arena = RowArena::new();
-                    let mut diffs = Vec::new();
-                    let mut datum_vec = mz_repr::DatumVec::new();
-
-                    while let Some(row) = rows.next() {
-                        if !assignments.is_empty() {
-                            assert!(
-                                matches!(kind, MutationKind::Update),
-                                "only updates support assignments"
-                            );
+            let upsert = match kind {
+                MutationKind::Upsert { update } => Some((desc.clone(), update)),
+                _ => None,
+            };
+            let make_diffs = move |mut rows: Box<dyn RowIterator>| -> Result<
+                (Vec<(Row, Diff)>, Vec<(Row, Diff)>, u64),
+                AdapterError,
+            > {
+                let arena = RowArena::new();
+                let mut diffs = Vec::new();
+                // The rows that a `MERGE` reports as inserted, updated, or
+                // deleted.
+                let mut merged = Vec::new();
+                let mut datum_vec = mz_repr::DatumVec::new();
+
+                while let Some(row) = rows.next() {
+                    if !assignments.is_empty() {
+                        assert!(
+                            matches!(kind, MutationKind::Update),
+                            "only updates support assignments"
+                        );
+                        let mut datums = datum_vec.borrow_with(row);
+                        let mut updates = vec![];
+                        for (idx, expr) in &assignments {
+                            let updated = match expr.eval(&datums, &arena) {
+                                Ok(updated) => updated,
+                                Err(e) => return Err(AdapterError::Unstructured(anyhow!(e))),
+                            };
+                            updates.push((*idx, updated));
+                        }
+                        for (idx, new_value) in updates {
+                            datums[idx] = new_value;
+                        }
+                        let updated = Row::pack_slice(&datums);
+                        diffs.push((updated, Diff::ONE));
+                    }
+                    match kind {
+                        // Updates and deletes always remove the
+                        // current row. Updates will also add an
+                        // updated value.
+                        MutationKind::Update | MutationKind::Delete => {
+                            diffs.push((row.to_owned(), Diff::MINUS_ONE))
+                        }
+                        MutationKind::Insert => diffs.push((row.to_owned(), Diff::ONE)),
+                        MutationKind::Upsert { .. } => {
                             let mut datums = datum_vec.borrow_with(row);
-                            let mut updates = vec![];
-                            for (idx, expr) in &assignments {
-                                let updated = match expr.eval(&datums, &arena) {
-                                    Ok(updated) => updated,
-                                    Err(e) => return Err(AdapterError::Unstructured(anyhow!(e))),
-                                };
-                                updates.push((*idx, updated));
-                            }
-                            for (idx, new_value) in updates {
-                                datums[idx] = new_value;
-                            }
-                            let updated = Row::pack_slice(&datums);
-                            diffs.push((updated, Diff::ONE));
+                            let retract = datums
+                                .pop()
+                                .expect("upsert rows end in a retraction marker");
+                            let diff = if retract == Datum::True {
+                                Diff::MINUS_ONE
+                            } else {
+                                Diff::ONE
+                            };
+                            diffs.push((Row::pack_slice(&datums), diff));
                         }
-                        match kind {
-                            // Updates and deletes always remove the
-                            // current row. Updates will also add an
-                            // updated value.
-                            MutationKind::Update | MutationKind::Delete => {
-                                diffs.push((row.to_owned(), Diff::MINUS_ONE))
-                            }
-                            MutationKind::Insert => diffs.push((row.to_owned(), Diff::ONE)),
+                        MutationKind::Merge { .. } => {
+                            let mut datums = datum_vec.borrow_with(row);
+                            let marker = datums.pop().expect("merge rows end in a marker");
+                            let row = Row::pack_slice(&datums);
+                            let diff = match marker {
+                                Datum::Null => Diff::MINUS_ONE,
+                                Datum::True => {
+                                    merged.push((row.clone(), Diff::ONE));
+                                    Diff::MINUS_ONE
+                                }
+                                _ => {
+                                    merged.push((row.clone(), Diff::ONE));
+                                    Diff::ONE
+                                }
+                            };
+                            diffs.push((row, diff));
                         }
                     }
+                }
 
-                    // Sum of all the rows' byte size, for checking if we go
-                    // above the max_result_size threshold.
-                    let mut byte_size: u64 = 0;
-                    for (row, diff) in &diffs {
-                        byte_size = byte_size.saturating_add(u64::cast_from(row.byte_len()));
-                        if diff.is_positive() {
-                            for (idx, datum) in row.iter().enumerate() {
-                                desc.constraints_met(idx, &datum)?;
-                            }
+                // Sum of all the rows' byte size, for checking if we go
+                // above the max_result_size threshold.
+                let mut byte_size: u64 = 0;
+                for (row, diff) in &diffs {
+                    byte_size = byte_size.saturating_add(u64::cast_from(row.byte_len()));
+                    if diff.is_positive() {
+                        for (idx, datum) in row.iter().enumerate() {
+                            desc.constraints_met(idx, &datum)?;
                         }
                     }
-                    Ok((diffs, byte_size))
-                };
+                }
+                Ok((diffs, merged, byte_size))
+            };
 
             let diffs = match peek_response {
                 ExecuteResponse::SendingRowsStreaming {
@@ -2970,19 +3112,21 @@ impl Coordinator {
                 } => {
                     let mut byte_size: u64 = 0;
                     let mut diffs = Vec::new();
+                    let mut merged = Vec::new();
                     let result = loop {
                         match tokio::time::timeout(timeout_dur, rows_stream.next()).await {
                             Ok(Some(res)) => match res {
                                 PeekResponseUnary::Rows(new_rows) => {
                                     match make_diffs(new_rows) {
-                                        Ok((mut new_diffs, new_byte_size)) => {
+                                        Ok((mut new_diffs, mut new_merged, new_byte_size)) => {
                                             byte_size = byte_size.saturating_add(new_byte_size);
                                             if byte_size > max_result_size {
                                                 break Err(AdapterError::ResultSize(format!(
                                                     "result exceeds max size of {max_result_size}"
                                                 )));
                                             }
-                                            diffs.append(&mut new_diffs)
+                                            diffs.append(&mut new_diffs);
+                                            merged.append(&mut new_merged);
                                         }
                                         Err(e) => break Err(e),
                                     };
@@ -2995,7 +3139,7 @@ impl Coordinator {
                                     break Err(dep.to_concurrent_dependency_drop());
                                 }
                             },
-                            Ok(None) => break Ok(diffs),
+                            Ok(None) => break Ok((diffs, merged)),
                             Err(_) => {
                                 // We timed out, so remove the pending peek. This is
                                 // best-effort and doesn't guarantee we won't
@@ -3015,18 +3159,30 @@ impl Coordinator {
                     result
                 }
                 ExecuteResponse::SendingRowsImmediate { rows } => {
-                    make_diffs(rows).map(|(diffs, _byte_size)| diffs)
+                    make_diffs(rows).map(|(diffs, merged, _byte_size)| (diffs, merged))
                 }
                 resp => Err(AdapterError::Unstructured(anyhow!(
                     "unexpected peek response: {resp:?}"
                 ))),
             };
+            let diffs = match (upsert, diffs) {
+                (Some((desc, update)), Ok((diffs, merged))) => {
+                    resolve_upsert_keys(&desc, diffs, update).map(|diffs| (diffs, merged))
+                }
+                (_, diffs) => diffs,
+            };
 
             let mut returning_rows = Vec::new();
             let mut diff_err: Option<AdapterError> = None;
-            if let (false, Ok(diffs)) = (returning.is_empty(), &diffs) {
+            if let (false, Ok((diffs, merged))) = (returning.is_empty(), &diffs) {
                 let arena = RowArena::new();
-                for (row, diff) in diffs {
+                // A `MERGE` returns the rows it inserts, updates, or deletes,
+                // rather than the rows it writes.
+                let returned = match kind {
+                    MutationKind::Merge { .. } => merged,
+                    _ => diffs,
+                };
+                for (row, diff) in returned {
                     if !diff.is_positive() {
                         continue;
                     }
@@ -3114,7 +3270,9 @@ impl Coordinator {
             }
 
             match diffs {
-                Ok(diffs) => {
+                Ok((diffs, merged)) => {
+                    let affected_rows =
+                        matches!(kind, MutationKind::Merge { .. }).then_some(merged.len());
                     let result = Self::send_diffs(
                         ctx.session_mut(),
                         plan::SendDiffsPlan {
@@ -3122,6 +3280,7 @@ impl Coordinator {
                             updates: diffs,
                             kind,
                             returning: returning_rows,
+                            affected_rows,
                             max_result_size,
                         },
                     );
@@ -4779,6 +4938,7 @@ impl Coordinator {
             name: column_name,
             typ: column_type,
             sql: raw_sql_type,
+            source_export: None,
         }];
 
         self.catalog_transact_with_context(None, Some(ctx), ops)
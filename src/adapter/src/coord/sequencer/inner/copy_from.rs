@@ -137,6 +137,10 @@ impl Coordinator {
                 mz_storage_types::oneshot_sources::ContentFormat::Csv(csv.to_owned())
             }
             CopyFormatParams::Parquet => mz_storage_types::oneshot_sources::ContentFormat::Parquet,
+            CopyFormatParams::Json(json) => {
+                mz_storage_types::oneshot_sources::ContentFormat::Json(json)
+            }
+            CopyFormatParams::Avro => mz_storage_types::oneshot_sources::ContentFormat::Avro,
             CopyFormatParams::Text(_) | CopyFormatParams::Binary => {
                 mz_ore::soft_panic_or_log!("unsupported formats should be rejected in planning");
                 ctx.retire(Err(AdapterError::Unsupported("COPY FROM URL/S3 format")));
@@ -215,6 +219,37 @@ impl Coordinator {
                     uri: uri.to_string(),
                 }
             }
+            CopyFromSource::Gcs {
+                uri,
+                connection,
+                connection_id,
+            } => {
+                let uri = return_if_err!(eval_uri(uri), ctx);
+
+                // Validate the URI is a GCS URI, with a bucket name. We rely on validating here
+                // and expect it in clusterd. The path may contain glob characters that aren't
+                // valid in a URI, so we don't parse it as one.
+                //
+                // TODO(cf2): Structured errors.
+                let result = match uri.strip_prefix("gs://") {
+                    None => Err(AdapterError::Unstructured(anyhow::anyhow!(
+                        "only 'gs://...' urls are supported as COPY FROM target with a GCP CONNECTION"
+                    ))),
+                    Some(rest) if rest.split('/').next().unwrap_or_default().is_empty() => {
+                        Err(AdapterError::Unstructured(anyhow::anyhow!(
+                            "missing bucket name from 'gs://...' url"
+                        )))
+                    }
+                    Some(_) => Ok(()),
+                };
+                return_if_err!(result, ctx);
+
+                mz_storage_types::oneshot_sources::ContentSource::Gcs {
+                    connection,
+                    connection_id,
+                    uri,
+                }
+            }
             CopyFromSource::Stdin => {
                 unreachable!("STDIN handled by the early return above")
             }
@@ -160,7 +160,7 @@ impl Coordinator {
         target_cluster: TargetCluster,
     ) {
         let uri = return_if_err!(
-            eval_copy_to_uri(to, ctx.session(), self.catalog().state()),
+            eval_copy_to_uri(to, &connection, ctx.session(), self.catalog().state()),
             ctx
         );
 
@@ -881,7 +881,7 @@ impl Coordinator {
                     ComputeSinkConnection::CopyToS3Oneshot(conn) => {
                         mz_storage_types::sinks::s3_oneshot_sink::preflight(
                             connection_context,
-                            &conn.aws_connection,
+                            &conn.connection,
                             &conn.upload_info,
                             conn.connection_id,
                             *sink_id,
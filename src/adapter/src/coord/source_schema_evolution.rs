@@ -0,0 +1,551 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Schema evolution of tables that are fed by PostgreSQL, MySQL and SQL Server
+//! sources.
+//!
+//! Tables created with `SCHEMA CHANGE BEHAVIOR = EVOLVE` pick up the nullable
+//! columns without a default value that are added to their upstream table. The
+//! source notices added columns and stalls on such a table until its new
+//! columns have been added, so that no values of the new columns are dropped.
+//! PostgreSQL sources notice them in the relation messages of their replication
+//! stream, MySQL sources in the `ALTER TABLE` statements in their binlog, and
+//! SQL Server sources in the newest capture instance of the upstream table,
+//! which is the only one that captures the added columns. The stalled table
+//! reports this in its status, which the storage controller passes on to us.
+//!
+//! The coordinator then looks up the added columns in a background task
+//! ([Coordinator::source_schema_evolution_check]) and adds them to the tables
+//! ([Coordinator::source_schema_evolution_apply]). Each column is added in a
+//! catalog transaction of its own, because every version of a table maps to
+//! exactly one persist schema. Tables fed by SQL Server sources switch to the
+//! capture instance that captures their added columns, which only captures
+//! the changes since its creation. The added columns read as NULL in the
+//! changes between their addition and the switch.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use mz_adapter_types::dyncfgs::ENABLE_SOURCE_SCHEMA_EVOLUTION;
+use mz_catalog::memory::objects::{
+    CatalogItem, DataSourceDesc, SourceExportColumn, Table, TableDataSource,
+};
+use mz_mysql_util::{MySqlAddedColumn, SchemaRequest};
+use mz_ore::collections::CollectionExt;
+use mz_ore::error::ErrorExt;
+use mz_ore::future::InTask;
+use mz_ore::task;
+use mz_postgres_util::desc::PostgresColumnDesc;
+use mz_proto::RustType;
+use mz_repr::{CatalogItemId, ColumnName, GlobalId};
+use mz_secrets::SecretsReader;
+use mz_sql::ast::{Statement, TableFromSourceOptionName, Value, WithOptionValue};
+use mz_sql::plan::StatementContext;
+use mz_sql::pure::AddedColumnType;
+use mz_sql_server_util::desc::SqlServerColumnDesc;
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::connections::inline::{InlinedConnection, IntoInlineConnection};
+use mz_storage_types::sources::mysql::{MySqlSourceConnection, MySqlSourceExportDetails};
+use mz_storage_types::sources::postgres::{PostgresSourceConnection, PostgresSourceExportDetails};
+use mz_storage_types::sources::sql_server::{
+    SqlServerSourceConnection, SqlServerSourceExportDetails,
+};
+use mz_storage_types::sources::{
+    GenericSourceConnection, ProtoSourceExportStatementDetails, SchemaChangeBehavior, SourceDesc,
+    SourceExportDetails, SourceExportStatementDetails,
+};
+use prost::Message as _;
+use tracing::{debug, info, warn};
+
+use crate::AdapterError;
+use crate::catalog::Op;
+use crate::coord::{Coordinator, Message};
+
+/// A column that was added to the upstream table of a table that evolves its
+/// schema.
+#[derive(Debug)]
+pub struct AddedSourceColumn {
+    table_id: CatalogItemId,
+    column: AddedColumn,
+}
+
+/// A column that was added to an upstream table, by the kind of source that
+/// feeds the table.
+#[derive(Debug)]
+enum AddedColumn {
+    Postgres(PostgresColumnDesc),
+    MySql(MySqlAddedColumn),
+    SqlServer {
+        column: SqlServerColumnDesc,
+        /// The capture instance that captures the column.
+        capture_instance: Arc<str>,
+    },
+}
+
+impl AddedColumn {
+    fn name(&self) -> &str {
+        match self {
+            AddedColumn::Postgres(column) => &column.name,
+            AddedColumn::MySql(column) => &column.desc.name,
+            AddedColumn::SqlServer { column, .. } => &column.name,
+        }
+    }
+}
+
+/// The tables that evolve their schema and are fed by the same source, along
+/// with the source's connection.
+enum SourceTables {
+    Postgres(
+        PostgresSourceConnection<InlinedConnection>,
+        Vec<(CatalogItemId, PostgresSourceExportDetails)>,
+    ),
+    MySql(
+        MySqlSourceConnection<InlinedConnection>,
+        Vec<(CatalogItemId, MySqlSourceExportDetails)>,
+    ),
+    SqlServer(
+        SqlServerSourceConnection<InlinedConnection>,
+        Vec<(CatalogItemId, SqlServerSourceExportDetails)>,
+    ),
+}
+
+impl Coordinator {
+    /// Kicks off a check for columns added to the upstream tables of the tables
+    /// whose exports `export_ids` reported that they wait for added columns.
+    ///
+    /// Connecting to the upstream databases happens in a background task,
+    /// which sends the added columns back as
+    /// [`Message::SourceSchemaEvolutionApply`]. Reports that are not acted on,
+    /// e.g. in read-only mode, are repeated whenever the source restarts.
+    pub(crate) fn source_schema_evolution_check(&self, export_ids: Vec<GlobalId>) {
+        // Tables can only be altered outside of read-only mode.
+        if !ENABLE_SOURCE_SCHEMA_EVOLUTION.get(self.catalog().system_config().dyncfgs())
+            || self.controller.read_only()
+        {
+            return;
+        }
+
+        // Group the evolving tables by the source that feeds them.
+        let mut tables_by_source: BTreeMap<_, BTreeMap<_, _>> = BTreeMap::new();
+        for export_id in export_ids {
+            // The table might have been dropped since the report.
+            let Some(entry) = self.catalog().try_get_entry_by_global_id(&export_id) else {
+                continue;
+            };
+            let CatalogItem::Table(Table {
+                data_source:
+                    TableDataSource::DataSource {
+                        desc:
+                            DataSourceDesc::IngestionExport {
+                                ingestion_id,
+                                details,
+                                ..
+                            },
+                        ..
+                    },
+                ..
+            }) = entry.item()
+            else {
+                continue;
+            };
+            let schema_change_behavior = match details {
+                SourceExportDetails::Postgres(details) => details.schema_change_behavior,
+                SourceExportDetails::MySql(details) => details.schema_change_behavior,
+                SourceExportDetails::SqlServer(details) => details.schema_change_behavior,
+                _ => continue,
+            };
+            if schema_change_behavior == SchemaChangeBehavior::Evolve {
+                tables_by_source
+                    .entry(*ingestion_id)
+                    .or_default()
+                    .insert(entry.id(), details.clone());
+            }
+        }
+
+        let mut checks = vec![];
+        for (source_id, tables) in tables_by_source {
+            let source = self.catalog().get_entry(&source_id);
+            let Ok(Some(SourceDesc { connection, .. })) = source.source_desc() else {
+                continue;
+            };
+            let tables = match connection
+                .clone()
+                .into_inline_connection(self.catalog().state())
+            {
+                GenericSourceConnection::Postgres(connection) => SourceTables::Postgres(
+                    connection,
+                    tables
+                        .into_iter()
+                        .filter_map(|(id, details)| match details {
+                            SourceExportDetails::Postgres(details) => Some((id, details)),
+                            _ => None,
+                        })
+                        .collect(),
+                ),
+                GenericSourceConnection::MySql(connection) => SourceTables::MySql(
+                    connection,
+                    tables
+                        .into_iter()
+                        .filter_map(|(id, details)| match details {
+                            SourceExportDetails::MySql(details) => Some((id, details)),
+                            _ => None,
+                        })
+                        .collect(),
+                ),
+                GenericSourceConnection::SqlServer(connection) => SourceTables::SqlServer(
+                    connection,
+                    tables
+                        .into_iter()
+                        .filter_map(|(id, details)| match details {
+                            SourceExportDetails::SqlServer(details) => Some((id, details)),
+                            _ => None,
+                        })
+                        .collect(),
+                ),
+                _ => continue,
+            };
+            checks.push((source_id, tables));
+        }
+        if checks.is_empty() {
+            return;
+        }
+
+        let secrets_reader = Arc::clone(self.secrets_reader());
+        let storage_config = self.controller.storage.config().clone();
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "source_schema_evolution_check", async move {
+            let mut added = vec![];
+            for (source_id, tables) in checks {
+                let result = match &tables {
+                    SourceTables::Postgres(connection, tables) => {
+                        postgres_added_columns(connection, tables, &secrets_reader, &storage_config)
+                            .await
+                    }
+                    SourceTables::MySql(connection, tables) => {
+                        mysql_added_columns(connection, tables, &secrets_reader, &storage_config)
+                            .await
+                    }
+                    SourceTables::SqlServer(connection, tables) => {
+                        sql_server_added_columns(
+                            connection,
+                            tables,
+                            &secrets_reader,
+                            &storage_config,
+                        )
+                        .await
+                    }
+                };
+                match result {
+                    Ok(columns) => added.extend(columns),
+                    // Problems with the upstream database are reported by the
+                    // source itself.
+                    Err(err) => debug!(
+                        %source_id,
+                        "failed to check for added columns: {}",
+                        err.display_with_causes()
+                    ),
+                }
+            }
+            // Send is best-effort: if the coordinator is shutting down, drop.
+            let _ = internal_cmd_tx.send(Message::SourceSchemaEvolutionApply(added));
+        });
+    }
+
+    /// Adds the columns found by [Coordinator::source_schema_evolution_check]
+    /// to their tables.
+    pub(crate) async fn source_schema_evolution_apply(&mut self, added: Vec<AddedSourceColumn>) {
+        for AddedSourceColumn { table_id, column } in added {
+            if let Err(err) = self.add_source_table_column(table_id, &column).await {
+                warn!(
+                    %table_id,
+                    column = %column.name(),
+                    "failed to add column to source table: {}",
+                    err.display_with_causes()
+                );
+            }
+        }
+    }
+
+    /// Adds the upstream `column` to the table `table_id`, unless the table was
+    /// dropped or already has the column.
+    async fn add_source_table_column(
+        &mut self,
+        table_id: CatalogItemId,
+        column: &AddedColumn,
+    ) -> Result<(), AdapterError> {
+        let Some(entry) = self.catalog().try_get_entry(&table_id) else {
+            return Ok(());
+        };
+        let Statement::CreateTableFromSource(stmt) =
+            mz_sql::parse::parse(entry.create_sql())?.into_element().ast
+        else {
+            return Err(AdapterError::Internal(format!(
+                "{table_id} is not a table from a source"
+            )));
+        };
+        let Some(WithOptionValue::Value(Value::String(details))) = stmt
+            .with_options
+            .iter()
+            .find(|o| o.name == TableFromSourceOptionName::Details)
+            .and_then(|o| o.value.as_ref())
+        else {
+            return Err(AdapterError::Internal(format!(
+                "table {table_id} is missing its source export details"
+            )));
+        };
+        let details = hex::decode(details).map_err(anyhow::Error::from)?;
+        let details =
+            ProtoSourceExportStatementDetails::decode(&*details).map_err(anyhow::Error::from)?;
+        let details =
+            SourceExportStatementDetails::from_proto(details).map_err(anyhow::Error::from)?;
+
+        let conn_catalog = self.catalog().for_system_session();
+        let scx = StatementContext::new(None, &conn_catalog);
+        let (details, column_type) = match (details, column) {
+            (
+                SourceExportStatementDetails::Postgres {
+                    mut table,
+                    cast_oid_full_range,
+                },
+                AddedColumn::Postgres(column),
+            ) => {
+                // The column might have been added since the check.
+                if table.columns.iter().any(|c| c.name == column.name) {
+                    return Ok(());
+                }
+                let column_type =
+                    mz_sql::pure::postgres::added_column_type(&scx, column, cast_oid_full_range)?;
+                table.columns.push(column.clone());
+                let details = SourceExportStatementDetails::Postgres {
+                    table,
+                    cast_oid_full_range,
+                };
+                (details, column_type)
+            }
+            (
+                SourceExportStatementDetails::MySql {
+                    mut table,
+                    initial_gtid_set,
+                    binlog_full_metadata,
+                },
+                AddedColumn::MySql(column),
+            ) => {
+                if table.columns.iter().any(|c| c.name == column.desc.name) {
+                    return Ok(());
+                }
+                let column_type =
+                    mz_sql::pure::mysql::added_column_type(&scx, &column.desc, column.text)?;
+                table.columns.push(column.desc.clone());
+                let details = SourceExportStatementDetails::MySql {
+                    table,
+                    initial_gtid_set,
+                    binlog_full_metadata,
+                };
+                (details, column_type)
+            }
+            (
+                SourceExportStatementDetails::SqlServer {
+                    mut table,
+                    capture_instance: _,
+                    initial_lsn,
+                },
+                AddedColumn::SqlServer {
+                    column,
+                    capture_instance,
+                },
+            ) => {
+                if table.columns.iter().any(|c| c.name == column.name) {
+                    return Ok(());
+                }
+                let column_type = mz_sql::pure::sql_server::added_column_type(&scx, column)?;
+                let mut columns = table.columns.into_vec();
+                columns.push(column.clone());
+                table.columns = columns.into();
+                // Only the latest capture instance captures the added column.
+                let details = SourceExportStatementDetails::SqlServer {
+                    table,
+                    capture_instance: Arc::clone(capture_instance),
+                    initial_lsn,
+                };
+                (details, column_type)
+            }
+            _ => {
+                return Err(AdapterError::Internal(format!(
+                    "table {table_id} is not fed by the source the column was added to"
+                )));
+            }
+        };
+        let AddedColumnType {
+            typ,
+            data_type,
+            text,
+        } = column_type;
+        let details = hex::encode(details.into_proto().encode_to_vec());
+
+        let (_, new_global_id) = self.allocate_user_id().await?;
+        let ops = vec![Op::AlterAddColumn {
+            id: table_id,
+            new_global_id,
+            name: ColumnName::from(column.name()),
+            typ,
+            sql: data_type,
+            source_export: Some(SourceExportColumn {
+                details,
+                text_column: text,
+            }),
+        }];
+        self.catalog_transact(None, ops).await?;
+        info!(%table_id, column = %column.name(), "added upstream column to source table");
+        Ok(())
+    }
+}
+
+/// Returns the columns that were added to the upstream tables of `tables`, which
+/// are all fed by the PostgreSQL source with connection `connection`.
+async fn postgres_added_columns(
+    connection: &PostgresSourceConnection<InlinedConnection>,
+    tables: &[(CatalogItemId, PostgresSourceExportDetails)],
+    secrets_reader: &Arc<dyn SecretsReader>,
+    storage_config: &StorageConfiguration,
+) -> Result<Vec<AddedSourceColumn>, anyhow::Error> {
+    let config = connection
+        .connection
+        .config(secrets_reader, storage_config, InTask::No)
+        .await?;
+    let client = config
+        .connect(
+            "postgres_source_schema_evolution",
+            &storage_config.connection_context.ssh_tunnel_manager,
+        )
+        .await?;
+
+    let oids: Vec<_> = tables
+        .iter()
+        .map(|(_, details)| details.table.oid)
+        .collect();
+    let upstream_tables =
+        mz_postgres_util::publication_info(&client, &connection.publication, Some(&oids)).await?;
+    let columns_with_defaults = mz_postgres_util::columns_with_defaults(&client, &oids).await?;
+
+    let mut added = vec![];
+    let no_defaults = BTreeSet::new();
+    for (table_id, details) in tables {
+        // Tables that were dropped or altered incompatibly upstream are
+        // reported by the source.
+        let Some(upstream_table) = upstream_tables.get(&details.table.oid) else {
+            continue;
+        };
+        let columns_with_defaults = columns_with_defaults
+            .get(&details.table.oid)
+            .unwrap_or(&no_defaults);
+        for column in details.table.added_columns(
+            upstream_table,
+            columns_with_defaults,
+            &details.exclude_columns,
+        ) {
+            added.push(AddedSourceColumn {
+                table_id: *table_id,
+                column: AddedColumn::Postgres(column.clone()),
+            });
+        }
+    }
+    Ok(added)
+}
+
+/// Returns the columns that were added to the upstream tables of `tables`, which
+/// are all fed by the MySQL source with connection `connection`.
+async fn mysql_added_columns(
+    connection: &MySqlSourceConnection<InlinedConnection>,
+    tables: &[(CatalogItemId, MySqlSourceExportDetails)],
+    secrets_reader: &Arc<dyn SecretsReader>,
+    storage_config: &StorageConfiguration,
+) -> Result<Vec<AddedSourceColumn>, anyhow::Error> {
+    let config = connection
+        .connection
+        .config(secrets_reader, storage_config, InTask::No)
+        .await?;
+    let mut conn = config
+        .connect(
+            "mysql_source_schema_evolution",
+            &storage_config.connection_context.ssh_tunnel_manager,
+        )
+        .await?;
+
+    let request = SchemaRequest::Tables(
+        tables
+            .iter()
+            .map(|(_, details)| {
+                (
+                    details.table.schema_name.as_str(),
+                    details.table.name.as_str(),
+                )
+            })
+            .collect(),
+    );
+    let upstream_tables = mz_mysql_util::schema_info(&mut *conn, &request).await?;
+
+    let mut added = vec![];
+    for (table_id, details) in tables {
+        // Tables that were dropped upstream are reported by the source.
+        let Some(upstream_table) = upstream_tables
+            .iter()
+            .find(|t| t.schema_name == details.table.schema_name && t.name == details.table.name)
+        else {
+            continue;
+        };
+        for column in upstream_table.added_columns(&details.table) {
+            added.push(AddedSourceColumn {
+                table_id: *table_id,
+                column: AddedColumn::MySql(column),
+            });
+        }
+    }
+    Ok(added)
+}
+
+/// Returns the columns that were added to the upstream tables of `tables`, which
+/// are all fed by the SQL Server source with connection `connection`.
+async fn sql_server_added_columns(
+    connection: &SqlServerSourceConnection<InlinedConnection>,
+    tables: &[(CatalogItemId, SqlServerSourceExportDetails)],
+    secrets_reader: &Arc<dyn SecretsReader>,
+    storage_config: &StorageConfiguration,
+) -> Result<Vec<AddedSourceColumn>, anyhow::Error> {
+    let config = connection
+        .connection
+        .resolve_config(secrets_reader, storage_config, InTask::No)
+        .await?;
+    let mut client = mz_sql_server_util::Client::connect(config).await?;
+
+    let mut added = vec![];
+    for (table_id, details) in tables {
+        // Capture instances that were dropped are reported by the source.
+        let Some(latest) = mz_sql_server_util::inspect::get_latest_capture_instance(
+            &mut client,
+            &details.capture_instance,
+        )
+        .await?
+        else {
+            continue;
+        };
+        for column in details
+            .table
+            .added_columns(&latest.columns, &latest.columns_with_defaults)
+        {
+            added.push(AddedSourceColumn {
+                table_id: *table_id,
+                column: AddedColumn::SqlServer {
+                    column,
+                    capture_instance: Arc::clone(&latest.name),
+                },
+            });
+        }
+    }
+    Ok(added)
+}
@@ -118,6 +118,8 @@ pub enum AdapterError {
     },
     /// Expression violated a column's constraint
     ConstraintViolation(NotNullViolation),
+    /// An `INSERT ... ON CONFLICT` statement wrote two rows with the same key.
+    OnConflictRowAffectedTwice,
     /// An error occurred while decoding COPY data.
     CopyFormatError(String),
     /// Transaction cluster was dropped in the middle of a transaction.
@@ -405,7 +407,8 @@ fn eval_error_code(err: &EvalError) -> SqlState {
         EvalError::ParseHex(_)
         | EvalError::InvalidBase64Equals
         | EvalError::InvalidBase64Symbol(_)
-        | EvalError::InvalidBase64EndSequence => SqlState::INVALID_TEXT_REPRESENTATION,
+        | EvalError::InvalidBase64EndSequence
+        | EvalError::InvalidEnumLabel(_) => SqlState::INVALID_TEXT_REPRESENTATION,
         EvalError::InvalidByteSequence { .. } => SqlState::CHARACTER_NOT_IN_REPERTOIRE,
         EvalError::CharacterNotValidForEncoding(_) | EvalError::CharacterTooLargeForEncoding(_) => {
             SqlState::PROGRAM_LIMIT_EXCEEDED
@@ -463,6 +466,7 @@ fn eval_error_code(err: &EvalError) -> SqlState {
             | InvalidRangeError::DiscontiguousUnion
             | InvalidRangeError::DiscontiguousDifference
             | InvalidRangeError::InvalidRangeData => SqlState::DATA_EXCEPTION,
+            InvalidRangeError::NullMultiRangeMember => SqlState::NULL_VALUE_NOT_ALLOWED,
         },
 
         // Cardinality violations from scalar subqueries.
@@ -735,6 +739,11 @@ impl AdapterError {
             ),
             AdapterError::Catalog(c) => c.hint(),
             AdapterError::Eval(e) => e.hint(),
+            AdapterError::OnConflictRowAffectedTwice => Some(
+                "Ensure that no rows proposed for insertion within the same command have \
+                duplicate constrained values."
+                    .to_string(),
+            ),
             AdapterError::AlterClusterUnmanagedWhileReconfiguring => Some(
                 "Cancel the reconfiguration by altering the cluster back to its current \
                 configuration, or wait for it to settle, then convert."
@@ -881,6 +890,7 @@ impl AdapterError {
                 SqlState::PROGRAM_LIMIT_EXCEEDED
             }
             AdapterError::ConstraintViolation(NotNullViolation(_)) => SqlState::NOT_NULL_VIOLATION,
+            AdapterError::OnConflictRowAffectedTwice => SqlState::CARDINALITY_VIOLATION,
             AdapterError::CopyFormatError(_) => SqlState::BAD_COPY_FILE_FORMAT,
             AdapterError::ConcurrentClusterDrop => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::ConcurrentDependencyDrop { .. } => SqlState::UNDEFINED_OBJECT,
@@ -1246,6 +1256,9 @@ impl fmt::Display for AdapterError {
             AdapterError::ConstraintViolation(not_null_violation) => {
                 write!(f, "{}", not_null_violation)
             }
+            AdapterError::OnConflictRowAffectedTwice => {
+                f.write_str("ON CONFLICT command cannot affect row a second time")
+            }
             AdapterError::CopyFormatError(e) => write!(f, "{e}"),
             AdapterError::ConcurrentClusterDrop => {
                 write!(f, "the transaction's active cluster has been dropped")
@@ -291,7 +291,7 @@ impl PeekClient {
                 format,
                 max_file_size,
             }) => {
-                let uri = eval_copy_to_uri(to.clone(), session, catalog.state())?;
+                let uri = eval_copy_to_uri(to.clone(), connection, session, catalog.state())?;
 
                 // (output_batch_count will be set later)
                 let copy_to_ctx = CopyToContext {
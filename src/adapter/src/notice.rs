@@ -202,6 +202,7 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(notice) => match notice {
                 PlanNotice::ObjectDoesNotExist { .. } => Severity::Notice,
                 PlanNotice::ColumnAlreadyExists { .. } => Severity::Notice,
+                PlanNotice::EnumLabelAlreadyExists { .. } => Severity::Notice,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => Severity::Warning,
                 PlanNotice::ReplicaDiskOptionDeprecated { .. } => Severity::Notice,
             },
@@ -311,6 +312,7 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(plan) => match plan {
                 PlanNotice::ObjectDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
                 PlanNotice::ColumnAlreadyExists { .. } => SqlState::DUPLICATE_COLUMN,
+                PlanNotice::EnumLabelAlreadyExists { .. } => SqlState::DUPLICATE_OBJECT,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => SqlState::WARNING,
                 PlanNotice::ReplicaDiskOptionDeprecated { .. } => {
                     SqlState::WARNING_DEPRECATED_FEATURE
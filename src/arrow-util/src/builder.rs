@@ -416,6 +416,11 @@ where
         // Parquet does have a UUID 'Logical Type' in parquet format 2.4+, but there is no arrow
         // UUID type, so we match the format (a 16-byte fixed-length binary array) ourselves.
         SqlScalarType::Uuid => (DataType::FixedSizeBinary(16), "uuid"),
+        // There are no arrow or parquet network address types, so we represent them using their
+        // text format, which is also what other parquet writers (e.g. Spark) produce.
+        SqlScalarType::Inet => (DataType::Utf8, "inet"),
+        SqlScalarType::Cidr => (DataType::Utf8, "cidr"),
+        SqlScalarType::MacAddr => (DataType::Utf8, "macaddr"),
         // Parquet does have a JSON 'Logical Type' in parquet format 2.4+, but there is no arrow
         // JSON type, so for now we represent JSON as 'large' utf8-encoded strings.
         SqlScalarType::Jsonb => (DataType::LargeUtf8, "jsonb"),
@@ -577,6 +582,16 @@ where
                 "range",
             )
         }
+        SqlScalarType::MultiRange { element_type } => {
+            // Multiranges are represented as Arrow Lists of their constituent ranges, each of
+            // which uses the range representation above.
+            let range_type = SqlScalarType::Range {
+                element_type: element_type.clone(),
+            };
+            let (inner_type, inner_name) = scalar_to_arrow_datatype_impl(&range_type, overrides)?;
+            let field = field_with_typename("item", inner_type, false, &inner_name);
+            (DataType::List(field.into()), "multirange")
+        }
         _ => anyhow::bail!("{:?} unimplemented", scalar_type),
     };
     Ok((data_type, extension_name.to_lowercase()))
@@ -937,6 +952,16 @@ impl ArrowColumn {
                 builder.append_value(val.as_bytes())?
             }
             (ColBuilder::StringBuilder(builder), Datum::String(s)) => builder.append_value(s),
+            (ColBuilder::StringBuilder(builder), Datum::Inet(inet)) => {
+                if self.extension_type_name == "cidr" {
+                    builder.append_value(inet.display_cidr().to_string())
+                } else {
+                    builder.append_value(inet.to_string())
+                }
+            }
+            (ColBuilder::StringBuilder(builder), Datum::MacAddr(mac_addr)) => {
+                builder.append_value(mac_addr.to_string())
+            }
             (ColBuilder::StringBuilder(builder), _) if self.extension_type_name == "jsonb" => {
                 builder.append_value(JsonbRef::from_datum(datum).to_serde_json().to_string())
             }
@@ -35,7 +35,7 @@ use mz_repr::adt::jsonb::JsonbPacker;
 use mz_repr::adt::numeric::{Numeric, rescale};
 use mz_repr::adt::range::{Range, RangeLowerBound, RangeUpperBound};
 use mz_repr::adt::timestamp::CheckedTimestamp;
-use mz_repr::{Datum, RelationDesc, Row, RowPacker, SharedRow, SqlScalarType};
+use mz_repr::{Datum, RelationDesc, Row, RowPacker, SharedRow, SqlScalarType, strconv};
 use ordered_float::OrderedFloat;
 use uuid::Uuid;
 
@@ -291,6 +291,21 @@ fn scalar_type_and_array_to_reader(
                 .context("json reader")?;
             Ok(ColReader::Jsonb(Box::new(reader)))
         }
+        // Network addresses are written using their text format, see `builder.rs`.
+        (
+            SqlScalarType::Inet | SqlScalarType::Cidr | SqlScalarType::MacAddr,
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View,
+        ) => {
+            let reader = scalar_type_and_array_to_reader(&SqlScalarType::String, array)
+                .context("network address reader")?;
+            let reader = Box::new(reader);
+            match scalar_type {
+                SqlScalarType::Inet => Ok(ColReader::Inet(reader)),
+                SqlScalarType::Cidr => Ok(ColReader::Cidr(reader)),
+                SqlScalarType::MacAddr => Ok(ColReader::MacAddr(reader)),
+                _ => unreachable!("checked above"),
+            }
+        }
         (SqlScalarType::Timestamp { .. }, DataType::Timestamp(TimeUnit::Second, None)) => {
             let array = downcast_array::<TimestampSecondArray>(array);
             Ok(ColReader::TimestampSecond(array))
@@ -502,6 +517,20 @@ fn scalar_type_and_array_to_reader(
                 nulls: struct_array.nulls().cloned(),
             })
         }
+        (SqlScalarType::MultiRange { element_type }, DataType::List(_)) => {
+            let array = downcast_array::<ListArray>(array);
+            let range_type = SqlScalarType::Range {
+                element_type: element_type.clone(),
+            };
+            let inner_decoder =
+                scalar_type_and_array_to_reader(&range_type, Arc::clone(array.values()))
+                    .context("multirange")?;
+            Ok(ColReader::MultiRange {
+                offsets: array.offsets().clone(),
+                values: Box::new(inner_decoder),
+                nulls: array.nulls().cloned(),
+            })
+        }
         (SqlScalarType::Interval, DataType::Interval(IntervalUnit::YearMonth)) => {
             Ok(ColReader::IntervalYearMonth(downcast_array::<
                 IntervalYearMonthArray,
@@ -594,6 +623,9 @@ enum ColReader {
     LargeString(arrow::array::LargeStringArray),
     StringView(arrow::array::StringViewArray),
     Jsonb(Box<ColReader>),
+    Inet(Box<ColReader>),
+    Cidr(Box<ColReader>),
+    MacAddr(Box<ColReader>),
 
     TimestampSecond(arrow::array::TimestampSecondArray),
     TimestampMillisecond(arrow::array::TimestampMillisecondArray),
@@ -651,6 +683,12 @@ enum ColReader {
         nulls: Option<NullBuffer>,
     },
 
+    MultiRange {
+        offsets: OffsetBuffer<i32>,
+        values: Box<ColReader>,
+        nulls: Option<NullBuffer>,
+    },
+
     IntervalYearMonth(IntervalYearMonthArray),
     IntervalDayTime(IntervalDayTimeArray),
     IntervalMonthDayNano(IntervalMonthDayNanoArray),
@@ -824,6 +862,30 @@ impl ColReader {
                 // Return early because we've already packed the necessasry Datums.
                 return Ok(());
             }
+            ColReader::Inet(reader) | ColReader::Cidr(reader) | ColReader::MacAddr(reader) => {
+                // First read a string value into a temp row, and later parse that as a network
+                // address into our actual Row Packer.
+                let mut temp_row = SharedRow::get();
+                reader
+                    .read(idx, &mut temp_row.packer())
+                    .context("network address")?;
+                let value = match temp_row.unpack_first() {
+                    Datum::String(value) => value,
+                    Datum::Null => {
+                        packer.push(Datum::Null);
+                        return Ok(());
+                    }
+                    other => anyhow::bail!("expected String, found {other:?}"),
+                };
+
+                let datum = match self {
+                    ColReader::Inet(_) => Datum::Inet(strconv::parse_inet(value)?),
+                    ColReader::Cidr(_) => Datum::Inet(strconv::parse_cidr(value)?),
+                    ColReader::MacAddr(_) => Datum::MacAddr(strconv::parse_mac_addr(value)?),
+                    _ => unreachable!("checked above"),
+                };
+                Some(datum)
+            }
             ColReader::TimestampSecond(array) => array
                 .is_valid(idx)
                 .then(|| array.value(idx))
@@ -1180,6 +1242,36 @@ impl ColReader {
 
                 return Ok(());
             }
+            ColReader::MultiRange {
+                offsets,
+                values,
+                nulls,
+            } => {
+                let is_valid = nulls.as_ref().map(|n| n.is_valid(idx)).unwrap_or(true);
+                if !is_valid {
+                    packer.push(Datum::Null);
+                    return Ok(());
+                }
+
+                let start: usize = offsets[idx].try_into().context("multirange start offset")?;
+                let end: usize = offsets[idx + 1].try_into().context("multirange end offset")?;
+
+                // Read the ranges into a scratch row and repack them with
+                // `push_multirange`, which brings them into canonical form for
+                // the same reason ranges are canonicalized above. This cannot
+                // use `SharedRow`, which the range reader borrows for bounds.
+                let mut temp = Row::default();
+                let mut temp_packer = temp.packer();
+                for idx in start..end {
+                    values.read(idx, &mut temp_packer)?;
+                }
+                let ranges = temp.iter().map(|d| d.unwrap_range()).collect();
+                packer
+                    .push_multirange(ranges)
+                    .context("pack multirange")?;
+
+                return Ok(());
+            }
             ColReader::IntervalYearMonth(array) => array
                 .is_valid(idx)
                 .then(|| array.value(idx))
@@ -1234,6 +1326,9 @@ mod tests {
                 }
                 .nullable(true),
             )
+            .with_column("inet", SqlScalarType::Inet.nullable(true))
+            .with_column("cidr", SqlScalarType::Cidr.nullable(true))
+            .with_column("macaddr", SqlScalarType::MacAddr.nullable(true))
             .finish();
 
         let mut og_row = Row::default();
@@ -1254,8 +1349,13 @@ mod tests {
             )
             .expect("failed to pack JSON");
         packer.push_list([Datum::UInt32(200), Datum::UInt32(300)]);
+        packer.extend([
+            Datum::Inet(strconv::parse_inet("10.1.2.3/16").unwrap()),
+            Datum::Inet(strconv::parse_cidr("2001:db8::/32").unwrap()),
+            Datum::MacAddr(strconv::parse_mac_addr("08:00:2b:01:02:03").unwrap()),
+        ]);
 
-        let null_row = Row::pack(vec![Datum::Null; 9]);
+        let null_row = Row::pack(vec![Datum::Null; 12]);
 
         // Encode our data with our ArrowBuilder.
         let mut builder = crate::builder::ArrowBuilder::new(&desc, 2, 46).unwrap();
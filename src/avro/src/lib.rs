@@ -350,7 +350,7 @@ pub use crate::decode::{
     give_value,
 };
 pub use crate::encode::encode as encode_unchecked;
-pub use crate::reader::{Block, BlockIter, Reader, from_avro_datum};
+pub use crate::reader::{Block, BlockIter, Header, Reader, from_avro_datum};
 pub use crate::schema::{ParseSchemaError, Schema};
 pub use crate::types::SchemaResolutionError;
 pub use crate::writer::{ValidationError, Writer, to_avro_datum, write_avro_datum};
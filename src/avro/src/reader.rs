@@ -39,8 +39,9 @@ use crate::schema::{
 use crate::types::Value;
 use crate::{Codec, SchemaResolutionError, util};
 
+/// The header of an OCF file.
 #[derive(Debug, Clone)]
-pub(crate) struct Header {
+pub struct Header {
     writer_schema: Schema,
     marker: [u8; 16],
     codec: Codec,
@@ -112,6 +113,40 @@ impl Header {
     pub fn into_parts(self) -> (Schema, [u8; 16], Codec) {
         (self.writer_schema, self.marker, self.codec)
     }
+
+    /// The schema that the file was written with.
+    pub fn writer_schema(&self) -> &Schema {
+        &self.writer_schema
+    }
+
+    /// Reads the next block of the file from `reader`, which must be positioned
+    /// after the header or the previous block.
+    ///
+    /// Unlike [`BlockIter`], this does not treat running out of bytes as the
+    /// end of the file, so that callers can read blocks from a buffer that they
+    /// fill incrementally: if `reader` does not contain a whole block, this
+    /// returns an [`AvroError::IO`] error of kind
+    /// [`std::io::ErrorKind::UnexpectedEof`].
+    pub fn read_block<R: AvroRead>(&self, reader: &mut R) -> Result<Block, AvroError> {
+        let len = util::safe_len(util::read_long(reader)? as usize)?;
+        let block_bytes = util::safe_len(util::read_long(reader)? as usize)?;
+        let mut bytes = vec![0; block_bytes];
+        reader.read_exact(&mut bytes)?;
+        let mut marker = [0u8; 16];
+        reader.read_exact(&mut marker)?;
+
+        if marker != self.marker {
+            return Err(DecodeError::MismatchedBlockHeader {
+                expected: self.marker,
+                actual: marker,
+            }
+            .into());
+        }
+
+        self.codec.decompress(&mut bytes)?;
+        bound_block_object_count(self.writer_schema.top_node(), len, bytes.len())?;
+        Ok(Block { bytes, len })
+    }
 }
 
 pub struct Reader<R> {
@@ -1106,6 +1141,66 @@ mod tests {
         );
     }
 
+    #[mz_ore::test]
+    fn header_reads_blocks_from_partial_input() {
+        // Callers that fill a buffer incrementally retry on `UnexpectedEof`
+        // until a whole block is available.
+        let first = &[0x04, b'a', b'b'][..];
+        let second = &[0x02, b'c', 0x00][..];
+        let file = ocf(
+            r#""string""#,
+            &[
+                (1, first.len() as i64, first),
+                (2, second.len() as i64, second),
+            ],
+        );
+
+        let mut bytes = &file[..];
+        let header = Header::from_reader(&mut bytes).expect("OCF header parses");
+        let body = bytes;
+
+        let mut blocks = vec![];
+        let mut start = 0;
+        for end in 0..=body.len() {
+            let mut bytes = &body[start..end];
+            match header.read_block(&mut bytes) {
+                Ok(block) => {
+                    start = end - bytes.len();
+                    blocks.push(block);
+                }
+                Err(AvroError::IO(std::io::ErrorKind::UnexpectedEof)) => {}
+                Err(err) => panic!("unexpected error: {err}"),
+            }
+        }
+        assert_eq!(start, body.len());
+        assert_eq!(
+            blocks
+                .iter()
+                .map(|b| (b.len, &b.bytes[..]))
+                .collect::<Vec<_>>(),
+            vec![(1, first), (2, second)]
+        );
+
+        // A block followed by the wrong sync marker is an error, not a request
+        // for more bytes.
+        let mut corrupt = body.to_vec();
+        *corrupt.last_mut().expect("file is not empty") ^= 1;
+        let mut bytes = &corrupt[..];
+        header
+            .read_block(&mut bytes)
+            .expect("first block is intact");
+        let err = header
+            .read_block(&mut bytes)
+            .expect_err("marker is corrupt");
+        assert!(
+            matches!(
+                err,
+                AvroError::Decode(DecodeError::MismatchedBlockHeader { .. })
+            ),
+            "unexpected error: {err}"
+        );
+    }
+
     static SCHEMA: &str = r#"
             {
                 "type": "record",
@@ -684,6 +684,7 @@ async fn upgrade_check(
                 &args.tracing.startup_log_filter,
                 args.aws_external_id_prefix,
                 args.aws_connection_role_arn,
+                None,
                 secrets_reader,
                 None,
             ),
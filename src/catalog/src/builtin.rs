@@ -966,7 +966,9 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Type(&TYPE_ANYARRAY),
         Builtin::Type(&TYPE_ANYELEMENT),
         Builtin::Type(&TYPE_ANYNONARRAY),
+        Builtin::Type(&TYPE_ANYENUM),
         Builtin::Type(&TYPE_ANYRANGE),
+        Builtin::Type(&TYPE_ANYMULTIRANGE),
         Builtin::Type(&TYPE_BOOL),
         Builtin::Type(&TYPE_BOOL_ARRAY),
         Builtin::Type(&TYPE_BYTEA),
@@ -1017,6 +1019,12 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Type(&TYPE_TIMESTAMPTZ_ARRAY),
         Builtin::Type(&TYPE_UUID),
         Builtin::Type(&TYPE_UUID_ARRAY),
+        Builtin::Type(&TYPE_INET),
+        Builtin::Type(&TYPE_INET_ARRAY),
+        Builtin::Type(&TYPE_CIDR),
+        Builtin::Type(&TYPE_CIDR_ARRAY),
+        Builtin::Type(&TYPE_MACADDR),
+        Builtin::Type(&TYPE_MACADDR_ARRAY),
         Builtin::Type(&TYPE_VARCHAR),
         Builtin::Type(&TYPE_VARCHAR_ARRAY),
         Builtin::Type(&TYPE_INT2_VECTOR),
@@ -1047,6 +1055,18 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Type(&TYPE_TS_RANGE_ARRAY),
         Builtin::Type(&TYPE_TSTZ_RANGE),
         Builtin::Type(&TYPE_TSTZ_RANGE_ARRAY),
+        Builtin::Type(&TYPE_INT4_MULTIRANGE),
+        Builtin::Type(&TYPE_INT4_MULTIRANGE_ARRAY),
+        Builtin::Type(&TYPE_INT8_MULTIRANGE),
+        Builtin::Type(&TYPE_INT8_MULTIRANGE_ARRAY),
+        Builtin::Type(&TYPE_DATE_MULTIRANGE),
+        Builtin::Type(&TYPE_DATE_MULTIRANGE_ARRAY),
+        Builtin::Type(&TYPE_NUM_MULTIRANGE),
+        Builtin::Type(&TYPE_NUM_MULTIRANGE_ARRAY),
+        Builtin::Type(&TYPE_TS_MULTIRANGE),
+        Builtin::Type(&TYPE_TS_MULTIRANGE_ARRAY),
+        Builtin::Type(&TYPE_TSTZ_MULTIRANGE),
+        Builtin::Type(&TYPE_TSTZ_MULTIRANGE_ARRAY),
         Builtin::Type(&TYPE_MZ_ACL_ITEM),
         Builtin::Type(&TYPE_MZ_ACL_ITEM_ARRAY),
         Builtin::Type(&TYPE_ACL_ITEM),
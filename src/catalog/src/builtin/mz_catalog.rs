@@ -367,7 +367,7 @@ pub static MZ_KAFKA_SOURCES: LazyLock<BuiltinMaterializedView> = LazyLock::new(|
             ),
             (
                 "topic",
-                "The name of the Kafka topic the source is reading from.",
+                "The name of the Kafka topic the source is reading from, the comma-separated topic names for `TOPICS`, or the pattern for `TOPIC PATTERN`.",
             ),
         ]),
         // NOTE: the `group_id_prefix` column is misnamed. It holds the
@@ -4584,8 +4584,12 @@ SELECT
     role_owner.oid AS typowner,
     NULL::pg_catalog.int2 AS typlen,
     -- 'a' is used internally to denote an array type, but in postgres they show up
-    -- as 'b'.
-    (CASE mztype WHEN 'a' THEN 'b' ELSE mztype END)::pg_catalog.char AS typtype,
+    -- as 'b'. Enum types are stored as base types, but show up as 'e'.
+    (CASE
+        WHEN mztype = 'a' THEN 'b'
+        WHEN category = 'enum' THEN 'e'
+        ELSE mztype
+    END)::pg_catalog.char AS typtype,
     (CASE category
         WHEN 'array' THEN 'A'
         WHEN 'bit-string' THEN 'V'
@@ -552,6 +552,96 @@ pub const TYPE_UUID_ARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_INET: BuiltinType<NameReference> = BuiltinType {
+    name: "inet",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_INET_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Inet,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 910,
+            typreceive_oid: 2496,
+        }),
+    },
+};
+
+pub const TYPE_INET_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_inet",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_INET_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_INET.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_CIDR: BuiltinType<NameReference> = BuiltinType {
+    name: "cidr",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_CIDR_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Cidr,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 1267,
+            typreceive_oid: 2498,
+        }),
+    },
+};
+
+pub const TYPE_CIDR_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_cidr",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_CIDR_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_CIDR.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_MACADDR: BuiltinType<NameReference> = BuiltinType {
+    name: "macaddr",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_MACADDR_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MacAddr,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 436,
+            typreceive_oid: 2494,
+        }),
+    },
+};
+
+pub const TYPE_MACADDR_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_macaddr",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_MACADDR_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_MACADDR.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
 pub const TYPE_JSONB: BuiltinType<NameReference> = BuiltinType {
     name: "jsonb",
     schema: PG_CATALOG_SCHEMA,
@@ -638,6 +728,20 @@ pub const TYPE_ANYNONARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_ANYENUM: BuiltinType<NameReference> = BuiltinType {
+    name: "anyenum",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_ANYENUM_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Pseudo,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 3504,
+            typreceive_oid: 0,
+        }),
+    },
+};
+
 pub const TYPE_ANYRANGE: BuiltinType<NameReference> = BuiltinType {
     name: "anyrange",
     schema: PG_CATALOG_SCHEMA,
@@ -652,6 +756,20 @@ pub const TYPE_ANYRANGE: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_ANYMULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "anymultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_ANYMULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Pseudo,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4229,
+            typreceive_oid: 0,
+        }),
+    },
+};
+
 pub const TYPE_CHAR: BuiltinType<NameReference> = BuiltinType {
     name: "char",
     schema: PG_CATALOG_SCHEMA,
@@ -1140,6 +1258,198 @@ pub const TYPE_TSTZ_RANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_INT4_MULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "int4multirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_INT4MULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MultiRange {
+            element_reference: TYPE_INT4.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4231,
+            typreceive_oid: 4233,
+        }),
+    },
+};
+
+pub const TYPE_INT4_MULTIRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_int4multirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_INT4MULTIRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_INT4_MULTIRANGE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_INT8_MULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "int8multirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_INT8MULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MultiRange {
+            element_reference: TYPE_INT8.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4231,
+            typreceive_oid: 4233,
+        }),
+    },
+};
+
+pub const TYPE_INT8_MULTIRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_int8multirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_INT8MULTIRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_INT8_MULTIRANGE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_DATE_MULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "datemultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_DATEMULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MultiRange {
+            element_reference: TYPE_DATE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4231,
+            typreceive_oid: 4233,
+        }),
+    },
+};
+
+pub const TYPE_DATE_MULTIRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_datemultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_DATEMULTIRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_DATE_MULTIRANGE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_NUM_MULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "nummultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_NUMMULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MultiRange {
+            element_reference: TYPE_NUMERIC.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4231,
+            typreceive_oid: 4233,
+        }),
+    },
+};
+
+pub const TYPE_NUM_MULTIRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_nummultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_NUMMULTIRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_NUM_MULTIRANGE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_TS_MULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "tsmultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_TSMULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MultiRange {
+            element_reference: TYPE_TIMESTAMP.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4231,
+            typreceive_oid: 4233,
+        }),
+    },
+};
+
+pub const TYPE_TS_MULTIRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_tsmultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_TSMULTIRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_TS_MULTIRANGE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_TSTZ_MULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "tstzmultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_TSTZMULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MultiRange {
+            element_reference: TYPE_TIMESTAMPTZ.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4231,
+            typreceive_oid: 4233,
+        }),
+    },
+};
+
+pub const TYPE_TSTZ_MULTIRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_tstzmultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_TSTZMULTIRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_TSTZ_MULTIRANGE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
 pub const TYPE_ACL_ITEM: BuiltinType<NameReference> = BuiltinType {
     name: "aclitem",
     schema: PG_CATALOG_SCHEMA,
@@ -38,7 +38,8 @@ use mz_repr::{
 };
 use mz_sql::ast::display::AstDisplay;
 use mz_sql::ast::{
-    ColumnDef, ColumnOption, ColumnOptionDef, ColumnVersioned, Expr, Raw, RawDataType, Statement,
+    ColumnDef, ColumnOption, ColumnOptionDef, ColumnVersioned, Expr, Ident, Raw, RawDataType,
+    Statement, TableFromSourceColumns, TableFromSourceOption, TableFromSourceOptionName,
     UnresolvedItemName, Value, WithOptionValue,
 };
 use mz_sql::catalog::{
@@ -746,7 +747,7 @@ impl mz_sql::catalog::CatalogItem for CatalogCollectionEntry {
         self.entry.oid()
     }
 
-    fn func(&self) -> Result<&'static mz_sql::func::Func, SqlCatalogError> {
+    fn func(&self) -> Result<mz_sql::func::FuncRef, SqlCatalogError> {
         self.entry.func()
     }
 
@@ -885,6 +886,18 @@ impl From<CatalogEntry> for durable::Item {
     }
 }
 
+/// How the definition of a table that is fed by a source changes when a column
+/// is added to it.
+#[derive(Debug, Clone)]
+pub struct SourceExportColumn {
+    /// The hex-encoded `DETAILS` of the source export as of the new version of
+    /// the table.
+    pub details: String,
+    /// Whether the column is ingested as text, i.e. must be added to the
+    /// table's `TEXT COLUMNS`.
+    pub text_column: bool,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Table {
     /// Parse-able SQL that defines this table.
@@ -1632,11 +1645,16 @@ pub struct Type {
 
 #[derive(Debug, Clone, Serialize)]
 pub struct Func {
-    /// Static definition of the function.
+    /// Parse-able SQL that defines this function, or `None` for built-in
+    /// functions.
+    pub create_sql: Option<String>,
+    /// Definition of the function.
     #[serde(skip)]
-    pub inner: &'static mz_sql::func::Func,
+    pub inner: mz_sql::func::FuncRef,
     /// [`GlobalId`] used to reference this function from outside the catalog.
     pub global_id: GlobalId,
+    /// Other catalog objects referenced by this function.
+    pub resolved_ids: ResolvedIds,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1887,12 +1905,9 @@ impl CatalogItem {
         }
     }
 
-    pub fn func(
-        &self,
-        entry: &CatalogEntry,
-    ) -> Result<&'static mz_sql::func::Func, SqlCatalogError> {
+    pub fn func(&self, entry: &CatalogEntry) -> Result<mz_sql::func::FuncRef, SqlCatalogError> {
         match &self {
-            CatalogItem::Func(func) => Ok(func.inner),
+            CatalogItem::Func(func) => Ok(func.inner.clone()),
             _ => Err(SqlCatalogError::UnexpectedType {
                 name: entry.name().item.to_string(),
                 actual_type: entry.item_type(),
@@ -1939,7 +1954,7 @@ impl CatalogItem {
     pub fn references(&self) -> &ResolvedIds {
         static EMPTY: LazyLock<ResolvedIds> = LazyLock::new(ResolvedIds::empty);
         match self {
-            CatalogItem::Func(_) => &*EMPTY,
+            CatalogItem::Func(func) => &func.resolved_ids,
             CatalogItem::Index(idx) => &idx.resolved_ids,
             CatalogItem::Sink(sink) => &sink.resolved_ids,
             CatalogItem::Source(source) => &source.resolved_ids,
@@ -1961,8 +1976,9 @@ impl CatalogItem {
     pub fn uses(&self) -> BTreeSet<CatalogItemId> {
         let mut uses: BTreeSet<_> = self.references().items().copied().collect();
         match self {
-            // TODO(jkosh44) This isn't really correct for functions. They may use other objects in
-            // their implementation. However, currently there's no way to get that information.
+            // TODO(jkosh44) This isn't really correct for builtin functions. They may use other
+            // objects in their implementation. However, currently there's no way to get that
+            // information. User-defined functions record their references like views do.
             CatalogItem::Func(_) => {}
             CatalogItem::Index(_) => {}
             CatalogItem::Sink(_) => {}
@@ -2117,7 +2133,11 @@ impl CatalogItem {
                 i.create_sql = i.create_sql.map(do_rewrite).transpose()?;
                 Ok(CatalogItem::Type(i))
             }
-            CatalogItem::Func(i) => Ok(CatalogItem::Func(i.clone())),
+            CatalogItem::Func(i) => {
+                let mut i = i.clone();
+                i.create_sql = i.create_sql.map(do_rewrite).transpose()?;
+                Ok(CatalogItem::Func(i))
+            }
         }
     }
 
@@ -2180,6 +2200,11 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Secret(i))
             }
+            CatalogItem::Func(i) if !rename_self => {
+                let mut i = i.clone();
+                i.create_sql = i.create_sql.map(do_rewrite).transpose()?;
+                Ok(CatalogItem::Func(i))
+            }
             CatalogItem::Func(_) | CatalogItem::Type(_) => {
                 unreachable!("{}s cannot be renamed", self.typ())
             }
@@ -2242,7 +2267,12 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql);
                 CatalogItem::Secret(i)
             }
-            CatalogItem::Func(_) | CatalogItem::Type(_) => {
+            CatalogItem::Func(i) => {
+                let mut i = i.clone();
+                i.create_sql = i.create_sql.map(do_rewrite);
+                CatalogItem::Func(i)
+            }
+            CatalogItem::Type(_) => {
                 unreachable!("references of {}s cannot be replaced", self.typ())
             }
             CatalogItem::Connection(i) => {
@@ -2372,11 +2402,16 @@ impl CatalogItem {
         }
     }
 
+    /// Adds a column to this table, creating a new version of it.
+    ///
+    /// Tables that are fed by a source must describe how the source export
+    /// changes along with the new version of the table.
     pub fn add_column(
         &mut self,
         name: ColumnName,
         typ: SqlColumnType,
         sql: RawDataType,
+        source_export: Option<SourceExportColumn>,
     ) -> Result<RelationVersion, PlanError> {
         let CatalogItem::Table(table) = self else {
             return Err(PlanError::Unsupported {
@@ -2386,24 +2421,56 @@ impl CatalogItem {
         };
         let next_version = table.desc.add_column(name.clone(), typ);
 
-        let update = |mut ast: &mut Statement<Raw>| match &mut ast {
-            Statement::CreateTable(stmt) => {
-                let version = ColumnOptionDef {
-                    name: None,
-                    option: ColumnOption::Versioned {
-                        action: ColumnVersioned::Added,
-                        version: next_version.into(),
-                    },
-                };
-                let column = ColumnDef {
-                    name: name.into(),
-                    data_type: sql,
-                    collation: None,
-                    options: vec![version],
-                };
+        let version = ColumnOptionDef {
+            name: None,
+            option: ColumnOption::Versioned {
+                action: ColumnVersioned::Added,
+                version: next_version.into(),
+            },
+        };
+        let column_ident = Ident::new_unchecked(name.as_str());
+        let column = ColumnDef {
+            name: name.into(),
+            data_type: sql,
+            collation: None,
+            options: vec![version],
+        };
+        let update = |ast: &mut Statement<Raw>| match (ast, source_export) {
+            (Statement::CreateTable(stmt), None) => {
                 stmt.columns.push(column);
                 Ok(())
             }
+            (Statement::CreateTableFromSource(stmt), Some(source_export)) => {
+                let TableFromSourceColumns::Defined(columns) = &mut stmt.columns else {
+                    return Err(());
+                };
+                columns.push(column);
+                let option = stmt
+                    .with_options
+                    .iter_mut()
+                    .find(|o| o.name == TableFromSourceOptionName::Details)
+                    .ok_or(())?;
+                option.value = Some(WithOptionValue::Value(Value::String(source_export.details)));
+                if source_export.text_column {
+                    let column = WithOptionValue::Ident(column_ident);
+                    match stmt
+                        .with_options
+                        .iter_mut()
+                        .find(|o| o.name == TableFromSourceOptionName::TextColumns)
+                    {
+                        Some(TableFromSourceOption {
+                            value: Some(WithOptionValue::Sequence(text_columns)),
+                            ..
+                        }) => text_columns.push(column),
+                        Some(_) => return Err(()),
+                        None => stmt.with_options.push(TableFromSourceOption {
+                            name: TableFromSourceOptionName::TextColumns,
+                            value: Some(WithOptionValue::Sequence(vec![column])),
+                        }),
+                    }
+                }
+                Ok(())
+            }
             _ => Err(()),
         };
 
@@ -2421,6 +2488,7 @@ impl CatalogItem {
         let create_sql = match self {
             CatalogItem::Table(Table { create_sql, .. })
             | CatalogItem::Type(Type { create_sql, .. })
+            | CatalogItem::Func(Func { create_sql, .. })
             | CatalogItem::Source(Source { create_sql, .. }) => create_sql.as_mut(),
             CatalogItem::Sink(Sink { create_sql, .. })
             | CatalogItem::View(View { create_sql, .. })
@@ -2428,7 +2496,7 @@ impl CatalogItem {
             | CatalogItem::Index(Index { create_sql, .. })
             | CatalogItem::Secret(Secret { create_sql, .. })
             | CatalogItem::Connection(Connection { create_sql, .. }) => Some(create_sql),
-            CatalogItem::Func(_) | CatalogItem::Log(_) => None,
+            CatalogItem::Log(_) => None,
         };
         let Some(create_sql) = create_sql else {
             return Err(());
@@ -2649,7 +2717,13 @@ impl CatalogItem {
                 connection.global_id,
                 BTreeMap::new(),
             ),
-            CatalogItem::Func(_) => unreachable!("cannot serialize functions yet"),
+            CatalogItem::Func(func) => {
+                let create_sql = func
+                    .create_sql
+                    .clone()
+                    .expect("builtin functions cannot be serialized");
+                (create_sql, func.global_id, BTreeMap::new())
+            }
         }
     }
 
@@ -2694,7 +2768,12 @@ impl CatalogItem {
             CatalogItem::Connection(connection) => {
                 (connection.create_sql, connection.global_id, BTreeMap::new())
             }
-            CatalogItem::Func(_) => unreachable!("cannot serialize functions yet"),
+            CatalogItem::Func(func) => {
+                let create_sql = func
+                    .create_sql
+                    .expect("builtin functions cannot be serialized");
+                (create_sql, func.global_id, BTreeMap::new())
+            }
         }
     }
 
@@ -2739,7 +2818,7 @@ impl CatalogEntry {
     }
 
     /// Returns the [`mz_sql::func::Func`] associated with this `CatalogEntry`.
-    pub fn func(&self) -> Result<&'static mz_sql::func::Func, SqlCatalogError> {
+    pub fn func(&self) -> Result<mz_sql::func::FuncRef, SqlCatalogError> {
         self.item.func(self)
     }
 
@@ -4002,7 +4081,7 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         self.oid()
     }
 
-    fn func(&self) -> Result<&'static mz_sql::func::Func, SqlCatalogError> {
+    fn func(&self) -> Result<mz_sql::func::FuncRef, SqlCatalogError> {
         self.func()
     }
 
@@ -4034,7 +4113,9 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
             }
             CatalogItem::Secret(Secret { create_sql, .. }) => create_sql,
             CatalogItem::Connection(Connection { create_sql, .. }) => create_sql,
-            CatalogItem::Func(_) => "<builtin>",
+            CatalogItem::Func(Func { create_sql, .. }) => {
+                create_sql.as_deref().unwrap_or("<builtin>")
+            }
             CatalogItem::Log(_) => "<builtin>",
         }
     }
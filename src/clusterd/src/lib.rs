@@ -121,6 +121,11 @@ struct Args {
     #[clap(long, env = "AWS_CONNECTION_ROLE_ARN")]
     aws_connection_role_arn: Option<String>,
 
+    /// The URI at which GCP service accounts mint access tokens instead of
+    /// Google's, e.g. to test against a GCS emulator.
+    #[clap(long, env = "GCP_OAUTH_TOKEN_URI")]
+    gcp_oauth_token_uri: Option<String>,
+
     // === Secrets reader options. ===
     #[clap(flatten)]
     secrets: SecretsReaderCliArgs,
@@ -410,6 +415,7 @@ async fn run(args: Args) -> Result<(), anyhow::Error> {
         &args.tracing.startup_log_filter,
         args.aws_external_id_prefix,
         args.aws_connection_role_arn,
+        args.gcp_oauth_token_uri,
         secrets_reader,
         None,
     );
@@ -695,6 +695,15 @@ pub fn convert_indexes_to_skips(mut indexes: Vec<usize>) -> Vec<usize> {
 /// significant input data). Hierarchical aggregates can be rendered more efficiently if the
 /// input stream is append-only as then we only need to retain the "currently winning" value.
 /// Every hierarchical aggregate needs to supply a corresponding ReductionMonoid implementation.
+///
+/// Ordered-set aggregates (`percentile_cont`, `percentile_disc`, `mode`) are planned as basic
+/// aggregations, but rendered as order-statistics reductions: a radix tree over an
+/// order-preserving key of the values, so that an update to a group only revisits the nodes on
+/// the path to the updated value rather than the whole group.
+///
+/// The HyperLogLog aggregates (`approx_count_distinct`, `hll_sketch_agg`, `hll_union_agg`) are
+/// accumulable: their state counts the `(register, rank)` pairs observed, from which the sketch
+/// registers are derived, so that retractions can be applied in place.
 pub fn reduction_type(func: &AggregateFunc) -> ReductionType {
     match func {
         AggregateFunc::SumInt16
@@ -709,6 +718,9 @@ pub fn reduction_type(func: &AggregateFunc) -> ReductionType {
         | AggregateFunc::Count
         | AggregateFunc::Any
         | AggregateFunc::All
+        | AggregateFunc::ApproxCountDistinct
+        | AggregateFunc::HllSketchAgg
+        | AggregateFunc::HllUnionAgg
         | AggregateFunc::Dummy => ReductionType::Accumulable,
         AggregateFunc::MaxNumeric
         | AggregateFunc::MaxInt16
@@ -753,11 +765,20 @@ pub fn reduction_type(func: &AggregateFunc) -> ReductionType {
         | AggregateFunc::RowNumber { .. }
         | AggregateFunc::Rank { .. }
         | AggregateFunc::DenseRank { .. }
+        | AggregateFunc::PercentRank { .. }
+        | AggregateFunc::CumeDist { .. }
+        | AggregateFunc::Ntile { .. }
         | AggregateFunc::LagLead { .. }
         | AggregateFunc::FirstValue { .. }
         | AggregateFunc::LastValue { .. }
+        | AggregateFunc::NthValue { .. }
         | AggregateFunc::WindowAggregate { .. }
         | AggregateFunc::FusedValueWindowFunc { .. }
-        | AggregateFunc::FusedWindowAggregate { .. } => ReductionType::Basic,
+        | AggregateFunc::FusedWindowAggregate { .. }
+        | AggregateFunc::PercentileCont { .. }
+        | AggregateFunc::PercentileDisc { .. }
+        | AggregateFunc::Mode { .. }
+        | AggregateFunc::RangeAgg
+        | AggregateFunc::RangeIntersectAgg => ReductionType::Basic,
     }
 }
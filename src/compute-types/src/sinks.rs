@@ -12,8 +12,7 @@
 use mz_expr::ColumnOrder;
 use mz_repr::refresh_schedule::RefreshSchedule;
 use mz_repr::{CatalogItemId, GlobalId, RelationDesc, Timestamp};
-use mz_storage_types::connections::aws::AwsConnection;
-use mz_storage_types::sinks::S3UploadInfo;
+use mz_storage_types::sinks::{CopyToConnection, S3UploadInfo};
 use serde::{Deserialize, Serialize};
 use timely::progress::Antichain;
 
@@ -89,8 +88,8 @@ pub struct MetricSinkConnection {}
 pub struct CopyToS3OneshotSinkConnection {
     /// Information specific to the upload.
     pub upload_info: S3UploadInfo,
-    /// The AWS connection information to do the writes.
-    pub aws_connection: AwsConnection,
+    /// The AWS or GCP connection information to do the writes.
+    pub connection: CopyToConnection,
     /// The ID of the Connection object, used to generate the External ID when
     /// using AssumeRole with AWS connection.
     pub connection_id: CatalogItemId,
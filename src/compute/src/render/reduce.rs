@@ -13,7 +13,7 @@
 
 use std::collections::BTreeMap;
 
-use columnation::{Columnation, CopyRegion};
+use columnation::{Columnation, Region};
 use dec::OrderedDecimal;
 use differential_dataflow::Diff as _;
 use differential_dataflow::collection::AsCollection;
@@ -33,8 +33,8 @@ use mz_compute_types::plan::reduce::{
     MonotonicPlan, ReducePlan, ReductionType, SingleBasicPlan, reduction_type,
 };
 use mz_compute_types::plan::scalar::LirScalarExpr;
-use mz_expr::{AggregateFunc, EvalError, SafeMfpPlan};
-use mz_ore::cast::CastLossy;
+use mz_expr::{AggregateFunc, EvalError, Percentiles, SafeMfpPlan, hll};
+use mz_ore::cast::{CastFrom, CastLossy};
 use mz_repr::adt::numeric::{self, Numeric, NumericAgg};
 use mz_repr::fixed_length::ExtendDatums;
 use mz_repr::{Datum, DatumVec, Diff, Row, RowArena, SharedRow};
@@ -555,6 +555,26 @@ impl<'scope, T: RenderTimestamp> Context<'scope, T> {
             }
         }
 
+        // Ordered-set aggregates are maintained incrementally by an order-statistics reduction
+        // rather than by sorting the whole group on every change.
+        if !fused_unnest_list
+            && matches!(
+                func,
+                AggregateFunc::PercentileCont { .. }
+                    | AggregateFunc::PercentileDisc { .. }
+                    | AggregateFunc::Mode { .. }
+            )
+        {
+            let must_validate = validating && err_output.is_none();
+            let (oks, errs) =
+                self.build_order_statistics_aggregate(partial, func, must_validate, mfp_after);
+            let err_output = match (err_output, errs) {
+                (Some(e1), Some(e2)) => Some(e1.concat(e2)),
+                (e1, e2) => e1.or(e2),
+            };
+            return (oks, err_output);
+        }
+
         // Allocations for the two closures.
         let mut datums1 = DatumVec::new();
         let mut datums2 = DatumVec::new();
@@ -830,6 +850,513 @@ impl<'scope, T: RenderTimestamp> Context<'scope, T> {
         })
     }
 
+    /// Build the dataflow to incrementally maintain a single ordered-set aggregation
+    /// (`percentile_cont`, `percentile_disc` or `mode`).
+    ///
+    /// Each non-null value is assigned an [`order_statistics::order_key`] that is monotone in
+    /// the sort order of the aggregation, and the values of each group are organized in a radix
+    /// tree over these keys with a fan-out of 256. Every node of the tree is a group of a reduce
+    /// operator, so an update only revisits the nodes on the path to its value rather than the
+    /// whole group. The leaves hold the values that share an order key, and sort them.
+    ///
+    /// Percentiles walk the tree top-down: the root of each group counts its values and turns
+    /// the requested fractions into positions, and each level forwards them to the child whose
+    /// subtree contains them. The mode is computed bottom-up, with each node reporting the most
+    /// frequent value of its subtree.
+    fn build_order_statistics_aggregate<'s>(
+        &self,
+        input: VecCollection<'s, T, (Row, Row), Diff>,
+        func: AggregateFunc,
+        validating: bool,
+        mfp_after: Option<SafeMfpPlan<LirScalarExpr>>,
+    ) -> (
+        RowRowArrangement<'s, T>,
+        Option<VecCollection<'s, T, DataflowErrorSer, Diff>>,
+    ) {
+        let desc = match &func {
+            AggregateFunc::PercentileCont { desc, .. }
+            | AggregateFunc::PercentileDisc { desc, .. }
+            | AggregateFunc::Mode { desc } => *desc,
+            _ => unreachable!("not an ordered-set aggregate: {func:?}"),
+        };
+
+        let mut err_output: Option<VecCollection<'s, T, _, _>> = None;
+        let outer_scope = input.scope();
+        let arranged_output = outer_scope
+            .clone()
+            .region_named("ReduceOrderStatistics", |inner| {
+                let input = input.enter(inner);
+
+                // Nulls don't participate in the aggregation, but a group of only nulls still
+                // produces a (null) result, so we keep a marker for them.
+                let nulls = input.clone().flat_map(|(key, val)| {
+                    let datum = val.unpack_first();
+                    datum
+                        .is_null()
+                        .then(|| (key, SharedRow::pack([Datum::False])))
+                });
+                let values = input.flat_map(move |(key, val)| {
+                    let datum = val.unpack_first();
+                    if datum.is_null() {
+                        return None;
+                    }
+                    let order_key = order_statistics::order_key(datum, desc);
+                    Some((order_key, key, val))
+                });
+
+                let (found, errs) = match &func {
+                    AggregateFunc::Mode { .. } => self.build_mode_tree(values, desc, validating),
+                    _ => self.build_percentile_tree(values, &func, desc, validating),
+                };
+                if let Some(errs) = errs {
+                    err_output = Some(errs.leave_region(outer_scope));
+                }
+
+                // Allocations for the two closures.
+                let mut datums1 = DatumVec::new();
+                let mut datums2 = DatumVec::new();
+                let mut vals1 = DatumVec::new();
+                let mut vals2 = DatumVec::new();
+                let mfp_after1 = mfp_after.clone();
+                let mfp_after2 = mfp_after.filter(|mfp| mfp.could_error());
+                let func2 = func.clone();
+
+                let arranged = nulls
+                    .concat(found)
+                    .mz_arrange::<
+                        ColumnationChunker<_>,
+                        RowRowBatcher<_, _>,
+                        RowRowBuilder<_, _>,
+                        RowRowSpine<_, _>,
+                    >("Arranged ReduceOrderStatistics");
+
+                let must_validate = validating;
+                if must_validate || mfp_after2.is_some() {
+                    let error_logger = self.error_logger();
+                    let errs = arranged
+                        .clone()
+                        .mz_reduce_abelian::<_, RowErrBuilder<_, _>, RowErrSpine<_, _>, _>(
+                            "ReduceOrderStatistics Error Check",
+                            move |key, source, target| {
+                                // The values themselves are validated in the leaves of the tree,
+                                // which leaves the null markers to check here.
+                                if must_validate {
+                                    for (val, count) in source.iter() {
+                                        if count.is_positive() {
+                                            continue;
+                                        }
+                                        let val = val.to_row();
+                                        let message =
+                                            "Non-positive accumulation in ReduceOrderStatistics";
+                                        error_logger
+                                            .log(message, &format!("val={val:?}, count={count}"));
+                                        target.push((
+                                            EvalError::Internal(message.into()).into(),
+                                            Diff::ONE,
+                                        ));
+                                        return;
+                                    }
+                                }
+
+                                // We know that `mfp_after` can error if it exists, so try to evaluate it here.
+                                let Some(mfp) = &mfp_after2 else { return };
+                                let temp_storage = RowArena::new();
+                                let mut decoded = vals2.borrow();
+                                let mut entries = Vec::with_capacity(source.len());
+                                for (val, count) in source.iter() {
+                                    entries.push((decoded.len(), *count));
+                                    val.extend_datums(&temp_storage, &mut decoded, None);
+                                }
+                                let mut datums_local = datums2.borrow();
+                                key.extend_datums(&temp_storage, &mut datums_local, None);
+                                datums_local.push(order_statistics::finalize(
+                                    &func2,
+                                    &decoded,
+                                    &entries,
+                                    &temp_storage,
+                                ));
+                                if let Err(e) = mfp.evaluate_inner(&mut datums_local, &temp_storage)
+                                {
+                                    target.push((e.into(), Diff::ONE));
+                                }
+                            },
+                        )
+                        .as_collection(|_, v| v.clone())
+                        .leave_region(outer_scope);
+                    if let Some(e) = err_output.take() {
+                        err_output = Some(e.concat(errs));
+                    } else {
+                        err_output = Some(errs);
+                    }
+                }
+
+                arranged
+                    .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>, _>(
+                        "ReduceOrderStatistics",
+                        move |key, source, target| {
+                            let temp_storage = RowArena::new();
+                            let mut decoded = vals1.borrow();
+                            let mut entries = Vec::with_capacity(source.len());
+                            for (val, count) in source.iter() {
+                                entries.push((decoded.len(), *count));
+                                val.extend_datums(&temp_storage, &mut decoded, None);
+                            }
+                            let mut datums_local = datums1.borrow();
+                            key.extend_datums(&temp_storage, &mut datums_local, None);
+                            let key_len = datums_local.len();
+                            datums_local.push(order_statistics::finalize(
+                                &func,
+                                &decoded,
+                                &entries,
+                                &temp_storage,
+                            ));
+                            if let Some(row) = evaluate_mfp_after(
+                                &mfp_after1,
+                                &mut datums_local,
+                                &temp_storage,
+                                key_len,
+                            ) {
+                                target.push((row, Diff::ONE));
+                            }
+                        },
+                    )
+                    .leave_region(outer_scope)
+            });
+        (arranged_output, err_output)
+    }
+
+    /// Build the radix tree of a percentile aggregation, and resolve the positions of its
+    /// fractions top-down.
+    ///
+    /// The input contains the non-null values of each group along with their order keys. The
+    /// output contains `(key, [true, id, total, value])` for each group, where `value` is at the
+    /// position identified by `id` among the `total` values of the group. Positions are
+    /// identified by the index of their fraction for `percentile_disc`, and by twice that index
+    /// (plus one for the upper value) for the two values that `percentile_cont` interpolates.
+    fn build_percentile_tree<'s>(
+        &self,
+        values: VecCollection<'s, T, (u64, Row, Row), Diff>,
+        func: &AggregateFunc,
+        desc: bool,
+        validating: bool,
+    ) -> (
+        VecCollection<'s, T, (Row, Row), Diff>,
+        Option<VecCollection<'s, T, DataflowErrorSer, Diff>>,
+    ) {
+        // The positions that the root of each group resolves, given its number of values.
+        let targets = {
+            let func = func.clone();
+            move |total: i64| -> Vec<(u64, i64)> {
+                if total <= 0 {
+                    return Vec::new();
+                }
+                match &func {
+                    AggregateFunc::PercentileDisc { percentiles, .. } => percentiles
+                        .fractions
+                        .iter()
+                        .enumerate()
+                        .map(|(index, fraction)| {
+                            let position = Percentiles::disc_position(fraction.0, total);
+                            (u64::cast_from(index), position)
+                        })
+                        .collect(),
+                    AggregateFunc::PercentileCont { percentiles, .. } => percentiles
+                        .fractions
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(index, fraction)| {
+                            let (lo, hi, _) = Percentiles::cont_positions(fraction.0, total);
+                            let id = 2 * u64::cast_from(index);
+                            [(id, lo), (id + 1, hi)]
+                        })
+                        .collect(),
+                    _ => unreachable!("not a percentile aggregate: {func:?}"),
+                }
+            }
+        };
+
+        // Requests to find positions, as `((prefix, key), [true, id, position, total])`, where
+        // `position` is relative to the subtree of `prefix`.
+        let mut requests: Option<VecCollection<'s, T, (Row, Row), Diff>> = None;
+        for level in 0..order_statistics::LEVELS {
+            let children = values.clone().map(move |(order_key, key, _)| {
+                let prefix = order_statistics::prefix(order_key, level);
+                let child = order_statistics::child(order_key, level);
+                (
+                    order_statistics::node(prefix, &key),
+                    SharedRow::pack([Datum::False, Datum::from(child)]),
+                )
+            });
+            let input = match requests.take() {
+                Some(requests) => children.concat(requests),
+                None => children,
+            };
+            let targets = targets.clone();
+            let mut vals = DatumVec::new();
+            let resolved = input
+                .mz_arrange::<
+                    ColumnationChunker<_>,
+                    RowRowBatcher<_, _>,
+                    RowRowBuilder<_, _>,
+                    RowRowSpine<_, _>,
+                >("Arranged ReduceOrderStatistics Node")
+                .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>, _>(
+                    "ReduceOrderStatistics Node",
+                    move |_node, source, target| {
+                        let temp_storage = RowArena::new();
+                        let mut decoded = vals.borrow();
+                        let mut children = Vec::new();
+                        let mut requests = Vec::new();
+                        for (val, count) in source.iter() {
+                            decoded.clear();
+                            val.extend_datums(&temp_storage, &mut decoded, None);
+                            if decoded[0] == Datum::True {
+                                requests.push((
+                                    decoded[1].unwrap_uint64(),
+                                    decoded[2].unwrap_int64(),
+                                    decoded[3].unwrap_int64(),
+                                ));
+                            } else if count.is_positive() {
+                                // Non-positive counts are reported by the leaves.
+                                children.push((decoded[1].unwrap_uint64(), count.into_inner()));
+                            }
+                        }
+                        children.sort_unstable();
+                        if level == 0 {
+                            let total = children.iter().map(|(_, count)| count).sum();
+                            requests = targets(total)
+                                .into_iter()
+                                .map(|(id, position)| (id, position, total))
+                                .collect();
+                        }
+                        for (id, position, total) in requests {
+                            let mut seen = 0;
+                            for (child, count) in &children {
+                                if position < seen + count {
+                                    let resolved = SharedRow::pack([
+                                        Datum::from(*child),
+                                        Datum::from(id),
+                                        Datum::from(position - seen),
+                                        Datum::from(total),
+                                    ]);
+                                    target.push((resolved, Diff::ONE));
+                                    break;
+                                }
+                                seen += count;
+                            }
+                        }
+                    },
+                );
+            // Move each request down to the child that contains its position.
+            requests = Some(
+                resolved
+                    .as_collection(|node, resolved| (node.to_row(), resolved.to_row()))
+                    .map(|(node, resolved)| {
+                        let mut node = node.iter();
+                        let prefix = node.next().unwrap().unwrap_uint64();
+                        let mut resolved = resolved.iter();
+                        let child = resolved.next().unwrap().unwrap_uint64();
+                        (
+                            SharedRow::pack(
+                                std::iter::once(Datum::from((prefix << 8) | child)).chain(node),
+                            ),
+                            SharedRow::pack(std::iter::once(Datum::True).chain(resolved)),
+                        )
+                    }),
+            );
+        }
+
+        let leaves = values.map(|(order_key, key, val)| {
+            (
+                order_statistics::node(order_key, &key),
+                SharedRow::pack(std::iter::once(Datum::False).chain(val.iter())),
+            )
+        });
+        let arranged = leaves
+            .concat(requests.expect("at least one level"))
+            .mz_arrange::<
+                ColumnationChunker<_>,
+                RowRowBatcher<_, _>,
+                RowRowBuilder<_, _>,
+                RowRowSpine<_, _>,
+            >("Arranged ReduceOrderStatistics Leaf");
+        let errs = validating.then(|| self.build_order_statistics_validation(arranged.clone()));
+        let mut vals = DatumVec::new();
+        let found = arranged
+            .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>, _>(
+                "ReduceOrderStatistics Leaf",
+                move |_leaf, source, target| {
+                    let temp_storage = RowArena::new();
+                    let mut decoded = vals.borrow();
+                    let mut values = Vec::new();
+                    let mut requests = Vec::new();
+                    for (val, count) in source.iter() {
+                        let start = decoded.len();
+                        val.extend_datums(&temp_storage, &mut decoded, None);
+                        if decoded[start] == Datum::True {
+                            requests.push(start);
+                        } else if count.is_positive() {
+                            values.push((decoded[start + 1], count.into_inner()));
+                        }
+                    }
+                    values.sort_unstable_by(
+                        |(a, _), (b, _)| {
+                            if desc { b.cmp(a) } else { a.cmp(b) }
+                        },
+                    );
+                    for start in requests {
+                        let id = decoded[start + 1];
+                        let position = decoded[start + 2].unwrap_int64();
+                        let total = decoded[start + 3];
+                        let mut seen = 0;
+                        for (value, count) in &values {
+                            seen += count;
+                            if position < seen {
+                                target.push((SharedRow::pack([id, total, *value]), Diff::ONE));
+                                break;
+                            }
+                        }
+                    }
+                },
+            )
+            .as_collection(|leaf, found| (leaf.to_row(), found.to_row()))
+            .map(|(leaf, found)| {
+                let mut leaf = leaf.iter();
+                let _order_key = leaf.next();
+                (
+                    SharedRow::pack(leaf),
+                    SharedRow::pack(std::iter::once(Datum::True).chain(found.iter())),
+                )
+            });
+        (found, errs)
+    }
+
+    /// Build the radix tree of a mode aggregation, and find the most frequent value bottom-up.
+    ///
+    /// The input contains the non-null values of each group along with their order keys. The
+    /// output contains `(key, [true, count, value])` for each group, where `value` is the mode
+    /// of the group and `count` its number of occurrences.
+    fn build_mode_tree<'s>(
+        &self,
+        values: VecCollection<'s, T, (u64, Row, Row), Diff>,
+        desc: bool,
+        validating: bool,
+    ) -> (
+        VecCollection<'s, T, (Row, Row), Diff>,
+        Option<VecCollection<'s, T, DataflowErrorSer, Diff>>,
+    ) {
+        let leaves =
+            values.map(|(order_key, key, val)| (order_statistics::node(order_key, &key), val));
+        let arranged = leaves.mz_arrange::<
+            ColumnationChunker<_>,
+            RowRowBatcher<_, _>,
+            RowRowBuilder<_, _>,
+            RowRowSpine<_, _>,
+        >("Arranged ReduceOrderStatistics Leaf");
+        let errs = validating.then(|| self.build_order_statistics_validation(arranged.clone()));
+        let mut vals = DatumVec::new();
+        let mut stage = arranged
+            .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>, _>(
+                "ReduceOrderStatistics Leaf",
+                move |_leaf, source, target| {
+                    let temp_storage = RowArena::new();
+                    let mut decoded = vals.borrow();
+                    let mut best: Option<(i64, Datum)> = None;
+                    for (val, count) in source.iter() {
+                        // Non-positive counts are reported by the validation.
+                        if !count.is_positive() {
+                            continue;
+                        }
+                        decoded.clear();
+                        val.extend_datums(&temp_storage, &mut decoded, Some(1));
+                        let candidate = (count.into_inner(), decoded[0]);
+                        best = order_statistics::more_frequent(best, candidate, desc);
+                    }
+                    if let Some((count, value)) = best {
+                        target.push((SharedRow::pack([Datum::from(count), value]), Diff::ONE));
+                    }
+                },
+            )
+            .as_collection(|leaf, best| (leaf.to_row(), best.to_row()));
+
+        // Repeatedly combine the most frequent values of the children of each node.
+        for _ in 0..order_statistics::LEVELS {
+            let mut vals = DatumVec::new();
+            stage = stage
+                .map(|(node, best)| {
+                    let mut node = node.iter();
+                    let prefix = node.next().unwrap().unwrap_uint64();
+                    (order_statistics::parent(prefix, node), best)
+                })
+                .mz_arrange::<
+                    ColumnationChunker<_>,
+                    RowRowBatcher<_, _>,
+                    RowRowBuilder<_, _>,
+                    RowRowSpine<_, _>,
+                >("Arranged ReduceOrderStatistics Node")
+                .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>, _>(
+                    "ReduceOrderStatistics Node",
+                    move |_node, source, target| {
+                        let temp_storage = RowArena::new();
+                        let mut decoded = vals.borrow();
+                        let mut best: Option<(i64, Datum)> = None;
+                        for (val, count) in source.iter() {
+                            if !count.is_positive() {
+                                continue;
+                            }
+                            let start = decoded.len();
+                            val.extend_datums(&temp_storage, &mut decoded, None);
+                            let candidate = (decoded[start].unwrap_int64(), decoded[start + 1]);
+                            best = order_statistics::more_frequent(best, candidate, desc);
+                        }
+                        if let Some((count, value)) = best {
+                            target
+                                .push((SharedRow::pack([Datum::from(count), value]), Diff::ONE));
+                        }
+                    },
+                )
+                .as_collection(|node, best| (node.to_row(), best.to_row()));
+        }
+
+        let found = stage.map(|(root, best)| {
+            let mut root = root.iter();
+            let _prefix = root.next();
+            (
+                SharedRow::pack(root),
+                SharedRow::pack(std::iter::once(Datum::True).chain(best.iter())),
+            )
+        });
+        (found, errs)
+    }
+
+    /// Build the validation of the leaves of an order-statistics reduction, which contain
+    /// every non-null input of the aggregation.
+    fn build_order_statistics_validation<'s>(
+        &self,
+        arranged: RowRowArrangement<'s, T>,
+    ) -> VecCollection<'s, T, DataflowErrorSer, Diff> {
+        let error_logger = self.error_logger();
+        arranged
+            .mz_reduce_abelian::<_, RowErrBuilder<_, _>, RowErrSpine<_, _>, _>(
+                "ReduceOrderStatistics Leaf Error Check",
+                move |_leaf, source, target| {
+                    // Negative counts would be surprising, but until we are 100% certain we won't
+                    // see them, we should report when we do.
+                    for (val, count) in source.iter() {
+                        if count.is_positive() {
+                            continue;
+                        }
+                        let val = val.to_row();
+                        let message = "Non-positive accumulation in ReduceOrderStatistics";
+                        error_logger.log(message, &format!("val={val:?}, count={count}"));
+                        target.push((EvalError::Internal(message.into()).into(), Diff::ONE));
+                        return;
+                    }
+                },
+            )
+            .as_collection(|_, v| v.clone())
+    }
+
     /// Build the dataflow to compute and arrange multiple hierarchical aggregations
     /// on non-monotonic inputs.
     ///
@@ -1504,7 +2031,12 @@ impl<'scope, T: RenderTimestamp> Context<'scope, T> {
                         key.extend_datums(&temp_storage, &mut datums_local, None);
                         let key_len = datums_local.len();
                         for (aggr, accum) in full_aggrs.iter().zip_eq(accums) {
-                            datums_local.push(finalize_accum(&aggr.func, accum, total));
+                            datums_local.push(finalize_accum(
+                                &aggr.func,
+                                accum,
+                                total,
+                                &temp_storage,
+                            ));
                         }
 
                         if let Some(row) = evaluate_mfp_after(
@@ -1556,6 +2088,11 @@ impl<'scope, T: RenderTimestamp> Context<'scope, T> {
                                     output.push((err.into(), Diff::ONE));
                                 }
                             }
+                            (AggregateFunc::HllUnionAgg, Accum::Hll { invalid, .. }) => {
+                                if invalid.is_positive() {
+                                    output.push((hll::invalid_sketch().into(), Diff::ONE));
+                                }
+                            }
                             _ => (), // no more errors to check for at this point!
                         }
                     }
@@ -1566,7 +2103,7 @@ impl<'scope, T: RenderTimestamp> Context<'scope, T> {
                     let mut datums_local = datums2.borrow();
                     key.extend_datums(&temp_storage, &mut datums_local, None);
                     for (aggr, accum) in full_aggrs2.iter().zip_eq(accums) {
-                        datums_local.push(finalize_accum(&aggr.func, accum, total));
+                        datums_local.push(finalize_accum(&aggr.func, accum, total, &temp_storage));
                     }
 
                     if let Result::Err(e) = mfp.evaluate_inner(&mut datums_local, &temp_storage) {
@@ -1628,6 +2165,13 @@ fn accumulable_zero(aggr_func: &AggregateFunc) -> Accum {
             nans: Diff::ZERO,
             non_nulls: Diff::ZERO,
         },
+        AggregateFunc::ApproxCountDistinct
+        | AggregateFunc::HllSketchAgg
+        | AggregateFunc::HllUnionAgg => Accum::Hll {
+            counts: Vec::new(),
+            invalid: Diff::ZERO,
+            non_nulls: Diff::ZERO,
+        },
         _ => Accum::SimpleNumber {
             accum: AccumCount::ZERO,
             non_nulls: Diff::ZERO,
@@ -1794,6 +2338,36 @@ fn datum_to_accumulator(aggregate_func: &AggregateFunc, datum: Datum) -> Accum {
             },
             x => panic!("Invalid argument to AggregateFunc::SumNumeric: {x:?}"),
         },
+        AggregateFunc::ApproxCountDistinct | AggregateFunc::HllSketchAgg => match datum {
+            Datum::Null => accumulable_zero(aggregate_func),
+            datum => {
+                let (register, rank) = hll::observe(datum);
+                Accum::Hll {
+                    counts: vec![(hll::count_key(register, rank), Diff::ONE)],
+                    invalid: Diff::ZERO,
+                    non_nulls: Diff::ONE,
+                }
+            }
+        },
+        AggregateFunc::HllUnionAgg => match datum {
+            Datum::Null => accumulable_zero(aggregate_func),
+            Datum::Bytes(sketch) => match hll::decode(sketch) {
+                Ok(registers) => Accum::Hll {
+                    counts: hll::register_keys(registers)
+                        .map(|key| (key, Diff::ONE))
+                        .collect(),
+                    invalid: Diff::ZERO,
+                    non_nulls: Diff::ONE,
+                },
+                // Reported as an error by `AccumulableErrorCheck`.
+                Err(_) => Accum::Hll {
+                    counts: Vec::new(),
+                    invalid: Diff::ONE,
+                    non_nulls: Diff::ONE,
+                },
+            },
+            x => panic!("Invalid argument to AggregateFunc::HllUnionAgg: {x:?}"),
+        },
         _ => {
             // Other accumulations need to disentangle the accumulable
             // value from its NULL-ness, which is not quite as easily
@@ -1837,11 +2411,22 @@ fn datum_to_accumulator(aggregate_func: &AggregateFunc, datum: Datum) -> Accum {
     }
 }
 
-fn finalize_accum<'a>(aggr_func: &'a AggregateFunc, accum: &'a Accum, total: Diff) -> Datum<'a> {
+fn finalize_accum<'a>(
+    aggr_func: &'a AggregateFunc,
+    accum: &'a Accum,
+    total: Diff,
+    temp_storage: &'a RowArena,
+) -> Datum<'a> {
     // The finished value depends on the aggregation function in a variety of ways.
-    // For all aggregates but count, if only null values were
+    // For all aggregates but the counts, if only null values were
     // accumulated, then the output is null.
-    if total.is_positive() && accum.is_zero() && *aggr_func != AggregateFunc::Count {
+    if total.is_positive()
+        && accum.is_zero()
+        && !matches!(
+            aggr_func,
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct
+        )
+    {
         Datum::Null
     } else {
         match (&aggr_func, &accum) {
@@ -1869,6 +2454,14 @@ fn finalize_accum<'a>(aggr_func: &'a AggregateFunc, accum: &'a Accum, total: Dif
                 }
             }
             (AggregateFunc::Dummy, _) => Datum::Dummy,
+            (AggregateFunc::ApproxCountDistinct, Accum::Hll { counts, .. }) => {
+                Datum::Int64(hll::estimate(&hll::registers_from_counts(counts)))
+            }
+            (AggregateFunc::HllSketchAgg, Accum::Hll { counts, .. })
+            | (AggregateFunc::HllUnionAgg, Accum::Hll { counts, .. }) => {
+                let registers = hll::registers_from_counts(counts);
+                Datum::Bytes(temp_storage.push_bytes(hll::encode(&registers)))
+            }
             // If any non-nulls, just report the aggregate.
             (AggregateFunc::SumInt16, Accum::SimpleNumber { accum, .. })
             | (AggregateFunc::SumInt32, Accum::SimpleNumber { accum, .. }) => {
@@ -2012,7 +2605,6 @@ type AccumCount = mz_ore::Overflowing<i128>;
 #[derive(
     Debug,
     Clone,
-    Copy,
     PartialEq,
     Eq,
     PartialOrd,
@@ -2062,6 +2654,16 @@ enum Accum {
         /// Counts non-NULL values
         non_nulls: Diff,
     },
+    /// Accumulates HyperLogLog observations.
+    Hll {
+        /// Signed counts of the observed `(register, rank)` pairs, keyed by
+        /// [`hll::count_key`], sorted by key and without zero counts.
+        counts: Vec<(u32, Diff)>,
+        /// Counts inputs that are not valid sketches
+        invalid: Diff,
+        /// Counts non-NULL values
+        non_nulls: Diff,
+    },
 }
 
 impl IsZero for Accum {
@@ -2095,6 +2697,11 @@ impl IsZero for Accum {
                     && nans.is_zero()
                     && non_nulls.is_zero()
             }
+            Accum::Hll {
+                counts,
+                invalid,
+                non_nulls,
+            } => counts.is_empty() && invalid.is_zero() && non_nulls.is_zero(),
         }
     }
 }
@@ -2195,6 +2802,22 @@ impl Semigroup for Accum {
                 *nans += other_nans;
                 *non_nulls += other_non_nulls;
             }
+            (
+                Accum::Hll {
+                    counts,
+                    invalid,
+                    non_nulls,
+                },
+                Accum::Hll {
+                    counts: other_counts,
+                    invalid: other_invalid,
+                    non_nulls: other_non_nulls,
+                },
+            ) => {
+                hll::add_counts(counts, other_counts);
+                *invalid += other_invalid;
+                *non_nulls += other_non_nulls;
+            }
             (l, r) => unreachable!(
                 "Accumulator::plus_equals called with non-matching variants: {l:?} vs {r:?}"
             ),
@@ -2261,12 +2884,80 @@ impl Multiply<Diff> for Accum {
                     non_nulls: non_nulls * factor,
                 }
             }
+            Accum::Hll {
+                counts,
+                invalid,
+                non_nulls,
+            } => Accum::Hll {
+                counts: counts
+                    .into_iter()
+                    .map(|(key, count)| (key, count * factor))
+                    .filter(|(_, count)| !count.is_zero())
+                    .collect(),
+                invalid: invalid * factor,
+                non_nulls: non_nulls * factor,
+            },
         }
     }
 }
 
 impl Columnation for Accum {
-    type InnerRegion = CopyRegion<Self>;
+    type InnerRegion = AccumRegion;
+}
+
+/// Region for [`Accum`]. All variants but [`Accum::Hll`] are plain data and
+/// are copied as is; the counts of the latter are stored in a backing region.
+#[derive(Default)]
+struct AccumRegion {
+    counts: <Vec<(u32, Diff)> as Columnation>::InnerRegion,
+}
+
+impl Region for AccumRegion {
+    type Item = Accum;
+
+    unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+        match item {
+            Accum::Hll {
+                counts,
+                invalid,
+                non_nulls,
+            } => Accum::Hll {
+                counts: unsafe { self.counts.copy(counts) },
+                invalid: *invalid,
+                non_nulls: *non_nulls,
+            },
+            // The remaining variants own no heap allocations.
+            item => item.clone(),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.counts.clear();
+    }
+
+    fn reserve_items<'a, I>(&mut self, items: I)
+    where
+        Self: 'a,
+        I: Iterator<Item = &'a Self::Item> + Clone,
+    {
+        self.counts
+            .reserve_items(items.filter_map(|item| match item {
+                Accum::Hll { counts, .. } => Some(counts),
+                _ => None,
+            }));
+    }
+
+    fn reserve_regions<'a, I>(&mut self, regions: I)
+    where
+        Self: 'a,
+        I: Iterator<Item = &'a Self> + Clone,
+    {
+        self.counts.reserve_regions(regions.map(|r| &r.counts));
+    }
+
+    fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+        self.counts.heap_size(callback);
+    }
 }
 
 /// Monoids for in-place compaction of monotonic streams.
@@ -2514,14 +3205,219 @@ mod monoids {
             | AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
-            | AggregateFunc::FusedWindowAggregate { .. } => None,
+            | AggregateFunc::FusedWindowAggregate { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::ApproxCountDistinct
+            | AggregateFunc::HllSketchAgg
+            | AggregateFunc::HllUnionAgg
+            | AggregateFunc::RangeAgg
+            | AggregateFunc::RangeIntersectAgg => None,
+        }
+    }
+}
+
+/// Helpers for rendering ordered-set aggregates as order-statistics reductions.
+mod order_statistics {
+    use std::collections::BTreeMap;
+
+    use mz_expr::{AggregateFunc, Percentiles};
+    use mz_repr::adt::numeric;
+    use mz_repr::{Datum, Diff, Row, RowArena, SharedRow};
+
+    /// The number of levels of the radix tree over order keys, each resolving eight bits.
+    pub const LEVELS: u32 = 8;
+
+    /// Maps `datum` to a key that is monotone in the sort order of an ordered-set
+    /// aggregation: if `a` sorts before `b`, then `order_key(a, desc) <= order_key(b, desc)`.
+    ///
+    /// Values that share a key are sorted by the leaves of the radix tree, so the key only needs
+    /// to be selective enough to keep the leaves small. Types without a cheap order-preserving
+    /// encoding all share a key, which degrades to sorting the group in a single leaf.
+    pub fn order_key(datum: Datum, desc: bool) -> u64 {
+        let key = match datum {
+            Datum::False => 0,
+            Datum::True => 1,
+            Datum::Int16(i) => signed(i.into()),
+            Datum::Int32(i) => signed(i.into()),
+            Datum::Int64(i) => signed(i),
+            Datum::UInt8(u) => u.into(),
+            Datum::UInt16(u) => u.into(),
+            Datum::UInt32(u) => u.into(),
+            Datum::UInt64(u) => u,
+            Datum::MzTimestamp(t) => t.into(),
+            Datum::Float32(f) => float(f.into_inner().into()),
+            Datum::Float64(f) => float(f.into_inner()),
+            Datum::Numeric(n) => {
+                // Rounding to the nearest float preserves the order, though not strictly.
+                let mut cx = numeric::cx_datum();
+                float(cx.try_into_f64(n.0).unwrap_or_else(|_| {
+                    if n.0.is_negative() {
+                        f64::NEG_INFINITY
+                    } else {
+                        f64::INFINITY
+                    }
+                }))
+            }
+            Datum::Date(d) => signed(d.unix_epoch_days().into()),
+            Datum::Time(t) => t
+                .signed_duration_since(Default::default())
+                .num_nanoseconds()
+                .map_or(0, signed),
+            Datum::Timestamp(t) => signed(t.and_utc().timestamp_micros()),
+            Datum::TimestampTz(t) => signed(t.timestamp_micros()),
+            // Intervals are ordered by months, then days, then microseconds.
+            Datum::Interval(i) => {
+                let months = i.months.cast_unsigned() ^ (1 << 31);
+                let days = i.days.cast_unsigned() ^ (1 << 31);
+                (u64::from(months) << 32) | u64::from(days)
+            }
+            Datum::Bytes(b) => leading_bytes(b),
+            Datum::String(s) => leading_bytes(s.as_bytes()),
+            Datum::Uuid(u) => leading_bytes(u.as_bytes()),
+            _ => 0,
+        };
+        if desc { !key } else { key }
+    }
+
+    fn signed(i: i64) -> u64 {
+        i.cast_unsigned() ^ (1 << 63)
+    }
+
+    /// Orders floats like `OrderedFloat`, which equates zeros and puts NaN last.
+    fn float(f: f64) -> u64 {
+        if f.is_nan() {
+            return u64::MAX;
+        }
+        let bits = if f == 0.0 { 0.0f64 } else { f }.to_bits();
+        if f < 0.0 { !bits } else { bits | (1 << 63) }
+    }
+
+    fn leading_bytes(b: &[u8]) -> u64 {
+        let mut leading = [0; 8];
+        let len = b.len().min(leading.len());
+        leading[..len].copy_from_slice(&b[..len]);
+        u64::from_be_bytes(leading)
+    }
+
+    /// The prefix of `order_key` that identifies its node at `level` of the radix tree.
+    pub fn prefix(order_key: u64, level: u32) -> u64 {
+        order_key.checked_shr(64 - 8 * level).unwrap_or(0)
+    }
+
+    /// The child of the node at `level` whose subtree contains `order_key`.
+    pub fn child(order_key: u64, level: u32) -> u64 {
+        (order_key >> (56 - 8 * level)) & 0xff
+    }
+
+    /// The node of a group's radix tree with the given `prefix`.
+    pub fn node(prefix: u64, key: &Row) -> Row {
+        SharedRow::pack(std::iter::once(Datum::from(prefix)).chain(key.iter()))
+    }
+
+    /// The parent of the node with the given `prefix` and key datums.
+    pub fn parent<'a>(prefix: u64, key: impl Iterator<Item = Datum<'a>>) -> Row {
+        SharedRow::pack(std::iter::once(Datum::from(prefix >> 8)).chain(key))
+    }
+
+    /// Whether `a` sorts before `b`.
+    fn precedes(a: Datum, b: Datum, desc: bool) -> bool {
+        if desc { a > b } else { a < b }
+    }
+
+    /// Returns the more frequent of `best` and `candidate`, preferring the first value in the
+    /// sort order among equally frequent ones.
+    pub fn more_frequent<'a>(
+        best: Option<(i64, Datum<'a>)>,
+        candidate: (i64, Datum<'a>),
+        desc: bool,
+    ) -> Option<(i64, Datum<'a>)> {
+        match best {
+            Some((count, value))
+                if count > candidate.0
+                    || (count == candidate.0 && precedes(value, candidate.1, desc)) =>
+            {
+                best
+            }
+            _ => Some(candidate),
+        }
+    }
+
+    /// Computes the result of an ordered-set aggregation from the entries of its final reduce.
+    ///
+    /// `entries` holds the offsets of the decoded values in `decoded` with their counts. These
+    /// are either null markers (`[false]`), or what the radix tree found: `[true, id, total,
+    /// value]` for percentiles, and `[true, count, value]` for the mode.
+    pub fn finalize<'a>(
+        func: &AggregateFunc,
+        decoded: &[Datum<'a>],
+        entries: &[(usize, Diff)],
+        temp_storage: &'a RowArena,
+    ) -> Datum<'a> {
+        let found = entries
+            .iter()
+            .filter(|(start, count)| decoded[*start] == Datum::True && count.is_positive())
+            .map(|(start, _)| &decoded[start + 1..]);
+        match func {
+            AggregateFunc::Mode { desc } => found
+                .fold(None, |best, found| {
+                    more_frequent(best, (found[0].unwrap_int64(), found[1]), *desc)
+                })
+                .map_or(Datum::Null, |(_, value)| value),
+            AggregateFunc::PercentileDisc { percentiles, .. } => {
+                let (values, _) = positions(found);
+                if values.is_empty() {
+                    return Datum::Null;
+                }
+                let mut ids = 0..;
+                percentiles.pack(temp_storage, |_| {
+                    let id = ids.next().unwrap();
+                    values.get(&id).copied().unwrap_or(Datum::Null)
+                })
+            }
+            AggregateFunc::PercentileCont { percentiles, .. } => {
+                let (values, total) = positions(found);
+                if values.is_empty() {
+                    return Datum::Null;
+                }
+                let mut ids = (0..).step_by(2);
+                percentiles.pack(temp_storage, |fraction| {
+                    let id = ids.next().unwrap();
+                    let (_, _, weight) = Percentiles::cont_positions(fraction, total);
+                    match (values.get(&id), values.get(&(id + 1))) {
+                        (Some(lo), Some(hi)) => Percentiles::interpolate(*lo, *hi, weight),
+                        _ => Datum::Null,
+                    }
+                })
+            }
+            _ => unreachable!("not an ordered-set aggregate: {func:?}"),
         }
     }
+
+    /// Collects the values found at each position by a percentile aggregation, along with
+    /// the number of values in the group.
+    fn positions<'a, 'b>(
+        found: impl Iterator<Item = &'b [Datum<'a>]>,
+    ) -> (BTreeMap<u64, Datum<'a>>, i64) {
+        let mut total = 0;
+        let values = found
+            .map(|found| {
+                total = found[1].unwrap_int64();
+                (found[0].unwrap_uint64(), found[2])
+            })
+            .collect();
+        (values, total)
+    }
 }
 
 mod window_agg_helpers {
@@ -2593,7 +3489,8 @@ mod window_agg_helpers {
 
         fn get_current_aggregate<'a>(&self, temp_storage: &'a RowArena) -> Datum<'a> {
             temp_storage.make_datum(|packer| {
-                packer.push(finalize_accum(&self.aggr_func, &self.accum, self.total));
+                let datum = finalize_accum(&self.aggr_func, &self.accum, self.total, temp_storage);
+                packer.push(datum);
             })
         }
     }
@@ -2632,6 +3529,8 @@ mod window_agg_helpers {
 
 #[cfg(test)]
 mod tests {
+    use mz_repr::adt::interval::Interval;
+
     use super::*;
 
     /// The saturating conversion that `float_to_fixed_point` replaces. Used to
@@ -2719,7 +3618,109 @@ mod tests {
         let mut acc = accumulable_zero(&func);
         acc.plus_equals(&datum_to_accumulator(&func, Datum::from(1.1e31_f64)));
         acc.plus_equals(&datum_to_accumulator(&func, Datum::from(-1.1e31_f64)));
-        let datum = finalize_accum(&func, &acc, Diff::from(2_i64));
+        let datum = finalize_accum(&func, &acc, Diff::from(2_i64), &RowArena::new());
         assert_eq!(datum, Datum::from(0.0_f64));
     }
+
+    #[mz_ore::test]
+    fn hll_accumulator_supports_retractions() {
+        let temp_storage = RowArena::new();
+        for func in [
+            AggregateFunc::ApproxCountDistinct,
+            AggregateFunc::HllSketchAgg,
+        ] {
+            let observe = |i, diff| datum_to_accumulator(&func, Datum::Int64(i)).multiply(&diff);
+            // Insert 0..1000 and 0..500 again, then retract 500..1000.
+            let mut acc = accumulable_zero(&func);
+            for i in (0..1_000).chain(0..500) {
+                acc.plus_equals(&observe(i, Diff::ONE));
+            }
+            for i in 500..1_000 {
+                acc.plus_equals(&observe(i, Diff::MINUS_ONE));
+            }
+            let expected = func.eval(
+                (0..500).map(|i| (Datum::Int64(i), Diff::ONE)),
+                &temp_storage,
+            );
+            let datum = finalize_accum(&func, &acc, Diff::from(1_000_i64), &temp_storage);
+            assert_eq!(datum, expected, "func = {func:?}");
+
+            for i in 0..500 {
+                acc.plus_equals(&observe(i, Diff::from(-2_i64)));
+            }
+            assert!(acc.is_zero(), "func = {func:?}");
+        }
+    }
+
+    #[mz_ore::test]
+    fn order_key_is_monotone() {
+        let ints = [i64::MIN, -1_000, -1, 0, 1, 1_000, i64::MAX].map(Datum::Int64);
+        let floats = [
+            f64::NEG_INFINITY,
+            -1e300,
+            -1.0,
+            -1e-300,
+            -0.0,
+            0.0,
+            1e-300,
+            1.0,
+            1e300,
+            f64::INFINITY,
+            f64::NAN,
+        ]
+        .map(Datum::from);
+        let strings = ["", "a", "a\0", "ab", "abcdefgh", "abcdefghi", "b"].map(Datum::String);
+        let intervals = [
+            (-1, 0, 0),
+            (0, -1, 0),
+            (0, 0, -1),
+            (0, 0, 0),
+            (0, 0, 1),
+            (0, 1, 0),
+            (1, -40, 0),
+        ]
+        .map(|(months, days, micros)| Datum::Interval(Interval::new(months, days, micros)));
+        for sorted in [&ints[..], &floats, &strings, &intervals] {
+            for desc in [false, true] {
+                let keys: Vec<_> = sorted
+                    .iter()
+                    .map(|datum| order_statistics::order_key(*datum, desc))
+                    .collect();
+                let monotone = if desc {
+                    keys.is_sorted_by(|a, b| a >= b)
+                } else {
+                    keys.is_sorted()
+                };
+                assert!(
+                    monotone,
+                    "sorted = {sorted:?}, desc = {desc}, keys = {keys:?}"
+                );
+            }
+        }
+        // Equal values share a key.
+        assert_eq!(
+            order_statistics::order_key(Datum::from(-0.0), false),
+            order_statistics::order_key(Datum::from(0.0), false),
+        );
+    }
+
+    #[mz_ore::test]
+    fn order_key_radix_tree_paths() {
+        for order_key in [0, 1, 0x0123_4567_89ab_cdef, u64::MAX] {
+            assert_eq!(order_statistics::prefix(order_key, 0), 0);
+            for level in 0..order_statistics::LEVELS {
+                let prefix = order_statistics::prefix(order_key, level);
+                let child = order_statistics::child(order_key, level);
+                assert!(child <= 0xff);
+                assert_eq!(
+                    (prefix << 8) | child,
+                    order_statistics::prefix(order_key, level + 1)
+                );
+            }
+            assert_eq!(
+                order_statistics::prefix(order_key, order_statistics::LEVELS),
+                order_key
+            );
+        }
+    }
 }
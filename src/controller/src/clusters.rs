@@ -695,6 +695,7 @@ impl Controller {
         let environment_id = self.connection_context().environment_id.clone();
         let aws_external_id_prefix = self.connection_context().aws_external_id_prefix.clone();
         let aws_connection_role_arn = self.connection_context().aws_connection_role_arn.clone();
+        let gcp_oauth_token_uri = self.connection_context().gcp_oauth_token_uri.clone();
         let persist_pubsub_url = self.persist_pubsub_url.clone();
         let secrets_args = self.secrets_args.to_flags();
 
@@ -786,6 +787,9 @@ impl Controller {
                             aws_connection_role_arn
                         ));
                     }
+                    if let Some(gcp_oauth_token_uri) = &gcp_oauth_token_uri {
+                        args.push(format!("--gcp-oauth-token-uri={}", gcp_oauth_token_uri));
+                    }
                     if let Some(memory_limit) = location.allocation.memory_limit {
                         args.push(format!(
                             "--announce-memory-limit={}",
@@ -125,6 +125,9 @@ pub enum ControllerResponse {
     /// [`Controller::install_compute_watch_set`] and
     /// [`Controller::install_storage_watch_set`] for details.
     WatchSetFinished(Vec<WatchSetId>),
+    /// Notification that source exports wait for columns that were added to
+    /// their upstream table to be added to them.
+    SourceSchemaEvolutionPending(Vec<GlobalId>),
 }
 
 /// Whether one of the underlying controllers is ready for their `process`
@@ -476,11 +479,14 @@ impl Controller {
         storage_metadata: &StorageMetadata,
     ) -> Result<Option<ControllerResponse>, anyhow::Error> {
         let maybe_response = self.storage.process(storage_metadata)?;
-        Ok(maybe_response.and_then(
-            |mz_storage_client::controller::Response::FrontierUpdates(r)| {
+        Ok(maybe_response.and_then(|r| match r {
+            mz_storage_client::controller::Response::FrontierUpdates(r) => {
                 self.handle_frontier_updates(&r)
-            },
-        ))
+            }
+            mz_storage_client::controller::Response::SchemaEvolutionPending(ids) => {
+                Some(ControllerResponse::SourceSchemaEvolutionPending(ids))
+            }
+        }))
     }
 
     /// Process a pending response from the compute controller. If necessary,
@@ -604,6 +604,10 @@ pub struct Args {
     /// a customer's requested role for an AWS connection.
     #[clap(long, env = "AWS_CONNECTION_ROLE_ARN")]
     aws_connection_role_arn: Option<String>,
+    /// The URI at which GCP service accounts mint access tokens instead of
+    /// Google's, e.g. to test against a GCS emulator.
+    #[clap(long, env = "GCP_OAUTH_TOKEN_URI")]
+    gcp_oauth_token_uri: Option<String>,
     /// Prefix for an external ID to be supplied to all AWS AssumeRole operations.
     ///
     /// Details: <https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_create_for-user_externalid.html>
@@ -1042,6 +1046,7 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         &args.tracing.startup_log_filter,
         args.aws_external_id_prefix,
         args.aws_connection_role_arn,
+        args.gcp_oauth_token_uri,
         secrets_reader,
         cloud_resource_reader,
     );
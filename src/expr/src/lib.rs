@@ -24,6 +24,7 @@ mod relation;
 mod scalar;
 
 pub mod explain;
+pub mod hll;
 pub mod row;
 pub mod virtual_syntax;
 pub mod visit;
@@ -37,7 +38,7 @@ pub use relation::func::REPEAT_ROW_NAME;
 pub use relation::func::order_aggregate_datums as order_aggregate_datums_exported_for_benchmarking;
 pub use relation::func::{
     AggregateFunc, AnalyzedRegex, AnalyzedRegexOpts, CaptureGroupDesc, LagLeadType,
-    NaiveOneByOneAggr, OneByOneAggr, TableFunc,
+    NaiveOneByOneAggr, OneByOneAggr, Percentiles, TableFunc,
 };
 pub use relation::join_input_mapper::JoinInputMapper;
 pub use relation::{
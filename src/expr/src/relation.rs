@@ -46,7 +46,8 @@ use crate::relation::func::{AggregateFunc, LagLeadType, TableFunc};
 use crate::row::{RowCollection, RowCollectionIter};
 use crate::scalar::columns::Columns;
 use crate::scalar::func::variadic::{
-    JsonbBuildArray, JsonbBuildObject, ListCreate, ListIndex, MapBuild, RecordCreate,
+    ArrayCreate, JsonbBuildArray, JsonbBuildObject, ListCreate, ListIndex, MapBuild,
+    MultiRangeCreate, RecordCreate,
 };
 use crate::visit::{Visit, VisitChildren};
 use crate::{
@@ -2528,6 +2529,8 @@ impl AggregateExpr {
             | AggregateFunc::MinTime
             | AggregateFunc::Any
             | AggregateFunc::All
+            | AggregateFunc::ApproxCountDistinct
+            | AggregateFunc::HllSketchAgg
             | AggregateFunc::Dummy => self.expr.is_literal(),
             AggregateFunc::Count => self.expr.is_literal_null(),
             AggregateFunc::SumInt16
@@ -2548,12 +2551,22 @@ impl AggregateExpr {
             | AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::WindowAggregate { .. }
-            | AggregateFunc::FusedWindowAggregate { .. } => self.expr.is_literal_err(),
+            | AggregateFunc::FusedWindowAggregate { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::HllUnionAgg
+            | AggregateFunc::RangeAgg
+            | AggregateFunc::RangeIntersectAgg => self.expr.is_literal_err(),
         }
     }
 
@@ -2562,8 +2575,9 @@ impl AggregateExpr {
     /// returned by `on_unique`, which is cheaper. (See `ReduceElision`.)
     pub fn on_unique(&self, input_type: &[ReprColumnType]) -> MirScalarExpr {
         match &self.func {
-            // Count is one if non-null, and zero if null.
-            AggregateFunc::Count => self
+            // Count is one if non-null, and zero if null. A sketch of a single
+            // value estimates its cardinality exactly.
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct => self
                 .expr
                 .clone()
                 .call_unary(UnaryFunc::IsNull(crate::func::IsNull))
@@ -2572,6 +2586,18 @@ impl AggregateExpr {
                     MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
                 ),
 
+            // The sketch of a single value, or null if the value is null.
+            AggregateFunc::HllSketchAgg => self
+                .expr
+                .clone()
+                .call_unary(UnaryFunc::HllSketch(scalar_func::HllSketch)),
+
+            // The union of a single sketch is that sketch, once validated.
+            AggregateFunc::HllUnionAgg => self
+                .expr
+                .clone()
+                .call_unary(UnaryFunc::HllValidateSketch(scalar_func::HllValidateSketch)),
+
             // SumInt16 takes Int16s as input, but outputs Int64s.
             AggregateFunc::SumInt16 => self
                 .expr
@@ -2666,13 +2692,69 @@ impl AggregateExpr {
                 .clone()
                 .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0))),
 
-            // RowNumber, Rank, DenseRank take a list of records and output a list containing exactly 1 element
-            AggregateFunc::RowNumber { .. } => {
-                self.on_unique_ranking_window_funcs(input_type, "?row_number?")
-            }
-            AggregateFunc::Rank { .. } => self.on_unique_ranking_window_funcs(input_type, "?rank?"),
-            AggregateFunc::DenseRank { .. } => {
-                self.on_unique_ranking_window_funcs(input_type, "?dense_rank?")
+            // RowNumber, Rank, DenseRank, PercentRank, CumeDist take a list of records and output a
+            // list containing exactly 1 element
+            AggregateFunc::RowNumber { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?row_number?",
+                MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
+            ),
+            AggregateFunc::Rank { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?rank?",
+                MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
+            ),
+            AggregateFunc::DenseRank { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?dense_rank?",
+                MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
+            ),
+            AggregateFunc::PercentRank { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?percent_rank?",
+                MirScalarExpr::literal_ok(Datum::Float64(0.0.into()), ReprScalarType::Float64),
+            ),
+            AggregateFunc::CumeDist { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?cume_dist?",
+                MirScalarExpr::literal_ok(Datum::Float64(1.0.into()), ReprScalarType::Float64),
+            ),
+
+            // The input type for Ntile is ((OriginalRow, NumBuckets), OrderByExprs...)
+            AggregateFunc::Ntile { .. } => {
+                let tuple = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Get the overall return type
+                let return_type_with_orig_row = self
+                    .typ(input_type)
+                    .scalar_type
+                    .unwrap_list_element_type()
+                    .clone();
+
+                // Extract the original row
+                let original_row = tuple
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Extract the number of buckets
+                let num_buckets = tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+
+                let (result_expr, column_name) = Self::on_unique_ntile(num_buckets);
+
+                MirScalarExpr::call_variadic(
+                    ListCreate {
+                        elem_type: SqlScalarType::from_repr(&return_type_with_orig_row),
+                    },
+                    vec![MirScalarExpr::call_variadic(
+                        RecordCreate {
+                            field_names: vec![column_name, ColumnName::from("?record?")],
+                        },
+                        vec![result_expr, original_row],
+                    )],
+                )
             }
 
             // The input type for LagLead is ((OriginalRow, (InputValue, Offset, Default)), OrderByExprs...)
@@ -2802,6 +2884,47 @@ impl AggregateExpr {
                 )
             }
 
+            // The input type for NthValue is ((OriginalRow, (InputValue, N)), OrderByExprs...)
+            AggregateFunc::NthValue { window_frame, .. } => {
+                let tuple = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Get the overall return type
+                let return_type_with_orig_row = self
+                    .typ(input_type)
+                    .scalar_type
+                    .unwrap_list_element_type()
+                    .clone();
+                let nth_value_return_type =
+                    return_type_with_orig_row.unwrap_record_element_type()[0].clone();
+
+                // Extract the original row
+                let original_row = tuple
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Extract the encoded args
+                let encoded_args =
+                    tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+
+                let (result_expr, column_name) =
+                    Self::on_unique_nth_value(window_frame, encoded_args, nth_value_return_type);
+
+                MirScalarExpr::call_variadic(
+                    ListCreate {
+                        elem_type: SqlScalarType::from_repr(&return_type_with_orig_row),
+                    },
+                    vec![MirScalarExpr::call_variadic(
+                        RecordCreate {
+                            field_names: vec![column_name, ColumnName::from("?record?")],
+                        },
+                        vec![result_expr, original_row],
+                    )],
+                )
+            }
+
             // The input type for window aggs is ((OriginalRow, InputValue), OrderByExprs...)
             // See an example MIR in `window_func_applied_to`.
             AggregateFunc::WindowAggregate {
@@ -2991,6 +3114,21 @@ impl AggregateExpr {
                                 return_type_for_func,
                             )
                         }
+                        AggregateFunc::Ntile { order_by } => {
+                            assert_eq!(order_by, outer_order_by);
+                            Self::on_unique_ntile(args_for_func)
+                        }
+                        AggregateFunc::NthValue {
+                            window_frame,
+                            order_by,
+                        } => {
+                            assert_eq!(order_by, outer_order_by);
+                            Self::on_unique_nth_value(
+                                window_frame,
+                                args_for_func,
+                                return_type_for_func,
+                            )
+                        }
                         _ => panic!("unknown function in FusedValueWindowFunc"),
                     };
                     func_result_exprs.push(result);
@@ -3062,14 +3200,61 @@ impl AggregateExpr {
             | AggregateFunc::Any
             | AggregateFunc::All
             | AggregateFunc::Dummy => self.expr.clone(),
+
+            // Every percentile and the mode of a single value is that value.
+            AggregateFunc::PercentileCont { percentiles, .. }
+            | AggregateFunc::PercentileDisc { percentiles, .. }
+                if !percentiles.array =>
+            {
+                self.expr.clone()
+            }
+            AggregateFunc::Mode { .. } => self.expr.clone(),
+
+            // The intersection of a single range or multirange is that value,
+            // as is the union of a single multirange. The union of a single
+            // range is the multirange containing it, or null if it is null.
+            AggregateFunc::RangeIntersectAgg => self.expr.clone(),
+            AggregateFunc::RangeAgg => match self.expr.typ(input_type).scalar_type {
+                ReprScalarType::Range { element_type } => {
+                    let return_type = self.typ(input_type).scalar_type;
+                    let elem_type = SqlScalarType::from_repr(&element_type);
+                    let multirange = MirScalarExpr::call_variadic(
+                        MultiRangeCreate { elem_type },
+                        vec![self.expr.clone()],
+                    );
+                    self.expr
+                        .clone()
+                        .call_is_null()
+                        .if_then_else(MirScalarExpr::literal_null(return_type), multirange)
+                }
+                _ => self.expr.clone(),
+            },
+
+            // The array forms repeat the value once per fraction, or are null
+            // if the value is null.
+            AggregateFunc::PercentileCont { percentiles, .. }
+            | AggregateFunc::PercentileDisc { percentiles, .. } => {
+                let return_type = self.typ(input_type).scalar_type;
+                let elem_type = SqlScalarType::from_repr(&self.expr.typ(input_type).scalar_type);
+                let array = MirScalarExpr::call_variadic(
+                    ArrayCreate { elem_type },
+                    vec![self.expr.clone(); percentiles.fractions.len()],
+                );
+                self.expr
+                    .clone()
+                    .call_is_null()
+                    .if_then_else(MirScalarExpr::literal_null(return_type), array)
+            }
         }
     }
 
-    /// `on_unique` for ROW_NUMBER, RANK, DENSE_RANK
+    /// `on_unique` for ROW_NUMBER, RANK, DENSE_RANK, PERCENT_RANK, CUME_DIST, where `value` is
+    /// the result for the single row of the window partition
     fn on_unique_ranking_window_funcs(
         &self,
         input_type: &[ReprColumnType],
         col_name: &str,
+        value: MirScalarExpr,
     ) -> MirScalarExpr {
         let sql_input_type: Vec<SqlColumnType> =
             input_type.iter().map(SqlColumnType::from_repr).collect();
@@ -3100,10 +3285,7 @@ impl AggregateExpr {
                 RecordCreate {
                     field_names: vec![ColumnName::from(col_name), ColumnName::from("?record?")],
                 },
-                vec![
-                    MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
-                    record,
-                ],
+                vec![value, record],
             )],
         )
     }
@@ -3159,6 +3341,46 @@ impl AggregateExpr {
         (result_expr, ColumnName::from("?first_value?"))
     }
 
+    /// `on_unique` for `ntile`
+    fn on_unique_ntile(num_buckets: MirScalarExpr) -> (MirScalarExpr, ColumnName) {
+        // The single row is in the first bucket, unless the number of buckets is null or not
+        // positive, in which case the result is null.
+        let result_expr = num_buckets
+            .call_binary(
+                MirScalarExpr::literal_ok(Datum::Int32(0), ReprScalarType::Int32),
+                crate::func::Gt,
+            )
+            .if_then_else(
+                MirScalarExpr::literal_ok(Datum::Int32(1), ReprScalarType::Int32),
+                MirScalarExpr::literal_null(ReprScalarType::Int32),
+            );
+        (result_expr, ColumnName::from("?ntile?"))
+    }
+
+    /// `on_unique` for `nth_value`
+    fn on_unique_nth_value(
+        window_frame: &WindowFrame,
+        encoded_args: MirScalarExpr,
+        return_type: ReprScalarType,
+    ) -> (MirScalarExpr, ColumnName) {
+        // If the window frame includes the current (single) row, and we are asked for the first
+        // row of the frame, return its value, null otherwise
+        let result_expr = if window_frame.includes_current_row() {
+            let expr = encoded_args
+                .clone()
+                .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+            let n = encoded_args.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+            n.call_binary(
+                MirScalarExpr::literal_ok(Datum::Int32(1), ReprScalarType::Int32),
+                crate::func::Eq,
+            )
+            .if_then_else(expr, MirScalarExpr::literal_null(return_type))
+        } else {
+            MirScalarExpr::literal_null(return_type)
+        };
+        (result_expr, ColumnName::from("?nth_value?"))
+    }
+
     /// `on_unique` for window aggregations
     fn on_unique_window_agg(
         window_frame: &WindowFrame,
@@ -18,7 +18,7 @@ use std::{fmt, iter};
 use chrono::{DateTime, NaiveDateTime, NaiveTime, Utc};
 use dec::OrderedDecimal;
 use itertools::{Either, Itertools};
-use mz_ore::cast::CastFrom;
+use mz_ore::cast::{CastFrom, CastLossy};
 
 use mz_ore::str::separated;
 use mz_ore::{soft_assert_eq_no_log, soft_assert_or_log};
@@ -26,6 +26,7 @@ use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::date::Date;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
+use mz_repr::adt::range::multirange;
 use mz_repr::adt::regex::{Regex as ReprRegex, RegexCompilationError};
 use mz_repr::adt::timestamp::{CheckedTimestamp, TimestampLike};
 use mz_repr::{
@@ -44,6 +45,7 @@ use crate::WindowFrameBound::{
 };
 use crate::WindowFrameUnits::{Groups, Range, Rows};
 use crate::explain::{HumanizedExpr, HumanizerMode};
+use crate::hll;
 use crate::relation::{
     ColumnOrder, WindowFrame, WindowFrameBound, WindowFrameUnits, compare_columns,
 };
@@ -209,6 +211,206 @@ where
     Datum::from(count.into_inner())
 }
 
+/// Sorts the non-null `datums` of an ordered-set aggregate, pairing each
+/// distinct value with its positive multiplicity.
+///
+/// Returns the sorted values along with the total number of inputs.
+fn ordered_set_counts<'a, I>(datums: I, desc: bool) -> (Vec<(Datum<'a>, i64)>, i64)
+where
+    I: IntoIterator<Item = (Datum<'a>, Diff)>,
+{
+    let mut counts: Vec<_> = datums
+        .into_iter()
+        .filter(|(datum, diff)| !datum.is_null() && diff.is_positive())
+        .map(|(datum, diff)| (datum, diff.into_inner()))
+        .collect();
+    counts.sort_by(|(a, _), (b, _)| if desc { b.cmp(a) } else { a.cmp(b) });
+    counts.dedup_by(|(next, next_count), (prev, prev_count)| {
+        let dup = next == prev;
+        if dup {
+            *prev_count += *next_count;
+        }
+        dup
+    });
+    let total = counts.iter().map(|(_, count)| count).sum();
+    (counts, total)
+}
+
+/// Returns the value at the zero-based `position` of the sorted `counts`.
+fn ordered_set_nth<'a>(counts: &[(Datum<'a>, i64)], position: i64) -> Datum<'a> {
+    let mut seen = 0;
+    for (datum, count) in counts {
+        seen += count;
+        if position < seen {
+            return *datum;
+        }
+    }
+    unreachable!("position {position} out of range")
+}
+
+fn percentile_disc<'a, I>(
+    datums: I,
+    temp_storage: &'a RowArena,
+    percentiles: &Percentiles,
+    desc: bool,
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = (Datum<'a>, Diff)>,
+{
+    let (counts, total) = ordered_set_counts(datums, desc);
+    if total == 0 {
+        return Datum::Null;
+    }
+    percentiles.pack(temp_storage, |fraction| {
+        ordered_set_nth(&counts, Percentiles::disc_position(fraction, total))
+    })
+}
+
+fn percentile_cont<'a, I>(
+    datums: I,
+    temp_storage: &'a RowArena,
+    percentiles: &Percentiles,
+    desc: bool,
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = (Datum<'a>, Diff)>,
+{
+    let (counts, total) = ordered_set_counts(datums, desc);
+    if total == 0 {
+        return Datum::Null;
+    }
+    percentiles.pack(temp_storage, |fraction| {
+        let (lo_pos, hi_pos, weight) = Percentiles::cont_positions(fraction, total);
+        let lo = ordered_set_nth(&counts, lo_pos);
+        let hi = ordered_set_nth(&counts, hi_pos);
+        Percentiles::interpolate(lo, hi, weight)
+    })
+}
+
+fn mode<'a, I>(datums: I, desc: bool) -> Datum<'a>
+where
+    I: IntoIterator<Item = (Datum<'a>, Diff)>,
+{
+    let (counts, _) = ordered_set_counts(datums, desc);
+    // `max_by_key` returns the last maximum, so scan in reverse to prefer the
+    // first value in the sort order.
+    counts
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, count)| *count)
+        .map_or(Datum::Null, |(datum, _)| datum)
+}
+
+/// Builds the HyperLogLog registers of the non-null inputs, or `None` if
+/// there are none.
+fn hll_registers<'a, I>(datums: I) -> Option<Vec<u8>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut registers = None;
+    for datum in datums.into_iter().filter(|d| !d.is_null()) {
+        let registers = registers.get_or_insert_with(|| vec![0; hll::REGISTERS]);
+        let (register, rank) = hll::observe(datum);
+        registers[register] = max(registers[register], rank);
+    }
+    registers
+}
+
+fn approx_count_distinct<'a, I>(datums: I) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let estimate = hll_registers(datums).map_or(0, |registers| hll::estimate(&registers));
+    Datum::Int64(estimate)
+}
+
+fn hll_sketch_agg<'a, I>(datums: I, temp_storage: &'a RowArena) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    match hll_registers(datums) {
+        Some(registers) => Datum::Bytes(temp_storage.push_bytes(hll::encode(&registers))),
+        None => Datum::Null,
+    }
+}
+
+fn hll_union_agg<'a, I>(datums: I, temp_storage: &'a RowArena) -> Result<Datum<'a>, EvalError>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut registers: Option<Vec<u8>> = None;
+    for datum in datums.into_iter().filter(|d| !d.is_null()) {
+        let registers = registers.get_or_insert_with(|| vec![0; hll::REGISTERS]);
+        hll::union(registers, hll::decode(datum.unwrap_bytes())?);
+    }
+    Ok(match registers {
+        Some(registers) => Datum::Bytes(temp_storage.push_bytes(hll::encode(&registers))),
+        None => Datum::Null,
+    })
+}
+
+/// Returns the ranges of a `range` or multirange datum.
+fn datum_ranges<'a>(datum: Datum<'a>) -> Vec<mz_repr::adt::range::Range<Datum<'a>>> {
+    match datum {
+        Datum::Range(_) => vec![datum.unwrap_range()],
+        datum => datum.unwrap_multirange(),
+    }
+}
+
+fn range_agg<'a, I>(datums: I, temp_storage: &'a RowArena) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut ranges = None;
+    for datum in datums.into_iter().filter(|d| !d.is_null()) {
+        ranges
+            .get_or_insert_with(Vec::new)
+            .extend(datum_ranges(datum));
+    }
+    match ranges {
+        // The inputs are canonical ranges, so merging them cannot fail.
+        Some(ranges) => temp_storage.make_datum(|packer| {
+            packer
+                .push_multirange(ranges)
+                .expect("canonical ranges can be merged")
+        }),
+        None => Datum::Null,
+    }
+}
+
+fn range_intersect_agg<'a, I>(datums: I, temp_storage: &'a RowArena) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut datums = datums.into_iter().filter(|d| !d.is_null());
+    let Some(first) = datums.next() else {
+        return Datum::Null;
+    };
+    match first {
+        Datum::Range(_) => {
+            let range = datums.fold(first.unwrap_range(), |acc, d| {
+                acc.intersection(&d.unwrap_range())
+            });
+            temp_storage.make_datum(|packer| {
+                packer
+                    .push_range(range)
+                    .expect("intersections of canonical ranges are canonical")
+            })
+        }
+        first => {
+            let ranges = datums.fold(first.unwrap_multirange(), |acc, d| {
+                multirange::intersection(&acc, &d.unwrap_multirange())
+                    .expect("intersections of canonical ranges are canonical")
+            });
+            temp_storage.make_datum(|packer| {
+                packer
+                    .push_multirange(ranges)
+                    .expect("intersections of canonical ranges are canonical")
+            })
+        }
+    }
+}
+
 fn any<'a, I>(datums: I) -> Datum<'a>
 where
     I: IntoIterator<Item = Datum<'a>>,
@@ -625,6 +827,143 @@ where
     })
 }
 
+/// The expected input is in the format of `[((OriginalRow, [EncodedArgs]), OrderByExprs...)]`
+/// The output is in the format of `[result_value, original_row]`.
+/// See an example at `lag_lead`, where the input-output formats are similar.
+fn percent_rank<'a, I>(
+    datums: I,
+    callers_temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let temp_storage = RowArena::new();
+    let datums = percent_rank_no_list(datums, &temp_storage, order_by);
+
+    callers_temp_storage.make_datum(|packer| {
+        packer.push_list(datums);
+    })
+}
+
+/// Like `percent_rank`, but doesn't perform the final wrapping in a list, returning an Iterator
+/// instead.
+fn percent_rank_no_list<'a: 'b, 'b, I>(
+    datums: I,
+    callers_temp_storage: &'b RowArena,
+    order_by: &[ColumnOrder],
+) -> impl Iterator<Item = Datum<'b>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let datums = peer_group_positions(datums, order_by);
+    let length = datums.len();
+
+    callers_temp_storage.reserve(length);
+    datums
+        .into_iter()
+        .map(move |(d, peer_group_start, _peer_group_end)| {
+            // (rank - 1) / (partition size - 1), where the rank is the position of the first peer
+            let percent_rank = if length > 1 {
+                f64::cast_lossy(peer_group_start) / f64::cast_lossy(length - 1)
+            } else {
+                0.0
+            };
+            callers_temp_storage.make_datum(|packer| {
+                packer.push_list_with(|packer| {
+                    packer.push(Datum::Float64(percent_rank.into()));
+                    packer.push(d);
+                });
+            })
+        })
+}
+
+/// The expected input is in the format of `[((OriginalRow, [EncodedArgs]), OrderByExprs...)]`
+/// The output is in the format of `[result_value, original_row]`.
+/// See an example at `lag_lead`, where the input-output formats are similar.
+fn cume_dist<'a, I>(
+    datums: I,
+    callers_temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let temp_storage = RowArena::new();
+    let datums = cume_dist_no_list(datums, &temp_storage, order_by);
+
+    callers_temp_storage.make_datum(|packer| {
+        packer.push_list(datums);
+    })
+}
+
+/// Like `cume_dist`, but doesn't perform the final wrapping in a list, returning an Iterator
+/// instead.
+fn cume_dist_no_list<'a: 'b, 'b, I>(
+    datums: I,
+    callers_temp_storage: &'b RowArena,
+    order_by: &[ColumnOrder],
+) -> impl Iterator<Item = Datum<'b>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let datums = peer_group_positions(datums, order_by);
+    let length = datums.len();
+
+    callers_temp_storage.reserve(length);
+    datums
+        .into_iter()
+        .map(move |(d, _peer_group_start, peer_group_end)| {
+            // The fraction of rows that precede or are peers of the current row
+            let cume_dist = f64::cast_lossy(peer_group_end + 1) / f64::cast_lossy(length);
+            callers_temp_storage.make_datum(|packer| {
+                packer.push_list_with(|packer| {
+                    packer.push(Datum::Float64(cume_dist.into()));
+                    packer.push(d);
+                });
+            })
+        })
+}
+
+/// Sorts the inputs of a ranking window function, and returns each original row together with
+/// the positions of the first and last rows (inclusive) of its peer group, i.e., the rows that
+/// have the same ORDER BY values.
+fn peer_group_positions<'a, I>(
+    datums: I,
+    order_by: &[ColumnOrder],
+) -> Vec<(Datum<'a>, usize, usize)>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Keep the row used for ordering around, as it is used to determine the peer groups
+    let datums = order_aggregate_datums_with_rank(datums, order_by)
+        .map(|(d0, order_row)| {
+            d0.unwrap_list()
+                .iter()
+                .map(move |d1| (d1, order_row.clone()))
+        })
+        .flatten()
+        .collect_vec();
+
+    let mut result = Vec::with_capacity(datums.len());
+    let mut peer_group_start = 0;
+    while peer_group_start < datums.len() {
+        let peer_group_order_row = &datums[peer_group_start].1;
+        let peer_group_len = datums[peer_group_start..]
+            .iter()
+            .take_while(|(_, order_row)| order_row == peer_group_order_row)
+            .count();
+        let peer_group_end = peer_group_start + peer_group_len - 1;
+        result.extend(
+            datums[peer_group_start..=peer_group_end]
+                .iter()
+                .map(|(d, _)| (*d, peer_group_start, peer_group_end)),
+        );
+        peer_group_start = peer_group_end + 1;
+    }
+    result
+}
+
 /// The expected input is in the format of `[((OriginalRow, EncodedArgs), OrderByExprs...)]`
 /// For example,
 ///
@@ -892,6 +1231,96 @@ fn lag_lead_inner_ignore_nulls<'a>(
     result
 }
 
+/// The expected input is in the format of [((OriginalRow, NumBuckets), OrderByExprs...)]
+fn ntile<'a, I>(
+    datums: I,
+    callers_temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let temp_storage = RowArena::new();
+    let iter = ntile_no_list(datums, &temp_storage, order_by);
+    callers_temp_storage.make_datum(|packer| {
+        packer.push_list(iter);
+    })
+}
+
+/// Like `ntile`, but doesn't perform the final wrapping in a list, returning an Iterator
+/// instead.
+fn ntile_no_list<'a: 'b, 'b, I>(
+    datums: I,
+    callers_temp_storage: &'b RowArena,
+    order_by: &[ColumnOrder],
+) -> impl Iterator<Item = Datum<'b>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Sort the datums according to the ORDER BY expressions and return the (OriginalRow, NumBuckets) record
+    let datums = order_aggregate_datums(datums, order_by);
+
+    // Decode the input (OriginalRow, NumBuckets) into separate datums
+    let (orig_rows, args): (Vec<_>, Vec<_>) = datums
+        .into_iter()
+        .map(|d| {
+            let mut iter = d.unwrap_list().iter();
+            let original_row = iter.next().unwrap();
+            let arg = iter.next().unwrap();
+
+            (original_row, arg)
+        })
+        .unzip();
+
+    let results = ntile_inner(args);
+
+    callers_temp_storage.reserve(results.len());
+    results
+        .into_iter()
+        .zip_eq(orig_rows)
+        .map(|(result_value, original_row)| {
+            callers_temp_storage.make_datum(|packer| {
+                packer.push_list_with(|packer| {
+                    packer.push(result_value);
+                    packer.push(original_row);
+                });
+            })
+        })
+}
+
+/// Divides the sorted window partition into as equal buckets as possible, numbered from 1. As in
+/// Postgres, the number of buckets is taken from the first row, and the first
+/// `length % num_buckets` buckets hold one extra row. The results are null if the number of
+/// buckets is null or not positive.
+fn ntile_inner<'a>(args: Vec<Datum<'a>>) -> Vec<Datum<'a>> {
+    let length = args.len();
+    let num_buckets = match args.first() {
+        None | Some(Datum::Null) => None,
+        Some(num_buckets) => usize::try_from(num_buckets.unwrap_int32())
+            .ok()
+            .filter(|n| *n > 0),
+    };
+    let Some(num_buckets) = num_buckets else {
+        return vec![Datum::Null; length];
+    };
+
+    let bucket_size = length / num_buckets;
+    let num_large_buckets = length % num_buckets;
+    // The rows before this position are in the buckets that hold one extra row
+    let large_buckets_end = num_large_buckets * (bucket_size + 1);
+    (0..length)
+        .map(|idx| {
+            let bucket = if idx < large_buckets_end {
+                idx / (bucket_size + 1)
+            } else {
+                num_large_buckets + (idx - large_buckets_end) / bucket_size
+            };
+            // The bucket number is at most `num_buckets`, which came from an `i32`.
+            Datum::Int32(i32::try_from(bucket + 1).expect("bucket number fits in i32"))
+        })
+        .collect()
+}
+
 /// The expected input is in the format of [((OriginalRow, InputValue), OrderByExprs...)]
 fn first_value<'a, I>(
     datums: I,
@@ -1126,49 +1555,197 @@ fn last_value_inner<'a>(
                     let start_offset = usize::cast_from(*start_offset);
                     let start_idx = idx.saturating_add(start_offset);
 
-                    // If the frame is empty or starts after the last row of the window, return null
-                    if end_offset < start_offset || start_idx >= length {
-                        Datum::Null
-                    } else {
-                        // Return the last valid element in the window
-                        args.get(end_idx).unwrap_or(&args[length - 1]).clone()
-                    }
-                } else {
-                    args.get(end_idx).unwrap_or(&args[length - 1]).clone()
-                }
-            }
-            WindowFrameBound::OffsetPreceding(offset) => {
-                let end_offset = usize::cast_from(*offset);
-                let end_idx = idx.saturating_sub(end_offset);
-                if idx < end_offset {
-                    // If the frame ends before the first row, return null
-                    Datum::Null
-                } else if let WindowFrameBound::OffsetPreceding(start_offset) =
-                    &window_frame.start_bound
-                {
-                    // If the frame is empty, return null
-                    if offset > start_offset {
-                        Datum::Null
-                    } else {
-                        args[end_idx]
-                    }
-                } else {
-                    args[end_idx]
-                }
-            }
-            // Forbidden during planning
-            WindowFrameBound::UnboundedPreceding => unreachable!(),
+                    // If the frame is empty or starts after the last row of the window, return null
+                    if end_offset < start_offset || start_idx >= length {
+                        Datum::Null
+                    } else {
+                        // Return the last valid element in the window
+                        args.get(end_idx).unwrap_or(&args[length - 1]).clone()
+                    }
+                } else {
+                    args.get(end_idx).unwrap_or(&args[length - 1]).clone()
+                }
+            }
+            WindowFrameBound::OffsetPreceding(offset) => {
+                let end_offset = usize::cast_from(*offset);
+                let end_idx = idx.saturating_sub(end_offset);
+                if idx < end_offset {
+                    // If the frame ends before the first row, return null
+                    Datum::Null
+                } else if let WindowFrameBound::OffsetPreceding(start_offset) =
+                    &window_frame.start_bound
+                {
+                    // If the frame is empty, return null
+                    if offset > start_offset {
+                        Datum::Null
+                    } else {
+                        args[end_idx]
+                    }
+                } else {
+                    args[end_idx]
+                }
+            }
+            // Forbidden during planning
+            WindowFrameBound::UnboundedPreceding => unreachable!(),
+        };
+        results.push(last_value);
+    }
+    results
+}
+
+/// The expected input is in the format of [((OriginalRow, (InputValue, N)), OrderByExprs...)]
+fn nth_value<'a, I>(
+    datums: I,
+    callers_temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+    window_frame: &WindowFrame,
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let temp_storage = RowArena::new();
+    let iter = nth_value_no_list(datums, &temp_storage, order_by, window_frame);
+    callers_temp_storage.make_datum(|packer| {
+        packer.push_list(iter);
+    })
+}
+
+/// Like `nth_value`, but doesn't perform the final wrapping in a list, returning an Iterator
+/// instead.
+fn nth_value_no_list<'a: 'b, 'b, I>(
+    datums: I,
+    callers_temp_storage: &'b RowArena,
+    order_by: &[ColumnOrder],
+    window_frame: &WindowFrame,
+) -> impl Iterator<Item = Datum<'b>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Sort the datums according to the ORDER BY expressions and return the ((OriginalRow, EncodedArgs), OrderByRow) record
+    // The OrderByRow is kept around because it is required to compute the peer groups in RANGE mode
+    let datums = order_aggregate_datums_with_rank(datums, order_by);
+
+    let size_hint = datums.size_hint().0;
+    let mut args = Vec::with_capacity(size_hint);
+    let mut original_rows = Vec::with_capacity(size_hint);
+    let mut order_by_rows = Vec::with_capacity(size_hint);
+    for (d, order_by_row) in datums.into_iter() {
+        let mut iter = d.unwrap_list().iter();
+        let original_row = iter.next().unwrap();
+        let encoded_args = iter.next().unwrap();
+        order_by_rows.push(order_by_row);
+        original_rows.push(original_row);
+        args.push(unwrap_nth_value_encoded_args(encoded_args));
+    }
+
+    let results = nth_value_inner(args, &order_by_rows, window_frame);
+
+    callers_temp_storage.reserve(results.len());
+    results
+        .into_iter()
+        .zip_eq(original_rows)
+        .map(|(result_value, original_row)| {
+            callers_temp_storage.make_datum(|packer| {
+                packer.push_list_with(|packer| {
+                    packer.push(result_value);
+                    packer.push(original_row);
+                });
+            })
+        })
+}
+
+/// nth_value's arguments are in a record. This function unwraps this record.
+fn unwrap_nth_value_encoded_args(encoded_args: Datum) -> (Datum, Datum) {
+    let mut encoded_args_iter = encoded_args.unwrap_list().iter();
+    let (input_value, n) = (
+        encoded_args_iter.next().unwrap(),
+        encoded_args_iter.next().unwrap(),
+    );
+    (input_value, n)
+}
+
+/// Each element of `args` has the input value and `n` evaluated for a single input row.
+/// Returns the value of the `n`th row of each row's window frame, or null if the frame has fewer
+/// than `n` rows or `n` is null or not positive.
+fn nth_value_inner<'a>(
+    args: Vec<(Datum<'a>, Datum<'a>)>,
+    order_by_rows: &[Row],
+    window_frame: &WindowFrame,
+) -> Vec<Datum<'a>> {
+    let length = args.len();
+    let mut results: Vec<Datum> = Vec::with_capacity(length);
+    for (idx, (_, n)) in args.iter().enumerate() {
+        let n = match n {
+            Datum::Null => None,
+            n => usize::try_from(n.unwrap_int32()).ok().filter(|n| *n > 0),
         };
-        results.push(last_value);
+        let nth_value = n
+            .zip(window_frame_bounds(
+                idx,
+                length,
+                order_by_rows,
+                window_frame,
+            ))
+            .and_then(|(n, (start_idx, end_idx))| {
+                let target_idx = start_idx.checked_add(n - 1)?;
+                (target_idx <= end_idx).then(|| args[target_idx].0)
+            })
+            .unwrap_or(Datum::Null);
+        results.push(nth_value);
     }
     results
 }
 
+/// Computes the first and last positions (inclusive) of the window frame of the row at `idx` in
+/// a sorted window partition of `length` rows, or `None` if the frame is empty.
+///
+/// `order_by_rows` is only consulted when the frame ends at the current row in RANGE mode, in
+/// which case the frame extends to the last peer of the current row. (RANGE is only supported
+/// for the default window frame, which is why it does not appear in the other branches.)
+fn window_frame_bounds(
+    idx: usize,
+    length: usize,
+    order_by_rows: &[Row],
+    window_frame: &WindowFrame,
+) -> Option<(usize, usize)> {
+    let start_idx = match &window_frame.start_bound {
+        WindowFrameBound::UnboundedPreceding => 0,
+        WindowFrameBound::OffsetPreceding(offset) => idx.saturating_sub(usize::cast_from(*offset)),
+        WindowFrameBound::CurrentRow => idx,
+        WindowFrameBound::OffsetFollowing(offset) => idx.saturating_add(usize::cast_from(*offset)),
+        // Forbidden during planning
+        WindowFrameBound::UnboundedFollowing => unreachable!(),
+    };
+    let end_idx = match &window_frame.end_bound {
+        WindowFrameBound::UnboundedFollowing => length - 1,
+        WindowFrameBound::OffsetFollowing(offset) => {
+            min(idx.saturating_add(usize::cast_from(*offset)), length - 1)
+        }
+        WindowFrameBound::CurrentRow => match &window_frame.units {
+            WindowFrameUnits::Rows => idx,
+            WindowFrameUnits::Range => {
+                let peers = order_by_rows[idx..]
+                    .iter()
+                    .take_while(|row| **row == order_by_rows[idx])
+                    .count();
+                idx + peers - 1
+            }
+            // GROUPS is not supported, and forbidden during planning
+            WindowFrameUnits::Groups => unreachable!(),
+        },
+        // If the frame ends before the first row, it is empty
+        WindowFrameBound::OffsetPreceding(offset) => idx.checked_sub(usize::cast_from(*offset))?,
+        // Forbidden during planning
+        WindowFrameBound::UnboundedPreceding => unreachable!(),
+    };
+    (start_idx <= end_idx).then_some((start_idx, end_idx))
+}
+
 /// Executes `FusedValueWindowFunc` on a reduction group.
 /// The expected input is in the format of `[((OriginalRow, (Args1, Args2, ...)), OrderByExprs...)]`
 /// where `Args1`, `Args2`, are the arguments of each of the fused functions. For functions that
-/// have only a single argument (first_value/last_value), these are simple values. For functions
-/// that have multiple arguments (lag/lead), these are also records.
+/// have only a single argument (first_value/last_value/ntile), these are simple values. For
+/// functions that have multiple arguments (lag/lead/nth_value), these are also records.
 fn fused_value_window_func<'a, I>(
     input_datums: I,
     callers_temp_storage: &'a RowArena,
@@ -1196,9 +1773,12 @@ fn fused_value_window_func_no_list<'a: 'b, 'b, I>(
 where
     I: IntoIterator<Item = Datum<'a>>,
 {
-    let has_last_value = funcs
-        .iter()
-        .any(|f| matches!(f, AggregateFunc::LastValue { .. }));
+    let needs_order_by_rows = funcs.iter().any(|f| {
+        matches!(
+            f,
+            AggregateFunc::LastValue { .. } | AggregateFunc::NthValue { .. }
+        )
+    });
 
     let input_datums_with_ranks = order_aggregate_datums_with_rank(input_datums, order_by);
 
@@ -1215,7 +1795,7 @@ where
             let encoded_args = argss_iter.next().unwrap();
             encoded_argsss[i].push(encoded_args);
         }
-        if has_last_value {
+        if needs_order_by_rows {
             order_by_rows.push(order_by_row);
         }
     }
@@ -1253,6 +1833,25 @@ where
                 // wrapped into a record.)
                 last_value_inner(encoded_argss, &order_by_rows, window_frame)
             }
+            AggregateFunc::Ntile {
+                order_by: inner_order_by,
+            } => {
+                assert_eq!(order_by, inner_order_by);
+                // (No unwrapping to do on the args here, because there is only 1 arg, so it's not
+                // wrapped into a record.)
+                ntile_inner(encoded_argss)
+            }
+            AggregateFunc::NthValue {
+                order_by: inner_order_by,
+                window_frame,
+            } => {
+                assert_eq!(order_by, inner_order_by);
+                let unwrapped_argss = encoded_argss
+                    .into_iter()
+                    .map(|encoded_args| unwrap_nth_value_encoded_args(encoded_args))
+                    .collect();
+                nth_value_inner(unwrapped_argss, &order_by_rows, window_frame)
+            }
             _ => panic!("unknown window function in FusedValueWindowFunc"),
         };
         for (results, result) in results_per_row.iter_mut().zip_eq(results) {
@@ -1861,6 +2460,92 @@ pub enum LagLeadType {
     Lead,
 }
 
+/// The fractions requested from a `percentile_cont` or `percentile_disc`
+/// aggregation, which are constant for the whole aggregation.
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Hash
+)]
+pub struct Percentiles {
+    /// The requested fractions, each between 0 and 1.
+    pub fractions: Vec<OrderedFloat<f64>>,
+    /// Whether the aggregation returns an array with one element per fraction
+    /// rather than a single value.
+    pub array: bool,
+}
+
+impl Percentiles {
+    /// The zero-based position in the sort order of `total` values that
+    /// `percentile_disc` returns for `fraction`: the first value whose
+    /// cumulative distribution reaches `fraction`.
+    pub fn disc_position(fraction: f64, total: i64) -> i64 {
+        #[allow(clippy::as_conversions)]
+        let position = (fraction * total as f64).ceil() as i64;
+        (position - 1).clamp(0, total - 1)
+    }
+
+    /// The zero-based positions in the sort order of `total` values that
+    /// `percentile_cont` interpolates between for `fraction`, along with the
+    /// weight of the upper one.
+    pub fn cont_positions(fraction: f64, total: i64) -> (i64, i64, f64) {
+        #[allow(clippy::as_conversions)]
+        let position = fraction * (total - 1) as f64;
+        #[allow(clippy::as_conversions)]
+        let (lo, hi) = (position.floor() as i64, position.ceil() as i64);
+        #[allow(clippy::as_conversions)]
+        let weight = position - lo as f64;
+        (lo, hi, weight)
+    }
+
+    /// Interpolates between the `float8` or `interval` values `lo` and `hi`
+    /// of a `percentile_cont` aggregation.
+    pub fn interpolate<'a>(lo: Datum<'a>, hi: Datum<'a>, weight: f64) -> Datum<'a> {
+        match (lo, hi) {
+            (Datum::Float64(lo), Datum::Float64(hi)) => Datum::from(*lo + (*hi - *lo) * weight),
+            (Datum::Interval(lo), Datum::Interval(hi)) => {
+                // Interpolating between two in-range intervals can only
+                // overflow in intermediate products, in which case the lower
+                // value is the closest representable answer.
+                let interpolated = lo
+                    .checked_mul(1.0 - weight)
+                    .and_then(|lo| hi.checked_mul(weight).and_then(|hi| lo.checked_add(&hi)));
+                Datum::Interval(interpolated.unwrap_or(lo))
+            }
+            (lo, hi) => panic!("unexpected percentile_cont inputs: {lo:?}, {hi:?}"),
+        }
+    }
+
+    /// Packs the result of a percentile aggregation, which is either a single
+    /// value or an array with one value per fraction.
+    pub fn pack<'a, F>(&self, temp_storage: &'a RowArena, mut value_at: F) -> Datum<'a>
+    where
+        F: FnMut(f64) -> Datum<'a>,
+    {
+        if !self.array {
+            return value_at(self.fractions[0].into_inner());
+        }
+        let datums: Vec<_> = self
+            .fractions
+            .iter()
+            .map(|fraction| value_at(fraction.into_inner()))
+            .collect();
+        let dims = ArrayDimension {
+            lower_bound: 1,
+            length: datums.len(),
+        };
+        temp_storage.make_datum(|packer| {
+            packer.try_push_array(&[dims], datums).unwrap();
+        })
+    }
+}
+
 #[derive(
     Clone,
     Debug,
@@ -1966,6 +2651,15 @@ pub enum AggregateFunc {
     DenseRank {
         order_by: Vec<ColumnOrder>,
     },
+    PercentRank {
+        order_by: Vec<ColumnOrder>,
+    },
+    CumeDist {
+        order_by: Vec<ColumnOrder>,
+    },
+    Ntile {
+        order_by: Vec<ColumnOrder>,
+    },
     LagLead {
         order_by: Vec<ColumnOrder>,
         lag_lead: LagLeadType,
@@ -1979,6 +2673,10 @@ pub enum AggregateFunc {
         order_by: Vec<ColumnOrder>,
         window_frame: WindowFrame,
     },
+    NthValue {
+        order_by: Vec<ColumnOrder>,
+        window_frame: WindowFrame,
+    },
     /// Several value window functions fused into one function, to amortize overheads.
     FusedValueWindowFunc {
         funcs: Vec<AggregateFunc>,
@@ -1996,6 +2694,35 @@ pub enum AggregateFunc {
         order_by: Vec<ColumnOrder>,
         window_frame: WindowFrame,
     },
+    /// The continuous percentiles of the non-null inputs, interpolating between
+    /// adjacent values. The inputs are `float8` or `interval` values, sorted in
+    /// descending order if `desc` is set.
+    PercentileCont {
+        percentiles: Percentiles,
+        desc: bool,
+    },
+    /// The discrete percentiles of the non-null inputs: the first input whose
+    /// position in the sort order is at or past each fraction.
+    PercentileDisc {
+        percentiles: Percentiles,
+        desc: bool,
+    },
+    /// The most frequent non-null input. Ties are broken by the sort order.
+    Mode {
+        desc: bool,
+    },
+    /// Approximates the number of distinct non-null inputs with a HyperLogLog
+    /// sketch. See [`crate::hll`] for the error bounds.
+    ApproxCountDistinct,
+    /// Summarizes the non-null inputs as a serialized HyperLogLog sketch.
+    HllSketchAgg,
+    /// Unions serialized HyperLogLog sketches into a single sketch.
+    HllUnionAgg,
+    /// The union of the non-null `range` or multirange inputs, as a multirange.
+    RangeAgg,
+    /// The intersection of the non-null `range` or multirange inputs, of the
+    /// same type as the inputs.
+    RangeIntersectAgg,
     /// Accumulates any number of `Datum::Dummy`s into `Datum::Dummy`.
     ///
     /// Useful for removing an expensive aggregation while maintaining the shape
@@ -2065,6 +2792,11 @@ impl AggregateFunc {
                 | MinTime
                 | Any
                 | All
+                | ApproxCountDistinct
+                | HllSketchAgg
+                | HllUnionAgg
+                | RangeAgg
+                | RangeIntersectAgg
         )
     }
 
@@ -2098,6 +2830,15 @@ impl AggregateFunc {
                 })
             }
             AggregateFunc::SumInt64 => sum_signed_int_counted(datums, Datum::from),
+            // Ordered-set aggregates only need the position of each distinct
+            // value in the sort order, which the diffs give directly.
+            AggregateFunc::PercentileCont { percentiles, desc } => {
+                percentile_cont(datums, temp_storage, percentiles, *desc)
+            }
+            AggregateFunc::PercentileDisc { percentiles, desc } => {
+                percentile_disc(datums, temp_storage, percentiles, *desc)
+            }
+            AggregateFunc::Mode { desc } => mode(datums, *desc),
             _ if self.ignores_multiplicity() => {
                 self.eval_datums(datums.into_iter().map(|(datum, _diff)| datum), temp_storage)
             }
@@ -2105,6 +2846,25 @@ impl AggregateFunc {
         }
     }
 
+    /// Evaluates the aggregate like [`AggregateFunc::eval`], but reports the
+    /// errors that the dataflow reports for invalid inputs, like `bytea` values
+    /// that are not HyperLogLog sketches.
+    pub fn try_eval<'a, I>(
+        &self,
+        datums: I,
+        temp_storage: &'a RowArena,
+    ) -> Result<Datum<'a>, EvalError>
+    where
+        I: IntoIterator<Item = (Datum<'a>, Diff)>,
+    {
+        match self {
+            AggregateFunc::HllUnionAgg => {
+                hll_union_agg(datums.into_iter().map(|(datum, _diff)| datum), temp_storage)
+            }
+            _ => Ok(self.eval(datums, temp_storage)),
+        }
+    }
+
     /// Evaluates the aggregate over a flat iterator of datums, ignoring multiplicity.
     fn eval_datums<'a, I>(&self, datums: I, temp_storage: &'a RowArena) -> Datum<'a>
     where
@@ -2167,8 +2927,22 @@ impl AggregateFunc {
             AggregateFunc::SumFloat64 => sum_datum::<'a, I, f64, f64>(datums),
             AggregateFunc::SumNumeric => sum_numeric(datums),
             AggregateFunc::Count => unreachable!("Count is handled in `eval`"),
+            AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. } => {
+                unreachable!("ordered-set aggregates are handled in `eval`")
+            }
             AggregateFunc::Any => any(datums),
             AggregateFunc::All => all(datums),
+            AggregateFunc::ApproxCountDistinct => approx_count_distinct(datums),
+            AggregateFunc::HllSketchAgg => hll_sketch_agg(datums, temp_storage),
+            // Callers that can report errors use `try_eval`, which reports
+            // invalid sketches.
+            AggregateFunc::HllUnionAgg => {
+                hll_union_agg(datums, temp_storage).unwrap_or(Datum::Null)
+            }
+            AggregateFunc::RangeAgg => range_agg(datums, temp_storage),
+            AggregateFunc::RangeIntersectAgg => range_intersect_agg(datums, temp_storage),
             AggregateFunc::JsonbAgg { order_by } => jsonb_agg(datums, temp_storage, order_by),
             AggregateFunc::MapAgg { order_by, .. } | AggregateFunc::JsonbObjectAgg { order_by } => {
                 dict_agg(datums, temp_storage, order_by)
@@ -2179,6 +2953,9 @@ impl AggregateFunc {
             AggregateFunc::RowNumber { order_by } => row_number(datums, temp_storage, order_by),
             AggregateFunc::Rank { order_by } => rank(datums, temp_storage, order_by),
             AggregateFunc::DenseRank { order_by } => dense_rank(datums, temp_storage, order_by),
+            AggregateFunc::PercentRank { order_by } => percent_rank(datums, temp_storage, order_by),
+            AggregateFunc::CumeDist { order_by } => cume_dist(datums, temp_storage, order_by),
+            AggregateFunc::Ntile { order_by } => ntile(datums, temp_storage, order_by),
             AggregateFunc::LagLead {
                 order_by,
                 lag_lead: lag_lead_type,
@@ -2192,6 +2969,10 @@ impl AggregateFunc {
                 order_by,
                 window_frame,
             } => last_value(datums, temp_storage, order_by, window_frame),
+            AggregateFunc::NthValue {
+                order_by,
+                window_frame,
+            } => nth_value(datums, temp_storage, order_by, window_frame),
             AggregateFunc::WindowAggregate {
                 wrapped_aggregate,
                 order_by,
@@ -2283,6 +3064,15 @@ impl AggregateFunc {
             AggregateFunc::DenseRank { order_by } => {
                 dense_rank_no_list(datums, temp_storage, order_by).collect_vec()
             }
+            AggregateFunc::PercentRank { order_by } => {
+                percent_rank_no_list(datums, temp_storage, order_by).collect_vec()
+            }
+            AggregateFunc::CumeDist { order_by } => {
+                cume_dist_no_list(datums, temp_storage, order_by).collect_vec()
+            }
+            AggregateFunc::Ntile { order_by } => {
+                ntile_no_list(datums, temp_storage, order_by).collect_vec()
+            }
             AggregateFunc::LagLead {
                 order_by,
                 lag_lead: lag_lead_type,
@@ -2297,6 +3087,10 @@ impl AggregateFunc {
                 order_by,
                 window_frame,
             } => last_value_no_list(datums, temp_storage, order_by, window_frame).collect_vec(),
+            AggregateFunc::NthValue {
+                order_by,
+                window_frame,
+            } => nth_value_no_list(datums, temp_storage, order_by, window_frame).collect_vec(),
             AggregateFunc::FusedValueWindowFunc { funcs, order_by } => {
                 fused_value_window_func_no_list(datums, temp_storage, funcs, order_by).collect_vec()
             }
@@ -2333,7 +3127,7 @@ impl AggregateFunc {
     /// input relation.
     pub fn default(&self) -> Datum<'static> {
         match self {
-            AggregateFunc::Count => Datum::Int64(0),
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct => Datum::Int64(0),
             AggregateFunc::Any => Datum::False,
             AggregateFunc::All => Datum::True,
             AggregateFunc::Dummy => Datum::Dummy,
@@ -2353,9 +3147,13 @@ impl AggregateFunc {
             AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::FusedWindowAggregate { .. } => Datum::empty_list(),
@@ -2406,7 +3204,15 @@ impl AggregateFunc {
             | AggregateFunc::JsonbAgg { .. }
             | AggregateFunc::JsonbObjectAgg { .. }
             | AggregateFunc::MapAgg { .. }
-            | AggregateFunc::StringAgg { .. } => Datum::Null,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::ApproxCountDistinct
+            | AggregateFunc::HllSketchAgg
+            | AggregateFunc::HllUnionAgg
+            | AggregateFunc::RangeAgg
+            | AggregateFunc::RangeIntersectAgg => Datum::Null,
         }
     }
 
@@ -2415,9 +3221,13 @@ impl AggregateFunc {
             AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::FusedWindowAggregate { .. } => true,
@@ -2473,7 +3283,15 @@ impl AggregateFunc {
             | AggregateFunc::JsonbAgg { .. }
             | AggregateFunc::JsonbObjectAgg { .. }
             | AggregateFunc::MapAgg { .. }
-            | AggregateFunc::StringAgg { .. } => false,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::ApproxCountDistinct
+            | AggregateFunc::HllSketchAgg
+            | AggregateFunc::HllUnionAgg
+            | AggregateFunc::RangeAgg
+            | AggregateFunc::RangeIntersectAgg => false,
         }
     }
 
@@ -2511,14 +3329,68 @@ impl AggregateFunc {
                 }
             }
             AggregateFunc::StringAgg { .. } => SqlScalarType::String,
-            AggregateFunc::RowNumber { .. } => {
-                AggregateFunc::output_type_ranking_window_funcs(&input_type, "?row_number?")
-            }
-            AggregateFunc::Rank { .. } => {
-                AggregateFunc::output_type_ranking_window_funcs(&input_type, "?rank?")
+            AggregateFunc::PercentileCont { percentiles, .. }
+            | AggregateFunc::PercentileDisc { percentiles, .. } => {
+                if percentiles.array {
+                    SqlScalarType::Array(Box::new(input_type.scalar_type.clone()))
+                } else {
+                    input_type.scalar_type.clone()
+                }
             }
-            AggregateFunc::DenseRank { .. } => {
-                AggregateFunc::output_type_ranking_window_funcs(&input_type, "?dense_rank?")
+            AggregateFunc::Mode { .. } => input_type.scalar_type.clone(),
+            AggregateFunc::ApproxCountDistinct => SqlScalarType::Int64,
+            AggregateFunc::HllSketchAgg | AggregateFunc::HllUnionAgg => SqlScalarType::Bytes,
+            AggregateFunc::RangeAgg => match &input_type.scalar_type {
+                SqlScalarType::Range { element_type }
+                | SqlScalarType::MultiRange { element_type } => SqlScalarType::MultiRange {
+                    element_type: element_type.clone(),
+                },
+                _ => unreachable!("range_agg takes ranges or multiranges"),
+            },
+            AggregateFunc::RangeIntersectAgg => input_type.scalar_type.clone(),
+            AggregateFunc::RowNumber { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?row_number?",
+                SqlScalarType::Int64,
+            ),
+            AggregateFunc::Rank { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?rank?",
+                SqlScalarType::Int64,
+            ),
+            AggregateFunc::DenseRank { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?dense_rank?",
+                SqlScalarType::Int64,
+            ),
+            AggregateFunc::PercentRank { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?percent_rank?",
+                SqlScalarType::Float64,
+            ),
+            AggregateFunc::CumeDist { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?cume_dist?",
+                SqlScalarType::Float64,
+            ),
+            AggregateFunc::Ntile { .. } => {
+                // The input type for Ntile is ((OriginalRow, NumBuckets), OrderByExprs...)
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let original_row_type = fields[0].unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(false);
+
+                SqlScalarType::List {
+                    element_type: Box::new(SqlScalarType::Record {
+                        fields: [
+                            // null when the number of buckets is null or not positive
+                            (ColumnName::from("?ntile?"), SqlScalarType::Int32.nullable(true)),
+                            (ColumnName::from("?orig_row?"), original_row_type),
+                        ].into(),
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
             }
             AggregateFunc::LagLead { lag_lead: lag_lead_type, .. } => {
                 // The input type for Lag is ((OriginalRow, EncodedArgs), OrderByExprs...)
@@ -2584,6 +3456,26 @@ impl AggregateFunc {
                     custom_id: None,
                 }
             }
+            AggregateFunc::NthValue { .. } => {
+                // The input type for NthValue is ((OriginalRow, (Arg, N)), OrderByExprs...)
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let original_row_type = fields[0].unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(false);
+                let encoded_args = fields[0].unwrap_record_element_type()[1];
+                let value_type = Self::nth_value_output_type_inner_from_encoded_args(encoded_args);
+
+                SqlScalarType::List {
+                    element_type: Box::new(SqlScalarType::Record {
+                        fields: [
+                            (ColumnName::from("?nth_value?"), value_type),
+                            (ColumnName::from("?orig_row?"), original_row_type),
+                        ].into(),
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
+            }
             AggregateFunc::WindowAggregate {
                 wrapped_aggregate, ..
             } => {
@@ -2644,8 +3536,8 @@ impl AggregateFunc {
             AggregateFunc::FusedValueWindowFunc { funcs, order_by: _ } => {
                 // The input type is ((OriginalRow, EncodedArgs), OrderByExprs...)
                 // where EncodedArgs is a record, where each element is the argument to one of the
-                // function calls that got fused. This is a record for lag/lead, and a simple type
-                // for first_value/last_value.
+                // function calls that got fused. This is a record for lag/lead/nth_value, and a
+                // simple type for first_value/last_value/ntile.
                 let fields = input_type.scalar_type.unwrap_record_element_type();
                 let original_row_type = fields[0].unwrap_record_element_type()[0]
                     .clone()
@@ -2687,6 +3579,19 @@ impl AggregateFunc {
                                                 arg_type.clone().nullable(true),
                                             )
                                         }
+                                        AggregateFunc::Ntile { .. } => {
+                                            (
+                                                ColumnName::from("?ntile?"),
+                                                SqlScalarType::Int32.nullable(true),
+                                            )
+                                        }
+                                        AggregateFunc::NthValue { .. } => {
+                                            let ty = Self
+                                                ::nth_value_output_type_inner_from_encoded_args(
+                                                    arg_type,
+                                                );
+                                            (ColumnName::from("?nth_value?"), ty)
+                                        }
                                         _ => panic!("FusedValueWindowFunc has an unknown function"),
                                     }
                                 }).collect(),
@@ -2744,7 +3649,7 @@ impl AggregateFunc {
         // Count never produces null, and other aggregations only produce
         // null in the presence of null inputs.
         let nullable = match self {
-            AggregateFunc::Count => false,
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct => false,
             // Use the nullability of the underlying column being aggregated, not the Records wrapping it
             AggregateFunc::StringAgg { .. } => match input_type.scalar_type {
                 // The outer Record wraps the input in the first position, and any ORDER BY expressions afterwards
@@ -2767,19 +3672,17 @@ impl AggregateFunc {
         ReprColumnType::from(&self.output_sql_type(SqlColumnType::from_repr(&input_type)))
     }
 
-    /// Compute output type for ROW_NUMBER, RANK, DENSE_RANK
+    /// Compute output type for ROW_NUMBER, RANK, DENSE_RANK, PERCENT_RANK, CUME_DIST
     fn output_type_ranking_window_funcs(
         input_type: &SqlColumnType,
         col_name: &str,
+        result_type: SqlScalarType,
     ) -> SqlScalarType {
         match input_type.scalar_type {
             SqlScalarType::Record { ref fields, .. } => SqlScalarType::List {
                 element_type: Box::new(SqlScalarType::Record {
                     fields: [
-                        (
-                            ColumnName::from(col_name),
-                            SqlScalarType::Int64.nullable(false),
-                        ),
+                        (ColumnName::from(col_name), result_type.nullable(false)),
                         (ColumnName::from("?orig_row?"), {
                             let inner = match &fields[0].1.scalar_type {
                                 SqlScalarType::List { element_type, .. } => element_type.clone(),
@@ -2811,6 +3714,18 @@ impl AggregateFunc {
             .nullable(true)
     }
 
+    /// Given the `EncodedArgs` part of `((OriginalRow, EncodedArgs), OrderByExprs...)`,
+    /// this computes the type of the first field of the output type for `nth_value`.
+    fn nth_value_output_type_inner_from_encoded_args(
+        encoded_args_type: &SqlScalarType,
+    ) -> SqlColumnType {
+        // The encoded args are `(value, n)`, and the output has the type of the value, but always
+        // nullable. (It's null when the window frame has fewer than `n` rows.)
+        encoded_args_type.unwrap_record_element_type()[0]
+            .clone()
+            .nullable(true)
+    }
+
     fn lag_lead_result_column_name(lag_lead_type: &LagLeadType) -> ColumnName {
         ColumnName::from(match lag_lead_type {
             LagLeadType::Lag => "?lag?",
@@ -2867,9 +3782,17 @@ impl AggregateFunc {
             | AggregateFunc::SumFloat32
             | AggregateFunc::SumFloat64
             | AggregateFunc::SumNumeric
-            | AggregateFunc::StringAgg { .. } => true,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::HllSketchAgg
+            | AggregateFunc::HllUnionAgg
+            | AggregateFunc::RangeAgg
+            | AggregateFunc::RangeIntersectAgg => true,
             // Count is never null
             AggregateFunc::Count
+            | AggregateFunc::ApproxCountDistinct
             | AggregateFunc::Any
             | AggregateFunc::All
             | AggregateFunc::JsonbAgg { .. }
@@ -2880,9 +3803,13 @@ impl AggregateFunc {
             | AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedWindowAggregate { .. }
@@ -3235,6 +4162,9 @@ impl AggregateFunc {
             Self::RowNumber { .. } => "row_number",
             Self::Rank { .. } => "rank",
             Self::DenseRank { .. } => "dense_rank",
+            Self::PercentRank { .. } => "percent_rank",
+            Self::CumeDist { .. } => "cume_dist",
+            Self::Ntile { .. } => "ntile",
             Self::LagLead {
                 lag_lead: LagLeadType::Lag,
                 ..
@@ -3245,9 +4175,18 @@ impl AggregateFunc {
             } => "lead",
             Self::FirstValue { .. } => "first_value",
             Self::LastValue { .. } => "last_value",
+            Self::NthValue { .. } => "nth_value",
             Self::WindowAggregate { .. } => "window_agg",
             Self::FusedValueWindowFunc { .. } => "fused_value_window_func",
             Self::FusedWindowAggregate { .. } => "fused_window_agg",
+            Self::PercentileCont { .. } => "percentile_cont",
+            Self::PercentileDisc { .. } => "percentile_disc",
+            Self::Mode { .. } => "mode",
+            Self::ApproxCountDistinct => "approx_count_distinct",
+            Self::HllSketchAgg => "hll_sketch_agg",
+            Self::HllUnionAgg => "hll_union_agg",
+            Self::RangeAgg => "range_agg",
+            Self::RangeIntersectAgg => "range_intersect_agg",
             Self::Dummy => "dummy",
         }
     }
@@ -3269,7 +4208,10 @@ where
             | StringAgg { order_by }
             | RowNumber { order_by }
             | Rank { order_by }
-            | DenseRank { order_by } => {
+            | DenseRank { order_by }
+            | PercentRank { order_by }
+            | CumeDist { order_by }
+            | Ntile { order_by } => {
                 let order_by = order_by.iter().map(|col| self.child(col));
                 write!(f, "{}[order_by=[{}]]", name, separated(", ", order_by))
             }
@@ -3303,6 +4245,10 @@ where
             LastValue {
                 order_by,
                 window_frame,
+            }
+            | NthValue {
+                order_by,
+                window_frame,
             } => {
                 let order_by = order_by.iter().map(|col| self.child(col));
                 f.write_str(name)?;
@@ -3338,6 +4284,23 @@ where
                 write!(f, "order_by=[{}]", separated(", ", order_by))?;
                 f.write_str("]")
             }
+            PercentileCont { percentiles, desc } | PercentileDisc { percentiles, desc } => {
+                let fractions = percentiles
+                    .fractions
+                    .iter()
+                    .map(|fraction| fraction.into_inner());
+                f.write_str(name)?;
+                if percentiles.array {
+                    write!(f, "[fractions=[{}]", separated(", ", fractions))?;
+                } else {
+                    write!(f, "[fraction={}", separated(", ", fractions))?;
+                }
+                if *desc {
+                    f.write_str(", desc=true")?;
+                }
+                f.write_str("]")
+            }
+            Mode { desc: true } => write!(f, "{}[desc=true]", name),
             _ => f.write_str(name),
         }
     }
@@ -4162,10 +5125,10 @@ pub const REPEAT_ROW_NAME: &str = "repeat_row";
 
 #[cfg(test)]
 mod tests {
-    use mz_repr::{Datum, RowArena, SqlScalarType};
+    use mz_repr::{Datum, Diff, Row, RowArena, SqlScalarType};
 
-    use super::TableFunc;
-    use crate::EvalError;
+    use super::{AggregateFunc, Percentiles, TableFunc, nth_value_inner, ntile_inner};
+    use crate::{EvalError, WindowFrame, WindowFrameBound, WindowFrameUnits};
 
     /// 0 and 1 are valid (no guard rows), >1 errors with
     /// `MultipleRowsFromSubquery`, <0 with `NegativeRowsFromSubquery`. Zero is
@@ -4197,4 +5160,131 @@ mod tests {
             Some(EvalError::NegativeRowsFromSubquery),
         );
     }
+
+    /// Ordered-set aggregates position values by their multiplicity rather than
+    /// expanding them, and ignore nulls and retracted values.
+    #[mz_ore::test]
+    fn ordered_set_aggregates_count_multiplicities() {
+        let temp_storage = RowArena::new();
+        let input = [
+            (Datum::Float64(10.0.into()), Diff::from(1)),
+            (Datum::Float64(20.0.into()), Diff::from(2)),
+            (Datum::Float64(40.0.into()), Diff::from(1)),
+            (Datum::Float64(50.0.into()), Diff::from(-1)),
+            (Datum::Null, Diff::from(3)),
+        ];
+        let percentiles = |fractions: &[f64], array| Percentiles {
+            fractions: fractions.iter().map(|f| (*f).into()).collect(),
+            array,
+        };
+
+        let func = AggregateFunc::PercentileCont {
+            percentiles: percentiles(&[0.25], false),
+            desc: false,
+        };
+        assert_eq!(
+            func.eval(input.clone(), &temp_storage),
+            Datum::Float64(17.5.into())
+        );
+
+        let func = AggregateFunc::PercentileDisc {
+            percentiles: percentiles(&[0.75, 0.9], true),
+            desc: false,
+        };
+        let result = func.eval(input.clone(), &temp_storage);
+        let elements: Vec<_> = result.unwrap_array().elements().iter().collect();
+        assert_eq!(
+            elements,
+            [Datum::Float64(20.0.into()), Datum::Float64(40.0.into())]
+        );
+
+        let func = AggregateFunc::Mode { desc: false };
+        assert_eq!(
+            func.eval(input.clone(), &temp_storage),
+            Datum::Float64(20.0.into())
+        );
+
+        let func = AggregateFunc::PercentileCont {
+            percentiles: percentiles(&[0.5], false),
+            desc: false,
+        };
+        assert_eq!(
+            func.eval([(Datum::Null, Diff::from(1))], &temp_storage),
+            Datum::Null
+        );
+    }
+
+    /// Like Postgres, `ntile` puts the extra rows into the first buckets, and
+    /// leaves a bucket per row when there are more buckets than rows.
+    #[mz_ore::test]
+    fn ntile_distributes_remainder_to_first_buckets() {
+        let buckets = |num_buckets: Datum<'static>, length| {
+            ntile_inner(vec![num_buckets; length])
+                .into_iter()
+                .map(|d| if d.is_null() { 0 } else { d.unwrap_int32() })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(buckets(Datum::Int32(3), 7), [1, 1, 1, 2, 2, 3, 3]);
+        assert_eq!(buckets(Datum::Int32(2), 4), [1, 1, 2, 2]);
+        assert_eq!(buckets(Datum::Int32(5), 3), [1, 2, 3]);
+        assert_eq!(buckets(Datum::Int32(0), 2), [0, 0]);
+        assert_eq!(buckets(Datum::Null, 2), [0, 0]);
+        assert_eq!(buckets(Datum::Int32(3), 0), Vec::<i32>::new());
+    }
+
+    /// `nth_value` counts from the start of each row's window frame, and the
+    /// default RANGE frame extends to the last peer of the current row.
+    #[mz_ore::test]
+    fn nth_value_respects_window_frame() {
+        let values = [10, 20, 30, 40];
+        let args = |n| {
+            values
+                .iter()
+                .map(|v| (Datum::Int32(*v), Datum::Int32(n)))
+                .collect::<Vec<_>>()
+        };
+        let results = |n, order_by_rows: &[Row], window_frame: &WindowFrame| {
+            nth_value_inner(args(n), order_by_rows, window_frame)
+                .into_iter()
+                .map(|d| if d.is_null() { 0 } else { d.unwrap_int32() })
+                .collect::<Vec<_>>()
+        };
+
+        // The second and third rows are peers.
+        let order_by_rows = [1, 2, 2, 3].map(|k| Row::pack_slice(&[Datum::Int32(k)]));
+        assert_eq!(
+            results(2, &order_by_rows, &WindowFrame::default()),
+            [0, 20, 20, 20]
+        );
+        assert_eq!(
+            results(3, &order_by_rows, &WindowFrame::default()),
+            [0, 30, 30, 30]
+        );
+
+        let rows_frame = |start_bound, end_bound| WindowFrame {
+            units: WindowFrameUnits::Rows,
+            start_bound,
+            end_bound,
+        };
+        let frame = rows_frame(
+            WindowFrameBound::OffsetPreceding(1),
+            WindowFrameBound::OffsetFollowing(1),
+        );
+        assert_eq!(results(1, &[], &frame), [10, 10, 20, 30]);
+        assert_eq!(results(2, &[], &frame), [20, 20, 30, 40]);
+        assert_eq!(results(3, &[], &frame), [0, 30, 40, 0]);
+        assert_eq!(results(0, &[], &frame), [0, 0, 0, 0]);
+
+        let frame = rows_frame(
+            WindowFrameBound::OffsetFollowing(1),
+            WindowFrameBound::UnboundedFollowing,
+        );
+        assert_eq!(results(2, &[], &frame), [30, 40, 0, 0]);
+
+        let frame = rows_frame(
+            WindowFrameBound::UnboundedPreceding,
+            WindowFrameBound::OffsetPreceding(2),
+        );
+        assert_eq!(results(1, &[], &frame), [0, 0, 10, 10]);
+    }
 }
@@ -1774,6 +1774,7 @@ pub enum EvalError {
     InvalidRegex(Box<str>),
     InvalidRegexFlag(char),
     InvalidParameterValue(Box<str>),
+    InvalidEnumLabel(Box<str>),
     InvalidDatePart(Box<str>),
     KeyCannotBeNull,
     NegSqrt,
@@ -2005,6 +2006,9 @@ impl fmt::Display for EvalError {
                 write!(f, "cannot concatenate incompatible arrays")
             }
             EvalError::TypeFromOid(msg) => write!(f, "{msg}"),
+            EvalError::InvalidEnumLabel(label) => {
+                write!(f, "invalid input value for enum: {}", label.quoted())
+            }
             EvalError::InvalidRange(e) => e.fmt(f),
             EvalError::InvalidRoleId(msg) => write!(f, "{msg}"),
             EvalError::InvalidPrivileges(privilege) => {
@@ -2280,6 +2284,7 @@ impl RustType<ProtoEvalError> for EvalError {
                 })
             }
             EvalError::TypeFromOid(v) => TypeFromOid(v.into_proto()),
+            EvalError::InvalidEnumLabel(v) => InvalidEnumLabel(v.into_proto()),
             EvalError::InvalidRange(error) => InvalidRange(error.into_proto()),
             EvalError::InvalidRoleId(v) => InvalidRoleId(v.into_proto()),
             EvalError::InvalidPrivileges(v) => InvalidPrivileges(v.into_proto()),
@@ -2410,6 +2415,7 @@ impl RustType<ProtoEvalError> for EvalError {
                     dims: v.dims.into_rust()?,
                 }),
                 TypeFromOid(v) => Ok(EvalError::TypeFromOid(v.into())),
+                InvalidEnumLabel(v) => Ok(EvalError::InvalidEnumLabel(v.into())),
                 InvalidRange(e) => Ok(EvalError::InvalidRange(e.into_rust()?)),
                 InvalidRoleId(v) => Ok(EvalError::InvalidRoleId(v.into())),
                 InvalidPrivileges(v) => Ok(EvalError::InvalidPrivileges(v.into())),
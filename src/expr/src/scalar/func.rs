@@ -35,11 +35,12 @@ use mz_pgrepr::Type;
 use mz_pgtz::timezone::{Timezone, TimezoneSpec};
 use mz_repr::adt::array::{Array, ArrayDimension};
 use mz_repr::adt::date::Date;
+use mz_repr::adt::inet::Inet;
 use mz_repr::adt::interval::{Interval, RoundBehavior};
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
 use mz_repr::adt::numeric::{self, Numeric};
-use mz_repr::adt::range::Range;
+use mz_repr::adt::range::{Range, multirange};
 use mz_repr::adt::regex::Regex;
 use mz_repr::adt::timestamp::{CheckedTimestamp, TimestampLike};
 use mz_repr::{
@@ -101,6 +102,7 @@ func_name! {
     CastArrayToString => "cast_array_to_string",
     CastDateToTimestamp => "cast_date_to_timestamp",
     CastDateToTimestampTz => "cast_date_to_timestamp_tz",
+    CastEnumToString => "cast_enum_to_string",
     CastFloat32ToNumeric => "cast_float32_to_numeric",
     CastFloat64ToNumeric => "cast_float64_to_numeric",
     CastInt16ToNumeric => "cast_int16_to_numeric",
@@ -111,14 +113,17 @@ func_name! {
     CastListToJsonb => "cast_list_to_jsonb",
     CastListToString => "cast_list_to_string",
     CastMapToString => "cast_map_to_string",
+    CastMultiRangeToString => "cast_multi_range_to_string",
     CastRangeToString => "cast_range_to_string",
     CastRecord1ToRecord2 => "cast_record1_to_record2",
     CastRecordToString => "cast_record_to_string",
     CastStringToArray => "cast_string_to_array",
     CastStringToChar => "cast_string_to_char",
+    CastStringToEnum => "cast_string_to_enum",
     CastStringToInt2Vector => "cast_string_to_int2_vector",
     CastStringToList => "cast_string_to_list",
     CastStringToMap => "cast_string_to_map",
+    CastStringToMultiRange => "cast_string_to_multi_range",
     CastStringToNumeric => "cast_string_to_numeric",
     CastStringToRange => "cast_string_to_range",
     CastStringToTimestamp => "cast_string_to_timestamp",
@@ -146,6 +151,7 @@ func_name! {
     ListLengthMax => "list_length_max",
     MapBuildFromRecordList => "map_build_from_record_list",
     PadChar => "pad_char",
+    variadic::MultiRangeCreate => "multi_range_create",
     variadic::RangeCreate => "range_create",
     RecordGet => "record_get",
     RegexpMatch => "regexp_match",
@@ -1478,6 +1484,62 @@ fn uuid_generate_v5(a: uuid::Uuid, b: &str) -> uuid::Uuid {
     uuid::Uuid::new_v5(&a, b.as_bytes())
 }
 
+#[sqlfunc(is_infix_op = true, sqlname = "<<", propagates_nulls = true)]
+fn inet_contained_by(a: Inet, b: Inet) -> bool {
+    b.contains(&a)
+}
+
+#[sqlfunc(is_infix_op = true, sqlname = "<<=", propagates_nulls = true)]
+fn inet_contained_by_or_equals(a: Inet, b: Inet) -> bool {
+    b.contains_or_equals(&a)
+}
+
+#[sqlfunc(is_infix_op = true, sqlname = ">>", propagates_nulls = true)]
+fn inet_contains(a: Inet, b: Inet) -> bool {
+    a.contains(&b)
+}
+
+#[sqlfunc(is_infix_op = true, sqlname = ">>=", propagates_nulls = true)]
+fn inet_contains_or_equals(a: Inet, b: Inet) -> bool {
+    a.contains_or_equals(&b)
+}
+
+#[sqlfunc(is_infix_op = true, sqlname = "&&", propagates_nulls = true)]
+fn inet_overlaps(a: Inet, b: Inet) -> bool {
+    a.overlaps(&b)
+}
+
+/// Changes the prefix length of `a` to `masklen`, where `-1` means the
+/// maximum prefix length of the address family.
+fn inet_with_masklen(a: Inet, masklen: i32) -> Result<Inet, EvalError> {
+    let netmask = if masklen == -1 {
+        Some(a.max_netmask())
+    } else {
+        u8::try_from(masklen).ok()
+    };
+    netmask
+        .and_then(|netmask| a.with_netmask(netmask).ok())
+        .ok_or_else(|| {
+            EvalError::InvalidParameterValue(format!("invalid mask length: {masklen}").into())
+        })
+}
+
+#[sqlfunc(sqlname = "set_masklen", propagates_nulls = true)]
+fn set_masklen_inet(a: Inet, masklen: i32) -> Result<Inet, EvalError> {
+    inet_with_masklen(a, masklen)
+}
+
+#[sqlfunc(
+    output_type_expr = "SqlScalarType::Cidr.nullable(input_types.iter().any(|t| t.nullable))",
+    sqlname = "set_masklen",
+    propagates_nulls = true,
+    introduces_nulls = false
+)]
+fn set_masklen_cidr(a: Inet, masklen: i32) -> Result<Inet, EvalError> {
+    // Unlike `inet`, a `cidr` may not have bits set to the right of the mask.
+    Ok(inet_with_masklen(a, masklen)?.network())
+}
+
 #[sqlfunc(output_type = "Numeric", propagates_nulls = true)]
 fn power_numeric(mut a: Numeric, b: Numeric) -> Result<Numeric, EvalError> {
     if a.is_zero() {
@@ -1683,6 +1745,113 @@ fn range_difference<'a>(
     Ok(l.difference(&r)?)
 }
 
+macro_rules! multirange_fn {
+    ($fn:ident, $sqlname:expr, |$l:ident, $r:ident| $body:expr) => {
+        #[sqlfunc(
+            output_type = "bool",
+            is_infix_op = true,
+            sqlname = $sqlname,
+            propagates_nulls = true
+        )]
+        fn $fn<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+            if a.is_null() || b.is_null() {
+                return Datum::Null;
+            }
+            let ($l, $r) = (a, b);
+            Datum::from($body)
+        }
+    };
+}
+
+// As with ranges, the `_rev` variants only differ in their display string.
+multirange_fn!(multi_range_contains_elem, "@>", |l, r| {
+    multirange::contains_elem(&l.unwrap_multirange(), r)
+});
+multirange_fn!(multi_range_contains_elem_rev, "<@", |l, r| {
+    multirange::contains_elem(&l.unwrap_multirange(), r)
+});
+multirange_fn!(multi_range_contains_range, "@>", |l, r| {
+    multirange::contains_range(&l.unwrap_multirange(), &r.unwrap_range())
+});
+multirange_fn!(multi_range_contains_range_rev, "<@", |l, r| {
+    multirange::contains_range(&l.unwrap_multirange(), &r.unwrap_range())
+});
+multirange_fn!(range_contains_multi_range, "@>", |l, r| {
+    multirange::contains_multirange(&[l.unwrap_range()], &r.unwrap_multirange())
+});
+multirange_fn!(range_contains_multi_range_rev, "<@", |l, r| {
+    multirange::contains_multirange(&[l.unwrap_range()], &r.unwrap_multirange())
+});
+multirange_fn!(multi_range_contains_multi_range, "@>", |l, r| {
+    multirange::contains_multirange(&l.unwrap_multirange(), &r.unwrap_multirange())
+});
+multirange_fn!(multi_range_contains_multi_range_rev, "<@", |l, r| {
+    multirange::contains_multirange(&l.unwrap_multirange(), &r.unwrap_multirange())
+});
+multirange_fn!(multi_range_overlaps_range, "&&", |l, r| {
+    multirange::overlaps_range(&l.unwrap_multirange(), &r.unwrap_range())
+});
+multirange_fn!(multi_range_overlaps_multi_range, "&&", |l, r| {
+    multirange::overlaps_multirange(&l.unwrap_multirange(), &r.unwrap_multirange())
+});
+
+#[sqlfunc(
+    output_type_expr = "input_types[0].scalar_type.without_modifiers().nullable(true)",
+    is_infix_op = true,
+    sqlname = "+",
+    propagates_nulls = true,
+    introduces_nulls = false
+)]
+fn multi_range_union<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    if a.is_null() || b.is_null() {
+        return Ok(Datum::Null);
+    }
+    let ranges = multirange::union(&a.unwrap_multirange(), &b.unwrap_multirange())?;
+    Ok(temp_storage.try_make_datum(|packer| packer.push_multirange(ranges))?)
+}
+
+#[sqlfunc(
+    output_type_expr = "input_types[0].scalar_type.without_modifiers().nullable(true)",
+    is_infix_op = true,
+    sqlname = "*",
+    propagates_nulls = true,
+    introduces_nulls = false
+)]
+fn multi_range_intersection<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    if a.is_null() || b.is_null() {
+        return Ok(Datum::Null);
+    }
+    let ranges = multirange::intersection(&a.unwrap_multirange(), &b.unwrap_multirange())?;
+    Ok(temp_storage.try_make_datum(|packer| packer.push_multirange(ranges))?)
+}
+
+#[sqlfunc(
+    output_type_expr = "input_types[0].scalar_type.without_modifiers().nullable(true)",
+    is_infix_op = true,
+    sqlname = "-",
+    propagates_nulls = true,
+    introduces_nulls = false
+)]
+fn multi_range_difference<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    if a.is_null() || b.is_null() {
+        return Ok(Datum::Null);
+    }
+    let ranges = multirange::difference(&a.unwrap_multirange(), &b.unwrap_multirange())?;
+    Ok(temp_storage.try_make_datum(|packer| packer.push_multirange(ranges))?)
+}
+
 #[sqlfunc(is_infix_op = true, sqlname = "=", negate = "Some(NotEq.into())")]
 fn eq<'a>(a: ExcludeNull<Datum<'a>>, b: ExcludeNull<Datum<'a>>) -> bool {
     // SQL equality demands that if either input is null, then the result should be null. However,
@@ -2711,6 +2880,9 @@ where
         }
         Jsonb => Ok(strconv::format_jsonb(buf, JsonbRef::from_datum(d))),
         Uuid => Ok(strconv::format_uuid(buf, d.unwrap_uuid())),
+        Inet => Ok(strconv::format_inet(buf, d.unwrap_inet())),
+        Cidr => Ok(strconv::format_cidr(buf, d.unwrap_inet())),
+        MacAddr => Ok(strconv::format_mac_addr(buf, d.unwrap_mac_addr())),
         Record { fields, .. } => {
             let mut fields = fields.iter();
             strconv::format_record(buf, d.unwrap_list(), |buf, d| {
@@ -2756,10 +2928,28 @@ where
             Some(d) => stringify_datum(buf.nonnull_buffer(), *d, element_type),
             None => Ok::<_, EvalError>(buf.write_null()),
         }),
+        MultiRange { element_type } => {
+            strconv::format_multirange(buf, &d.unwrap_multirange(), |buf, d| match d {
+                Some(d) => stringify_datum(buf.nonnull_buffer(), *d, element_type),
+                None => Ok::<_, EvalError>(buf.write_null()),
+            })
+        }
         MzAclItem => Ok(strconv::format_mz_acl_item(buf, d.unwrap_mz_acl_item())),
+        Enum { labels, .. } => Ok(strconv::format_string(
+            buf,
+            enum_label(labels, d.unwrap_uint32())?,
+        )),
     }
 }
 
+/// Returns the label at `ordinal` among the `labels` of an enum type.
+pub(crate) fn enum_label(labels: &[String], ordinal: u32) -> Result<&str, EvalError> {
+    labels
+        .get(usize::cast_from(ordinal))
+        .map(|label| label.as_str())
+        .ok_or_else(|| EvalError::Internal(format!("invalid enum ordinal {ordinal}").into()))
+}
+
 #[sqlfunc]
 fn position(substring: &str, string: &str) -> Result<i32, EvalError> {
     let char_index = string.find(substring);
@@ -420,7 +420,27 @@ mod derive {
         RangeUnion(RangeUnion),
         RangeIntersection(RangeIntersection),
         RangeDifference(RangeDifference),
+        MultiRangeContainsElem(MultiRangeContainsElem),
+        MultiRangeContainsElemRev(MultiRangeContainsElemRev),
+        MultiRangeContainsRange(MultiRangeContainsRange),
+        MultiRangeContainsRangeRev(MultiRangeContainsRangeRev),
+        RangeContainsMultiRange(RangeContainsMultiRange),
+        RangeContainsMultiRangeRev(RangeContainsMultiRangeRev),
+        MultiRangeContainsMultiRange(MultiRangeContainsMultiRange),
+        MultiRangeContainsMultiRangeRev(MultiRangeContainsMultiRangeRev),
+        MultiRangeOverlapsRange(MultiRangeOverlapsRange),
+        MultiRangeOverlapsMultiRange(MultiRangeOverlapsMultiRange),
+        MultiRangeUnion(MultiRangeUnion),
+        MultiRangeIntersection(MultiRangeIntersection),
+        MultiRangeDifference(MultiRangeDifference),
         UuidGenerateV5(UuidGenerateV5),
+        InetContainedBy(InetContainedBy),
+        InetContainedByOrEquals(InetContainedByOrEquals),
+        InetContains(InetContains),
+        InetContainsOrEquals(InetContainsOrEquals),
+        InetOverlaps(InetOverlaps),
+        SetMasklenInet(SetMasklenInet),
+        SetMasklenCidr(SetMasklenCidr),
         MzAclItemContainsPrivilege(MzAclItemContainsPrivilege),
         ParseIdent(ParseIdent),
         PrettySql(PrettySql),
@@ -15,8 +15,10 @@ mod case_literal;
 mod char;
 mod date;
 mod datum;
+mod enums;
 mod float32;
 mod float64;
+mod inet;
 mod int16;
 mod int2vector;
 mod int32;
@@ -50,8 +52,10 @@ pub use crate::scalar::func::impls::case_literal::*;
 pub use crate::scalar::func::impls::char::*;
 pub use crate::scalar::func::impls::date::*;
 pub use crate::scalar::func::impls::datum::*;
+pub use crate::scalar::func::impls::enums::*;
 pub use crate::scalar::func::impls::float32::*;
 pub use crate::scalar::func::impls::float64::*;
+pub use crate::scalar::func::impls::inet::*;
 pub use crate::scalar::func::impls::int2vector::*;
 pub use crate::scalar::func::impls::int16::*;
 pub use crate::scalar::func::impls::int32::*;
@@ -54,6 +54,16 @@ fn seahash_string<'a>(a: &'a str) -> u64 {
     seahash_bytes(a.as_bytes())
 }
 
+#[sqlfunc(sqlname = "hll_cardinality")]
+fn hll_cardinality<'a>(a: &'a [u8]) -> Result<i64, EvalError> {
+    crate::hll::cardinality(a)
+}
+
+#[sqlfunc(sqlname = "hll_validate_sketch")]
+fn hll_validate_sketch<'a>(a: &'a [u8]) -> Result<Vec<u8>, EvalError> {
+    crate::hll::decode(a).map(crate::hll::encode)
+}
+
 #[sqlfunc(sqlname = "bit_count")]
 fn bit_count_bytes<'a>(a: &'a [u8]) -> Result<i64, EvalError> {
     let count: u64 = a.iter().map(|b| u64::cast_from(b.count_ones())).sum();
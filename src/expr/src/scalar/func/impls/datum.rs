@@ -27,6 +27,14 @@ fn is_false<'a>(a: Datum<'a>) -> bool {
     a == Datum::False
 }
 
+#[sqlfunc(sqlname = "hll_sketch")]
+fn hll_sketch<'a>(a: Datum<'a>) -> Option<Vec<u8>> {
+    match a {
+        Datum::Null => None,
+        datum => Some(crate::hll::sketch_of(datum)),
+    }
+}
+
 #[sqlfunc]
 fn pg_column_size<'a>(a: Datum<'a>) -> Result<Option<i32>, EvalError> {
     match a {
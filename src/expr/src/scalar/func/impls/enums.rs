@@ -0,0 +1,88 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt;
+
+use mz_repr::{Datum, RowArena, SqlColumnType, SqlScalarType};
+use serde::{Deserialize, Serialize};
+
+use crate::scalar::func::{LazyUnaryFunc, enum_label};
+use crate::{Eval, EvalError};
+
+#[derive(
+    Ord,
+    PartialOrd,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Hash
+)]
+pub struct CastEnumToString {
+    pub ty: SqlScalarType,
+}
+
+impl LazyUnaryFunc for CastEnumToString {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a impl Eval,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let labels = match &self.ty {
+            SqlScalarType::Enum { labels, .. } => labels,
+            ty => unreachable!("CastEnumToString called with non-enum type {ty:?}"),
+        };
+        let label = enum_label(labels, a.unwrap_uint32())?;
+        Ok(Datum::String(temp_storage.push_string(label.to_owned())))
+    }
+
+    fn output_sql_type(&self, input_type: SqlColumnType) -> SqlColumnType {
+        SqlScalarType::String.nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastStringToEnum {
+            return_ty: self.ty.clone(),
+        })
+    }
+
+    fn is_monotone(&self) -> bool {
+        // Labels are ordered by declaration, not alphabetically.
+        false
+    }
+
+    fn is_eliminable_cast(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for CastEnumToString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("enumtostr")
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_expr_derive::sqlfunc;
+use mz_repr::adt::inet::{Inet, MacAddr};
+use mz_repr::{SqlScalarType, strconv};
+
+#[sqlfunc(
+    sqlname = "inet_to_text",
+    preserves_uniqueness = true,
+    inverse = to_unary!(super::CastStringToInet)
+)]
+fn cast_inet_to_string(a: Inet) -> String {
+    let mut buf = String::new();
+    strconv::format_inet(&mut buf, a);
+    buf
+}
+
+#[sqlfunc(
+    sqlname = "cidr_to_text",
+    preserves_uniqueness = true,
+    inverse = to_unary!(super::CastStringToCidr)
+)]
+fn cast_cidr_to_string(a: Inet) -> String {
+    let mut buf = String::new();
+    strconv::format_cidr(&mut buf, a);
+    buf
+}
+
+#[sqlfunc(
+    sqlname = "macaddr_to_text",
+    preserves_uniqueness = true,
+    inverse = to_unary!(super::CastStringToMacAddr)
+)]
+fn cast_mac_addr_to_string(a: MacAddr) -> String {
+    let mut buf = String::with_capacity(17);
+    strconv::format_mac_addr(&mut buf, a);
+    buf
+}
+
+#[sqlfunc(
+    sqlname = "inet_to_cidr",
+    introduces_nulls = false,
+    output_type_expr = SqlScalarType::Cidr.nullable(input_type.nullable)
+)]
+fn cast_inet_to_cidr(a: Inet) -> Inet {
+    a.network()
+}
+
+#[sqlfunc(
+    sqlname = "cidr_to_inet",
+    preserves_uniqueness = true,
+    inverse = to_unary!(super::CastInetToCidr)
+)]
+fn cast_cidr_to_inet(a: Inet) -> Inet {
+    a
+}
+
+#[sqlfunc(sqlname = "host")]
+fn host(a: Inet) -> String {
+    a.addr().to_string()
+}
+
+#[sqlfunc(sqlname = "masklen")]
+fn masklen(a: Inet) -> i32 {
+    i32::from(a.netmask())
+}
+
+#[sqlfunc(
+    sqlname = "network",
+    introduces_nulls = false,
+    output_type_expr = SqlScalarType::Cidr.nullable(input_type.nullable)
+)]
+fn network(a: Inet) -> Inet {
+    a.network()
+}
+
+#[sqlfunc(sqlname = "family")]
+fn family(a: Inet) -> i32 {
+    a.family()
+}
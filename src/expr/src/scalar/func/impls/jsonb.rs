@@ -588,6 +588,7 @@ fn parse_catalog_create_sql<'a>(a: &'a str) -> Result<Jsonb, EvalError> {
                 "index"
             }
             CreateType(_) => "type",
+            CreateFunction(_) => "function",
             _ => return Err("not a CREATE item statement".into()),
         };
         info.insert("type", json!(item_type));
@@ -675,11 +676,12 @@ fn parse_postgres_source_details<'a>(a: &'a str) -> Result<Jsonb, EvalError> {
 ///
 /// - jsonb `{"topic": <text>, "group_id_prefix": <text | null>, "connection_id": <text>}`
 ///   for `CREATE SOURCE ... FROM KAFKA CONNECTION ... (TOPIC = ..., [GROUP ID PREFIX = ...])`
-///   statements.
+///   statements. For `TOPICS` the topic is the comma-separated list of topic
+///   names, and for `TOPIC PATTERN` it is the pattern.
 /// - jsonb `null` for any other statement.
 ///
 /// Errors if the statement fails to parse, is a kafka source without a
-/// `TOPIC` option, or references an unresolved connection name (i.e. one
+/// `TOPIC`, `TOPICS`, or `TOPIC PATTERN` option, or references an unresolved connection name (i.e. one
 /// that hasn't been through purification).
 #[sqlfunc]
 fn parse_kafka_source_details<'a>(a: &'a str) -> Result<Jsonb, EvalError> {
@@ -718,7 +720,15 @@ fn parse_kafka_source_details<'a>(a: &'a str) -> Result<Jsonb, EvalError> {
         for opt in options {
             let string_value = opt.value.as_ref().and_then(option_string);
             match opt.name {
-                KafkaSourceConfigOptionName::Topic => topic = string_value,
+                KafkaSourceConfigOptionName::Topic | KafkaSourceConfigOptionName::TopicPattern => {
+                    topic = string_value
+                }
+                KafkaSourceConfigOptionName::Topics => {
+                    if let Some(WithOptionValue::Sequence(topics)) = &opt.value {
+                        let topics: Option<Vec<_>> = topics.iter().map(option_string).collect();
+                        topic = topics.map(|topics| topics.join(","));
+                    }
+                }
                 KafkaSourceConfigOptionName::GroupIdPrefix => group_id_prefix = string_value,
                 _ => {}
             }
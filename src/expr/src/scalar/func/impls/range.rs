@@ -10,8 +10,8 @@
 use std::fmt;
 
 use mz_expr_derive::sqlfunc;
-use mz_repr::adt::range::Range;
-use mz_repr::{Datum, RowArena, SqlColumnType, SqlScalarType};
+use mz_repr::adt::range::{Range, multirange};
+use mz_repr::{Datum, DatumList, RowArena, SqlColumnType, SqlScalarType};
 use serde::{Deserialize, Serialize};
 
 use crate::scalar::func::{LazyUnaryFunc, stringify_datum};
@@ -84,6 +84,72 @@ impl fmt::Display for CastRangeToString {
     }
 }
 
+#[derive(
+    Ord,
+    PartialOrd,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Hash
+)]
+pub struct CastMultiRangeToString {
+    pub ty: SqlScalarType,
+}
+
+impl LazyUnaryFunc for CastMultiRangeToString {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a impl Eval,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let mut buf = String::new();
+        stringify_datum(&mut buf, a, &self.ty)?;
+        Ok(Datum::String(temp_storage.push_string(buf)))
+    }
+
+    fn output_sql_type(&self, input_type: SqlColumnType) -> SqlColumnType {
+        SqlScalarType::String.nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        None
+    }
+
+    fn is_monotone(&self) -> bool {
+        false
+    }
+
+    fn is_eliminable_cast(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for CastMultiRangeToString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("multirangetostr")
+    }
+}
+
 #[sqlfunc(sqlname = "rangelower", is_monotone = true)]
 fn range_lower<T>(a: Range<T>) -> Option<T> {
     a.inner.map(|inner| inner.lower.bound).flatten()
@@ -130,3 +196,21 @@ fn range_upper_inf<T>(a: Range<T>) -> bool {
         Some(inner) => inner.upper.bound.is_none(),
     }
 }
+
+/// Returns the smallest range that contains every range of the multirange.
+#[sqlfunc(
+    sqlname = "range_merge",
+    output_type_expr = SqlScalarType::Range {
+        element_type: Box::new(
+            input_type
+                .scalar_type
+                .unwrap_multirange_element_type()
+                .clone()
+        )
+    }
+    .nullable(input_type.nullable)
+)]
+fn multi_range_span<'a>(a: DatumList<'a>) -> Range<Datum<'a>> {
+    let ranges: Vec<_> = a.iter().map(|r| r.unwrap_range()).collect();
+    multirange::span(&ranges)
+}
@@ -18,6 +18,7 @@ use mz_ore::result::ResultExt;
 use mz_ore::str::StrExt;
 use mz_repr::adt::char::{Char, format_str_trim};
 use mz_repr::adt::date::Date;
+use mz_repr::adt::inet::{Inet, MacAddr};
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::jsonb::Jsonb;
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
@@ -331,6 +332,35 @@ fn cast_string_to_uuid<'a>(a: &'a str) -> Result<Uuid, EvalError> {
     strconv::parse_uuid(a).err_into()
 }
 
+#[sqlfunc(
+    sqlname = "text_to_inet",
+    preserves_uniqueness = false,
+    inverse = to_unary!(super::CastInetToString)
+)]
+fn cast_string_to_inet<'a>(a: &'a str) -> Result<Inet, EvalError> {
+    strconv::parse_inet(a).err_into()
+}
+
+#[sqlfunc(
+    sqlname = "text_to_cidr",
+    preserves_uniqueness = false,
+    introduces_nulls = false,
+    output_type_expr = SqlScalarType::Cidr.nullable(input_type.nullable),
+    inverse = to_unary!(super::CastCidrToString)
+)]
+fn cast_string_to_cidr<'a>(a: &'a str) -> Result<Inet, EvalError> {
+    strconv::parse_cidr(a).err_into()
+}
+
+#[sqlfunc(
+    sqlname = "text_to_macaddr",
+    preserves_uniqueness = false,
+    inverse = to_unary!(super::CastMacAddrToString)
+)]
+fn cast_string_to_mac_addr<'a>(a: &'a str) -> Result<MacAddr, EvalError> {
+    strconv::parse_mac_addr(a).err_into()
+}
+
 #[derive(
     Ord,
     PartialOrd,
@@ -770,6 +800,172 @@ impl fmt::Display for CastStringToRange {
     }
 }
 
+#[derive(
+    Ord,
+    PartialOrd,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Hash
+)]
+pub struct CastStringToMultiRange {
+    // Target multirange's type
+    pub return_ty: SqlScalarType,
+    // The expression to cast the discovered range elements to the multirange's
+    // element type.
+    pub cast_expr: Box<MirScalarExpr>,
+}
+
+impl LazyUnaryFunc for CastStringToMultiRange {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a impl Eval,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let ranges = strconv::parse_multirange(a.unwrap_str(), |elem_text| {
+            let elem_text = match elem_text {
+                Cow::Owned(s) => temp_storage.push_string(s),
+                Cow::Borrowed(s) => s,
+            };
+            self.cast_expr
+                .eval(&[Datum::String(elem_text)], temp_storage)
+        })?;
+
+        Ok(temp_storage.try_make_datum(|packer| packer.push_multirange(ranges))?)
+    }
+
+    /// The output SqlColumnType of this function
+    fn output_sql_type(&self, input_type: SqlColumnType) -> SqlColumnType {
+        self.return_ty
+            .without_modifiers()
+            .nullable(input_type.nullable)
+    }
+
+    /// Whether this function will produce NULL on NULL input
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    /// Whether this function will produce NULL on non-NULL input
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    /// Whether this function preserves uniqueness
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastMultiRangeToString {
+            ty: self.return_ty.clone(),
+        })
+    }
+
+    fn is_monotone(&self) -> bool {
+        false
+    }
+
+    fn is_eliminable_cast(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for CastStringToMultiRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("strtomultirange")
+    }
+}
+
+#[derive(
+    Ord,
+    PartialOrd,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Hash
+)]
+pub struct CastStringToEnum {
+    // Target enum's type
+    pub return_ty: SqlScalarType,
+}
+
+impl LazyUnaryFunc for CastStringToEnum {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a impl Eval,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let labels = match &self.return_ty {
+            SqlScalarType::Enum { labels, .. } => labels,
+            ty => unreachable!("CastStringToEnum called with non-enum type {ty:?}"),
+        };
+        let label = a.unwrap_str();
+        match labels.iter().position(|l| l == label) {
+            Some(ordinal) => Ok(Datum::UInt32(
+                u32::try_from(ordinal).expect("enum types have fewer than 2^32 labels"),
+            )),
+            None => Err(EvalError::InvalidEnumLabel(label.into())),
+        }
+    }
+
+    /// The output SqlColumnType of this function
+    fn output_sql_type(&self, input_type: SqlColumnType) -> SqlColumnType {
+        self.return_ty.clone().nullable(input_type.nullable)
+    }
+
+    /// Whether this function will produce NULL on NULL input
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    /// Whether this function will produce NULL on non-NULL input
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    /// Whether this function preserves uniqueness
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastEnumToString {
+            ty: self.return_ty.clone(),
+        })
+    }
+
+    fn is_monotone(&self) -> bool {
+        false
+    }
+
+    fn is_eliminable_cast(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for CastStringToEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("strtoenum")
+    }
+}
+
 #[derive(
     Ord,
     PartialOrd,
@@ -366,12 +366,16 @@ derive_unary!(
     CastStringToList,
     CastStringToMap,
     CastStringToRange,
+    CastStringToMultiRange,
     CastStringToTime,
     CastStringToTimestamp,
     CastStringToTimestampTz,
     CastStringToInterval,
     CastStringToNumeric,
     CastStringToUuid,
+    CastStringToInet,
+    CastStringToCidr,
+    CastStringToMacAddr,
     CastStringToChar,
     PadChar,
     CastStringToVarChar,
@@ -410,6 +414,15 @@ derive_unary!(
     CastJsonbToNumeric,
     CastJsonbToBool,
     CastUuidToString,
+    CastInetToString,
+    CastCidrToString,
+    CastMacAddrToString,
+    CastInetToCidr,
+    CastCidrToInet,
+    Host,
+    Masklen,
+    Network,
+    Family,
     CastRecordToString,
     CastRecord1ToRecord2,
     CastArrayToArray,
@@ -422,6 +435,9 @@ derive_unary!(
     CastMapToString,
     CastInt2VectorToString,
     CastRangeToString,
+    CastMultiRangeToString,
+    CastEnumToString,
+    CastStringToEnum,
     CeilFloat32,
     CeilFloat64,
     CeilNumeric,
@@ -518,6 +534,7 @@ derive_unary!(
     MzTypeName,
     StepMzTimestamp,
     RangeLower,
+    MultiRangeSpan,
     RangeUpper,
     RangeEmpty,
     RangeLowerInc,
@@ -543,6 +560,9 @@ derive_unary!(
     KafkaMurmur2String,
     SeahashBytes,
     SeahashString,
+    HllCardinality,
+    HllSketch,
+    HllValidateSketch,
     Reverse
 );
 
@@ -612,6 +612,67 @@ impl EagerVariadicFunc for RangeCreate {
     }
 }
 
+#[derive(
+    Ord,
+    PartialOrd,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Hash
+)]
+pub struct MultiRangeCreate {
+    pub elem_type: SqlScalarType,
+}
+
+impl fmt::Display for MultiRangeCreate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match &self.elem_type {
+            SqlScalarType::Int32 => "int4multirange",
+            SqlScalarType::Int64 => "int8multirange",
+            SqlScalarType::Date => "datemultirange",
+            SqlScalarType::Numeric { .. } => "nummultirange",
+            SqlScalarType::Timestamp { .. } => "tsmultirange",
+            SqlScalarType::TimestampTz { .. } => "tstzmultirange",
+            _ => unreachable!(),
+        })
+    }
+}
+
+impl EagerVariadicFunc for MultiRangeCreate {
+    type Input<'a> = Variadic<Datum<'a>>;
+    type Output<'a> = Result<Datum<'a>, EvalError>;
+
+    fn call<'a>(&self, ranges: Self::Input<'a>, temp_storage: &'a RowArena) -> Self::Output<'a> {
+        let ranges = ranges
+            .iter()
+            .map(|range| match range {
+                Datum::Null => Err(InvalidRangeError::NullMultiRangeMember),
+                range => Ok(range.unwrap_range()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(temp_storage.try_make_datum(|packer| packer.push_multirange(ranges))?)
+    }
+
+    fn output_type(&self, _input_types: &[SqlColumnType]) -> SqlColumnType {
+        SqlScalarType::MultiRange {
+            element_type: Box::new(self.elem_type.clone()),
+        }
+        .nullable(false)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        false
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+}
+
 #[sqlfunc(sqlname = "datediff")]
 fn date_diff_date(unit_str: &str, a: Date, b: Date) -> Result<i64, EvalError> {
     let unit = unit_str
@@ -1736,6 +1797,7 @@ derive_variadic! {
     And(And),
     Or(Or),
     RangeCreate(RangeCreate),
+    MultiRangeCreate(MultiRangeCreate),
     MakeAclItem(MakeAclItem),
     MakeMzAclItem(MakeMzAclItem),
     Translate(Translate),
@@ -467,7 +467,19 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                     Value::String(datum.unwrap_mz_timestamp().to_string())
                 }
                 SqlScalarType::Range { .. } => Value::String(datum.unwrap_range().to_string()),
+                SqlScalarType::MultiRange { .. } => Value::String(format!(
+                    "{{{}}}",
+                    datum.unwrap_multirange().iter().join(",")
+                )),
                 SqlScalarType::MzAclItem => Value::String(datum.unwrap_mz_acl_item().to_string()),
+                SqlScalarType::Enum { labels, .. } => {
+                    Value::String(labels[usize::cast_from(datum.unwrap_uint32())].clone())
+                }
+                SqlScalarType::Inet => Value::String(datum.unwrap_inet().to_string()),
+                SqlScalarType::Cidr => {
+                    Value::String(datum.unwrap_inet().display_cidr().to_string())
+                }
+                SqlScalarType::MacAddr => Value::String(datum.unwrap_mac_addr().to_string()),
             };
             if typ.nullable {
                 val = Value::Union {
@@ -11,6 +11,7 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
 use itertools::Itertools;
+use mz_ore::cast::CastFrom;
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::char;
 use mz_repr::adt::jsonb::JsonbRef;
@@ -231,7 +232,16 @@ impl ToJson for TypedDatum<'_> {
                 // records.
                 json!(datum.unwrap_range().to_string())
             }
+            SqlScalarType::MultiRange { .. } => {
+                json!(format!("{{{}}}", datum.unwrap_multirange().iter().join(",")))
+            }
             SqlScalarType::MzAclItem => json!(datum.unwrap_mz_acl_item().to_string()),
+            SqlScalarType::Enum { labels, .. } => {
+                json!(labels[usize::cast_from(datum.unwrap_uint32())])
+            }
+            SqlScalarType::Inet => json!(datum.unwrap_inet().to_string()),
+            SqlScalarType::Cidr => json!(datum.unwrap_inet().display_cidr().to_string()),
+            SqlScalarType::MacAddr => json!(datum.unwrap_mac_addr().to_string()),
         };
         // We don't need to recurse into map or object here because those already recursively call
         // .json() with the number policy to generate the member Values.
@@ -397,8 +407,12 @@ fn build_row_schema_field_type(
         }
         SqlScalarType::MzTimestamp => json!("string"),
         // https://debezium.io/documentation/reference/stable/connectors/postgresql.html
-        SqlScalarType::Range { .. } => json!("string"),
+        SqlScalarType::Range { .. } | SqlScalarType::MultiRange { .. } => json!("string"),
         SqlScalarType::MzAclItem => json!("string"),
+        // Enum labels are encoded as strings, matching Debezium.
+        SqlScalarType::Enum { .. } => json!("string"),
+        // Network addresses are encoded as strings, matching Debezium.
+        SqlScalarType::Inet | SqlScalarType::Cidr | SqlScalarType::MacAddr => json!("string"),
     };
     if typ.nullable {
         // Should be revisited if we ever support a different kind of union scheme.
@@ -76,12 +76,18 @@ fn format_mysql_timestamp(
     format!("{y:04}-{m:02}-{d:02} {hr:02}:{min:02}:{sec:02}.{scaled:0width$}")
 }
 
+/// Packs the values of `row` into a [`Row`] of the shape of `table_desc`.
+///
+/// If `null_missing_columns` is set, nullable columns of `table_desc` that are
+/// missing from `row`, because they were added to the table after the row was
+/// written, are packed as NULL.
 pub fn pack_mysql_row(
     row_container: &mut Row,
     row: MySqlRow,
     table_desc: &MySqlTableDesc,
     gtid_set: Option<&str>,
     binlog_full_metadata: bool,
+    null_missing_columns: bool,
 ) -> Result<Row, MySqlError> {
     let mut packer = row_container.packer();
 
@@ -109,6 +115,12 @@ pub fn pack_mysql_row(
 
         let wire_idx = match wire_idx {
             Some(idx) => idx,
+            None if null_missing_columns
+                && col_desc.column_type.as_ref().is_some_and(|t| t.nullable) =>
+            {
+                packer.push(Datum::Null);
+                continue;
+            }
             None => {
                 // We could not find a column in the incoming row that matches this descriptor column.
                 // This is an error as the column is not ignored (ignored columns have already been skipped).
@@ -11,7 +11,7 @@ use std::collections::BTreeSet;
 
 use anyhow::bail;
 use mz_proto::{ProtoType, RustType, TryFromProtoError};
-use mz_repr::SqlColumnType;
+use mz_repr::{SqlColumnType, SqlScalarType};
 #[cfg(any(test, feature = "proptest"))]
 use proptest::prelude::any;
 #[cfg(any(test, feature = "proptest"))]
@@ -172,6 +172,48 @@ impl MySqlTableDesc {
 
         Ok(())
     }
+
+    /// Removes the length limit of the `varchar` columns of this table.
+    ///
+    /// Tables that evolve their schema ingest `varchar` columns without a
+    /// length limit, so that the limit may be changed upstream.
+    pub fn remove_varchar_limits(&mut self) {
+        for column in &mut self.columns {
+            if let Some(SqlColumnType {
+                scalar_type: SqlScalarType::VarChar { max_length },
+                ..
+            }) = &mut column.column_type
+            {
+                *max_length = None;
+            }
+        }
+    }
+
+    /// Takes over the values that were appended to the enum columns of this
+    /// table in `other`, a later description of the same table.
+    ///
+    /// The binlog refers to enum values by their index, so values that are
+    /// appended to an enum upstream can only be decoded once they are known.
+    pub fn update_enum_values(&mut self, other: &MySqlTableDesc) {
+        for column in &mut self.columns {
+            let Some(MySqlColumnMeta::Enum(self_enum)) = &mut column.meta else {
+                continue;
+            };
+            let other_enum = other
+                .columns
+                .iter()
+                .find(|c| c.name == column.name)
+                .and_then(|c| match &c.meta {
+                    Some(MySqlColumnMeta::Enum(other_enum)) => Some(other_enum),
+                    _ => None,
+                });
+            if let Some(other_enum) = other_enum
+                && other_enum.values.starts_with(&self_enum.values)
+            {
+                self_enum.values.clone_from(&other_enum.values);
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -33,7 +33,8 @@ pub use replication::{
 
 pub mod schemas;
 pub use schemas::{
-    MySqlTableSchema, QualifiedTableRef, SYSTEM_SCHEMAS, SchemaRequest, schema_info,
+    MySqlAddedColumn, MySqlTableSchema, QualifiedTableRef, SYSTEM_SCHEMAS, SchemaRequest,
+    schema_info,
 };
 
 pub mod privileges;
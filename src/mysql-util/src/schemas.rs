@@ -50,6 +50,8 @@ const INFO_SCHEMA_COLS: &[&str] = &[
     "numeric_scale",
     "datetime_precision",
     "character_maximum_length",
+    "column_default",
+    "extra",
 ];
 
 // NOTE: The order of these fields *must* match the order of names of the [`INFO_SCHEMA_COLS`] list.
@@ -63,6 +65,8 @@ pub struct InfoSchema {
     numeric_scale: Option<i64>,
     datetime_precision: Option<i64>,
     character_maximum_length: Option<i64>,
+    column_default: Option<String>,
+    extra: String,
 }
 
 impl FromRow for InfoSchema {
@@ -70,7 +74,7 @@ impl FromRow for InfoSchema {
         let actual = row.columns_ref().iter().map(|c| c.name_ref());
         let expected = INFO_SCHEMA_COLS.iter().map(|c| c.as_bytes());
         itertools::assert_equal(actual, expected);
-        let (a, b, c, d, e, f, g, h) = FromRow::from_row_opt(row)?;
+        let (a, b, c, d, e, f, g, h, i, j) = FromRow::from_row_opt(row)?;
         Ok(Self {
             column_name: a,
             data_type: b,
@@ -80,6 +84,8 @@ impl FromRow for InfoSchema {
             numeric_scale: f,
             datetime_precision: g,
             character_maximum_length: h,
+            column_default: i,
+            extra: j,
         })
     }
 }
@@ -188,6 +194,59 @@ impl MySqlTableSchema {
             keys: self.keys,
         })
     }
+
+    /// Returns the columns of this table that are missing from `desc`, an
+    /// earlier description of the table, and that can be added to it.
+    ///
+    /// Only nullable columns without a default value can be added, because the
+    /// values that other columns take on in the existing rows never appear in
+    /// the binlog.
+    /// Columns whose type can't be ingested natively are added as text, and
+    /// `varchar` columns are added without a length limit.
+    pub fn added_columns(&self, desc: &MySqlTableDesc) -> Vec<MySqlAddedColumn> {
+        self.columns
+            .iter()
+            .filter(|info| {
+                !desc.columns.iter().any(|c| c.name == info.column_name)
+                    && info.is_nullable == "YES"
+                    && info.column_default.is_none()
+                    && info.extra.is_empty()
+            })
+            .filter_map(|info| {
+                let (scalar_type, meta, text) =
+                    match parse_data_type(info, &self.schema_name, &self.name) {
+                        Ok((scalar_type, meta)) => (scalar_type, meta, false),
+                        Err(_) => {
+                            let (scalar_type, meta) =
+                                parse_as_text_column(info, &self.schema_name, &self.name).ok()?;
+                            (scalar_type, meta, true)
+                        }
+                    };
+                let scalar_type = match scalar_type {
+                    SqlScalarType::VarChar { .. } => SqlScalarType::VarChar { max_length: None },
+                    scalar_type => scalar_type,
+                };
+                Some(MySqlAddedColumn {
+                    desc: MySqlColumnDesc {
+                        name: info.column_name.clone(),
+                        column_type: Some(scalar_type.nullable(true)),
+                        meta,
+                    },
+                    text,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A column that was added to an upstream table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MySqlAddedColumn {
+    /// The description of the column.
+    pub desc: MySqlColumnDesc,
+    /// Whether the column is ingested as text because its type can't be
+    /// ingested natively.
+    pub text: bool,
 }
 
 /// Request for table schemas from MySQL
@@ -542,6 +601,78 @@ fn enum_vals_from_column_type(s: &str) -> Result<Vec<String>, anyhow::Error> {
 mod tests {
     use super::*;
 
+    fn info_schema(name: &str, data_type: &str, column_type: &str) -> InfoSchema {
+        InfoSchema {
+            column_name: name.to_string(),
+            data_type: data_type.to_string(),
+            column_type: column_type.to_string(),
+            is_nullable: "YES".to_string(),
+            numeric_precision: None,
+            numeric_scale: None,
+            datetime_precision: None,
+            character_maximum_length: None,
+            column_default: None,
+            extra: String::new(),
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_added_columns() {
+        let schema = |columns| MySqlTableSchema {
+            schema_name: "db".to_string(),
+            name: "t".to_string(),
+            columns,
+            keys: BTreeSet::new(),
+        };
+        let desc = schema(vec![info_schema("a", "int", "int")])
+            .to_desc(None, None)
+            .unwrap();
+
+        let mut varchar = info_schema("b", "varchar", "varchar(10)");
+        varchar.character_maximum_length = Some(10);
+        let mut not_null = info_schema("d", "int", "int");
+        not_null.is_nullable = "NO".to_string();
+        let mut with_default = info_schema("e", "int", "int");
+        with_default.column_default = Some("1".to_string());
+        let mut generated = info_schema("f", "int", "int");
+        generated.extra = "VIRTUAL GENERATED".to_string();
+        let upstream = schema(vec![
+            info_schema("a", "int", "int"),
+            varchar,
+            info_schema("c", "enum", "enum('x','y')"),
+            not_null,
+            with_default,
+            generated,
+            info_schema("g", "geometry", "geometry"),
+        ]);
+
+        assert_eq!(
+            upstream.added_columns(&desc),
+            vec![
+                MySqlAddedColumn {
+                    desc: MySqlColumnDesc {
+                        name: "b".to_string(),
+                        column_type: Some(
+                            SqlScalarType::VarChar { max_length: None }.nullable(true)
+                        ),
+                        meta: None,
+                    },
+                    text: false,
+                },
+                MySqlAddedColumn {
+                    desc: MySqlColumnDesc {
+                        name: "c".to_string(),
+                        column_type: Some(SqlScalarType::String.nullable(true)),
+                        meta: Some(MySqlColumnMeta::Enum(MySqlColumnMetaEnum {
+                            values: vec!["x".to_string(), "y".to_string()],
+                        })),
+                    },
+                    text: true,
+                },
+            ]
+        );
+    }
+
     #[mz_ore::test]
     fn test_enum_value_parsing() {
         let vals =
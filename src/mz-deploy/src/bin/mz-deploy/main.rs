@@ -18,6 +18,7 @@ use mz_build_info::{BuildInfo, build_info};
 use mz_deploy::cli;
 use mz_deploy::cli::CliError;
 use mz_deploy::cli::commands::delete;
+use mz_deploy::cli::commands::graph;
 use mz_deploy::cli::commands::new_project::ScaffoldOpts;
 use mz_deploy::config::Settings;
 use mz_deploy::log;
@@ -32,6 +33,15 @@ enum OutputFormat {
     Json,
 }
 
+/// Output format for `mz-deploy graph`.
+#[derive(Debug, Clone, Copy, Default, clap::ValueEnum)]
+enum GraphFormat {
+    #[default]
+    Dot,
+    Mermaid,
+    Json,
+}
+
 const BUILD_INFO: BuildInfo = build_info!();
 static VERSION: LazyLock<String> = LazyLock::new(|| BUILD_INFO.human_version(None));
 
@@ -52,8 +62,10 @@ Getting started:
 Develop:
   compile              Compile and validate SQL without connecting to database
   clean                Delete the project's target/ build directory
+  fmt                  Format project SQL files
   test                 Run SQL unit tests defined in test files
   explain              Show the EXPLAIN plan for a materialized view or index
+  graph                Export the project's lineage graph as DOT, Mermaid, or JSON
   dev                  Iterate on your dirty views in a per-developer overlay
   lsp                  Start Language Server Protocol server for editor integration
   sql                  Launch an interactive psql session using the active profile
@@ -67,7 +79,9 @@ Infrastructure:
 Deploy:
   stage                Create a staging deployment for testing changes
   wait                 Wait for staging deployment clusters to be hydrated and ready
+  estimate             Compare a staging deployment's resource usage against production
   promote              Promote a staging deployment to production
+  rollback             Restore the state of an earlier promoted deployment
   abort                Clean up a staging deployment by dropping all resources
   describe             Show detailed information about a specific deployment
   list                 List all active staging deployments
@@ -146,7 +160,12 @@ enum Command {
         hide = true,
         after_help = "Run 'mz-deploy help compile' for a detailed usage guide."
     )]
-    Compile {},
+    Compile {
+        /// Allow breaking changes to sink inputs and externally consumed
+        /// objects, reporting them as warnings instead of errors
+        #[arg(long)]
+        allow_breaking: bool,
+    },
 
     /// Delete the project's target/ build directory
     ///
@@ -160,6 +179,29 @@ enum Command {
     )]
     Clean,
 
+    /// Format project SQL files
+    ///
+    /// Rewrites every .sql file under models/, clusters/, roles/, and
+    /// network-policies/ in canonical form. Comments, PRAGMA directives, and
+    /// :variable references are preserved; statements that contain comments
+    /// are left as written.
+    ///
+    /// With --check, no files are written. A diff is printed for each file
+    /// that would change and the command exits non-zero, for use in CI.
+    ///
+    /// Examples:
+    ///   mz-deploy fmt
+    ///   mz-deploy fmt --check
+    #[command(
+        hide = true,
+        after_help = "Run 'mz-deploy help fmt' for a detailed usage guide."
+    )]
+    Fmt {
+        /// Report files that need formatting without modifying them
+        #[arg(long)]
+        check: bool,
+    },
+
     /// Show the EXPLAIN plan for a materialized view or index
     ///
     /// Compiles the project, stages the target object's dependencies in an
@@ -187,6 +229,39 @@ enum Command {
         overlay: Option<PathBuf>,
     },
 
+    /// Export the project's lineage graph as DOT, Mermaid, or JSON
+    ///
+    /// Compiles the project and writes its dependency graph — objects,
+    /// indexes, clusters, and external dependencies — to stdout. Objects the
+    /// next `stage` would redeploy, compared against the last promoted
+    /// deployment, are highlighted. Use --offline to skip the comparison.
+    ///
+    /// Examples:
+    ///   mz-deploy graph | dot -Tsvg > lineage.svg
+    ///   mz-deploy graph --format mermaid --focus materialize.public.orders
+    ///   mz-deploy graph --focus materialize.public.orders --upstream 1 --downstream 2
+    #[command(
+        hide = true,
+        after_help = "Run 'mz-deploy help graph' for a detailed usage guide."
+    )]
+    Graph {
+        /// Output format
+        #[arg(long, value_enum, default_value_t)]
+        format: GraphFormat,
+        /// Only show objects connected to this one (database.schema.object)
+        #[arg(long, value_name = "OBJECT")]
+        focus: Option<String>,
+        /// Maximum number of dependency hops to show above --focus
+        #[arg(long, value_name = "N", requires = "focus")]
+        upstream: Option<usize>,
+        /// Maximum number of dependency hops to show below --focus
+        #[arg(long, value_name = "N", requires = "focus")]
+        downstream: Option<usize>,
+        /// Do not connect to Materialize; skip highlighting changed objects
+        #[arg(long)]
+        offline: bool,
+    },
+
     /// Apply infrastructure objects to Materialize (Terraform-like)
     ///
     /// Declarative, diff-based, idempotent management of infrastructure objects.
@@ -280,6 +355,53 @@ enum Command {
         dry_run: bool,
     },
 
+    /// Restore the state of an earlier promoted deployment
+    ///
+    /// Rebuilds the objects recorded for an earlier promoted deployment, stages
+    /// them under a new deploy ID, waits for hydration, and promotes the result
+    /// with the same conflict checks as 'promote'.
+    ///
+    /// Examples:
+    ///   mz-deploy rollback                  # Undo the latest deployment
+    ///   mz-deploy rollback abc123           # Restore deployment abc123
+    ///   mz-deploy rollback abc123 --dry-run # Preview what would be restored
+    #[command(
+        hide = true,
+        name = "rollback",
+        after_help = "Run 'mz-deploy help rollback' for a detailed usage guide."
+    )]
+    Rollback {
+        /// Promoted deployment ID to restore
+        ///
+        /// Defaults to the most recent promoted deployment before the latest one
+        /// that touched any of the latest deployment's schemas. Find deployment
+        /// IDs with 'mz-deploy log'.
+        #[arg(value_name = "DEPLOY_ID")]
+        target: Option<String>,
+
+        /// ID for the rollback deployment (default: random)
+        #[arg(long, value_name = "ID")]
+        deploy_id: Option<String>,
+
+        /// Skip conflict detection when promoting
+        #[arg(long)]
+        force: bool,
+
+        /// Maximum time to wait for hydration in seconds
+        ///
+        /// By default, waits indefinitely.
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
+
+        /// Maximum lag threshold in seconds for the readiness check
+        #[arg(long, value_name = "SECONDS", default_value = "300")]
+        allowed_lag: i64,
+
+        /// Print what would be restored without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// Create a staging deployment for testing changes
     ///
     /// Deploys schemas and objects to staging with suffixed names (e.g., 'public_abc123').
@@ -334,6 +456,11 @@ enum Command {
         /// Mutually exclusive with --redeploy-schema.
         #[arg(long, conflicts_with = "redeploy_schema")]
         redeploy_all: bool,
+
+        /// Allow breaking changes to sink inputs and externally consumed
+        /// objects, reporting them as warnings instead of errors
+        #[arg(long)]
+        allow_breaking: bool,
     },
 
     /// Initialize deployment tracking database and tables
@@ -655,6 +782,27 @@ enum Command {
         allowed_lag: i64,
     },
 
+    /// Compare a staging deployment's resource usage against production
+    ///
+    /// Reports arrangement memory, record counts, and hydration time for each
+    /// materialized view and index on the deployment's staging clusters, next
+    /// to the production objects they replace, with per-cluster deltas. Run it
+    /// after 'wait' so the staging numbers reflect fully hydrated dataflows.
+    ///
+    /// Examples:
+    ///   mz-deploy estimate abc123                # Text report
+    ///   mz-deploy estimate abc123 --output json  # Machine-readable report
+    #[command(
+        hide = true,
+        name = "estimate",
+        after_help = "Run 'mz-deploy help estimate' for a detailed usage guide."
+    )]
+    Estimate {
+        /// Staging deployment ID to estimate
+        #[arg(value_name = "DEPLOY_ID")]
+        deploy_id: String,
+    },
+
     /// Delete an object from Materialize and remove its project file
     ///
     /// Drops the specified object without CASCADE. If the object has
@@ -939,14 +1087,9 @@ async fn run(args: Args) -> Result<(), CliError> {
             )
             .await
         }
-        Command::Compile {} => {
+        Command::Compile { allow_breaking } => {
             let settings = load_settings(false)?;
-            if log::json_output_enabled() {
-                return Err(CliError::Message(
-                    "--output json is not supported for the 'compile' command".to_string(),
-                ));
-            }
-            cli::commands::compile::run(&settings, true)
+            cli::commands::compile::run_checked(&settings, allow_breaking)
                 .await
                 .map(|_| ())
         }
@@ -954,10 +1097,37 @@ async fn run(args: Args) -> Result<(), CliError> {
             let settings = load_settings(false)?;
             cli::commands::clean::run(&settings)
         }
+        Command::Fmt { check } => {
+            let settings = load_settings(false)?;
+            cli::commands::fmt::run(&settings, check)
+        }
         Command::Explain { target, overlay } => {
             let settings = load_settings(false)?;
             cli::commands::explain::run(&settings, &target, overlay.as_deref()).await
         }
+        Command::Graph {
+            format,
+            focus,
+            upstream,
+            downstream,
+            offline,
+        } => {
+            let settings = load_settings(!offline)?;
+            let format = match format {
+                GraphFormat::Dot => graph::Format::Dot,
+                GraphFormat::Mermaid => graph::Format::Mermaid,
+                GraphFormat::Json => graph::Format::Json,
+            };
+            graph::run(
+                &settings,
+                format,
+                focus.as_deref(),
+                upstream,
+                downstream,
+                offline,
+            )
+            .await
+        }
         Command::Apply {
             skip_secrets,
             dry_run,
@@ -1002,6 +1172,26 @@ async fn run(args: Args) -> Result<(), CliError> {
             }
             cli::commands::promote::run(&settings, &deploy_id, force, dry_run).await
         }
+        Command::Rollback {
+            target,
+            deploy_id,
+            force,
+            timeout,
+            allowed_lag,
+            dry_run,
+        } => {
+            let settings = load_settings(true)?;
+            cli::commands::rollback::run(
+                &settings,
+                target.as_deref(),
+                deploy_id.as_deref(),
+                force,
+                timeout,
+                allowed_lag,
+                dry_run,
+            )
+            .await
+        }
         Command::Stage {
             deploy_id,
             allow_dirty,
@@ -1009,6 +1199,7 @@ async fn run(args: Args) -> Result<(), CliError> {
             dry_run,
             redeploy_schema,
             redeploy_all,
+            allow_breaking,
         } => {
             let settings = load_settings(true)?;
             cli::commands::stage::run(
@@ -1019,6 +1210,7 @@ async fn run(args: Args) -> Result<(), CliError> {
                 dry_run,
                 &redeploy_schema,
                 redeploy_all,
+                allow_breaking,
             )
             .await
         }
@@ -1099,6 +1291,10 @@ async fn run(args: Args) -> Result<(), CliError> {
             let settings = load_settings(true)?;
             cli::commands::wait::run(&settings, &name, once, timeout, allowed_lag).await
         }
+        Command::Estimate { deploy_id } => {
+            let settings = load_settings(true)?;
+            cli::commands::estimate::run(&settings, &deploy_id).await
+        }
         Command::Delete { yes, subcommand } => {
             let settings = load_settings(true)?;
             let (kind, name) = match subcommand {
@@ -17,10 +17,15 @@
 //!
 //! - **[`new_project`]** — Scaffold a new mz-deploy project directory.
 //! - **[`compile`]** — Parse, validate, and type-check the project locally.
+//! - **[`fmt`]** — Rewrite project SQL files in canonical form.
 //! - **[`explain`]** — Show the EXPLAIN plan for a materialized view or index.
+//! - **[`graph`]** — Export the project's lineage graph as DOT, Mermaid, or JSON.
 //! - **[`stage`]** — Deploy the project to a staging environment.
 //! - **[`wait`]** — Check hydration status of a staged deployment.
+//! - **[`estimate`]** — Compare a staged deployment's resource usage against
+//!   production.
 //! - **[`promote`]** — Promote a staged deployment to production.
+//! - **[`rollback`]** — Restore the state of an earlier promoted deployment.
 //! - **[`apply_all`]** — Orchestrate all infrastructure apply steps.
 //! - **[`abort`]** — Roll back a staged deployment.
 //! - **[`apply_sources`]** — Create sources that don't exist.
@@ -68,8 +73,11 @@ pub mod debug;
 pub mod delete;
 pub mod describe;
 pub mod dev;
+pub mod estimate;
 pub mod explain;
+pub mod fmt;
 pub mod grants;
+pub mod graph;
 pub mod list;
 pub mod lock;
 pub mod log;
@@ -78,6 +86,7 @@ pub mod new_project;
 pub mod profile;
 pub mod promote;
 pub mod roles;
+pub mod rollback;
 pub mod setup;
 mod setup_schema;
 pub mod sql;
@@ -148,6 +148,23 @@ pub async fn run(settings: &Settings, deploy_id: &str) -> Result<(), CliError> {
         .await
         .map_err(|source| CliError::DeploymentStateWriteFailed { source })?;
 
+    // Clean up recorded definitions, assertions, and rollback records
+    client
+        .deployments()
+        .delete_object_statements(deploy_id)
+        .await
+        .map_err(|source| CliError::DeploymentStateWriteFailed { source })?;
+    client
+        .deployments()
+        .delete_assertions(deploy_id)
+        .await
+        .map_err(|source| CliError::DeploymentStateWriteFailed { source })?;
+    client
+        .deployments()
+        .delete_rollback(deploy_id)
+        .await
+        .map_err(|source| CliError::DeploymentStateWriteFailed { source })?;
+
     // Clean up apply state schemas if they exist (from interrupted apply)
     client
         .deployments()
@@ -44,7 +44,10 @@ struct Connections {
 impl Connections {
     fn new(settings: &Settings) -> Result<Self, CliError> {
         Ok(Connections {
-            resolver: SecretResolver::new(&settings.profile_config.security),
+            resolver: SecretResolver::new(
+                &settings.profile_config.security,
+                settings.connection().vault.as_ref(),
+            ),
         })
     }
 
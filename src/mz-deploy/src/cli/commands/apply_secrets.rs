@@ -40,7 +40,10 @@ struct Secrets {
 impl Secrets {
     fn new(settings: &Settings) -> Result<Self, CliError> {
         Ok(Secrets {
-            resolver: SecretResolver::new(&settings.profile_config.security),
+            resolver: SecretResolver::new(
+                &settings.profile_config.security,
+                settings.connection().vault.as_ref(),
+            ),
         })
     }
 
@@ -18,11 +18,24 @@
 //!    objects whose definitions changed since the last build are re-validated;
 //!    unchanged builds skip typechecking entirely.
 //! 5. **Display** — Print the deployment plan with dependencies and SQL.
+//!
+//! The `compile` command additionally compares the typed output of every
+//! contract object (sink inputs and objects consumed outside the project)
+//! against the baseline recorded in `types.lock` by the last `promote`, and
+//! fails on breaking changes unless they are explicitly allowed. It then runs
+//! the lint rules enabled in `project.toml` (see
+//! [`lint`](crate::project::analysis::lint)), rendering each finding as an
+//! annotated snippet or, with `--output json`, as a JSON document on stdout.
 
 use crate::cli::CliError;
-use crate::cli::progress;
+use crate::cli::{progress, render};
 use crate::config::Settings;
+use crate::fs::FileSystem;
+use crate::project::analysis::assertions;
+use crate::project::analysis::breaking::{self, ObjectChanges};
+use crate::project::analysis::lint::{self, LintFinding};
 use crate::project::ir::graph::Project;
+use crate::types::Types;
 use crate::{project, verbose};
 use std::time::Instant;
 
@@ -51,6 +64,117 @@ pub async fn run(settings: &Settings, show_progress: bool) -> Result<Project, Cl
     run_with_fs(settings, show_progress, crate::fs::FileSystem::new()).await
 }
 
+/// Compile the project for the `compile` command, rejecting breaking changes
+/// to contract objects recorded in `types.lock` and reporting lint findings.
+///
+/// The baseline is the one [`super::lock::record_contracts`] wrote at the last
+/// promotion, or `mz-deploy lock` wrote since.
+///
+/// # Arguments
+/// * `settings` - Resolved project and profile configuration
+/// * `allow_breaking` - Downgrade every breaking change to a warning
+///
+/// # Errors
+/// Returns `CliError::BreakingChanges` if a contract object changed
+/// incompatibly without `PRAGMA ALLOW_BREAKING_CHANGE` or `allow_breaking`,
+/// `CliError::InvalidAssertion` for a malformed `-- @assert` directive, and
+/// `CliError::LintFailed` if a rule configured as `error` reported a
+/// violation.
+pub async fn run_checked(settings: &Settings, allow_breaking: bool) -> Result<Project, CliError> {
+    let (project, types) = run_with_types(settings, true).await?;
+    let fs = FileSystem::new();
+
+    let findings = lint::run(&project, &settings.directory, &settings.lint, &fs);
+    report_lints(&findings, &fs);
+
+    let baseline = crate::types::load_types_lock(&settings.directory).unwrap_or_default();
+    let changes = breaking::detect(
+        &project,
+        &settings.directory,
+        &types.tables,
+        &baseline.contracts,
+        &fs,
+    );
+    check_breaking_changes(&changes, allow_breaking)?;
+
+    assertions::collect(&project, &settings.directory, &fs).map_err(CliError::InvalidAssertion)?;
+
+    let errors = findings.iter().filter(|f| f.is_error()).count();
+    if errors > 0 {
+        return Err(CliError::LintFailed { count: errors });
+    }
+    Ok(project)
+}
+
+/// Lint findings, as written to stdout by `compile --output json`.
+#[derive(serde::Serialize)]
+struct CompileOutput<'a> {
+    lints: &'a [LintFinding],
+}
+
+/// Print lint findings: one annotated snippet per finding on stderr, or a
+/// single [`CompileOutput`] document in JSON mode.
+fn report_lints(findings: &[LintFinding], fs: &FileSystem) {
+    if crate::log::json_output_enabled() {
+        crate::log::output_json(&CompileOutput { lints: findings });
+        return;
+    }
+    for finding in findings {
+        let source = fs.read_to_string(&finding.file).unwrap_or_default();
+        crate::info!("{}", render::render(&finding.to_positional(source)));
+    }
+}
+
+/// Like [`run`] but also returns the typed output columns of every project
+/// object.
+pub(crate) async fn run_with_types(
+    settings: &Settings,
+    show_progress: bool,
+) -> Result<(Project, Types), CliError> {
+    let settings = settings.clone();
+    mz_ore::task::spawn_blocking(
+        || "compile-run",
+        move || {
+            run_inner(
+                &settings,
+                show_progress,
+                false,
+                crate::fs::FileSystem::new(),
+            )
+        },
+    )
+    .await
+}
+
+/// Report column changes to contract objects.
+///
+/// Breaking changes are printed as warnings when the object's file carries
+/// `PRAGMA ALLOW_BREAKING_CHANGE` or `allow_breaking` is set, and returned as
+/// an error otherwise. Compatible changes are only shown in verbose mode.
+pub(crate) fn check_breaking_changes(
+    changes: &[ObjectChanges],
+    allow_breaking: bool,
+) -> Result<(), CliError> {
+    let mut rejected = Vec::new();
+    for object in changes {
+        for change in &object.changes {
+            let line = format!("{}: {}", object.object, change);
+            if !change.is_breaking() {
+                verbose!("compatible change: {}", line);
+            } else if object.allowed || allow_breaking {
+                progress::warn(&format!("allowed breaking change: {}", line));
+            } else {
+                rejected.push(line);
+            }
+        }
+    }
+    if rejected.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::BreakingChanges { changes: rejected })
+    }
+}
+
 /// Like [`run`] but uses the provided [`crate::fs::FileSystem`] (typically an
 /// overlay built from unsaved editor buffers) instead of constructing a
 /// disk-only one.
@@ -65,6 +189,7 @@ pub(crate) async fn run_with_fs(
         move || run_inner(&settings, show_progress, false, fs),
     )
     .await
+    .map(|(project, _)| project)
 }
 
 /// Compile the project without type checking.
@@ -83,6 +208,7 @@ pub async fn run_without_typecheck(
         move || run_inner(&settings, show_progress, true, crate::fs::FileSystem::new()),
     )
     .await
+    .map(|(project, _)| project)
 }
 
 fn run_inner(
@@ -90,7 +216,7 @@ fn run_inner(
     show_progress: bool,
     skip_typecheck: bool,
     fs: crate::fs::FileSystem,
-) -> Result<Project, CliError> {
+) -> Result<(Project, Types), CliError> {
     let start_time = Instant::now();
     let directory = &settings.directory;
 
@@ -130,9 +256,11 @@ fn run_inner(
         return Err(CliError::UndeclaredDependencies { undeclared });
     }
 
-    if !skip_typecheck {
-        typecheck_project(settings, &planned_project)?;
-    }
+    let types = if skip_typecheck {
+        Types::default()
+    } else {
+        typecheck_project(settings, &planned_project)?
+    };
 
     if show_progress && crate::log::verbose_enabled() {
         print_verbose_details(&planned_project);
@@ -143,17 +271,18 @@ fn run_inner(
         progress::finished("compile", total_duration);
     }
 
-    Ok(planned_project)
+    Ok((planned_project, types))
 }
 
-/// Perform type checking using the in-process catalog backend.
-fn typecheck_project(settings: &Settings, planned_project: &Project) -> Result<(), CliError> {
+/// Perform type checking using the in-process catalog backend, returning the
+/// output columns of every project object.
+fn typecheck_project(settings: &Settings, planned_project: &Project) -> Result<Types, CliError> {
     let directory = &settings.directory;
     use crate::project::compiler::typecheck;
 
     let external_types = crate::types::load_types_lock(directory).unwrap_or_default();
 
-    let (_, stats) = typecheck::run(
+    let (types, stats) = typecheck::run(
         directory,
         settings.profile_name().unwrap_or(""),
         settings.profile_suffix(),
@@ -169,7 +298,7 @@ fn typecheck_project(settings: &Settings, planned_project: &Project) -> Result<(
         stats.schema_changed,
     );
 
-    Ok(())
+    Ok(types)
 }
 
 /// Print verbose details about the project (only shown with VERBOSE env var)
@@ -0,0 +1,514 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Estimate command - compare a staged deployment's resource usage against
+//! production.
+//!
+//! `wait` answers whether a staging deployment is ready; `estimate` answers
+//! what it will cost once it is. For every staging cluster it reads the
+//! arrangement memory, record counts, and hydration time of each materialized
+//! view and index, pairs each object with the production object it replaces
+//! (same database and name, with the `_<deploy_id>` suffix stripped from the
+//! schema and cluster), and reports per-cluster totals and deltas.
+//!
+//! Memory is the largest replica's arrangement size, as reported by
+//! `mz_internal.mz_object_arrangement_sizes`, which rounds to 10 MiB. The
+//! numbers are only meaningful once the staging clusters have hydrated, so
+//! the report notes clusters that are still hydrating.
+
+use crate::cli::CliError;
+use crate::client::{Client, ObjectResourceUsage};
+use crate::config::Settings;
+use crate::log;
+use owo_colors::{OwoColorize, Stream};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// Aggregate resource usage of one object or one cluster.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+struct Usage {
+    arrangement_bytes: i64,
+    /// `None` when no replica could report record counts.
+    records: Option<i64>,
+    /// `None` while anything counted is still hydrating.
+    hydration_ms: Option<u64>,
+}
+
+impl Usage {
+    fn of(object: &ObjectResourceUsage) -> Self {
+        Usage {
+            arrangement_bytes: object.arrangement_bytes,
+            records: object.records,
+            hydration_ms: object.hydration_ms,
+        }
+    }
+
+    /// Sum memory and records; a cluster hydrates when its slowest object does.
+    fn total<'a>(objects: impl IntoIterator<Item = &'a Usage>) -> Self {
+        let mut total = Usage {
+            arrangement_bytes: 0,
+            records: None,
+            hydration_ms: Some(0),
+        };
+        for usage in objects {
+            total.arrangement_bytes += usage.arrangement_bytes;
+            if let Some(records) = usage.records {
+                total.records = Some(total.records.unwrap_or(0) + records);
+            }
+            total.hydration_ms = match (total.hydration_ms, usage.hydration_ms) {
+                (Some(a), Some(b)) => Some(a.max(b)),
+                _ => None,
+            };
+        }
+        total
+    }
+}
+
+/// Staging minus production; a missing production side counts as zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+struct UsageDelta {
+    arrangement_bytes: i64,
+    records: Option<i64>,
+    hydration_ms: Option<i64>,
+}
+
+impl UsageDelta {
+    fn between(staging: &Usage, production: Option<&Usage>) -> Self {
+        let production = production.copied().unwrap_or(Usage {
+            arrangement_bytes: 0,
+            records: Some(0),
+            hydration_ms: Some(0),
+        });
+        UsageDelta {
+            arrangement_bytes: staging.arrangement_bytes - production.arrangement_bytes,
+            records: staging.records.zip(production.records).map(|(s, p)| s - p),
+            hydration_ms: staging
+                .hydration_ms
+                .zip(production.hydration_ms)
+                .and_then(|(s, p)| Some(i64::try_from(s).ok()? - i64::try_from(p).ok()?)),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ObjectEstimate {
+    database: String,
+    schema: String,
+    name: String,
+    object_type: String,
+    /// `None` when the object is removed by this deployment.
+    staging: Option<Usage>,
+    /// `None` when the object is new in this deployment.
+    production: Option<Usage>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ClusterEstimate {
+    cluster: String,
+    staging_cluster: String,
+    staging: Usage,
+    /// `None` when the cluster is new in this deployment.
+    production: Option<Usage>,
+    delta: UsageDelta,
+    objects: Vec<ObjectEstimate>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct EstimateReport {
+    deploy_id: String,
+    clusters: Vec<ClusterEstimate>,
+}
+
+/// Compare the resource usage of a staging deployment against production.
+///
+/// # Arguments
+/// * `settings` - Resolved CLI settings (profile, project directory, etc.)
+/// * `deploy_id` - Staging deployment ID
+///
+/// # Errors
+/// Surfaces connection errors, including unknown or already-promoted
+/// deployments.
+pub async fn run(settings: &Settings, deploy_id: &str) -> Result<(), CliError> {
+    let client = Client::connect_with_profile(settings.connection().clone())
+        .await
+        .map_err(CliError::Connection)?;
+    client.deployments().validate_staging(deploy_id).await?;
+
+    let clusters = client
+        .deployments()
+        .get_deployment_clusters(deploy_id)
+        .await?;
+    let staging_clusters: Vec<String> = clusters
+        .iter()
+        .map(|c| format!("{}_{}", c, deploy_id))
+        .collect();
+
+    let introspection = client.introspection();
+    let existing = introspection.check_clusters_exist(&clusters).await?;
+    let staging = introspection
+        .get_object_resource_usage(&staging_clusters)
+        .await?;
+    let production = introspection.get_object_resource_usage(&clusters).await?;
+
+    let report = build_report(deploy_id, &clusters, &existing, staging, production);
+    log::output(&report);
+    Ok(())
+}
+
+/// Pair each staging object with the production object it replaces and
+/// total the result per cluster.
+///
+/// `clusters` are the deployment's production cluster names; `existing` is
+/// the subset that already exists in production.
+fn build_report(
+    deploy_id: &str,
+    clusters: &[String],
+    existing: &BTreeSet<String>,
+    staging: Vec<ObjectResourceUsage>,
+    production: Vec<ObjectResourceUsage>,
+) -> EstimateReport {
+    let suffix = format!("_{}", deploy_id);
+    let unsuffixed = |name: &str| name.strip_suffix(&suffix).unwrap_or(name).to_string();
+
+    // (cluster, database, schema, name) -> (type, staging, production)
+    type Key = (String, String, String, String);
+    let mut objects: BTreeMap<Key, (String, Option<Usage>, Option<Usage>)> = BTreeMap::new();
+    for object in &staging {
+        let key = (
+            unsuffixed(&object.cluster),
+            object.database.clone(),
+            unsuffixed(&object.schema),
+            object.name.clone(),
+        );
+        let entry = objects
+            .entry(key)
+            .or_insert_with(|| (object.object_type.clone(), None, None));
+        entry.1 = Some(Usage::of(object));
+    }
+    for object in &production {
+        let key = (
+            object.cluster.clone(),
+            object.database.clone(),
+            object.schema.clone(),
+            object.name.clone(),
+        );
+        let entry = objects
+            .entry(key)
+            .or_insert_with(|| (object.object_type.clone(), None, None));
+        entry.2 = Some(Usage::of(object));
+    }
+
+    let clusters = clusters
+        .iter()
+        .map(|cluster| {
+            let cluster_objects: Vec<ObjectEstimate> = objects
+                .iter()
+                .filter(|((c, ..), _)| c == cluster)
+                .map(
+                    |((_, database, schema, name), (object_type, staging, production))| {
+                        ObjectEstimate {
+                            database: database.clone(),
+                            schema: schema.clone(),
+                            name: name.clone(),
+                            object_type: object_type.clone(),
+                            staging: *staging,
+                            production: *production,
+                        }
+                    },
+                )
+                .collect();
+            let staging = Usage::total(cluster_objects.iter().filter_map(|o| o.staging.as_ref()));
+            let production = existing.contains(cluster).then(|| {
+                Usage::total(cluster_objects.iter().filter_map(|o| o.production.as_ref()))
+            });
+            ClusterEstimate {
+                delta: UsageDelta::between(&staging, production.as_ref()),
+                cluster: cluster.clone(),
+                staging_cluster: format!("{}{}", cluster, suffix),
+                staging,
+                production,
+                objects: cluster_objects,
+            }
+        })
+        .collect();
+
+    EstimateReport {
+        deploy_id: deploy_id.to_string(),
+        clusters,
+    }
+}
+
+/// Format a byte count with a binary unit, e.g. `1.5 GiB`.
+fn format_bytes(bytes: i64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let sign = if bytes < 0 { "-" } else { "" };
+    #[allow(clippy::as_conversions)]
+    let mut value = bytes.unsigned_abs() as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{}{} B", sign, bytes.unsigned_abs())
+    } else {
+        format!("{}{:.1} {}", sign, value, UNITS[unit])
+    }
+}
+
+fn format_records(records: Option<i64>) -> String {
+    records.map_or_else(|| "-".to_string(), |r| r.to_string())
+}
+
+fn format_hydration(ms: Option<u64>) -> String {
+    #[allow(clippy::as_conversions)]
+    ms.map_or_else(
+        || "hydrating".to_string(),
+        |ms| format!("{:.1}s", ms as f64 / 1000.0),
+    )
+}
+
+/// Signed change with a percentage when the baseline is non-zero.
+fn format_change(delta: i64, baseline: i64, unit: impl Fn(i64) -> String) -> String {
+    let sign = if delta > 0 { "+" } else { "" };
+    if baseline > 0 {
+        #[allow(clippy::as_conversions)]
+        let percent = delta as f64 * 100.0 / baseline as f64;
+        format!("{}{} ({}{:.0}%)", sign, unit(delta), sign, percent)
+    } else {
+        format!("{}{}", sign, unit(delta))
+    }
+}
+
+impl fmt::Display for EstimateReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Resource estimate for '{}':", self.deploy_id)?;
+
+        if self.clusters.is_empty() {
+            writeln!(f, "\n  No staging clusters in this deployment.")?;
+            return Ok(());
+        }
+
+        for cluster in &self.clusters {
+            let header = format!("{} (staging: {})", cluster.cluster, cluster.staging_cluster);
+            writeln!(
+                f,
+                "\n  {}",
+                header.if_supports_color(Stream::Stderr, |t| t.bold())
+            )?;
+            let production = cluster.production.unwrap_or_default();
+            let new = cluster.production.is_none();
+
+            let memory_change = format_change(
+                cluster.delta.arrangement_bytes,
+                production.arrangement_bytes,
+                format_bytes,
+            );
+            let memory_change = if cluster.delta.arrangement_bytes > 0 {
+                memory_change
+                    .if_supports_color(Stream::Stderr, |t| t.yellow())
+                    .to_string()
+            } else {
+                memory_change
+            };
+            writeln!(
+                f,
+                "    memory      {:>12} -> {:>12}  {}",
+                if new {
+                    "-".to_string()
+                } else {
+                    format_bytes(production.arrangement_bytes)
+                },
+                format_bytes(cluster.staging.arrangement_bytes),
+                memory_change
+            )?;
+            writeln!(
+                f,
+                "    records     {:>12} -> {:>12}  {}",
+                if new {
+                    "-".to_string()
+                } else {
+                    format_records(production.records)
+                },
+                format_records(cluster.staging.records),
+                cluster.delta.records.map_or_else(String::new, |d| {
+                    format_change(d, production.records.unwrap_or(0), |r| r.to_string())
+                })
+            )?;
+            writeln!(
+                f,
+                "    hydration   {:>12} -> {:>12}",
+                if new {
+                    "-".to_string()
+                } else {
+                    format_hydration(production.hydration_ms)
+                },
+                format_hydration(cluster.staging.hydration_ms),
+            )?;
+
+            if !cluster.objects.is_empty() {
+                writeln!(f, "    objects:")?;
+                for object in &cluster.objects {
+                    let change = match (&object.production, &object.staging) {
+                        (None, Some(s)) => format!("new, {}", format_bytes(s.arrangement_bytes)),
+                        (Some(p), None) => {
+                            format!("removed, {}", format_bytes(p.arrangement_bytes))
+                        }
+                        (Some(p), Some(s)) => format!(
+                            "{} -> {}",
+                            format_bytes(p.arrangement_bytes),
+                            format_bytes(s.arrangement_bytes)
+                        ),
+                        (None, None) => continue,
+                    };
+                    writeln!(
+                        f,
+                        "      {}.{}.{} ({}): {}",
+                        object.database, object.schema, object.name, object.object_type, change
+                    )?;
+                }
+            }
+            if cluster.staging.hydration_ms.is_none() {
+                writeln!(
+                    f,
+                    "    {}",
+                    "still hydrating; numbers will change until 'mz-deploy wait' succeeds"
+                        .if_supports_color(Stream::Stderr, |t| t.dimmed())
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(
+        cluster: &str,
+        schema: &str,
+        name: &str,
+        bytes: i64,
+        records: Option<i64>,
+        hydration_ms: Option<u64>,
+    ) -> ObjectResourceUsage {
+        ObjectResourceUsage {
+            cluster: cluster.to_string(),
+            database: "materialize".to_string(),
+            schema: schema.to_string(),
+            name: name.to_string(),
+            object_type: "materialized-view".to_string(),
+            arrangement_bytes: bytes,
+            records,
+            hydration_ms,
+        }
+    }
+
+    const MIB: i64 = 1024 * 1024;
+
+    #[mz_ore::test]
+    fn test_build_report_pairs_staging_with_production() {
+        let staging = vec![
+            usage(
+                "compute_d1",
+                "public_d1",
+                "orders",
+                200 * MIB,
+                Some(2000),
+                Some(3000),
+            ),
+            usage(
+                "compute_d1",
+                "public_d1",
+                "fresh",
+                10 * MIB,
+                Some(10),
+                Some(500),
+            ),
+        ];
+        let production = vec![
+            usage(
+                "compute",
+                "public",
+                "orders",
+                100 * MIB,
+                Some(1000),
+                Some(1000),
+            ),
+            usage("compute", "public", "stale", 20 * MIB, Some(50), Some(200)),
+        ];
+        let report = build_report(
+            "d1",
+            &["compute".to_string()],
+            &BTreeSet::from(["compute".to_string()]),
+            staging,
+            production,
+        );
+
+        assert_eq!(report.clusters.len(), 1);
+        let cluster = &report.clusters[0];
+        assert_eq!(cluster.cluster, "compute");
+        assert_eq!(cluster.staging.arrangement_bytes, 210 * MIB);
+        assert_eq!(cluster.staging.records, Some(2010));
+        assert_eq!(cluster.staging.hydration_ms, Some(3000));
+        let production = cluster.production.unwrap();
+        assert_eq!(production.arrangement_bytes, 120 * MIB);
+        assert_eq!(cluster.delta.arrangement_bytes, 90 * MIB);
+        assert_eq!(cluster.delta.records, Some(960));
+        assert_eq!(cluster.delta.hydration_ms, Some(2000));
+
+        let names: Vec<_> = cluster
+            .objects
+            .iter()
+            .map(|o| (o.name.as_str(), o.staging.is_some(), o.production.is_some()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("fresh", true, false),
+                ("orders", true, true),
+                ("stale", false, true)
+            ]
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_build_report_new_cluster_and_hydrating_objects() {
+        let staging = vec![
+            usage("etl_d1", "public_d1", "a", 30 * MIB, None, Some(100)),
+            usage("etl_d1", "public_d1", "b", 0, None, None),
+        ];
+        let report = build_report(
+            "d1",
+            &["etl".to_string()],
+            &BTreeSet::new(),
+            staging,
+            vec![],
+        );
+
+        let cluster = &report.clusters[0];
+        assert_eq!(cluster.production, None);
+        assert_eq!(cluster.staging.records, None);
+        assert_eq!(cluster.staging.hydration_ms, None);
+        assert_eq!(cluster.delta.arrangement_bytes, 30 * MIB);
+        assert_eq!(cluster.delta.hydration_ms, None);
+
+        let text = report.to_string();
+        assert!(text.contains("etl (staging: etl_d1)"), "{text}");
+        assert!(text.contains("still hydrating"), "{text}");
+    }
+
+    #[mz_ore::test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(10 * MIB), "10.0 MiB");
+        assert_eq!(format_bytes(-3 * 1024 * MIB / 2), "-1.5 GiB");
+    }
+}
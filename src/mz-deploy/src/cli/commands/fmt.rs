@@ -0,0 +1,175 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Fmt command — rewrite project SQL files in canonical form.
+//!
+//! Formats every `.sql` file under the project's source directories using
+//! [`format_sql`]. With `--check`, files are left untouched; a unified diff
+//! is printed for each file that would change and the command fails if any
+//! file is not already formatted, which makes it suitable for CI.
+
+use crate::cli::CliError;
+use crate::config::Settings;
+use crate::log;
+use crate::project::syntax::format::format_sql;
+use similar::TextDiff;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Project directories that contain SQL source files.
+const SOURCE_DIRS: &[&str] = &["models", "clusters", "roles", "network-policies"];
+
+#[derive(serde::Serialize)]
+struct FmtResult {
+    check: bool,
+    /// Files that were (or, with `--check`, would be) reformatted.
+    changed: Vec<ChangedFile>,
+    unchanged: usize,
+}
+
+#[derive(serde::Serialize)]
+struct ChangedFile {
+    path: PathBuf,
+    #[serde(skip)]
+    diff: String,
+}
+
+impl fmt::Display for FmtResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.check {
+            for file in &self.changed {
+                write!(f, "{}", file.diff)?;
+            }
+            if self.changed.is_empty() {
+                write!(
+                    f,
+                    "  \u{2713} {} file{} already formatted",
+                    self.unchanged,
+                    if self.unchanged == 1 { "" } else { "s" }
+                )?;
+            }
+            return Ok(());
+        }
+        for file in &self.changed {
+            writeln!(f, "  Formatted {}", file.path.display())?;
+        }
+        write!(
+            f,
+            "  \u{2713} {} file{} formatted, {} unchanged",
+            self.changed.len(),
+            if self.changed.len() == 1 { "" } else { "s" },
+            self.unchanged
+        )
+    }
+}
+
+/// Format all SQL files in the project.
+///
+/// # Arguments
+/// * `settings` - Resolved project configuration
+/// * `check` - If true, report files that need formatting without writing them
+///
+/// # Errors
+/// Returns `CliError::FormatFailed` if a file contains a syntax error, and
+/// `CliError::UnformattedFiles` in check mode when any file would change.
+pub fn run(settings: &Settings, check: bool) -> Result<(), CliError> {
+    let root = &settings.directory;
+    let mut files = Vec::new();
+    for dir in SOURCE_DIRS {
+        collect_sql_files(&root.join(dir), &mut files)?;
+    }
+    files.sort();
+
+    let mut changed = Vec::new();
+    let mut unchanged = 0;
+    for path in files {
+        let relative = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+        let original = std::fs::read_to_string(&path)?;
+        let formatted = format_sql(&original).map_err(|e| CliError::FormatFailed {
+            path: relative.display().to_string(),
+            message: e.to_string(),
+        })?;
+        if formatted == original {
+            unchanged += 1;
+            continue;
+        }
+
+        let diff = if check {
+            let name = relative.display().to_string();
+            TextDiff::from_lines(&original, &formatted)
+                .unified_diff()
+                .header(&format!("a/{name}"), &format!("b/{name}"))
+                .to_string()
+        } else {
+            std::fs::write(&path, &formatted)?;
+            String::new()
+        };
+        changed.push(ChangedFile {
+            path: relative,
+            diff,
+        });
+    }
+
+    let count = changed.len();
+    log::output(&FmtResult {
+        check,
+        changed,
+        unchanged,
+    });
+    if check && count > 0 {
+        return Err(CliError::UnformattedFiles { count });
+    }
+    Ok(())
+}
+
+/// Recursively collect `.sql` files under `dir`. Missing directories are
+/// skipped, since every source directory is optional.
+fn collect_sql_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), CliError> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(CliError::Io(e)),
+    };
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_sql_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "sql") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn collects_sql_files_recursively() {
+        let dir = tempfile::tempdir().unwrap();
+        let schema = dir.path().join("models/db/public");
+        std::fs::create_dir_all(&schema).unwrap();
+        std::fs::write(schema.join("v.sql"), "").unwrap();
+        std::fs::write(schema.join("notes.md"), "").unwrap();
+        std::fs::write(dir.path().join("models/db/public.sql"), "").unwrap();
+
+        let mut files = Vec::new();
+        collect_sql_files(&dir.path().join("models"), &mut files).unwrap();
+        collect_sql_files(&dir.path().join("roles"), &mut files).unwrap();
+        files.sort();
+        assert_eq!(
+            files,
+            vec![
+                dir.path().join("models/db/public.sql"),
+                schema.join("v.sql"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Graph command — export the project's lineage graph.
+//!
+//! Compiles the project and renders its dependency graph (objects, indexes,
+//! clusters, and external dependencies) as Graphviz DOT, Mermaid, or JSON on
+//! stdout. Unless `--offline` is given, the compiled project is compared
+//! against the last promoted deployment and every object the next `stage`
+//! would redeploy is highlighted, so reviewers can see the blast radius of a
+//! change.
+
+use crate::cli::{CliError, progress};
+use crate::client::Client;
+use crate::config::Settings;
+use crate::log;
+use crate::project::analysis::changeset::ChangeSet;
+use crate::project::analysis::deployment_snapshot;
+use crate::project::analysis::lineage::{DirtySet, Focus, LineageGraph};
+use crate::project::ir::graph::Project;
+use crate::project::ir::object_id::ObjectId;
+use std::collections::BTreeSet;
+use std::time::Instant;
+
+/// Output format for the rendered graph.
+#[derive(Debug, Clone, Copy)]
+pub enum Format {
+    Dot,
+    Mermaid,
+    Json,
+}
+
+/// Render the project's lineage graph to stdout.
+///
+/// # Arguments
+/// * `settings` - Resolved project configuration
+/// * `format` - Output format; `--output json` always selects JSON
+/// * `focus` - Optional `database.schema.object` to center the graph on
+/// * `upstream` / `downstream` - Hop limits around `focus`; `None` is unbounded
+/// * `offline` - Skip comparing against the last promoted deployment
+///
+/// # Errors
+/// Returns `CliError::Message` if `focus` is malformed or not part of the
+/// project, and `CliError::Connection` for database errors.
+pub async fn run(
+    settings: &Settings,
+    format: Format,
+    focus: Option<&str>,
+    upstream: Option<usize>,
+    downstream: Option<usize>,
+    offline: bool,
+) -> Result<(), CliError> {
+    let focus = focus
+        .map(|root| {
+            root.parse::<ObjectId>().map(|root| Focus {
+                root,
+                upstream,
+                downstream,
+            })
+        })
+        .transpose()
+        .map_err(CliError::Message)?;
+
+    let project = super::compile::run(settings, true).await?;
+
+    let dirty = if offline {
+        DirtySet::default()
+    } else {
+        let client = Client::connect_with_profile(settings.connection().clone())
+            .await
+            .map_err(CliError::Connection)?;
+        super::setup::verify(&client, settings.emulator()).await?;
+        super::setup::validate_connection(&client, settings.emulator()).await?;
+        compute_dirty(&client, &project).await?
+    };
+
+    let Some(graph) = LineageGraph::build(&project, &dirty, focus.as_ref()) else {
+        // `build` only fails when the focus root is not part of the project.
+        let root = focus.map(|f| f.root.to_string()).unwrap_or_default();
+        return Err(CliError::Message(format!("object '{}' not found in project", root)));
+    };
+
+    if log::json_output_enabled() {
+        log::output_json(&graph);
+        return Ok(());
+    }
+    match format {
+        Format::Dot => log::print_document(&graph.to_dot()),
+        Format::Mermaid => log::print_document(&graph.to_mermaid()),
+        Format::Json => log::output_json(&graph),
+    }
+    Ok(())
+}
+
+/// Compute the objects and clusters the next deployment would redeploy.
+///
+/// Mirrors the analysis `stage` performs: with no promoted deployment, every
+/// object and cluster is new; otherwise the changeset against production
+/// decides.
+async fn compute_dirty(client: &Client, project: &Project) -> Result<DirtySet, CliError> {
+    progress::stage_start("Comparing against production");
+    let start = Instant::now();
+
+    let new_snapshot = deployment_snapshot::build_snapshot_from_planned(project)?;
+    let production_snapshot = deployment_snapshot::load_from_database(client, None).await?;
+
+    let dirty = if production_snapshot.objects.is_empty() {
+        DirtySet {
+            objects: project.iter_objects().map(|o| o.id.clone()).collect(),
+            clusters: project
+                .iter_objects()
+                .flat_map(|o| o.typed_object.clusters())
+                .collect(),
+        }
+    } else {
+        let change_set = ChangeSet::from_deployment_snapshot_comparison(
+            &production_snapshot,
+            &new_snapshot,
+            project,
+            &BTreeSet::new(),
+        );
+        DirtySet {
+            objects: change_set.objects_to_deploy,
+            clusters: change_set
+                .dirty_clusters
+                .into_iter()
+                .map(|c| c.name)
+                .collect(),
+        }
+    };
+
+    progress::stage_success(
+        &format!("{} objects would be redeployed", dirty.objects.len()),
+        start.elapsed(),
+    );
+    Ok(dirty)
+}
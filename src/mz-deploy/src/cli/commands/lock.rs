@@ -15,14 +15,25 @@
 //! `CREATE TABLE FROM SOURCE` tables via full project compilation (a
 //! lightweight syntax-only path is a planned follow-up). Hard-errors if any
 //! declared dependency does not exist in the target database.
+//!
+//! Also records the promoted output columns of the project's *contract
+//! objects* — sink inputs and objects read from outside the project — as the
+//! baseline for breaking-change detection in `compile` and the LSP. Contract
+//! objects that have not been promoted yet are skipped. `promote` refreshes
+//! this baseline after every successful promotion via [`record_contracts`].
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::time::Instant;
 
 use crate::cli::CliError;
 use crate::cli::progress;
 use crate::client::Client;
 use crate::config::Settings;
+use crate::project::SchemaQualifier;
+use crate::project::analysis::breaking;
+use crate::project::ir::graph::Project;
 use crate::project::ir::object_id::ObjectId;
+use crate::types::{ColumnType, Types};
 
 /// Resolve declared dependencies into a types.lock file.
 pub async fn run(settings: &Settings) -> Result<(), CliError> {
@@ -35,9 +46,13 @@ pub async fn run(settings: &Settings) -> Result<(), CliError> {
 
     // Discover source tables via compilation (pragmatic first step;
     // lightweight syntax-only extraction is a follow-up optimization)
-    let source_tables = discover_source_tables(settings)?;
+    let project = plan_project(settings)?;
+    let source_tables: Vec<ObjectId> = project.get_tables_from_source().collect();
 
-    if settings.dependencies.is_empty() && source_tables.is_empty() {
+    if settings.dependencies.is_empty()
+        && source_tables.is_empty()
+        && project.iter_objects().next().is_none()
+    {
         progress::finished("lock", start.elapsed());
         return Ok(());
     }
@@ -52,7 +67,7 @@ pub async fn run(settings: &Settings) -> Result<(), CliError> {
     // catalog query. Missing objects (those not in the target catalog) are
     // surfaced as DeclaredDependenciesMissing with a user-friendly hint.
     let declared: Vec<ObjectId> = settings.dependencies.iter().cloned().collect();
-    let (types, missing) = client
+    let (mut types, missing) = client
         .types()
         .query_types_for_objects(&declared, &source_tables)
         .await
@@ -62,6 +77,8 @@ pub async fn run(settings: &Settings) -> Result<(), CliError> {
         return Err(CliError::DeclaredDependenciesMissing { missing });
     }
 
+    types.contracts = query_contracts(&client, &project).await?;
+
     types.write_types_lock(directory)?;
 
     progress::finished(
@@ -72,8 +89,48 @@ pub async fn run(settings: &Settings) -> Result<(), CliError> {
     Ok(())
 }
 
-/// Discover CREATE TABLE FROM SOURCE tables by compiling the project.
-fn discover_source_tables(settings: &Settings) -> Result<Vec<ObjectId>, CliError> {
+/// Replace the contract baseline in `types.lock` with the output columns the
+/// project's contract objects have in production.
+///
+/// Run by `promote` once the swap succeeded, so that `compile` compares
+/// against what was last promoted rather than against whenever `lock` last
+/// ran. The rest of `types.lock` is left unchanged.
+pub(crate) async fn record_contracts(settings: &Settings, client: &Client) -> Result<(), CliError> {
+    let directory = &settings.directory;
+    let project = plan_project(settings)?;
+    let mut types = if directory.join("types.lock").exists() {
+        crate::types::load_types_lock(directory)?
+    } else {
+        Types::default()
+    };
+    types.contracts = query_contracts(client, &project).await?;
+    types.write_types_lock(directory)?;
+    Ok(())
+}
+
+/// Query the production output columns of the project's contract objects.
+///
+/// Contract objects that are missing from the catalog have never been
+/// promoted, so they have no consumers to break yet and are left out.
+async fn query_contracts(
+    client: &Client,
+    project: &Project,
+) -> Result<BTreeMap<ObjectId, BTreeMap<String, ColumnType>>, CliError> {
+    let contracts: Vec<ObjectId> = contract_objects(client, project)
+        .await?
+        .into_iter()
+        .collect();
+    let (contract_types, _) = client
+        .types()
+        .query_types_for_objects(&contracts, &[])
+        .await
+        .map_err(CliError::Connection)?;
+    Ok(contract_types.tables)
+}
+
+/// Plan the project without typechecking, to discover source tables and
+/// contract objects.
+fn plan_project(settings: &Settings) -> Result<Project, CliError> {
     let fs = crate::fs::FileSystem::new();
     let planned = crate::project::plan_sync(
         &fs,
@@ -82,5 +139,37 @@ fn discover_source_tables(settings: &Settings) -> Result<Vec<ObjectId>, CliError
         settings.profile_suffix(),
         settings.variables(),
     )?;
-    Ok(planned.get_tables_from_source().collect())
+    Ok(planned)
+}
+
+/// Project objects whose output columns are consumed by something the
+/// project does not redeploy: its own sinks, or catalog objects outside the
+/// project's schemas.
+pub(crate) async fn contract_objects(
+    client: &Client,
+    project: &Project,
+) -> Result<BTreeSet<ObjectId>, CliError> {
+    let schemas: Vec<SchemaQualifier> = project
+        .databases
+        .iter()
+        .flat_map(|db| {
+            db.schemas
+                .iter()
+                .map(|schema| SchemaQualifier::new(db.name.clone(), schema.name.clone()))
+        })
+        .collect();
+
+    let consumed = client
+        .introspection()
+        .find_external_consumers(&schemas)
+        .await
+        .map_err(CliError::Connection)?;
+
+    let mut contracts = breaking::sink_inputs(project);
+    contracts.extend(
+        consumed
+            .into_iter()
+            .filter(|id| project.find_object(id).is_some()),
+    );
+    Ok(contracts)
 }
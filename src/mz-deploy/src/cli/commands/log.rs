@@ -47,6 +47,14 @@ impl fmt::Display for HistoryOutput {
                     .to_string()
                     .if_supports_color(Stream::Stderr, |t| t.dimmed())
             )?;
+            if let Some(target) = &entry.rollback_of {
+                writeln!(
+                    f,
+                    "{}: {}",
+                    "Rollback of".if_supports_color(Stream::Stderr, |t| t.dimmed()),
+                    target.if_supports_color(Stream::Stderr, |t| t.cyan())
+                )?;
+            }
             if let Some(commit_sha) = &entry.git_commit {
                 writeln!(
                     f,
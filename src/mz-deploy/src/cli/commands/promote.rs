@@ -441,6 +441,9 @@ async fn generate_deployment_plan(
 }
 
 /// Promote a staging deployment to production using ALTER SWAP.
+///
+/// After a successful promotion, the output columns of the promoted contract
+/// objects are recorded in `types.lock` as the new breaking-change baseline.
 pub async fn run(
     settings: &Settings,
     deploy_id: &str,
@@ -494,6 +497,15 @@ pub async fn run(
     maybe_crash("after-post-swap");
     cleanup_apply_state(&client, &plan.deploy_id).await?;
 
+    // The swap is committed, so a failure to refresh the breaking-change
+    // baseline must not fail the promotion.
+    if let Err(err) = super::lock::record_contracts(settings, &client).await {
+        progress::warn(&format!(
+            "failed to record contract objects in types.lock: {}; run `mz-deploy lock` to refresh it",
+            err
+        ));
+    }
+
     progress::success("Deployment completed successfully!");
 
     Ok(())
@@ -0,0 +1,722 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Rollback command - restore the state of an earlier promoted deployment.
+//!
+//! Rollback does not need the project source. Every `stage` records the
+//! production-name definitions of the objects it deploys in
+//! `_mz_deploy.tables.object_statements`; rollback replays those definitions
+//! into a fresh staging deployment, waits for it to hydrate, and promotes it
+//! through [`super::promote`], so the swap gets the same readiness and
+//! conflict checks as any other promotion.
+//!
+//! The new deployment copies the target's object hashes and git commit, so
+//! the next `stage` diffs the project against the restored state. The link
+//! between the two is recorded in `_mz_deploy.tables.rollbacks` and shown by
+//! `mz-deploy log`.
+
+use super::ObjectRef;
+use super::stage::{
+    collect_stage_resources, create_staging_clusters, deploy_single_object,
+    rollback_staging_resources, validate_stage_name,
+};
+use crate::cli::CliError;
+use crate::cli::executor::{self, DeploymentExecutor};
+use crate::cli::progress;
+use crate::client::{
+    AssertionRecord, Client, DeploymentHistoryEntry, DeploymentKind, DeploymentMode,
+    ObjectStatement, PendingStatement,
+};
+use crate::config::Settings;
+use crate::log;
+use crate::project::SchemaQualifier;
+use crate::project::analysis::deployment_snapshot::{self, DeploymentSnapshot};
+use crate::project::ast::Statement;
+use crate::project::ir::compiled::DatabaseObject;
+use crate::project::ir::object_id::ObjectId;
+use crate::project::resolve::normalize;
+use crate::verbose;
+use mz_sql_parser::ast::{Raw, Statement as ParserStatement};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Instant;
+
+/// Summary returned after a successful rollback, used for terminal output
+/// and `--json`.
+#[derive(serde::Serialize)]
+struct RollbackResult {
+    deploy_id: String,
+    target_deploy_id: String,
+    objects_restored: usize,
+    #[serde(skip)]
+    duration: std::time::Duration,
+}
+
+impl fmt::Display for RollbackResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "  \u{2713} Restored {} objects from deployment '{}' as '{}' ({:.1}s)",
+            self.objects_restored,
+            self.target_deploy_id,
+            self.deploy_id,
+            self.duration.as_secs_f64()
+        )
+    }
+}
+
+#[derive(serde::Serialize)]
+struct RollbackPlan {
+    deploy_id: String,
+    target_deploy_id: String,
+    schemas: Vec<SchemaQualifier>,
+    clusters: Vec<String>,
+    objects: Vec<String>,
+    sinks: Vec<String>,
+}
+
+impl fmt::Display for RollbackPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Rollback plan for '{}' (restores '{}'):",
+            self.deploy_id, self.target_deploy_id
+        )?;
+
+        if !self.schemas.is_empty() {
+            writeln!(f, "\nSchemas ({}):", self.schemas.len())?;
+            for sq in &self.schemas {
+                writeln!(f, "    {}.{}", sq.database, sq.schema)?;
+            }
+        }
+
+        if !self.clusters.is_empty() {
+            writeln!(f, "\nClusters ({}):", self.clusters.len())?;
+            for c in &self.clusters {
+                writeln!(f, "    {}", c)?;
+            }
+        }
+
+        if !self.objects.is_empty() {
+            writeln!(f, "\nObjects ({}):", self.objects.len())?;
+            for o in &self.objects {
+                writeln!(f, "    {}", o)?;
+            }
+        }
+
+        if !self.sinks.is_empty() {
+            writeln!(f, "\nSinks ({}):", self.sinks.len())?;
+            for s in &self.sinks {
+                writeln!(f, "    {}", s)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Object definitions rebuilt from a deployment's recorded statements.
+#[derive(Debug)]
+struct RestoredState {
+    /// Views and materialized views, in recorded (dependency) order.
+    objects: Vec<(ObjectId, DatabaseObject)>,
+    /// Sinks, created after the swap like any staged sink.
+    sinks: Vec<(ObjectId, DatabaseObject)>,
+    /// Schema setup statements, keyed by the schema they belong to.
+    schema_statements: Vec<(SchemaQualifier, ParserStatement<Raw>)>,
+}
+
+/// Restore the state recorded for an earlier promoted deployment.
+///
+/// Stages the target's recorded objects under a new deploy ID, waits for the
+/// staging clusters to hydrate, and promotes the result.
+///
+/// # Arguments
+/// * `settings` - Resolved CLI settings (profile, project directory, etc.)
+/// * `target` - Promoted deployment to restore; defaults to the most recent
+///   earlier deployment that touched a schema of the latest one
+/// * `deploy_id` - Optional ID for the rollback deployment
+/// * `force` - Skip conflict detection when promoting
+/// * `timeout` - Maximum seconds to wait for hydration
+/// * `allowed_lag_secs` - Lag threshold for the readiness check
+/// * `dry_run` - Print the rollback plan without changing anything
+///
+/// # Errors
+/// Returns `CliError::NoRollbackTarget`, `CliError::RollbackTargetNotPromoted`,
+/// or `CliError::RollbackUnavailable` if the target cannot be restored, and
+/// surfaces staging, readiness, and promotion errors unchanged.
+pub async fn run(
+    settings: &Settings,
+    target: Option<&str>,
+    deploy_id: Option<&str>,
+    force: bool,
+    timeout: Option<u64>,
+    allowed_lag_secs: i64,
+    dry_run: bool,
+) -> Result<(), CliError> {
+    let start_time = Instant::now();
+    let client = Client::connect_with_profile(settings.connection().clone())
+        .await
+        .map_err(CliError::Connection)?;
+
+    super::setup::verify(&client, settings.emulator()).await?;
+    let role = super::setup::validate_connection(&client, settings.emulator()).await?;
+    super::setup::require_deployer(role)?;
+
+    let target = match target {
+        Some(target) => target.to_string(),
+        None => {
+            let history = client.deployments().list_deployment_history(None).await?;
+            previous_deployment(&history)
+                .ok_or(CliError::NoRollbackTarget)?
+                .deploy_id
+                .clone()
+        }
+    };
+    let details = client
+        .deployments()
+        .get_deployment_details(&target)
+        .await?
+        .ok_or_else(|| CliError::RollbackUnavailable {
+            deploy_id: target.clone(),
+            reason: "no such deployment".to_string(),
+        })?;
+    if details.promoted_at.is_none() {
+        return Err(CliError::RollbackTargetNotPromoted { deploy_id: target });
+    }
+
+    let snapshot = deployment_snapshot::load_from_database(&client, Some(&target)).await?;
+    if snapshot
+        .schemas
+        .values()
+        .any(|kind| *kind == DeploymentKind::Replacement)
+    {
+        return Err(CliError::RollbackUnavailable {
+            deploy_id: target,
+            reason: "it updated materialized views in place, which cannot be swapped back"
+                .to_string(),
+        });
+    }
+
+    let statements = client.deployments().get_object_statements(&target).await?;
+    let restored = restore_objects(&statements)
+        .and_then(|restored| check_complete(&restored, &snapshot).map(|()| restored))
+        .map_err(|reason| CliError::RollbackUnavailable {
+            deploy_id: target.clone(),
+            reason,
+        })?;
+
+    let deploy_id = deploy_id
+        .map(str::to_string)
+        .unwrap_or_else(executor::generate_random_env_name);
+    validate_stage_name(&deploy_id)?;
+    let staging_suffix = format!("_{}", deploy_id);
+
+    let object_refs: Vec<ObjectRef> = restored
+        .objects
+        .iter()
+        .map(|(id, obj)| (id.clone(), obj))
+        .collect();
+    let (schema_set, cluster_set) = collect_stage_resources(&object_refs, &[]);
+
+    if dry_run {
+        log::output(&RollbackPlan {
+            deploy_id,
+            target_deploy_id: target,
+            schemas: schema_set.into_iter().collect(),
+            clusters: cluster_set.into_iter().collect(),
+            objects: restored
+                .objects
+                .iter()
+                .map(|(id, _)| id.to_string())
+                .collect(),
+            sinks: restored
+                .sinks
+                .iter()
+                .map(|(id, _)| id.to_string())
+                .collect(),
+        });
+        return Ok(());
+    }
+
+    let staged = async {
+        record_rollback_metadata(
+            &client,
+            settings,
+            &deploy_id,
+            &target,
+            details.git_commit.clone(),
+            &snapshot,
+            &statements,
+            &restored,
+        )
+        .await?;
+        stage_restored_objects(
+            &client,
+            &deploy_id,
+            &staging_suffix,
+            &restored,
+            &object_refs,
+            &schema_set,
+            &cluster_set,
+        )
+        .await
+    }
+    .await;
+    if let Err(e) = staged {
+        progress::error("Rollback failed, cleaning up...");
+        rollback_staging_resources(&client, &deploy_id).await;
+        return Err(e);
+    }
+
+    super::wait::run(settings, &deploy_id, false, timeout, allowed_lag_secs).await?;
+    super::promote::run(settings, &deploy_id, force, false).await?;
+
+    log::output(&RollbackResult {
+        deploy_id: deploy_id.clone(),
+        target_deploy_id: target,
+        objects_restored: restored.objects.len() + restored.sinks.len(),
+        duration: start_time.elapsed(),
+    });
+    log::print_deploy_id(&deploy_id);
+    Ok(())
+}
+
+/// Picks the deployment a bare `rollback` restores: the most recent promoted
+/// deployment before the latest one that touched any of the latest one's
+/// schemas.
+///
+/// `history` is ordered newest first, as returned by
+/// `list_deployment_history`.
+fn previous_deployment(history: &[DeploymentHistoryEntry]) -> Option<&DeploymentHistoryEntry> {
+    let (latest, earlier) = history.split_first()?;
+    let latest_schemas: BTreeSet<_> = latest.schemas.iter().collect();
+    earlier
+        .iter()
+        .find(|entry| entry.schemas.iter().any(|sq| latest_schemas.contains(sq)))
+}
+
+/// Rebuild object definitions from recorded statements.
+///
+/// Statements are grouped by object in recording order; indexes, grants, and
+/// comments attach to the object recorded before them.
+fn restore_objects(statements: &[ObjectStatement]) -> Result<RestoredState, String> {
+    if statements.is_empty() {
+        return Err(
+            "no object definitions were recorded for it (it was staged by an older mz-deploy)"
+                .to_string(),
+        );
+    }
+
+    let mut objects: Vec<(ObjectId, DatabaseObject)> = Vec::new();
+    let mut positions: BTreeMap<ObjectId, usize> = BTreeMap::new();
+    let mut schema_statements = Vec::new();
+
+    for recorded in statements {
+        let ast = parse_recorded(&recorded.statement_sql)?;
+        if recorded.statement_kind == "schema" {
+            let sq = SchemaQualifier::new(recorded.database.clone(), recorded.schema.clone());
+            schema_statements.push((sq, ast));
+            continue;
+        }
+
+        let id = ObjectId::new(
+            recorded.database.clone(),
+            recorded.schema.clone(),
+            recorded.object.clone(),
+        );
+        if recorded.statement_kind == "object" {
+            let stmt = match ast {
+                ParserStatement::CreateView(s) => Statement::CreateView(s),
+                ParserStatement::CreateMaterializedView(s) => Statement::CreateMaterializedView(s),
+                ParserStatement::CreateSink(s) => Statement::CreateSink(s),
+                other => return Err(format!("unexpected definition for {}: {}", id, other)),
+            };
+            positions.insert(id.clone(), objects.len());
+            objects.push((
+                id,
+                DatabaseObject {
+                    path: PathBuf::new(),
+                    stmt,
+                    indexes: vec![],
+                    grants: vec![],
+                    comments: vec![],
+                    tests: vec![],
+                },
+            ));
+            continue;
+        }
+
+        let Some(&pos) = positions.get(&id) else {
+            return Err(format!(
+                "{} is recorded before {}",
+                recorded.statement_kind, id
+            ));
+        };
+        let object = &mut objects[pos].1;
+        match ast {
+            ParserStatement::CreateIndex(s) => object.indexes.push(s),
+            ParserStatement::GrantPrivileges(s) => object.grants.push(s),
+            ParserStatement::Comment(s) => object.comments.push(s),
+            other => return Err(format!("unexpected statement for {}: {}", id, other)),
+        }
+    }
+
+    let (sinks, objects) = objects
+        .into_iter()
+        .partition(|(_, obj)| matches!(obj.stmt, Statement::CreateSink(_)));
+    Ok(RestoredState {
+        objects,
+        sinks,
+        schema_statements,
+    })
+}
+
+/// Parse a single recorded statement.
+fn parse_recorded(sql: &str) -> Result<ParserStatement<Raw>, String> {
+    let mut parsed = mz_sql_parser::parser::parse_statements(sql)
+        .map_err(|e| format!("recorded statement does not parse: {}", e.error))?;
+    match (parsed.pop(), parsed.is_empty()) {
+        (Some(stmt), true) => Ok(stmt.ast),
+        _ => Err(format!("expected one recorded statement, found: {}", sql)),
+    }
+}
+
+/// Verify that every object in the target's snapshot has a definition, so the
+/// swap cannot silently drop objects the target deployed.
+fn check_complete(restored: &RestoredState, snapshot: &DeploymentSnapshot) -> Result<(), String> {
+    let recorded: BTreeSet<&ObjectId> = restored
+        .objects
+        .iter()
+        .chain(&restored.sinks)
+        .map(|(id, _)| id)
+        .collect();
+    match snapshot.objects.keys().find(|id| !recorded.contains(id)) {
+        Some(missing) => Err(format!("no definition was recorded for {}", missing)),
+        None => Ok(()),
+    }
+}
+
+/// Record the rollback deployment: the target's snapshot and commit, its
+/// object definitions and assertions under the new ID, deferred sink
+/// statements, and the link back to the target.
+#[allow(clippy::too_many_arguments)]
+async fn record_rollback_metadata(
+    client: &Client,
+    settings: &Settings,
+    deploy_id: &str,
+    target: &str,
+    git_commit: Option<String>,
+    snapshot: &DeploymentSnapshot,
+    statements: &[ObjectStatement],
+    restored: &RestoredState,
+) -> Result<(), CliError> {
+    progress::stage_start("Recording deployment metadata");
+    let metadata_start = Instant::now();
+
+    let mut metadata = executor::collect_deployment_metadata(client, &settings.directory).await;
+    metadata.git_commit = git_commit;
+    deployment_snapshot::write_to_database(
+        client,
+        snapshot,
+        deploy_id,
+        &metadata,
+        None,
+        DeploymentMode::Stage,
+    )
+    .await?;
+
+    let copied: Vec<ObjectStatement> = statements
+        .iter()
+        .map(|stmt| ObjectStatement {
+            deploy_id: deploy_id.to_string(),
+            ..stmt.clone()
+        })
+        .collect();
+    client
+        .deployments()
+        .insert_object_statements(&copied)
+        .await?;
+
+    // The rebuilt objects are the target's, so its assertions still apply.
+    let assertions: Vec<AssertionRecord> = client
+        .deployments()
+        .get_assertions(target)
+        .await?
+        .into_iter()
+        .map(|record| AssertionRecord {
+            deploy_id: deploy_id.to_string(),
+            ..record
+        })
+        .collect();
+    if !assertions.is_empty() {
+        client.deployments().insert_assertions(&assertions).await?;
+    }
+
+    let pending_statements: Vec<PendingStatement> = restored
+        .sinks
+        .iter()
+        .enumerate()
+        .map(|(idx, (object_id, sink))| {
+            #[allow(clippy::as_conversions)]
+            PendingStatement {
+                deploy_id: deploy_id.to_string(),
+                sequence_num: idx as i32,
+                database: object_id.expect_database().to_string(),
+                schema: object_id.schema().to_string(),
+                object: object_id.object().to_string(),
+                object_hash: snapshot.objects.get(object_id).cloned().unwrap_or_default(),
+                statement_sql: sink.stmt.to_string(),
+                statement_kind: "sink".to_string(),
+                executed_at: None,
+            }
+        })
+        .collect();
+    client
+        .deployments()
+        .insert_pending_statements(&pending_statements)
+        .await?;
+
+    client
+        .deployments()
+        .insert_rollback(deploy_id, target)
+        .await?;
+
+    progress::stage_success("Deployment metadata recorded", metadata_start.elapsed());
+    Ok(())
+}
+
+/// Create staging schemas and clusters for the restored objects and deploy
+/// them, mirroring `stage`.
+///
+/// Indexes that other objects keep on the staged clusters are copied from
+/// the catalog, since the swap replaces those clusters wholesale.
+async fn stage_restored_objects(
+    client: &Client,
+    deploy_id: &str,
+    staging_suffix: &str,
+    restored: &RestoredState,
+    object_refs: &[ObjectRef<'_>],
+    schema_set: &BTreeSet<SchemaQualifier>,
+    cluster_set: &BTreeSet<String>,
+) -> Result<(), CliError> {
+    let executor = DeploymentExecutor::new(client);
+
+    progress::stage_start("Creating staging schemas and applying setup statements");
+    let schema_start = Instant::now();
+    for sq in schema_set {
+        executor.ensure_database(&sq.database).await?;
+        executor
+            .ensure_schema(&sq.database, &format!("{}{}", sq.schema, staging_suffix))
+            .await?;
+        executor.ensure_schema(&sq.database, &sq.schema).await?;
+    }
+    for (sq, statement) in &restored.schema_statements {
+        if schema_set.contains(sq) {
+            let mut rewritten = statement.clone();
+            normalize::rewrite_schema_names(
+                std::slice::from_mut(&mut rewritten),
+                &sq.schema,
+                staging_suffix,
+            );
+            executor.execute_sql(&rewritten).await?;
+        }
+    }
+    progress::stage_success(
+        &format!(
+            "Created {} staging schema(s) with setup statements",
+            schema_set.len()
+        ),
+        schema_start.elapsed(),
+    );
+
+    create_staging_clusters(&executor, client, deploy_id, cluster_set, staging_suffix).await?;
+
+    progress::stage_start("Deploying objects to staging");
+    let deploy_start = Instant::now();
+    let objects_to_deploy: BTreeSet<ObjectId> =
+        object_refs.iter().map(|(id, _)| id.clone()).collect();
+
+    let clusters: Vec<String> = cluster_set.iter().cloned().collect();
+    for index in client
+        .introspection()
+        .get_cluster_indexes(&clusters)
+        .await?
+    {
+        let on_schema = SchemaQualifier::new(
+            index.on.expect_database().to_string(),
+            index.on.schema().to_string(),
+        );
+        if schema_set.contains(&on_schema) {
+            continue;
+        }
+        let ParserStatement::CreateIndex(stmt) = parse_recorded(&index.create_sql)
+            .map_err(|reason| CliError::Message(format!("index on {}: {}", index.on, reason)))?
+        else {
+            continue;
+        };
+        let mut indexes = vec![stmt];
+        normalize::transform_cluster_names_for_staging(&mut indexes, staging_suffix);
+        for index in indexes {
+            verbose!("Creating external index {}", index);
+            executor.execute_sql(&index).await?;
+        }
+    }
+
+    let no_ids = BTreeSet::new();
+    for (idx, (object_id, obj)) in object_refs.iter().enumerate() {
+        verbose!(
+            "Applying {}/{}: {}{} (to schema {}{})",
+            idx + 1,
+            object_refs.len(),
+            object_id.object(),
+            staging_suffix,
+            object_id.schema(),
+            staging_suffix
+        );
+        deploy_single_object(
+            &executor,
+            object_id,
+            obj,
+            staging_suffix,
+            &no_ids,
+            &objects_to_deploy,
+            &no_ids,
+            |stmt| stmt,
+        )
+        .await?;
+    }
+    progress::stage_success(
+        &format!(
+            "Deployed {} view(s)/materialized view(s)",
+            object_refs.len()
+        ),
+        deploy_start.elapsed(),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn entry(deploy_id: &str, schemas: &[&str]) -> DeploymentHistoryEntry {
+        DeploymentHistoryEntry {
+            deploy_id: deploy_id.to_string(),
+            promoted_at: Utc::now(),
+            deployed_by: "deployer".to_string(),
+            git_commit: None,
+            kind: DeploymentKind::Objects,
+            schemas: schemas
+                .iter()
+                .map(|s| SchemaQualifier::new("db".to_string(), s.to_string()))
+                .collect(),
+            rollback_of: None,
+        }
+    }
+
+    fn recorded(seq: i32, object: &str, kind: &str, sql: &str) -> ObjectStatement {
+        ObjectStatement {
+            deploy_id: "d1".to_string(),
+            sequence_num: seq,
+            database: "db".to_string(),
+            schema: "public".to_string(),
+            object: object.to_string(),
+            statement_kind: kind.to_string(),
+            statement_sql: sql.to_string(),
+        }
+    }
+
+    #[mz_ore::test]
+    fn previous_deployment_skips_unrelated_schemas() {
+        let history = vec![
+            entry("latest", &["a"]),
+            entry("other", &["b"]),
+            entry("prior", &["a", "b"]),
+        ];
+        assert_eq!(previous_deployment(&history).unwrap().deploy_id, "prior");
+        assert!(previous_deployment(&history[..2]).is_none());
+        assert!(previous_deployment(&[]).is_none());
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn restore_objects_groups_statements_by_object() {
+        let statements = vec![
+            recorded(0, "v", "object", "CREATE VIEW db.public.v AS SELECT 1 AS x"),
+            recorded(
+                1,
+                "v",
+                "index",
+                "CREATE INDEX v_idx IN CLUSTER c ON db.public.v (x)",
+            ),
+            recorded(2, "v", "grant", "GRANT SELECT ON db.public.v TO reader"),
+            recorded(
+                3,
+                "s",
+                "object",
+                "CREATE SINK db.public.s IN CLUSTER c FROM db.public.v \
+                 INTO KAFKA CONNECTION db.public.k (TOPIC 't') FORMAT JSON ENVELOPE DEBEZIUM",
+            ),
+            recorded(4, "", "schema", "GRANT USAGE ON SCHEMA db.public TO reader"),
+        ];
+
+        let restored = restore_objects(&statements).unwrap();
+        assert_eq!(restored.objects.len(), 1);
+        let (id, view) = &restored.objects[0];
+        assert_eq!(id.object(), "v");
+        assert_eq!(view.indexes.len(), 1);
+        assert_eq!(view.grants.len(), 1);
+        assert_eq!(restored.sinks.len(), 1);
+        assert_eq!(restored.sinks[0].0.object(), "s");
+        assert_eq!(restored.schema_statements.len(), 1);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn restore_objects_rejects_missing_or_orphaned_statements() {
+        assert!(restore_objects(&[]).is_err());
+
+        let orphan = vec![recorded(
+            0,
+            "v",
+            "index",
+            "CREATE INDEX v_idx IN CLUSTER c ON db.public.v (x)",
+        )];
+        assert!(restore_objects(&orphan).is_err());
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn check_complete_requires_every_snapshot_object() {
+        let statements = vec![recorded(
+            0,
+            "v",
+            "object",
+            "CREATE VIEW db.public.v AS SELECT 1",
+        )];
+        let restored = restore_objects(&statements).unwrap();
+
+        let mut snapshot = DeploymentSnapshot::default();
+        snapshot.objects.insert(
+            ObjectId::new("db".into(), "public".into(), "v".into()),
+            "h1".to_string(),
+        );
+        assert!(check_complete(&restored, &snapshot).is_ok());
+
+        snapshot.objects.insert(
+            ObjectId::new("db".into(), "public".into(), "w".into()),
+            "h2".to_string(),
+        );
+        assert!(check_complete(&restored, &snapshot).is_err());
+    }
+}
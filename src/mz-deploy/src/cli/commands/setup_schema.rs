@@ -81,6 +81,31 @@ pub(super) const SETUP_STATEMENTS: &[&str] = &[
         target_name        TEXT NOT NULL,
         replacement_schema TEXT NOT NULL
     )"#,
+    // Production-name SQL for every staged object, replayed by `rollback`.
+    r#"CREATE TABLE IF NOT EXISTS _mz_deploy.tables.object_statements (
+        deploy_id      TEXT NOT NULL,
+        sequence_num   INT NOT NULL,
+        database       TEXT NOT NULL,
+        schema         TEXT NOT NULL,
+        object         TEXT NOT NULL,
+        statement_kind TEXT NOT NULL,
+        statement_sql  TEXT NOT NULL
+    )"#,
+    r#"CREATE INDEX IF NOT EXISTS object_statements_deploy_id_idx
+        IN CLUSTER _mz_deploy_server
+        ON _mz_deploy.tables.object_statements (deploy_id)"#,
+    r#"CREATE TABLE IF NOT EXISTS _mz_deploy.tables.rollbacks (
+        deploy_id        TEXT NOT NULL,
+        target_deploy_id TEXT NOT NULL
+    )"#,
+    // Data-quality assertions for every staged object, evaluated by `wait`.
+    r#"CREATE TABLE IF NOT EXISTS _mz_deploy.tables.assertions (
+        deploy_id TEXT NOT NULL,
+        database  TEXT NOT NULL,
+        schema    TEXT NOT NULL,
+        object    TEXT NOT NULL,
+        assertion TEXT NOT NULL
+    )"#,
     r#"CREATE TABLE IF NOT EXISTS _mz_deploy.tables.version (
         version BIGINT NOT NULL
     )"#,
@@ -150,6 +175,16 @@ pub(super) const SETUP_STATEMENTS: &[&str] = &[
     SELECT deploy_id, target_database, target_schema, target_name,
            replacement_schema
     FROM _mz_deploy.tables.replacement_mvs"#,
+    r#"CREATE VIEW IF NOT EXISTS _mz_deploy.public.object_statements AS
+    SELECT deploy_id, sequence_num, database, schema, object, statement_kind,
+           statement_sql
+    FROM _mz_deploy.tables.object_statements"#,
+    r#"CREATE VIEW IF NOT EXISTS _mz_deploy.public.rollbacks AS
+    SELECT deploy_id, target_deploy_id
+    FROM _mz_deploy.tables.rollbacks"#,
+    r#"CREATE VIEW IF NOT EXISTS _mz_deploy.public.assertions AS
+    SELECT deploy_id, database, schema, object, assertion
+    FROM _mz_deploy.tables.assertions"#,
     r#"CREATE VIEW IF NOT EXISTS _mz_deploy.public.version AS
     SELECT version
     FROM _mz_deploy.tables.version"#,
@@ -172,6 +207,10 @@ pub(super) const EXPECTED_OBJECTS: &[(&str, &str, &str)] = &[
     ("tables", "pending_statements", "table"),
     ("tables", "pending_statements_deploy_id_idx", "index"),
     ("tables", "replacement_mvs", "table"),
+    ("tables", "object_statements", "table"),
+    ("tables", "object_statements_deploy_id_idx", "index"),
+    ("tables", "rollbacks", "table"),
+    ("tables", "assertions", "table"),
     ("tables", "version", "table"),
     ("tables", "version_idx", "index"),
     ("tables", "dev_overlays", "table"),
@@ -188,6 +227,9 @@ pub(super) const EXPECTED_OBJECTS: &[(&str, &str, &str)] = &[
     ("public", "objects", "view"),
     ("public", "pending_statements", "view"),
     ("public", "replacement_mvs", "view"),
+    ("public", "object_statements", "view"),
+    ("public", "rollbacks", "view"),
+    ("public", "assertions", "view"),
     ("public", "version", "view"),
 ];
 
@@ -18,11 +18,14 @@ use crate::cli::executor::{self, DeploymentExecutor};
 use crate::cli::{git, progress};
 use crate::client::DeploymentMode;
 use crate::client::{
-    Client, ClusterConfig, ClusterOptions, DeploymentKind, PendingStatement, ReplacementMvRecord,
+    AssertionRecord, Client, ClusterConfig, ClusterOptions, DeploymentKind, ObjectStatement,
+    PendingStatement, ReplacementMvRecord,
 };
 use crate::config::Settings;
+use crate::fs::FileSystem;
 use crate::log;
-use crate::project::SchemaQualifier;
+use crate::project::analysis::assertions::{self, ObjectAssertion};
+use crate::project::analysis::breaking;
 use crate::project::analysis::changeset::ChangeSet;
 use crate::project::analysis::deployment_snapshot::{self, DeploymentSnapshot};
 use crate::project::analysis::deps::extract_external_indexes;
@@ -31,6 +34,8 @@ use crate::project::ir::compiled::{DatabaseObject, FullyQualifiedName};
 use crate::project::ir::graph::Project;
 use crate::project::ir::object_id::ObjectId;
 use crate::project::resolve::normalize::{self, NormalizingVisitor};
+use crate::project::{ModStatement, SchemaQualifier};
+use crate::types::Types;
 use crate::verbose;
 use mz_ore::option::OptionExt;
 use mz_sql_parser::ast::Ident;
@@ -39,7 +44,7 @@ use std::collections::BTreeSet;
 /// Reject a stage name long enough that appending the staging suffix
 /// `_<stage_name>` to a schema or cluster identifier would exceed the
 /// identifier length limit and panic during deploy.
-fn validate_stage_name(stage_name: &str) -> Result<(), CliError> {
+pub(super) fn validate_stage_name(stage_name: &str) -> Result<(), CliError> {
     // The suffix is appended to existing identifiers, so reserve headroom for
     // the base name rather than letting the suffix consume the whole limit.
     if stage_name.len() + 1 > Ident::MAX_LENGTH / 2 {
@@ -191,13 +196,16 @@ impl fmt::Display for StagePlan {
 /// * `allow_dirty` - Allow deploying with uncommitted changes
 /// * `no_rollback` - Skip automatic rollback on failure (for debugging)
 /// * `dry_run` - Print SQL instead of executing it
+/// * `allow_breaking` - Stage even if contract objects change incompatibly
 ///
 /// # Returns
 /// `Ok(())` if the deployment succeeds.
 ///
 /// # Errors
 /// Surfaces `CliError` variants from git checks, project compilation, and
-/// database execution.
+/// database execution. Returns `CliError::BreakingChanges` if a sink input or
+/// externally consumed object changed incompatibly relative to production.
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     settings: &Settings,
     stage_name: Option<&str>,
@@ -206,6 +214,7 @@ pub async fn run(
     dry_run: bool,
     redeploy_schemas: &[String],
     redeploy_all: bool,
+    allow_breaking: bool,
 ) -> Result<(), CliError> {
     let profile = settings.connection();
     let directory = &settings.directory;
@@ -221,7 +230,9 @@ pub async fn run(
         .unwrap_or_else(executor::generate_random_env_name);
     validate_stage_name(&stage_name)?;
 
-    let planned_project = super::compile::run(settings, true).await?;
+    let (planned_project, types) = super::compile::run_with_types(settings, true).await?;
+    let assertions = assertions::collect(&planned_project, directory, &FileSystem::new())
+        .map_err(CliError::InvalidAssertion)?;
     let staging_suffix = format!("_{}", stage_name);
 
     let client = Client::connect_with_profile(profile.clone())
@@ -233,6 +244,8 @@ pub async fn run(
         crate::cli::commands::setup::validate_connection(&client, settings.emulator()).await?;
     crate::cli::commands::setup::require_deployer(role)?;
 
+    check_breaking_changes(&client, &planned_project, &types, directory, allow_breaking).await?;
+
     let forced_dirty_schemas = resolve_redeploy_schemas(&planned_project, redeploy_schemas)?;
 
     let Some(analysis) = analyze_project_changes(
@@ -271,7 +284,8 @@ pub async fn run(
             &analysis.objects,
             &analysis.sinks,
             &analysis.replacement_mvs,
-            &planned_project.replacement_schemas,
+            &planned_project,
+            &assertions,
         )
         .await
         {
@@ -612,7 +626,7 @@ fn log_partition_summary(partitioned: &PartitionedObjects<'_>) {
 /// Builds schema and cluster sets solely from the objects being staged.
 /// Apply-managed objects (sources, tables, secrets, connections) are excluded
 /// by `partition_objects`, so their schemas and clusters are never staged.
-fn collect_stage_resources(
+pub(super) fn collect_stage_resources(
     objects: &[ObjectRef<'_>],
     replacement_mvs: &[ObjectRef<'_>],
 ) -> (BTreeSet<SchemaQualifier>, BTreeSet<String>) {
@@ -630,6 +644,44 @@ fn collect_stage_resources(
     (schema_set, cluster_set)
 }
 
+/// Compares the typed output of every contract object against its columns in
+/// production and rejects breaking changes.
+///
+/// Contract objects are sink inputs and objects read from outside the project.
+/// Objects not yet in production have no consumers and are skipped.
+async fn check_breaking_changes(
+    client: &Client,
+    planned_project: &Project,
+    types: &Types,
+    directory: &Path,
+    allow_breaking: bool,
+) -> Result<(), CliError> {
+    progress::stage_start("Checking for breaking changes");
+    let check_start = Instant::now();
+    let contracts: Vec<ObjectId> = super::lock::contract_objects(client, planned_project)
+        .await?
+        .into_iter()
+        .collect();
+    let (production, _) = client
+        .types()
+        .query_types_for_objects(&contracts, &[])
+        .await
+        .map_err(CliError::Connection)?;
+    let changes = breaking::detect(
+        planned_project,
+        directory,
+        &types.tables,
+        &production.tables,
+        &FileSystem::new(),
+    );
+    super::compile::check_breaking_changes(&changes, allow_breaking)?;
+    progress::stage_success(
+        &format!("{} contract objects checked", contracts.len()),
+        check_start.elapsed(),
+    );
+    Ok(())
+}
+
 /// Runs all preflight database validations required before mutating deployment state.
 ///
 /// This is intentionally isolated so stage fails before any metadata/resource writes.
@@ -674,7 +726,11 @@ async fn validate_project_for_stage(
 /// Persists stage deployment state and deferred apply actions.
 ///
 /// Records object hashes plus schema deployment kinds, then stores sink/replacement
-/// records that the `apply` command consumes after swap.
+/// records that the `apply` command consumes after swap. The production-name
+/// definitions of every staged object are stored too, so `rollback` can rebuild
+/// this deployment later without the project source. Assertions declared on
+/// staged objects are stored so `wait` and `promote` can evaluate them.
+#[allow(clippy::too_many_arguments)]
 async fn record_stage_metadata(
     client: &Client,
     directory: &Path,
@@ -683,7 +739,8 @@ async fn record_stage_metadata(
     objects: &[ObjectRef<'_>],
     sinks: &[ObjectRef<'_>],
     replacement_mvs: &[ObjectRef<'_>],
-    replacement_schemas: &BTreeSet<SchemaQualifier>,
+    planned_project: &Project,
+    assertions: &[ObjectAssertion],
 ) -> Result<(), CliError> {
     progress::stage_start("Recording deployment metadata");
     let metadata_start = Instant::now();
@@ -731,7 +788,7 @@ async fn record_stage_metadata(
     // During Objects→Replacement transitions, MVs go through the regular objects
     // path (for blue-green swap), but the metadata must reflect the final kind
     // so future deploys know to use CREATE REPLACEMENT.
-    for sq in replacement_schemas {
+    for sq in &planned_project.replacement_schemas {
         if staging_snapshot.schemas.contains_key(sq) {
             staging_snapshot
                 .schemas
@@ -749,6 +806,33 @@ async fn record_stage_metadata(
     )
     .await?;
 
+    let swapped_schemas: BTreeSet<SchemaQualifier> = staging_snapshot
+        .schemas
+        .iter()
+        .filter(|(_, kind)| **kind != DeploymentKind::Sinks)
+        .map(|(sq, _)| sq.clone())
+        .collect();
+    let object_statements = collect_object_statements(
+        stage_name,
+        objects.iter().chain(sinks).chain(replacement_mvs),
+        planned_project,
+        &swapped_schemas,
+    );
+    client
+        .deployments()
+        .insert_object_statements(&object_statements)
+        .await?;
+    verbose!("Stored {} object definition(s)", object_statements.len());
+
+    let assertion_records = collect_assertion_records(stage_name, objects, assertions);
+    if !assertion_records.is_empty() {
+        client
+            .deployments()
+            .insert_assertions(&assertion_records)
+            .await?;
+        verbose!("Stored {} assertion(s)", assertion_records.len());
+    }
+
     if !sinks.is_empty() {
         let pending_statements: Vec<PendingStatement> = sinks
             .iter()
@@ -810,6 +894,107 @@ async fn record_stage_metadata(
     Ok(())
 }
 
+/// Selects the assertions declared on objects staged by this deployment.
+///
+/// Assertions on objects outside the change set are not recorded: those
+/// objects are not rebuilt in the staging schemas, so there is nothing new
+/// to check.
+fn collect_assertion_records(
+    stage_name: &str,
+    objects: &[ObjectRef<'_>],
+    assertions: &[ObjectAssertion],
+) -> Vec<AssertionRecord> {
+    let staged: BTreeSet<&ObjectId> = objects.iter().map(|(id, _)| id).collect();
+    assertions
+        .iter()
+        .filter(|a| staged.contains(&a.object))
+        .map(|a| AssertionRecord {
+            deploy_id: stage_name.to_string(),
+            database: a.object.expect_database().to_string(),
+            schema: a.object.schema().to_string(),
+            object: a.object.object().to_string(),
+            assertion: a.assertion.to_string(),
+        })
+        .collect()
+}
+
+/// Renders the production-name definitions `rollback` replays to rebuild a
+/// deployment.
+///
+/// Each object is followed by its indexes, grants, and comments, in that order.
+/// Setup statements for the schemas in `schemas` come last, with an empty
+/// object name.
+fn collect_object_statements<'a>(
+    stage_name: &str,
+    objects: impl IntoIterator<Item = &'a ObjectRef<'a>>,
+    planned_project: &Project,
+    schemas: &BTreeSet<SchemaQualifier>,
+) -> Vec<ObjectStatement> {
+    let mut statements = Vec::new();
+    let mut push = |database: &str, schema: &str, object: &str, kind: &str, sql: String| {
+        #[allow(clippy::as_conversions)]
+        let sequence_num = statements.len() as i32;
+        statements.push(ObjectStatement {
+            deploy_id: stage_name.to_string(),
+            sequence_num,
+            database: database.to_string(),
+            schema: schema.to_string(),
+            object: object.to_string(),
+            statement_kind: kind.to_string(),
+            statement_sql: sql,
+        });
+    };
+
+    for (object_id, typed_obj) in objects {
+        let fqn: FullyQualifiedName = object_id.clone().into();
+        let mut visitor = NormalizingVisitor::fully_qualifying(&fqn);
+        let stmt = typed_obj
+            .stmt
+            .clone()
+            .normalize_name_with(&visitor, &fqn.to_item_name())
+            .normalize_dependencies_with(&mut visitor);
+
+        let mut indexes = typed_obj.indexes.clone();
+        let mut grants = typed_obj.grants.clone();
+        let mut comments = typed_obj.comments.clone();
+        visitor.normalize_index_references(&mut indexes);
+        visitor.normalize_grant_references(&mut grants);
+        visitor.normalize_comment_references(&mut comments);
+
+        let (database, schema, object) = (
+            object_id.expect_database(),
+            object_id.schema(),
+            object_id.object(),
+        );
+        push(database, schema, object, "object", stmt.to_string());
+        for index in &indexes {
+            push(database, schema, object, "index", index.to_string());
+        }
+        for grant in &grants {
+            push(database, schema, object, "grant", grant.to_string());
+        }
+        for comment in &comments {
+            push(database, schema, object, "comment", comment.to_string());
+        }
+    }
+
+    for mod_stmt in planned_project.iter_mod_statements() {
+        if let ModStatement::Schema {
+            database,
+            schema,
+            statement,
+        } = mod_stmt
+        {
+            let sq = SchemaQualifier::new(database.to_string(), schema.to_string());
+            if schemas.contains(&sq) {
+                push(database, schema, "", "schema", statement.to_string());
+            }
+        }
+    }
+
+    statements
+}
+
 /// Top-level orchestrator for the staging deployment pipeline.
 ///
 /// Provisions all databases, schemas, clusters, and objects needed for a blue-green
@@ -921,7 +1106,7 @@ async fn create_databases_and_schemas(
 ///
 /// Clusters that already exist are skipped. Cluster names are recorded for rollback
 /// tracking before any cluster is created, so partial failures can be cleaned up.
-async fn create_staging_clusters(
+pub(super) async fn create_staging_clusters(
     executor: &DeploymentExecutor<'_>,
     client: &Client,
     stage_name: &str,
@@ -1116,7 +1301,7 @@ async fn deploy_objects_to_staging<'a>(
             object_id,
             typed_obj,
             staging_suffix,
-            planned_project,
+            &planned_project.external_dependencies,
             &objects_to_deploy_set,
             &replacement_object_ids,
             |stmt| stmt,
@@ -1141,7 +1326,7 @@ async fn deploy_objects_to_staging<'a>(
             object_id,
             typed_obj,
             staging_suffix,
-            planned_project,
+            &planned_project.external_dependencies,
             &objects_to_deploy_set,
             &replacement_object_ids,
             |stmt| match stmt {
@@ -1178,7 +1363,10 @@ async fn deploy_objects_to_staging<'a>(
 ///
 /// # Returns
 /// Number of schemas and clusters that were cleaned up (for summary message)
-async fn rollback_staging_resources(client: &Client, environment: &str) -> (usize, usize) {
+pub(super) async fn rollback_staging_resources(
+    client: &Client,
+    environment: &str,
+) -> (usize, usize) {
     let staging_schemas = best_effort_fetch(
         client
             .introspection()
@@ -1249,6 +1437,21 @@ async fn rollback_staging_resources(client: &Client, environment: &str) -> (usiz
             .await,
         "delete replacement MV records",
     );
+    best_effort_delete(
+        client
+            .deployments()
+            .delete_object_statements(environment)
+            .await,
+        "delete recorded definitions",
+    );
+    best_effort_delete(
+        client.deployments().delete_assertions(environment).await,
+        "delete recorded assertions",
+    );
+    best_effort_delete(
+        client.deployments().delete_rollback(environment).await,
+        "delete rollback record",
+    );
     best_effort_delete(
         client.deployments().delete_deployment(environment).await,
         "delete deployment records",
@@ -1288,12 +1491,12 @@ fn best_effort_delete<E: fmt::Display>(result: Result<(), E>, action: &str) {
 /// in-place via replacement MVs. References to these objects are left
 /// unsuffixed (pointing to production). During full deployment the set is
 /// empty, so every reference is suffixed to point at the staging schemas.
-async fn deploy_single_object(
+pub(super) async fn deploy_single_object(
     executor: &DeploymentExecutor<'_>,
     object_id: &ObjectId,
     typed_obj: &DatabaseObject,
     staging_suffix: &str,
-    planned_project: &Project,
+    external_dependencies: &BTreeSet<ObjectId>,
     objects_to_deploy_set: &BTreeSet<ObjectId>,
     replacement_objects: &BTreeSet<ObjectId>,
     transform: impl FnOnce(Statement) -> Statement,
@@ -1303,7 +1506,7 @@ async fn deploy_single_object(
     let mut visitor = NormalizingVisitor::staging(
         &original_fqn,
         staging_suffix.to_string(),
-        &planned_project.external_dependencies,
+        external_dependencies,
         Some(objects_to_deploy_set),
         replacement_objects,
     );
@@ -1559,6 +1762,64 @@ mod tests {
         );
     }
 
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    #[mz_ore::test]
+    fn test_collect_object_statements_records_each_object_with_indexes() {
+        let view_obj = make_typed_object(&[
+            "CREATE VIEW my_view AS SELECT 1 AS x",
+            "CREATE INDEX my_idx IN CLUSTER index_cluster ON my_view (x)",
+        ]);
+        let mv_obj = make_typed_object(&[
+            "CREATE MATERIALIZED VIEW my_mv IN CLUSTER compute AS SELECT * FROM my_view",
+        ]);
+        let project = make_planned_project(vec![
+            ("db", "public", "my_view", view_obj.clone()),
+            ("db", "public", "my_mv", mv_obj.clone()),
+        ]);
+        let objects: Vec<ObjectRef> = vec![
+            (
+                ObjectId::new("db".into(), "public".into(), "my_view".into()),
+                &view_obj,
+            ),
+            (
+                ObjectId::new("db".into(), "public".into(), "my_mv".into()),
+                &mv_obj,
+            ),
+        ];
+
+        let statements = collect_object_statements("d1", &objects, &project, &BTreeSet::new());
+
+        let kinds: Vec<_> = statements
+            .iter()
+            .map(|s| (s.object.as_str(), s.statement_kind.as_str()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("my_view", "object"),
+                ("my_view", "index"),
+                ("my_mv", "object")
+            ]
+        );
+        for (idx, stmt) in statements.iter().enumerate() {
+            assert_eq!(stmt.deploy_id, "d1");
+            assert_eq!(usize::try_from(stmt.sequence_num).unwrap(), idx);
+            // Recorded SQL must parse back, since rollback replays it.
+            mz_sql_parser::parser::parse_statements(&stmt.statement_sql).unwrap();
+        }
+        // Names are fully qualified against production schemas.
+        let parsed = mz_sql_parser::parser::parse_statements(&statements[2].statement_sql)
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap()
+            .ast;
+        let mz_sql_parser::ast::Statement::CreateMaterializedView(mv) = parsed else {
+            panic!("expected CREATE MATERIALIZED VIEW");
+        };
+        assert_eq!(mv.name.to_string(), "db.public.my_mv");
+    }
+
     #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
     #[mz_ore::test]
     fn test_full_deploy_view_indexed_different_cluster() {
@@ -22,9 +22,15 @@
 //! 5. **Report** — Print pass/fail output and, when requested, produce a
 //!    JUnit XML report.
 //!
+//! Tests with a `STEPS` clause run the same pipeline, but step 4 executes one
+//! assertion per `EXPECTED` step in the order written and stops at the first
+//! failing step. `EXPECTED CHANGES` steps are checked client-side: the target
+//! is read at both ends of the interval and the consolidated difference is
+//! compared against the expected `(row, diff)` pairs.
+//!
 //! ## Outcome Classification
 //!
-//! - **Passed** — Assertion query returned zero rows (no mismatches).
+//! - **Passed** — Every assertion query returned zero rows (no mismatches).
 //! - **Failed** — Assertion query returned rows (missing or unexpected data)
 //!   or a runtime error occurred during execution.
 //! - **ValidationFailed** — Test definition is invalid (bad target, malformed
@@ -188,6 +194,8 @@ enum ExecutionFailure {
     /// Test assertion mismatch with raw row data for structured reporting.
     /// Display output is formatted on demand from these fields.
     AssertionFailed {
+        /// The failing step of a multi-step test, e.g. "AT TIME 2000 EXPECTED".
+        step: Option<String>,
         columns: Vec<String>,
         missing: Vec<BTreeMap<String, String>>,
         unexpected: Vec<BTreeMap<String, String>>,
@@ -228,11 +236,16 @@ impl TestResultEntry {
         let mut test_case = match &self.status {
             TestOutcome::Passed => junit_report::TestCase::success(&self.name, elapsed),
             TestOutcome::Failed(ExecutionFailure::AssertionFailed {
+                step,
                 columns,
                 missing,
                 unexpected,
             }) => {
-                let msg = format_assertion_rows_for_junit(columns, missing, unexpected);
+                let mut msg = String::new();
+                if let Some(step) = step {
+                    writeln!(msg, "step: {}", step).unwrap();
+                }
+                msg.push_str(&format_assertion_rows_for_junit(columns, missing, unexpected));
                 junit_report::TestCase::failure(&self.name, elapsed, "assertion", &msg)
             }
             TestOutcome::Failed(ExecutionFailure::Error(message)) => {
@@ -307,11 +320,11 @@ impl TestResults {
 /// EXECUTE UNIT TEST <name>
 ///   FOR <target>
 ///   [AT TIME <expr>]
-///   [MOCK <mock_view>(<columns>) AS <query>]*
-///   EXPECTED <expected_result>
+///   [MOCK <mock_view>(<columns>) [AS <query>]]*
+///   EXPECTED <expected_result> | STEPS (<step>, ...)
 /// ```
 ///
-/// A test passes when the derived assertion query returns no rows. Rows are
+/// A test passes when every derived assertion query returns no rows. Rows are
 /// returned when:
 /// - Expected rows are MISSING from the actual results
 /// - Unexpected rows appear in the actual results
@@ -535,6 +548,17 @@ async fn run_single_test(
     };
 
     let typed_fqn: FullyQualifiedName = object_id.clone().into();
+    if !test.steps.is_empty() {
+        return run_step_test(
+            client,
+            object_id,
+            test,
+            &target_obj.typed_object.stmt,
+            &typed_fqn,
+        )
+        .await;
+    }
+
     let sql_statements = lower::lower_unit_test(test, &target_obj.typed_object.stmt, &typed_fqn)
         .map_err(|reason| CliError::InvalidUnitTestTarget {
             test_name: test.name.clone(),
@@ -577,6 +601,7 @@ async fn run_single_test(
             } else {
                 let (columns, missing, unexpected) = extract_assertion_data(&rows);
                 TestOutcome::Failed(ExecutionFailure::AssertionFailed {
+                    step: None,
                     columns,
                     missing,
                     unexpected,
@@ -596,6 +621,215 @@ async fn run_single_test(
     Ok(outcome)
 }
 
+/// Executes a multi-step test: setup SQL, then each assertion step in order.
+///
+/// Stops at the first failing step. As with single-snapshot tests, cleanup
+/// happens when the caller drops the per-test connection.
+async fn run_step_test(
+    client: &Client,
+    object_id: &ObjectId,
+    test: &unit_test::UnitTest,
+    target_stmt: &project::ast::Statement,
+    typed_fqn: &FullyQualifiedName,
+) -> Result<TestOutcome, CliError> {
+    let lowered = lower::lower_step_test(test, target_stmt, typed_fqn).map_err(|reason| {
+        CliError::InvalidUnitTestTarget {
+            test_name: test.name.clone(),
+            object_id: object_id.to_string(),
+            reason,
+        }
+    })?;
+
+    for (source, sql) in &lowered.setup {
+        verbose!("executing: {}", sql);
+        if let Err(e) = client.batch_execute(sql).await {
+            return Ok(TestOutcome::Failed(ExecutionFailure::Error(format!(
+                "failed to execute {}: {}",
+                source, e
+            ))));
+        }
+    }
+
+    for assertion in &lowered.assertions {
+        let failure = match assertion {
+            lower::StepAssertion::Snapshot { step, query } => {
+                verbose!("executing: {}", query);
+                match client.simple_query(query).await {
+                    Ok(messages) => {
+                        let rows: Vec<_> = messages
+                            .into_iter()
+                            .filter_map(|m| match m {
+                                tokio_postgres::SimpleQueryMessage::Row(row) => Some(row),
+                                _ => None,
+                            })
+                            .collect();
+                        if rows.is_empty() {
+                            None
+                        } else {
+                            let (columns, missing, unexpected) = extract_assertion_data(&rows);
+                            Some(ExecutionFailure::AssertionFailed {
+                                step: Some(step.clone()),
+                                columns,
+                                missing,
+                                unexpected,
+                            })
+                        }
+                    }
+                    Err(e) => Some(ExecutionFailure::Error(format!(
+                        "failed to execute step '{}': {}",
+                        step, e
+                    ))),
+                }
+            }
+            lower::StepAssertion::Changes {
+                step,
+                columns,
+                from,
+                to,
+                expected,
+            } => {
+                let mut results = Vec::with_capacity(3);
+                for query in [from, to, expected] {
+                    verbose!("executing: {}", query);
+                    match client.simple_query(query).await {
+                        Ok(messages) => results.push(text_rows(messages)),
+                        Err(e) => {
+                            return Ok(TestOutcome::Failed(ExecutionFailure::Error(format!(
+                                "failed to execute step '{}': {}",
+                                step, e
+                            ))));
+                        }
+                    }
+                }
+                let [before, after, expected_rows] = <[_; 3]>::try_from(results)
+                    .expect("one result per query");
+                match compare_changes(columns, &before, &after, &expected_rows) {
+                    Ok((missing, unexpected)) if missing.is_empty() && unexpected.is_empty() => {
+                        None
+                    }
+                    Ok((missing, unexpected)) => Some(ExecutionFailure::AssertionFailed {
+                        step: Some(step.clone()),
+                        columns: columns.clone(),
+                        missing,
+                        unexpected,
+                    }),
+                    Err(reason) => Some(ExecutionFailure::Error(format!(
+                        "step '{}': {}",
+                        step, reason
+                    ))),
+                }
+            }
+        };
+        if let Some(failure) = failure {
+            return Ok(TestOutcome::Failed(failure));
+        }
+    }
+
+    Ok(TestOutcome::Passed)
+}
+
+/// Collects the rows of a simple query as text values, rendering `NULL` as
+/// `<null>`.
+fn text_rows(messages: Vec<tokio_postgres::SimpleQueryMessage>) -> Vec<Vec<String>> {
+    messages
+        .into_iter()
+        .filter_map(|m| match m {
+            tokio_postgres::SimpleQueryMessage::Row(row) => Some(
+                (0..row.len())
+                    .map(|i| row.get(i).unwrap_or("<null>").to_string())
+                    .collect(),
+            ),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Compares the changes between two snapshots of the target against the
+/// expected `(row, diff)` pairs of an `EXPECTED CHANGES` step.
+///
+/// Both sides are consolidated first, so the comparison is insensitive to
+/// row order and to how the expected changes are split across rows. The last
+/// entry of `columns` (and of each expected row) is the diff. Returns
+/// `(missing, unexpected)`: expected changes the target did not produce, and
+/// changes it produced that were not expected, each with its diff.
+///
+/// Returns an error if a snapshot row or an expected row does not match the
+/// width of `columns`.
+fn compare_changes(
+    columns: &[String],
+    before: &[Vec<String>],
+    after: &[Vec<String>],
+    expected: &[Vec<String>],
+) -> Result<
+    (
+        Vec<BTreeMap<String, String>>,
+        Vec<BTreeMap<String, String>>,
+    ),
+    String,
+> {
+    let width = columns.len().saturating_sub(1);
+    if let Some(row) = before.iter().chain(after).find(|row| row.len() != width) {
+        return Err(format!(
+            "target has {} columns but EXPECTED CHANGES lists {}",
+            row.len(),
+            width
+        ));
+    }
+    if let Some(row) = expected.iter().find(|row| row.len() != columns.len()) {
+        return Err(format!(
+            "expected row has {} values but EXPECTED CHANGES lists {} columns",
+            row.len(),
+            columns.len()
+        ));
+    }
+
+    let mut actual: BTreeMap<&[String], i64> = BTreeMap::new();
+    for row in after {
+        *actual.entry(row.as_slice()).or_default() += 1;
+    }
+    for row in before {
+        *actual.entry(row.as_slice()).or_default() -= 1;
+    }
+    actual.retain(|_, diff| *diff != 0);
+
+    let mut wanted: BTreeMap<&[String], i64> = BTreeMap::new();
+    for row in expected {
+        let Some((diff, values)) = row.split_last() else {
+            continue;
+        };
+        let diff: i64 = diff
+            .parse()
+            .map_err(|_| format!("expected diff '{}' is not an integer", diff))?;
+        *wanted.entry(values).or_default() += diff;
+    }
+    wanted.retain(|_, diff| *diff != 0);
+
+    let to_map = |values: &[String], diff: i64| -> BTreeMap<String, String> {
+        columns
+            .iter()
+            .cloned()
+            .zip(
+                values
+                    .iter()
+                    .cloned()
+                    .chain(std::iter::once(diff.to_string())),
+            )
+            .collect()
+    };
+
+    let missing = wanted
+        .iter()
+        .filter(|(values, diff)| actual.get(*values) != Some(*diff))
+        .map(|(values, diff)| to_map(values, *diff))
+        .collect();
+    let unexpected = actual
+        .iter()
+        .filter(|(values, diff)| wanted.get(*values) != Some(*diff))
+        .map(|(values, diff)| to_map(values, *diff))
+        .collect();
+    Ok((missing, unexpected))
+}
+
 /// Prints the test summary line showing pass/fail counts.
 fn print_summary(summary: &TestSummary) {
     let total_failed = summary.failed + summary.validation_failed;
@@ -676,10 +910,18 @@ fn print_test_outcome(name: &str, outcome: &TestOutcome) {
             );
             match failure {
                 ExecutionFailure::AssertionFailed {
+                    step,
                     columns,
                     missing,
                     unexpected,
                 } => {
+                    if let Some(step) = step {
+                        info!(
+                            "  {} {}",
+                            "step:".if_supports_color(Stream::Stderr, |t| t.bold()),
+                            step
+                        );
+                    }
                     info_nonl!("{}", format_assertion_rows(columns, missing, unexpected))
                 }
                 ExecutionFailure::Error(msg) => {
@@ -710,6 +952,9 @@ fn print_test_validation_error(error: &TestValidationError) {
         TestValidationError::InvalidAtTime(inner) => {
             info!("{}", inner)
         }
+        TestValidationError::UnknownStepMock(inner) => {
+            info!("{}", inner)
+        }
         TestValidationError::TypesCacheUnavailable { reason } => {
             let style = Style::new().bright_red().bold();
             info!(
@@ -738,7 +983,8 @@ async fn runtime_client(runtime: &DockerRuntime, _empty_types: &Types) -> Result
     }
 }
 
-/// Pre-validates optional `AT TIME` test expressions against `mz_timestamp` casting.
+/// Pre-validates `AT TIME` and `BETWEEN` test expressions, including those
+/// of steps, against `mz_timestamp` casting.
 ///
 /// Returns `Ok(Ok(()))` when valid, `Ok(Err(InvalidAtTimeError))` on
 /// validation failure, and `Err(CliError)` on connection errors.
@@ -746,12 +992,12 @@ async fn validate_at_time(
     client: &Client,
     test: &unit_test::UnitTest,
 ) -> Result<Result<(), lower::InvalidAtTimeError>, CliError> {
-    if let Some(at_time) = &test.at_time {
-        let validation_query = format!("SELECT {}::mz_timestamp", at_time);
+    for at_time in test.timestamps() {
+        let validation_query = format!("SELECT ({})::mz_timestamp", at_time);
         if let Err(e) = client.simple_query(&validation_query).await {
             let error = lower::InvalidAtTimeError {
                 test_name: test.name.clone(),
-                at_time_value: at_time.clone(),
+                at_time_value: at_time.to_string(),
                 db_error: e.to_string(),
             };
             return Ok(Err(error));
@@ -945,3 +1191,60 @@ fn load_or_generate_types_cache(
     )
     .map_err(|e| CliError::Message(format!("Failed to open types cache: {}", e)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| row.iter().map(|v| v.to_string()).collect())
+            .collect()
+    }
+
+    #[mz_ore::test]
+    fn test_compare_changes_matches_consolidated_diff() {
+        let columns = vec!["id".to_string(), "mz_diff".to_string()];
+        let before = rows(&[&["1"], &["2"]]);
+        let after = rows(&[&["2"], &["3"], &["3"]]);
+        let expected = rows(&[&["1", "-1"], &["3", "1"], &["3", "1"]]);
+
+        let (missing, unexpected) = compare_changes(&columns, &before, &after, &expected).unwrap();
+        assert!(missing.is_empty(), "unexpected missing rows: {:?}", missing);
+        assert!(unexpected.is_empty(), "unexpected rows: {:?}", unexpected);
+    }
+
+    #[mz_ore::test]
+    fn test_compare_changes_reports_mismatched_diffs() {
+        let columns = vec!["id".to_string(), "mz_diff".to_string()];
+        let before = rows(&[&["1"]]);
+        let after = rows(&[&["1"], &["2"]]);
+        let expected = rows(&[&["2", "2"]]);
+
+        let (missing, unexpected) = compare_changes(&columns, &before, &after, &expected).unwrap();
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0]["mz_diff"], "2");
+        assert_eq!(unexpected.len(), 1);
+        assert_eq!(unexpected[0]["id"], "2");
+        assert_eq!(unexpected[0]["mz_diff"], "1");
+    }
+
+    #[mz_ore::test]
+    fn test_compare_changes_rejects_non_integer_diff() {
+        let columns = vec!["id".to_string(), "mz_diff".to_string()];
+        let expected = rows(&[&["1", "one"]]);
+
+        assert!(compare_changes(&columns, &[], &[], &expected).is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_compare_changes_rejects_mismatched_widths() {
+        let columns = vec!["id".to_string(), "mz_diff".to_string()];
+        let wide = rows(&[&["1", "a"]]);
+        let expected = rows(&[&["1", "1"]]);
+
+        assert!(compare_changes(&columns, &wide, &[], &expected).is_err());
+        assert!(compare_changes(&columns, &[], &wide, &expected).is_err());
+        assert!(compare_changes(&columns, &[], &[], &rows(&[&["1", "a", "1"]])).is_err());
+    }
+}
@@ -27,11 +27,30 @@
 //!   SELECT * FROM VALUES (...)
 //! );
 //! ```
+//!
+//! Multi-step tests replace `EXPECTED` with a list of timestamped steps.
+//! Mocks touched by a step are lowered to a view that unions the inserted
+//! rows and `EXCEPT ALL`s the retracted rows, each behind an
+//! `mz_now() >= <time>` temporal filter, so querying the target `AS OF` a
+//! timestamp observes exactly the steps at or before it. Each assertion step
+//! becomes its own query (see [`StepAssertion`]).
+//!
+//! ```sql
+//! EXECUTE UNIT TEST test_name
+//! FOR database.schema.view_name
+//! MOCK database.schema.mock1(col1 TYPE1)   -- body optional; starts empty
+//! STEPS (
+//!   AT TIME 1000 INSERT INTO database.schema.mock1 AS (VALUES (...)),
+//!   AT TIME 2000 RETRACT FROM database.schema.mock1 AS (VALUES (...)),
+//!   AT TIME 2000 EXPECTED(col TYPE) AS (VALUES (...)),
+//!   BETWEEN 1000 AND 2000 EXPECTED CHANGES(col TYPE) AS (VALUES (..., diff))
+//! );
+//! ```
 
 use crate::project::ast::Statement;
 use crate::project::ir::compiled::FullyQualifiedName;
 use crate::project::ir::object_id::ObjectId;
-use crate::project::ir::unit_test::{ExpectedResult, MockView, UnitTest};
+use crate::project::ir::unit_test::{ExpectedResult, MockView, TestStep, UnitTest};
 use crate::project::resolve::normalize::NormalizingVisitor;
 use crate::types::ColumnType;
 #[cfg(test)]
@@ -62,6 +81,10 @@ pub enum TestValidationError {
     #[error("invalid at_time timestamp")]
     InvalidAtTime(InvalidAtTimeError),
 
+    /// A step inserts into or retracts from a relation that is not mocked
+    #[error("step references unknown mock")]
+    UnknownStepMock(UnknownStepMockError),
+
     /// Types cache is missing or stale
     #[error("types cache unavailable: {reason}")]
     TypesCacheUnavailable { reason: String },
@@ -420,12 +443,68 @@ impl fmt::Display for InvalidAtTimeError {
 
 impl std::error::Error for InvalidAtTimeError {}
 
+/// Error: A step changes a relation that has no `MOCK` declaration.
+#[derive(Debug, Serialize)]
+pub struct UnknownStepMockError {
+    /// Test name
+    pub test_name: String,
+    /// The step as written, e.g. "AT TIME 1000 INSERT INTO orders"
+    pub step: String,
+    /// The mocks declared by the test
+    pub declared_mocks: Vec<String>,
+}
+
+impl fmt::Display for UnknownStepMockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error_style = Style::new().bright_red().bold();
+        let marker_style = Style::new().bright_blue().bold();
+        writeln!(
+            f,
+            "{}: test '{}' has a step on a relation that is not mocked",
+            "error".if_supports_color(Stream::Stderr, |t| error_style.style(t)),
+            self.test_name
+                .if_supports_color(Stream::Stderr, |t| t.cyan())
+        )?;
+        writeln!(
+            f,
+            " {} step: {}",
+            "-->".if_supports_color(Stream::Stderr, |t| marker_style.style(t)),
+            self.step.if_supports_color(Stream::Stderr, |t| t.yellow())
+        )?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "  {} Declared mocks:",
+            "|".if_supports_color(Stream::Stderr, |t| marker_style.style(t))
+        )?;
+        for mock in &self.declared_mocks {
+            writeln!(
+                f,
+                "  {}   - {}",
+                "|".if_supports_color(Stream::Stderr, |t| marker_style.style(t)),
+                mock.if_supports_color(Stream::Stderr, |t| t.green())
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "  {} Steps can only insert into or retract from relations declared with MOCK",
+            "=".if_supports_color(Stream::Stderr, |t| marker_style.style(t))
+        )?;
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnknownStepMockError {}
+
 /// Validate a unit test against the known types.
 ///
-/// This function performs three validations:
+/// This function performs four validations:
 /// 1. All dependencies of the target view are mocked
 /// 2. Each mock's columns match the actual schema of the mocked object
-/// 3. The expected output columns match the target view's output schema
+/// 3. Every expected output (top-level or per step) matches the target
+///    view's output schema
+/// 4. Every `INSERT`/`RETRACT` step targets a declared mock
 ///
 /// # Arguments
 /// * `test` - The unit test to validate
@@ -492,29 +571,44 @@ pub(super) fn validate_unit_test(
     }
 
     if let Some(target_columns) = get_columns(target_id) {
-        let (extra, missing, type_mismatches) =
-            compare_columns(&test.expected.columns, &target_columns);
+        for expected in test.expectations() {
+            let (extra, missing, type_mismatches) =
+                compare_columns(&expected.columns, &target_columns);
 
-        if !extra.is_empty() || !missing.is_empty() || !type_mismatches.is_empty() {
-            let actual_schema: Vec<(String, String)> = target_columns
-                .iter()
-                .map(|(name, col_type)| (name.clone(), col_type.r#type.clone()))
-                .collect();
+            if !extra.is_empty() || !missing.is_empty() || !type_mismatches.is_empty() {
+                let actual_schema: Vec<(String, String)> = target_columns
+                    .iter()
+                    .map(|(name, col_type)| (name.clone(), col_type.r#type.clone()))
+                    .collect();
 
-            return Err(TestValidationError::ExpectedSchemaMismatch(
-                ExpectedSchemaMismatchError {
-                    test_name: test.name.clone(),
-                    target_view: target_id.to_string(),
-                    extra_columns: extra,
-                    missing_columns: missing,
-                    type_mismatches,
-                    actual_schema,
-                },
-            ));
+                return Err(TestValidationError::ExpectedSchemaMismatch(
+                    ExpectedSchemaMismatchError {
+                        test_name: test.name.clone(),
+                        target_view: target_id.to_string(),
+                        extra_columns: extra,
+                        missing_columns: missing,
+                        type_mismatches,
+                        actual_schema,
+                    },
+                ));
+            }
         }
     }
     // If target isn't in types, we'll catch it during test execution.
 
+    for step in &test.steps {
+        let (TestStep::Insert { mock, .. } | TestStep::Retract { mock, .. }) = step else {
+            continue;
+        };
+        if !mocked_ids.contains(&normalize_fqn(mock, target_id)) {
+            return Err(TestValidationError::UnknownStepMock(UnknownStepMockError {
+                test_name: test.name.clone(),
+                step: step.to_string(),
+                declared_mocks: mocked_ids.iter().map(|id| id.to_string()).collect(),
+            }));
+        }
+    }
+
     Ok(())
 }
 
@@ -695,6 +789,11 @@ pub(super) fn lower_unit_test(
     target_stmt: &Statement,
     target_fqn: &FullyQualifiedName,
 ) -> Result<Vec<String>, String> {
+    let expected = test
+        .expected
+        .as_ref()
+        .ok_or_else(|| format!("test '{}' has no EXPECTED clause", test.name))?;
+
     let mut statements = Vec::new();
 
     for mock in &test.mocks {
@@ -702,7 +801,7 @@ pub(super) fn lower_unit_test(
         statements.push(create_mock_view_sql(&qualified_mock));
     }
 
-    statements.push(create_expected_view_sql(&test.expected));
+    statements.push(create_expected_view_sql(expected));
 
     statements.push(create_target_view_sql(target_stmt, target_fqn)?);
 
@@ -721,28 +820,172 @@ pub(super) fn lower_unit_test(
     Ok(statements)
 }
 
+/// A multi-step test lowered into SQL.
+pub(super) struct LoweredStepTest {
+    /// Setup statements in execution order, each paired with a label naming
+    /// the clause it came from (e.g. `MOCK materialize.public.orders`).
+    pub setup: Vec<(String, String)>,
+    /// One assertion per `EXPECTED` step, in the order written.
+    pub assertions: Vec<StepAssertion>,
+}
+
+/// The queries that check a single assertion step.
+pub(super) enum StepAssertion {
+    /// A query in the same shape as the single-snapshot assertion: rows
+    /// describe mismatches and an empty result means the step passed.
+    Snapshot { step: String, query: String },
+    /// Snapshots of the target at both ends of the interval, plus a query
+    /// for the expected changes whose last column is the diff. The runner
+    /// diffs the snapshots and compares the result against `expected`.
+    /// `columns` names the expected columns, diff column included.
+    Changes {
+        step: String,
+        columns: Vec<String>,
+        from: String,
+        to: String,
+        expected: String,
+    },
+}
+
+/// An `INSERT` or `RETRACT` step applied to one mock.
+struct MockChange<'a> {
+    insert: bool,
+    at_time: &'a str,
+    query: &'a str,
+}
+
+/// Lower a multi-step unit test into setup SQL and per-step assertions.
+///
+/// Setup creates, in order: every mock (with temporal filters for mocks that
+/// steps change), one expected view per assertion step, and the flattened
+/// target view.
+pub(super) fn lower_step_test(
+    test: &UnitTest,
+    target_stmt: &Statement,
+    target_fqn: &FullyQualifiedName,
+) -> Result<LoweredStepTest, String> {
+    let mut setup = Vec::new();
+
+    for mock in &test.mocks {
+        let qualified_mock = qualify_mock_name(mock, target_fqn);
+        let label = format!("MOCK {}", mock.fqn);
+        let changes: Vec<MockChange<'_>> = test
+            .steps
+            .iter()
+            .filter_map(|step| match step {
+                TestStep::Insert {
+                    at_time,
+                    mock,
+                    query,
+                } if qualify_name(mock, target_fqn) == qualified_mock.fqn => Some(MockChange {
+                    insert: true,
+                    at_time,
+                    query,
+                }),
+                TestStep::Retract {
+                    at_time,
+                    mock,
+                    query,
+                } if qualify_name(mock, target_fqn) == qualified_mock.fqn => Some(MockChange {
+                    insert: false,
+                    at_time,
+                    query,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        if changes.is_empty() {
+            setup.push((label, create_mock_view_sql(&qualified_mock)));
+        } else {
+            for sql in create_temporal_mock_view_sql(&qualified_mock, &changes) {
+                setup.push((label.clone(), sql));
+            }
+        }
+    }
+
+    let target_fqn_str = format!(
+        "{}.{}.{}",
+        target_fqn.database(),
+        target_fqn.schema(),
+        target_fqn.object()
+    );
+    let flattened_target_name = flatten_fqn(&target_fqn_str);
+
+    let mut assertions = Vec::new();
+    for (i, step) in test.steps.iter().enumerate() {
+        let view_name = format!("expected_{}", i + 1);
+        match step {
+            TestStep::Insert { .. } | TestStep::Retract { .. } => {}
+            TestStep::ExpectSnapshot { at_time, expected } => {
+                setup.push((
+                    step.to_string(),
+                    create_named_expected_view_sql(&view_name, expected),
+                ));
+                assertions.push(StepAssertion::Snapshot {
+                    step: step.to_string(),
+                    query: create_snapshot_query_sql(
+                        &flattened_target_name,
+                        &view_name,
+                        Some(at_time),
+                    ),
+                });
+            }
+            TestStep::ExpectChanges { from, to, expected } => {
+                let mut with_diff = expected.clone();
+                with_diff
+                    .columns
+                    .push((CHANGES_DIFF_COLUMN.to_string(), "bigint".to_string()));
+                setup.push((
+                    step.to_string(),
+                    create_named_expected_view_sql(&view_name, &with_diff),
+                ));
+                assertions.push(StepAssertion::Changes {
+                    step: step.to_string(),
+                    columns: with_diff
+                        .columns
+                        .iter()
+                        .map(|(name, _)| name.clone())
+                        .collect(),
+                    from: create_as_of_query_sql(&flattened_target_name, from),
+                    to: create_as_of_query_sql(&flattened_target_name, to),
+                    expected: format!("SELECT * FROM {}", view_name),
+                });
+            }
+        }
+    }
+
+    setup.push((
+        format!("target view '{}'", test.target_view),
+        create_target_view_sql(target_stmt, target_fqn)?,
+    ));
+
+    Ok(LoweredStepTest { setup, assertions })
+}
+
+/// Name of the trailing diff column of an `EXPECTED CHANGES` relation.
+pub(super) const CHANGES_DIFF_COLUMN: &str = "mz_diff";
+
 /// Quote a fully qualified name as a single identifier with dots.
 fn flatten_fqn(fqn: &str) -> String {
     format!("\"{}\"", fqn)
 }
 
+/// Qualify a possibly partial name with the target's database and schema.
+fn qualify_name(name: &str, target_fqn: &FullyQualifiedName) -> String {
+    let parts = name.matches('.').count() + 1;
+
+    match parts {
+        1 => format!("{}.{}.{}", target_fqn.database(), target_fqn.schema(), name),
+        2 => format!("{}.{}", target_fqn.database(), name),
+        _ => name.to_string(),
+    }
+}
+
 /// Qualify a mock name with the target's FQN context if it's not already qualified.
 fn qualify_mock_name(mock: &MockView, target_fqn: &FullyQualifiedName) -> MockView {
-    let parts = mock.fqn.matches('.').count() + 1;
-
-    let qualified_fqn = match parts {
-        1 => format!(
-            "{}.{}.{}",
-            target_fqn.database(),
-            target_fqn.schema(),
-            mock.fqn
-        ),
-        2 => format!("{}.{}", target_fqn.database(), mock.fqn),
-        _ => mock.fqn.clone(),
-    };
-
     MockView {
-        fqn: qualified_fqn,
+        fqn: qualify_name(&mock.fqn, target_fqn),
         columns: mock.columns.clone(),
         query: mock.query.clone(),
     }
@@ -763,7 +1006,62 @@ fn create_mock_view_sql(mock: &MockView) -> String {
     )
 }
 
+/// Create SQL for a mock whose contents change over time.
+///
+/// The mock's declared body and each step's rows get their own typed
+/// temporary view (`"<fqn>#<n>"`). The mock itself unions the body with every
+/// inserted step and `EXCEPT ALL`s every retracted step, gating each step on
+/// `mz_now()` so that a query `AS OF t` sees only the steps at or before `t`.
+fn create_temporal_mock_view_sql(mock: &MockView, changes: &[MockChange<'_>]) -> Vec<String> {
+    let part_fqn = |i: usize| format!("{}#{}", mock.fqn, i);
+
+    let mut statements = vec![create_mock_view_sql(&MockView {
+        fqn: part_fqn(0),
+        columns: mock.columns.clone(),
+        query: mock.query.clone(),
+    })];
+    let mut inserts = vec![format!("SELECT * FROM {}", flatten_fqn(&part_fqn(0)))];
+    let mut retracts = Vec::new();
+
+    for (i, change) in changes.iter().enumerate() {
+        statements.push(create_mock_view_sql(&MockView {
+            fqn: part_fqn(i + 1),
+            columns: mock.columns.clone(),
+            query: change.query.to_string(),
+        }));
+        let select = format!(
+            "SELECT * FROM {} WHERE mz_now() >= ({})::mz_timestamp",
+            flatten_fqn(&part_fqn(i + 1)),
+            change.at_time
+        );
+        if change.insert {
+            inserts.push(select);
+        } else {
+            retracts.push(select);
+        }
+    }
+
+    let mut body = inserts.join("\nUNION ALL\n");
+    if !retracts.is_empty() {
+        body = format!(
+            "({})\nEXCEPT ALL\n({})",
+            body,
+            retracts.join("\nUNION ALL\n")
+        );
+    }
+    statements.push(format!(
+        "CREATE TEMPORARY VIEW {} AS\n{};",
+        flatten_fqn(&mock.fqn),
+        body
+    ));
+    statements
+}
+
 fn create_expected_view_sql(expected: &ExpectedResult) -> String {
+    create_named_expected_view_sql("expected", expected)
+}
+
+fn create_named_expected_view_sql(view_name: &str, expected: &ExpectedResult) -> String {
     let columns_def = expected
         .columns
         .iter()
@@ -772,8 +1070,8 @@ fn create_expected_view_sql(expected: &ExpectedResult) -> String {
         .join(", ");
 
     format!(
-        "CREATE TEMPORARY VIEW expected AS\nWITH MUTUALLY RECURSIVE data({}) AS (\n  {}\n)\nSELECT * FROM data;",
-        columns_def, expected.query
+        "CREATE TEMPORARY VIEW {} AS\nWITH MUTUALLY RECURSIVE data({}) AS (\n  {}\n)\nSELECT * FROM data;",
+        view_name, columns_def, expected.query
     )
 }
 
@@ -828,11 +1126,21 @@ fn create_target_view_sql(stmt: &Statement, fqn: &FullyQualifiedName) -> Result<
 /// If `at_time` is provided, the query includes an `AS OF` clause to set
 /// the value of `mz_now()` during test execution.
 fn create_test_query_sql(flattened_target_name: &str, at_time: Option<&str>) -> String {
+    create_snapshot_query_sql(flattened_target_name, "expected", at_time)
+}
+
+/// Create an assertion query comparing the target against the named
+/// expected view. See [`create_test_query_sql`].
+fn create_snapshot_query_sql(
+    flattened_target_name: &str,
+    expected_view: &str,
+    at_time: Option<&str>,
+) -> String {
     let as_of_clause = at_time
-        .map(|t| format!(" AS OF {}::mz_timestamp", t))
+        .map(|t| format!(" AS OF ({})::mz_timestamp", t))
         .unwrap_or_default();
     format!(
-        r#"SELECT 'MISSING' as status, * FROM expected
+        r#"SELECT 'MISSING' as status, * FROM {}
 EXCEPT
 SELECT 'MISSING', * FROM {}
 
@@ -840,8 +1148,16 @@ UNION ALL
 
 SELECT 'UNEXPECTED' as status, * FROM {}
 EXCEPT
-SELECT 'UNEXPECTED', * FROM expected{}"#,
-        flattened_target_name, flattened_target_name, as_of_clause
+SELECT 'UNEXPECTED', * FROM {}{}"#,
+        expected_view, flattened_target_name, flattened_target_name, expected_view, as_of_clause
+    )
+}
+
+/// Create a query reading the target's full contents as of `at_time`.
+fn create_as_of_query_sql(flattened_target_name: &str, at_time: &str) -> String {
+    format!(
+        "SELECT * FROM {} AS OF ({})::mz_timestamp",
+        flattened_target_name, at_time
     )
 }
 
@@ -917,7 +1233,88 @@ mod tests {
             create_test_query_sql("materialize_public_my_view", Some("'2024-01-15 10:00:00'"));
 
         assert!(sql.contains("SELECT 'MISSING' as status, * FROM expected"));
-        assert!(sql.contains("AS OF '2024-01-15 10:00:00'::mz_timestamp"));
+        assert!(sql.contains("AS OF ('2024-01-15 10:00:00')::mz_timestamp"));
+    }
+
+    #[mz_ore::test]
+    fn test_create_temporal_mock_view_sql() {
+        let mock = MockView {
+            fqn: "materialize.public.orders".to_string(),
+            columns: vec![("id".to_string(), "bigint".to_string())],
+            query: "SELECT * FROM VALUES (1)".to_string(),
+        };
+        let changes = [
+            MockChange {
+                insert: true,
+                at_time: "1000",
+                query: "VALUES (2)",
+            },
+            MockChange {
+                insert: false,
+                at_time: "2000",
+                query: "VALUES (1)",
+            },
+        ];
+
+        let sql = create_temporal_mock_view_sql(&mock, &changes);
+
+        assert_eq!(sql.len(), 4);
+        assert!(sql[0].contains("CREATE TEMPORARY VIEW \"materialize.public.orders#0\""));
+        assert!(sql[1].contains("CREATE TEMPORARY VIEW \"materialize.public.orders#1\""));
+        assert!(sql[2].contains("CREATE TEMPORARY VIEW \"materialize.public.orders#2\""));
+        assert!(sql[3].starts_with("CREATE TEMPORARY VIEW \"materialize.public.orders\" AS"));
+        assert!(sql[3].contains(
+            "SELECT * FROM \"materialize.public.orders#1\" WHERE mz_now() >= (1000)::mz_timestamp"
+        ));
+        assert!(sql[3].contains("EXCEPT ALL"));
+        assert!(sql[3].contains(
+            "SELECT * FROM \"materialize.public.orders#2\" WHERE mz_now() >= (2000)::mz_timestamp"
+        ));
+    }
+
+    #[mz_ore::test]
+    fn test_create_temporal_mock_view_sql_insert_only() {
+        let mock = MockView {
+            fqn: "materialize.public.orders".to_string(),
+            columns: vec![("id".to_string(), "bigint".to_string())],
+            query: "SELECT * FROM VALUES (1)".to_string(),
+        };
+        let changes = [MockChange {
+            insert: true,
+            at_time: "1000",
+            query: "VALUES (2)",
+        }];
+
+        let sql = create_temporal_mock_view_sql(&mock, &changes);
+
+        assert_eq!(sql.len(), 3);
+        assert!(sql[2].contains("UNION ALL"));
+        assert!(!sql[2].contains("EXCEPT ALL"));
+    }
+
+    #[mz_ore::test]
+    fn test_create_snapshot_query_sql() {
+        let sql = create_snapshot_query_sql("\"a.b.c\"", "expected_3", Some("1000"));
+
+        assert!(sql.contains("SELECT 'MISSING' as status, * FROM expected_3"));
+        assert!(sql.contains("SELECT 'UNEXPECTED', * FROM expected_3"));
+        assert!(sql.contains("AS OF (1000)::mz_timestamp"));
+    }
+
+    #[mz_ore::test]
+    fn test_qualify_name() {
+        let fqn = FullyQualifiedName::from(ObjectId::new(
+            "materialize".to_string(),
+            "public".to_string(),
+            "my_view".to_string(),
+        ));
+
+        assert_eq!(qualify_name("orders", &fqn), "materialize.public.orders");
+        assert_eq!(
+            qualify_name("other.orders", &fqn),
+            "materialize.other.orders"
+        );
+        assert_eq!(qualify_name("db.other.orders", &fqn), "db.other.orders");
     }
 
     fn make_test_types() -> Types {
@@ -1029,6 +1426,7 @@ mod tests {
             tables: objects,
             kinds: BTreeMap::new(),
             comments: BTreeMap::new(),
+            contracts: BTreeMap::new(),
         }
     }
 
@@ -1081,14 +1479,15 @@ mod tests {
                     query: "SELECT * FROM VALUES (1, 1, 100.00)".to_string(),
                 },
             ],
-            expected: ExpectedResult {
+            expected: Some(ExpectedResult {
                 columns: vec![
                     ("user_id".to_string(), "bigint".to_string()),
                     ("user_name".to_string(), "text".to_string()),
                     ("total_orders".to_string(), "bigint".to_string()),
                 ],
                 query: "SELECT * FROM VALUES (1, 'alice', 1)".to_string(),
-            },
+            }),
+            steps: vec![],
         };
 
         let types = make_test_types();
@@ -1119,14 +1518,15 @@ mod tests {
                 ],
                 query: "SELECT * FROM VALUES (1, 'alice', 'alice@example.com')".to_string(),
             }],
-            expected: ExpectedResult {
+            expected: Some(ExpectedResult {
                 columns: vec![
                     ("user_id".to_string(), "bigint".to_string()),
                     ("user_name".to_string(), "text".to_string()),
                     ("total_orders".to_string(), "bigint".to_string()),
                 ],
                 query: "SELECT * FROM VALUES (1, 'alice', 1)".to_string(),
-            },
+            }),
+            steps: vec![],
         };
 
         let types = make_test_types();
@@ -1178,14 +1578,15 @@ mod tests {
                     query: "SELECT * FROM VALUES (1, 1, 100.00)".to_string(),
                 },
             ],
-            expected: ExpectedResult {
+            expected: Some(ExpectedResult {
                 columns: vec![
                     ("user_id".to_string(), "bigint".to_string()),
                     ("user_name".to_string(), "text".to_string()),
                     ("total_orders".to_string(), "bigint".to_string()),
                 ],
                 query: "SELECT * FROM VALUES (1, 'alice', 1)".to_string(),
-            },
+            }),
+            steps: vec![],
         };
 
         let types = make_test_types();
@@ -1238,14 +1639,15 @@ mod tests {
                     query: "SELECT * FROM VALUES (1, 1, 100.00)".to_string(),
                 },
             ],
-            expected: ExpectedResult {
+            expected: Some(ExpectedResult {
                 columns: vec![
                     ("user_id".to_string(), "bigint".to_string()),
                     ("user_name".to_string(), "text".to_string()),
                     ("total_orders".to_string(), "bigint".to_string()),
                 ],
                 query: "SELECT * FROM VALUES (1, 'alice', 1)".to_string(),
-            },
+            }),
+            steps: vec![],
         };
 
         let types = make_test_types();
@@ -1296,14 +1698,15 @@ mod tests {
                     query: "SELECT * FROM VALUES (1, 1, 100.00)".to_string(),
                 },
             ],
-            expected: ExpectedResult {
+            expected: Some(ExpectedResult {
                 columns: vec![
                     ("user_id".to_string(), "bigint".to_string()),
                     ("user_name".to_string(), "text".to_string()),
                     ("total_orders".to_string(), "bigint".to_string()),
                 ],
                 query: "SELECT * FROM VALUES (1, 'alice', 1)".to_string(),
-            },
+            }),
+            steps: vec![],
         };
 
         let types = make_test_types();
@@ -1357,13 +1760,14 @@ mod tests {
                     query: "SELECT * FROM VALUES (1, 1, 100.00)".to_string(),
                 },
             ],
-            expected: ExpectedResult {
+            expected: Some(ExpectedResult {
                 columns: vec![
                     ("user_id".to_string(), "bigint".to_string()),
                     ("total_orders".to_string(), "bigint".to_string()),
                 ],
                 query: "SELECT * FROM VALUES (1, 1)".to_string(),
-            },
+            }),
+            steps: vec![],
         };
 
         let types = make_test_types();
@@ -1418,14 +1822,15 @@ mod tests {
                     query: "SELECT * FROM VALUES (1, 1, 100.00)".to_string(),
                 },
             ],
-            expected: ExpectedResult {
+            expected: Some(ExpectedResult {
                 columns: vec![
                     ("user_id".to_string(), "bigint".to_string()),
                     ("user_name".to_string(), "bigint".to_string()),
                     ("total_orders".to_string(), "bigint".to_string()),
                 ],
                 query: "SELECT * FROM VALUES (1, 1, 1)".to_string(),
-            },
+            }),
+            steps: vec![],
         };
 
         let types = make_test_types();
@@ -1818,14 +2223,15 @@ mod tests {
                     query: "SELECT * FROM VALUES (1, 1, 100.00)".to_string(),
                 },
             ],
-            expected: ExpectedResult {
+            expected: Some(ExpectedResult {
                 columns: vec![
                     ("user_id".to_string(), "bigint".to_string()),
                     ("user_name".to_string(), "text".to_string()),
                     ("total_orders".to_string(), "bigint".to_string()),
                 ],
                 query: "SELECT * FROM VALUES (1, 'alice', 1)".to_string(),
-            },
+            }),
+            steps: vec![],
         };
 
         let types = make_test_types();
@@ -1848,10 +2254,11 @@ mod tests {
             target_view: "materialize.public.my_view".to_string(),
             at_time: None,
             mocks: vec![],
-            expected: ExpectedResult {
+            expected: Some(ExpectedResult {
                 columns: vec![("result".to_string(), "integer".to_string())],
                 query: "SELECT * FROM VALUES (42)".to_string(),
-            },
+            }),
+            steps: vec![],
         };
 
         let types = Types::default();
@@ -1882,10 +2289,11 @@ mod tests {
                 columns: vec![("id".to_string(), "bigint".to_string())],
                 query: "SELECT * FROM VALUES (1)".to_string(),
             }],
-            expected: ExpectedResult {
+            expected: Some(ExpectedResult {
                 columns: vec![("result".to_string(), "integer".to_string())],
                 query: "SELECT * FROM VALUES (42)".to_string(),
-            },
+            }),
+            steps: vec![],
         };
 
         let types = Types::default();
@@ -1911,6 +2319,50 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[mz_ore::test]
+    fn test_validate_fails_with_step_on_unmocked_relation() {
+        let test = UnitTest {
+            name: "test_steps".to_string(),
+            target_view: "materialize.public.my_view".to_string(),
+            at_time: None,
+            mocks: vec![MockView {
+                fqn: "orders".to_string(),
+                columns: vec![("id".to_string(), "bigint".to_string())],
+                query: "SELECT * FROM VALUES (1)".to_string(),
+            }],
+            expected: None,
+            steps: vec![
+                TestStep::Insert {
+                    at_time: "1000".to_string(),
+                    mock: "orders".to_string(),
+                    query: "VALUES (2)".to_string(),
+                },
+                TestStep::Retract {
+                    at_time: "2000".to_string(),
+                    mock: "users".to_string(),
+                    query: "VALUES (1)".to_string(),
+                },
+            ],
+        };
+
+        let target_id = ObjectId::new(
+            "materialize".to_string(),
+            "public".to_string(),
+            "my_view".to_string(),
+        );
+
+        let result = validate_unit_test(&test, &target_id, &|_| None, &BTreeSet::new());
+
+        match result.unwrap_err() {
+            TestValidationError::UnknownStepMock(err) => {
+                assert_eq!(err.test_name, "test_steps");
+                assert_eq!(err.step, "AT TIME 2000 RETRACT FROM users");
+                assert_eq!(err.declared_mocks, vec!["materialize.public.orders"]);
+            }
+            other => panic!("Expected UnknownStepMock error, got: {:?}", other),
+        }
+    }
+
     #[mz_ore::test]
     fn test_normalize_type_list() {
         assert_eq!(normalize_type("int8 list"), "bigint list");
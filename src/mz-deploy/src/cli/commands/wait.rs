@@ -12,9 +12,13 @@
 use crate::cli::CliError;
 use crate::client::{
     Client, ClusterDeploymentStatus, ClusterStatusContext, FailureReason, HydrationStatusUpdate,
+    quote_identifier,
 };
 use crate::config::Settings;
 use crate::log;
+use crate::project::SchemaQualifier;
+use crate::project::analysis::assertions::Assertion;
+use crate::project::ir::object_id::ObjectId;
 use crate::{info, info_nonl};
 use crossterm::{
     cursor::{Hide, MoveToColumn, MoveUp, Show},
@@ -23,7 +27,9 @@ use crossterm::{
 };
 use futures::StreamExt;
 use owo_colors::{OwoColorize, Stream, Style};
-use std::collections::BTreeMap;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
 use std::io::{self, Write};
 use std::pin::pin;
 use std::time::{Duration, Instant};
@@ -35,6 +41,8 @@ use std::time::{Duration, Instant};
 /// - Subscribes to cluster hydration status
 /// - Shows a live dashboard tracking hydration, lag, and health for each cluster
 /// - Exits when all clusters are ready or timeout is reached
+/// - Evaluates the deployment's data-quality assertions against the staging
+///   schemas once every cluster is ready
 ///
 /// # Arguments
 /// * `settings` - Resolved CLI settings (profile, project directory, etc.)
@@ -44,11 +52,12 @@ use std::time::{Duration, Instant};
 /// * `allowed_lag_secs` - Maximum allowed lag in seconds before marking as "lagging"
 ///
 /// # Returns
-/// `Ok(())` once the deployment is ready.
+/// `Ok(())` once the deployment is ready and its assertions hold.
 ///
 /// # Errors
 /// Surfaces connection errors (including unknown or already-promoted
-/// deployments) and `CliError::ReadyTimeout` when the timeout is reached.
+/// deployments), `CliError::ReadyTimeout` when the timeout is reached, and
+/// `CliError::AssertionsFailed` when an assertion does not hold.
 pub async fn run(
     settings: &Settings,
     deploy_id: &str,
@@ -64,21 +73,208 @@ pub async fn run(
     // Validate staging deployment exists and is not promoted
     client.deployments().validate_staging(deploy_id).await?;
 
-    if log::json_output_enabled() {
+    let ready = if log::json_output_enabled() {
         if once {
-            return run_snapshot_json(deploy_id, &client, allowed_lag_secs).await;
+            run_snapshot_json(deploy_id, &client, allowed_lag_secs).await
         } else {
-            return run_continuous_json(deploy_id, &mut client, timeout, allowed_lag_secs).await;
+            run_continuous_json(deploy_id, &mut client, timeout, allowed_lag_secs).await
         }
-    }
-
-    if once {
+    } else if once {
         // Snapshot mode: query once and display status
         run_snapshot(deploy_id, &client, allowed_lag_secs).await
     } else {
         // Continuous mode: subscribe and track with live dashboard
         run_continuous(deploy_id, &mut client, timeout, allowed_lag_secs).await
+    };
+    ready?;
+
+    // Assertions only mean something against hydrated data, so they run once
+    // every cluster is ready.
+    check_assertions(deploy_id, &client).await
+}
+
+/// Outcome of evaluating one recorded assertion against the staging schemas.
+#[derive(Debug, Serialize)]
+struct AssertionOutcome {
+    object: String,
+    assertion: String,
+    passed: bool,
+    detail: String,
+}
+
+/// Assertion results for a staging deployment.
+#[derive(Debug, Serialize)]
+struct AssertionReport {
+    deploy_id: String,
+    assertions: Vec<AssertionOutcome>,
+    passed: bool,
+}
+
+impl fmt::Display for AssertionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "  Assertions:")?;
+        for outcome in &self.assertions {
+            let mark = if outcome.passed {
+                format!(
+                    "{}",
+                    "\u{2713}".if_supports_color(Stream::Stderr, |t| t.green())
+                )
+            } else {
+                format!(
+                    "{}",
+                    "\u{2717}".if_supports_color(Stream::Stderr, |t| t.red())
+                )
+            };
+            write!(
+                f,
+                "\n    {} {} {} ({})",
+                mark, outcome.object, outcome.assertion, outcome.detail
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Evaluate the assertions `stage` recorded for a deployment.
+///
+/// Each assertion runs against the object in its staging schema. A
+/// `references` target that was staged too is read from its staging schema,
+/// otherwise from production. `row_count` is skipped for objects that do not
+/// exist in production yet.
+///
+/// # Errors
+/// Returns `CliError::AssertionsFailed` if any assertion does not hold.
+async fn check_assertions(deploy_id: &str, client: &Client) -> Result<(), CliError> {
+    let records = client.deployments().get_assertions(deploy_id).await?;
+    if records.is_empty() {
+        return Ok(());
+    }
+
+    let staging_schemas: BTreeSet<SchemaQualifier> = client
+        .introspection()
+        .get_staging_schemas(deploy_id)
+        .await?
+        .into_iter()
+        .collect();
+    let staged_name = |object: &ObjectId| {
+        let staging = SchemaQualifier::new(
+            object.expect_database().to_string(),
+            format!("{}_{}", object.schema(), deploy_id),
+        );
+        if staging_schemas.contains(&staging) {
+            relation_name(&staging.database, &staging.schema, object.object())
+        } else {
+            production_name(object)
+        }
+    };
+
+    let mut outcomes = Vec::with_capacity(records.len());
+    for record in records {
+        let object = ObjectId::new(record.database, record.schema, record.object);
+        let assertion = Assertion::parse(&record.assertion, &object).map_err(|e| {
+            CliError::Message(format!(
+                "recorded assertion '{}' on {} is invalid: {}",
+                record.assertion, object, e
+            ))
+        })?;
+        let relation = staged_name(&object);
+        let (passed, detail) = match &assertion {
+            Assertion::RowCount { min, max } => {
+                if !relation_exists(client, &object).await? {
+                    (true, "skipped, not in production".to_string())
+                } else {
+                    let staged = count_rows(client, &relation).await?;
+                    let production = count_rows(client, &production_name(&object)).await?;
+                    #[allow(clippy::as_conversions)]
+                    let passed = if production == 0 {
+                        staged == 0
+                    } else {
+                        let ratio = staged as f64 / production as f64;
+                        *min <= ratio && ratio <= *max
+                    };
+                    (
+                        passed,
+                        format!("{} row(s), {} in production", staged, production),
+                    )
+                }
+            }
+            _ => {
+                let target = match &assertion {
+                    Assertion::References { target, .. } => staged_name(target),
+                    _ => String::new(),
+                };
+                let query = assertion
+                    .violations_query(&relation, &target)
+                    .expect("only row_count lacks a violations query");
+                let violations: i64 = client.query_one(&query, &[]).await?.get(0);
+                (violations == 0, format!("{} violating row(s)", violations))
+            }
+        };
+        outcomes.push(AssertionOutcome {
+            object: object.to_string(),
+            assertion: assertion.to_string(),
+            passed,
+            detail,
+        });
+    }
+
+    let failed = outcomes.iter().filter(|o| !o.passed).count();
+    log::output(&AssertionReport {
+        deploy_id: deploy_id.to_string(),
+        assertions: outcomes,
+        passed: failed == 0,
+    });
+
+    if failed > 0 {
+        return Err(CliError::AssertionsFailed {
+            name: deploy_id.to_string(),
+            failed,
+        });
     }
+    Ok(())
+}
+
+/// The quoted production name of `object`.
+fn production_name(object: &ObjectId) -> String {
+    relation_name(object.expect_database(), object.schema(), object.object())
+}
+
+fn relation_name(database: &str, schema: &str, object: &str) -> String {
+    format!(
+        "{}.{}.{}",
+        quote_identifier(database),
+        quote_identifier(schema),
+        quote_identifier(object)
+    )
+}
+
+/// Whether `object` exists under its production name.
+async fn relation_exists(client: &Client, object: &ObjectId) -> Result<bool, CliError> {
+    let row = client
+        .query_one(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM mz_objects o
+                JOIN mz_schemas s ON o.schema_id = s.id
+                JOIN mz_databases d ON s.database_id = d.id
+                WHERE d.name = $1 AND s.name = $2 AND o.name = $3
+            ) AS exists
+            "#,
+            &[
+                &object.expect_database(),
+                &object.schema(),
+                &object.object(),
+            ],
+        )
+        .await?;
+    Ok(row.get("exists"))
+}
+
+async fn count_rows(client: &Client, relation: &str) -> Result<i64, CliError> {
+    let row = client
+        .query_one(&format!("SELECT count(*)::bigint FROM {}", relation), &[])
+        .await?;
+    Ok(row.get(0))
 }
 
 /// Run in snapshot mode: query hydration status once and display.
@@ -17,6 +17,7 @@ use crate::client::{
     ConflictRecord, ConnectionError, DatabaseValidationError, ProductionClusterRecord,
 };
 use crate::config::ConfigError;
+use crate::project::analysis::assertions::AssertionError;
 use crate::project::analysis::deployment_snapshot::DeploymentSnapshotError;
 use crate::project::compiler::typecheck::TypeCheckError;
 use crate::project::error::{DependencyError, ProjectError};
@@ -158,6 +159,18 @@ pub enum CliError {
     #[error("invalid staging environment name: '{name}'")]
     InvalidEnvironmentName { name: String },
 
+    /// No earlier promoted deployment exists to roll back to
+    #[error("no earlier promoted deployment to roll back to")]
+    NoRollbackTarget,
+
+    /// The rollback target was staged but never promoted
+    #[error("deployment '{deploy_id}' was never promoted")]
+    RollbackTargetNotPromoted { deploy_id: String },
+
+    /// The rollback target's state cannot be rebuilt from the tracking tables
+    #[error("cannot roll back to deployment '{deploy_id}': {reason}")]
+    RollbackUnavailable { deploy_id: String, reason: String },
+
     /// Schema does not exist in database
     #[error("schema '{schema}' does not exist in database '{database}'")]
     SchemaNotFound { database: String, schema: String },
@@ -171,6 +184,15 @@ pub enum CliError {
         plural = if *failed == 1 { "" } else { "s" })]
     TestsFailed { failed: usize, passed: usize },
 
+    /// Project files are not in canonical format (`fmt --check`)
+    #[error("{count} file{plural} would be reformatted",
+        plural = if *count == 1 { "" } else { "s" })]
+    UnformattedFiles { count: usize },
+
+    /// A file could not be formatted because it does not parse
+    #[error("failed to format {path}: {message}")]
+    FormatFailed { path: String, message: String },
+
     /// Tests filter did not match anythinh
     #[error("no tests matched filter '{filter}'")]
     TestsFilterMissed { filter: String },
@@ -256,6 +278,23 @@ pub enum CliError {
     #[error("declared dependencies not found in target database")]
     DeclaredDependenciesMissing { missing: Vec<ObjectId> },
 
+    /// Output columns of sink inputs or externally consumed objects changed
+    /// incompatibly
+    #[error("breaking changes to objects consumed outside the project")]
+    BreakingChanges { changes: Vec<String> },
+
+    /// Lint rules configured as `error` reported violations
+    #[error("{count} lint error(s)")]
+    LintFailed { count: usize },
+
+    /// An `-- @assert` directive is malformed or on an unsupported object
+    #[error("invalid assertion at {0}")]
+    InvalidAssertion(AssertionError),
+
+    /// Data-quality assertions failed against a staging deployment
+    #[error("{failed} assertion(s) failed for deployment '{name}'")]
+    AssertionsFailed { name: String, failed: usize },
+
     /// Generic error message
     #[error("{0}")]
     Message(String),
@@ -480,6 +519,18 @@ impl CliError {
                 "environment names must contain only alphanumeric characters, hyphens, and underscores"
                     .to_string(),
             ),
+            Self::NoRollbackTarget => Some(
+                "rollback needs at least two promoted deployments. Run 'mz-deploy log' to see history"
+                    .to_string(),
+            ),
+            Self::RollbackTargetNotPromoted { .. } => Some(
+                "only promoted deployments can be restored. Run 'mz-deploy log' to list them"
+                    .to_string(),
+            ),
+            Self::RollbackUnavailable { .. } => Some(
+                "check out the deployment's commit and run 'mz-deploy stage' and 'mz-deploy promote' instead"
+                    .to_string(),
+            ),
             Self::SchemaNotFound { database, schema } => Some(format!(
                 "create the schema first, or check that you're connected to the correct database.\n  \
                  CREATE SCHEMA {}.{}",
@@ -493,6 +544,8 @@ impl CliError {
                 "M.1-small".if_supports_color(Stream::Stderr, |t| t.cyan())
             )),
             Self::TestsFailed { .. } => None,
+            Self::UnformattedFiles { .. } => Some("run 'mz-deploy fmt' to format them".to_string()),
+            Self::FormatFailed { .. } => None,
             Self::TestValidationFailed(_) => Some(
                 "review the validation error above and update your test to match the schema.\n\
                  Run 'mz-deploy compile' to regenerate the type cache if needed"
@@ -603,6 +656,40 @@ impl CliError {
                     list
                 ))
             }
+            Self::BreakingChanges { changes } => {
+                let list = changes
+                    .iter()
+                    .map(|c| format!("  {} {}", "×".if_supports_color(Stream::Stderr, |t| t.red()), c))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Some(format!(
+                    "{}\n\nsinks or other projects read these columns. If the change is \
+                     intended, add {} to the object's file\n  or pass {}. Run {} after \
+                     promoting to refresh the baseline.",
+                    list,
+                    "-- PRAGMA ALLOW_BREAKING_CHANGE;".if_supports_color(Stream::Stderr, |t| t.cyan()),
+                    "--allow-breaking".if_supports_color(Stream::Stderr, |t| t.cyan()),
+                    "mz-deploy lock".if_supports_color(Stream::Stderr, |t| t.cyan()),
+                ))
+            }
+            Self::InvalidAssertion(_) => Some(format!(
+                "supported assertions are {}, {},\n  {}, and {}.",
+                "unique(col, ...)".if_supports_color(Stream::Stderr, |t| t.cyan()),
+                "not_null(col, ...)".if_supports_color(Stream::Stderr, |t| t.cyan()),
+                "references(col, ..., target(col, ...))".if_supports_color(Stream::Stderr, |t| t.cyan()),
+                "row_count(min, max)".if_supports_color(Stream::Stderr, |t| t.cyan()),
+            )),
+            Self::AssertionsFailed { .. } => Some(format!(
+                "the staged data does not satisfy the assertions above. Fix the views or the \
+                 assertions and re-stage,\n  or promote anyway with {}.",
+                "--no-ready-check".if_supports_color(Stream::Stderr, |t| t.cyan()),
+            )),
+            Self::LintFailed { .. } => Some(format!(
+                "fix the violations above, lower the rule's severity in the {} table of \
+                 project.toml,\n  or add {} to the object's file.",
+                "[lint]".if_supports_color(Stream::Stderr, |t| t.cyan()),
+                "-- mz-deploy:allow(<rule>)".if_supports_color(Stream::Stderr, |t| t.cyan()),
+            )),
             Self::Config(ConfigError::NoProfileConfigured) => Some(format!(
                 "record a default profile for this project:\n  \
                  {}\n\n\
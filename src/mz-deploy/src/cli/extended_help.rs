@@ -35,10 +35,13 @@ const COMMANDS: &[(&str, &str)] = &[
     ("clean", include_str!("help/clean.md")),
     ("compile", include_str!("help/compile.md")),
     ("explain", include_str!("help/explain.md")),
+    ("fmt", include_str!("help/fmt.md")),
+    ("graph", include_str!("help/graph.md")),
     ("init", include_str!("help/init.md")),
     ("debug", include_str!("help/debug.md")),
     ("delete", include_str!("help/delete.md")),
     ("dev", include_str!("help/dev.md")),
+    ("estimate", include_str!("help/estimate.md")),
     ("promote", include_str!("help/promote.md")),
     ("describe", include_str!("help/describe.md")),
     ("list", include_str!("help/list.md")),
@@ -48,6 +51,7 @@ const COMMANDS: &[(&str, &str)] = &[
     ("mcp", include_str!("help/mcp.md")),
     ("new", include_str!("help/new.md")),
     ("profiles", include_str!("help/profiles.md")),
+    ("rollback", include_str!("help/rollback.md")),
     ("setup", include_str!("help/setup.md")),
     ("sql", include_str!("help/sql.md")),
     ("wait", include_str!("help/wait.md")),
@@ -64,6 +68,7 @@ const ALIASES: &[(&str, &str)] = &[
     ("deployments", "list"),
     ("gen-data-contracts", "lock"),
     ("history", "log"),
+    ("lineage", "graph"),
     ("network-policies", "apply-network-policies"),
     ("deploy", "promote"),
     ("profile", "profiles"),
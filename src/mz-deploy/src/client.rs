@@ -126,10 +126,10 @@ pub use deployment_ops::{
     HydrationStatusUpdate,
 };
 pub use errors::{ConnectionError, DatabaseValidationError, format_relative_path};
-pub use introspection::DependentSink;
+pub use introspection::{ClusterIndex, DependentSink, ObjectResourceUsage};
 pub use models::{
-    ApplyState, Cluster, ClusterConfig, ClusterOptions, ClusterReplica, ConflictRecord,
+    ApplyState, AssertionRecord, Cluster, ClusterConfig, ClusterOptions, ClusterReplica, ConflictRecord,
     DeploymentDetails, DeploymentHistoryEntry, DeploymentKind, DeploymentMetadata, DeploymentMode,
-    DeploymentObjectRecord, ObjectGrant, PendingStatement, ProductionClusterRecord,
-    ReplacementMvRecord, SchemaDeploymentRecord, StagingDeployment,
+    DeploymentObjectRecord, ObjectGrant, ObjectStatement, PendingStatement,
+    ProductionClusterRecord, ReplacementMvRecord, SchemaDeploymentRecord, StagingDeployment,
 };
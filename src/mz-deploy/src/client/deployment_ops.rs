@@ -59,8 +59,8 @@
 use crate::client::connection::{Client, DeploymentsClient, DeploymentsClientMut};
 use crate::client::errors::ConnectionError;
 use crate::client::models::{
-    ApplyState, ConflictRecord, DeploymentDetails, DeploymentHistoryEntry, DeploymentKind,
-    DeploymentMetadata, DeploymentMode, DeploymentObjectRecord, PendingStatement,
+    ApplyState, AssertionRecord, ConflictRecord, DeploymentDetails, DeploymentHistoryEntry, DeploymentKind,
+    DeploymentMetadata, DeploymentMode, DeploymentObjectRecord, ObjectStatement, PendingStatement,
     ProductionClusterRecord, SchemaDeploymentRecord, StagingDeployment,
 };
 use crate::client::quote_identifier;
@@ -725,28 +725,32 @@ pub(super) async fn list_deployment_history(
                    d.commit,
                    d.kind,
                    d.database,
-                   d.schema
+                   d.schema,
+                   r.target_deploy_id
             FROM _mz_deploy.public.deployments d
             JOIN unique_deployments u
               ON d.deploy_id = u.deploy_id
               AND d.promoted_at = u.promoted_at
               AND d.deployed_by = u.deployed_by
+            LEFT JOIN _mz_deploy.public.rollbacks r ON d.deploy_id = r.deploy_id
             ORDER BY d.promoted_at DESC, d.database, d.schema
         "#,
             limit
         )
     } else {
         r#"
-            SELECT deploy_id,
-                   promoted_at,
-                   deployed_by,
-                   commit,
-                   kind,
-                   database,
-                   schema
-            FROM _mz_deploy.public.deployments
-            WHERE promoted_at IS NOT NULL
-            ORDER BY promoted_at DESC, database, schema
+            SELECT d.deploy_id,
+                   d.promoted_at,
+                   d.deployed_by,
+                   d.commit,
+                   d.kind,
+                   d.database,
+                   d.schema,
+                   r.target_deploy_id
+            FROM _mz_deploy.public.deployments d
+            LEFT JOIN _mz_deploy.public.rollbacks r ON d.deploy_id = r.deploy_id
+            WHERE d.promoted_at IS NOT NULL
+            ORDER BY d.promoted_at DESC, d.database, d.schema
         "#
         .to_string()
     };
@@ -765,6 +769,7 @@ pub(super) async fn list_deployment_history(
         let kind_str: String = row.get("kind");
         let database: String = row.get("database");
         let schema: String = row.get("schema");
+        let rollback_of: Option<String> = row.get("target_deploy_id");
 
         // Check if this is a new deployment or same as current
         if current_deploy_id.as_ref() != Some(&deploy_id) {
@@ -778,6 +783,7 @@ pub(super) async fn list_deployment_history(
                 git_commit,
                 kind,
                 schemas: vec![SchemaQualifier::new(database, schema)],
+                rollback_of,
             });
             current_deploy_id = Some(deploy_id);
         } else {
@@ -1300,6 +1306,187 @@ pub(super) async fn insert_replacement_mvs(
     Ok(())
 }
 
+/// Insert the production-name definitions recorded for a deployment.
+pub(super) async fn insert_object_statements(
+    client: &Client,
+    statements: &[ObjectStatement],
+) -> Result<(), ConnectionError> {
+    let insert_sql = r#"
+        INSERT INTO _mz_deploy.tables.object_statements
+            (deploy_id, sequence_num, database, schema, object, statement_kind, statement_sql)
+        VALUES
+            ($1, $2, $3, $4, $5, $6, $7)
+    "#;
+
+    for stmt in statements {
+        client
+            .execute(
+                insert_sql,
+                &[
+                    &stmt.deploy_id,
+                    &stmt.sequence_num,
+                    &stmt.database,
+                    &stmt.schema,
+                    &stmt.object,
+                    &stmt.statement_kind,
+                    &stmt.statement_sql,
+                ],
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Get the recorded definitions for a deployment, in recording order.
+pub(super) async fn get_object_statements(
+    client: &Client,
+    deploy_id: &str,
+) -> Result<Vec<ObjectStatement>, ConnectionError> {
+    let rows = client
+        .query(
+            r#"SELECT deploy_id, sequence_num, database, schema, object,
+                      statement_kind, statement_sql
+               FROM _mz_deploy.public.object_statements
+               WHERE deploy_id = $1
+               ORDER BY sequence_num"#,
+            &[&deploy_id],
+        )
+        .await?;
+
+    Ok(rows
+        .iter()
+        .map(|row| ObjectStatement {
+            deploy_id: row.get("deploy_id"),
+            sequence_num: row.get("sequence_num"),
+            database: row.get("database"),
+            schema: row.get("schema"),
+            object: row.get("object"),
+            statement_kind: row.get("statement_kind"),
+            statement_sql: row.get("statement_sql"),
+        })
+        .collect())
+}
+
+/// Delete all recorded definitions for a deployment.
+pub(super) async fn delete_object_statements(
+    client: &Client,
+    deploy_id: &str,
+) -> Result<(), ConnectionError> {
+    client
+        .execute(
+            "DELETE FROM _mz_deploy.tables.object_statements WHERE deploy_id = $1",
+            &[&deploy_id],
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Insert the data-quality assertions recorded for a deployment.
+pub(super) async fn insert_assertions(
+    client: &Client,
+    assertions: &[AssertionRecord],
+) -> Result<(), ConnectionError> {
+    let insert_sql = r#"
+        INSERT INTO _mz_deploy.tables.assertions
+            (deploy_id, database, schema, object, assertion)
+        VALUES
+            ($1, $2, $3, $4, $5)
+    "#;
+
+    for record in assertions {
+        client
+            .execute(
+                insert_sql,
+                &[
+                    &record.deploy_id,
+                    &record.database,
+                    &record.schema,
+                    &record.object,
+                    &record.assertion,
+                ],
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Get the assertions recorded for a deployment.
+pub(super) async fn get_assertions(
+    client: &Client,
+    deploy_id: &str,
+) -> Result<Vec<AssertionRecord>, ConnectionError> {
+    let rows = client
+        .query(
+            r#"SELECT deploy_id, database, schema, object, assertion
+               FROM _mz_deploy.public.assertions
+               WHERE deploy_id = $1
+               ORDER BY database, schema, object, assertion"#,
+            &[&deploy_id],
+        )
+        .await?;
+
+    Ok(rows
+        .iter()
+        .map(|row| AssertionRecord {
+            deploy_id: row.get("deploy_id"),
+            database: row.get("database"),
+            schema: row.get("schema"),
+            object: row.get("object"),
+            assertion: row.get("assertion"),
+        })
+        .collect())
+}
+
+/// Delete all assertions recorded for a deployment.
+pub(super) async fn delete_assertions(
+    client: &Client,
+    deploy_id: &str,
+) -> Result<(), ConnectionError> {
+    client
+        .execute(
+            "DELETE FROM _mz_deploy.tables.assertions WHERE deploy_id = $1",
+            &[&deploy_id],
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Record that `deploy_id` restores the state of `target_deploy_id`.
+pub(super) async fn insert_rollback(
+    client: &Client,
+    deploy_id: &str,
+    target_deploy_id: &str,
+) -> Result<(), ConnectionError> {
+    client
+        .execute(
+            r#"INSERT INTO _mz_deploy.tables.rollbacks (deploy_id, target_deploy_id)
+               VALUES ($1, $2)"#,
+            &[&deploy_id, &target_deploy_id],
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Delete the rollback record for a deployment, if any.
+pub(super) async fn delete_rollback(
+    client: &Client,
+    deploy_id: &str,
+) -> Result<(), ConnectionError> {
+    client
+        .execute(
+            "DELETE FROM _mz_deploy.tables.rollbacks WHERE deploy_id = $1",
+            &[&deploy_id],
+        )
+        .await?;
+
+    Ok(())
+}
+
 /// Get replacement MV records for a deployment.
 pub(super) async fn get_replacement_mvs(
     client: &Client,
@@ -1513,6 +1700,54 @@ impl DeploymentsClient<'_> {
         insert_replacement_mvs(self.client, records).await
     }
 
+    pub async fn insert_object_statements(
+        &self,
+        statements: &[ObjectStatement],
+    ) -> Result<(), ConnectionError> {
+        insert_object_statements(self.client, statements).await
+    }
+
+    pub async fn get_object_statements(
+        &self,
+        deploy_id: &str,
+    ) -> Result<Vec<ObjectStatement>, ConnectionError> {
+        get_object_statements(self.client, deploy_id).await
+    }
+
+    pub async fn delete_object_statements(&self, deploy_id: &str) -> Result<(), ConnectionError> {
+        delete_object_statements(self.client, deploy_id).await
+    }
+
+    pub async fn insert_assertions(
+        &self,
+        assertions: &[AssertionRecord],
+    ) -> Result<(), ConnectionError> {
+        insert_assertions(self.client, assertions).await
+    }
+
+    pub async fn get_assertions(
+        &self,
+        deploy_id: &str,
+    ) -> Result<Vec<AssertionRecord>, ConnectionError> {
+        get_assertions(self.client, deploy_id).await
+    }
+
+    pub async fn delete_assertions(&self, deploy_id: &str) -> Result<(), ConnectionError> {
+        delete_assertions(self.client, deploy_id).await
+    }
+
+    pub async fn insert_rollback(
+        &self,
+        deploy_id: &str,
+        target_deploy_id: &str,
+    ) -> Result<(), ConnectionError> {
+        insert_rollback(self.client, deploy_id, target_deploy_id).await
+    }
+
+    pub async fn delete_rollback(&self, deploy_id: &str) -> Result<(), ConnectionError> {
+        delete_rollback(self.client, deploy_id).await
+    }
+
     pub async fn get_replacement_mvs(
         &self,
         deploy_id: &str,
@@ -41,6 +41,41 @@ pub struct DependentSink {
     pub dependency_type: String,
 }
 
+/// Resource usage of a dataflow-backed object (materialized view or index),
+/// read from the introspection data of the cluster it runs on.
+///
+/// Used by `estimate` to compare a staged deployment against the production
+/// objects it replaces.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ObjectResourceUsage {
+    pub cluster: String,
+    pub database: String,
+    pub schema: String,
+    pub name: String,
+    /// `materialized-view` or `index`
+    pub object_type: String,
+    /// Arrangement heap and batcher size in bytes on the largest replica,
+    /// rounded by Materialize to the nearest 10 MiB.
+    pub arrangement_bytes: i64,
+    /// Records held in the object's arrangements, when the cluster has a
+    /// replica to ask.
+    pub records: Option<i64>,
+    /// Hydration time on the fastest replica, `None` until hydrated.
+    pub hydration_ms: Option<u64>,
+}
+
+/// An index installed on a cluster, with the object it indexes.
+///
+/// Used by rollback to recreate indexes on staging clusters for objects
+/// that are not part of the restored deployment.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ClusterIndex {
+    /// The indexed object
+    pub on: ObjectId,
+    /// `SHOW CREATE INDEX` output for the index
+    pub create_sql: String,
+}
+
 /// Check if a schema exists in the specified database.
 pub(super) async fn schema_exists(
     client: &Client,
@@ -666,6 +701,154 @@ pub(super) async fn find_sinks_depending_on_schemas(
         .collect())
 }
 
+/// Find objects in the specified schemas that are read by views, materialized
+/// views, or sinks outside those schemas.
+///
+/// Used by breaking-change detection to discover objects another project
+/// depends on. Dependents in a staging copy of one of the schemas
+/// (`<schema>_<deploy_id>`) belong to an in-flight deployment of the same
+/// project and are not counted as consumers.
+pub(super) async fn find_external_consumers(
+    client: &Client,
+    schemas: &[SchemaQualifier],
+) -> Result<BTreeSet<ObjectId>, ConnectionError> {
+    if schemas.is_empty() {
+        return Ok(BTreeSet::new());
+    }
+
+    let mut conditions = Vec::new();
+    let mut param_idx = 1;
+    for _ in schemas {
+        conditions.push(format!(
+            "(ref_db.name = ${} AND ref_schema.name = ${})",
+            param_idx,
+            param_idx + 1
+        ));
+        param_idx += 2;
+    }
+
+    let query = format!(
+        r#"
+        SELECT DISTINCT
+            ref_db.name AS ref_database,
+            ref_schema.name AS ref_schema,
+            ref_obj.name AS ref_name,
+            dep_db.name AS dependent_database,
+            dep_schema.name AS dependent_schema
+        FROM mz_internal.mz_object_dependencies deps
+        JOIN mz_objects ref_obj ON deps.referenced_object_id = ref_obj.id
+        JOIN mz_schemas ref_schema ON ref_obj.schema_id = ref_schema.id
+        JOIN mz_databases ref_db ON ref_schema.database_id = ref_db.id
+        JOIN mz_objects dep_obj ON deps.object_id = dep_obj.id
+        JOIN mz_schemas dep_schema ON dep_obj.schema_id = dep_schema.id
+        JOIN mz_databases dep_db ON dep_schema.database_id = dep_db.id
+        WHERE ({})
+          AND dep_obj.type IN ('view', 'materialized-view', 'sink')
+        "#,
+        conditions.join(" OR ")
+    );
+
+    let mut params: Vec<&(dyn ToSql + Sync)> = Vec::new();
+    for sq in schemas {
+        params.push(&sq.database);
+        params.push(&sq.schema);
+    }
+
+    let rows = client.query(&query, &params).await?;
+
+    let internal = |database: &str, schema: &str| {
+        schemas.iter().any(|sq| {
+            sq.database == database
+                && (sq.schema == schema
+                    || schema
+                        .strip_prefix(sq.schema.as_str())
+                        .is_some_and(|rest| rest.starts_with('_')))
+        })
+    };
+
+    Ok(rows
+        .iter()
+        .filter(|row| !internal(row.get("dependent_database"), row.get("dependent_schema")))
+        .map(|row| {
+            ObjectId::new(
+                row.get("ref_database"),
+                row.get("ref_schema"),
+                row.get("ref_name"),
+            )
+        })
+        .collect())
+}
+
+/// List the indexes installed on any of the given clusters.
+///
+/// Names come from the catalog, and definitions from `SHOW CREATE INDEX`,
+/// which returns fully qualified SQL that can be replayed as-is.
+pub(super) async fn get_cluster_indexes(
+    client: &Client,
+    clusters: &[String],
+) -> Result<Vec<ClusterIndex>, ConnectionError> {
+    if clusters.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let query = format!(
+        r#"
+        SELECT
+            idx_db.name AS index_database,
+            idx_schema.name AS index_schema,
+            i.name AS index_name,
+            on_db.name AS on_database,
+            on_schema.name AS on_schema,
+            on_obj.name AS on_name
+        FROM mz_catalog.mz_indexes i
+        JOIN mz_catalog.mz_clusters c ON i.cluster_id = c.id
+        JOIN mz_catalog.mz_schemas idx_schema ON i.schema_id = idx_schema.id
+        JOIN mz_catalog.mz_databases idx_db ON idx_schema.database_id = idx_db.id
+        JOIN mz_catalog.mz_objects on_obj ON i.on_id = on_obj.id
+        JOIN mz_catalog.mz_schemas on_schema ON on_obj.schema_id = on_schema.id
+        JOIN mz_catalog.mz_databases on_db ON on_schema.database_id = on_db.id
+        WHERE c.name IN ({})
+        ORDER BY on_db.name, on_schema.name, on_obj.name, i.name
+        "#,
+        sql_placeholders(clusters.len())
+    );
+
+    let mut params: Vec<&(dyn ToSql + Sync)> = Vec::new();
+    for name in clusters {
+        params.push(name);
+    }
+
+    let rows = client.query(&query, &params).await?;
+
+    let mut indexes = Vec::new();
+    for row in rows {
+        let show = format!(
+            "SHOW CREATE INDEX {}.{}.{}",
+            quote_identifier(row.get("index_database")),
+            quote_identifier(row.get("index_schema")),
+            quote_identifier(row.get("index_name"))
+        );
+        let Some(create_sql) = client
+            .query(&show, &[])
+            .await?
+            .first()
+            .map(|row| row.get("create_sql"))
+        else {
+            continue;
+        };
+        indexes.push(ClusterIndex {
+            on: ObjectId::new(
+                row.get("on_database"),
+                row.get("on_schema"),
+                row.get("on_name"),
+            ),
+            create_sql,
+        });
+    }
+
+    Ok(indexes)
+}
+
 /// Check if a connection exists in the specified database and schema.
 pub(super) async fn check_connection_exists(
     client: &Client,
@@ -758,6 +941,151 @@ pub(super) async fn get_staging_clusters(
     Ok(rows.iter().map(|row| row.get("name")).collect())
 }
 
+/// Report arrangement memory, record counts, and hydration time for every
+/// materialized view and index on the given clusters.
+///
+/// Memory and hydration time come from the region-wide
+/// `mz_object_arrangement_sizes` and `mz_compute_hydration_times`
+/// collections. Record counts only exist in per-replica introspection, so
+/// they are read by targeting one replica of each cluster in turn; clusters
+/// without replicas report no record counts.
+pub(super) async fn get_object_resource_usage(
+    client: &Client,
+    clusters: &[String],
+) -> Result<Vec<ObjectResourceUsage>, ConnectionError> {
+    if clusters.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let query = format!(
+        r#"
+        WITH
+        objects AS (
+            SELECT o.id, o.name, o.type, s.name AS schema_name,
+                d.name AS database_name, c.name AS cluster_name
+            FROM mz_catalog.mz_objects o
+            JOIN mz_catalog.mz_schemas s ON o.schema_id = s.id
+            JOIN mz_catalog.mz_databases d ON s.database_id = d.id
+            JOIN mz_catalog.mz_clusters c ON o.cluster_id = c.id
+            WHERE c.name IN ({})
+                AND o.type IN ('materialized-view', 'index')
+        ),
+        memory AS (
+            SELECT object_id, MAX(size) AS size
+            FROM mz_internal.mz_object_arrangement_sizes
+            GROUP BY object_id
+        ),
+        hydration AS (
+            SELECT object_id, MIN(time_ns) AS time_ns
+            FROM mz_internal.mz_compute_hydration_times
+            GROUP BY object_id
+        )
+        SELECT
+            o.id, o.name, o.type, o.schema_name, o.database_name, o.cluster_name,
+            COALESCE(m.size, 0)::bigint AS arrangement_bytes,
+            (h.time_ns / 1000000)::bigint AS hydration_ms
+        FROM objects o
+        LEFT JOIN memory m ON m.object_id = o.id
+        LEFT JOIN hydration h ON h.object_id = o.id
+        ORDER BY o.cluster_name, o.database_name, o.schema_name, o.name
+        "#,
+        sql_placeholders(clusters.len())
+    );
+
+    let mut params: Vec<&(dyn ToSql + Sync)> = Vec::new();
+    for name in clusters {
+        params.push(name);
+    }
+
+    let rows = client.query(&query, &params).await?;
+
+    let mut records = BTreeMap::new();
+    for cluster in clusters {
+        records.extend(get_arrangement_records(client, cluster).await?);
+    }
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let id: String = row.get("id");
+            let hydration_ms: Option<i64> = row.get("hydration_ms");
+            ObjectResourceUsage {
+                cluster: row.get("cluster_name"),
+                database: row.get("database_name"),
+                schema: row.get("schema_name"),
+                name: row.get("name"),
+                object_type: row.get("type"),
+                arrangement_bytes: row.get("arrangement_bytes"),
+                records: records.get(&id).copied(),
+                hydration_ms: hydration_ms.and_then(|ms| u64::try_from(ms).ok()),
+            }
+        })
+        .collect())
+}
+
+/// Count the records in each exported object's arrangements on one replica
+/// of `cluster`, keyed by catalog ID.
+///
+/// Per-replica introspection relations are only readable with the session
+/// targeting that replica, so this sets `cluster` and `cluster_replica` for
+/// the duration of the query and resets them afterwards.
+async fn get_arrangement_records(
+    client: &Client,
+    cluster: &str,
+) -> Result<BTreeMap<String, i64>, ConnectionError> {
+    let replica = client
+        .query(
+            r#"
+            SELECT r.name
+            FROM mz_catalog.mz_cluster_replicas r
+            JOIN mz_catalog.mz_clusters c ON r.cluster_id = c.id
+            WHERE c.name = $1
+            ORDER BY r.name
+            LIMIT 1
+            "#,
+            &[&cluster],
+        )
+        .await?
+        .first()
+        .map(|row| row.get::<_, String>("name"));
+    let Some(replica) = replica else {
+        return Ok(BTreeMap::new());
+    };
+
+    client
+        .batch_execute(&format!(
+            "SET cluster = {}; SET cluster_replica = {}",
+            quote_identifier(cluster),
+            quote_identifier(&replica)
+        ))
+        .await?;
+
+    let result = client
+        .query(
+            r#"
+            SELECT ce.export_id, SUM(das.records)::bigint AS records
+            FROM mz_introspection.mz_compute_exports ce
+            JOIN mz_introspection.mz_dataflow_arrangement_sizes das
+                ON das.id = ce.dataflow_id
+            GROUP BY ce.export_id
+            "#,
+            &[],
+        )
+        .await;
+
+    client
+        .batch_execute("RESET cluster_replica; RESET cluster")
+        .await?;
+
+    Ok(result?
+        .iter()
+        .filter_map(|row| {
+            let records: Option<i64> = row.get("records");
+            records.map(|records| (row.get("export_id"), records))
+        })
+        .collect())
+}
+
 /// Map a Materialize object type string to its DROP keyword.
 fn mz_type_to_drop_keyword(obj_type: &str) -> Option<&'static str> {
     match obj_type {
@@ -1235,6 +1563,22 @@ impl IntrospectionClient<'_> {
         check_clusters_exist(self.client, clusters).await
     }
 
+    /// List the indexes installed on any of the given clusters.
+    pub async fn get_cluster_indexes(
+        &self,
+        clusters: &[String],
+    ) -> Result<Vec<ClusterIndex>, ConnectionError> {
+        get_cluster_indexes(self.client, clusters).await
+    }
+
+    /// Find objects in the specified schemas read by objects outside them.
+    pub async fn find_external_consumers(
+        &self,
+        schemas: &[SchemaQualifier],
+    ) -> Result<BTreeSet<ObjectId>, ConnectionError> {
+        find_external_consumers(self.client, schemas).await
+    }
+
     /// Find sinks that depend on objects in the specified schemas.
     pub async fn find_sinks_depending_on_schemas(
         &self,
@@ -1279,6 +1623,15 @@ impl IntrospectionClient<'_> {
         get_staging_clusters(self.client, deploy_id).await
     }
 
+    /// Report arrangement memory, record counts, and hydration time for the
+    /// materialized views and indexes on the given clusters.
+    pub async fn get_object_resource_usage(
+        &self,
+        clusters: &[String],
+    ) -> Result<Vec<ObjectResourceUsage>, ConnectionError> {
+        get_object_resource_usage(self.client, clusters).await
+    }
+
     /// Drop all objects in a schema.
     pub async fn drop_schema_objects(
         &self,
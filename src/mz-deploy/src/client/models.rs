@@ -347,6 +347,8 @@ pub struct DeploymentHistoryEntry {
     pub kind: DeploymentKind,
     /// List of (database, schema) tuples in this deployment
     pub schemas: Vec<SchemaQualifier>,
+    /// Deploy ID whose state this deployment restored, if it was a rollback
+    pub rollback_of: Option<String>,
 }
 
 /// State of an apply operation for resumable apply.
@@ -415,6 +417,48 @@ pub struct PendingStatement {
     pub executed_at: Option<DateTime<Utc>>,
 }
 
+/// A recorded definition for an object in a deployment, with every name
+/// fully qualified against production schemas.
+///
+/// Written by `stage` so `rollback` can rebuild the deployment's state
+/// without the project source. Stored in `_mz_deploy.public.object_statements`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ObjectStatement {
+    /// Deploy ID this statement belongs to
+    pub deploy_id: String,
+    /// Sequence number for replay ordering
+    pub sequence_num: i32,
+    /// Database containing the object
+    pub database: String,
+    /// Schema containing the object
+    pub schema: String,
+    /// Object name (empty for schema setup statements)
+    pub object: String,
+    /// Kind of statement: "object", "index", "grant", "comment", or "schema"
+    pub statement_kind: String,
+    /// SQL statement text
+    pub statement_sql: String,
+}
+
+/// A data-quality assertion recorded for an object in a deployment.
+///
+/// Written by `stage` from the object's `-- @assert` directives so `wait`
+/// can evaluate them without the project source. Stored in
+/// `_mz_deploy.public.assertions`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct AssertionRecord {
+    /// Deploy ID this assertion belongs to
+    pub deploy_id: String,
+    /// Database containing the object
+    pub database: String,
+    /// Production schema containing the object
+    pub schema: String,
+    /// Object name
+    pub object: String,
+    /// Assertion text, e.g. `unique(id)`, with references fully qualified
+    pub assertion: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
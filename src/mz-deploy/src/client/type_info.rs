@@ -23,6 +23,8 @@
 //!   single query per object.
 //! - **`query_external_types`** delegates to `query_types_for_objects`, extracting
 //!   object lists from the compiled project graph.
+//! - **`stage`** and **`lock`** use `query_types_for_objects` to read the
+//!   production columns of contract objects for breaking-change detection.
 
 use crate::client::connection::TypeInfoClient;
 use crate::client::errors::ConnectionError;
@@ -82,6 +84,7 @@ impl TypeInfoClient<'_> {
                     tables: BTreeMap::new(),
                     kinds: BTreeMap::new(),
                     comments: BTreeMap::new(),
+                    contracts: BTreeMap::new(),
                 },
                 Vec::new(),
             ));
@@ -208,6 +211,7 @@ impl TypeInfoClient<'_> {
                 tables,
                 kinds,
                 comments,
+                contracts: BTreeMap::new(),
             },
             missing,
         ))
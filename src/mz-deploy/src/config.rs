@@ -19,12 +19,14 @@
 //!   Materialize version / Docker image override, and an optional `dependencies`
 //!   array of fully qualified `database.schema.object` names that this project
 //!   reads from but does not own.
+//! - [`LintLevel`] — Severity of a lint rule enabled in the `[lint]` table of
+//!   `project.toml`.
 //!
-//! Passwords can be pulled from the environment via an inline `${VAR}` in the
-//! password field, or via `MZ_PROFILE_<NAME>_PASSWORD` (see
+//! Passwords and Vault credentials can be pulled from the environment via an
+//! inline `${VAR}`, and passwords also via `MZ_PROFILE_<NAME>_PASSWORD` (see
 //! [`ProfilesConfig::expand_env_vars`] for details).
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -79,11 +81,23 @@ pub struct ProfileConfig {
     pub emulator: bool,
 }
 
+/// Severity assigned to a lint rule in the `[lint]` table of `project.toml`.
+///
+/// Rules that are not listed are `off`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Off,
+    Warn,
+    Error,
+}
+
 /// Parsed contents of `project.toml`.
 ///
 /// Specifies optional Materialize version override, per-profile configuration
-/// sections, and an optional list of external dependency object names (fully
-/// qualified `database.schema.object` strings).
+/// sections, an optional list of external dependency object names (fully
+/// qualified `database.schema.object` strings), and the severity of each
+/// enabled lint rule.
 ///
 /// The active profile is **not** stored here — it's resolved from `--profile`,
 /// `MZ_DEPLOY_PROFILE`, or the per-project `.mzprofile` file. See
@@ -91,6 +105,9 @@ pub struct ProfileConfig {
 #[derive(Debug, Deserialize, Clone)]
 pub struct ProjectSettings {
     pub mz_version: Option<String>,
+    /// Lint rule name → severity, from the `[lint]` table.
+    #[serde(default)]
+    pub lint: BTreeMap<String, LintLevel>,
 
     #[serde(flatten)]
     pub profiles: BTreeMap<String, ProfileConfig>,
@@ -218,6 +235,22 @@ impl ProjectSettings {
         }
         Ok(seen)
     }
+
+    /// Check that every rule in the `[lint]` table names a known lint rule.
+    pub fn validate_lint(&self) -> Result<(), ConfigError> {
+        let known = crate::project::analysis::lint::rule_names();
+        match self
+            .lint
+            .keys()
+            .find(|rule| !known.contains(&rule.as_str()))
+        {
+            Some(rule) => Err(ConfigError::UnknownLintRule {
+                rule: rule.clone(),
+                known: known.join(", "),
+            }),
+            None => Ok(()),
+        }
+    }
 }
 
 /// Errors that can occur when loading or resolving configuration from
@@ -269,6 +302,13 @@ pub enum ConfigError {
         "duplicate dependency '{entry}': each object may appear at most once in 'dependencies'"
     )]
     DuplicateDependency { entry: String },
+    #[error("unknown lint rule '{rule}' in [lint]: expected one of {known}")]
+    UnknownLintRule { rule: String, known: String },
+    #[error(
+        "profile '{profile}' has an invalid vault configuration: set either 'token', \
+         or both 'role_id' and 'secret_id'"
+    )]
+    InvalidVaultAuth { profile: String },
     #[error(
         "profile '{profile}' has no host configured: set 'host' (SQL pgwire) \
          or 'http_host' (HTTP API) in profiles.toml"
@@ -335,6 +375,9 @@ pub struct Profile {
     pub sslrootcert: Option<PathBuf>,
     /// Hostname of the Materialize HTTP API.
     pub http_host: Option<String>,
+    /// HashiCorp Vault server used by `vault_secret()`, from the profile's
+    /// `vault` table.
+    pub vault: Option<VaultConfig>,
 }
 
 impl Profile {
@@ -359,6 +402,84 @@ impl Profile {
     }
 }
 
+/// Connection to a HashiCorp Vault server for resolving `vault_secret()`.
+#[derive(Debug, Clone)]
+pub struct VaultConfig {
+    /// Base URL of the Vault server, e.g. `https://vault.example.com:8200`.
+    pub address: String,
+    /// Vault Enterprise namespace, sent as `X-Vault-Namespace`.
+    pub namespace: Option<String>,
+    /// Mount path of the KV version 2 secrets engine.
+    pub mount: String,
+    pub auth: VaultAuth,
+}
+
+/// How mz-deploy authenticates to Vault.
+#[derive(Debug, Clone)]
+pub enum VaultAuth {
+    /// A pre-issued Vault token.
+    Token(String),
+    /// AppRole login, exchanging a role ID and secret ID for a token.
+    AppRole {
+        /// Mount path of the AppRole auth method.
+        mount: String,
+        role_id: String,
+        secret_id: String,
+    },
+}
+
+/// The `vault` table of a `profiles.toml` entry, before validation.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+struct VaultData {
+    address: String,
+    #[serde(default)]
+    namespace: Option<String>,
+    #[serde(default = "default_vault_mount")]
+    mount: String,
+    #[serde(default)]
+    token: Option<String>,
+    #[serde(default)]
+    role_id: Option<String>,
+    #[serde(default)]
+    secret_id: Option<String>,
+    #[serde(default = "default_approle_mount")]
+    approle_mount: String,
+}
+
+fn default_vault_mount() -> String {
+    "secret".to_string()
+}
+
+fn default_approle_mount() -> String {
+    "approle".to_string()
+}
+
+impl VaultData {
+    /// Validate that exactly one authentication method is configured.
+    fn into_config(self, profile: &str) -> Result<VaultConfig, ConfigError> {
+        let auth = match (self.token, self.role_id, self.secret_id) {
+            (Some(token), None, None) => VaultAuth::Token(token),
+            (None, Some(role_id), Some(secret_id)) => VaultAuth::AppRole {
+                mount: self.approle_mount,
+                role_id,
+                secret_id,
+            },
+            _ => {
+                return Err(ConfigError::InvalidVaultAuth {
+                    profile: profile.to_string(),
+                });
+            }
+        };
+        Ok(VaultConfig {
+            address: self.address.trim_end_matches('/').to_string(),
+            namespace: self.namespace,
+            mount: self.mount.trim_matches('/').to_string(),
+            auth,
+        })
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 struct ProfileData {
     #[serde(default)]
@@ -375,6 +496,8 @@ struct ProfileData {
     pub sslrootcert: Option<PathBuf>,
     #[serde(default)]
     pub http_host: Option<String>,
+    #[serde(default)]
+    pub vault: Option<VaultData>,
 }
 
 fn default_port() -> u16 {
@@ -401,6 +524,7 @@ fn emulator_profile() -> Profile {
         sslmode: None,
         sslrootcert: None,
         http_host: None,
+        vault: None,
     }
 }
 
@@ -497,6 +621,10 @@ impl ProfilesConfig {
                     profile: name.clone(),
                 });
             }
+            let vault = data
+                .vault
+                .map(|vault| vault.into_config(&name))
+                .transpose()?;
             profiles.insert(
                 name.clone(),
                 Profile {
@@ -509,6 +637,7 @@ impl ProfilesConfig {
                     sslmode: data.sslmode,
                     sslrootcert: data.sslrootcert,
                     http_host: data.http_host,
+                    vault,
                 },
             );
         }
@@ -535,30 +664,38 @@ impl ProfilesConfig {
             })
     }
 
-    /// Resolve a profile's password, applying environment variable overrides.
+    /// Resolve a profile's password and Vault credentials, applying
+    /// environment variable overrides.
     ///
     /// Two override mechanisms, applied in order:
     ///
-    /// 1. **Inline `${VAR_NAME}`** in `profiles.toml` — when the `password` field
-    ///    is exactly `"${SOMETHING}"` (no surrounding text), the referenced env
-    ///    var is read and substituted. Missing vars produce an error.
+    /// 1. **Inline `${VAR_NAME}`** in `profiles.toml` — when the `password`
+    ///    field, or the Vault `token`, `role_id`, or `secret_id`, is exactly
+    ///    `"${SOMETHING}"` (no surrounding text), the referenced env var is
+    ///    read and substituted. Missing vars produce an error.
     /// 2. **`MZ_PROFILE_<NAME>_PASSWORD`** — always checked; if set, overrides
-    ///    whatever the file (or step 1) produced. The profile name is
-    ///    uppercased and non-alphanumeric characters are replaced with `_` to
-    ///    form a valid shell identifier (so `my-prod` → `MY_PROD`). Profile
-    ///    names that differ only in such characters will collide on the same
-    ///    env var.
+    ///    whatever the file (or step 1) produced for the password. The profile
+    ///    name is uppercased and non-alphanumeric characters are replaced with
+    ///    `_` to form a valid shell identifier (so `my-prod` → `MY_PROD`).
+    ///    Profile names that differ only in such characters will collide on
+    ///    the same env var.
     pub fn expand_env_vars(&self, mut profile: Profile) -> Result<Profile, ConfigError> {
-        if let Some(password) = &profile.password
-            && password.starts_with("${")
-            && password.ends_with("}")
-        {
-            let var_name = &password[2..password.len() - 1];
-            let env_value = std::env::var(var_name).map_err(|_| ConfigError::EnvVarNotFound {
-                var: var_name.to_string(),
-                profile: profile.name.clone(),
-            })?;
-            profile.password = Some(env_value);
+        if let Some(password) = &profile.password {
+            profile.password = Some(expand_env_reference(password, &profile.name)?);
+        }
+
+        if let Some(vault) = &mut profile.vault {
+            match &mut vault.auth {
+                VaultAuth::Token(token) => {
+                    *token = expand_env_reference(token, &profile.name)?;
+                }
+                VaultAuth::AppRole {
+                    role_id, secret_id, ..
+                } => {
+                    *role_id = expand_env_reference(role_id, &profile.name)?;
+                    *secret_id = expand_env_reference(secret_id, &profile.name)?;
+                }
+            }
         }
 
         let env_var_name = format!(
@@ -602,6 +739,21 @@ impl ProfilesConfig {
     }
 }
 
+/// Substitute `value` with the named env var when it is exactly
+/// `"${VAR_NAME}"`; return it unchanged otherwise.
+fn expand_env_reference(value: &str, profile: &str) -> Result<String, ConfigError> {
+    match value
+        .strip_prefix("${")
+        .and_then(|rest| rest.strip_suffix('}'))
+    {
+        Some(var_name) => std::env::var(var_name).map_err(|_| ConfigError::EnvVarNotFound {
+            var: var_name.to_string(),
+            profile: profile.to_string(),
+        }),
+        None => Ok(value.to_string()),
+    }
+}
+
 /// Resolved settings for an mz-deploy execution.
 ///
 /// Constructed once in `main.rs` from CLI args + `project.toml` + `profiles.toml`,
@@ -627,6 +779,8 @@ pub struct Settings {
     pub profile_config: ProfileConfig,
     /// Validated external dependencies declared in `project.toml`.
     pub dependencies: BTreeSet<ObjectId>,
+    /// Severity of each lint rule enabled in `project.toml`.
+    pub lint: BTreeMap<String, LintLevel>,
     /// Database connection profile. None for commands that don't connect (compile, test).
     connection: Option<Profile>,
 }
@@ -672,6 +826,7 @@ impl Settings {
         };
 
         let dependencies = project_settings.validate_dependencies()?;
+        project_settings.validate_lint()?;
 
         let connection = if needs_connection {
             // Safe to unwrap: if we got here with needs_connection, profile_name is Some.
@@ -689,6 +844,7 @@ impl Settings {
             docker_image,
             profile_config,
             dependencies,
+            lint: project_settings.lint,
             connection,
         })
     }
@@ -1011,6 +1167,67 @@ mod tests {
         }
     }
 
+    #[mz_ore::test]
+    fn test_profiles_config_loads_vault_approle() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("profiles.toml"),
+            r#"
+                [production]
+                host = "prod.example.com"
+                username = "deploy_bot"
+
+                [production.vault]
+                address = "https://vault.example.com:8200/"
+                namespace = "data"
+                role_id = "role-1"
+                secret_id = "secret-1"
+            "#,
+        )
+        .unwrap();
+        let config = ProfilesConfig::load(Some(dir.path())).unwrap();
+        let profile = config
+            .expand_env_vars(config.get_profile("production").unwrap())
+            .unwrap();
+        let vault = profile.vault.unwrap();
+        assert_eq!(vault.address, "https://vault.example.com:8200");
+        assert_eq!(vault.namespace.as_deref(), Some("data"));
+        assert_eq!(vault.mount, "secret");
+        match vault.auth {
+            VaultAuth::AppRole {
+                mount,
+                role_id,
+                secret_id,
+            } => {
+                assert_eq!(mount, "approle");
+                assert_eq!(role_id, "role-1");
+                assert_eq!(secret_id, "secret-1");
+            }
+            other => panic!("expected AppRole, got {other:?}"),
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_profiles_config_rejects_ambiguous_vault_auth() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("profiles.toml"),
+            r#"
+                [production]
+                host = "prod.example.com"
+                username = "deploy_bot"
+
+                [production.vault]
+                address = "https://vault.example.com:8200"
+                token = "t"
+                role_id = "role-1"
+            "#,
+        )
+        .unwrap();
+        let err = ProfilesConfig::load(Some(dir.path())).unwrap_err();
+        assert!(matches!(err, ConfigError::InvalidVaultAuth { .. }));
+    }
+
     #[mz_ore::test]
     fn test_builtin_emulator_profile_present_in_loaded_config() {
         let dir = tempfile::tempdir().unwrap();
@@ -1149,4 +1366,39 @@ mod tests {
         let err = settings.validate_dependencies().unwrap_err();
         assert!(matches!(err, ConfigError::DuplicateDependency { .. }));
     }
+
+    #[mz_ore::test]
+    fn lint_table_is_not_a_profile() {
+        let settings: ProjectSettings = toml::from_str(
+            r#"
+            [lint]
+            select-star-in-mv = "error"
+            missing-comment = "warn"
+
+            [staging]
+            profile_suffix = "_staging"
+        "#,
+        )
+        .unwrap();
+        assert_eq!(
+            settings.lint.get("select-star-in-mv"),
+            Some(&LintLevel::Error)
+        );
+        assert_eq!(settings.lint.get("missing-comment"), Some(&LintLevel::Warn));
+        assert!(!settings.profiles.contains_key("lint"));
+        settings.validate_lint().unwrap();
+    }
+
+    #[mz_ore::test]
+    fn validate_lint_rejects_unknown_rules() {
+        let settings: ProjectSettings = toml::from_str(
+            r#"
+            [lint]
+            no-such-rule = "warn"
+        "#,
+        )
+        .unwrap();
+        let err = settings.validate_lint().unwrap_err();
+        assert!(matches!(err, ConfigError::UnknownLintRule { .. }));
+    }
 }
@@ -109,6 +109,7 @@ impl DockerRuntime {
             sslmode: None,
             sslrootcert: None,
             http_host: None,
+            vault: None,
         }
     }
 
@@ -168,6 +168,16 @@ pub fn print_deploy_id(deploy_id: &str) {
     println!("{deploy_id}");
 }
 
+/// Write a rendered document, such as a `graph` export, to stdout.
+///
+/// Like [`print_deploy_id`], this is an intentional stdout write in human
+/// mode: the document is the command's product and is meant to be piped or
+/// redirected (`mz-deploy graph | dot -Tsvg`).
+#[allow(clippy::print_stdout)]
+pub fn print_document(text: &str) {
+    print!("{text}");
+}
+
 /// Print an informational message to stderr without a trailing newline. Silenced by `--quiet`.
 #[macro_export]
 macro_rules! info_nonl {
@@ -11,7 +11,7 @@
 //!
 //! Provides IDE integration for `.sql` files in mz-deploy projects via the
 //! Language Server Protocol (LSP). The server runs over stdio and supports
-//! nine capabilities:
+//! eleven capabilities:
 //!
 //! ## Go-to-definition
 //!
@@ -31,6 +31,20 @@
 //! inverse of go-to-definition: it answers "who uses this table/view/source?"
 //! by building the reverse dependency graph from `graph::Project`.
 //!
+//! ## Rename
+//!
+//! Renames a project object across its defining file and every dependent
+//! file (queries, unit test mocks, grants) in one workspace edit, moving the
+//! defining file when it follows the `models/<database>/<schema>/<object>.sql`
+//! layout. `prepareRename` limits renames to the object-name part of a
+//! reference, and renames that collide with an existing object are refused.
+//!
+//! ## Formatting
+//!
+//! Formats a whole document or just the statements overlapping a selection,
+//! using the same formatter as `mz-deploy fmt`. Comments and `:variable`
+//! references are preserved, and files with syntax errors are left alone.
+//!
 //! ## Hover
 //!
 //! Shows the output schema (column names, types, nullability) for a referenced
@@ -103,10 +117,12 @@ mod code_lens;
 mod completion;
 pub mod diagnostics;
 mod document_symbol;
+mod formatting;
 pub mod functions;
 pub mod goto_definition;
 pub mod hover;
 mod references;
+mod rename;
 mod run;
 mod semantic_tokens;
 mod server;
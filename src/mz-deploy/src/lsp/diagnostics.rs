@@ -14,7 +14,7 @@
 //! helpers, then converts it to a [`tower_lsp::lsp_types::Diagnostic`] via
 //! `to_lsp` using a [`Rope`] for byte-offset → line/column conversion.
 //!
-//! Three tiers of diagnostics:
+//! Five tiers of diagnostics:
 //!
 //! - **Per-keystroke diagnostics** ([`diagnose()`]) — Resolves psql-style
 //!   variables before parsing. Unresolved variables produce positioned
@@ -32,16 +32,27 @@
 //! - **On-save typecheck errors** (`typecheck_diagnostics()`) — Inspects
 //!   the structured upstream error to position the diagnostic. See
 //!   `locate_typecheck` for the dispatch.
+//!
+//! - **On-save breaking changes** (`breaking_change_diagnostics()`) — Reports
+//!   incompatible column changes to contract objects against the baseline in
+//!   `types.lock`, positioned at the affected column. `WARNING` when the file
+//!   carries `PRAGMA ALLOW_BREAKING_CHANGE`, `ERROR` otherwise.
+//!
+//! - **On-save lint findings** (`lint_diagnostics()`) — Reports violations of
+//!   the lint rules enabled in `project.toml`, with the severity configured
+//!   for each rule and the rule name as the diagnostic code.
 
 use crate::diagnostics::{PositionalDiagnostic, Severity, Suggestion, locate_typecheck};
 use crate::fs::FileSystem;
+use crate::project::analysis::breaking::ObjectChanges;
+use crate::project::analysis::lint::LintFinding;
 use crate::project::compiler::typecheck::{ObjectTypeCheckError, TypeCheckError};
 use crate::project::error::ValidationError;
 use crate::project::syntax::variables::{resolve_variables, resolved_to_original};
 use ropey::Rope;
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
-use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
 
 /// Parse `text` as SQL and return diagnostics for any parse errors and variable issues.
 ///
@@ -255,6 +266,88 @@ pub(crate) fn typecheck_diagnostics(
     map
 }
 
+/// Convert breaking column changes into LSP diagnostics grouped by file path.
+///
+/// Each diagnostic is positioned at the first whole-word occurrence of the
+/// affected column in the object's file, falling back to the object name and
+/// then to `(0, 0)`. Compatible changes produce no diagnostics.
+pub(crate) fn breaking_change_diagnostics(
+    fs: &FileSystem,
+    changes: &[ObjectChanges],
+) -> BTreeMap<PathBuf, Vec<Diagnostic>> {
+    let mut map: BTreeMap<PathBuf, Vec<Diagnostic>> = BTreeMap::new();
+    let zero = Position::new(0, 0);
+
+    for object in changes {
+        let source = read_source(fs, &object.path);
+        let severity = if object.allowed {
+            DiagnosticSeverity::WARNING
+        } else {
+            DiagnosticSeverity::ERROR
+        };
+        for change in object.breaking() {
+            let range = source
+                .as_ref()
+                .and_then(|(text, rope)| {
+                    let byte_range = change
+                        .column()
+                        .and_then(|column| crate::diagnostics::find_identifier(text, column))
+                        .or_else(|| {
+                            crate::diagnostics::find_identifier(text, object.object.object())
+                        })?;
+                    Some(Range::new(
+                        offset_to_position(byte_range.start, rope)?,
+                        offset_to_position(byte_range.end, rope)?,
+                    ))
+                })
+                .unwrap_or(Range::new(zero, zero));
+            let mut message = format!("breaking change to {}: {}", object.object, change);
+            if !object.allowed {
+                append_detail_and_hints(
+                    &mut message,
+                    None,
+                    &[
+                        "add `-- PRAGMA ALLOW_BREAKING_CHANGE;` to this file if the change \
+                       is intended"
+                            .to_string(),
+                    ],
+                );
+            }
+            map.entry(object.path.clone())
+                .or_default()
+                .push(Diagnostic {
+                    range,
+                    severity: Some(severity),
+                    source: Some("mz-deploy".to_string()),
+                    message,
+                    ..Default::default()
+                });
+        }
+    }
+
+    map
+}
+
+/// Convert lint findings into LSP diagnostics grouped by file path.
+///
+/// Each diagnostic carries the rule name as its code and the rule's help
+/// text appended to the message.
+pub(crate) fn lint_diagnostics(
+    fs: &FileSystem,
+    findings: &[LintFinding],
+) -> BTreeMap<PathBuf, Vec<Diagnostic>> {
+    let mut map: BTreeMap<PathBuf, Vec<Diagnostic>> = BTreeMap::new();
+    for finding in findings {
+        let (text, rope) = read_source(fs, &finding.file).unwrap_or_default();
+        let pd = finding.to_positional(text);
+        let mut diag = to_lsp(&pd, &rope);
+        append_detail_and_hints(&mut diag.message, None, &pd.footers);
+        diag.code = Some(NumberOrString::String(finding.rule.to_string()));
+        map.entry(finding.file.clone()).or_default().push(diag);
+    }
+    map
+}
+
 fn read_source(fs: &FileSystem, path: &Path) -> Option<(String, Rope)> {
     let text = fs.read_to_string(path).ok()?;
     let rope = Rope::from_str(&text);
@@ -452,6 +545,74 @@ mod tests {
         assert!(diagnose(text, &rope, &BTreeMap::new(), None).is_empty());
     }
 
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // can't call foreign function `llvm.aarch64.neon.uaddlv.i32.v16i8` on OS `linux`
+    fn breaking_change_diagnostic_points_at_column() {
+        use crate::project::analysis::breaking::ColumnChange;
+        use crate::project::ir::object_id::ObjectId;
+
+        let path = PathBuf::from("/project/models/db/public/orders.sql");
+        let text =
+            "-- PRAGMA ALLOW_BREAKING_CHANGE;\nCREATE VIEW orders AS\nSELECT id, amount FROM t;";
+        let fs = FileSystem::with_overlay(BTreeMap::from([(path.clone(), text.to_string())]));
+        let changes = vec![ObjectChanges {
+            object: ObjectId::new("db".to_string(), "public".to_string(), "orders".to_string()),
+            path: path.clone(),
+            changes: vec![
+                ColumnChange::Renamed {
+                    from: "total".to_string(),
+                    to: "amount".to_string(),
+                },
+                ColumnChange::Added {
+                    column: "note".to_string(),
+                    r#type: "text".to_string(),
+                    nullable: true,
+                },
+            ],
+            allowed: true,
+        }];
+
+        let map = breaking_change_diagnostics(&fs, &changes);
+        let diags = &map[&path];
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Some(DiagnosticSeverity::WARNING));
+        assert_eq!(diags[0].range.start, Position::new(2, 11));
+        assert!(diags[0].message.contains("renamed to 'amount'"));
+    }
+
+    #[cfg_attr(miri, ignore)] // can't call foreign function `llvm.aarch64.neon.uaddlv.i32.v16i8` on OS `linux`
+    #[mz_ore::test]
+    fn lint_diagnostic_uses_configured_severity_and_rule_code() {
+        use crate::config::LintLevel;
+        use crate::project::ir::object_id::ObjectId;
+
+        let path = PathBuf::from("/project/models/db/public/mv.sql");
+        let text = "CREATE MATERIALIZED VIEW mv AS\nSELECT * FROM t;";
+        let fs = FileSystem::with_overlay(BTreeMap::from([(path.clone(), text.to_string())]));
+        let findings = vec![LintFinding {
+            rule: "select-star-in-mv",
+            level: LintLevel::Warn,
+            object: ObjectId::new("db".to_string(), "public".to_string(), "mv".to_string()),
+            file: path.clone(),
+            line: 2,
+            column: 8,
+            message: "materialized view db.public.mv selects `*`".to_string(),
+            help: Some("list the output columns explicitly".to_string()),
+            byte_range: 38..39,
+        }];
+
+        let map = lint_diagnostics(&fs, &findings);
+        let diags = &map[&path];
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Some(DiagnosticSeverity::WARNING));
+        assert_eq!(diags[0].range.start, Position::new(1, 7));
+        assert_eq!(
+            diags[0].code,
+            Some(NumberOrString::String("select-star-in-mv".to_string()))
+        );
+        assert!(diags[0].message.contains("list the output columns"));
+    }
+
     // --- Variable-aware diagnose tests ---
 
     fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
@@ -0,0 +1,125 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! LSP document and range formatting handlers.
+//!
+//! Both handlers delegate to [`crate::project::syntax::format`], the same
+//! formatter behind `mz-deploy fmt`, so editor formatting and CI checks
+//! agree.
+//!
+//! - `textDocument/formatting` replaces the whole document with the
+//!   formatted text in a single edit.
+//! - `textDocument/rangeFormatting` rewrites only the statements that
+//!   overlap the requested range, leaving surrounding comments and
+//!   whitespace untouched.
+//!
+//! Files that fail to parse produce no edits; the parse diagnostics already
+//! tell the user what is wrong.
+
+use crate::lsp::diagnostics::{offset_to_position, position_to_offset};
+use crate::project::syntax::format::{format_sql, format_statements};
+use ropey::Rope;
+use tower_lsp::lsp_types::{Range, TextEdit};
+
+/// Compute the edits that format an entire document.
+pub(super) fn format_document(text: &str) -> Vec<TextEdit> {
+    let Ok(formatted) = format_sql(text) else {
+        return vec![];
+    };
+    if formatted == text {
+        return vec![];
+    }
+    let rope = Rope::from_str(text);
+    let (Some(start), Some(end)) = (
+        offset_to_position(0, &rope),
+        offset_to_position(text.len(), &rope),
+    ) else {
+        return vec![];
+    };
+    vec![TextEdit {
+        range: Range { start, end },
+        new_text: formatted,
+    }]
+}
+
+/// Compute the edits that format every statement overlapping `range`.
+pub(super) fn format_range(text: &str, range: Range) -> Vec<TextEdit> {
+    let rope = Rope::from_str(text);
+    let (Some(start), Some(end)) = (
+        position_to_offset(range.start, &rope),
+        position_to_offset(range.end, &rope),
+    ) else {
+        return vec![];
+    };
+    let Ok(statements) = format_statements(text) else {
+        return vec![];
+    };
+
+    statements
+        .into_iter()
+        .filter(|stmt| stmt.range.start <= end && start <= stmt.range.end)
+        .filter(|stmt| text[stmt.range.clone()] != stmt.text)
+        .filter_map(|stmt| {
+            Some(TextEdit {
+                range: Range {
+                    start: offset_to_position(stmt.range.start, &rope)?,
+                    end: offset_to_position(stmt.range.end, &rope)?,
+                },
+                new_text: stmt.text,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower_lsp::lsp_types::Position;
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn formatted_document_has_no_edits() {
+        let text = format_sql("create view v as select 1;").unwrap();
+        assert!(format_document(&text).is_empty());
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn document_edit_replaces_whole_text() {
+        let text = "select   1;\nselect 2;\n";
+        let edits = format_document(text);
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range.start, Position::new(0, 0));
+        assert_eq!(edits[0].range.end, Position::new(2, 0));
+        assert_eq!(edits[0].new_text, format_sql(text).unwrap());
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn range_formats_only_overlapping_statements() {
+        let text = "select   1;\n-- keep\nselect   2;\n";
+        let range = Range {
+            start: Position::new(2, 0),
+            end: Position::new(2, 3),
+        };
+        let edits = format_range(text, range);
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range.start, Position::new(2, 0));
+        assert_eq!(edits[0].range.end, Position::new(2, 11));
+        assert_eq!(
+            edits[0].new_text,
+            mz_sql_pretty::pretty_str_simple("select 2", mz_sql_pretty::DEFAULT_WIDTH).unwrap()
+        );
+    }
+
+    #[mz_ore::test]
+    fn unparseable_document_has_no_edits() {
+        assert!(format_document("select 'unterminated").is_empty());
+    }
+}
@@ -0,0 +1,1544 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Rename for project objects, schemas and columns.
+//!
+//! Renames a table, view, source, sink, secret or connection, a schema, or a
+//! column everywhere it is referenced, in a single [`WorkspaceEdit`].
+//!
+//! ## Algorithm
+//!
+//! 1. Resolve the identifier under the cursor to a [`Target`]:
+//!    - the object-name part of a reference to a project object (reuses
+//!      [`goto_definition::resolve_object_id`]) is that object;
+//!    - the schema part of such a reference (`public.foo`), or the name after
+//!      `SCHEMA` in a grant or comment, is that schema;
+//!    - a column qualified by an object or its alias (`foo.id`, `f.id`), an
+//!      element of a column list (`CREATE TABLE foo (id int)`, `ON foo (id)`,
+//!      `MOCK foo(id int)`), an output column of the file's own view, or a bare
+//!      column name that exactly one of the file's dependencies has, is that
+//!      column.
+//! 2. Collect the files that can mention the target: the defining files of the
+//!    objects involved (the object itself, or every object of the schema plus
+//!    the schema's mod file) and the files of their direct dependents from
+//!    [`ProjectCache::get_dependents`] — the same set
+//!    [`references::find_references`] returns.
+//! 3. Lex each file and rewrite every reference to the target, using the
+//!    file's own `models/<database>/<schema>/` context. For an object, a chain
+//!    whose prefix resolves to it (a column qualifier such as `foo.id`) has the
+//!    qualifier rewritten too.
+//! 4. Move the files whose paths follow from the old name: an object's
+//!    `models/<database>/<schema>/<object>.sql`, or a schema's directory and
+//!    its `models/<database>/<schema>.sql` mod file.
+//!
+//! ## Columns
+//!
+//! Columns are resolved lexically. The columns of an object come from the
+//! typecheck results when there are any, and otherwise from the column list or
+//! select list of its definition, so the columns of a `SELECT *` view are only
+//! known once the project has been typechecked. A bare column name in a view's
+//! own definition refers to its inputs, so it is left alone.
+//!
+//! Dependents keep their own column names: a select item that only names the
+//! renamed column gets an alias, e.g. `SELECT foo.id` becomes
+//! `SELECT foo.ident AS id`. Likewise, renaming a view's output column that is
+//! not aliased yet aliases it, e.g. `SELECT t.id` becomes `SELECT t.id AS ident`.
+//!
+//! ## Collisions
+//!
+//! The rename is refused if the new name is already taken — by another object
+//! of the schema, another schema of the database or another column of the
+//! object — or if a destination file already exists.
+//!
+//! [`references::find_references`]: super::references::find_references
+
+use crate::project::compiler::cache::ProjectCache;
+use crate::project::compiler::cache::project_cache::CachedObject;
+use crate::project::ir::object_id::ObjectId;
+use mz_sql_lexer::keywords::{
+    ALL, AS, CHECK, CONSTRAINT, CREATE, DISTINCT, EXCEPT, EXISTS, FETCH, FOREIGN, FROM, GROUP,
+    HAVING, INTERSECT, Keyword, LIMIT, OFFSET, ON, ORDER, PRIMARY, SCHEMA, SELECT, TABLE, UNION,
+    UNIQUE, VIEW, WHERE, WINDOW,
+};
+use mz_sql_lexer::lexer::{self, Token};
+use mz_sql_parser::ast::Ident;
+use ropey::Rope;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use tower_lsp::lsp_types::*;
+
+use super::diagnostics::offset_to_position;
+use super::goto_definition;
+
+/// Keywords that end a select list.
+const SELECT_LIST_END: [Keyword; 12] = [
+    FROM, WHERE, GROUP, HAVING, WINDOW, ORDER, LIMIT, OFFSET, FETCH, UNION, EXCEPT, INTERSECT,
+];
+
+/// Keywords that start a table constraint rather than a column definition.
+const TABLE_CONSTRAINTS: [Keyword; 5] = [CONSTRAINT, PRIMARY, UNIQUE, CHECK, FOREIGN];
+
+/// Reasons a rename request is refused.
+#[derive(Debug, Error, PartialEq)]
+pub(super) enum RenameError {
+    #[error("the cursor is not on the name of a project object, schema or column")]
+    NotRenameable,
+    #[error("'{0}' is not a valid identifier")]
+    InvalidName(String),
+    #[error("cannot rename '{old}': '{new}' already exists")]
+    Collision { old: String, new: String },
+    #[error("cannot rename '{old}': file '{path}' already exists")]
+    FileExists { old: String, path: String },
+}
+
+/// What a rename request renames.
+#[derive(Debug, Clone, PartialEq)]
+enum Target {
+    /// A table, view, source, sink, secret or connection.
+    Object(ObjectId),
+    /// A schema, i.e. a `models/<database>/<schema>/` directory.
+    Schema { database: String, schema: String },
+    /// A column of a project object.
+    Column { object: ObjectId, column: String },
+}
+
+impl Target {
+    /// The name that the rename replaces.
+    fn name(&self) -> &str {
+        match self {
+            Target::Object(id) => id.object(),
+            Target::Schema { schema, .. } => schema,
+            Target::Column { column, .. } => column,
+        }
+    }
+
+    /// The target after renaming it to `new_name`.
+    fn renamed(&self, new_name: &str) -> Target {
+        match self {
+            Target::Object(id) => Target::Object(ObjectId::new(
+                id.expect_database().to_string(),
+                id.schema().to_string(),
+                new_name.to_string(),
+            )),
+            Target::Schema { database, .. } => Target::Schema {
+                database: database.clone(),
+                schema: new_name.to_string(),
+            },
+            Target::Column { object, .. } => Target::Column {
+                object: object.clone(),
+                column: new_name.to_string(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Target::Object(id) => write!(f, "{id}"),
+            Target::Schema { database, schema } => write!(
+                f,
+                "{}.{}",
+                Ident::new_unchecked(database.as_str()),
+                Ident::new_unchecked(schema.as_str())
+            ),
+            Target::Column { object, column } => {
+                write!(f, "{object}.{}", Ident::new_unchecked(column.as_str()))
+            }
+        }
+    }
+}
+
+/// An identifier token: its text, byte span in the source and index in the
+/// token stream.
+struct IdentToken {
+    name: String,
+    start: usize,
+    end: usize,
+    index: usize,
+}
+
+/// An item of the select list that defines a view's columns.
+enum SelectItem {
+    /// `expr AS name` or `expr name`: the token index of the name.
+    Aliased(usize),
+    /// A column reference such as `id` or `foo.id`, which names the output
+    /// column after itself: the index of its chain.
+    Implicit(usize),
+    /// An expression whose output column name is not known.
+    Unnamed,
+}
+
+/// A lexed file: its tokens and maximal dot-separated identifier chains.
+struct Lexed<'a> {
+    text: &'a str,
+    tokens: Vec<lexer::PosToken>,
+    chains: Vec<Vec<IdentToken>>,
+}
+
+impl<'a> Lexed<'a> {
+    fn new(text: &'a str) -> Lexed<'a> {
+        let tokens = lexer::lex(text).unwrap_or_default();
+        let chains = identifier_chains(text, &tokens);
+        Lexed {
+            text,
+            tokens,
+            chains,
+        }
+    }
+
+    /// The identifier at token `index`, if it is one.
+    fn ident(&self, index: usize) -> Option<IdentToken> {
+        ident_token(self.text, &self.tokens[index], index)
+    }
+
+    /// Find the identifier chain containing `byte_offset`, and the index of
+    /// the part under the cursor.
+    fn chain_at_offset(&self, byte_offset: usize) -> Option<(&[IdentToken], usize)> {
+        self.chains.iter().find_map(|chain| {
+            let idx = chain
+                .iter()
+                .position(|t| t.start <= byte_offset && byte_offset < t.end)?;
+            Some((chain.as_slice(), idx))
+        })
+    }
+
+    /// Whether `chain` directly follows the keyword `keyword`.
+    fn follows_keyword(&self, chain: &[IdentToken], keyword: Keyword) -> bool {
+        chain[0]
+            .index
+            .checked_sub(1)
+            .is_some_and(|i| matches!(self.tokens[i].kind, Token::Keyword(kw) if kw == keyword))
+    }
+
+    /// Whether the token after token `index` is an opening parenthesis.
+    fn opens_list(&self, index: usize) -> bool {
+        matches!(
+            self.tokens.get(index + 1).map(|t| &t.kind),
+            Some(Token::LParen)
+        )
+    }
+
+    /// The token indexes of the identifiers that start an element of the
+    /// parenthesized list that opens at token `open`, e.g. the column names of
+    /// `(id int, name text)`.
+    fn column_list(&self, open: usize) -> Vec<usize> {
+        let mut columns = Vec::new();
+        let mut depth = 0;
+        let mut element_start = true;
+        for (i, token) in self.tokens.iter().enumerate().skip(open + 1) {
+            match &token.kind {
+                Token::LParen => depth += 1,
+                Token::RParen if depth == 0 => break,
+                Token::RParen => depth -= 1,
+                Token::Comma if depth == 0 => {
+                    element_start = true;
+                    continue;
+                }
+                kind if depth == 0 && element_start && is_name(kind) => {
+                    if !matches!(kind, Token::Keyword(kw) if TABLE_CONSTRAINTS.contains(kw)) {
+                        columns.push(i);
+                    }
+                }
+                _ => {}
+            }
+            element_start = false;
+        }
+        columns
+    }
+
+    /// The chain in front of the parenthesized list in which token `index`
+    /// starts an element, e.g. `foo` for `id` in `ON foo (id)`.
+    fn column_list_owner(&self, index: usize) -> Option<&[IdentToken]> {
+        let prev = index.checked_sub(1)?;
+        let mut depth = 0;
+        let mut open = index;
+        loop {
+            open = open.checked_sub(1)?;
+            match self.tokens[open].kind {
+                Token::RParen => depth += 1,
+                Token::LParen if depth == 0 => break,
+                Token::LParen => depth -= 1,
+                Token::Semicolon => return None,
+                _ => {}
+            }
+        }
+        if prev != open && !matches!(self.tokens[prev].kind, Token::Comma) {
+            return None;
+        }
+        let owner_end = open.checked_sub(1)?;
+        self.chains
+            .iter()
+            .find(|chain| chain.last().is_some_and(|t| t.index == owner_end))
+            .map(|chain| chain.as_slice())
+    }
+
+    /// The token indexes of the column list after the name of the first
+    /// `CREATE TABLE` or `CREATE [MATERIALIZED] VIEW` statement, as in
+    /// `CREATE TABLE foo (id int)` or `CREATE VIEW foo (id) AS ...`.
+    fn create_column_list(&self) -> Option<Vec<usize>> {
+        let create = self
+            .tokens
+            .iter()
+            .position(|t| matches!(t.kind, Token::Keyword(CREATE)))?;
+        let name = self.chains.iter().find(|c| {
+            c[0].index > create
+                && matches!(
+                    self.tokens[c[0].index - 1].kind,
+                    Token::Keyword(TABLE | VIEW | EXISTS)
+                )
+        })?;
+        let end = name.last().expect("chains are not empty").index;
+        self.opens_list(end).then(|| self.column_list(end + 1))
+    }
+
+    /// The token index of the top-level `SELECT` of the first `CREATE`
+    /// statement that has one, i.e. the query that defines a view.
+    fn main_select(&self) -> Option<usize> {
+        let mut depth = 0usize;
+        let mut in_create = false;
+        let mut at_start = true;
+        for (i, token) in self.tokens.iter().enumerate() {
+            if at_start {
+                in_create = matches!(token.kind, Token::Keyword(CREATE));
+                at_start = false;
+            }
+            match token.kind {
+                Token::LParen => depth += 1,
+                Token::RParen => depth = depth.saturating_sub(1),
+                Token::Semicolon => {
+                    depth = 0;
+                    at_start = true;
+                }
+                Token::Keyword(SELECT) if depth == 0 && in_create => return Some(i),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// The items of the select list of [`Lexed::main_select`].
+    fn main_select_items(&self) -> Vec<SelectItem> {
+        let Some(select) = self.main_select() else {
+            return Vec::new();
+        };
+        let kind = |i: usize| self.tokens.get(i).map(|t| &t.kind);
+        let mut i = select + 1;
+        match kind(i) {
+            Some(Token::Keyword(ALL)) => i += 1,
+            Some(Token::Keyword(DISTINCT)) => {
+                i += 1;
+                if matches!(kind(i), Some(Token::Keyword(ON))) {
+                    // Skip the `ON (...)` expressions.
+                    let mut depth = 0;
+                    while let Some(k) = kind(i + 1) {
+                        i += 1;
+                        match k {
+                            Token::LParen => depth += 1,
+                            Token::RParen => {
+                                depth -= 1;
+                                if depth == 0 {
+                                    break;
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                    i += 1;
+                }
+            }
+            _ => {}
+        }
+
+        let mut items = Vec::new();
+        let mut item_start = i;
+        let mut depth = 0;
+        while let Some(k) = kind(i) {
+            match k {
+                Token::LParen | Token::LBracket => depth += 1,
+                Token::RParen | Token::RBracket if depth == 0 => break,
+                Token::RParen | Token::RBracket => depth -= 1,
+                Token::Comma if depth == 0 => {
+                    items.push(self.select_item(item_start, i));
+                    item_start = i + 1;
+                }
+                Token::Semicolon => break,
+                Token::Keyword(kw) if depth == 0 && SELECT_LIST_END.contains(kw) => break,
+                _ => {}
+            }
+            i += 1;
+        }
+        if item_start < i {
+            items.push(self.select_item(item_start, i));
+        }
+        items
+    }
+
+    /// Classify the select item made of the tokens `start..end`.
+    fn select_item(&self, start: usize, end: usize) -> SelectItem {
+        if end >= start + 2 && is_name(&self.tokens[end - 1].kind) {
+            match self.tokens[end - 2].kind {
+                Token::Keyword(AS)
+                | Token::Ident(_)
+                | Token::RParen
+                | Token::RBracket
+                | Token::Number(_)
+                | Token::String(_) => return SelectItem::Aliased(end - 1),
+                _ => {}
+            }
+        }
+        match self.chains.iter().position(|chain| {
+            chain[0].index == start && chain.last().is_some_and(|t| t.index + 1 == end)
+        }) {
+            Some(chain) => SelectItem::Implicit(chain),
+            None => SelectItem::Unnamed,
+        }
+    }
+
+    /// The output column name of a select item, if it is known.
+    fn output_name(&self, item: &SelectItem) -> Option<String> {
+        match item {
+            SelectItem::Aliased(index) => self.ident(*index).map(|t| t.name),
+            SelectItem::Implicit(chain) => self.chains[*chain].last().map(|t| t.name.clone()),
+            SelectItem::Unnamed => None,
+        }
+    }
+}
+
+/// Check that the cursor is on a renameable name.
+///
+/// Returns the range of the name under the cursor and its current text as the
+/// placeholder, or `None` if the cursor is not on the name of a project
+/// object, schema or column.
+pub(super) fn prepare_rename(
+    text: &str,
+    byte_offset: usize,
+    file_uri: &Url,
+    root: &Path,
+    project_cache: &ProjectCache,
+) -> Option<PrepareRenameResponse> {
+    let lexed = Lexed::new(text);
+    let (_, token) = resolve_target(&lexed, byte_offset, file_uri, root, project_cache)?;
+
+    let rope = Rope::from_str(text);
+    Some(PrepareRenameResponse::RangeWithPlaceholder {
+        range: Range::new(
+            offset_to_position(token.start, &rope)?,
+            offset_to_position(token.end, &rope)?,
+        ),
+        placeholder: token.name.clone(),
+    })
+}
+
+/// Rename the object, schema or column under the cursor to `new_name`.
+///
+/// `documents` holds the text of open documents, which takes precedence over
+/// the file on disk so unsaved edits are renamed too.
+///
+/// # Errors
+/// See [`RenameError`].
+pub(super) fn rename(
+    text: &str,
+    byte_offset: usize,
+    file_uri: &Url,
+    root: &Path,
+    project_cache: &ProjectCache,
+    documents: &BTreeMap<Url, String>,
+    new_name: &str,
+) -> Result<WorkspaceEdit, RenameError> {
+    let lexed = Lexed::new(text);
+    let (target, _) = resolve_target(&lexed, byte_offset, file_uri, root, project_cache)
+        .ok_or(RenameError::NotRenameable)?;
+
+    let ident = Ident::new(new_name).map_err(|_| RenameError::InvalidName(new_name.into()))?;
+    if ident.as_str() == target.name() {
+        return Ok(WorkspaceEdit::default());
+    }
+    let new_target = target.renamed(ident.as_str());
+    let collision = || RenameError::Collision {
+        old: target.to_string(),
+        new: new_target.to_string(),
+    };
+
+    let mut file_paths = Vec::new();
+    let mut moves = Vec::new();
+    match (&target, &new_target) {
+        (Target::Object(id), Target::Object(new_id)) => {
+            if project_cache.get_object(new_id).is_some() {
+                return Err(collision());
+            }
+            let definition = project_cache
+                .get_object(id)
+                .ok_or(RenameError::NotRenameable)?;
+            let old_path = root.join(&definition.file_path);
+            if old_path == object_path(root, id) {
+                moves.push((old_path, object_path(root, new_id)));
+            }
+            file_paths = referencing_files(project_cache, std::slice::from_ref(id));
+        }
+        (Target::Schema { database, schema }, Target::Schema { schema: new, .. }) => {
+            let objects = project_cache.list_objects();
+            if objects
+                .iter()
+                .any(|o| o.database == *database && o.schema == *new)
+            {
+                return Err(collision());
+            }
+            let ids: Vec<ObjectId> = objects
+                .into_iter()
+                .filter(|o| o.database == *database && o.schema == *schema)
+                .map(|o| ObjectId::new(o.database, o.schema, o.name))
+                .collect();
+            file_paths = referencing_files(project_cache, &ids);
+
+            let database_dir = root.join("models").join(database);
+            moves.push((database_dir.join(schema), database_dir.join(new)));
+            let mod_file = format!("models/{database}/{schema}.sql");
+            if root.join(&mod_file).exists() {
+                file_paths.push(mod_file);
+                moves.push((
+                    database_dir.join(format!("{schema}.sql")),
+                    database_dir.join(format!("{new}.sql")),
+                ));
+            }
+        }
+        (Target::Column { object, .. }, Target::Column { column: new, .. }) => {
+            if columns(object, project_cache).contains(new) {
+                return Err(collision());
+            }
+            file_paths = referencing_files(project_cache, std::slice::from_ref(object));
+        }
+        _ => unreachable!("renaming keeps the kind of the target"),
+    }
+    for (_, new_path) in &moves {
+        if new_path.exists() {
+            return Err(RenameError::FileExists {
+                old: target.to_string(),
+                path: new_path.display().to_string(),
+            });
+        }
+    }
+
+    let replacement = ident.to_string();
+    let mut operations = Vec::new();
+    for file_path in &file_paths {
+        let full_path = root.join(file_path);
+        let Ok(uri) = Url::from_file_path(&full_path) else {
+            continue;
+        };
+        let contents = match documents.get(&uri) {
+            Some(text) => text.clone(),
+            None => match std::fs::read_to_string(&full_path) {
+                Ok(text) => text,
+                Err(_) => continue,
+            },
+        };
+        let edits = rename_edits(&contents, &uri, root, &target, &replacement, project_cache);
+        if !edits.is_empty() {
+            operations.push(DocumentChangeOperation::Edit(TextDocumentEdit {
+                text_document: OptionalVersionedTextDocumentIdentifier { uri, version: None },
+                edits: edits.into_iter().map(OneOf::Left).collect(),
+            }));
+        }
+    }
+
+    // Files are moved after they are edited, so the edits address them by
+    // their old paths.
+    for (old_path, new_path) in moves {
+        if !old_path.exists() {
+            continue;
+        }
+        if let (Ok(old_uri), Ok(new_uri)) = (
+            Url::from_file_path(&old_path),
+            Url::from_file_path(&new_path),
+        ) {
+            operations.push(DocumentChangeOperation::Op(ResourceOp::Rename(
+                RenameFile {
+                    old_uri,
+                    new_uri,
+                    options: None,
+                    annotation_id: None,
+                },
+            )));
+        }
+    }
+
+    Ok(WorkspaceEdit {
+        document_changes: Some(DocumentChanges::Operations(operations)),
+        ..Default::default()
+    })
+}
+
+/// Resolve the identifier under the cursor to a rename target, and return the
+/// token that names it.
+fn resolve_target<'l>(
+    lexed: &'l Lexed<'_>,
+    byte_offset: usize,
+    file_uri: &Url,
+    root: &Path,
+    project_cache: &ProjectCache,
+) -> Option<(Target, &'l IdentToken)> {
+    let (chain, idx) = lexed.chain_at_offset(byte_offset)?;
+    let parts: Vec<String> = chain.iter().map(|t| t.name.clone()).collect();
+    let token = &chain[idx];
+
+    // `SCHEMA [<database>.]<schema>`, as in grants, comments and default
+    // privileges.
+    if lexed.follows_keyword(chain, SCHEMA) {
+        let (database, schema) = match parts.as_slice() {
+            [schema] if idx == 0 => (file_database(file_uri, root)?, schema.clone()),
+            [database, schema] if idx == 1 => (database.clone(), schema.clone()),
+            _ => return None,
+        };
+        let exists = project_cache
+            .list_objects()
+            .iter()
+            .any(|o| o.database == database && o.schema == schema);
+        return exists.then_some((Target::Schema { database, schema }, token));
+    }
+
+    if let Some((len, id)) = object_prefix(&parts, file_uri, root, project_cache) {
+        let target = if idx + 1 == len {
+            Target::Object(id)
+        } else if idx + 2 == len {
+            Target::Schema {
+                database: id.expect_database().to_string(),
+                schema: id.schema().to_string(),
+            }
+        } else if idx == len && idx + 1 == parts.len() {
+            Target::Column {
+                object: id,
+                column: token.name.clone(),
+            }
+        } else {
+            return None;
+        };
+        if let Target::Column { object, column } = &target {
+            if !columns(object, project_cache).contains(column) {
+                return None;
+            }
+        }
+        return Some((target, token));
+    }
+
+    let file_object = file_object(file_uri, root, project_cache);
+    let object = match parts.as_slice() {
+        // `f.id`, where `f` is an alias of an object in the file's query.
+        [alias, _] if idx == 1 => file_object
+            .as_ref()
+            .and_then(|(_, obj)| obj.aliases.get(&alias.to_lowercase()))
+            .and_then(|fqn| fqn.parse::<ObjectId>().ok())?,
+        [_] => bare_column_object(lexed, chain, file_uri, root, project_cache, file_object)?,
+        _ => return None,
+    };
+    let column = token.name.clone();
+    columns(&object, project_cache)
+        .contains(&column)
+        .then_some((Target::Column { object, column }, token))
+}
+
+/// Resolve the object whose column a bare identifier names.
+///
+/// That is the object in front of a column list (`ON foo (id)`), the file's
+/// own object for an output column alias, or else the only dependency of the
+/// file's object that has a column of that name.
+fn bare_column_object(
+    lexed: &Lexed<'_>,
+    chain: &[IdentToken],
+    file_uri: &Url,
+    root: &Path,
+    project_cache: &ProjectCache,
+    file_object: Option<(ObjectId, CachedObject)>,
+) -> Option<ObjectId> {
+    let token = &chain[0];
+    if let Some(owner) = lexed.column_list_owner(token.index) {
+        let parts: Vec<String> = owner.iter().map(|t| t.name.clone()).collect();
+        if let Some(id) = goto_definition::resolve_object_id(&parts, file_uri, root) {
+            if project_cache.get_object(&id).is_some() {
+                return Some(id);
+            }
+        }
+    }
+    let (file_id, _) = file_object?;
+    let is_output_alias = lexed
+        .main_select_items()
+        .iter()
+        .any(|item| matches!(item, SelectItem::Aliased(i) if *i == token.index));
+    if is_output_alias {
+        return Some(file_id);
+    }
+    if lexed.follows_keyword(chain, AS) {
+        return None;
+    }
+    only_dependency_with_column(project_cache, &file_id, &token.name)
+}
+
+/// Compute the edits that rename every reference to `target` in one file.
+fn rename_edits(
+    text: &str,
+    file_uri: &Url,
+    root: &Path,
+    target: &Target,
+    replacement: &str,
+    project_cache: &ProjectCache,
+) -> Vec<TextEdit> {
+    let lexed = Lexed::new(text);
+    let spans = match target {
+        Target::Object(id) => object_spans(&lexed, file_uri, root, id, replacement, project_cache),
+        Target::Schema { database, schema } => schema_spans(
+            &lexed,
+            file_uri,
+            root,
+            database,
+            schema,
+            replacement,
+            project_cache,
+        ),
+        Target::Column { object, column } => column_spans(
+            &lexed,
+            file_uri,
+            root,
+            object,
+            column,
+            replacement,
+            project_cache,
+        ),
+    };
+
+    let rope = Rope::from_str(text);
+    spans
+        .into_iter()
+        .filter_map(|(start, end, new_text)| {
+            Some(TextEdit {
+                range: Range::new(
+                    offset_to_position(start, &rope)?,
+                    offset_to_position(end, &rope)?,
+                ),
+                new_text,
+            })
+        })
+        .collect()
+}
+
+/// The byte spans to replace to rename the object `id`.
+fn object_spans(
+    lexed: &Lexed<'_>,
+    file_uri: &Url,
+    root: &Path,
+    id: &ObjectId,
+    replacement: &str,
+    project_cache: &ProjectCache,
+) -> Vec<(usize, usize, String)> {
+    let mut spans = Vec::new();
+    for chain in &lexed.chains {
+        let parts: Vec<String> = chain.iter().map(|t| t.name.clone()).collect();
+        let resolves_to_id = |parts: &[String]| {
+            goto_definition::resolve_object_id(parts, file_uri, root).as_ref() == Some(id)
+        };
+
+        let token = if resolves_to_id(&parts) {
+            chain.last()
+        } else {
+            // A qualified column reference (`foo.id`, `public.foo.id`) whose
+            // full chain is not itself a project object.
+            (1..parts.len())
+                .rev()
+                .find(|&len| resolves_to_id(&parts[..len]))
+                .filter(|_| {
+                    goto_definition::resolve_object_id(&parts, file_uri, root)
+                        .is_none_or(|full| project_cache.get_object(&full).is_none())
+                })
+                .map(|len| &chain[len - 1])
+        };
+
+        if let Some(token) = token {
+            spans.push((token.start, token.end, replacement.to_string()));
+        }
+    }
+    spans
+}
+
+/// The byte spans to replace to rename the schema `database.schema`.
+///
+/// References inside the schema are unqualified or keep working after the
+/// move, so only the qualified ones are rewritten.
+fn schema_spans(
+    lexed: &Lexed<'_>,
+    file_uri: &Url,
+    root: &Path,
+    database: &str,
+    schema: &str,
+    replacement: &str,
+    project_cache: &ProjectCache,
+) -> Vec<(usize, usize, String)> {
+    let file_database = file_database(file_uri, root);
+    let mut spans = Vec::new();
+    for chain in &lexed.chains {
+        let parts: Vec<String> = chain.iter().map(|t| t.name.clone()).collect();
+        let token = if lexed.follows_keyword(chain, SCHEMA) {
+            let names_schema = match parts.as_slice() {
+                [s] => file_database.as_deref() == Some(database) && s == schema,
+                [d, s] => d == database && s == schema,
+                _ => false,
+            };
+            names_schema.then(|| &chain[parts.len() - 1])
+        } else {
+            object_prefix(&parts, file_uri, root, project_cache)
+                .filter(|(len, id)| {
+                    *len >= 2 && id.expect_database() == database && id.schema() == schema
+                })
+                .map(|(len, _)| &chain[len - 2])
+        };
+        if let Some(token) = token {
+            spans.push((token.start, token.end, replacement.to_string()));
+        }
+    }
+    spans
+}
+
+/// The byte spans to replace to rename the column `object.column`.
+fn column_spans(
+    lexed: &Lexed<'_>,
+    file_uri: &Url,
+    root: &Path,
+    object: &ObjectId,
+    column: &str,
+    replacement: &str,
+    project_cache: &ProjectCache,
+) -> Vec<(usize, usize, String)> {
+    let file_object = file_object(file_uri, root, project_cache);
+    let in_definition = file_object.as_ref().is_some_and(|(id, _)| id == object);
+    // Bare column names refer to `object` only if no other input has them.
+    let bare_references = !in_definition
+        && file_object.as_ref().is_some_and(|(id, _)| {
+            only_dependency_with_column(project_cache, id, column).as_ref() == Some(object)
+        });
+    let aliases = file_object.map(|(_, obj)| obj.aliases).unwrap_or_default();
+    let names_object = |parts: &[String]| {
+        let alias = match parts {
+            [alias] => aliases
+                .get(&alias.to_lowercase())
+                .and_then(|fqn| fqn.parse::<ObjectId>().ok()),
+            _ => None,
+        };
+        alias.as_ref() == Some(object)
+            || goto_definition::resolve_object_id(parts, file_uri, root).as_ref() == Some(object)
+    };
+    let names_project_object = |parts: &[String]| {
+        goto_definition::resolve_object_id(parts, file_uri, root)
+            .is_some_and(|id| project_cache.get_object(&id).is_some())
+    };
+
+    let items = lexed.main_select_items();
+    let mut implicit_items = BTreeSet::new();
+    let mut alias_tokens = BTreeSet::new();
+    for item in &items {
+        match item {
+            SelectItem::Implicit(chain) => {
+                implicit_items.insert(*chain);
+            }
+            SelectItem::Aliased(index) => {
+                alias_tokens.insert(*index);
+            }
+            SelectItem::Unnamed => {}
+        }
+    }
+    let keep_name = format!("{} AS {}", replacement, Ident::new_unchecked(column));
+
+    let mut spans = Vec::new();
+    for (c, chain) in lexed.chains.iter().enumerate() {
+        let parts: Vec<String> = chain.iter().map(|t| t.name.clone()).collect();
+        let last = chain.last().expect("chains are not empty");
+
+        // `foo (id, ...)`: a table definition, index, mock or insert.
+        if lexed.opens_list(last.index) && names_object(&parts) {
+            for index in lexed.column_list(last.index + 1) {
+                if let Some(token) = lexed.ident(index).filter(|t| t.name == column) {
+                    spans.push((token.start, token.end, replacement.to_string()));
+                }
+            }
+        }
+
+        let is_reference = match parts.as_slice() {
+            [.., name] if parts.len() >= 2 => {
+                name == column && names_object(&parts[..parts.len() - 1])
+            }
+            [name] => {
+                bare_references
+                    && name == column
+                    && !lexed.opens_list(last.index)
+                    && !lexed.follows_keyword(chain, AS)
+                    && !alias_tokens.contains(&last.index)
+                    && lexed.column_list_owner(last.index).is_none_or(|owner| {
+                        let owner: Vec<String> = owner.iter().map(|t| t.name.clone()).collect();
+                        !names_project_object(&owner)
+                    })
+            }
+            _ => false,
+        };
+        if is_reference {
+            let new_text = if implicit_items.contains(&c) {
+                keep_name.clone()
+            } else {
+                replacement.to_string()
+            };
+            spans.push((last.start, last.end, new_text));
+        }
+    }
+
+    // The output columns of a view without a column list.
+    if in_definition && lexed.create_column_list().is_none() {
+        for item in &items {
+            match item {
+                SelectItem::Aliased(index) => {
+                    if let Some(token) = lexed.ident(*index).filter(|t| t.name == column) {
+                        spans.push((token.start, token.end, replacement.to_string()));
+                    }
+                }
+                SelectItem::Implicit(chain) => {
+                    let last = lexed.chains[*chain].last().expect("chains are not empty");
+                    if last.name == column {
+                        spans.push((last.end, last.end, format!(" AS {replacement}")));
+                    }
+                }
+                SelectItem::Unnamed => {}
+            }
+        }
+    }
+    spans
+}
+
+/// The longest prefix of `parts` (at most three parts) that names a project
+/// object, as its length and the object.
+fn object_prefix(
+    parts: &[String],
+    file_uri: &Url,
+    root: &Path,
+    project_cache: &ProjectCache,
+) -> Option<(usize, ObjectId)> {
+    (1..=parts.len().min(3)).rev().find_map(|len| {
+        let id = goto_definition::resolve_object_id(&parts[..len], file_uri, root)?;
+        project_cache.get_object(&id).map(|_| (len, id))
+    })
+}
+
+/// The object defined in the file at `file_uri`.
+fn file_object(
+    file_uri: &Url,
+    root: &Path,
+    project_cache: &ProjectCache,
+) -> Option<(ObjectId, CachedObject)> {
+    let path = file_uri.to_file_path().ok()?;
+    let relative = path.strip_prefix(root).ok()?;
+    let object = project_cache.get_object_by_path(&relative.to_string_lossy())?;
+    Some((object.fqn.parse().ok()?, object))
+}
+
+/// The database of a file under `models/<database>/`, including the mod files
+/// of its schemas.
+fn file_database(file_uri: &Url, root: &Path) -> Option<String> {
+    let path = file_uri.to_file_path().ok()?;
+    let relative = path.strip_prefix(root.join("models")).ok()?;
+    let mut components = relative.components();
+    let database = components
+        .next()?
+        .as_os_str()
+        .to_string_lossy()
+        .into_owned();
+    components.next()?;
+    Some(database)
+}
+
+/// The only dependency of `id` that has a column named `column`.
+fn only_dependency_with_column(
+    project_cache: &ProjectCache,
+    id: &ObjectId,
+    column: &str,
+) -> Option<ObjectId> {
+    let mut owners = project_cache
+        .get_dependencies(id)
+        .into_iter()
+        .filter(|dep| columns(dep, project_cache).contains(column));
+    let owner = owners.next()?;
+    owners.next().is_none().then_some(owner)
+}
+
+/// The column names of the object `id`: its typecheck results if there are
+/// any, and otherwise the columns that its definition names.
+fn columns(id: &ObjectId, project_cache: &ProjectCache) -> BTreeSet<String> {
+    if let Some(columns) = project_cache.get_columns(id) {
+        return columns.into_keys().collect();
+    }
+    project_cache
+        .get_object(id)
+        .map(|obj| defined_columns(&obj.sql_text))
+        .unwrap_or_default()
+}
+
+/// The column names that a `CREATE` statement spells out: its column list, or
+/// the names of its select items.
+fn defined_columns(sql: &str) -> BTreeSet<String> {
+    let lexed = Lexed::new(sql);
+    match lexed.create_column_list() {
+        Some(list) => list
+            .into_iter()
+            .filter_map(|index| lexed.ident(index).map(|t| t.name))
+            .collect(),
+        None => lexed
+            .main_select_items()
+            .iter()
+            .filter_map(|item| lexed.output_name(item))
+            .collect(),
+    }
+}
+
+/// The files that can mention the objects `ids`: their defining files and
+/// the files of their direct dependents.
+fn referencing_files(project_cache: &ProjectCache, ids: &[ObjectId]) -> Vec<String> {
+    let mut file_paths = Vec::new();
+    for id in ids {
+        let dependents = project_cache.get_dependents(id);
+        for object_id in std::iter::once(id).chain(&dependents) {
+            if let Some(object) = project_cache.get_object(object_id) {
+                if !file_paths.contains(&object.file_path) {
+                    file_paths.push(object.file_path);
+                }
+            }
+        }
+    }
+    file_paths
+}
+
+/// The path that the `models/<database>/<schema>/<object>.sql` layout gives
+/// the object `id`.
+fn object_path(root: &Path, id: &ObjectId) -> PathBuf {
+    root.join("models")
+        .join(id.expect_database())
+        .join(id.schema())
+        .join(format!("{}.sql", id.object()))
+}
+
+/// Whether a token can be an unquoted name: an identifier or a keyword that is
+/// never reserved, like `id` or `name`.
+fn is_name(kind: &Token) -> bool {
+    match kind {
+        Token::Ident(_) => true,
+        Token::Keyword(kw) => !kw.is_sometimes_reserved(),
+        _ => false,
+    }
+}
+
+/// Split the tokens into maximal dot-separated identifier chains.
+///
+/// Keywords are accepted as parts of qualified chains (`mydb.schema.t`). A bare
+/// keyword is only an identifier if it is never reserved, as many column names
+/// are.
+fn identifier_chains(text: &str, tokens: &[lexer::PosToken]) -> Vec<Vec<IdentToken>> {
+    let mut chains = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let Some(first) = ident_token(text, &tokens[i], i) else {
+            i += 1;
+            continue;
+        };
+        let mut chain = vec![first];
+        let is_name = is_name(&tokens[i].kind);
+        while i + 2 < tokens.len() && matches!(tokens[i + 1].kind, Token::Dot) {
+            match ident_token(text, &tokens[i + 2], i + 2) {
+                Some(next) => {
+                    chain.push(next);
+                    i += 2;
+                }
+                None => break,
+            }
+        }
+        if is_name || chain.len() > 1 {
+            chains.push(chain);
+        }
+        i += 1;
+    }
+    chains
+}
+
+/// Extract an identifier (or keyword used as one) with its source span.
+fn ident_token(text: &str, token: &lexer::PosToken, index: usize) -> Option<IdentToken> {
+    let name = match &token.kind {
+        Token::Ident(s) => s.to_string(),
+        Token::Keyword(kw) => kw.as_str().to_lowercase(),
+        _ => return None,
+    };
+    Some(IdentToken {
+        name,
+        start: token.offset,
+        end: ident_end(text, token.offset),
+        index,
+    })
+}
+
+/// Find the end of the identifier starting at `start`, honoring `""` escapes
+/// inside quoted identifiers.
+fn ident_end(text: &str, start: usize) -> usize {
+    let rest = &text[start..];
+    if let Some(quoted) = rest.strip_prefix('"') {
+        let mut chars = quoted.char_indices().peekable();
+        while let Some((i, ch)) = chars.next() {
+            if ch == '"' {
+                if chars.peek().map(|(_, c)| *c) == Some('"') {
+                    chars.next();
+                } else {
+                    return start + 1 + i + 1;
+                }
+            }
+        }
+        text.len()
+    } else {
+        start
+            + rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+                .unwrap_or(rest.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsp::diagnostics::position_to_offset;
+    use crate::project::compiler::cache::ProjectCache;
+
+    #[mz_ore::test]
+    fn ident_end_handles_quotes() {
+        let text = r#"SELECT * FROM "my ""view""" x"#;
+        assert_eq!(ident_end(text, 14), 27);
+        assert_eq!(ident_end("foo_1.bar", 0), 5);
+    }
+
+    #[mz_ore::test]
+    fn identifier_chains_skip_reserved_keywords() {
+        let lexed = Lexed::new("SELECT foo.id, name FROM public.foo");
+        let names: Vec<Vec<String>> = lexed
+            .chains
+            .iter()
+            .map(|c| c.iter().map(|t| t.name.clone()).collect())
+            .collect();
+        assert_eq!(
+            names,
+            vec![vec!["foo", "id"], vec!["name"], vec!["public", "foo"]]
+                .into_iter()
+                .map(|c| c.into_iter().map(String::from).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[mz_ore::test]
+    fn defined_columns_of_tables_and_views() {
+        let names = |sql: &str| defined_columns(sql).into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            names("CREATE TABLE mydb.s.t (id int4, name text, PRIMARY KEY (id));"),
+            ["id", "name"]
+        );
+        assert_eq!(
+            names("CREATE VIEW mydb.s.v (a, b) AS SELECT 1, 2;"),
+            ["a", "b"]
+        );
+        assert_eq!(
+            names(
+                "CREATE MATERIALIZED VIEW mydb.s.mv IN CLUSTER c AS \
+                 SELECT DISTINCT ON (x) x, t.y, z + 1 AS z1, w w1, count(*) \
+                 FROM (SELECT 1 AS inner_col) t;"
+            ),
+            ["w1", "x", "y", "z1"]
+        );
+    }
+
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    #[mz_ore::test]
+    fn rename_edits_definition_and_dependents_and_moves_file() {
+        let (root, cache) = build_test_project_cache();
+        let bar = root.path().join("models/mydb/public/bar.sql");
+        let file_uri = Url::from_file_path(&bar).unwrap();
+        let text = std::fs::read_to_string(&bar).unwrap();
+        let offset = text.rfind("foo").unwrap();
+
+        let edit = rename(
+            &text,
+            offset,
+            &file_uri,
+            root.path(),
+            &cache,
+            &BTreeMap::new(),
+            "renamed",
+        )
+        .unwrap();
+
+        let (files, moves) = apply(root.path(), edit);
+        assert_eq!(
+            files,
+            BTreeMap::from([
+                (
+                    "models/mydb/public/bar.sql".to_string(),
+                    "CREATE VIEW bar AS SELECT renamed.id FROM renamed;".to_string(),
+                ),
+                (
+                    "models/mydb/public/foo.sql".to_string(),
+                    "CREATE VIEW renamed AS SELECT 1 AS id;".to_string(),
+                ),
+            ])
+        );
+        assert_eq!(
+            moves,
+            [(
+                "models/mydb/public/foo.sql".to_string(),
+                "models/mydb/public/renamed.sql".to_string(),
+            )]
+        );
+    }
+
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    #[mz_ore::test]
+    fn rename_refuses_collision() {
+        let (root, cache) = build_test_project_cache();
+        let bar = root.path().join("models/mydb/public/bar.sql");
+        let file_uri = Url::from_file_path(&bar).unwrap();
+        let text = std::fs::read_to_string(&bar).unwrap();
+        let offset = text.rfind("foo").unwrap();
+
+        let err = rename(
+            &text,
+            offset,
+            &file_uri,
+            root.path(),
+            &cache,
+            &BTreeMap::new(),
+            "bar",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            RenameError::Collision {
+                old: "mydb.public.foo".to_string(),
+                new: "mydb.public.bar".to_string(),
+            }
+        );
+    }
+
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    #[mz_ore::test]
+    fn prepare_rename_accepts_schema_object_and_column_parts() {
+        let (root, cache) = build_test_project_cache();
+        let file_uri = Url::from_file_path(root.path().join("models/mydb/public/bar.sql")).unwrap();
+        let text = "SELECT public.foo.id FROM mydb.public.foo";
+
+        let placeholder =
+            |offset| match prepare_rename(text, offset, &file_uri, root.path(), &cache) {
+                Some(PrepareRenameResponse::RangeWithPlaceholder { range, placeholder }) => {
+                    Some((range, placeholder))
+                }
+                _ => None,
+            };
+        let range = |start, end| Range::new(Position::new(0, start), Position::new(0, end));
+
+        assert_eq!(placeholder(7), Some((range(7, 13), "public".to_string())));
+        assert_eq!(placeholder(14), Some((range(14, 17), "foo".to_string())));
+        assert_eq!(placeholder(18), Some((range(18, 20), "id".to_string())));
+        // Databases cannot be renamed.
+        assert_eq!(placeholder(26), None);
+        assert_eq!(placeholder(31), Some((range(31, 37), "public".to_string())));
+    }
+
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    #[mz_ore::test]
+    fn rename_schema_edits_qualified_references_and_moves_directory() {
+        let (root, cache) = build_project(&[
+            (
+                "models/mydb/public/foo.sql",
+                "CREATE VIEW foo AS SELECT 1 AS id;",
+            ),
+            (
+                "models/mydb/public.sql",
+                "COMMENT ON SCHEMA public IS 'models';",
+            ),
+            (
+                "models/mydb/other/baz.sql",
+                "CREATE VIEW baz AS SELECT id FROM public.foo;",
+            ),
+        ]);
+        let baz = root.path().join("models/mydb/other/baz.sql");
+        let file_uri = Url::from_file_path(&baz).unwrap();
+        let text = std::fs::read_to_string(&baz).unwrap();
+        let offset = text.find("public").unwrap();
+
+        let edit = rename(
+            &text,
+            offset,
+            &file_uri,
+            root.path(),
+            &cache,
+            &BTreeMap::new(),
+            "core",
+        )
+        .unwrap();
+
+        let (files, moves) = apply(root.path(), edit);
+        assert_eq!(
+            files,
+            BTreeMap::from([
+                (
+                    "models/mydb/other/baz.sql".to_string(),
+                    "CREATE VIEW baz AS SELECT id FROM core.foo;".to_string(),
+                ),
+                (
+                    "models/mydb/public.sql".to_string(),
+                    "COMMENT ON SCHEMA core IS 'models';".to_string(),
+                ),
+            ])
+        );
+        assert_eq!(
+            moves,
+            [
+                (
+                    "models/mydb/public".to_string(),
+                    "models/mydb/core".to_string(),
+                ),
+                (
+                    "models/mydb/public.sql".to_string(),
+                    "models/mydb/core.sql".to_string(),
+                ),
+            ]
+        );
+
+        let err = rename(
+            &text,
+            offset,
+            &file_uri,
+            root.path(),
+            &cache,
+            &BTreeMap::new(),
+            "other",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            RenameError::Collision {
+                old: "mydb.public".to_string(),
+                new: "mydb.other".to_string(),
+            }
+        );
+    }
+
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    #[mz_ore::test]
+    fn rename_table_column_edits_definition_and_bare_references() {
+        let (root, cache) = build_project(&[
+            (
+                "models/mydb/storage/t.sql",
+                "CREATE TABLE t (id int, name text);",
+            ),
+            (
+                "models/mydb/public/v.sql",
+                "CREATE VIEW v AS SELECT id, name FROM storage.t WHERE id > 0;",
+            ),
+        ]);
+        let t = root.path().join("models/mydb/storage/t.sql");
+        let file_uri = Url::from_file_path(&t).unwrap();
+        let text = std::fs::read_to_string(&t).unwrap();
+        let offset = text.find("id").unwrap();
+
+        let edit = rename(
+            &text,
+            offset,
+            &file_uri,
+            root.path(),
+            &cache,
+            &BTreeMap::new(),
+            "key",
+        )
+        .unwrap();
+
+        // `v` keeps its own column name.
+        let (files, moves) = apply(root.path(), edit);
+        assert_eq!(
+            files,
+            BTreeMap::from([
+                (
+                    "models/mydb/public/v.sql".to_string(),
+                    "CREATE VIEW v AS SELECT key AS id, name FROM storage.t WHERE key > 0;"
+                        .to_string(),
+                ),
+                (
+                    "models/mydb/storage/t.sql".to_string(),
+                    "CREATE TABLE t (key int, name text);".to_string(),
+                ),
+            ])
+        );
+        assert!(moves.is_empty());
+
+        let err = rename(
+            &text,
+            offset,
+            &file_uri,
+            root.path(),
+            &cache,
+            &BTreeMap::new(),
+            "name",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            RenameError::Collision {
+                old: "mydb.storage.t.id".to_string(),
+                new: "mydb.storage.t.name".to_string(),
+            }
+        );
+    }
+
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    #[mz_ore::test]
+    fn rename_view_column_through_alias_aliases_output_columns() {
+        let (root, cache) = build_project(&[
+            ("models/mydb/storage/t.sql", "CREATE TABLE t (id int);"),
+            (
+                "models/mydb/public/foo.sql",
+                "CREATE VIEW foo AS SELECT t.id FROM storage.t;",
+            ),
+            (
+                "models/mydb/public/bar.sql",
+                "CREATE VIEW bar AS SELECT f.id FROM foo AS f;",
+            ),
+        ]);
+        let bar = root.path().join("models/mydb/public/bar.sql");
+        let file_uri = Url::from_file_path(&bar).unwrap();
+        let text = std::fs::read_to_string(&bar).unwrap();
+        let offset = text.find("id").unwrap();
+
+        let edit = rename(
+            &text,
+            offset,
+            &file_uri,
+            root.path(),
+            &cache,
+            &BTreeMap::new(),
+            "ident",
+        )
+        .unwrap();
+
+        // Neither `t.id` nor the output column of `bar` are renamed.
+        let (files, _) = apply(root.path(), edit);
+        assert_eq!(
+            files,
+            BTreeMap::from([
+                (
+                    "models/mydb/public/bar.sql".to_string(),
+                    "CREATE VIEW bar AS SELECT f.ident AS id FROM foo AS f;".to_string(),
+                ),
+                (
+                    "models/mydb/public/foo.sql".to_string(),
+                    "CREATE VIEW foo AS SELECT t.id AS ident FROM storage.t;".to_string(),
+                ),
+            ])
+        );
+    }
+
+    /// Apply the edits of a rename to the files on disk, without moving them.
+    ///
+    /// Returns the edited files and the moves, by paths relative to `root`.
+    fn apply(
+        root: &Path,
+        edit: WorkspaceEdit,
+    ) -> (BTreeMap<String, String>, Vec<(String, String)>) {
+        let relative = |uri: &Url| {
+            uri.to_file_path()
+                .unwrap()
+                .strip_prefix(root)
+                .unwrap()
+                .display()
+                .to_string()
+        };
+        let Some(DocumentChanges::Operations(ops)) = edit.document_changes else {
+            panic!("expected document change operations");
+        };
+        let mut files = BTreeMap::new();
+        let mut moves = Vec::new();
+        for op in ops {
+            match op {
+                DocumentChangeOperation::Edit(e) => {
+                    let path = e.text_document.uri.to_file_path().unwrap();
+                    let mut text = std::fs::read_to_string(path).unwrap();
+                    let rope = Rope::from_str(&text);
+                    let mut spans: Vec<_> = e
+                        .edits
+                        .into_iter()
+                        .map(|edit| match edit {
+                            OneOf::Left(edit) => (
+                                position_to_offset(edit.range.start, &rope).unwrap(),
+                                position_to_offset(edit.range.end, &rope).unwrap(),
+                                edit.new_text,
+                            ),
+                            OneOf::Right(_) => panic!("unexpected annotated edit"),
+                        })
+                        .collect();
+                    spans.sort_by_key(|(start, _, _)| std::cmp::Reverse(*start));
+                    for (start, end, new_text) in spans {
+                        text.replace_range(start..end, &new_text);
+                    }
+                    files.insert(relative(&e.text_document.uri), text);
+                }
+                DocumentChangeOperation::Op(ResourceOp::Rename(r)) => {
+                    moves.push((relative(&r.old_uri), relative(&r.new_uri)))
+                }
+                other => panic!("unexpected operation: {:?}", other),
+            }
+        }
+        (files, moves)
+    }
+
+    /// Compile a project and open a ProjectCache from its SQLite DB.
+    fn build_test_project_cache() -> (tempfile::TempDir, ProjectCache) {
+        build_project(&[
+            (
+                "models/mydb/public/foo.sql",
+                "CREATE VIEW foo AS SELECT 1 AS id;",
+            ),
+            (
+                "models/mydb/public/bar.sql",
+                "CREATE VIEW bar AS SELECT foo.id FROM foo;",
+            ),
+        ])
+    }
+
+    /// Compile a project made of `files` and open a ProjectCache from its
+    /// SQLite DB.
+    fn build_project(files: &[(&str, &str)]) -> (tempfile::TempDir, ProjectCache) {
+        let root = tempfile::tempdir().unwrap();
+        for (path, contents) in files {
+            let path = root.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        }
+        std::fs::write(
+            root.path().join("project.toml"),
+            "[project]\nname = \"test\"\n",
+        )
+        .unwrap();
+
+        let _project = crate::project::plan_sync(
+            &crate::fs::FileSystem::new(),
+            root.path(),
+            None,
+            None,
+            &Default::default(),
+        )
+        .expect("project should compile");
+        let cache = ProjectCache::open(root.path(), "", None, &Default::default())
+            .expect("cache should open")
+            .expect("cache DB should exist");
+        (root, cache)
+    }
+}
@@ -21,7 +21,7 @@
 //!   first successful build; the same handle is reused across rebuilds.
 //! - **`parse_diagnostics`** — Per-keystroke parse-level diagnostics, keyed by
 //!   URI. Updated on every `didOpen` / `didChange`.
-//! - **`project_diagnostics`** — Project-level (validation + typecheck)
+//! - **`project_diagnostics`** — Project-level (validation, typecheck, lint)
 //!   diagnostics, keyed by URI. Updated on every `rebuild_project`.
 //! - **`root`** — The workspace root directory.
 //! - **`settings`** / **`variables`** — Project and profile configuration,
@@ -42,10 +42,11 @@
 
 use crate::config::{ProjectSettings, read_mzprofile};
 use crate::lsp::{
-    code_action, code_lens, completion, diagnostics, document_symbol, goto_definition, hover,
-    references, semantic_tokens, workspace_symbol,
+    code_action, code_lens, completion, diagnostics, document_symbol, formatting, goto_definition,
+    hover, references, rename, semantic_tokens, workspace_symbol,
 };
 use crate::project;
+use crate::project::analysis::{breaking, lint};
 use crate::project::compiler::cache::ProjectCache;
 use crate::project::compiler::typecheck::TypeCheckError;
 use crate::project::error::{ProjectError, ValidationErrors};
@@ -337,7 +338,7 @@ impl Backend {
 
         self.load_settings().await;
         let root = self.root.read().await.clone();
-        let (profile, profile_suffix, variables) = {
+        let (profile, profile_suffix, variables, lint_levels) = {
             let settings_guard = self.settings.read().await;
             match settings_guard.as_ref() {
                 Some(ps) => {
@@ -350,9 +351,10 @@ impl Backend {
                         profile,
                         config.profile_suffix.clone(),
                         config.variables.clone(),
+                        ps.lint.clone(),
                     )
                 }
-                None => (None, None, BTreeMap::new()),
+                None => (None, None, BTreeMap::new(), BTreeMap::new()),
             }
         };
 
@@ -387,11 +389,21 @@ impl Backend {
             }
         };
 
+        // Lints only need the compiled project, so they run whether or not
+        // typechecking succeeds.
+        if let Some(ref project) = project {
+            let findings = lint::run(project, &root, &lint_levels, &fs);
+            for (path, diags) in diagnostics::lint_diagnostics(&fs, &findings) {
+                new_diagnostics.entry(path).or_default().extend(diags);
+            }
+        }
+
         // Run typecheck only when the project compiled. Merge typecheck errors
-        // into the diagnostic map so they flow through the same publish/clear
-        // pipeline as validation errors.
+        // and breaking changes to contract objects into the diagnostic map so
+        // they flow through the same publish/clear pipeline as validation
+        // errors.
         if let Some(ref project) = project {
-            if let Some(tc_err) = self
+            match self
                 .run_typecheck(
                     Arc::clone(project),
                     profile.as_deref().unwrap_or(""),
@@ -400,20 +412,30 @@ impl Backend {
                 )
                 .await
             {
-                let candidates = {
-                    let guard = self.project_cache.lock().await;
-                    code_action::harvest_candidates(guard.as_ref())
-                };
-                let tc_diags = diagnostics::typecheck_diagnostics(&fs, &tc_err, &candidates);
-                if tc_diags.is_empty() {
-                    self.client
-                        .log_message(MessageType::ERROR, format!("Typecheck failed: {tc_err}"))
-                        .await;
-                } else {
-                    for (path, diags) in tc_diags {
+                Ok(types) => {
+                    let baseline = types::load_types_lock(&root).unwrap_or_default();
+                    let changes =
+                        breaking::detect(project, &root, &types.tables, &baseline.contracts, &fs);
+                    for (path, diags) in diagnostics::breaking_change_diagnostics(&fs, &changes) {
                         new_diagnostics.entry(path).or_default().extend(diags);
                     }
                 }
+                Err(tc_err) => {
+                    let candidates = {
+                        let guard = self.project_cache.lock().await;
+                        code_action::harvest_candidates(guard.as_ref())
+                    };
+                    let tc_diags = diagnostics::typecheck_diagnostics(&fs, &tc_err, &candidates);
+                    if tc_diags.is_empty() {
+                        self.client
+                            .log_message(MessageType::ERROR, format!("Typecheck failed: {tc_err}"))
+                            .await;
+                    } else {
+                        for (path, diags) in tc_diags {
+                            new_diagnostics.entry(path).or_default().extend(diags);
+                        }
+                    }
+                }
             }
         }
 
@@ -473,28 +495,26 @@ impl Backend {
 
     /// Run compiler-owned typechecking.
     ///
-    /// Returns `Some(err)` on typecheck failure so the caller can convert it
-    /// into LSP diagnostics, `None` on success.
+    /// Returns the typed output columns of every project object on success,
+    /// or the error so the caller can convert it into LSP diagnostics.
     async fn run_typecheck(
         &self,
         project: Arc<graph::Project>,
         profile: &str,
         profile_suffix: Option<&str>,
         variables: &BTreeMap<String, String>,
-    ) -> Option<TypeCheckError> {
+    ) -> std::result::Result<types::Types, TypeCheckError> {
         let root = self.root.read().await.clone();
         let types_lock = types::load_types_lock(&root).unwrap_or_default();
-        match project::compiler::typecheck::run(
+        project::compiler::typecheck::run(
             &root,
             profile,
             profile_suffix,
             variables,
             &project,
             types_lock,
-        ) {
-            Ok((_, _stats)) => None,
-            Err(e) => Some(e),
-        }
+        )
+        .map(|(types, _stats)| types)
     }
 }
 
@@ -523,7 +543,13 @@ impl LanguageServer for Backend {
                 completion_provider: Some(CompletionOptions::default()),
                 definition_provider: Some(OneOf::Left(true)),
                 references_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
                 document_symbol_provider: Some(OneOf::Left(true)),
+                document_formatting_provider: Some(OneOf::Left(true)),
+                document_range_formatting_provider: Some(OneOf::Left(true)),
                 workspace_symbol_provider: Some(OneOf::Left(true)),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 code_lens_provider: Some(CodeLensOptions {
@@ -650,6 +676,100 @@ impl LanguageServer for Backend {
         }
     }
 
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let (text, byte_offset, _) = match self
+            .snapshot_at_position(&params.text_document.uri, params.position)
+            .await
+        {
+            Some(s) => s,
+            None => return Ok(None),
+        };
+
+        let root = self.root.read().await.clone();
+        let cache_guard = self.project_cache.lock().await;
+        let cache = match cache_guard.as_ref() {
+            Some(c) => c,
+            None => return Ok(None),
+        };
+
+        Ok(rename::prepare_rename(
+            &text,
+            byte_offset,
+            &params.text_document.uri,
+            &root,
+            cache,
+        ))
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let uri = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+
+        let (text, byte_offset, _) = match self.snapshot_at_position(&uri, position).await {
+            Some(s) => s,
+            None => return Ok(None),
+        };
+        let documents: BTreeMap<Url, String> = {
+            let docs = self.documents.lock().await;
+            docs.iter()
+                .map(|(uri, rope)| (uri.clone(), rope.to_string()))
+                .collect()
+        };
+
+        let root = self.root.read().await.clone();
+        let cache_guard = self.project_cache.lock().await;
+        let cache = match cache_guard.as_ref() {
+            Some(c) => c,
+            None => return Ok(None),
+        };
+
+        rename::rename(
+            &text,
+            byte_offset,
+            &uri,
+            &root,
+            cache,
+            &documents,
+            &params.new_name,
+        )
+        .map(Some)
+        .map_err(|e| tower_lsp::jsonrpc::Error::invalid_params(e.to_string()))
+    }
+
+    async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        let doc_text = {
+            let docs = self.documents.lock().await;
+            docs.get(&params.text_document.uri)
+                .map(|rope| rope.to_string())
+        };
+        let text = match doc_text.as_deref() {
+            Some(t) => t,
+            None => return Ok(None),
+        };
+
+        Ok(Some(formatting::format_document(text)))
+    }
+
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let doc_text = {
+            let docs = self.documents.lock().await;
+            docs.get(&params.text_document.uri)
+                .map(|rope| rope.to_string())
+        };
+        let text = match doc_text.as_deref() {
+            Some(t) => t,
+            None => return Ok(None),
+        };
+
+        Ok(Some(formatting::format_range(text, params.range)))
+    }
+
     async fn document_symbol(
         &self,
         params: DocumentSymbolParams,
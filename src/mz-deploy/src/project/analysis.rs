@@ -16,9 +16,17 @@
 //! - dirty propagation and incremental deployment planning
 //! - dependency extraction and topological traversal
 //! - graph-wide deployment validations
+//! - lineage graph export
+//! - breaking-change detection for contract objects
+//! - configurable lint rules
+//! - data-quality assertions declared in object files
 
+pub(crate) mod assertions;
+pub(crate) mod breaking;
 pub(crate) mod changeset;
 pub(crate) mod deployment_snapshot;
 pub(crate) mod deps;
 pub(crate) mod graph_validation;
+pub(crate) mod lineage;
+pub(crate) mod lint;
 pub(crate) mod topology;
@@ -0,0 +1,552 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Data-quality assertions declared in object files.
+//!
+//! Unit tests run against mocked inputs; assertions run against the real data
+//! of a staged deployment once it hydrates. They are declared as comments in
+//! the file of the view or materialized view they check:
+//!
+//! ```sql
+//! -- @assert unique(order_id)
+//! -- @assert not_null(order_id, customer_id)
+//! -- @assert references(customer_id, customers(id))
+//! -- @assert row_count(0.9, 1.1)
+//! CREATE MATERIALIZED VIEW orders AS ...
+//! ```
+//!
+//! - `unique(col, ...)` — no two rows share the column values.
+//! - `not_null(col, ...)` — none of the columns is `NULL`.
+//! - `references(col, ..., target(col, ...))` — every non-`NULL` key has a
+//!   matching row in `target`. An unqualified target resolves against the
+//!   object's database and schema.
+//! - `row_count(min, max)` — the staged row count is between `min` and `max`
+//!   times the production row count. Skipped when the object is new.
+//!
+//! `stage` validates the directives and records them with the deployment;
+//! `wait` (and so `promote`'s readiness check) evaluates them against the
+//! staging schemas after every cluster is ready.
+
+use crate::fs::FileSystem;
+use crate::project::ast::Statement;
+use crate::project::ir::graph::Project;
+use crate::project::ir::object_id::ObjectId;
+use itertools::Itertools;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Marker that starts an assertion directive inside a `--` comment.
+const ASSERT_MARKER: &str = "@assert";
+
+/// A single data-quality check on a view or materialized view.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Assertion {
+    Unique(Vec<String>),
+    NotNull(Vec<String>),
+    References {
+        columns: Vec<String>,
+        target: ObjectId,
+        target_columns: Vec<String>,
+    },
+    RowCount {
+        min: f64,
+        max: f64,
+    },
+}
+
+/// An assertion together with the object it checks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectAssertion {
+    pub object: ObjectId,
+    pub assertion: Assertion,
+}
+
+/// A directive that could not be parsed, or was placed on an object that
+/// cannot be queried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssertionError {
+    pub file: PathBuf,
+    pub line: usize,
+    pub message: String,
+}
+
+/// Collect the assertions declared in every object file of the project.
+///
+/// # Errors
+/// Returns the first malformed directive, or a directive on an object other
+/// than a view or materialized view.
+pub fn collect(
+    project: &Project,
+    root: &Path,
+    fs: &FileSystem,
+) -> Result<Vec<ObjectAssertion>, AssertionError> {
+    let mut assertions = Vec::new();
+    for object in project.iter_objects() {
+        let file = root.join(&object.typed_object.path);
+        let source = fs.read_to_string(&file).unwrap_or_default();
+        let queryable = matches!(
+            object.typed_object.stmt,
+            Statement::CreateView(_) | Statement::CreateMaterializedView(_)
+        );
+        for (line, directive) in directives(&source) {
+            let error = |message: String| AssertionError {
+                file: file.clone(),
+                line,
+                message,
+            };
+            if !queryable {
+                return Err(error(
+                    "assertions are only supported on views and materialized views".to_string(),
+                ));
+            }
+            let assertion = Assertion::parse(directive, &object.id).map_err(error)?;
+            assertions.push(ObjectAssertion {
+                object: object.id.clone(),
+                assertion,
+            });
+        }
+    }
+    Ok(assertions)
+}
+
+/// The `@assert` directives in `source`, with their 1-based line numbers.
+fn directives(source: &str) -> Vec<(usize, &str)> {
+    source
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| {
+            let (_, comment) = line.split_once("--")?;
+            let rest = comment.trim_start().strip_prefix(ASSERT_MARKER)?;
+            Some((idx + 1, rest.trim()))
+        })
+        .collect()
+}
+
+impl Assertion {
+    /// Parse the text after `@assert`. Unqualified references resolve against
+    /// the database and schema of `object`.
+    pub fn parse(text: &str, object: &ObjectId) -> Result<Assertion, String> {
+        let tokens = tokenize(text)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let name = parser.ident()?;
+        parser.expect(Token::LParen)?;
+        let args = parser.args()?;
+        parser.expect(Token::RParen)?;
+        if parser.pos != parser.tokens.len() {
+            return Err(format!("unexpected input after {}(...)", name));
+        }
+
+        let columns = |args: Vec<Arg>| -> Result<Vec<String>, String> {
+            if args.is_empty() {
+                return Err(format!("{} needs at least one column", name));
+            }
+            args.into_iter()
+                .map(|arg| match arg {
+                    Arg::Path(mut path, None) if path.len() == 1 => Ok(path.remove(0)),
+                    _ => Err(format!("{} expects a list of column names", name)),
+                })
+                .collect()
+        };
+
+        match name.as_str() {
+            "unique" => Ok(Assertion::Unique(columns(args)?)),
+            "not_null" => Ok(Assertion::NotNull(columns(args)?)),
+            "references" => {
+                let mut args = args;
+                let Some(Arg::Path(path, Some(target_columns))) = args.pop() else {
+                    return Err(
+                        "references expects columns followed by target(column, ...)".to_string()
+                    );
+                };
+                let columns = columns(args)?;
+                if columns.len() != target_columns.len() {
+                    return Err(format!(
+                        "references lists {} column(s) but the target lists {}",
+                        columns.len(),
+                        target_columns.len()
+                    ));
+                }
+                let database = object.expect_database().to_string();
+                let target = match path.as_slice() {
+                    [database, schema, name] => {
+                        ObjectId::new(database.clone(), schema.clone(), name.clone())
+                    }
+                    [schema, name] => ObjectId::new(database, schema.clone(), name.clone()),
+                    [name] => ObjectId::new(database, object.schema().to_string(), name.clone()),
+                    _ => return Err("invalid references target".to_string()),
+                };
+                Ok(Assertion::References {
+                    columns,
+                    target,
+                    target_columns,
+                })
+            }
+            "row_count" => match args.as_slice() {
+                [Arg::Number(min), Arg::Number(max)] if 0.0 <= *min && min <= max => {
+                    Ok(Assertion::RowCount {
+                        min: *min,
+                        max: *max,
+                    })
+                }
+                _ => Err(
+                    "row_count expects (min, max) ratios to production with 0 <= min <= max"
+                        .to_string(),
+                ),
+            },
+            other => Err(format!(
+                "unknown assertion '{}' (expected unique, not_null, references, or row_count)",
+                other
+            )),
+        }
+    }
+
+    /// A query returning a single `bigint` count of rows in `relation` that
+    /// violate the assertion, or `None` for `row_count`, which compares two
+    /// counts instead. `target` is the relation a `references` assertion
+    /// checks against.
+    pub fn violations_query(&self, relation: &str, target: &str) -> Option<String> {
+        let quoted = |columns: &[String]| {
+            columns
+                .iter()
+                .map(|c| quote(c))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self {
+            Assertion::Unique(columns) => Some(format!(
+                "SELECT count(*)::bigint FROM (SELECT {cols} FROM {relation} GROUP BY {cols} HAVING count(*) > 1)",
+                cols = quoted(columns),
+            )),
+            Assertion::NotNull(columns) => Some(format!(
+                "SELECT count(*)::bigint FROM {} WHERE {}",
+                relation,
+                columns
+                    .iter()
+                    .map(|c| format!("{} IS NULL", quote(c)))
+                    .collect::<Vec<_>>()
+                    .join(" OR ")
+            )),
+            Assertion::References {
+                columns,
+                target_columns,
+                ..
+            } => {
+                let not_null = columns
+                    .iter()
+                    .map(|c| format!("s.{} IS NOT NULL", quote(c)))
+                    .collect::<Vec<_>>()
+                    .join(" AND ");
+                let matches = columns
+                    .iter()
+                    .zip_eq(target_columns)
+                    .map(|(c, t)| format!("t.{} = s.{}", quote(t), quote(c)))
+                    .collect::<Vec<_>>()
+                    .join(" AND ");
+                Some(format!(
+                    "SELECT count(*)::bigint FROM {relation} s WHERE {not_null} \
+                     AND NOT EXISTS (SELECT 1 FROM {target} t WHERE {matches})"
+                ))
+            }
+            Assertion::RowCount { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for Assertion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |columns: &[String]| {
+            columns
+                .iter()
+                .map(|c| quote_if_needed(c))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self {
+            Assertion::Unique(columns) => write!(f, "unique({})", list(columns)),
+            Assertion::NotNull(columns) => write!(f, "not_null({})", list(columns)),
+            Assertion::References {
+                columns,
+                target,
+                target_columns,
+            } => write!(
+                f,
+                "references({}, {}.{}.{}({}))",
+                list(columns),
+                quote_if_needed(target.expect_database()),
+                quote_if_needed(target.schema()),
+                quote_if_needed(target.object()),
+                list(target_columns)
+            ),
+            Assertion::RowCount { min, max } => write!(f, "row_count({}, {})", min, max),
+        }
+    }
+}
+
+impl fmt::Display for AssertionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.file.display(), self.line, self.message)
+    }
+}
+
+fn quote(ident: &str) -> String {
+    crate::client::quote_identifier(ident)
+}
+
+/// Quote an identifier only when it would not round-trip unquoted.
+fn quote_if_needed(ident: &str) -> String {
+    let mut chars = ident.chars();
+    let plain = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if plain {
+        ident.to_string()
+    } else {
+        quote(ident)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(f64),
+    LParen,
+    RParen,
+    Comma,
+    Dot,
+}
+
+/// A directive argument: a possibly qualified name with an optional
+/// parenthesized column list, or a number.
+#[derive(Debug)]
+enum Arg {
+    Path(Vec<String>, Option<Vec<String>>),
+    Number(f64),
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' | ',' | '.' => {
+                chars.next();
+                tokens.push(match c {
+                    '(' => Token::LParen,
+                    ')' => Token::RParen,
+                    ',' => Token::Comma,
+                    _ => Token::Dot,
+                });
+            }
+            '"' => {
+                chars.next();
+                let mut ident = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            ident.push('"');
+                        }
+                        Some('"') => break,
+                        Some(c) => ident.push(c),
+                        None => return Err("unterminated quoted identifier".to_string()),
+                    }
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            c if c.is_ascii_digit() => {
+                let mut number = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_ascii_digit() || c == '.') {
+                        break;
+                    }
+                    number.push(c);
+                    chars.next();
+                }
+                let value = number
+                    .parse()
+                    .map_err(|_| format!("invalid number '{}'", number))?;
+                tokens.push(Token::Number(value));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut ident = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_' || c == '$') {
+                        break;
+                    }
+                    ident.extend(c.to_lowercase());
+                    chars.next();
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            other => return Err(format!("unexpected character '{}'", other)),
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn expect(&mut self, token: Token) -> Result<(), String> {
+        if self.peek() == Some(&token) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            let symbol = match token {
+                Token::LParen => "'('",
+                Token::RParen => "')'",
+                Token::Comma => "','",
+                Token::Dot => "'.'",
+                Token::Ident(_) | Token::Number(_) => "a token",
+            };
+            Err(format!("expected {}", symbol))
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, String> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Ident(ident)) => {
+                self.pos += 1;
+                Ok(ident.clone())
+            }
+            _ => Err("expected an identifier".to_string()),
+        }
+    }
+
+    fn ident_list(&mut self) -> Result<Vec<String>, String> {
+        let mut idents = vec![self.ident()?];
+        while self.peek() == Some(&Token::Comma) {
+            self.pos += 1;
+            idents.push(self.ident()?);
+        }
+        Ok(idents)
+    }
+
+    fn args(&mut self) -> Result<Vec<Arg>, String> {
+        let mut args = Vec::new();
+        if self.peek() == Some(&Token::RParen) {
+            return Ok(args);
+        }
+        loop {
+            if let Some(Token::Number(value)) = self.peek() {
+                args.push(Arg::Number(*value));
+                self.pos += 1;
+            } else {
+                let mut path = vec![self.ident()?];
+                while self.peek() == Some(&Token::Dot) {
+                    self.pos += 1;
+                    path.push(self.ident()?);
+                }
+                let columns = if self.peek() == Some(&Token::LParen) {
+                    self.pos += 1;
+                    let columns = self.ident_list()?;
+                    self.expect(Token::RParen)?;
+                    Some(columns)
+                } else {
+                    None
+                };
+                args.push(Arg::Path(path, columns));
+            }
+            if self.peek() != Some(&Token::Comma) {
+                return Ok(args);
+            }
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn orders() -> ObjectId {
+        ObjectId::new(
+            "materialize".to_string(),
+            "sales".to_string(),
+            "orders".to_string(),
+        )
+    }
+
+    #[mz_ore::test]
+    fn directives_are_read_from_comments() {
+        let sql = "-- @assert unique(id)\n-- not an @assert x()\nCREATE VIEW v AS SELECT 1 -- @assert not_null(a)\n";
+        assert_eq!(directives(sql), vec![(1, "unique(id)"), (3, "not_null(a)")]);
+    }
+
+    #[mz_ore::test]
+    fn parse_round_trips_through_display() {
+        for (text, expected) in [
+            ("unique(ID, \"Name\")", "unique(id, \"Name\")"),
+            ("not_null( a ,b )", "not_null(a, b)"),
+            (
+                "references(customer_id, customers(id))",
+                "references(customer_id, materialize.sales.customers(id))",
+            ),
+            (
+                "references(a, b, other.t(x, y))",
+                "references(a, b, materialize.other.t(x, y))",
+            ),
+            ("row_count(0.9, 1.1)", "row_count(0.9, 1.1)"),
+        ] {
+            let assertion = Assertion::parse(text, &orders()).unwrap();
+            assert_eq!(assertion.to_string(), expected);
+            assert_eq!(Assertion::parse(expected, &orders()).unwrap(), assertion);
+        }
+    }
+
+    #[mz_ore::test]
+    fn parse_rejects_malformed_directives() {
+        for (text, message) in [
+            ("unique()", "needs at least one column"),
+            ("unique(a.b)", "list of column names"),
+            ("references(a, b)", "followed by target"),
+            (
+                "references(a, b, t(x))",
+                "lists 2 column(s) but the target lists 1",
+            ),
+            ("row_count(1.1, 0.9)", "0 <= min <= max"),
+            ("distinct(a)", "unknown assertion 'distinct'"),
+            ("unique(a) extra", "unexpected input"),
+        ] {
+            let err = Assertion::parse(text, &orders()).unwrap_err();
+            assert!(err.contains(message), "{text}: {err}");
+        }
+    }
+
+    #[mz_ore::test]
+    fn violations_query_renders_sql() {
+        let unique = Assertion::Unique(vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(
+            unique.violations_query("s.v", "").unwrap(),
+            "SELECT count(*)::bigint FROM (SELECT \"a\", \"b\" FROM s.v GROUP BY \"a\", \"b\" HAVING count(*) > 1)"
+        );
+
+        let references =
+            Assertion::parse("references(customer_id, customers(id))", &orders()).unwrap();
+        assert_eq!(
+            references.violations_query("s.v", "s.c").unwrap(),
+            "SELECT count(*)::bigint FROM s.v s WHERE s.\"customer_id\" IS NOT NULL \
+             AND NOT EXISTS (SELECT 1 FROM s.c t WHERE t.\"id\" = s.\"customer_id\")"
+        );
+
+        let row_count = Assertion::RowCount { min: 0.5, max: 2.0 };
+        assert_eq!(row_count.violations_query("s.v", ""), None);
+    }
+}
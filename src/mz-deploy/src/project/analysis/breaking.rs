@@ -0,0 +1,482 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Breaking-change detection for contract objects.
+//!
+//! A *contract object* is a project object whose output columns are read by
+//! something the project does not redeploy alongside it: the input of a
+//! Kafka or Iceberg sink, or an object another project (or any catalog
+//! object outside this project) depends on. Changing such an object's
+//! output schema silently breaks those consumers, so every change to its
+//! columns is classified before it ships:
+//!
+//! | Change                                  | Classification |
+//! |-----------------------------------------|----------------|
+//! | Added nullable column                   | compatible     |
+//! | Widened type (`int4` → `int8`, `varchar(n)` → `text`) | compatible |
+//! | Added `NOT NULL` column                 | breaking       |
+//! | Dropped or renamed column               | breaking       |
+//! | Narrowed or otherwise changed type      | breaking       |
+//! | Nullability change (either direction)   | breaking       |
+//!
+//! A rename is reported when a column is dropped and another added at the
+//! same ordinal position with the same type and nullability.
+//!
+//! Breaking changes are rejected unless the object's file carries
+//! `-- PRAGMA ALLOW_BREAKING_CHANGE;` (see [`pragma`]) or the caller passes
+//! `--allow-breaking`.
+//!
+//! Type names are normalized before comparison, so the catalog's spelling
+//! (`integer`, `character varying(10)`) and the typechecker's (`int4`,
+//! `varchar(10)`) compare equal.
+
+use crate::fs::FileSystem;
+use crate::project::ast::Statement;
+use crate::project::ir::graph::Project;
+use crate::project::ir::object_id::ObjectId;
+use crate::project::syntax::pragma;
+use crate::types::ColumnType;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A single difference between an object's baseline and new output columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnChange {
+    Added {
+        column: String,
+        r#type: String,
+        nullable: bool,
+    },
+    Dropped {
+        column: String,
+    },
+    Renamed {
+        from: String,
+        to: String,
+    },
+    TypeChanged {
+        column: String,
+        from: String,
+        to: String,
+    },
+    NullabilityChanged {
+        column: String,
+        nullable: bool,
+    },
+}
+
+impl ColumnChange {
+    /// Whether this change can break an existing consumer.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            ColumnChange::Added { nullable, .. } => !nullable,
+            ColumnChange::Dropped { .. }
+            | ColumnChange::Renamed { .. }
+            | ColumnChange::NullabilityChanged { .. } => true,
+            ColumnChange::TypeChanged { from, to, .. } => !is_widening(from, to),
+        }
+    }
+
+    /// The column in the new schema this change points at, if it still exists.
+    pub fn column(&self) -> Option<&str> {
+        match self {
+            ColumnChange::Added { column, .. }
+            | ColumnChange::TypeChanged { column, .. }
+            | ColumnChange::NullabilityChanged { column, .. } => Some(column),
+            ColumnChange::Renamed { to, .. } => Some(to),
+            ColumnChange::Dropped { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for ColumnChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColumnChange::Added {
+                column,
+                r#type,
+                nullable,
+            } => {
+                let null = if *nullable { "" } else { " NOT NULL" };
+                write!(f, "column '{}' added ({}{})", column, r#type, null)
+            }
+            ColumnChange::Dropped { column } => write!(f, "column '{}' dropped", column),
+            ColumnChange::Renamed { from, to } => {
+                write!(f, "column '{}' renamed to '{}'", from, to)
+            }
+            ColumnChange::TypeChanged { column, from, to } => {
+                write!(
+                    f,
+                    "column '{}' changed type from {} to {}",
+                    column, from, to
+                )
+            }
+            ColumnChange::NullabilityChanged { column, nullable } => {
+                let now = if *nullable { "nullable" } else { "NOT NULL" };
+                write!(f, "column '{}' is now {}", column, now)
+            }
+        }
+    }
+}
+
+/// Column changes to one contract object.
+#[derive(Debug, Clone)]
+pub struct ObjectChanges {
+    pub object: ObjectId,
+    /// Source file defining the object.
+    pub path: PathBuf,
+    pub changes: Vec<ColumnChange>,
+    /// Whether the object's file carries `PRAGMA ALLOW_BREAKING_CHANGE`.
+    pub allowed: bool,
+}
+
+impl ObjectChanges {
+    /// The subset of [`changes`](Self::changes) that can break consumers.
+    pub fn breaking(&self) -> impl Iterator<Item = &ColumnChange> {
+        self.changes.iter().filter(|c| c.is_breaking())
+    }
+}
+
+/// Compare an object's `old` output columns with its `new` ones.
+///
+/// Changes are returned in column order: changes to existing columns first
+/// (by old position), then added columns (by new position).
+pub fn diff_columns(
+    old: &BTreeMap<String, ColumnType>,
+    new: &BTreeMap<String, ColumnType>,
+) -> Vec<ColumnChange> {
+    let old_order = ordered(old);
+    let new_order = ordered(new);
+
+    let mut changes = Vec::new();
+    let mut renamed_to = BTreeSet::new();
+
+    for (ordinal, (name, old_col)) in old_order.iter().enumerate() {
+        let Some(new_col) = new.get(*name) else {
+            // A dropped column whose slot is taken by a new column of the same
+            // shape is a rename.
+            let renamed = new_order.get(ordinal).filter(|(new_name, new_col)| {
+                !old.contains_key(*new_name)
+                    && new_col.nullable == old_col.nullable
+                    && same_type(&new_col.r#type, &old_col.r#type)
+            });
+            match renamed {
+                Some((new_name, _)) => {
+                    renamed_to.insert(*new_name);
+                    changes.push(ColumnChange::Renamed {
+                        from: name.to_string(),
+                        to: new_name.to_string(),
+                    });
+                }
+                None => changes.push(ColumnChange::Dropped {
+                    column: name.to_string(),
+                }),
+            }
+            continue;
+        };
+
+        if !same_type(&old_col.r#type, &new_col.r#type) {
+            changes.push(ColumnChange::TypeChanged {
+                column: name.to_string(),
+                from: old_col.r#type.clone(),
+                to: new_col.r#type.clone(),
+            });
+        }
+        if old_col.nullable != new_col.nullable {
+            changes.push(ColumnChange::NullabilityChanged {
+                column: name.to_string(),
+                nullable: new_col.nullable,
+            });
+        }
+    }
+
+    let added = new_order
+        .iter()
+        .filter(|(name, _)| !old.contains_key(*name) && !renamed_to.contains(name));
+    changes.extend(added.map(|(name, col)| ColumnChange::Added {
+        column: name.to_string(),
+        r#type: col.r#type.clone(),
+        nullable: col.nullable,
+    }));
+
+    changes
+}
+
+/// Project objects read directly by one of the project's sinks.
+pub fn sink_inputs(project: &Project) -> BTreeSet<ObjectId> {
+    project
+        .iter_objects()
+        .filter(|o| matches!(o.typed_object.stmt, Statement::CreateSink(_)))
+        .flat_map(|o| o.dependencies.iter().cloned())
+        .filter(|dep| project.find_object(dep).is_some())
+        .collect()
+}
+
+/// Compare the new output columns of every contract object against its
+/// baseline.
+///
+/// Objects missing from either `new_columns` or `baseline` (new objects, or
+/// objects that failed to typecheck) are skipped, as are objects whose
+/// columns are unchanged. Each changed object's file, resolved against the
+/// project `root`, is read through `fs` for the `ALLOW_BREAKING_CHANGE`
+/// pragma.
+pub fn detect(
+    project: &Project,
+    root: &Path,
+    new_columns: &BTreeMap<ObjectId, BTreeMap<String, ColumnType>>,
+    baseline: &BTreeMap<ObjectId, BTreeMap<String, ColumnType>>,
+    fs: &FileSystem,
+) -> Vec<ObjectChanges> {
+    baseline
+        .iter()
+        .filter_map(|(id, old)| {
+            let object = project.find_object(id)?;
+            let new = new_columns.get(id)?;
+            let changes = diff_columns(old, new);
+            if changes.is_empty() {
+                return None;
+            }
+            let path = root.join(&object.typed_object.path);
+            let allowed = fs
+                .read_to_string(&path)
+                .is_ok_and(|sql| pragma::has_pragma(&sql, pragma::ALLOW_BREAKING_CHANGE));
+            Some(ObjectChanges {
+                object: id.clone(),
+                path,
+                changes,
+                allowed,
+            })
+        })
+        .collect()
+}
+
+/// Columns sorted by position.
+fn ordered(columns: &BTreeMap<String, ColumnType>) -> Vec<(&str, &ColumnType)> {
+    let mut cols: Vec<_> = columns.iter().map(|(n, c)| (n.as_str(), c)).collect();
+    cols.sort_by_key(|(_, c)| c.position);
+    cols
+}
+
+/// A type name split into its canonical base and optional modifier, e.g.
+/// `character varying(10)` → `("varchar", Some("10"))`.
+#[derive(Debug, PartialEq, Eq)]
+struct NormalizedType {
+    base: String,
+    modifier: Option<String>,
+}
+
+/// Whether `a` and `b` name the same type.
+///
+/// Modifiers are only significant when both sides carry one: the catalog
+/// reports `character varying` where the typechecker reports `varchar(10)`.
+fn same_type(a: &str, b: &str) -> bool {
+    let (a, b) = (normalize_type(a), normalize_type(b));
+    a.base == b.base
+        && match (&a.modifier, &b.modifier) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        }
+}
+
+/// Canonicalize a type name so catalog and typechecker spellings compare equal.
+fn normalize_type(ty: &str) -> NormalizedType {
+    let ty = ty.trim().to_lowercase();
+    if let Some(element) = ty.strip_suffix("[]") {
+        let element = normalize_type(element);
+        return NormalizedType {
+            base: format!("{}[]", element.base),
+            modifier: element.modifier,
+        };
+    }
+    let (base, modifier) = match ty.find('(') {
+        Some(open) if ty.ends_with(')') => (
+            ty[..open].trim().to_string(),
+            Some(ty[open + 1..ty.len() - 1].replace(' ', "")),
+        ),
+        _ => (ty.clone(), None),
+    };
+    let base = match base.as_str() {
+        "smallint" => "int2",
+        "integer" | "int" => "int4",
+        "bigint" => "int8",
+        "real" => "float4",
+        "double precision" | "double" => "float8",
+        "boolean" => "bool",
+        "character varying" => "varchar",
+        "character" | "bpchar" => "char",
+        "timestamp without time zone" => "timestamp",
+        "timestamp with time zone" => "timestamptz",
+        "time without time zone" => "time",
+        "decimal" => "numeric",
+        other => other,
+    }
+    .to_string();
+    NormalizedType { base, modifier }
+}
+
+/// Whether changing a column from `from` to `to` keeps every existing value
+/// representable, so consumers can keep reading it.
+fn is_widening(from: &str, to: &str) -> bool {
+    let from = normalize_type(from);
+    let to = normalize_type(to);
+    if from.base == to.base {
+        // Dropping a length or precision limit widens; so does raising it.
+        return match (&from.modifier, &to.modifier) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(a), Some(b)) => match (a.parse::<u32>(), b.parse::<u32>()) {
+                (Ok(a), Ok(b)) => a <= b,
+                _ => a == b,
+            },
+        };
+    }
+    matches!(
+        (from.base.as_str(), to.base.as_str()),
+        ("int2", "int4" | "int8" | "numeric")
+            | ("int4", "int8" | "numeric")
+            | ("int8", "numeric")
+            | ("float4", "float8")
+            | ("varchar" | "char", "text")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns(cols: &[(&str, &str, bool)]) -> BTreeMap<String, ColumnType> {
+        cols.iter()
+            .enumerate()
+            .map(|(position, (name, ty, nullable))| {
+                (
+                    name.to_string(),
+                    ColumnType {
+                        r#type: ty.to_string(),
+                        nullable: *nullable,
+                        position,
+                        comment: None,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[mz_ore::test]
+    fn unchanged_columns_have_no_changes() {
+        let old = columns(&[
+            ("id", "integer", false),
+            ("name", "character varying(10)", true),
+        ]);
+        let new = columns(&[("id", "int4", false), ("name", "varchar(10)", true)]);
+        assert_eq!(diff_columns(&old, &new), vec![]);
+    }
+
+    #[mz_ore::test]
+    fn added_nullable_column_is_compatible() {
+        let old = columns(&[("id", "int4", false)]);
+        let new = columns(&[("id", "int4", false), ("note", "text", true)]);
+        let changes = diff_columns(&old, &new);
+        assert_eq!(
+            changes,
+            vec![ColumnChange::Added {
+                column: "note".to_string(),
+                r#type: "text".to_string(),
+                nullable: true,
+            }]
+        );
+        assert!(!changes[0].is_breaking());
+    }
+
+    #[mz_ore::test]
+    fn added_not_null_column_is_breaking() {
+        let old = columns(&[("id", "int4", false)]);
+        let new = columns(&[("id", "int4", false), ("n", "int4", false)]);
+        assert!(diff_columns(&old, &new)[0].is_breaking());
+    }
+
+    #[mz_ore::test]
+    fn dropped_and_renamed_columns_are_breaking() {
+        let old = columns(&[
+            ("id", "int4", false),
+            ("total", "numeric", true),
+            ("x", "text", true),
+        ]);
+        let new = columns(&[("id", "int4", false), ("amount", "numeric", true)]);
+        let changes = diff_columns(&old, &new);
+        assert_eq!(
+            changes,
+            vec![
+                ColumnChange::Renamed {
+                    from: "total".to_string(),
+                    to: "amount".to_string(),
+                },
+                ColumnChange::Dropped {
+                    column: "x".to_string(),
+                },
+            ]
+        );
+        assert!(changes.iter().all(|c| c.is_breaking()));
+    }
+
+    #[mz_ore::test]
+    fn type_changes_are_classified_by_direction() {
+        let widened = diff_columns(
+            &columns(&[("a", "integer", true), ("b", "varchar(5)", true)]),
+            &columns(&[("a", "int8", true), ("b", "text", true)]),
+        );
+        assert_eq!(widened.len(), 2);
+        assert!(widened.iter().all(|c| !c.is_breaking()));
+
+        let narrowed = diff_columns(
+            &columns(&[
+                ("a", "bigint", true),
+                ("b", "text", true),
+                ("c", "varchar(10)", true),
+            ]),
+            &columns(&[
+                ("a", "int4", true),
+                ("b", "varchar(5)", true),
+                ("c", "varchar(5)", true),
+            ]),
+        );
+        assert_eq!(narrowed.len(), 3);
+        assert!(narrowed.iter().all(|c| c.is_breaking()));
+    }
+
+    #[mz_ore::test]
+    fn nullability_changes_are_breaking() {
+        let changes = diff_columns(
+            &columns(&[("a", "int4", false), ("b", "int4", true)]),
+            &columns(&[("a", "int4", true), ("b", "int4", false)]),
+        );
+        assert_eq!(
+            changes,
+            vec![
+                ColumnChange::NullabilityChanged {
+                    column: "a".to_string(),
+                    nullable: true,
+                },
+                ColumnChange::NullabilityChanged {
+                    column: "b".to_string(),
+                    nullable: false,
+                },
+            ]
+        );
+        assert!(changes.iter().all(|c| c.is_breaking()));
+    }
+
+    #[mz_ore::test]
+    fn same_type_ignores_spelling() {
+        assert!(same_type("timestamp with time zone", "timestamptz"));
+        assert!(same_type("integer[]", "int4[]"));
+        assert!(same_type("character varying", "varchar(10)"));
+        assert!(!same_type("numeric(10, 2)", "numeric(12,2)"));
+    }
+}
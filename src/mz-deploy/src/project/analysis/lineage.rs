@@ -0,0 +1,648 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Lineage graph export.
+//!
+//! [`LineageGraph`] is a render-ready view of a compiled project's dependency
+//! graph: one node per object, one per index, and one per external
+//! dependency, grouped by the cluster they run on. Edges point from an
+//! upstream object to its dependents, so rendered graphs read in the
+//! direction data flows.
+//!
+//! Nodes and clusters carry a `dirty` flag, supplied by the caller from a
+//! [`ChangeSet`](super::changeset::ChangeSet), so a rendered graph shows
+//! which part of the project the next deployment would touch.
+//!
+//! A [`Focus`] restricts the graph to the objects reachable from a single
+//! root within a bounded number of hops in each direction. Indexes follow
+//! the object they are defined on and never extend the traversal.
+//!
+//! The graph renders as Graphviz DOT ([`LineageGraph::to_dot`]), Mermaid
+//! ([`LineageGraph::to_mermaid`]), or JSON via `serde`.
+
+use crate::project::ir::graph::Project;
+use crate::project::ir::object_id::ObjectId;
+use crate::types::ObjectKind;
+use mz_sql_parser::ast::RawClusterName;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::{self, Write};
+
+/// The kind of a node in the lineage graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NodeKind {
+    Table,
+    View,
+    MaterializedView,
+    Source,
+    Sink,
+    Secret,
+    Connection,
+    Index,
+    /// An object referenced by the project but not defined in it.
+    External,
+}
+
+impl From<ObjectKind> for NodeKind {
+    fn from(kind: ObjectKind) -> Self {
+        match kind {
+            ObjectKind::Table => NodeKind::Table,
+            ObjectKind::View => NodeKind::View,
+            ObjectKind::MaterializedView => NodeKind::MaterializedView,
+            ObjectKind::Source => NodeKind::Source,
+            ObjectKind::Sink => NodeKind::Sink,
+            ObjectKind::Secret => NodeKind::Secret,
+            ObjectKind::Connection => NodeKind::Connection,
+        }
+    }
+}
+
+impl fmt::Display for NodeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeKind::Table => write!(f, "table"),
+            NodeKind::View => write!(f, "view"),
+            NodeKind::MaterializedView => write!(f, "materialized view"),
+            NodeKind::Source => write!(f, "source"),
+            NodeKind::Sink => write!(f, "sink"),
+            NodeKind::Secret => write!(f, "secret"),
+            NodeKind::Connection => write!(f, "connection"),
+            NodeKind::Index => write!(f, "index"),
+            NodeKind::External => write!(f, "external"),
+        }
+    }
+}
+
+/// The relationship an edge represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EdgeKind {
+    /// `to` reads from `from`.
+    Dependency,
+    /// `to` is an index defined on `from`.
+    Index,
+}
+
+/// An object, index, or external dependency.
+#[derive(Debug, Clone, Serialize)]
+pub struct Node {
+    /// Fully qualified name; indexes use `database.schema.object#index`.
+    pub id: String,
+    pub kind: NodeKind,
+    /// Cluster the node runs on, if any.
+    pub cluster: Option<String>,
+    /// Whether the next deployment would redeploy this node.
+    pub dirty: bool,
+}
+
+/// A directed edge from an upstream node to a downstream node.
+#[derive(Debug, Clone, Serialize)]
+pub struct Edge {
+    pub from: String,
+    pub to: String,
+    pub kind: EdgeKind,
+}
+
+/// A cluster hosting one or more nodes.
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterNode {
+    pub name: String,
+    /// Whether the next deployment would recreate this cluster.
+    pub dirty: bool,
+}
+
+/// Restricts a lineage graph to the neighbourhood of one object.
+#[derive(Debug, Clone)]
+pub struct Focus {
+    pub root: ObjectId,
+    /// Maximum number of dependency hops to follow upstream; `None` is unbounded.
+    pub upstream: Option<usize>,
+    /// Maximum number of dependency hops to follow downstream; `None` is unbounded.
+    pub downstream: Option<usize>,
+}
+
+/// Objects and clusters the next deployment would redeploy.
+#[derive(Debug, Clone, Default)]
+pub struct DirtySet {
+    pub objects: BTreeSet<ObjectId>,
+    pub clusters: BTreeSet<String>,
+}
+
+/// A renderable lineage graph.
+#[derive(Debug, Clone, Serialize)]
+pub struct LineageGraph {
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+    pub clusters: Vec<ClusterNode>,
+}
+
+impl LineageGraph {
+    /// Build the lineage graph for `project`.
+    ///
+    /// Returns `None` if `focus` names an object that is neither defined in
+    /// nor referenced by the project.
+    pub fn build(project: &Project, dirty: &DirtySet, focus: Option<&Focus>) -> Option<Self> {
+        let included = match focus {
+            Some(focus) => Some(neighbourhood(project, focus)?),
+            None => None,
+        };
+        let is_included = |id: &ObjectId| included.as_ref().is_none_or(|set| set.contains(id));
+
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        let mut clusters = BTreeSet::new();
+
+        for object in project.iter_objects().filter(|o| is_included(&o.id)) {
+            let typed = &object.typed_object;
+            let object_dirty = dirty.objects.contains(&object.id);
+            let cluster = typed.in_cluster();
+            clusters.extend(cluster.clone());
+            nodes.push(Node {
+                id: object.id.to_string(),
+                kind: typed.stmt.kind().into(),
+                cluster,
+                dirty: object_dirty,
+            });
+
+            for (position, index) in typed.indexes.iter().enumerate() {
+                let name = match &index.name {
+                    Some(name) => name.to_string(),
+                    None => format!("index{}", position + 1),
+                };
+                let cluster = match &index.in_cluster {
+                    Some(RawClusterName::Unresolved(name)) => Some(name.to_string()),
+                    _ => None,
+                };
+                let cluster_dirty = cluster
+                    .as_ref()
+                    .is_some_and(|c| dirty.clusters.contains(c));
+                clusters.extend(cluster.clone());
+                let id = format!("{}#{}", object.id, name);
+                edges.push(Edge {
+                    from: object.id.to_string(),
+                    to: id.clone(),
+                    kind: EdgeKind::Index,
+                });
+                nodes.push(Node {
+                    id,
+                    kind: NodeKind::Index,
+                    cluster,
+                    dirty: object_dirty || cluster_dirty,
+                });
+            }
+        }
+
+        for external in project
+            .external_dependencies
+            .iter()
+            .filter(|id| is_included(id))
+        {
+            nodes.push(Node {
+                id: external.to_string(),
+                kind: NodeKind::External,
+                cluster: None,
+                dirty: false,
+            });
+        }
+
+        for (object, dependencies) in &project.dependency_graph {
+            if !is_included(object) {
+                continue;
+            }
+            for dependency in dependencies.iter().filter(|id| is_included(id)) {
+                edges.push(Edge {
+                    from: dependency.to_string(),
+                    to: object.to_string(),
+                    kind: EdgeKind::Dependency,
+                });
+            }
+        }
+
+        let clusters = clusters
+            .into_iter()
+            .map(|name| ClusterNode {
+                dirty: dirty.clusters.contains(&name),
+                name,
+            })
+            .collect();
+
+        Some(LineageGraph {
+            nodes,
+            edges,
+            clusters,
+        })
+    }
+
+    /// Render the graph in Graphviz DOT format.
+    ///
+    /// Clusters become `subgraph cluster_*` boxes; dirty nodes and clusters
+    /// are drawn in red.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "digraph lineage {{").unwrap();
+        writeln!(out, "  rankdir=LR;").unwrap();
+        writeln!(
+            out,
+            "  node [shape=box, style=\"rounded,filled\", fillcolor=white, fontname=\"Helvetica\"];"
+        )
+        .unwrap();
+
+        let by_cluster = self.nodes_by_cluster();
+        for (position, cluster) in self.clusters.iter().enumerate() {
+            writeln!(out, "  subgraph cluster_{} {{", position).unwrap();
+            writeln!(out, "    label={};", dot_quote(&cluster.name)).unwrap();
+            if cluster.dirty {
+                writeln!(out, "    style=dashed; color=red; fontcolor=red;").unwrap();
+            } else {
+                writeln!(out, "    style=dashed; color=gray;").unwrap();
+            }
+            for node in by_cluster.get(cluster.name.as_str()).into_iter().flatten() {
+                writeln!(out, "    {}", dot_node(node)).unwrap();
+            }
+            writeln!(out, "  }}").unwrap();
+        }
+        for node in by_cluster.get("").into_iter().flatten() {
+            writeln!(out, "  {}", dot_node(node)).unwrap();
+        }
+
+        for edge in &self.edges {
+            let style = match edge.kind {
+                EdgeKind::Dependency => "",
+                EdgeKind::Index => " [style=dotted, arrowhead=none]",
+            };
+            writeln!(
+                out,
+                "  {} -> {}{};",
+                dot_quote(&edge.from),
+                dot_quote(&edge.to),
+                style
+            )
+            .unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+        out
+    }
+
+    /// Render the graph as a Mermaid flowchart.
+    ///
+    /// Node identifiers are positional (`n0`, `n1`, ...) because Mermaid
+    /// does not accept dots in identifiers; the fully qualified name is
+    /// the node label.
+    pub fn to_mermaid(&self) -> String {
+        let ids: BTreeMap<&str, String> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(position, node)| (node.id.as_str(), format!("n{}", position)))
+            .collect();
+
+        let mut out = String::new();
+        writeln!(out, "flowchart LR").unwrap();
+
+        let by_cluster = self.nodes_by_cluster();
+        for (position, cluster) in self.clusters.iter().enumerate() {
+            writeln!(
+                out,
+                "  subgraph c{}[\"{}\"]",
+                position,
+                mermaid_escape(&cluster.name)
+            )
+            .unwrap();
+            for node in by_cluster.get(cluster.name.as_str()).into_iter().flatten() {
+                writeln!(out, "    {}", mermaid_node(&ids[node.id.as_str()], node)).unwrap();
+            }
+            writeln!(out, "  end").unwrap();
+        }
+        for node in by_cluster.get("").into_iter().flatten() {
+            writeln!(out, "  {}", mermaid_node(&ids[node.id.as_str()], node)).unwrap();
+        }
+
+        for edge in &self.edges {
+            let arrow = match edge.kind {
+                EdgeKind::Dependency => "-->",
+                EdgeKind::Index => "-.-",
+            };
+            writeln!(
+                out,
+                "  {} {} {}",
+                ids[edge.from.as_str()],
+                arrow,
+                ids[edge.to.as_str()]
+            )
+            .unwrap();
+        }
+
+        let dirty_nodes: Vec<&str> = self
+            .nodes
+            .iter()
+            .filter(|node| node.dirty)
+            .map(|node| ids[node.id.as_str()].as_str())
+            .collect();
+        if !dirty_nodes.is_empty() {
+            writeln!(out, "  classDef dirty fill:#f8d7da,stroke:#c0392b").unwrap();
+            writeln!(out, "  class {} dirty", dirty_nodes.join(",")).unwrap();
+        }
+        for (position, cluster) in self.clusters.iter().enumerate() {
+            if cluster.dirty {
+                writeln!(out, "  style c{} stroke:#c0392b", position).unwrap();
+            }
+        }
+        out
+    }
+
+    /// Group nodes by cluster name; nodes without a cluster are keyed by `""`.
+    fn nodes_by_cluster(&self) -> BTreeMap<&str, Vec<&Node>> {
+        let mut by_cluster: BTreeMap<&str, Vec<&Node>> = BTreeMap::new();
+        for node in &self.nodes {
+            by_cluster
+                .entry(node.cluster.as_deref().unwrap_or(""))
+                .or_default()
+                .push(node);
+        }
+        by_cluster
+    }
+}
+
+/// Collect the objects within `focus` of its root, following dependency
+/// edges upstream and reverse-dependency edges downstream.
+///
+/// Returns `None` if the root is not part of the project's dependency graph.
+fn neighbourhood(project: &Project, focus: &Focus) -> Option<BTreeSet<ObjectId>> {
+    if !project.dependency_graph.contains_key(&focus.root)
+        && !project.external_dependencies.contains(&focus.root)
+    {
+        return None;
+    }
+
+    let mut included = BTreeSet::from([focus.root.clone()]);
+    let reverse = project.build_reverse_dependency_graph();
+    for (graph, limit) in [
+        (&project.dependency_graph, focus.upstream),
+        (&reverse, focus.downstream),
+    ] {
+        let mut visited = BTreeSet::from([focus.root.clone()]);
+        let mut queue = VecDeque::from([(focus.root.clone(), 0)]);
+        while let Some((id, depth)) = queue.pop_front() {
+            if limit.is_some_and(|limit| depth >= limit) {
+                continue;
+            }
+            for next in graph.get(&id).into_iter().flatten() {
+                if visited.insert(next.clone()) {
+                    queue.push_back((next.clone(), depth + 1));
+                }
+            }
+        }
+        included.extend(visited);
+    }
+    Some(included)
+}
+
+fn dot_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn dot_node(node: &Node) -> String {
+    let shape = match node.kind {
+        NodeKind::Table | NodeKind::Source => "cylinder",
+        NodeKind::MaterializedView => "box3d",
+        NodeKind::Sink => "cds",
+        NodeKind::Index => "hexagon",
+        NodeKind::Secret | NodeKind::Connection => "note",
+        NodeKind::View | NodeKind::External => "box",
+    };
+    let mut attrs = vec![
+        format!("label={}", dot_quote(&format!("{}\n{}", node.id, node.kind))),
+        format!("shape={}", shape),
+    ];
+    if node.kind == NodeKind::External {
+        attrs.push("style=\"rounded,dashed\"".to_string());
+    }
+    if node.dirty {
+        attrs.push("fillcolor=\"#f8d7da\", color=\"#c0392b\"".to_string());
+    }
+    format!("{} [{}];", dot_quote(&node.id), attrs.join(", "))
+}
+
+fn mermaid_escape(s: &str) -> String {
+    s.replace('"', "#quot;")
+}
+
+fn mermaid_node(id: &str, node: &Node) -> String {
+    format!(
+        "{}[\"{}<br/><i>{}</i>\"]",
+        id,
+        mermaid_escape(&node.id),
+        node.kind
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::ast::Statement;
+    use crate::project::ir::compiled;
+    use crate::project::ir::graph::{Database, DatabaseObject, Schema, SchemaType};
+
+    fn id(name: &str) -> ObjectId {
+        ObjectId::new("db".to_string(), "public".to_string(), name.to_string())
+    }
+
+    fn parse(sql: &str) -> compiled::DatabaseObject {
+        let mut parsed = mz_sql_parser::parser::parse_statements(sql).unwrap();
+        let stmt = match parsed.remove(0).ast {
+            mz_sql_parser::ast::Statement::CreateView(s) => Statement::CreateView(s),
+            mz_sql_parser::ast::Statement::CreateMaterializedView(s) => {
+                Statement::CreateMaterializedView(s)
+            }
+            mz_sql_parser::ast::Statement::CreateTable(s) => Statement::CreateTable(s),
+            other => panic!("unexpected statement: {other}"),
+        };
+        let indexes = parsed
+            .into_iter()
+            .map(|p| match p.ast {
+                mz_sql_parser::ast::Statement::CreateIndex(s) => s,
+                other => panic!("unexpected statement: {other}"),
+            })
+            .collect();
+        compiled::DatabaseObject {
+            path: std::path::PathBuf::from("test.sql"),
+            stmt,
+            indexes,
+            grants: vec![],
+            comments: vec![],
+            tests: vec![],
+        }
+    }
+
+    /// A chain `t -> v -> mv -> leaf`, with an index on `mv` and an external
+    /// `db.ext.src` feeding `t`'s sibling `other`.
+    fn project() -> Project {
+        let objects = [
+            ("t", "CREATE TABLE t (a int)", vec![]),
+            ("v", "CREATE VIEW v AS SELECT * FROM t", vec![id("t")]),
+            (
+                "mv",
+                "CREATE MATERIALIZED VIEW mv IN CLUSTER compute AS SELECT * FROM v;
+                 CREATE INDEX mv_idx IN CLUSTER serving ON mv (a)",
+                vec![id("v")],
+            ),
+            ("leaf", "CREATE VIEW leaf AS SELECT * FROM mv", vec![id("mv")]),
+            (
+                "other",
+                "CREATE VIEW other AS SELECT * FROM db.ext.src",
+                vec![ObjectId::new(
+                    "db".to_string(),
+                    "ext".to_string(),
+                    "src".to_string(),
+                )],
+            ),
+        ];
+
+        let mut dependency_graph = BTreeMap::new();
+        let mut db_objects = vec![];
+        for (name, sql, deps) in objects {
+            let deps: BTreeSet<_> = deps.into_iter().collect();
+            dependency_graph.insert(id(name), deps.clone());
+            db_objects.push(DatabaseObject {
+                id: id(name),
+                typed_object: parse(sql),
+                dependencies: deps,
+            });
+        }
+
+        Project {
+            databases: vec![Database {
+                name: "db".to_string(),
+                schemas: vec![Schema {
+                    name: "public".to_string(),
+                    objects: db_objects,
+                    mod_statements: None,
+                    schema_type: SchemaType::Compute,
+                }],
+                mod_statements: None,
+            }],
+            dependency_graph,
+            external_dependencies: BTreeSet::from([ObjectId::new(
+                "db".to_string(),
+                "ext".to_string(),
+                "src".to_string(),
+            )]),
+            cluster_dependencies: BTreeSet::new(),
+            tests: vec![],
+            replacement_schemas: BTreeSet::new(),
+            compile_dirty: BTreeSet::new(),
+        }
+    }
+
+    fn node_ids(graph: &LineageGraph) -> BTreeSet<&str> {
+        graph.nodes.iter().map(|n| n.id.as_str()).collect()
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn build_includes_objects_indexes_and_externals() {
+        let graph = LineageGraph::build(&project(), &DirtySet::default(), None).unwrap();
+
+        assert_eq!(
+            node_ids(&graph),
+            BTreeSet::from([
+                "db.public.t",
+                "db.public.v",
+                "db.public.mv",
+                "db.public.mv#mv_idx",
+                "db.public.leaf",
+                "db.public.other",
+                "db.ext.src",
+            ])
+        );
+        let index = graph
+            .nodes
+            .iter()
+            .find(|n| n.id == "db.public.mv#mv_idx")
+            .unwrap();
+        assert_eq!(index.kind, NodeKind::Index);
+        assert_eq!(index.cluster.as_deref(), Some("serving"));
+        assert!(graph.edges.iter().any(|e| e.from == "db.ext.src"
+            && e.to == "db.public.other"
+            && e.kind == EdgeKind::Dependency));
+        let clusters: Vec<_> = graph.clusters.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(clusters, vec!["compute", "serving"]);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn focus_limits_hops_in_each_direction() {
+        let focus = Focus {
+            root: id("mv"),
+            upstream: Some(1),
+            downstream: None,
+        };
+        let graph = LineageGraph::build(&project(), &DirtySet::default(), Some(&focus)).unwrap();
+
+        assert_eq!(
+            node_ids(&graph),
+            BTreeSet::from([
+                "db.public.v",
+                "db.public.mv",
+                "db.public.mv#mv_idx",
+                "db.public.leaf",
+            ])
+        );
+        assert!(
+            !graph.edges.iter().any(|e| e.from == "db.public.t"),
+            "edges to excluded nodes must be dropped"
+        );
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn focus_on_unknown_object_is_none() {
+        let focus = Focus {
+            root: id("missing"),
+            upstream: None,
+            downstream: None,
+        };
+        assert!(LineageGraph::build(&project(), &DirtySet::default(), Some(&focus)).is_none());
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn dirty_objects_and_clusters_are_highlighted() {
+        let dirty = DirtySet {
+            objects: BTreeSet::from([id("leaf")]),
+            clusters: BTreeSet::from(["serving".to_string()]),
+        };
+        let graph = LineageGraph::build(&project(), &dirty, None).unwrap();
+
+        let dirty_nodes: BTreeSet<_> = graph
+            .nodes
+            .iter()
+            .filter(|n| n.dirty)
+            .map(|n| n.id.as_str())
+            .collect();
+        assert_eq!(
+            dirty_nodes,
+            BTreeSet::from(["db.public.leaf", "db.public.mv#mv_idx"])
+        );
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph lineage {"));
+        assert!(dot.contains(
+            "subgraph cluster_1 {\n    label=\"serving\";\n    style=dashed; color=red;"
+        ));
+        assert!(dot.contains("\"db.public.mv\" -> \"db.public.leaf\";"));
+
+        let mermaid = graph.to_mermaid();
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("subgraph c1[\"serving\"]"));
+        assert!(mermaid.contains("style c1 stroke:#c0392b"));
+        assert!(mermaid.contains("classDef dirty"));
+    }
+}
@@ -0,0 +1,421 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Configurable lint rules over the compiled project.
+//!
+//! Unlike the structural checks in
+//! [`object_validation`](crate::project::compiler::object_validation), lints
+//! flag SQL that is valid but usually a mistake. Every rule is off unless
+//! the `[lint]` table in `project.toml` gives it a severity:
+//!
+//! ```toml
+//! [lint]
+//! select-star-in-mv = "error"
+//! missing-comment = "warn"
+//! ```
+//!
+//! A file opts out of specific rules with a comment anywhere in it:
+//!
+//! ```sql
+//! -- mz-deploy:allow(cross-join, missing-comment)
+//! ```
+//!
+//! ## Rules
+//!
+//! Each rule is a submodule that implements [`LintRule`]:
+//!
+//! - `cross_join::CrossJoin` — `CROSS JOIN`, or a comma-separated `FROM`
+//!   list with no `WHERE` clause
+//! - `missing_comment::MissingComment` — objects without `COMMENT ON`
+//! - `mv_missing_index::MvMissingIndex` — materialized views with no index
+//!   that no other project object reads, so they are only read by peeks
+//! - `mz_now_outside_where::MzNowOutsideWhere` — `mz_now()` outside `WHERE`
+//!   or `HAVING` in a maintained view
+//! - `select_star_in_mv::SelectStarInMv` — `SELECT *` in a materialized view
+//! - `sink_without_key::SinkWithoutKey` — Kafka and Iceberg sinks without
+//!   a `KEY`
+
+mod cross_join;
+mod missing_comment;
+mod mv_missing_index;
+mod mz_now_outside_where;
+mod select_star_in_mv;
+mod sink_without_key;
+
+use crate::config::LintLevel;
+use crate::diagnostics::{PositionalDiagnostic, Severity};
+use crate::fs::FileSystem;
+use crate::project::ast::Statement;
+use crate::project::ir::graph::{DatabaseObject, Project};
+use crate::project::ir::object_id::ObjectId;
+use mz_sql_parser::ast::{Query, Raw};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Marker that introduces a suppression comment.
+const ALLOW_MARKER: &str = "mz-deploy:allow(";
+
+/// A lint rule checked against every object in the project.
+pub(crate) trait LintRule: Send + Sync {
+    /// The rule's name in `project.toml` and suppression comments
+    /// (e.g. `"select-star-in-mv"`).
+    fn name(&self) -> &'static str;
+    /// Report every violation of this rule in `object`.
+    fn check(&self, project: &Project, object: &DatabaseObject) -> Vec<Violation>;
+}
+
+/// A single violation reported by a [`LintRule`].
+pub(crate) struct Violation {
+    pub message: String,
+    /// Text to point at in the object's file, matched case-insensitively.
+    /// Falls back to the object's name when `None` or not found.
+    pub anchor: Option<&'static str>,
+    pub help: Option<String>,
+}
+
+/// All rules, ordered by name.
+fn rules() -> Vec<Box<dyn LintRule>> {
+    vec![
+        Box::new(cross_join::CrossJoin),
+        Box::new(missing_comment::MissingComment),
+        Box::new(mv_missing_index::MvMissingIndex),
+        Box::new(mz_now_outside_where::MzNowOutsideWhere),
+        Box::new(select_star_in_mv::SelectStarInMv),
+        Box::new(sink_without_key::SinkWithoutKey),
+    ]
+}
+
+/// Names of every known lint rule.
+pub(crate) fn rule_names() -> Vec<&'static str> {
+    rules().iter().map(|rule| rule.name()).collect()
+}
+
+/// A located lint violation, ready for rendering.
+#[derive(Debug, Clone, Serialize)]
+pub struct LintFinding {
+    pub rule: &'static str,
+    pub level: LintLevel,
+    pub object: ObjectId,
+    pub file: PathBuf,
+    /// 1-based line of the reported location.
+    pub line: usize,
+    /// 1-based column (in characters) of the reported location.
+    pub column: usize,
+    pub message: String,
+    pub help: Option<String>,
+    #[serde(skip)]
+    pub byte_range: Range<usize>,
+}
+
+impl LintFinding {
+    /// Whether this finding fails the build.
+    pub fn is_error(&self) -> bool {
+        self.level == LintLevel::Error
+    }
+
+    /// Convert to a [`PositionalDiagnostic`] over the file's `source`.
+    pub(crate) fn to_positional(&self, source: String) -> PositionalDiagnostic {
+        PositionalDiagnostic {
+            severity: if self.is_error() {
+                Severity::Error
+            } else {
+                Severity::Warning
+            },
+            file: self.file.clone(),
+            source,
+            byte_range: self.byte_range.clone(),
+            message: format!("{} [{}]", self.message, self.rule),
+            footers: self.help.iter().cloned().collect(),
+            suggestions: Vec::new(),
+        }
+    }
+}
+
+/// Run every rule enabled in `levels` over the project.
+///
+/// Each object's file, resolved against the project `root`, is read through
+/// `fs` for suppression comments and to locate findings. Findings are
+/// ordered by file, then position.
+pub fn run(
+    project: &Project,
+    root: &Path,
+    levels: &BTreeMap<String, LintLevel>,
+    fs: &FileSystem,
+) -> Vec<LintFinding> {
+    let enabled: Vec<(Box<dyn LintRule>, LintLevel)> = rules()
+        .into_iter()
+        .filter_map(|rule| match levels.get(rule.name()) {
+            Some(level) if *level != LintLevel::Off => Some((rule, *level)),
+            _ => None,
+        })
+        .collect();
+    if enabled.is_empty() {
+        return Vec::new();
+    }
+
+    let mut findings = Vec::new();
+    for object in project.iter_objects() {
+        let file = root.join(&object.typed_object.path);
+        let source = fs.read_to_string(&file).unwrap_or_default();
+        let allowed = suppressions(&source);
+        for (rule, level) in &enabled {
+            if allowed.contains(rule.name()) {
+                continue;
+            }
+            for violation in rule.check(project, object) {
+                let byte_range = violation
+                    .anchor
+                    .and_then(|anchor| locate(&source, anchor))
+                    .or_else(|| crate::diagnostics::find_identifier(&source, object.id.object()))
+                    .unwrap_or(0..0);
+                let (line, column) = line_column(&source, byte_range.start);
+                findings.push(LintFinding {
+                    rule: rule.name(),
+                    level: *level,
+                    object: object.id.clone(),
+                    file: file.clone(),
+                    line,
+                    column,
+                    message: violation.message,
+                    help: violation.help,
+                    byte_range,
+                });
+            }
+        }
+    }
+    findings.sort_by(|a, b| {
+        (&a.file, a.byte_range.start, a.rule).cmp(&(&b.file, b.byte_range.start, b.rule))
+    });
+    findings
+}
+
+/// The query of a view or materialized view.
+fn query(stmt: &Statement) -> Option<&Query<Raw>> {
+    match stmt {
+        Statement::CreateView(view) => Some(&view.definition.query),
+        Statement::CreateMaterializedView(mv) => Some(&mv.query),
+        _ => None,
+    }
+}
+
+/// Rules named in `-- mz-deploy:allow(rule, ...)` comments in `sql`.
+fn suppressions(sql: &str) -> BTreeSet<String> {
+    let mut allowed = BTreeSet::new();
+    for line in sql.lines() {
+        let Some((_, comment)) = line.split_once("--") else {
+            continue;
+        };
+        let Some(start) = comment.find(ALLOW_MARKER) else {
+            continue;
+        };
+        let rest = &comment[start + ALLOW_MARKER.len()..];
+        let Some(end) = rest.find(')') else {
+            continue;
+        };
+        allowed.extend(
+            rest[..end]
+                .split(',')
+                .map(|rule| rule.trim().to_string())
+                .filter(|rule| !rule.is_empty()),
+        );
+    }
+    allowed
+}
+
+/// Find the first case-insensitive occurrence of `anchor` in `source` that
+/// is not inside a `--` comment.
+///
+/// Anchors that start or end with an identifier character only match on
+/// word boundaries, so `SINK` does not match inside `sinks`.
+fn locate(source: &str, anchor: &str) -> Option<Range<usize>> {
+    let bytes = source.as_bytes();
+    let needle = anchor.as_bytes();
+    if needle.is_empty() || needle.len() > bytes.len() {
+        return None;
+    }
+    let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    let mut line_start = 0;
+    for start in 0..=(bytes.len() - needle.len()) {
+        if start > 0 && bytes[start - 1] == b'\n' {
+            line_start = start;
+        }
+        let end = start + needle.len();
+        if !bytes[start..end].eq_ignore_ascii_case(needle) {
+            continue;
+        }
+        if is_ident(needle[0]) && start > 0 && is_ident(bytes[start - 1]) {
+            continue;
+        }
+        if is_ident(needle[needle.len() - 1]) && end < bytes.len() && is_ident(bytes[end]) {
+            continue;
+        }
+        if source[line_start..start].contains("--") {
+            continue;
+        }
+        return Some(start..end);
+    }
+    None
+}
+
+/// 1-based line and character column of byte `offset` in `source`.
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = source.get(..offset).unwrap_or(source);
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .map_or(0, |last| last.chars().count())
+        + 1;
+    (line, column)
+}
+
+/// Project builders shared by the tests of the lint framework and of each
+/// rule.
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::project::ir::compiled;
+    use crate::project::ir::graph::{Database, Schema, SchemaType};
+
+    fn id(name: &str) -> ObjectId {
+        ObjectId::new("db".to_string(), "public".to_string(), name.to_string())
+    }
+
+    fn parse(name: &str, sql: &str) -> compiled::DatabaseObject {
+        let mut parsed = mz_sql_parser::parser::parse_statements(sql).unwrap();
+        let stmt = match parsed.remove(0).ast {
+            mz_sql_parser::ast::Statement::CreateView(s) => Statement::CreateView(s),
+            mz_sql_parser::ast::Statement::CreateMaterializedView(s) => {
+                Statement::CreateMaterializedView(s)
+            }
+            mz_sql_parser::ast::Statement::CreateTable(s) => Statement::CreateTable(s),
+            mz_sql_parser::ast::Statement::CreateSink(s) => Statement::CreateSink(s),
+            other => panic!("unexpected statement: {other}"),
+        };
+        let mut indexes = vec![];
+        let mut comments = vec![];
+        for p in parsed {
+            match p.ast {
+                mz_sql_parser::ast::Statement::CreateIndex(s) => indexes.push(s),
+                mz_sql_parser::ast::Statement::Comment(s) => comments.push(s),
+                other => panic!("unexpected statement: {other}"),
+            }
+        }
+        compiled::DatabaseObject {
+            path: PathBuf::from(format!("{name}.sql")),
+            stmt,
+            indexes,
+            grants: vec![],
+            comments,
+            tests: vec![],
+        }
+    }
+
+    /// Build a single-schema project from `(name, sql, dependencies)` and an
+    /// overlay holding each object's source.
+    pub(super) fn project(objects: &[(&str, &str, &[&str])]) -> (Project, FileSystem) {
+        let mut dependency_graph = BTreeMap::new();
+        let mut db_objects = vec![];
+        let mut overlay = BTreeMap::new();
+        for (name, sql, deps) in objects {
+            let deps: BTreeSet<_> = deps.iter().map(|d| id(d)).collect();
+            dependency_graph.insert(id(name), deps.clone());
+            overlay.insert(PathBuf::from(format!("/p/{name}.sql")), sql.to_string());
+            db_objects.push(DatabaseObject {
+                id: id(name),
+                typed_object: parse(name, sql),
+                dependencies: deps,
+            });
+        }
+        let project = Project {
+            databases: vec![Database {
+                name: "db".to_string(),
+                schemas: vec![Schema {
+                    name: "public".to_string(),
+                    objects: db_objects,
+                    mod_statements: None,
+                    schema_type: SchemaType::Compute,
+                }],
+                mod_statements: None,
+            }],
+            dependency_graph,
+            external_dependencies: BTreeSet::new(),
+            cluster_dependencies: BTreeSet::new(),
+            tests: vec![],
+            replacement_schemas: BTreeSet::new(),
+            compile_dirty: BTreeSet::new(),
+        };
+        (project, FileSystem::with_overlay(overlay))
+    }
+
+    /// Run only `rule`, at `warn`, over the project built from `objects`.
+    pub(super) fn lint(objects: &[(&str, &str, &[&str])], rule: &str) -> Vec<LintFinding> {
+        let (project, fs) = project(objects);
+        let levels = BTreeMap::from([(rule.to_string(), LintLevel::Warn)]);
+        run(&project, Path::new("/p"), &levels, &fs)
+    }
+
+    /// The names of the objects that `rule` flags, in report order.
+    pub(super) fn flagged(objects: &[(&str, &str, &[&str])], rule: &str) -> Vec<String> {
+        lint(objects, rule)
+            .iter()
+            .map(|f| f.object.object().to_string())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::testing::project;
+    use super::*;
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn rules_are_off_by_default() {
+        let (project, fs) =
+            project(&[("mv", "CREATE MATERIALIZED VIEW mv AS SELECT * FROM t", &[])]);
+        assert!(run(&project, Path::new("/p"), &BTreeMap::new(), &fs).is_empty());
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn allow_comment_suppresses_named_rules() {
+        let sql = "-- mz-deploy:allow(missing-comment, select-star-in-mv)\n\
+                   CREATE MATERIALIZED VIEW mv AS SELECT * FROM t";
+        let (project, fs) = project(&[("mv", sql, &[])]);
+        let levels = BTreeMap::from([
+            ("select-star-in-mv".to_string(), LintLevel::Error),
+            ("missing-comment".to_string(), LintLevel::Error),
+            ("mv-missing-index".to_string(), LintLevel::Warn),
+        ]);
+        let findings = run(&project, Path::new("/p"), &levels, &fs);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule, "mv-missing-index");
+        assert!(!findings[0].is_error());
+    }
+
+    #[mz_ore::test]
+    fn suppressions_parse_rule_lists() {
+        let sql = "-- mz-deploy:allow(cross-join)\nSELECT 1 -- mz-deploy:allow( a , b )\n";
+        assert_eq!(
+            suppressions(sql),
+            BTreeSet::from(["a".to_string(), "b".to_string(), "cross-join".to_string()])
+        );
+        assert!(suppressions("SELECT 'mz-deploy:allow(x)'").is_empty());
+    }
+
+    #[mz_ore::test]
+    fn locate_skips_comments_and_partial_words() {
+        let source = "-- a sink\nCREATE SINK sinks";
+        assert_eq!(locate(source, "SINK"), Some(17..21));
+        assert_eq!(locate(source, "missing"), None);
+    }
+}
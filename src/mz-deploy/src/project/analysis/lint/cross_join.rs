@@ -0,0 +1,134 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! `cross-join`: `CROSS JOIN`, or a comma-separated `FROM` list with no
+//! `WHERE` clause.
+//!
+//! Both produce the Cartesian product of their inputs, which Materialize
+//! must maintain in full. Comma joins whose extra items are lateral
+//! (`unnest(...)`, `LATERAL (...)`) are not flagged.
+
+use super::{LintRule, Violation};
+use crate::project::ir::graph::{DatabaseObject, Project};
+use mz_sql_parser::ast::visit::{self, Visit};
+use mz_sql_parser::ast::{JoinOperator, Raw, Select, TableFactor};
+
+pub(super) struct CrossJoin;
+
+impl LintRule for CrossJoin {
+    fn name(&self) -> &'static str {
+        "cross-join"
+    }
+
+    fn check(&self, _project: &Project, object: &DatabaseObject) -> Vec<Violation> {
+        let Some(query) = super::query(&object.typed_object.stmt) else {
+            return Vec::new();
+        };
+        let mut visitor = CrossJoinVisitor::default();
+        visitor.visit_query(query);
+
+        let mut violations = Vec::new();
+        for _ in 0..visitor.explicit {
+            violations.push(Violation {
+                message: format!("{} uses a CROSS JOIN", object.id),
+                anchor: Some("CROSS"),
+                help: Some(
+                    "join on a condition, or suppress if the product is intended".to_string(),
+                ),
+            });
+        }
+        for _ in 0..visitor.comma {
+            violations.push(Violation {
+                message: format!(
+                    "{} lists several relations in FROM without a WHERE clause",
+                    object.id
+                ),
+                anchor: Some("FROM"),
+                help: Some("add a WHERE clause or an explicit JOIN ... ON".to_string()),
+            });
+        }
+        violations
+    }
+}
+
+#[derive(Default)]
+struct CrossJoinVisitor {
+    explicit: usize,
+    comma: usize,
+}
+
+impl<'ast> Visit<'ast, Raw> for CrossJoinVisitor {
+    fn visit_select(&mut self, node: &'ast Select<Raw>) {
+        let plain_tables = node
+            .from
+            .iter()
+            .all(|twj| matches!(twj.relation, TableFactor::Table { .. }));
+        if node.from.len() > 1 && plain_tables && node.selection.is_none() {
+            self.comma += 1;
+        }
+        visit::visit_select(self, node)
+    }
+
+    fn visit_join_operator(&mut self, node: &'ast JoinOperator<Raw>) {
+        if matches!(node, JoinOperator::CrossJoin) {
+            self.explicit += 1;
+        }
+        visit::visit_join_operator(self, node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testing::flagged;
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn flags_cross_and_comma_joins() {
+        let objects = flagged(
+            &[
+                ("a", "CREATE VIEW a AS SELECT * FROM t CROSS JOIN u", &[]),
+                ("b", "CREATE VIEW b AS SELECT * FROM t, u", &[]),
+                (
+                    "c",
+                    "CREATE VIEW c AS SELECT * FROM (SELECT * FROM t CROSS JOIN u)",
+                    &[],
+                ),
+            ],
+            "cross-join",
+        );
+        assert_eq!(objects, vec!["a", "b", "c"]);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn skips_filtered_and_lateral_joins() {
+        let objects = flagged(
+            &[
+                (
+                    "joined",
+                    "CREATE VIEW joined AS SELECT * FROM t JOIN u ON t.x = u.x",
+                    &[],
+                ),
+                (
+                    "filtered",
+                    "CREATE VIEW filtered AS SELECT * FROM t, u WHERE t.x = u.x",
+                    &[],
+                ),
+                (
+                    "unnested",
+                    "CREATE VIEW unnested AS SELECT * FROM t, unnest(t.xs) AS x",
+                    &[],
+                ),
+                ("single", "CREATE VIEW single AS SELECT * FROM t", &[]),
+            ],
+            "cross-join",
+        );
+        assert!(objects.is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! `missing-comment`: an object without `COMMENT ON` for the object itself.
+//!
+//! Column comments alone don't count; the object needs its own description.
+
+use super::{LintRule, Violation};
+use crate::project::ast::Statement;
+use crate::project::ir::graph::{DatabaseObject, Project};
+use mz_sql_parser::ast::CommentObjectType;
+
+pub(super) struct MissingComment;
+
+impl LintRule for MissingComment {
+    fn name(&self) -> &'static str {
+        "missing-comment"
+    }
+
+    fn check(&self, _project: &Project, object: &DatabaseObject) -> Vec<Violation> {
+        let documented = object
+            .typed_object
+            .comments
+            .iter()
+            .any(|c| !matches!(c.object, CommentObjectType::Column { .. }));
+        if documented {
+            return Vec::new();
+        }
+        let kind = match &object.typed_object.stmt {
+            Statement::CreateTable(_) | Statement::CreateTableFromSource(_) => "TABLE",
+            Statement::CreateView(_) => "VIEW",
+            Statement::CreateMaterializedView(_) => "MATERIALIZED VIEW",
+            Statement::CreateSource(_) => "SOURCE",
+            Statement::CreateSink(_) => "SINK",
+            Statement::CreateSecret(_) => "SECRET",
+            Statement::CreateConnection(_) => "CONNECTION",
+        };
+        vec![Violation {
+            message: format!("{} has no comment", object.id),
+            anchor: None,
+            help: Some(format!(
+                "add `COMMENT ON {} {} IS '...';` to this file",
+                kind,
+                object.id.object()
+            )),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testing::{flagged, lint};
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn flags_objects_without_an_object_comment() {
+        let objects = flagged(
+            &[
+                ("bare", "CREATE TABLE bare (a int)", &[]),
+                (
+                    "columns_only",
+                    "CREATE TABLE columns_only (a int);
+                     COMMENT ON COLUMN columns_only.a IS 'doc'",
+                    &[],
+                ),
+            ],
+            "missing-comment",
+        );
+        assert_eq!(objects, vec!["bare", "columns_only"]);
+
+        let findings = lint(
+            &[("v", "CREATE MATERIALIZED VIEW v AS SELECT 1 AS a", &[])],
+            "missing-comment",
+        );
+        assert_eq!(
+            findings[0].help.as_deref(),
+            Some("add `COMMENT ON MATERIALIZED VIEW v IS '...';` to this file")
+        );
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn skips_documented_objects() {
+        let objects = flagged(
+            &[
+                (
+                    "documented",
+                    "CREATE TABLE documented (a int);
+                     COMMENT ON TABLE documented IS 'doc'",
+                    &[],
+                ),
+                (
+                    "documented_view",
+                    "CREATE VIEW documented_view AS SELECT 1 AS a;
+                     COMMENT ON VIEW documented_view IS 'doc'",
+                    &[],
+                ),
+            ],
+            "missing-comment",
+        );
+        assert!(objects.is_empty());
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! `mv-missing-index`: a materialized view with no index that no other
+//! project object reads.
+//!
+//! Such a view is only read by ad hoc queries, which are served from storage
+//! rather than from memory unless an index exists.
+
+use super::{LintRule, Violation};
+use crate::project::ast::Statement;
+use crate::project::ir::graph::{DatabaseObject, Project};
+
+pub(super) struct MvMissingIndex;
+
+impl LintRule for MvMissingIndex {
+    fn name(&self) -> &'static str {
+        "mv-missing-index"
+    }
+
+    fn check(&self, project: &Project, object: &DatabaseObject) -> Vec<Violation> {
+        if !matches!(
+            object.typed_object.stmt,
+            Statement::CreateMaterializedView(_)
+        ) || !object.typed_object.indexes.is_empty()
+        {
+            return Vec::new();
+        }
+        let has_dependents = project
+            .dependency_graph
+            .values()
+            .any(|deps| deps.contains(&object.id));
+        if has_dependents {
+            return Vec::new();
+        }
+        vec![Violation {
+            message: format!(
+                "materialized view {} has no index and is not read by any project object",
+                object.id
+            ),
+            anchor: None,
+            help: Some(
+                "queries against it read from storage; add an index in a serving cluster"
+                    .to_string(),
+            ),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testing::flagged;
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn flags_unindexed_unread_materialized_views() {
+        let objects = flagged(
+            &[(
+                "peeked",
+                "CREATE MATERIALIZED VIEW peeked AS SELECT 1 AS a",
+                &[],
+            )],
+            "mv-missing-index",
+        );
+        assert_eq!(objects, vec!["peeked"]);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn skips_indexed_read_and_plain_views() {
+        let objects = flagged(
+            &[
+                (
+                    "indexed",
+                    "CREATE MATERIALIZED VIEW indexed AS SELECT 1 AS a;
+                     CREATE INDEX indexed_idx ON indexed (a)",
+                    &[],
+                ),
+                (
+                    "upstream",
+                    "CREATE MATERIALIZED VIEW upstream AS SELECT 1 AS a",
+                    &[],
+                ),
+                (
+                    "reader",
+                    "CREATE VIEW reader AS SELECT a FROM upstream",
+                    &["upstream"],
+                ),
+            ],
+            "mv-missing-index",
+        );
+        assert!(objects.is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! `mz-now-outside-where`: `mz_now()` outside `WHERE` or `HAVING` in a
+//! maintained view.
+//!
+//! Materialize can only maintain `mz_now()` as a temporal filter. Anywhere
+//! else in a materialized or indexed view it fails to plan, or forces the
+//! view to be recomputed. Unindexed views, which are only evaluated by ad
+//! hoc queries, are not flagged.
+
+use super::{LintRule, Violation};
+use crate::project::ast::Statement;
+use crate::project::ir::graph::{DatabaseObject, Project};
+use mz_sql_parser::ast::visit::{self, Visit};
+use mz_sql_parser::ast::{Expr, Raw, Select};
+
+pub(super) struct MzNowOutsideWhere;
+
+impl LintRule for MzNowOutsideWhere {
+    fn name(&self) -> &'static str {
+        "mz-now-outside-where"
+    }
+
+    fn check(&self, _project: &Project, object: &DatabaseObject) -> Vec<Violation> {
+        let maintained = match &object.typed_object.stmt {
+            Statement::CreateMaterializedView(_) => true,
+            Statement::CreateView(_) => !object.typed_object.indexes.is_empty(),
+            _ => false,
+        };
+        let Some(query) = super::query(&object.typed_object.stmt).filter(|_| maintained) else {
+            return Vec::new();
+        };
+        let mut visitor = MzNowVisitor::default();
+        visitor.visit_query(query);
+        if visitor.outside == 0 {
+            return Vec::new();
+        }
+        vec![Violation {
+            message: format!(
+                "{} uses mz_now() outside a WHERE or HAVING clause",
+                object.id
+            ),
+            anchor: Some("mz_now"),
+            help: Some("only temporal filters in WHERE or HAVING can be maintained".to_string()),
+        }]
+    }
+}
+
+#[derive(Default)]
+struct MzNowVisitor<'ast> {
+    /// `WHERE` and `HAVING` expressions seen so far.
+    filters: Vec<&'ast Expr<Raw>>,
+    /// How many filter expressions enclose the current node.
+    depth: usize,
+    /// `mz_now()` calls found outside any filter.
+    outside: usize,
+}
+
+impl<'ast> Visit<'ast, Raw> for MzNowVisitor<'ast> {
+    fn visit_select(&mut self, node: &'ast Select<Raw>) {
+        self.filters
+            .extend(node.selection.iter().chain(node.having.iter()));
+        visit::visit_select(self, node)
+    }
+
+    fn visit_expr(&mut self, node: &'ast Expr<Raw>) {
+        let is_filter = self.filters.iter().any(|f| std::ptr::eq(*f, node));
+        if is_filter {
+            self.depth += 1;
+        }
+        if let Expr::Function(func) = node {
+            let is_mz_now = func
+                .name
+                .name()
+                .0
+                .last()
+                .is_some_and(|ident| ident.as_str().eq_ignore_ascii_case("mz_now"));
+            if is_mz_now && self.depth == 0 {
+                self.outside += 1;
+            }
+        }
+        visit::visit_expr(self, node);
+        if is_filter {
+            self.depth -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testing::flagged;
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn flags_mz_now_in_maintained_projections() {
+        let objects = flagged(
+            &[
+                (
+                    "projected",
+                    "CREATE MATERIALIZED VIEW projected AS SELECT mz_now() AS now FROM t",
+                    &[],
+                ),
+                (
+                    "indexed",
+                    "CREATE VIEW indexed AS SELECT a FROM t ORDER BY mz_now();
+                     CREATE INDEX indexed_idx IN CLUSTER c ON indexed (a)",
+                    &[],
+                ),
+            ],
+            "mz-now-outside-where",
+        );
+        assert_eq!(objects, vec!["indexed", "projected"]);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn skips_temporal_filters_and_unmaintained_views() {
+        let objects = flagged(
+            &[
+                (
+                    "filtered",
+                    "CREATE MATERIALIZED VIEW filtered AS SELECT a FROM t WHERE mz_now() <= a",
+                    &[],
+                ),
+                (
+                    "grouped",
+                    "CREATE MATERIALIZED VIEW grouped AS SELECT a FROM t GROUP BY a \
+                     HAVING mz_now() <= max(b)",
+                    &[],
+                ),
+                (
+                    "adhoc",
+                    "CREATE VIEW adhoc AS SELECT mz_now() AS now FROM t",
+                    &[],
+                ),
+            ],
+            "mz-now-outside-where",
+        );
+        assert!(objects.is_empty());
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! `select-star-in-mv`: `SELECT *` in a materialized view.
+//!
+//! A materialized view's columns are its contract with everything that
+//! reads it. With `*`, an upstream column change silently reshapes the view.
+
+use super::{LintRule, Violation};
+use crate::project::ast::Statement;
+use crate::project::ir::graph::{DatabaseObject, Project};
+use mz_sql_parser::ast::{Expr, Raw, SelectItem, SetExpr};
+
+pub(super) struct SelectStarInMv;
+
+impl LintRule for SelectStarInMv {
+    fn name(&self) -> &'static str {
+        "select-star-in-mv"
+    }
+
+    fn check(&self, _project: &Project, object: &DatabaseObject) -> Vec<Violation> {
+        let Statement::CreateMaterializedView(mv) = &object.typed_object.stmt else {
+            return Vec::new();
+        };
+        if !has_wildcard(&mv.query.body) {
+            return Vec::new();
+        }
+        vec![Violation {
+            message: format!("materialized view {} selects `*`", object.id),
+            anchor: Some("*"),
+            help: Some("list the output columns explicitly".to_string()),
+        }]
+    }
+}
+
+/// Whether the output columns of `body` come from a wildcard. Wildcards in
+/// CTEs and subqueries don't reach the output and are ignored.
+fn has_wildcard(body: &SetExpr<Raw>) -> bool {
+    match body {
+        SetExpr::Select(select) => select.projection.iter().any(|item| {
+            matches!(
+                item,
+                SelectItem::Wildcard
+                    | SelectItem::Expr {
+                        expr: Expr::QualifiedWildcard(_),
+                        ..
+                    }
+            )
+        }),
+        SetExpr::Query(query) => has_wildcard(&query.body),
+        SetExpr::SetOperation { left, right, .. } => has_wildcard(left) || has_wildcard(right),
+        SetExpr::Table(_) => true,
+        SetExpr::Values(_) | SetExpr::Show(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testing::{flagged, lint};
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn flags_wildcards_in_the_output() {
+        let sql = "CREATE MATERIALIZED VIEW mv AS\nSELECT * FROM t";
+        let findings = lint(&[("mv", sql, &[])], "select-star-in-mv");
+        assert_eq!(findings.len(), 1);
+        assert_eq!(&sql[findings[0].byte_range.clone()], "*");
+        assert_eq!((findings[0].line, findings[0].column), (2, 8));
+
+        let objects = flagged(
+            &[
+                (
+                    "qualified",
+                    "CREATE MATERIALIZED VIEW qualified AS SELECT t.* FROM t",
+                    &[],
+                ),
+                (
+                    "union",
+                    "CREATE MATERIALIZED VIEW \"union\" AS SELECT a FROM t UNION SELECT * FROM u",
+                    &[],
+                ),
+            ],
+            "select-star-in-mv",
+        );
+        assert_eq!(objects, vec!["qualified", "union"]);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn skips_explicit_columns_subqueries_and_views() {
+        let objects = flagged(
+            &[
+                (
+                    "explicit",
+                    "CREATE MATERIALIZED VIEW explicit AS SELECT a FROM t",
+                    &[],
+                ),
+                (
+                    "subquery",
+                    "CREATE MATERIALIZED VIEW subquery AS SELECT a FROM (SELECT * FROM t)",
+                    &[],
+                ),
+                ("plain", "CREATE VIEW plain AS SELECT * FROM t", &[]),
+            ],
+            "select-star-in-mv",
+        );
+        assert!(objects.is_empty());
+    }
+}
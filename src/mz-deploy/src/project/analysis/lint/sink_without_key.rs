@@ -0,0 +1,66 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! `sink-without-key`: a Kafka or Iceberg sink with no `KEY`.
+//!
+//! Without a key, consumers cannot tell which earlier record an update
+//! replaces, and Kafka cannot compact the topic.
+
+use super::{LintRule, Violation};
+use crate::project::ast::Statement;
+use crate::project::ir::graph::{DatabaseObject, Project};
+use mz_sql_parser::ast::CreateSinkConnection;
+
+pub(super) struct SinkWithoutKey;
+
+impl LintRule for SinkWithoutKey {
+    fn name(&self) -> &'static str {
+        "sink-without-key"
+    }
+
+    fn check(&self, _project: &Project, object: &DatabaseObject) -> Vec<Violation> {
+        let Statement::CreateSink(sink) = &object.typed_object.stmt else {
+            return Vec::new();
+        };
+        let key = match &sink.connection {
+            CreateSinkConnection::Kafka { key, .. } | CreateSinkConnection::Iceberg { key, .. } => {
+                key
+            }
+        };
+        if key.is_some() {
+            return Vec::new();
+        }
+        vec![Violation {
+            message: format!("sink {} has no KEY", object.id),
+            anchor: Some("SINK"),
+            help: Some("add `KEY (<columns>)` naming the sink input's unique columns".to_string()),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testing::flagged;
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn flags_sinks_without_a_key() {
+        let sink = "CREATE SINK s FROM mv INTO KAFKA CONNECTION k (TOPIC 't') \
+                    FORMAT JSON ENVELOPE DEBEZIUM";
+        assert_eq!(flagged(&[("s", sink, &[])], "sink-without-key"), vec!["s"]);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn skips_keyed_sinks() {
+        let keyed = "CREATE SINK s FROM mv INTO KAFKA CONNECTION k (TOPIC 't') \
+                     KEY (id) FORMAT JSON ENVELOPE UPSERT";
+        assert!(flagged(&[("s", keyed, &[])], "sink-without-key").is_empty());
+    }
+}
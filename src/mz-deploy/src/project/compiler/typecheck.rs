@@ -354,6 +354,7 @@ pub(crate) fn run(
             tables: merged_tables,
             kinds: merged_kinds,
             comments: BTreeMap::new(),
+            contracts: BTreeMap::new(),
         },
         stats,
     ))
@@ -676,6 +677,7 @@ mod run_tests {
                 tables,
                 kinds,
                 comments: BTreeMap::new(),
+                contracts: BTreeMap::new(),
             }
         };
 
@@ -345,12 +345,14 @@ impl CatalogItem for LocalItem {
         self.oid
     }
 
-    fn func(&self) -> Result<&'static mz_sql::func::Func, CatalogError> {
-        self.func.ok_or_else(|| CatalogError::UnexpectedType {
-            name: self.name.item.clone(),
-            actual_type: self.item_type,
-            expected_type: CatalogItemType::Func,
-        })
+    fn func(&self) -> Result<mz_sql::func::FuncRef, CatalogError> {
+        self.func
+            .map(mz_sql::func::FuncRef::Builtin)
+            .ok_or_else(|| CatalogError::UnexpectedType {
+                name: self.name.item.clone(),
+                actual_type: self.item_type,
+                expected_type: CatalogItemType::Func,
+            })
     }
 
     fn source_desc(&self) -> Result<Option<&SourceDesc<ReferencedConnection>>, CatalogError> {
@@ -963,6 +965,9 @@ impl CatalogRuntime {
             CatalogType::Range { element_reference } => CatalogType::Range {
                 element_reference: self.get_system_type(element_reference).id(),
             },
+            CatalogType::MultiRange { element_reference } => CatalogType::MultiRange {
+                element_reference: self.get_system_type(element_reference).id(),
+            },
             CatalogType::Record { fields } => CatalogType::Record {
                 fields: fields
                     .iter()
@@ -977,6 +982,9 @@ impl CatalogRuntime {
             CatalogType::Bytes => CatalogType::Bytes,
             CatalogType::Char => CatalogType::Char,
             CatalogType::Date => CatalogType::Date,
+            CatalogType::Enum { labels } => CatalogType::Enum {
+                labels: labels.clone(),
+            },
             CatalogType::Float32 => CatalogType::Float32,
             CatalogType::Float64 => CatalogType::Float64,
             CatalogType::Int16 => CatalogType::Int16,
@@ -1001,6 +1009,9 @@ impl CatalogRuntime {
             CatalogType::Timestamp => CatalogType::Timestamp,
             CatalogType::TimestampTz => CatalogType::TimestampTz,
             CatalogType::Uuid => CatalogType::Uuid,
+            CatalogType::Inet => CatalogType::Inet,
+            CatalogType::Cidr => CatalogType::Cidr,
+            CatalogType::MacAddr => CatalogType::MacAddr,
             CatalogType::VarChar => CatalogType::VarChar,
             CatalogType::Int2Vector => CatalogType::Int2Vector,
             CatalogType::MzAclItem => CatalogType::MzAclItem,
@@ -199,6 +199,9 @@ fn sql_scalar_type_to_sql(scalar_type: &SqlScalarType) -> String {
         },
         SqlScalarType::Jsonb => "jsonb".into(),
         SqlScalarType::Uuid => "uuid".into(),
+        SqlScalarType::Inet => "inet".into(),
+        SqlScalarType::Cidr => "cidr".into(),
+        SqlScalarType::MacAddr => "macaddr".into(),
         SqlScalarType::Array(element_type) => format!("{}[]", sql_scalar_type_to_sql(element_type)),
         SqlScalarType::List { element_type, .. } => {
             format!("{} list", sql_scalar_type_to_sql(element_type))
@@ -215,9 +218,15 @@ fn sql_scalar_type_to_sql(scalar_type: &SqlScalarType) -> String {
         SqlScalarType::Range { element_type } => {
             format!("range({})", sql_scalar_type_to_sql(element_type))
         }
+        SqlScalarType::MultiRange { element_type } => {
+            format!("multirange({})", sql_scalar_type_to_sql(element_type))
+        }
         SqlScalarType::MzAclItem => "mz_aclitem".into(),
         SqlScalarType::AclItem => "aclitem".into(),
         SqlScalarType::Record { .. } => "record".into(),
+        // Stub tables cannot reference the enum type itself, so its values are
+        // typed by their labels.
+        SqlScalarType::Enum { .. } => "text".into(),
     }
 }
 
@@ -432,6 +432,22 @@ impl DatabaseObject {
     pub fn clusters(&self) -> BTreeSet<String> {
         let mut cluster_set = BTreeSet::new();
 
+        if let Some(cluster_name) = self.in_cluster() {
+            cluster_set.insert(cluster_name);
+        }
+
+        for index in &self.indexes {
+            if let Some(RawClusterName::Unresolved(cluster_name)) = &index.in_cluster {
+                cluster_set.insert(cluster_name.to_string());
+            }
+        }
+        cluster_set
+    }
+
+    /// The cluster named by the primary statement's `IN CLUSTER` clause.
+    ///
+    /// Unlike [`clusters`](Self::clusters), this ignores the object's indexes.
+    pub fn in_cluster(&self) -> Option<String> {
         let in_cluster = match &self.stmt {
             Statement::CreateMaterializedView(mv) => mv.in_cluster.as_ref(),
             Statement::CreateSink(sink) => sink.in_cluster.as_ref(),
@@ -442,16 +458,10 @@ impl DatabaseObject {
             | Statement::CreateSecret(_)
             | Statement::CreateConnection(_) => None,
         };
-        if let Some(RawClusterName::Unresolved(cluster_name)) = in_cluster {
-            cluster_set.insert(cluster_name.to_string());
+        match in_cluster {
+            Some(RawClusterName::Unresolved(cluster_name)) => Some(cluster_name.to_string()),
+            _ => None,
         }
-
-        for index in &self.indexes {
-            if let Some(RawClusterName::Unresolved(cluster_name)) = &index.in_cluster {
-                cluster_set.insert(cluster_name.to_string());
-            }
-        }
-        cluster_set
     }
 
     /// Convert the statement to a `Query<Raw>` for type checking purposes.
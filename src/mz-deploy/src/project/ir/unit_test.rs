@@ -12,6 +12,14 @@
 //! The compiler stores the parsed test definition on the project graph;
 //! validation and SQL lowering happen later in the test runner (see
 //! `cli::commands::test::lower`).
+//!
+//! A test takes one of two forms: a single-snapshot test with one
+//! `EXPECTED` relation, or a multi-step test whose `STEPS` insert and
+//! retract mock rows at given timestamps and assert on the target along
+//! the way.
+
+use mz_sql_parser::ast::display::{AstDisplay, FormatMode};
+use mz_sql_parser::ast::{ColumnDef, Raw, UnitTestStep};
 
 /// Represents a parsed unit test definition.
 #[derive(Debug, Clone)]
@@ -24,17 +32,17 @@ pub struct UnitTest {
     pub at_time: Option<String>,
     /// Mock views to create for dependencies
     pub mocks: Vec<MockView>,
-    /// Expected results definition
-    pub expected: ExpectedResult,
+    /// Expected results definition; `None` for multi-step tests
+    pub expected: Option<ExpectedResult>,
+    /// Timestamped steps; empty for single-snapshot tests
+    pub steps: Vec<TestStep>,
 }
 
 impl UnitTest {
     /// Convert an ExecuteUnitTestStatement from the AST into a UnitTest.
     pub fn from_execute_statement(
-        stmt: &mz_sql_parser::ast::ExecuteUnitTestStatement<mz_sql_parser::ast::Raw>,
+        stmt: &mz_sql_parser::ast::ExecuteUnitTestStatement<Raw>,
     ) -> Self {
-        use mz_sql_parser::ast::display::{AstDisplay, FormatMode};
-
         let name = stmt.name.to_string();
         let target_view = stmt.target.to_ast_string(FormatMode::Simple);
 
@@ -48,17 +56,12 @@ impl UnitTest {
             .iter()
             .map(|mock| {
                 let fqn = mock.name.to_ast_string(FormatMode::Simple);
-                let columns = mock
-                    .columns
-                    .iter()
-                    .map(|col| {
-                        (
-                            col.name.to_string(),
-                            col.data_type.to_ast_string(FormatMode::Simple),
-                        )
-                    })
-                    .collect();
-                let query = mock.query.to_ast_string(FormatMode::Simple);
+                let columns = columns_of(&mock.columns);
+                // A mock without a body starts out empty.
+                let query = match &mock.query {
+                    Some(query) => query.to_ast_string(FormatMode::Simple),
+                    None => empty_query(&columns),
+                };
                 MockView {
                     fqn,
                     columns,
@@ -67,20 +70,50 @@ impl UnitTest {
             })
             .collect();
 
-        let expected = ExpectedResult {
-            columns: stmt
-                .expected
-                .columns
-                .iter()
-                .map(|col| {
-                    (
-                        col.name.to_string(),
-                        col.data_type.to_ast_string(FormatMode::Simple),
-                    )
-                })
-                .collect(),
-            query: stmt.expected.query.to_ast_string(FormatMode::Simple),
-        };
+        let expected = stmt.expected.as_ref().map(|expected| ExpectedResult {
+            columns: columns_of(&expected.columns),
+            query: expected.query.to_ast_string(FormatMode::Simple),
+        });
+
+        let steps = stmt
+            .steps
+            .iter()
+            .map(|step| match step {
+                UnitTestStep::Insert {
+                    at_time,
+                    mock,
+                    query,
+                } => TestStep::Insert {
+                    at_time: at_time.to_ast_string(FormatMode::Simple),
+                    mock: mock.to_ast_string(FormatMode::Simple),
+                    query: query.to_ast_string(FormatMode::Simple),
+                },
+                UnitTestStep::Retract {
+                    at_time,
+                    mock,
+                    query,
+                } => TestStep::Retract {
+                    at_time: at_time.to_ast_string(FormatMode::Simple),
+                    mock: mock.to_ast_string(FormatMode::Simple),
+                    query: query.to_ast_string(FormatMode::Simple),
+                },
+                UnitTestStep::ExpectSnapshot { at_time, expected } => TestStep::ExpectSnapshot {
+                    at_time: at_time.to_ast_string(FormatMode::Simple),
+                    expected: ExpectedResult {
+                        columns: columns_of(&expected.columns),
+                        query: expected.query.to_ast_string(FormatMode::Simple),
+                    },
+                },
+                UnitTestStep::ExpectChanges { from, to, expected } => TestStep::ExpectChanges {
+                    from: from.to_ast_string(FormatMode::Simple),
+                    to: to.to_ast_string(FormatMode::Simple),
+                    expected: ExpectedResult {
+                        columns: columns_of(&expected.columns),
+                        query: expected.query.to_ast_string(FormatMode::Simple),
+                    },
+                },
+            })
+            .collect();
 
         UnitTest {
             name,
@@ -88,10 +121,59 @@ impl UnitTest {
             at_time,
             mocks,
             expected,
+            steps,
+        }
+    }
+
+    /// Every expected relation in the test, whose columns must match the
+    /// target view's output schema.
+    pub fn expectations(&self) -> impl Iterator<Item = &ExpectedResult> {
+        self.expected
+            .iter()
+            .chain(self.steps.iter().filter_map(TestStep::expected))
+    }
+
+    /// Every timestamp expression in the test, in the order written.
+    pub fn timestamps(&self) -> Vec<&str> {
+        let mut times: Vec<&str> = self.at_time.iter().map(String::as_str).collect();
+        for step in &self.steps {
+            match step {
+                TestStep::Insert { at_time, .. }
+                | TestStep::Retract { at_time, .. }
+                | TestStep::ExpectSnapshot { at_time, .. } => times.push(at_time),
+                TestStep::ExpectChanges { from, to, .. } => {
+                    times.push(from);
+                    times.push(to);
+                }
+            }
         }
+        times
     }
 }
 
+/// Convert parsed column definitions into `(name, type)` pairs.
+fn columns_of(columns: &[ColumnDef<Raw>]) -> Vec<(String, String)> {
+    columns
+        .iter()
+        .map(|col| {
+            (
+                col.name.to_string(),
+                col.data_type.to_ast_string(FormatMode::Simple),
+            )
+        })
+        .collect()
+}
+
+/// A query producing no rows with the given column types.
+fn empty_query(columns: &[(String, String)]) -> String {
+    let projection = columns
+        .iter()
+        .map(|(_, typ)| format!("NULL::{}", typ))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("SELECT {} WHERE false", projection)
+}
+
 /// A mock view definition that replaces a real dependency.
 #[derive(Debug, Clone)]
 pub struct MockView {
@@ -111,3 +193,61 @@ pub struct ExpectedResult {
     /// SQL query body (the part after AS)
     pub query: String,
 }
+
+/// One step of a multi-step test. Timestamps are SQL expressions castable
+/// to `mz_timestamp`.
+#[derive(Debug, Clone)]
+pub enum TestStep {
+    /// Add the query's rows to a mock from `at_time` onwards.
+    Insert {
+        at_time: String,
+        mock: String,
+        query: String,
+    },
+    /// Remove the query's rows from a mock from `at_time` onwards.
+    Retract {
+        at_time: String,
+        mock: String,
+        query: String,
+    },
+    /// Assert the target's contents as of `at_time`.
+    ExpectSnapshot {
+        at_time: String,
+        expected: ExpectedResult,
+    },
+    /// Assert the net `(row, diff)` changes to the target between `from`
+    /// and `to`. Each expected row carries a trailing diff column.
+    ExpectChanges {
+        from: String,
+        to: String,
+        expected: ExpectedResult,
+    },
+}
+
+impl TestStep {
+    /// The expected relation asserted by this step, if any.
+    pub fn expected(&self) -> Option<&ExpectedResult> {
+        match self {
+            TestStep::Insert { .. } | TestStep::Retract { .. } => None,
+            TestStep::ExpectSnapshot { expected, .. }
+            | TestStep::ExpectChanges { expected, .. } => Some(expected),
+        }
+    }
+}
+
+impl std::fmt::Display for TestStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TestStep::Insert { at_time, mock, .. } => {
+                write!(f, "AT TIME {} INSERT INTO {}", at_time, mock)
+            }
+            TestStep::Retract { at_time, mock, .. } => {
+                write!(f, "AT TIME {} RETRACT FROM {}", at_time, mock)
+            }
+            TestStep::ExpectSnapshot { at_time, .. } => write!(f, "AT TIME {} EXPECTED", at_time),
+            TestStep::ExpectChanges { from, to, .. } => {
+                write!(f, "BETWEEN {} AND {} EXPECTED CHANGES", from, to)
+            }
+        }
+    }
+}
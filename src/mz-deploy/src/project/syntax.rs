@@ -15,11 +15,15 @@
 //! - profile-specific file variants
 //! - parsed input structures
 //! - variable substitution
+//! - file-level `PRAGMA` directives
 //! - SQL parsing with source locations
+//! - canonical formatting of source files
 //!
 //! These modules describe how bytes on disk become structured compiler inputs.
 
+pub(crate) mod format;
 pub(crate) mod input;
 pub(crate) mod parser;
+pub(crate) mod pragma;
 pub(crate) mod profile_files;
 pub(crate) mod variables;
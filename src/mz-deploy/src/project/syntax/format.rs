@@ -0,0 +1,374 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Canonical formatting for project SQL files.
+//!
+//! Backs both `mz-deploy fmt` and the language server's formatting requests.
+//! Each statement is pretty-printed with [`mz_sql_pretty`] at
+//! [`mz_sql_pretty::DEFAULT_WIDTH`], while everything the pretty printer
+//! cannot represent is carried through unchanged:
+//!
+//! - **Variables** — `:name`, `:'name'`, and `:"name"` references are masked
+//!   with placeholders before parsing and restored afterwards, so files are
+//!   formatted without needing a profile.
+//! - **Comments** — comments between statements (including `PRAGMA`
+//!   directives) are kept in place. A statement that contains a comment is
+//!   left verbatim, since the AST has nowhere to put it.
+//!
+//! Statements are separated by a blank line and the file ends with a single
+//! newline. A statement is only rewritten when re-parsing the pretty-printed
+//! text yields an identical AST; otherwise the original text is kept.
+
+use std::ops::Range;
+
+use mz_sql_lexer::lexer::{self, Token};
+use mz_sql_parser::ast::display::FormatMode;
+use mz_sql_parser::parser::parse_statements;
+use mz_sql_pretty::PrettyConfig;
+use thiserror::Error;
+
+use super::variables::{self, Substitution};
+
+/// Prefix of the placeholder identifiers used to mask variable references.
+const PLACEHOLDER_PREFIX: &str = "__mz_deploy_var_";
+
+/// An error that prevents a file from being formatted.
+#[derive(Debug, Error)]
+pub(crate) enum FormatError {
+    #[error("syntax error at line {line}: {message}")]
+    Syntax { line: usize, message: String },
+}
+
+/// The canonical text for one statement in a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FormattedStatement {
+    /// Byte range of the statement in the original text, including its
+    /// terminating `;` when present.
+    pub range: Range<usize>,
+    /// The formatted statement, including its terminating `;`.
+    pub text: String,
+}
+
+/// Format an entire SQL file.
+pub(crate) fn format_sql(sql: &str) -> Result<String, FormatError> {
+    let statements = format_statements(sql)?;
+    let mut out = String::new();
+    let mut pos = 0;
+    for statement in &statements {
+        push_gap(&mut out, &sql[pos..statement.range.start], true);
+        out.push_str(&statement.text);
+        pos = statement.range.end;
+    }
+    push_gap(&mut out, &sql[pos..], false);
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Format each statement in `sql` independently.
+///
+/// Returned ranges refer to the original text, so callers can rewrite a
+/// subset of statements (e.g. for LSP range formatting) without touching the
+/// surrounding comments and whitespace.
+pub(crate) fn format_statements(sql: &str) -> Result<Vec<FormattedStatement>, FormatError> {
+    let masked = Masked::new(sql);
+    let tokens =
+        lexer::lex(&masked.text).map_err(|e| syntax_error(sql, &masked, e.pos, e.message))?;
+
+    let mut statements = Vec::new();
+    let mut start = None;
+    for token in &tokens {
+        match (token.kind == Token::Semicolon, start) {
+            (true, Some(begin)) => {
+                statements.push(masked.format_statement(sql, begin, token.offset, true)?);
+                start = None;
+            }
+            (false, None) => start = Some(token.offset),
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        let end = begin + masked.text[begin..].trim_end().len();
+        statements.push(masked.format_statement(sql, begin, end, false)?);
+    }
+    Ok(statements)
+}
+
+/// SQL text with every variable reference replaced by a parseable placeholder.
+struct Masked {
+    text: String,
+    /// Original reference text, indexed by placeholder number.
+    originals: Vec<String>,
+    substitutions: Vec<Substitution>,
+}
+
+impl Masked {
+    fn new(sql: &str) -> Self {
+        let mut text = String::with_capacity(sql.len());
+        let mut originals = Vec::new();
+        let mut substitutions = Vec::new();
+        let mut pos = 0;
+        for span in variables::variable_spans(sql) {
+            text.push_str(&sql[pos..span.start]);
+            let original = &sql[span.clone()];
+            let placeholder = placeholder(originals.len());
+            let replacement = match quote_char(original) {
+                Some(quote) => format!("{quote}{placeholder}{quote}"),
+                None => placeholder,
+            };
+            substitutions.push(Substitution {
+                original_start: span.start,
+                original_len: span.len(),
+                resolved_len: replacement.len(),
+            });
+            text.push_str(&replacement);
+            originals.push(original.to_string());
+            pos = span.end;
+        }
+        text.push_str(&sql[pos..]);
+        Masked {
+            text,
+            originals,
+            substitutions,
+        }
+    }
+
+    /// Map a byte offset in the masked text back to the original text.
+    fn original_offset(&self, offset: usize) -> usize {
+        variables::resolved_to_original(offset, &self.substitutions)
+    }
+
+    /// Format the statement spanning `start..end` in the masked text. When
+    /// `terminated` is set, `end` is the offset of the terminating `;`.
+    fn format_statement(
+        &self,
+        sql: &str,
+        start: usize,
+        end: usize,
+        terminated: bool,
+    ) -> Result<FormattedStatement, FormatError> {
+        let original_start = self.original_offset(start);
+        let original_end = self.original_offset(end) + usize::from(terminated);
+        let original = &sql[original_start..original_end];
+        let verbatim = FormattedStatement {
+            range: original_start..original_end,
+            text: original.to_string(),
+        };
+
+        if variables::contains_comment(original) {
+            return Ok(verbatim);
+        }
+
+        let body = &self.text[start..end];
+        let parsed = match parse_statements(body) {
+            Ok(parsed) => parsed,
+            // Raw variables may stand in for arbitrary SQL fragments that do
+            // not parse as identifiers; leave such statements alone rather
+            // than reject a file that compiles once variables are resolved.
+            Err(_) if body.contains(PLACEHOLDER_PREFIX) => return Ok(verbatim),
+            Err(e) => {
+                let pos = start + e.error.pos;
+                return Err(syntax_error(sql, self, pos, e.error.message));
+            }
+        };
+        let [statement] = parsed.as_slice() else {
+            return Ok(verbatim);
+        };
+
+        let pretty = mz_sql_pretty::to_pretty(
+            &statement.ast,
+            PrettyConfig {
+                width: mz_sql_pretty::DEFAULT_WIDTH,
+                format_mode: FormatMode::Simple,
+            },
+        );
+        let round_trips = matches!(
+            parse_statements(&pretty).as_deref(),
+            Ok([reparsed]) if reparsed.ast == statement.ast
+        );
+        if !round_trips {
+            return Ok(verbatim);
+        }
+
+        match self.unmask(&pretty) {
+            Some(text) => Ok(FormattedStatement {
+                range: original_start..original_end,
+                text,
+            }),
+            None => Ok(verbatim),
+        }
+    }
+
+    /// Restore the original variable references in `formatted`. Returns
+    /// `None` if a placeholder was altered and cannot be restored.
+    fn unmask(&self, formatted: &str) -> Option<String> {
+        let mut text = formatted.to_string();
+        for (n, original) in self.originals.iter().enumerate() {
+            let placeholder = placeholder(n);
+            // The printer drops the quotes around identifiers that don't need
+            // them, so a quoted placeholder may come back bare.
+            if let Some(quote) = quote_char(original) {
+                text = text.replace(&format!("{quote}{placeholder}{quote}"), original);
+            }
+            text = text.replace(&placeholder, original);
+        }
+        (!text.contains(PLACEHOLDER_PREFIX)).then_some(text)
+    }
+}
+
+fn placeholder(n: usize) -> String {
+    format!("{PLACEHOLDER_PREFIX}{n}__")
+}
+
+/// The quote character of a `:'name'` or `:"name"` reference, if any.
+fn quote_char(reference: &str) -> Option<char> {
+    match reference.as_bytes().get(1) {
+        Some(b'\'') => Some('\''),
+        Some(b'"') => Some('"'),
+        _ => None,
+    }
+}
+
+fn syntax_error(sql: &str, masked: &Masked, pos: usize, message: String) -> FormatError {
+    let offset = masked.original_offset(pos).min(sql.len());
+    let line = sql[..offset].matches('\n').count() + 1;
+    FormatError::Syntax { line, message }
+}
+
+/// Append the whitespace and comments found between two statements.
+///
+/// A line comment on the same line as the previous statement's `;` stays
+/// there. Remaining comments are kept as a block with trailing whitespace
+/// trimmed and runs of blank lines collapsed. Statements are separated by a
+/// blank line; a blank line between a comment block and the statement that
+/// follows it is preserved.
+fn push_gap(out: &mut String, gap: &str, before_statement: bool) {
+    let mut rest = gap;
+    if !out.is_empty() {
+        let (first_line, remainder) = gap.split_once('\n').unwrap_or((gap, ""));
+        let first_line = first_line.trim();
+        if first_line.starts_with("--") {
+            out.push(' ');
+            out.push_str(first_line);
+            rest = remainder;
+        }
+    }
+
+    let mut block = String::new();
+    let mut pending_blank = false;
+    for line in rest.lines().map(str::trim_end) {
+        if line.trim().is_empty() {
+            pending_blank = !block.is_empty();
+            continue;
+        }
+        if !block.is_empty() {
+            block.push('\n');
+            if pending_blank {
+                block.push('\n');
+            }
+        }
+        pending_blank = false;
+        block.push_str(line);
+    }
+
+    if !out.is_empty() && (before_statement || !block.is_empty()) {
+        out.push_str("\n\n");
+    }
+    out.push_str(&block);
+    if before_statement && !block.is_empty() {
+        out.push_str(if pending_blank { "\n\n" } else { "\n" });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pretty-print a single statement the way the formatter does.
+    fn pretty(sql: &str) -> String {
+        mz_sql_pretty::pretty_str_simple(sql, mz_sql_pretty::DEFAULT_WIDTH).unwrap()
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn formats_statements_with_blank_line_separator() {
+        let sql = "create   view v as select a,b from t;create index i on v (a);";
+        let expected = format!(
+            "{}\n\n{}\n",
+            pretty("create view v as select a, b from t"),
+            pretty("create index i on v (a)")
+        );
+        assert_eq!(format_sql(sql).unwrap(), expected);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn preserves_comments_and_pragma() {
+        let sql = "-- PRAGMA WARN_ON_MISSING_VARIABLES;\n\n\
+                   -- the view\n\
+                   create view v as select 1;  -- trailing\n\
+                   /* tail */\n";
+        let expected = format!(
+            "-- PRAGMA WARN_ON_MISSING_VARIABLES;\n\n-- the view\n{} -- trailing\n\n/* tail */\n",
+            pretty("create view v as select 1")
+        );
+        assert_eq!(format_sql(sql).unwrap(), expected);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn statement_with_inner_comment_is_verbatim() {
+        let sql = "create view v as\n  select 1 -- one\n  ;\n";
+        assert_eq!(format_sql(sql).unwrap(), sql);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn preserves_variables() {
+        let sql = "create materialized view v in cluster :cluster as \
+                   select :'host' as h, x::text from :\"tbl\";";
+        let formatted = format_sql(sql).unwrap();
+        assert!(formatted.contains("IN CLUSTER :cluster"), "{formatted}");
+        assert!(formatted.contains(":'host' AS h"), "{formatted}");
+        assert!(formatted.contains("x::text"), "{formatted}");
+        assert!(formatted.contains("FROM :\"tbl\""), "{formatted}");
+        assert!(!formatted.contains(PLACEHOLDER_PREFIX), "{formatted}");
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn formatting_is_idempotent() {
+        let sql = "-- header\n\
+                   create view v as select a from t where a > :min;  -- note\n\n\n\
+                   -- next\n\
+                   create index i on v (a)";
+        let once = format_sql(sql).unwrap();
+        assert_eq!(format_sql(&once).unwrap(), once);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn statement_ranges_refer_to_original_text() {
+        let sql = "select :a;\nselect   2";
+        let statements = format_statements(sql).unwrap();
+        assert_eq!(statements.len(), 2);
+        assert_eq!(&sql[statements[0].range.clone()], "select :a;");
+        assert_eq!(statements[0].text, pretty("select a").replace(" a;", " :a;"));
+        assert_eq!(&sql[statements[1].range.clone()], "select   2");
+        assert_eq!(statements[1].text, pretty("select 2"));
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn reports_syntax_errors_with_line() {
+        let err = format_sql("select 1;\nselect 1 +;").unwrap_err();
+        assert!(matches!(err, FormatError::Syntax { line: 2, .. }), "{err}");
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! File-level `PRAGMA` directives.
+//!
+//! A pragma is written as `PRAGMA <NAME>;` inside a comment in the file's
+//! leading comment block — the run of line and block comments before the
+//! first statement:
+//!
+//! ```sql
+//! -- PRAGMA WARN_ON_MISSING_VARIABLES;
+//! /* PRAGMA ALLOW_BREAKING_CHANGE; */
+//! CREATE VIEW ...
+//! ```
+//!
+//! Pragmas after the first statement are ignored.
+
+/// Downgrade undefined-variable errors to warnings.
+pub(crate) const WARN_ON_MISSING_VARIABLES: &str = "WARN_ON_MISSING_VARIABLES";
+
+/// Allow breaking changes to the output columns of a contract object.
+pub(crate) const ALLOW_BREAKING_CHANGE: &str = "ALLOW_BREAKING_CHANGE";
+
+/// Check whether the leading comment block of `sql` contains `PRAGMA <name>;`.
+pub(crate) fn has_pragma(sql: &str, name: &str) -> bool {
+    let directive = format!("PRAGMA {};", name);
+    let mut rest = sql.trim_start();
+    loop {
+        let (comment, tail) = if let Some(body) = rest.strip_prefix("--") {
+            match body.find('\n') {
+                Some(pos) => (&body[..pos], &body[pos..]),
+                None => (body, ""),
+            }
+        } else if let Some(body) = rest.strip_prefix("/*") {
+            match body.find("*/") {
+                Some(pos) => (&body[..pos], &body[pos + 2..]),
+                None => (body, ""),
+            }
+        } else {
+            return false;
+        };
+        if comment.contains(&directive) {
+            return true;
+        }
+        rest = tail.trim_start();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn pragma_in_line_comment() {
+        assert!(has_pragma(
+            "-- PRAGMA ALLOW_BREAKING_CHANGE;\nCREATE VIEW v AS SELECT 1",
+            ALLOW_BREAKING_CHANGE
+        ));
+    }
+
+    #[mz_ore::test]
+    fn pragma_after_other_leading_comments() {
+        let sql = "-- Orders feed for the billing team.\n\
+                   /* PRAGMA WARN_ON_MISSING_VARIABLES; */\n\
+                   -- PRAGMA ALLOW_BREAKING_CHANGE;\n\
+                   CREATE VIEW v AS SELECT 1";
+        assert!(has_pragma(sql, ALLOW_BREAKING_CHANGE));
+        assert!(has_pragma(sql, WARN_ON_MISSING_VARIABLES));
+    }
+
+    #[mz_ore::test]
+    fn pragma_after_first_statement_is_ignored() {
+        assert!(!has_pragma(
+            "CREATE VIEW v AS SELECT 1;\n-- PRAGMA ALLOW_BREAKING_CHANGE;",
+            ALLOW_BREAKING_CHANGE
+        ));
+    }
+
+    #[mz_ore::test]
+    fn pragma_requires_exact_name() {
+        assert!(!has_pragma(
+            "-- PRAGMA ALLOW_BREAKING;\nCREATE VIEW v AS SELECT 1",
+            ALLOW_BREAKING_CHANGE
+        ));
+    }
+}
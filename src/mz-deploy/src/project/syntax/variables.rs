@@ -48,8 +48,10 @@
 //! The `::` token (PostgreSQL type cast) is never interpreted as a variable
 //! reference.
 
+use super::pragma;
 use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::ops::Range;
 use std::path::PathBuf;
 
 /// An unresolved variable reference with its location in the original SQL.
@@ -131,31 +133,6 @@ pub(crate) fn resolved_to_original(offset: usize, substitutions: &[Substitution]
     (offset as isize + delta) as usize
 }
 
-const PRAGMA: &str = "PRAGMA WARN_ON_MISSING_VARIABLES;";
-
-/// Check whether the first non-whitespace content in `sql` is a comment
-/// containing the warn-on-missing-variables pragma.
-fn detect_warn_pragma(sql: &str) -> bool {
-    let trimmed = sql.trim_start();
-
-    if let Some(rest) = trimmed.strip_prefix("--") {
-        // Line comment: check the text up to the first newline.
-        let line = match rest.find('\n') {
-            Some(pos) => &rest[..pos],
-            None => rest,
-        };
-        line.contains(PRAGMA)
-    } else if let Some(rest) = trimmed.strip_prefix("/*") {
-        // Block comment: check the text up to the closing `*/`.
-        match rest.find("*/") {
-            Some(pos) => rest[..pos].contains(PRAGMA),
-            None => rest.contains(PRAGMA),
-        }
-    } else {
-        false
-    }
-}
-
 /// The kind of variable reference found in the SQL text.
 enum VarKind {
     /// `:name` — substitute raw value
@@ -394,6 +371,77 @@ pub(crate) fn find_variable_at_position(
     None
 }
 
+/// Return the byte ranges of every variable reference in `sql`.
+///
+/// Uses the same context-awareness rules as [`resolve_variables`], so
+/// references inside strings, comments, and dollar-quotes are not reported.
+/// Ranges cover the full reference including the leading `:` and any quotes.
+pub(crate) fn variable_spans(sql: &str) -> Vec<Range<usize>> {
+    let bytes = sql.as_bytes();
+    let len = bytes.len();
+    let mut spans = Vec::new();
+    let mut i = 0;
+
+    while i < len {
+        if bytes[i] == b'\'' {
+            i = consume_single_quoted(bytes, i + 1, len);
+        } else if bytes[i] == b'"' {
+            i = consume_double_quoted(bytes, i + 1, len);
+        } else if starts_with(bytes, i, b"--") {
+            i = consume_line_comment(bytes, i + 2, len);
+        } else if starts_with(bytes, i, b"/*") {
+            i = consume_block_comment(bytes, i + 2, len);
+        } else if bytes[i] == b'$' {
+            if let Some((end, tag)) = try_dollar_tag(bytes, i, len) {
+                i = consume_dollar_quoted(bytes, end, len, tag);
+            } else {
+                i += 1;
+            }
+        } else if starts_with(bytes, i, b"::") {
+            i += 2;
+        } else if bytes[i] == b':' {
+            if let Some((_name, _kind, end)) = try_read_variable(sql, bytes, i) {
+                spans.push(i..end);
+                i = end;
+            } else {
+                i += 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    spans
+}
+
+/// Check whether `sql` contains a line or block comment outside of strings,
+/// quoted identifiers, and dollar-quotes.
+pub(crate) fn contains_comment(sql: &str) -> bool {
+    let bytes = sql.as_bytes();
+    let len = bytes.len();
+    let mut i = 0;
+
+    while i < len {
+        if bytes[i] == b'\'' {
+            i = consume_single_quoted(bytes, i + 1, len);
+        } else if bytes[i] == b'"' {
+            i = consume_double_quoted(bytes, i + 1, len);
+        } else if starts_with(bytes, i, b"--") || starts_with(bytes, i, b"/*") {
+            return true;
+        } else if bytes[i] == b'$' {
+            if let Some((end, tag)) = try_dollar_tag(bytes, i, len) {
+                i = consume_dollar_quoted(bytes, end, len, tag);
+            } else {
+                i += 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    false
+}
+
 /// Resolve psql-style variables (`:foo`, `:'foo'`, `:"foo"`) in SQL text.
 ///
 /// Always returns `ResolvedSql` with the SQL text (unresolved variables left as-is),
@@ -415,7 +463,7 @@ pub(crate) fn resolve_variables<'a>(
         };
     }
 
-    let has_warn_pragma = detect_warn_pragma(sql);
+    let has_warn_pragma = pragma::has_pragma(sql, pragma::WARN_ON_MISSING_VARIABLES);
 
     let mut i = 0;
     let mut output: Option<String> = None;
@@ -858,6 +906,25 @@ mod tests {
         assert_eq!(find_variable_at_position(sql, 11), None);
     }
 
+    // --- variable_spans / contains_comment tests ---
+
+    #[mz_ore::test]
+    fn variable_spans_skips_strings_comments_and_casts() {
+        let sql = "SELECT :a, :'b', x::int, ':c' -- :d\nFROM :\"e\"";
+        let spans: Vec<&str> = variable_spans(sql)
+            .into_iter()
+            .map(|span| &sql[span])
+            .collect();
+        assert_eq!(spans, vec![":a", ":'b'", ":\"e\""]);
+    }
+
+    #[mz_ore::test]
+    fn contains_comment_ignores_quoted_text() {
+        assert!(contains_comment("SELECT 1 -- note"));
+        assert!(contains_comment("SELECT /* note */ 1"));
+        assert!(!contains_comment("SELECT '--', \"/*\", $$--$$"));
+    }
+
     // --- Substitution tracking tests ---
 
     #[mz_ore::test]
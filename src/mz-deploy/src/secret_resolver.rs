@@ -20,23 +20,31 @@
 //! Each provider is a submodule that implements [`SecretProvider`]:
 //!
 //! - `env_var::EnvVarProvider` — reads from environment variables
+//! - `file_secret::FileSecretProvider` — reads the contents of a file
 //! - `aws_secret::AwsSecretProvider` — reads from AWS Secrets Manager
 //! - `aws_secret::UnconfiguredAwsProvider` — placeholder when `aws_profile` is not set
+//! - `vault_secret::VaultSecretProvider` — reads from a HashiCorp Vault KV v2 engine
+//! - `vault_secret::UnconfiguredVaultProvider` — placeholder when the profile
+//!   has no `vault` table in `profiles.toml`
 
 mod aws_secret;
 mod env_var;
+mod file_secret;
 mod json_field;
+mod vault_secret;
 
 use crate::cli::CliError;
-use crate::config::SecurityConfig;
+use crate::config::{SecurityConfig, VaultConfig};
 use crate::project::ast::Statement;
 use async_trait::async_trait;
 use aws_secret::{AwsSecretProvider, UnconfiguredAwsProvider};
 use env_var::EnvVarProvider;
+use file_secret::FileSecretProvider;
 use mz_sql_parser::ast::{CreateSecretStatement, Expr, FunctionArgs, Raw, RawItemName, Value};
 use std::collections::BTreeMap;
 use std::ops::RangeInclusive;
 use thiserror::Error;
+use vault_secret::{UnconfiguredVaultProvider, VaultSecretProvider};
 
 /// Errors that can occur during secret resolution.
 #[derive(Debug, Error)]
@@ -90,16 +98,18 @@ pub(crate) struct SecretResolver {
 }
 
 impl SecretResolver {
-    /// Creates a new resolver with providers configured from the given security config.
+    /// Creates a new resolver with providers configured from the given
+    /// security config and the connection profile's Vault settings.
     ///
-    /// Always registers `env_var`. For AWS, registers the real Secrets
-    /// Manager provider when `aws_profile` is set, or a placeholder that
-    /// errors clearly when it isn't. All credentials are loaded lazily.
-    pub(crate) fn new(config: &SecurityConfig) -> Self {
+    /// Always registers `env_var` and `file_secret`. For AWS and Vault,
+    /// registers the real provider when it is configured, or a placeholder
+    /// that errors clearly when it isn't. All credentials are loaded lazily.
+    pub(crate) fn new(config: &SecurityConfig, vault: Option<&VaultConfig>) -> Self {
         let mut resolver = Self {
             providers: BTreeMap::new(),
         };
         resolver.register(Box::new(EnvVarProvider));
+        resolver.register(Box::new(FileSecretProvider));
 
         if let Some(profile) = config.aws_profile() {
             resolver.register(Box::new(AwsSecretProvider::new(profile)));
@@ -107,6 +117,12 @@ impl SecretResolver {
             resolver.register(Box::new(UnconfiguredAwsProvider));
         }
 
+        if let Some(vault) = vault {
+            resolver.register(Box::new(VaultSecretProvider::new(vault)));
+        } else {
+            resolver.register(Box::new(UnconfiguredVaultProvider));
+        }
+
         resolver
     }
 
@@ -151,7 +167,9 @@ impl SecretResolver {
                             got: 0,
                         });
                     }
-                    FunctionArgs::Args { args, .. } => args,
+                    FunctionArgs::Args { args, .. } | FunctionArgs::WithinGroup { args, .. } => {
+                        args
+                    }
                 };
 
                 if !accepted.contains(&arg_exprs.len()) {
@@ -256,7 +274,7 @@ mod tests {
 
     #[mz_ore::test(tokio::test)]
     async fn test_resolve_string_literal_passthrough() {
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
         let expr = Expr::Value(Value::String("hello".to_string()));
         let original = format!("{}", expr);
         let resolved = resolver.resolve_expr(expr).await.unwrap();
@@ -267,7 +285,7 @@ mod tests {
     async fn test_resolve_env_var_success() {
         // SAFETY: test-only; no other thread reads this variable.
         unsafe { std::env::set_var("MZ_TEST_SECRET_123", "my_secret_value") };
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
         let expr = make_env_var_expr("MZ_TEST_SECRET_123");
         let resolved = resolver.resolve_expr(expr).await.unwrap();
         match resolved {
@@ -278,7 +296,7 @@ mod tests {
 
     #[mz_ore::test(tokio::test)]
     async fn test_resolve_env_var_not_set() {
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
         let expr = make_env_var_expr("MZ_DEFINITELY_NOT_SET_XYZ_999");
         let err = resolver.resolve_expr(expr).await.unwrap_err();
         assert!(matches!(err, SecretResolveError::ResolutionFailed { .. }));
@@ -286,7 +304,7 @@ mod tests {
 
     #[mz_ore::test(tokio::test)]
     async fn test_resolve_unknown_function_passthrough() {
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
         let expr = make_function_expr("vault", vec![Expr::Value(Value::String("foo".to_string()))]);
         let original = format!("{}", expr);
         let resolved = resolver.resolve_expr(expr).await.unwrap();
@@ -295,7 +313,7 @@ mod tests {
 
     #[mz_ore::test(tokio::test)]
     async fn test_resolve_arbitrary_expr_passthrough() {
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
 
         // Number literal
         let expr = Expr::Value(Value::Number("42".to_string()));
@@ -311,7 +329,7 @@ mod tests {
 
     #[mz_ore::test(tokio::test)]
     async fn test_resolve_wrong_arg_count_zero() {
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
         let expr = make_function_expr("env_var", vec![]);
         let err = resolver.resolve_expr(expr).await.unwrap_err();
         match err {
@@ -330,7 +348,7 @@ mod tests {
 
     #[mz_ore::test(tokio::test)]
     async fn test_resolve_wrong_arg_count_two() {
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
         let expr = make_function_expr(
             "env_var",
             vec![
@@ -357,7 +375,7 @@ mod tests {
     async fn test_resolve_aws_secret_wrong_arg_count() {
         // aws_secret accepts 1 or 2 args; zero and three should both error
         // with a message that reflects the range.
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
 
         let expr = make_function_expr("aws_secret", vec![]);
         match resolver.resolve_expr(expr).await.unwrap_err() {
@@ -397,7 +415,7 @@ mod tests {
 
     #[mz_ore::test(tokio::test)]
     async fn test_resolve_non_literal_arg() {
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
         let expr = make_function_expr(
             "env_var",
             vec![Expr::Identifier(vec![Ident::new("col").unwrap()])],
@@ -408,7 +426,7 @@ mod tests {
 
     #[mz_ore::test(tokio::test)]
     async fn test_resolve_star_args() {
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
         let expr = Expr::Function(Function {
             name: RawItemName::Name(UnresolvedItemName(vec![Ident::new("env_var").unwrap()])),
             args: FunctionArgs::Star,
@@ -427,7 +445,7 @@ mod tests {
     async fn test_resolve_create_secret_with_env_var() {
         // SAFETY: test-only; no other thread reads this variable.
         unsafe { std::env::set_var("MZ_TEST_SECRET_456", "resolved_value") };
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
         let stmt = CreateSecretStatement::<Raw> {
             name: UnresolvedItemName(vec![Ident::new("my_secret").unwrap()]),
             if_not_exists: false,
@@ -443,7 +461,7 @@ mod tests {
 
     #[mz_ore::test(tokio::test)]
     async fn test_resolve_create_secret_plain_string() {
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
         let stmt = CreateSecretStatement::<Raw> {
             name: UnresolvedItemName(vec![Ident::new("my_secret").unwrap()]),
             if_not_exists: false,
@@ -458,7 +476,7 @@ mod tests {
 
     #[mz_ore::test(tokio::test)]
     async fn test_unconfigured_aws_provider_error() {
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
         let expr = make_function_expr("aws_secret", vec![Expr::Value(Value::String("foo".into()))]);
         let err = resolver.resolve_expr(expr).await.unwrap_err();
         match err {
@@ -478,7 +496,7 @@ mod tests {
     async fn test_aws_secret_passthrough_when_unconfigured() {
         // env_var still works even when AWS is unconfigured
         unsafe { std::env::set_var("MZ_TEST_AWS_PASSTHROUGH", "works") };
-        let resolver = SecretResolver::new(&Default::default());
+        let resolver = SecretResolver::new(&Default::default(), None);
         let expr = make_env_var_expr("MZ_TEST_AWS_PASSTHROUGH");
         let resolved = resolver.resolve_expr(expr).await.unwrap();
         match resolved {
@@ -486,4 +504,39 @@ mod tests {
             other => panic!("expected string literal, got: {:?}", other),
         }
     }
+
+    #[mz_ore::test(tokio::test)]
+    async fn test_unconfigured_vault_provider_error() {
+        let resolver = SecretResolver::new(&Default::default(), None);
+        let expr = make_function_expr(
+            "vault_secret",
+            vec![
+                Expr::Value(Value::String("app/kafka".into())),
+                Expr::Value(Value::String("password".into())),
+            ],
+        );
+        match resolver.resolve_expr(expr).await.unwrap_err() {
+            SecretResolveError::ResolutionFailed { name, reason } => {
+                assert_eq!(name, "vault_secret");
+                assert!(
+                    reason.contains("profiles.toml"),
+                    "error should mention profiles.toml, got: {}",
+                    reason
+                );
+            }
+            other => panic!("expected ResolutionFailed, got: {:?}", other),
+        }
+
+        let expr = make_function_expr(
+            "vault_secret",
+            vec![Expr::Value(Value::String("app/kafka".into()))],
+        );
+        match resolver.resolve_expr(expr).await.unwrap_err() {
+            SecretResolveError::WrongArgCount { expected, got, .. } => {
+                assert_eq!(expected, "2");
+                assert_eq!(got, 1);
+            }
+            other => panic!("expected WrongArgCount, got: {:?}", other),
+        }
+    }
 }
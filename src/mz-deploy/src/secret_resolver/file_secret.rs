@@ -0,0 +1,81 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! File secret provider.
+//!
+//! Resolves secret values by reading a file, such as a secret mounted into a
+//! CI job or Kubernetes pod.
+
+use super::{SecretProvider, SecretResolveError};
+use async_trait::async_trait;
+use std::ops::RangeInclusive;
+
+/// Resolves secrets from the contents of a file.
+///
+/// Usage in SQL: `CREATE SECRET x AS file_secret('/run/secrets/kafka_password')`
+///
+/// Relative paths resolve against the current working directory. A single
+/// trailing newline is stripped, since most tools that write secret files
+/// (`echo`, editors) add one.
+pub(super) struct FileSecretProvider;
+
+#[async_trait]
+impl SecretProvider for FileSecretProvider {
+    fn name(&self) -> &str {
+        "file_secret"
+    }
+
+    fn accepted_args(&self) -> RangeInclusive<usize> {
+        1..=1
+    }
+
+    async fn resolve(&self, args: &[String]) -> Result<String, SecretResolveError> {
+        let path = &args[0];
+        let contents = tokio::fs::read_to_string(path).await.map_err(|e| {
+            SecretResolveError::ResolutionFailed {
+                name: self.name().to_string(),
+                reason: format!("failed to read '{}': {}", path, e),
+            }
+        })?;
+        let value = contents
+            .strip_suffix('\n')
+            .map(|s| s.strip_suffix('\r').unwrap_or(s))
+            .unwrap_or(&contents);
+        Ok(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // touches the filesystem
+    async fn file_secret_strips_one_trailing_newline() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("password");
+        std::fs::write(&path, "s3cr3t\n\n").unwrap();
+
+        let value = FileSecretProvider
+            .resolve(&[path.display().to_string()])
+            .await
+            .unwrap();
+        assert_eq!(value, "s3cr3t\n");
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // touches the filesystem
+    async fn file_secret_missing_file() {
+        let err = FileSecretProvider
+            .resolve(&["/definitely/not/a/secret/file".to_string()])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SecretResolveError::ResolutionFailed { .. }));
+    }
+}
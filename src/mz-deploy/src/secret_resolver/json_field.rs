@@ -7,10 +7,10 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-//! Shared helper for extracting a top-level string field from a JSON secret.
+//! Shared helpers for extracting a top-level string field from a JSON secret.
 //!
 //! Used by providers that support the `name(secret, field)` shape — `aws_secret`
-//! for RDS-style credential blobs.
+//! for RDS-style credential blobs, and `vault_secret` for KV version 2 secrets.
 
 /// Extract a top-level string field from a JSON secret.
 ///
@@ -27,7 +27,15 @@ pub(super) fn extract_json_field(
             secret_name, json_key, e
         )
     })?;
+    string_field(&value, json_key, secret_name)
+}
 
+/// Extract a top-level string field from an already-parsed JSON secret.
+pub(super) fn string_field(
+    value: &serde_json::Value,
+    json_key: &str,
+    secret_name: &str,
+) -> Result<String, String> {
     let field = value
         .get(json_key)
         .ok_or_else(|| format!("secret '{}' has no field '{}'", secret_name, json_key))?;
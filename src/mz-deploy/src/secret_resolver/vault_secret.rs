@@ -0,0 +1,389 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! HashiCorp Vault secret provider.
+//!
+//! Resolves secret values by reading a field from a KV version 2 secret over
+//! Vault's HTTP API. Authenticates with either a static token or an AppRole
+//! login, as configured in the profile's `vault` table in `profiles.toml`.
+
+use super::json_field::string_field;
+use super::{SecretProvider, SecretResolveError};
+use crate::config::{VaultAuth, VaultConfig};
+use async_trait::async_trait;
+use reqwest::{Client, Method, RequestBuilder, StatusCode};
+use std::ops::RangeInclusive;
+use tokio::sync::OnceCell;
+
+/// Function name shared by both the real and unconfigured providers.
+const PROVIDER_NAME: &str = "vault_secret";
+
+/// Resolves secrets from a HashiCorp Vault KV version 2 engine.
+///
+/// Usage in SQL: `CREATE SECRET x AS vault_secret('app/kafka', 'password')`
+/// reads `<mount>/data/app/kafka` and returns its `password` field.
+///
+/// AppRole logins happen lazily on the first `resolve()` call, and the
+/// resulting token is reused for every later secret.
+pub(super) struct VaultSecretProvider {
+    config: VaultConfig,
+    http: Client,
+    token: OnceCell<String>,
+}
+
+impl VaultSecretProvider {
+    pub(super) fn new(config: &VaultConfig) -> Self {
+        Self {
+            config: config.clone(),
+            http: Client::new(),
+            token: OnceCell::new(),
+        }
+    }
+
+    fn failed(&self, reason: String) -> SecretResolveError {
+        SecretResolveError::ResolutionFailed {
+            name: PROVIDER_NAME.to_string(),
+            reason,
+        }
+    }
+
+    /// Start a request to `/v1/<path>` on the configured server.
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let url = format!("{}/v1/{}", self.config.address, path);
+        let request = self.http.request(method, url);
+        match &self.config.namespace {
+            Some(namespace) => request.header("X-Vault-Namespace", namespace),
+            None => request,
+        }
+    }
+
+    /// The Vault token to authenticate with, logging in first if needed.
+    async fn token(&self) -> Result<&str, SecretResolveError> {
+        let token = self
+            .token
+            .get_or_try_init(|| async {
+                match &self.config.auth {
+                    VaultAuth::Token(token) => Ok(token.clone()),
+                    VaultAuth::AppRole {
+                        mount,
+                        role_id,
+                        secret_id,
+                    } => self.login(mount, role_id, secret_id).await,
+                }
+            })
+            .await?;
+        Ok(token)
+    }
+
+    /// Exchange an AppRole role ID and secret ID for a client token.
+    async fn login(
+        &self,
+        mount: &str,
+        role_id: &str,
+        secret_id: &str,
+    ) -> Result<String, SecretResolveError> {
+        let response = self
+            .request(Method::POST, &format!("auth/{}/login", mount))
+            .json(&serde_json::json!({ "role_id": role_id, "secret_id": secret_id }))
+            .send()
+            .await
+            .map_err(|e| self.failed(format!("AppRole login failed: {}", e)))?;
+        let status = response.status();
+        let body: serde_json::Value = response.json().await.unwrap_or_default();
+        if !status.is_success() {
+            return Err(self.failed(format!(
+                "AppRole login failed with status {}{}",
+                status,
+                vault_errors(&body)
+            )));
+        }
+        body.pointer("/auth/client_token")
+            .and_then(|token| token.as_str())
+            .map(|token| token.to_string())
+            .ok_or_else(|| self.failed("AppRole login returned no client token".to_string()))
+    }
+}
+
+#[async_trait]
+impl SecretProvider for VaultSecretProvider {
+    fn name(&self) -> &str {
+        PROVIDER_NAME
+    }
+
+    fn accepted_args(&self) -> RangeInclusive<usize> {
+        2..=2
+    }
+
+    async fn resolve(&self, args: &[String]) -> Result<String, SecretResolveError> {
+        let (path, key) = (args[0].trim_matches('/'), &args[1]);
+        let token = self.token().await?;
+        let response = self
+            .request(Method::GET, &format!("{}/data/{}", self.config.mount, path))
+            .header("X-Vault-Token", token)
+            .send()
+            .await
+            .map_err(|e| self.failed(format!("failed to fetch secret '{}': {}", path, e)))?;
+        let status = response.status();
+        let body: serde_json::Value = response.json().await.unwrap_or_default();
+        if status == StatusCode::NOT_FOUND {
+            return Err(self.failed(format!(
+                "secret '{}' not found in KV mount '{}'",
+                path, self.config.mount
+            )));
+        }
+        if !status.is_success() {
+            return Err(self.failed(format!(
+                "failed to fetch secret '{}': status {}{}",
+                path,
+                status,
+                vault_errors(&body)
+            )));
+        }
+        let data = body.pointer("/data/data").ok_or_else(|| {
+            self.failed(format!(
+                "secret '{}' is not a KV version 2 secret; check the mount '{}'",
+                path, self.config.mount
+            ))
+        })?;
+        string_field(data, key, path).map_err(|reason| self.failed(reason))
+    }
+}
+
+/// Render the `errors` array of a Vault error response as `: a; b`, or an
+/// empty string if there is none.
+fn vault_errors(body: &serde_json::Value) -> String {
+    let errors: Vec<&str> = body
+        .get("errors")
+        .and_then(|errors| errors.as_array())
+        .map(|errors| errors.iter().filter_map(|e| e.as_str()).collect())
+        .unwrap_or_default();
+    if errors.is_empty() {
+        String::new()
+    } else {
+        format!(": {}", errors.join("; "))
+    }
+}
+
+/// Placeholder provider registered when the profile has no `vault` table.
+///
+/// Always returns an error directing the user to configure Vault.
+pub(super) struct UnconfiguredVaultProvider;
+
+#[async_trait]
+impl SecretProvider for UnconfiguredVaultProvider {
+    fn name(&self) -> &str {
+        PROVIDER_NAME
+    }
+
+    fn accepted_args(&self) -> RangeInclusive<usize> {
+        2..=2
+    }
+
+    async fn resolve(&self, _args: &[String]) -> Result<String, SecretResolveError> {
+        Err(SecretResolveError::ResolutionFailed {
+            name: self.name().to_string(),
+            reason: "HashiCorp Vault is not configured. Add a [<profile>.vault] table with 'address' and credentials to profiles.toml to enable vault_secret().".to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    /// A canned response from the mock Vault server. A request matches when
+    /// it starts with `request_line` and contains every string in `requires`
+    /// (compared case-insensitively, so header names match regardless of
+    /// how the client spells them).
+    struct Route {
+        request_line: &'static str,
+        requires: Vec<&'static str>,
+        status: u16,
+        body: &'static str,
+    }
+
+    /// Serve `routes` over HTTP on a local port and return the server's base
+    /// URL. Unmatched requests get a 403, like a Vault token without access.
+    async fn mock_vault(routes: Vec<Route>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        mz_ore::task::spawn(|| "mock-vault", async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let request = read_request(&mut stream).await.to_lowercase();
+                let (status, body) = routes
+                    .iter()
+                    .find(|route| {
+                        request.starts_with(&route.request_line.to_lowercase())
+                            && route
+                                .requires
+                                .iter()
+                                .all(|r| request.contains(&r.to_lowercase()))
+                    })
+                    .map(|route| (route.status, route.body))
+                    .unwrap_or((403, r#"{"errors":["permission denied"]}"#));
+                let response = format!(
+                    "HTTP/1.1 {} Mock\r\ncontent-type: application/json\r\n\
+                     content-length: {}\r\nconnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        address
+    }
+
+    /// Read one HTTP/1.1 request, including its body.
+    async fn read_request(stream: &mut TcpStream) -> String {
+        let mut buf = Vec::new();
+        let mut chunk = [0u8; 1024];
+        loop {
+            let n = stream.read(&mut chunk).await.unwrap();
+            if n == 0 {
+                break;
+            }
+            buf.extend_from_slice(&chunk[..n]);
+            let text = String::from_utf8_lossy(&buf);
+            let Some(head_end) = text.find("\r\n\r\n") else {
+                continue;
+            };
+            let content_length = text[..head_end]
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    if name.eq_ignore_ascii_case("content-length") {
+                        value.trim().parse::<usize>().ok()
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(0);
+            if buf.len() >= head_end + 4 + content_length {
+                break;
+            }
+        }
+        String::from_utf8_lossy(&buf).into_owned()
+    }
+
+    fn config(address: String, auth: VaultAuth) -> VaultConfig {
+        VaultConfig {
+            address,
+            namespace: None,
+            mount: "secret".to_string(),
+            auth,
+        }
+    }
+
+    const KAFKA_SECRET: &str =
+        r#"{"data":{"data":{"password":"hunter2","port":9092},"metadata":{"version":3}}}"#;
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // opens a TCP socket
+    async fn vault_secret_reads_kv2_field_with_token() {
+        let address = mock_vault(vec![Route {
+            request_line: "GET /v1/secret/data/app/kafka ",
+            requires: vec!["x-vault-token: root-token"],
+            status: 200,
+            body: KAFKA_SECRET,
+        }])
+        .await;
+        let provider =
+            VaultSecretProvider::new(&config(address, VaultAuth::Token("root-token".to_string())));
+
+        let value = provider
+            .resolve(&["/app/kafka".to_string(), "password".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(value, "hunter2");
+
+        let err = provider
+            .resolve(&["app/kafka".to_string(), "port".to_string()])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("is not a string"), "{err}");
+
+        let err = provider
+            .resolve(&["app/kafka".to_string(), "username".to_string()])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("has no field 'username'"), "{err}");
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // opens a TCP socket
+    async fn vault_secret_logs_in_with_approle() {
+        let address = mock_vault(vec![
+            Route {
+                request_line: "POST /v1/auth/ci-approle/login ",
+                requires: vec![
+                    "x-vault-namespace: team-a",
+                    r#""role_id":"role-1""#,
+                    r#""secret_id":"secret-1""#,
+                ],
+                status: 200,
+                body: r#"{"auth":{"client_token":"approle-token"}}"#,
+            },
+            Route {
+                request_line: "GET /v1/kv/data/app/kafka ",
+                requires: vec!["x-vault-token: approle-token", "x-vault-namespace: team-a"],
+                status: 200,
+                body: KAFKA_SECRET,
+            },
+        ])
+        .await;
+        let provider = VaultSecretProvider::new(&VaultConfig {
+            address,
+            namespace: Some("team-a".to_string()),
+            mount: "kv".to_string(),
+            auth: VaultAuth::AppRole {
+                mount: "ci-approle".to_string(),
+                role_id: "role-1".to_string(),
+                secret_id: "secret-1".to_string(),
+            },
+        });
+
+        let value = provider
+            .resolve(&["app/kafka".to_string(), "password".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(value, "hunter2");
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // opens a TCP socket
+    async fn vault_secret_reports_missing_secret_and_denied_access() {
+        let address = mock_vault(vec![Route {
+            request_line: "GET /v1/secret/data/missing ",
+            requires: vec![],
+            status: 404,
+            body: r#"{"errors":[]}"#,
+        }])
+        .await;
+        let provider =
+            VaultSecretProvider::new(&config(address, VaultAuth::Token("root-token".to_string())));
+
+        let err = provider
+            .resolve(&["missing".to_string(), "password".to_string()])
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("not found in KV mount 'secret'"),
+            "{err}"
+        );
+
+        let err = provider
+            .resolve(&["app/kafka".to_string(), "password".to_string()])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("permission denied"), "{err}");
+    }
+}
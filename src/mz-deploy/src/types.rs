@@ -193,6 +193,11 @@ pub struct Types {
     /// Object-level comments from `COMMENT ON` in the source database.
     #[serde(default)]
     pub comments: BTreeMap<ObjectId, String>,
+    /// Promoted output schemas of project objects consumed outside the
+    /// project (by sinks or other projects). The baseline for breaking-change
+    /// detection; see [`breaking`](crate::project::analysis::breaking).
+    #[serde(default)]
+    pub contracts: BTreeMap<ObjectId, BTreeMap<String, ColumnType>>,
 }
 
 impl Default for Types {
@@ -202,6 +207,7 @@ impl Default for Types {
             tables: BTreeMap::new(),
             kinds: BTreeMap::new(),
             comments: BTreeMap::new(),
+            contracts: BTreeMap::new(),
         }
     }
 }
@@ -224,6 +230,8 @@ struct TypesLock {
     secret: Vec<ObjectLock>,
     #[serde(default)]
     connection: Vec<ObjectLock>,
+    #[serde(default)]
+    contract: Vec<ObjectLock>,
 }
 
 impl Default for TypesLock {
@@ -237,6 +245,7 @@ impl Default for TypesLock {
             sink: vec![],
             secret: vec![],
             connection: vec![],
+            contract: vec![],
         }
     }
 }
@@ -312,20 +321,11 @@ impl From<&Types> for TypesLock {
             sink: Vec::new(),
             secret: Vec::new(),
             connection: Vec::new(),
+            contract: Vec::new(),
         };
 
         for (id, columns) in &types.tables {
-            let mut cols: Vec<_> = columns.iter().collect();
-            cols.sort_by_key(|(_, ct)| ct.position);
-            let cols: Vec<ColumnLock> = cols
-                .into_iter()
-                .map(|(col_name, col_type)| ColumnLock {
-                    name: col_name.clone(),
-                    r#type: col_type.r#type.clone(),
-                    nullable: col_type.nullable,
-                    comment: col_type.comment.clone(),
-                })
-                .collect();
+            let cols = columns_to_lock(columns);
 
             let kind = types
                 .kinds
@@ -342,10 +342,51 @@ impl From<&Types> for TypesLock {
             lock.vec_for_kind(*kind).push(obj);
         }
 
+        for (id, columns) in &types.contracts {
+            lock.contract.push(ObjectLock {
+                name: id.clone(),
+                comment: None,
+                columns: columns_to_lock(columns),
+            });
+        }
+
         lock
     }
 }
 
+/// Convert a column map into lock entries ordered by position.
+fn columns_to_lock(columns: &BTreeMap<String, ColumnType>) -> Vec<ColumnLock> {
+    let mut cols: Vec<_> = columns.iter().collect();
+    cols.sort_by_key(|(_, ct)| ct.position);
+    cols.into_iter()
+        .map(|(col_name, col_type)| ColumnLock {
+            name: col_name.clone(),
+            r#type: col_type.r#type.clone(),
+            nullable: col_type.nullable,
+            comment: col_type.comment.clone(),
+        })
+        .collect()
+}
+
+/// Convert lock entries into a column map, numbering positions in file order.
+fn columns_from_lock(columns: &[ColumnLock]) -> BTreeMap<String, ColumnType> {
+    columns
+        .iter()
+        .enumerate()
+        .map(|(position, col)| {
+            (
+                col.name.clone(),
+                ColumnType {
+                    r#type: col.r#type.clone(),
+                    nullable: col.nullable,
+                    position,
+                    comment: col.comment.clone(),
+                },
+            )
+        })
+        .collect()
+}
+
 impl From<TypesLock> for Types {
     fn from(lock: TypesLock) -> Self {
         let mut tables = BTreeMap::new();
@@ -354,18 +395,7 @@ impl From<TypesLock> for Types {
 
         for (kind, obj) in lock.all_objects() {
             let id = obj.name.clone();
-            let mut columns = BTreeMap::new();
-            for (position, col) in obj.columns.iter().enumerate() {
-                columns.insert(
-                    col.name.clone(),
-                    ColumnType {
-                        r#type: col.r#type.clone(),
-                        nullable: col.nullable,
-                        position,
-                        comment: col.comment.clone(),
-                    },
-                );
-            }
+            let columns = columns_from_lock(&obj.columns);
             kinds.insert(id.clone(), kind);
             if let Some(comment) = &obj.comment {
                 comments.insert(id.clone(), comment.clone());
@@ -373,11 +403,18 @@ impl From<TypesLock> for Types {
             tables.insert(id, columns);
         }
 
+        let contracts = lock
+            .contract
+            .iter()
+            .map(|obj| (obj.name.clone(), columns_from_lock(&obj.columns)))
+            .collect();
+
         Types {
             version: lock.version,
             tables,
             kinds,
             comments,
+            contracts,
         }
     }
 }
@@ -418,10 +455,15 @@ fn write_toml(lock: &TypesLock) -> String {
         (ObjectKind::Sink, &lock.sink),
     ];
 
-    for (kind, objs) in sections {
-        for obj in *objs {
+    let sections = sections
+        .iter()
+        .map(|(kind, objs)| (kind.as_str(), *objs))
+        .chain(std::iter::once(("contract", &lock.contract)));
+
+    for (section, objs) in sections {
+        for obj in objs {
             out.push('\n');
-            out.push_str(&format!("[[{}]]\n", kind.as_str()));
+            out.push_str(&format!("[[{}]]\n", section));
             out.push_str(&format!(
                 "name = \"{}\"\n",
                 escape_toml_string(&obj.name.to_string())
@@ -565,6 +607,7 @@ mod tests {
             tables,
             kinds,
             comments: BTreeMap::new(),
+            contracts: BTreeMap::new(),
         };
 
         let dir = tempfile::tempdir().expect("failed to create temp dir");
@@ -613,6 +656,7 @@ mod tests {
             tables,
             kinds,
             comments: BTreeMap::new(),
+            contracts: BTreeMap::new(),
         };
 
         let dir = tempfile::tempdir().expect("failed to create temp dir");
@@ -665,6 +709,45 @@ mod tests {
             tables,
             kinds,
             comments,
+            contracts: BTreeMap::new(),
+        };
+
+        let dir = tempfile::tempdir().expect("failed to create temp dir");
+        types
+            .write_types_lock(dir.path())
+            .expect("failed to write types.lock");
+
+        let loaded = load_types_lock(dir.path()).expect("failed to load types.lock");
+        assert_eq!(types, loaded);
+    }
+
+    #[mz_ore::test]
+    fn test_round_trip_with_contracts() {
+        let mut cols = BTreeMap::new();
+        cols.insert(
+            "id".to_string(),
+            ColumnType {
+                r#type: "int4".to_string(),
+                nullable: false,
+                position: 0,
+                comment: None,
+            },
+        );
+        cols.insert(
+            "total".to_string(),
+            ColumnType {
+                r#type: "numeric".to_string(),
+                nullable: true,
+                position: 1,
+                comment: None,
+            },
+        );
+        let mut contracts = BTreeMap::new();
+        contracts.insert("app.public.order_totals".parse::<ObjectId>().unwrap(), cols);
+
+        let types = Types {
+            contracts,
+            ..Default::default()
         };
 
         let dir = tempfile::tempdir().expect("failed to create temp dir");
@@ -672,8 +755,12 @@ mod tests {
             .write_types_lock(dir.path())
             .expect("failed to write types.lock");
 
+        let contents = fs::read_to_string(dir.path().join("types.lock")).unwrap();
+        assert!(contents.contains("[[contract]]\nname = \"app.public.order_totals\""));
+
         let loaded = load_types_lock(dir.path()).expect("failed to load types.lock");
         assert_eq!(types, loaded);
+        assert!(loaded.tables.is_empty());
     }
 
     #[mz_ore::test]
@@ -436,6 +436,8 @@ pub enum CopyFormatParams<'a> {
     Csv(CopyCsvFormatParams<'a>),
     Binary,
     Parquet,
+    Json(CopyJsonFormatParams),
+    Avro,
 }
 
 impl CopyFormatParams<'static> {
@@ -445,6 +447,8 @@ impl CopyFormatParams<'static> {
             &CopyFormatParams::Csv(_) => "csv",
             &CopyFormatParams::Binary => "bin",
             &CopyFormatParams::Parquet => "parquet",
+            &CopyFormatParams::Json(_) => "json",
+            &CopyFormatParams::Avro => "avro",
         }
     }
 
@@ -454,6 +458,8 @@ impl CopyFormatParams<'static> {
             CopyFormatParams::Csv(params) => params.header,
             CopyFormatParams::Binary => false,
             CopyFormatParams::Parquet => false,
+            CopyFormatParams::Json(_) => false,
+            CopyFormatParams::Avro => false,
         }
     }
 }
@@ -475,6 +481,8 @@ pub fn decode_copy_format<'a>(
             // TODO(cf2): Support Parquet over STDIN.
             Err(io::Error::new(io::ErrorKind::Unsupported, "parquet format"))
         }
+        CopyFormatParams::Json(_) => Err(io::Error::new(io::ErrorKind::Unsupported, "json format")),
+        CopyFormatParams::Avro => Err(io::Error::new(io::ErrorKind::Unsupported, "avro format")),
     }
 }
 
@@ -498,6 +506,8 @@ pub fn encode_copy_format<'a>(
             // TODO(cf2): Support Parquet over STDIN.
             Err(io::Error::new(io::ErrorKind::Unsupported, "parquet format"))
         }
+        CopyFormatParams::Json(_) => Err(io::Error::new(io::ErrorKind::Unsupported, "json format")),
+        CopyFormatParams::Avro => Err(io::Error::new(io::ErrorKind::Unsupported, "avro format")),
     }
 }
 
@@ -527,6 +537,8 @@ pub fn encode_copy_format_header<'a>(
             // TODO(cf2): Support Parquet over STDIN.
             Err(io::Error::new(io::ErrorKind::Unsupported, "parquet format"))
         }
+        CopyFormatParams::Json(_) => Err(io::Error::new(io::ErrorKind::Unsupported, "json format")),
+        CopyFormatParams::Avro => Err(io::Error::new(io::ErrorKind::Unsupported, "avro format")),
     }
 }
 
@@ -686,6 +698,14 @@ impl<'a> CopyCsvFormatParams<'a> {
     }
 }
 
+/// Parameters for decoding newline-delimited JSON.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct CopyJsonFormatParams {
+    /// Load each JSON value into a single `jsonb` column, instead of mapping
+    /// the top-level keys of each object to columns.
+    pub single_column: bool,
+}
+
 /// One field decoded out of a CSV record by [`decode_copy_format_csv`]:
 /// `start..end` indexes into the per-record `output` buffer (csv-core
 /// unquotes/unescapes into that buffer), and `quoted` records whether the
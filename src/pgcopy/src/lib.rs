@@ -16,6 +16,6 @@
 mod copy;
 
 pub use copy::{
-    CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams, CopyTextFormatParser,
-    decode_copy_format, encode_copy_format, encode_copy_format_header,
+    CopyCsvFormatParams, CopyFormatParams, CopyJsonFormatParams, CopyTextFormatParams,
+    CopyTextFormatParser, decode_copy_format, encode_copy_format, encode_copy_format_header,
 };
@@ -22,6 +22,7 @@ pub const TYPE_ANYCOMPATIBLE_OID: u32 = 5077;
 pub const TYPE_ANYCOMPATIBLEARRAY_OID: u32 = 5078;
 pub const TYPE_ANYCOMPATIBLENONARRAY_OID: u32 = 5079;
 pub const TYPE_ANYELEMENT_OID: u32 = 2283;
+pub const TYPE_ANYENUM_OID: u32 = 3500;
 pub const TYPE_ANYNONARRAY_OID: u32 = 2776;
 pub const TYPE_BOOL_ARRAY_OID: u32 = 1000;
 pub const TYPE_BOOL_OID: u32 = 16;
@@ -31,12 +32,16 @@ pub const TYPE_BYTEA_ARRAY_OID: u32 = 1001;
 pub const TYPE_BYTEA_OID: u32 = 17;
 pub const TYPE_CHAR_ARRAY_OID: u32 = 1002;
 pub const TYPE_CHAR_OID: u32 = 18;
+pub const TYPE_CIDR_ARRAY_OID: u32 = 651;
+pub const TYPE_CIDR_OID: u32 = 650;
 pub const TYPE_DATE_ARRAY_OID: u32 = 1182;
 pub const TYPE_DATE_OID: u32 = 1082;
 pub const TYPE_FLOAT4_ARRAY_OID: u32 = 1021;
 pub const TYPE_FLOAT4_OID: u32 = 700;
 pub const TYPE_FLOAT8_ARRAY_OID: u32 = 1022;
 pub const TYPE_FLOAT8_OID: u32 = 701;
+pub const TYPE_INET_ARRAY_OID: u32 = 1041;
+pub const TYPE_INET_OID: u32 = 869;
 pub const TYPE_INT2_ARRAY_OID: u32 = 1005;
 pub const TYPE_INT2_OID: u32 = 21;
 pub const TYPE_INT2_VECTOR_ARRAY_OID: u32 = 1006;
@@ -50,6 +55,8 @@ pub const TYPE_INTERVAL_OID: u32 = 1186;
 pub const TYPE_JSONB_ARRAY_OID: u32 = 3807;
 pub const TYPE_JSONB_OID: u32 = 3802;
 pub const TYPE_LIST_OID_OID: u32 = 16_384;
+pub const TYPE_MACADDR_ARRAY_OID: u32 = 1040;
+pub const TYPE_MACADDR_OID: u32 = 829;
 pub const TYPE_NAME_ARRAY_OID: u32 = 1003;
 pub const TYPE_NAME_OID: u32 = 19;
 pub const TYPE_NUMERIC_ARRAY_OID: u32 = 1231;
@@ -90,6 +97,19 @@ pub const TYPE_TSRANGE_OID: u32 = 3908;
 pub const TYPE_TSRANGE_ARRAY_OID: u32 = 3909;
 pub const TYPE_TSTZRANGE_OID: u32 = 3910;
 pub const TYPE_TSTZRANGE_ARRAY_OID: u32 = 3911;
+pub const TYPE_INT4MULTIRANGE_OID: u32 = 4451;
+pub const TYPE_INT4MULTIRANGE_ARRAY_OID: u32 = 6150;
+pub const TYPE_NUMMULTIRANGE_OID: u32 = 4532;
+pub const TYPE_NUMMULTIRANGE_ARRAY_OID: u32 = 6151;
+pub const TYPE_TSMULTIRANGE_OID: u32 = 4533;
+pub const TYPE_TSMULTIRANGE_ARRAY_OID: u32 = 6152;
+pub const TYPE_TSTZMULTIRANGE_OID: u32 = 4534;
+pub const TYPE_TSTZMULTIRANGE_ARRAY_OID: u32 = 6153;
+pub const TYPE_DATEMULTIRANGE_OID: u32 = 4535;
+pub const TYPE_DATEMULTIRANGE_ARRAY_OID: u32 = 6155;
+pub const TYPE_INT8MULTIRANGE_OID: u32 = 4536;
+pub const TYPE_INT8MULTIRANGE_ARRAY_OID: u32 = 6157;
+pub const TYPE_ANYMULTIRANGE_OID: u32 = 4537;
 pub const FUNC_NORMALIZE_OID: u32 = 4350;
 
 /// The first OID in PostgreSQL's system catalog that is not pinned during
@@ -822,3 +842,7 @@ pub const FUNC_PARSE_CONNECTION_DETAILS_OID: u32 = 17112;
 pub const FUNC_MZ_AWS_ACCOUNT_ID_OID: u32 = 17113;
 pub const FUNC_MZ_AWS_EXTERNAL_ID_PREFIX_OID: u32 = 17114;
 pub const FUNC_MZ_AWS_CONNECTION_ROLE_ARN_OID: u32 = 17115;
+pub const FUNC_APPROX_COUNT_DISTINCT_OID: u32 = 17116;
+pub const FUNC_HLL_SKETCH_AGG_OID: u32 = 17117;
+pub const FUNC_HLL_UNION_AGG_OID: u32 = 17118;
+pub const FUNC_HLL_CARDINALITY_OID: u32 = 17119;
@@ -28,6 +28,7 @@ pub use types::{
     ANYCOMPATIBLELIST, ANYCOMPATIBLEMAP, LIST, MAP, Type, TypeConversionError, TypeFromOidError,
 };
 pub use value::error::{IntoDatumError, NulCharacterError};
+pub use value::inet::{Inet, MacAddr};
 pub use value::interval::Interval;
 pub use value::jsonb::Jsonb;
 pub use value::numeric::Numeric;
@@ -142,6 +142,12 @@ pub enum Type {
     },
     /// A universally unique identifier.
     Uuid,
+    /// An IPv4 or IPv6 host address, with an optional subnet.
+    Inet,
+    /// An IPv4 or IPv6 network.
+    Cidr,
+    /// A MAC address.
+    MacAddr,
     /// A function name.
     RegProc,
     /// A type name.
@@ -157,12 +163,24 @@ pub enum Type {
         /// The domain type.
         element_type: Box<Type>,
     },
+    /// A set of non-overlapping ranges of values of the inner type.
+    MultiRange {
+        /// The domain type.
+        element_type: Box<Type>,
+    },
     /// A list of privileges granted to a user, that uses [`mz_repr::role_id::RoleId`]s for role
     /// references.
     MzAclItem,
     /// A list of privileges granted to a user that uses [`mz_repr::adt::system::Oid`]s for role
     /// references. This type is used primarily for compatibility with PostgreSQL.
     AclItem,
+    /// A value of a user-defined enumerated type.
+    Enum {
+        /// The OID of the enum type.
+        oid: u32,
+        /// The labels of the enum type, in declaration order.
+        labels: Box<[String]>,
+    },
 }
 
 /// An unpacked [`typmod`](Type::typmod) for a [`Type`].
@@ -568,6 +586,9 @@ impl Type {
             postgres_types::Type::TIMESTAMP => Type::Timestamp { precision: None },
             postgres_types::Type::TIMESTAMPTZ => Type::TimestampTz { precision: None },
             postgres_types::Type::UUID => Type::Uuid,
+            postgres_types::Type::INET => Type::Inet,
+            postgres_types::Type::CIDR => Type::Cidr,
+            postgres_types::Type::MACADDR => Type::MacAddr,
             postgres_types::Type::REGCLASS => Type::RegClass,
             postgres_types::Type::REGPROC => Type::RegProc,
             postgres_types::Type::REGTYPE => Type::RegType,
@@ -606,6 +627,9 @@ impl Type {
                 Type::Array(Box::new(Type::TimestampTz { precision: None }))
             }
             postgres_types::Type::UUID_ARRAY => Type::Array(Box::new(Type::Uuid)),
+            postgres_types::Type::INET_ARRAY => Type::Array(Box::new(Type::Inet)),
+            postgres_types::Type::CIDR_ARRAY => Type::Array(Box::new(Type::Cidr)),
+            postgres_types::Type::MACADDR_ARRAY => Type::Array(Box::new(Type::MacAddr)),
             postgres_types::Type::VARCHAR_ARRAY => {
                 Type::Array(Box::new(Type::VarChar { max_length: None }))
             }
@@ -650,6 +674,42 @@ impl Type {
             postgres_types::Type::DATE_RANGE_ARRAY => Type::Array(Box::new(Type::Range {
                 element_type: Box::new(Type::Date),
             })),
+            postgres_types::Type::INT4MULTI_RANGE => Type::MultiRange {
+                element_type: Box::new(Type::Int4),
+            },
+            postgres_types::Type::INT4MULTI_RANGE_ARRAY => Type::Array(Box::new(Type::MultiRange {
+                element_type: Box::new(Type::Int4),
+            })),
+            postgres_types::Type::INT8MULTI_RANGE => Type::MultiRange {
+                element_type: Box::new(Type::Int8),
+            },
+            postgres_types::Type::INT8MULTI_RANGE_ARRAY => Type::Array(Box::new(Type::MultiRange {
+                element_type: Box::new(Type::Int8),
+            })),
+            postgres_types::Type::NUMMULTI_RANGE => Type::MultiRange {
+                element_type: Box::new(Type::Numeric { constraints: None }),
+            },
+            postgres_types::Type::NUMMULTI_RANGE_ARRAY => Type::Array(Box::new(Type::MultiRange {
+                element_type: Box::new(Type::Numeric { constraints: None }),
+            })),
+            postgres_types::Type::TSMULTI_RANGE => Type::MultiRange {
+                element_type: Box::new(Type::Timestamp { precision: None }),
+            },
+            postgres_types::Type::TSMULTI_RANGE_ARRAY => Type::Array(Box::new(Type::MultiRange {
+                element_type: Box::new(Type::Timestamp { precision: None }),
+            })),
+            postgres_types::Type::TSTZMULTI_RANGE => Type::MultiRange {
+                element_type: Box::new(Type::TimestampTz { precision: None }),
+            },
+            postgres_types::Type::TSTZMULTI_RANGE_ARRAY => Type::Array(Box::new(Type::MultiRange {
+                element_type: Box::new(Type::TimestampTz { precision: None }),
+            })),
+            postgres_types::Type::DATEMULTI_RANGE => Type::MultiRange {
+                element_type: Box::new(Type::Date),
+            },
+            postgres_types::Type::DATEMULTI_RANGE_ARRAY => Type::Array(Box::new(Type::MultiRange {
+                element_type: Box::new(Type::Date),
+            })),
             _ => return Err(TypeFromOidError::UnknownOid(oid)),
         };
 
@@ -731,6 +791,9 @@ impl Type {
                 Type::Timestamp { .. } => &postgres_types::Type::TIMESTAMP_ARRAY,
                 Type::TimestampTz { .. } => &postgres_types::Type::TIMESTAMPTZ_ARRAY,
                 Type::Uuid => &postgres_types::Type::UUID_ARRAY,
+                Type::Inet => &postgres_types::Type::INET_ARRAY,
+                Type::Cidr => &postgres_types::Type::CIDR_ARRAY,
+                Type::MacAddr => &postgres_types::Type::MACADDR_ARRAY,
                 Type::RegClass => &postgres_types::Type::REGCLASS_ARRAY,
                 Type::RegProc => &postgres_types::Type::REGPROC_ARRAY,
                 Type::RegType => &postgres_types::Type::REGTYPE_ARRAY,
@@ -745,7 +808,17 @@ impl Type {
                     Type::Date => &postgres_types::Type::DATE_RANGE_ARRAY,
                     _ => unreachable!(),
                 },
+                Type::MultiRange { element_type } => match **element_type {
+                    Type::Int4 => &postgres_types::Type::INT4MULTI_RANGE_ARRAY,
+                    Type::Int8 => &postgres_types::Type::INT8MULTI_RANGE_ARRAY,
+                    Type::Numeric { .. } => &postgres_types::Type::NUMMULTI_RANGE_ARRAY,
+                    Type::Timestamp { .. } => &postgres_types::Type::TSMULTI_RANGE_ARRAY,
+                    Type::TimestampTz { .. } => &postgres_types::Type::TSTZMULTI_RANGE_ARRAY,
+                    Type::Date => &postgres_types::Type::DATEMULTI_RANGE_ARRAY,
+                    _ => unreachable!(),
+                },
                 Type::MzAclItem => &MZ_ACL_ITEM_ARRAY,
+                Type::Enum { .. } => unreachable!(),
             },
             Type::Bool => &postgres_types::Type::BOOL,
             Type::Bytea => &postgres_types::Type::BYTEA,
@@ -776,6 +849,9 @@ impl Type {
             Type::Timestamp { .. } => &postgres_types::Type::TIMESTAMP,
             Type::TimestampTz { .. } => &postgres_types::Type::TIMESTAMPTZ,
             Type::Uuid => &postgres_types::Type::UUID,
+            Type::Inet => &postgres_types::Type::INET,
+            Type::Cidr => &postgres_types::Type::CIDR,
+            Type::MacAddr => &postgres_types::Type::MACADDR,
             Type::RegClass => &postgres_types::Type::REGCLASS,
             Type::RegProc => &postgres_types::Type::REGPROC,
             Type::RegType => &postgres_types::Type::REGTYPE,
@@ -790,7 +866,19 @@ impl Type {
                 Type::Date => &postgres_types::Type::DATE_RANGE,
                 t => unreachable!("{t:?} is not a range element type"),
             },
+            Type::MultiRange { element_type } => match &**element_type {
+                Type::Int4 => &postgres_types::Type::INT4MULTI_RANGE,
+                Type::Int8 => &postgres_types::Type::INT8MULTI_RANGE,
+                Type::Numeric { .. } => &postgres_types::Type::NUMMULTI_RANGE,
+                Type::Timestamp { .. } => &postgres_types::Type::TSMULTI_RANGE,
+                Type::TimestampTz { .. } => &postgres_types::Type::TSTZMULTI_RANGE,
+                Type::Date => &postgres_types::Type::DATEMULTI_RANGE,
+                t => unreachable!("{t:?} is not a range element type"),
+            },
             Type::MzAclItem => &MZ_ACL_ITEM,
+            // Enum types are defined by users, so there is no static type to
+            // return. Their OID is reported by `Type::oid`.
+            Type::Enum { .. } => &postgres_types::Type::ANYENUM,
         }
     }
 
@@ -825,6 +913,9 @@ impl Type {
             &postgres_types::Type::TIMESTAMP_ARRAY => "timestamp without time zone[]",
             &postgres_types::Type::TIMESTAMPTZ_ARRAY => "timestamp with time zone[]",
             &postgres_types::Type::UUID_ARRAY => "uuid[]",
+            &postgres_types::Type::INET_ARRAY => "inet[]",
+            &postgres_types::Type::CIDR_ARRAY => "cidr[]",
+            &postgres_types::Type::MACADDR_ARRAY => "macaddr[]",
             &postgres_types::Type::VARCHAR_ARRAY => "character varying[]",
             &postgres_types::Type::BOOL => "boolean",
             &postgres_types::Type::BPCHAR => "character",
@@ -857,7 +948,10 @@ impl Type {
     ///
     /// [OID]: https://www.postgresql.org/docs/current/datatype-oid.html
     pub fn oid(&self) -> u32 {
-        self.inner().oid()
+        match self {
+            Type::Enum { oid, .. } => *oid,
+            _ => self.inner().oid(),
+        }
     }
 
     /// Returns the constraint on the type, if any.
@@ -921,10 +1015,15 @@ impl Type {
             | Type::Timestamp { precision: None }
             | Type::TimestampTz { precision: None }
             | Type::Uuid
+            | Type::Inet
+            | Type::Cidr
+            | Type::MacAddr
             | Type::MzTimestamp
             | Type::VarChar { max_length: None }
             | Type::Range { .. }
-            | Type::MzAclItem => None,
+            | Type::MultiRange { .. }
+            | Type::MzAclItem
+            | Type::Enum { .. } => None,
         }
     }
 
@@ -962,6 +1061,9 @@ impl Type {
             Type::Timestamp { .. } => 8,
             Type::TimestampTz { .. } => 8,
             Type::Uuid => 16,
+            Type::Inet => -1,
+            Type::Cidr => -1,
+            Type::MacAddr => 6,
             Type::RegClass => 4,
             Type::RegProc => 4,
             Type::RegType => 4,
@@ -972,8 +1074,10 @@ impl Type {
                 .try_into()
                 .expect("must fit"),
             Type::Range { .. } => -1,
+            Type::MultiRange { .. } => -1,
             Type::MzAclItem => MzAclItem::binary_size().try_into().expect("must fit"),
             Type::AclItem => AclItem::binary_size().try_into().expect("must fit"),
+            Type::Enum { .. } => 4,
         }
     }
 
@@ -1097,6 +1201,9 @@ impl TryFrom<&Type> for SqlScalarType {
                 },
             }),
             Type::Uuid => Ok(SqlScalarType::Uuid),
+            Type::Inet => Ok(SqlScalarType::Inet),
+            Type::Cidr => Ok(SqlScalarType::Cidr),
+            Type::MacAddr => Ok(SqlScalarType::MacAddr),
             Type::RegClass => Ok(SqlScalarType::RegClass),
             Type::RegProc => Ok(SqlScalarType::RegProc),
             Type::RegType => Ok(SqlScalarType::RegType),
@@ -1105,7 +1212,12 @@ impl TryFrom<&Type> for SqlScalarType {
             Type::Range { element_type } => Ok(SqlScalarType::Range {
                 element_type: Box::new(TryFrom::try_from(&**element_type)?),
             }),
+            Type::MultiRange { element_type } => Ok(SqlScalarType::MultiRange {
+                element_type: Box::new(TryFrom::try_from(&**element_type)?),
+            }),
             Type::MzAclItem => Ok(SqlScalarType::MzAclItem),
+            // The catalog ID of an enum type cannot be recovered from its OID.
+            Type::Enum { .. } => Err(TypeConversionError::UnsupportedType(typ.clone())),
         }
     }
 }
@@ -1256,6 +1368,9 @@ impl From<&SqlScalarType> for Type {
                 precision: (*precision).map(TimestampPrecision::from),
             },
             SqlScalarType::Uuid => Type::Uuid,
+            SqlScalarType::Inet => Type::Inet,
+            SqlScalarType::Cidr => Type::Cidr,
+            SqlScalarType::MacAddr => Type::MacAddr,
             SqlScalarType::Numeric { max_scale } => Type::Numeric {
                 constraints: max_scale.map(|max_scale| NumericConstraints {
                     max_precision: i32::from(NUMERIC_DATUM_MAX_PRECISION),
@@ -1270,7 +1385,16 @@ impl From<&SqlScalarType> for Type {
             SqlScalarType::Range { element_type } => Type::Range {
                 element_type: Box::new(From::from(&**element_type)),
             },
+            SqlScalarType::MultiRange { element_type } => Type::MultiRange {
+                element_type: Box::new(From::from(&**element_type)),
+            },
             SqlScalarType::MzAclItem => Type::MzAclItem,
+            SqlScalarType::Enum {
+                custom_oid, labels, ..
+            } => Type::Enum {
+                oid: *custom_oid,
+                labels: labels.clone(),
+            },
         }
     }
 }
@@ -15,7 +15,7 @@ use bytes::{BufMut, BytesMut};
 use chrono::{DateTime, NaiveDateTime, NaiveTime, Utc};
 use dec::OrderedDecimal;
 use itertools::Itertools;
-use mz_ore::cast::ReinterpretCast;
+use mz_ore::cast::{CastFrom, ReinterpretCast};
 use mz_ore::fmt::FormatBuffer;
 use mz_pgrepr_consts::oid::TYPE_INT2_OID;
 use mz_pgwire_common::Format;
@@ -35,9 +35,10 @@ use uuid::Uuid;
 
 use crate::types::{NumericConstraints, UINT2, UINT4, UINT8};
 use crate::value::error::{IntoDatumError, NulCharacterError};
-use crate::{Interval, Jsonb, Numeric, Type, UInt2, UInt4, UInt8};
+use crate::{Inet, Interval, Jsonb, MacAddr, Numeric, Type, UInt2, UInt4, UInt8};
 
 pub mod error;
+pub mod inet;
 pub mod interval;
 pub mod jsonb;
 pub mod numeric;
@@ -108,6 +109,12 @@ pub enum Value {
     VarChar(String),
     /// A universally unique identifier.
     Uuid(Uuid),
+    /// An IPv4 or IPv6 host address, with an optional subnet.
+    Inet(Inet),
+    /// An IPv4 or IPv6 network.
+    Cidr(Inet),
+    /// A MAC address.
+    MacAddr(MacAddr),
     /// A small int vector.
     Int2Vector {
         /// The elements of the vector.
@@ -117,12 +124,16 @@ pub enum Value {
     MzTimestamp(mz_repr::Timestamp),
     /// A contiguous range of values along a domain.
     Range(Range<Box<Value>>),
+    /// A set of non-overlapping ranges along a domain.
+    MultiRange(Vec<Range<Box<Value>>>),
     /// A list of privileges granted to a role, that uses [`mz_repr::role_id::RoleId`]s for role
     /// references.
     MzAclItem(MzAclItem),
     /// A list of privileges granted to a user that uses [`mz_repr::adt::system::Oid`]s for role
     /// references. This type is used primarily for compatibility with PostgreSQL.
     AclItem(AclItem),
+    /// A label of a user-defined enumerated type.
+    Enum(String),
 }
 
 impl Value {
@@ -152,6 +163,9 @@ impl Value {
             (Datum::MzTimestamp(t), SqlScalarType::MzTimestamp) => Some(Value::MzTimestamp(t)),
             (Datum::MzAclItem(mai), SqlScalarType::MzAclItem) => Some(Value::MzAclItem(mai)),
             (Datum::AclItem(ai), SqlScalarType::AclItem) => Some(Value::AclItem(ai)),
+            (Datum::UInt32(ordinal), SqlScalarType::Enum { labels, .. }) => {
+                Some(Value::Enum(labels[usize::cast_from(ordinal)].clone()))
+            }
             (Datum::Date(d), SqlScalarType::Date) => Some(Value::Date(d)),
             (Datum::Time(t), SqlScalarType::Time) => Some(Value::Time(t)),
             (Datum::Timestamp(ts), SqlScalarType::Timestamp { .. }) => Some(Value::Timestamp(ts)),
@@ -170,6 +184,9 @@ impl Value {
                 Some(Value::Jsonb(Jsonb(JsonbRef::from_datum(datum).to_owned())))
             }
             (Datum::Uuid(u), SqlScalarType::Uuid) => Some(Value::Uuid(u)),
+            (Datum::Inet(i), SqlScalarType::Inet) => Some(Value::Inet(Inet(i))),
+            (Datum::Inet(i), SqlScalarType::Cidr) => Some(Value::Cidr(Inet(i))),
+            (Datum::MacAddr(m), SqlScalarType::MacAddr) => Some(Value::MacAddr(MacAddr(m))),
             (Datum::Array(array), SqlScalarType::Array(elem_type)) => {
                 let dims = array.dims().into_iter().collect();
                 let elements = array
@@ -222,6 +239,20 @@ impl Value {
                 });
                 Some(Value::Range(value_range))
             }
+            (Datum::List(ranges), SqlScalarType::MultiRange { element_type }) => {
+                let ranges = ranges
+                    .iter()
+                    .map(|range| {
+                        range.unwrap_range().into_bounds(|b| {
+                            Box::new(
+                                Value::from_datum(b, element_type)
+                                    .expect("RangeBounds never contain Datum::Null"),
+                            )
+                        })
+                    })
+                    .collect();
+                Some(Value::MultiRange(ranges))
+            }
             _ => panic!("can't serialize {}::{:?}", datum, typ),
         }
     }
@@ -320,6 +351,8 @@ impl Value {
             }
             Value::BpChar(s) => Datum::String(buf.push_string(s.trim_end().into())),
             Value::Uuid(u) => Datum::Uuid(u),
+            Value::Inet(i) | Value::Cidr(i) => Datum::Inet(i.0),
+            Value::MacAddr(m) => Datum::MacAddr(m.0),
             Value::Numeric(n) => Datum::Numeric(n.0),
             Value::MzTimestamp(t) => Datum::MzTimestamp(t),
             Value::Range(range) => {
@@ -330,8 +363,33 @@ impl Value {
                 let range = range.try_into_bounds(|elem| elem.into_datum(buf, elem_pg_type))?;
                 buf.try_make_datum(|packer| packer.push_range(range).map_err(IntoDatumError::from))?
             }
+            Value::MultiRange(ranges) => {
+                let elem_pg_type = match typ {
+                    Type::MultiRange { element_type } => &*element_type,
+                    _ => panic!(
+                        "Value::MultiRange should have type Type::MultiRange. Found {:?}",
+                        typ
+                    ),
+                };
+                let ranges = ranges
+                    .into_iter()
+                    .map(|range| range.try_into_bounds(|elem| elem.into_datum(buf, elem_pg_type)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                buf.try_make_datum(|packer| {
+                    packer
+                        .push_multirange(ranges)
+                        .map_err(IntoDatumError::from)
+                })?
+            }
             Value::MzAclItem(mz_acl_item) => Datum::MzAclItem(mz_acl_item),
             Value::AclItem(acl_item) => Datum::AclItem(acl_item),
+            Value::Enum(label) => {
+                let labels = match typ {
+                    Type::Enum { labels, .. } => labels,
+                    _ => panic!("Value::Enum should have type Type::Enum. Found {:?}", typ),
+                };
+                Datum::UInt32(enum_ordinal(labels, &label)?)
+            }
         })
     }
 
@@ -434,6 +492,9 @@ impl Value {
             Value::Timestamp(ts) => strconv::format_timestamp(buf, ts),
             Value::TimestampTz(ts) => strconv::format_timestamptz(buf, ts),
             Value::Uuid(u) => strconv::format_uuid(buf, *u),
+            Value::Inet(i) => strconv::format_inet(buf, i.0),
+            Value::Cidr(i) => strconv::format_cidr(buf, i.0),
+            Value::MacAddr(m) => strconv::format_mac_addr(buf, m.0),
             Value::Numeric(d) => strconv::format_numeric(buf, &d.0),
             Value::MzTimestamp(t) => strconv::format_mz_timestamp(buf, *t),
             Value::Range(range) => strconv::format_range(buf, range, |buf, elem| match elem {
@@ -441,8 +502,16 @@ impl Value {
                 None => Ok::<_, ()>(buf.write_null()),
             })
             .expect("provided closure never fails"),
+            Value::MultiRange(ranges) => {
+                strconv::format_multirange(buf, ranges, |buf, elem| match elem {
+                    Some(elem) => Ok(elem.encode_text(buf.nonnull_buffer(), settings)),
+                    None => Ok::<_, ()>(buf.write_null()),
+                })
+                .expect("provided closure never fails")
+            }
             Value::MzAclItem(mz_acl_item) => strconv::format_mz_acl_item(buf, *mz_acl_item),
             Value::AclItem(acl_item) => strconv::format_acl_item(buf, *acl_item),
+            Value::Enum(label) => strconv::format_string(buf, label),
         }
     }
 
@@ -565,6 +634,9 @@ impl Value {
             Value::Timestamp(ts) => ts.to_sql(&PgType::TIMESTAMP, buf),
             Value::TimestampTz(ts) => ts.to_sql(&PgType::TIMESTAMPTZ, buf),
             Value::Uuid(u) => u.to_sql(&PgType::UUID, buf),
+            Value::Inet(i) => i.to_sql(&PgType::INET, buf),
+            Value::Cidr(i) => i.to_sql(&PgType::CIDR, buf),
+            Value::MacAddr(m) => m.to_sql(&PgType::MACADDR, buf),
             Value::Numeric(a) => a.to_sql(&PgType::NUMERIC, buf),
             Value::MzTimestamp(t) => t.to_string().to_sql(&PgType::TEXT, buf),
             Value::Range(range) => {
@@ -588,11 +660,32 @@ impl Value {
                 }
                 Ok(postgres_types::IsNull::No)
             }
+            Value::MultiRange(ranges) => {
+                let elem_type = match ty {
+                    Type::MultiRange { element_type } => element_type,
+                    _ => unreachable!(),
+                };
+                let range_type = Type::Range {
+                    element_type: elem_type.clone(),
+                };
+
+                buf.put_i32(pg_len("number of multirange ranges", ranges.len())?);
+                for range in ranges {
+                    let base = buf.len();
+                    buf.put_i32(0);
+                    Value::Range(range.clone()).encode_binary(&range_type, buf)?;
+                    let len = pg_len("encoded range", buf.len() - base - 4)?;
+                    buf[base..base + 4].copy_from_slice(&len.to_be_bytes());
+                }
+                Ok(postgres_types::IsNull::No)
+            }
             Value::MzAclItem(mz_acl_item) => {
                 buf.extend_from_slice(&mz_acl_item.encode_binary());
                 Ok(postgres_types::IsNull::No)
             }
             Value::AclItem(_) => Err("aclitem has no binary encoding".into()),
+            // Like PostgreSQL, enum labels are sent as their text.
+            Value::Enum(label) => label.to_sql(&PgType::TEXT, buf),
         }
         .expect("encode_binary should never trigger a to_sql failure");
         if let IsNull::Yes = is_null {
@@ -646,6 +739,9 @@ impl Value {
             SqlScalarType::PgLegacyName => Ok(()),
             SqlScalarType::Jsonb => Ok(()),
             SqlScalarType::Uuid => Ok(()),
+            SqlScalarType::Inet => Ok(()),
+            SqlScalarType::Cidr => Ok(()),
+            SqlScalarType::MacAddr => Ok(()),
             SqlScalarType::Array(elem_type) => Self::binary_encoding_error(elem_type),
             SqlScalarType::Int2Vector => Ok(()),
             SqlScalarType::List { .. } => Err("no binary output function available for type list"),
@@ -654,6 +750,8 @@ impl Value {
                 .iter()
                 .try_for_each(|(_, ty)| Self::binary_encoding_error(&ty.scalar_type)),
             SqlScalarType::Range { element_type } => Self::binary_encoding_error(element_type),
+            SqlScalarType::MultiRange { element_type } => Self::binary_encoding_error(element_type),
+            SqlScalarType::Enum { .. } => Ok(()),
         }
     }
 
@@ -748,12 +846,24 @@ impl Value {
             Type::Timestamp { .. } => Value::Timestamp(strconv::parse_timestamp(s)?),
             Type::TimestampTz { .. } => Value::TimestampTz(strconv::parse_timestamptz(s)?),
             Type::Uuid => Value::Uuid(strconv::parse_uuid(s)?),
+            Type::Inet => Value::Inet(Inet(strconv::parse_inet(s)?)),
+            Type::Cidr => Value::Cidr(Inet(strconv::parse_cidr(s)?)),
+            Type::MacAddr => Value::MacAddr(MacAddr(strconv::parse_mac_addr(s)?)),
             Type::MzTimestamp => Value::MzTimestamp(strconv::parse_mz_timestamp(s)?),
             Type::Range { element_type } => Value::Range(strconv::parse_range(s, |elem_text| {
                 Value::decode_text(element_type, elem_text.as_bytes()).map(Box::new)
             })?),
+            Type::MultiRange { element_type } => {
+                Value::MultiRange(strconv::parse_multirange(s, |elem_text| {
+                    Value::decode_text(element_type, elem_text.as_bytes()).map(Box::new)
+                })?)
+            }
             Type::MzAclItem => Value::MzAclItem(strconv::parse_mz_acl_item(s)?),
             Type::AclItem => Value::AclItem(strconv::parse_acl_item(s)?),
+            Type::Enum { labels, .. } => {
+                enum_ordinal(labels, s)?;
+                Value::Enum(s.to_owned())
+            }
         })
     }
 
@@ -860,6 +970,9 @@ impl Value {
                 packer.push(Datum::TimestampTz(strconv::parse_timestamptz(s)?))
             }
             Type::Uuid => packer.push(Datum::Uuid(strconv::parse_uuid(s)?)),
+            Type::Inet => packer.push(Datum::Inet(strconv::parse_inet(s)?)),
+            Type::Cidr => packer.push(Datum::Inet(strconv::parse_cidr(s)?)),
+            Type::MacAddr => packer.push(Datum::MacAddr(strconv::parse_mac_addr(s)?)),
             Type::MzTimestamp => packer.push(Datum::MzTimestamp(strconv::parse_mz_timestamp(s)?)),
             Type::Range { element_type } => {
                 let range = strconv::parse_range(s, |elem_text| {
@@ -875,8 +988,23 @@ impl Value {
                     .push_range(range)
                     .map_err(Box::<dyn Error + Sync + Send>::from)?;
             }
+            Type::MultiRange { element_type } => {
+                let ranges = strconv::parse_multirange(s, |elem_text| {
+                    Value::decode_text(element_type, elem_text.as_bytes()).map(Box::new)
+                })?;
+                let buf = RowArena::new();
+                let ranges = ranges
+                    .into_iter()
+                    .map(|range| range.try_into_bounds(|elem| elem.into_datum(&buf, element_type)))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(Box::<dyn Error + Sync + Send>::from)?;
+                packer
+                    .push_multirange(ranges)
+                    .map_err(Box::<dyn Error + Sync + Send>::from)?;
+            }
             Type::MzAclItem => packer.push(Datum::MzAclItem(strconv::parse_mz_acl_item(s)?)),
             Type::AclItem => packer.push(Datum::AclItem(strconv::parse_acl_item(s)?)),
+            Type::Enum { labels, .. } => packer.push(Datum::UInt32(enum_ordinal(labels, s)?)),
         })
     }
 
@@ -971,17 +1099,28 @@ impl Value {
                 )?))
             }
             Type::Uuid => Uuid::from_sql(ty.inner(), raw).map(Value::Uuid),
+            Type::Inet => Inet::from_sql(ty.inner(), raw).map(Value::Inet),
+            Type::Cidr => Inet::from_sql(ty.inner(), raw).map(Value::Cidr),
+            Type::MacAddr => MacAddr::from_sql(ty.inner(), raw).map(Value::MacAddr),
             Type::MzTimestamp => {
                 let s = String::from_sql(ty.inner(), raw)?;
                 let t: mz_repr::Timestamp = s.parse()?;
                 Ok(Value::MzTimestamp(t))
             }
             Type::Range { .. } => Err("binary decoding of range types is not implemented".into()),
+            Type::MultiRange { .. } => {
+                Err("binary decoding of multirange types is not implemented".into())
+            }
             Type::MzAclItem => {
                 let mz_acl_item = MzAclItem::decode_binary(raw)?;
                 Ok(Value::MzAclItem(mz_acl_item))
             }
             Type::AclItem => Err("aclitem has no binary encoding".into()),
+            Type::Enum { labels, .. } => {
+                let label = decode_binary_string(ty, raw)?;
+                enum_ordinal(labels, &label)?;
+                Ok(Value::Enum(label))
+            }
         }
     }
 }
@@ -1070,6 +1209,15 @@ fn decode_binary_string(ty: &Type, raw: &[u8]) -> Result<String, Box<dyn Error +
     Ok(s)
 }
 
+/// Returns the ordinal of `label` among the declared `labels` of an enum type.
+fn enum_ordinal(labels: &[String], label: &str) -> Result<u32, IntoDatumError> {
+    labels
+        .iter()
+        .position(|l| l == label)
+        .map(|i| u32::try_from(i).expect("enum types have fewer than 2^32 labels"))
+        .ok_or_else(|| IntoDatumError::EnumLabel(label.to_owned()))
+}
+
 /// Rescales `n` to the scale required by `constraints`, if any.
 fn rescale_numeric(
     mut n: OrderedDecimal<mz_repr_numeric::Numeric>,
@@ -37,6 +37,8 @@ pub enum IntoDatumError {
     Range(InvalidRangeError),
     /// Invalid array (e.g. wrong cardinality or too many dimensions).
     Array(InvalidArrayError),
+    /// A label that is not among the labels of the enum type.
+    EnumLabel(String),
 }
 
 impl fmt::Display for IntoDatumError {
@@ -44,6 +46,9 @@ impl fmt::Display for IntoDatumError {
         match self {
             IntoDatumError::Range(e) => e.fmt(f),
             IntoDatumError::Array(e) => e.fmt(f),
+            IntoDatumError::EnumLabel(label) => {
+                write!(f, "invalid input value for enum: \"{}\"", label)
+            }
         }
     }
 }
@@ -53,6 +58,7 @@ impl Error for IntoDatumError {
         match self {
             IntoDatumError::Range(e) => Some(e),
             IntoDatumError::Array(e) => Some(e),
+            IntoDatumError::EnumLabel(_) => None,
         }
     }
 }
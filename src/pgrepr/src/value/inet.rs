@@ -0,0 +1,143 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::error::Error;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use bytes::{BufMut, BytesMut};
+use mz_repr::adt::inet::{Inet as ReprInet, MacAddr as ReprMacAddr};
+use postgres_types::{FromSql, IsNull, ToSql, Type, to_sql_checked};
+
+/// The address family PostgreSQL uses on the wire for IPv4 addresses.
+const PGSQL_AF_INET: u8 = 2;
+/// The address family PostgreSQL uses on the wire for IPv6 addresses.
+const PGSQL_AF_INET6: u8 = 3;
+
+/// A wrapper for the `repr` crate's [`Inet`](mz_repr::adt::inet::Inet) type
+/// that can be serialized to and deserialized from the PostgreSQL binary
+/// format of both `inet` and `cidr`.
+#[derive(Debug, Clone, Copy)]
+pub struct Inet(pub ReprInet);
+
+impl fmt::Display for Inet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ToSql for Inet {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + 'static + Send + Sync>> {
+        // PostgreSQL represents inet and cidr values as the address family,
+        // the prefix length, a flag indicating whether the value is a cidr,
+        // the number of address bytes, and finally the address bytes.
+        //
+        // Postgres implementation: https://github.com/postgres/postgres/blob/REL_16_0/src/backend/utils/adt/network.c#L268
+        let is_cidr = *ty == Type::CIDR;
+        match self.0.addr() {
+            IpAddr::V4(addr) => {
+                out.put_u8(PGSQL_AF_INET);
+                out.put_u8(self.0.netmask());
+                out.put_u8(u8::from(is_cidr));
+                out.put_u8(4);
+                out.put_slice(&addr.octets());
+            }
+            IpAddr::V6(addr) => {
+                out.put_u8(PGSQL_AF_INET6);
+                out.put_u8(self.0.netmask());
+                out.put_u8(u8::from(is_cidr));
+                out.put_u8(16);
+                out.put_slice(&addr.octets());
+            }
+        }
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::INET | Type::CIDR)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Inet {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Inet, Box<dyn Error + Sync + Send>> {
+        let addr = match raw {
+            [PGSQL_AF_INET, _, _, 4, octets @ ..] => {
+                let octets: [u8; 4] = octets
+                    .try_into()
+                    .map_err(|_| "invalid length in external \"inet\" value")?;
+                IpAddr::V4(Ipv4Addr::from(octets))
+            }
+            [PGSQL_AF_INET6, _, _, 16, octets @ ..] => {
+                let octets: [u8; 16] = octets
+                    .try_into()
+                    .map_err(|_| "invalid length in external \"inet\" value")?;
+                IpAddr::V6(Ipv6Addr::from(octets))
+            }
+            _ => return Err("invalid external \"inet\" value".into()),
+        };
+        let inet =
+            ReprInet::new(addr, raw[1]).map_err(|_| "invalid bits in external \"inet\" value")?;
+        if *ty == Type::CIDR && !inet.is_network() {
+            return Err("invalid external \"cidr\" value".into());
+        }
+        Ok(Inet(inet))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::INET | Type::CIDR)
+    }
+}
+
+/// A wrapper for the `repr` crate's [`MacAddr`](mz_repr::adt::inet::MacAddr)
+/// type that can be serialized to and deserialized from the PostgreSQL binary
+/// format.
+#[derive(Debug, Clone, Copy)]
+pub struct MacAddr(pub ReprMacAddr);
+
+impl fmt::Display for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ToSql for MacAddr {
+    fn to_sql(
+        &self,
+        _: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + 'static + Send + Sync>> {
+        out.put_slice(&self.0.0);
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::MACADDR)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for MacAddr {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<MacAddr, Box<dyn Error + Sync + Send>> {
+        let octets: [u8; ReprMacAddr::BINARY_SIZE] = raw
+            .try_into()
+            .map_err(|_| "invalid length in external \"macaddr\" value")?;
+        Ok(MacAddr(ReprMacAddr(octets)))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::MACADDR)
+    }
+}
@@ -2437,6 +2437,7 @@ where
             | ExecuteResponse::CreatedSource { .. }
             | ExecuteResponse::CreatedTable { .. }
             | ExecuteResponse::CreatedType
+            | ExecuteResponse::CreatedFunction
             | ExecuteResponse::CreatedView { .. }
             | ExecuteResponse::CreatedViews { .. }
             | ExecuteResponse::CreatedNetworkPolicy
@@ -2450,6 +2451,7 @@ where
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(..)
+            | ExecuteResponse::Merged(..)
             | ExecuteResponse::Copied(..)
             | ExecuteResponse::Prepare
             | ExecuteResponse::Raised
@@ -2763,8 +2765,8 @@ where
                 CopyFormatParams::Csv(CopyCsvFormatParams::default()),
                 Format::Text,
             ),
-            CopyFormat::Parquet => {
-                let text = "Parquet format is not supported".to_string();
+            CopyFormat::Parquet | CopyFormat::Json | CopyFormat::Avro => {
+                let text = format!("{format:?} format is not supported");
                 return self
                     .send_error_and_get_state(ErrorResponse::error(
                         SqlState::INTERNAL_ERROR,
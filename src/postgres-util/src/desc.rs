@@ -62,10 +62,15 @@ impl PostgresTableDesc {
     ///   Compatibility is defined as returning `true` for
     ///   `PostgresColumnDesc::is_compatible`.
     /// - `self`'s keys are all present in `other`
+    ///
+    /// The types of the columns in `allow_type_to_change_by_col_num` may change
+    /// entirely, those of the columns in `allow_type_mod_to_change_by_col_num`
+    /// only in their modifier.
     pub fn determine_compatibility(
         &self,
         other: &PostgresTableDesc,
         allow_type_to_change_by_col_num: &BTreeSet<u16>,
+        allow_type_mod_to_change_by_col_num: &BTreeSet<u16>,
     ) -> Result<(), anyhow::Error> {
         if self == other {
             return Ok(());
@@ -87,7 +92,9 @@ impl PostgresTableDesc {
                     Some(other_info) => {
                         let allow_type_change =
                             allow_type_to_change_by_col_num.contains(&info.col_num);
-                        info.is_compatible(other_info, allow_type_change)
+                        let allow_type_mod_change =
+                            allow_type_mod_to_change_by_col_num.contains(&info.col_num);
+                        info.is_compatible(other_info, allow_type_change, allow_type_mod_change)
                     }
                     None => false,
                 });
@@ -112,6 +119,32 @@ impl PostgresTableDesc {
             )
         }
     }
+
+    /// Returns the columns of `other`, a later schema of the same table, that
+    /// are not part of `self` and that can be added to data that was ingested
+    /// with the schema `self`.
+    ///
+    /// Rows ingested before a column was added have no value for it, which only
+    /// matches the upstream table if the column is nullable and has no default
+    /// value, i.e. if the column is NULL in all existing rows. Columns named in
+    /// `ignored` are never returned.
+    pub fn added_columns<'a>(
+        &self,
+        other: &'a PostgresTableDesc,
+        columns_with_defaults: &BTreeSet<String>,
+        ignored: &[String],
+    ) -> Vec<&'a PostgresColumnDesc> {
+        other
+            .columns
+            .iter()
+            .filter(|c| {
+                c.nullable
+                    && !columns_with_defaults.contains(&c.name)
+                    && !ignored.contains(&c.name)
+                    && !self.columns.iter().any(|own| own.name == c.name)
+            })
+            .collect()
+    }
 }
 
 impl RustType<ProtoPostgresTableDesc> for PostgresTableDesc {
@@ -177,17 +210,28 @@ impl PostgresColumnDesc {
     /// Note that this function somewhat unnecessarily errors if the names
     /// differ; this is negotiable but we want users to understand the fixedness
     /// of names in our schemas.
-    fn is_compatible(&self, other: &PostgresColumnDesc, allow_type_change: bool) -> bool {
+    fn is_compatible(
+        &self,
+        other: &PostgresColumnDesc,
+        allow_type_change: bool,
+        allow_type_mod_change: bool,
+    ) -> bool {
         self.name == other.name
             && self.col_num == other.col_num
             && (self.type_oid == other.type_oid || allow_type_change)
-            && (self.type_mod == other.type_mod || allow_type_change)
+            && (self.type_mod == other.type_mod || allow_type_change || allow_type_mod_change)
             // Columns are compatible if:
             // - self is nullable; introducing a not null constraint doesn't
             //   change this column's behavior.
             // - self and other are both not nullable
             && (self.nullable || self.nullable == other.nullable)
     }
+
+    /// Reports whether the column is a `varchar` column, whose length limit is
+    /// its type modifier.
+    pub fn is_varchar(&self) -> bool {
+        self.type_oid == tokio_postgres::types::Type::VARCHAR.oid()
+    }
 }
 
 impl RustType<ProtoPostgresColumnDesc> for PostgresColumnDesc {
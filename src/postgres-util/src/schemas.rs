@@ -281,3 +281,31 @@ pub async fn publication_info(
         })
         .collect())
 }
+
+/// Fetches the names of the columns that have a default value, for each of the
+/// tables with the specified OIDs.
+pub async fn columns_with_defaults(
+    client: &Client,
+    oids: &[Oid],
+) -> Result<BTreeMap<Oid, BTreeSet<String>>, PostgresError> {
+    let mut columns: BTreeMap<Oid, BTreeSet<String>> = BTreeMap::new();
+    for row in query(
+        client,
+        crate::sql!(
+            "SELECT a.attrelid AS table_oid, a.attname AS name
+            FROM pg_catalog.pg_attribute a
+            WHERE a.attnum > 0::pg_catalog.int2
+                AND NOT a.attisdropped
+                AND a.atthasdef
+                AND a.attrelid = ANY ($1)"
+        ),
+        &[&oids],
+    )
+    .await?
+    {
+        let table_oid: Oid = row.get("table_oid");
+        let name: String = row.get("name");
+        columns.entry(table_oid).or_default().insert(name);
+    }
+    Ok(columns)
+}
@@ -0,0 +1,489 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Network address types.
+//!
+//! [`Inet`] backs both the PostgreSQL `inet` and `cidr` types, which share a
+//! representation and differ only in validation and text output. [`MacAddr`]
+//! backs the PostgreSQL `macaddr` type.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use anyhow::{Error, anyhow, bail};
+use mz_persist_types::columnar::FixedSizeCodec;
+#[cfg(any(test, feature = "proptest"))]
+use proptest::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// An IPv4 or IPv6 host address together with the length of its network
+/// prefix.
+///
+/// Values sort like PostgreSQL's `inet`: IPv4 before IPv6, then by network
+/// address, then by prefix length, then by host address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Inet {
+    addr: IpAddr,
+    netmask: u8,
+}
+
+impl Inet {
+    /// The length of [`Inet::encode_binary`] for an IPv4 address.
+    pub const V4_BINARY_SIZE: usize = 6;
+    /// The length of [`Inet::encode_binary`] for an IPv6 address.
+    pub const V6_BINARY_SIZE: usize = 18;
+
+    /// Constructs an address with the given prefix length.
+    pub fn new(addr: IpAddr, netmask: u8) -> Result<Inet, InvalidInetError> {
+        if netmask > max_netmask(&addr) {
+            return Err(InvalidInetError::NetmaskOutOfRange(netmask));
+        }
+        Ok(Inet { addr, netmask })
+    }
+
+    /// Constructs an address whose prefix covers the entire address.
+    pub fn host_only(addr: IpAddr) -> Inet {
+        let netmask = max_netmask(&addr);
+        Inet { addr, netmask }
+    }
+
+    /// The host address.
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// The length of the network prefix in bits.
+    pub fn netmask(&self) -> u8 {
+        self.netmask
+    }
+
+    /// The number of bits in the address.
+    pub fn max_netmask(&self) -> u8 {
+        max_netmask(&self.addr)
+    }
+
+    /// The PostgreSQL address family number: 4 for IPv4, 6 for IPv6.
+    pub fn family(&self) -> i32 {
+        match self.addr {
+            IpAddr::V4(_) => 4,
+            IpAddr::V6(_) => 6,
+        }
+    }
+
+    /// The network containing this address, i.e. the address with every bit
+    /// outside the prefix cleared.
+    pub fn network(&self) -> Inet {
+        Inet {
+            addr: mask_addr(self.addr, self.netmask),
+            netmask: self.netmask,
+        }
+    }
+
+    /// Reports whether no bits outside the prefix are set, which is required
+    /// of `cidr` values.
+    pub fn is_network(&self) -> bool {
+        mask_addr(self.addr, self.netmask) == self.addr
+    }
+
+    /// Returns the same address with a different prefix length.
+    pub fn with_netmask(&self, netmask: u8) -> Result<Inet, InvalidInetError> {
+        Inet::new(self.addr, netmask)
+    }
+
+    /// Reports whether `other` lies within this network and has a longer
+    /// prefix (the `>>` operator).
+    pub fn contains(&self, other: &Inet) -> bool {
+        self.netmask < other.netmask && self.contains_addr_of(other)
+    }
+
+    /// Reports whether `other` lies within or is equal to this network (the
+    /// `>>=` operator).
+    pub fn contains_or_equals(&self, other: &Inet) -> bool {
+        self.netmask <= other.netmask && self.contains_addr_of(other)
+    }
+
+    /// Reports whether either network contains the other (the `&&` operator).
+    pub fn overlaps(&self, other: &Inet) -> bool {
+        let netmask = self.netmask.min(other.netmask);
+        self.same_family(other) && mask_addr(self.addr, netmask) == mask_addr(other.addr, netmask)
+    }
+
+    fn same_family(&self, other: &Inet) -> bool {
+        self.addr.is_ipv4() == other.addr.is_ipv4()
+    }
+
+    fn contains_addr_of(&self, other: &Inet) -> bool {
+        self.same_family(other)
+            && mask_addr(self.addr, self.netmask) == mask_addr(other.addr, self.netmask)
+    }
+
+    /// Formats the address as a `cidr` value, which always includes the
+    /// prefix length.
+    pub fn display_cidr(&self) -> impl fmt::Display + '_ {
+        DisplayCidr(self)
+    }
+
+    /// Encodes the address as a family byte, the prefix length, and the
+    /// address octets.
+    pub fn encode_binary(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::V6_BINARY_SIZE);
+        match self.addr {
+            IpAddr::V4(addr) => {
+                buf.push(4);
+                buf.push(self.netmask);
+                buf.extend_from_slice(&addr.octets());
+            }
+            IpAddr::V6(addr) => {
+                buf.push(6);
+                buf.push(self.netmask);
+                buf.extend_from_slice(&addr.octets());
+            }
+        }
+        buf
+    }
+
+    /// Decodes an address encoded with [`Inet::encode_binary`].
+    pub fn decode_binary(raw: &[u8]) -> Result<Inet, Error> {
+        let addr = match raw {
+            [4, _, octets @ ..] => {
+                let octets: [u8; 4] = octets
+                    .try_into()
+                    .map_err(|_| anyhow!("invalid IPv4 inet length {}", raw.len()))?;
+                IpAddr::V4(Ipv4Addr::from(octets))
+            }
+            [6, _, octets @ ..] => {
+                let octets: [u8; 16] = octets
+                    .try_into()
+                    .map_err(|_| anyhow!("invalid IPv6 inet length {}", raw.len()))?;
+                IpAddr::V6(Ipv6Addr::from(octets))
+            }
+            _ => bail!("invalid inet encoding"),
+        };
+        Ok(Inet::new(addr, raw[1])?)
+    }
+}
+
+impl Ord for Inet {
+    fn cmp(&self, other: &Inet) -> Ordering {
+        // Comparing the masked network addresses before the prefix lengths
+        // matches PostgreSQL, which compares the leading `min(netmask)` bits
+        // first: a network sorts before the longer-prefix networks it
+        // contains.
+        self.addr
+            .is_ipv6()
+            .cmp(&other.addr.is_ipv6())
+            .then_with(|| self.network().addr.cmp(&other.network().addr))
+            .then_with(|| self.netmask.cmp(&other.netmask))
+            .then_with(|| self.addr.cmp(&other.addr))
+    }
+}
+
+impl PartialOrd for Inet {
+    fn partial_cmp(&self, other: &Inet) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Formats the address like PostgreSQL's `inet`, which omits the prefix
+/// length when it covers the entire address.
+impl fmt::Display for Inet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.addr)?;
+        if self.netmask != self.max_netmask() {
+            write!(f, "/{}", self.netmask)?;
+        }
+        Ok(())
+    }
+}
+
+struct DisplayCidr<'a>(&'a Inet);
+
+impl fmt::Display for DisplayCidr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.0.addr, self.0.netmask)
+    }
+}
+
+#[cfg(any(test, feature = "proptest"))]
+impl Arbitrary for Inet {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Inet>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            (any::<[u8; 4]>(), 0..=32u8).prop_map(|(octets, netmask)| Inet::new(
+                Ipv4Addr::from(octets).into(),
+                netmask
+            )
+            .expect("valid netmask")),
+            (any::<[u8; 16]>(), 0..=128u8).prop_map(|(octets, netmask)| Inet::new(
+                Ipv6Addr::from(octets).into(),
+                netmask
+            )
+            .expect("valid netmask")),
+        ]
+        .boxed()
+    }
+}
+
+fn max_netmask(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+/// Clears every bit of `addr` after the first `netmask` bits.
+fn mask_addr(addr: IpAddr, netmask: u8) -> IpAddr {
+    match addr {
+        IpAddr::V4(addr) => {
+            let mask = u32::MAX.checked_shl(32 - u32::from(netmask)).unwrap_or(0);
+            IpAddr::V4(Ipv4Addr::from(u32::from(addr) & mask))
+        }
+        IpAddr::V6(addr) => {
+            let mask = u128::MAX.checked_shl(128 - u32::from(netmask)).unwrap_or(0);
+            IpAddr::V6(Ipv6Addr::from(u128::from(addr) & mask))
+        }
+    }
+}
+
+/// An error constructing an [`Inet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidInetError {
+    /// The prefix length exceeds the number of bits in the address.
+    NetmaskOutOfRange(u8),
+}
+
+impl fmt::Display for InvalidInetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InvalidInetError::NetmaskOutOfRange(netmask) => {
+                write!(f, "invalid mask length: {netmask}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvalidInetError {}
+
+/// An encoded packed variant of [`Inet`].
+///
+/// We uphold the variant that [`PackedInet`] sorts the same as [`Inet`]: the
+/// layout is the family, the network address, the prefix length and the host
+/// address, with IPv4 addresses stored in the leading bytes of their fields.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PackedInet([u8; Self::SIZE]);
+
+impl FixedSizeCodec<Inet> for PackedInet {
+    const SIZE: usize = 34;
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    fn from_bytes(slice: &[u8]) -> Result<Self, String> {
+        let buf: [u8; Self::SIZE] = slice.try_into().map_err(|_| {
+            format!(
+                "size for PackedInet is {} bytes, got {}",
+                Self::SIZE,
+                slice.len()
+            )
+        })?;
+        Ok(PackedInet(buf))
+    }
+
+    #[inline]
+    fn from_value(value: Inet) -> Self {
+        fn octets(addr: IpAddr) -> [u8; 16] {
+            match addr {
+                IpAddr::V4(addr) => {
+                    let mut buf = [0; 16];
+                    buf[..4].copy_from_slice(&addr.octets());
+                    buf
+                }
+                IpAddr::V6(addr) => addr.octets(),
+            }
+        }
+
+        let mut buf = [0u8; Self::SIZE];
+        buf[0] = u8::from(value.addr.is_ipv6());
+        buf[1..17].copy_from_slice(&octets(value.network().addr));
+        buf[17] = value.netmask;
+        buf[18..].copy_from_slice(&octets(value.addr));
+        PackedInet(buf)
+    }
+
+    #[inline]
+    fn into_value(self) -> Inet {
+        let addr = if self.0[0] == 0 {
+            let octets: [u8; 4] = self.0[18..22].try_into().expect("known size");
+            IpAddr::V4(Ipv4Addr::from(octets))
+        } else {
+            let octets: [u8; 16] = self.0[18..].try_into().expect("known size");
+            IpAddr::V6(Ipv6Addr::from(octets))
+        };
+        Inet::new(addr, self.0[17]).expect("PackedInet should roundtrip")
+    }
+}
+
+/// A MAC (Ethernet hardware) address.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+#[cfg_attr(any(test, feature = "proptest"), derive(proptest_derive::Arbitrary))]
+pub struct MacAddr(pub [u8; 6]);
+
+impl MacAddr {
+    /// The length of the binary encoding of a MAC address.
+    pub const BINARY_SIZE: usize = 6;
+}
+
+/// Formats the address in PostgreSQL's canonical form, e.g.
+/// `08:00:2b:01:02:03`.
+impl fmt::Display for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+    }
+}
+
+impl std::str::FromStr for MacAddr {
+    type Err = Error;
+
+    /// Parses the formats PostgreSQL accepts: six groups of two hex digits,
+    /// two groups of six, or three groups of four, separated by `:`, `-` or
+    /// `.`, or twelve hex digits with no separator.
+    fn from_str(s: &str) -> Result<MacAddr, Error> {
+        let separator = s.chars().find(|c| matches!(c, ':' | '-' | '.'));
+        let groups: Vec<&str> = match separator {
+            Some(separator) => s.split(separator).collect(),
+            None => vec![s],
+        };
+        let lengths: Vec<usize> = groups.iter().map(|g| g.len()).collect();
+        let valid_grouping = match separator {
+            None => lengths == [12],
+            Some(':' | '-') => lengths == [2; 6] || lengths == [6, 6] || lengths == [4, 4, 4],
+            Some(_) => lengths == [4, 4, 4],
+        };
+        if !valid_grouping {
+            bail!("invalid MAC address grouping");
+        }
+        let digits: String = groups.concat();
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            bail!("invalid hex digit");
+        }
+        let mut octets = [0; 6];
+        for (i, octet) in octets.iter_mut().enumerate() {
+            *octet = u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16)?;
+        }
+        Ok(MacAddr(octets))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inet(s: &str) -> Inet {
+        let (addr, netmask) = s.split_once('/').expect("test input has a netmask");
+        Inet::new(addr.parse().unwrap(), netmask.parse().unwrap()).unwrap()
+    }
+
+    #[mz_ore::test]
+    fn test_inet_containment() {
+        assert!(inet("10.0.0.0/8").contains(&inet("10.1.2.3/32")));
+        assert!(!inet("10.0.0.0/8").contains(&inet("10.0.0.0/8")));
+        assert!(inet("10.0.0.0/8").contains_or_equals(&inet("10.0.0.0/8")));
+        assert!(!inet("10.0.0.0/8").contains(&inet("11.0.0.0/16")));
+        assert!(!inet("::/0").contains(&inet("10.0.0.0/8")));
+        assert!(inet("10.0.0.0/8").overlaps(&inet("10.5.0.0/16")));
+        assert!(inet("10.5.0.0/16").overlaps(&inet("10.0.0.0/8")));
+        assert!(!inet("10.5.0.0/16").overlaps(&inet("10.6.0.0/16")));
+    }
+
+    #[mz_ore::test]
+    fn test_inet_ordering() {
+        let mut values = vec![
+            inet("::1/128"),
+            inet("10.0.0.1/8"),
+            inet("10.0.0.0/16"),
+            inet("10.0.0.0/8"),
+            inet("9.255.255.255/32"),
+        ];
+        values.sort();
+        assert_eq!(
+            values,
+            vec![
+                inet("9.255.255.255/32"),
+                inet("10.0.0.0/8"),
+                inet("10.0.0.1/8"),
+                inet("10.0.0.0/16"),
+                inet("::1/128"),
+            ]
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_inet_display() {
+        assert_eq!(inet("10.1.2.3/32").to_string(), "10.1.2.3");
+        assert_eq!(inet("10.1.2.3/8").to_string(), "10.1.2.3/8");
+        assert_eq!(
+            inet("10.0.0.0/32").display_cidr().to_string(),
+            "10.0.0.0/32"
+        );
+        assert_eq!(inet("10.1.2.3/8").network().to_string(), "10.0.0.0/8");
+    }
+
+    #[mz_ore::test]
+    fn test_mac_addr_parse() {
+        let expected = MacAddr([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03]);
+        for s in [
+            "08:00:2b:01:02:03",
+            "08-00-2b-01-02-03",
+            "08002b:010203",
+            "08002b-010203",
+            "0800.2b01.0203",
+            "0800-2b01-0203",
+            "08002b010203",
+        ] {
+            assert_eq!(s.parse::<MacAddr>().unwrap(), expected, "{s}");
+        }
+        assert_eq!(expected.to_string(), "08:00:2b:01:02:03");
+        for s in [
+            "08:00:2b:01:02",
+            "08:00-2b:01:02:03",
+            "0800.2b01:0203",
+            "zz002b010203",
+        ] {
+            mz_ore::assert_err!(s.parse::<MacAddr>());
+        }
+    }
+
+    proptest! {
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // slow
+        fn packed_inet_roundtrip_and_order(a: Inet, b: Inet) {
+            let packed_a = PackedInet::from_value(a);
+            let packed_b = PackedInet::from_value(b);
+            prop_assert_eq!(packed_a.into_value(), a);
+            prop_assert_eq!(packed_a.cmp(&packed_b), a.cmp(&b));
+            prop_assert_eq!(Inet::decode_binary(&a.encode_binary()).unwrap(), a);
+        }
+    }
+}
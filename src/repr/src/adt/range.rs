@@ -556,6 +556,165 @@ impl<'a> Range<Datum<'a>> {
     }
 }
 
+/// Operations over multiranges.
+///
+/// A multirange is stored as a list of ranges in canonical form: every range
+/// is canonicalized and non-empty, and the ranges are sorted and pairwise
+/// neither overlapping nor adjacent. Two multiranges representing the same set
+/// of values therefore have the same encoding, which lets them be compared and
+/// hashed as plain lists.
+pub mod multirange {
+    use super::*;
+
+    /// Brings `ranges` into canonical multirange form by canonicalizing each
+    /// range, dropping empty ranges, and merging ranges that overlap or are
+    /// adjacent.
+    pub fn canonicalize<'a>(
+        ranges: Vec<Range<Datum<'a>>>,
+    ) -> Result<Vec<Range<Datum<'a>>>, InvalidRangeError> {
+        let mut sorted = Vec::with_capacity(ranges.len());
+        for mut range in ranges {
+            range.canonicalize()?;
+            if range.inner.is_some() {
+                sorted.push(range);
+            }
+        }
+        sorted.sort();
+
+        let mut merged: Vec<Range<Datum<'a>>> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match merged.last_mut() {
+                Some(last) if last.overlaps(&range) || last.adjacent(&range) => {
+                    *last = last.union(&range)?;
+                }
+                _ => merged.push(range),
+            }
+        }
+        Ok(merged)
+    }
+
+    /// Returns the union of two multiranges.
+    pub fn union<'a>(
+        a: &[Range<Datum<'a>>],
+        b: &[Range<Datum<'a>>],
+    ) -> Result<Vec<Range<Datum<'a>>>, InvalidRangeError> {
+        canonicalize(a.iter().chain(b).copied().collect())
+    }
+
+    /// Returns the intersection of two multiranges.
+    pub fn intersection<'a>(
+        a: &[Range<Datum<'a>>],
+        b: &[Range<Datum<'a>>],
+    ) -> Result<Vec<Range<Datum<'a>>>, InvalidRangeError> {
+        let mut ranges = vec![];
+        for r in a {
+            for o in b {
+                if r.overlaps(o) {
+                    ranges.push(r.intersection(o));
+                }
+            }
+        }
+        canonicalize(ranges)
+    }
+
+    /// Returns the values in multirange `a` that are not in multirange `b`.
+    pub fn difference<'a>(
+        a: &[Range<Datum<'a>>],
+        b: &[Range<Datum<'a>>],
+    ) -> Result<Vec<Range<Datum<'a>>>, InvalidRangeError> {
+        let mut pieces = a.to_vec();
+        for o in b {
+            let mut next = Vec::with_capacity(pieces.len());
+            for piece in pieces {
+                match piece.difference(o) {
+                    Ok(r) => next.push(r),
+                    // `o` lies strictly within `piece`, so removing it splits
+                    // `piece` in two. Compute each half as the difference with
+                    // `o` extended to the corresponding end of `piece`.
+                    Err(InvalidRangeError::DiscontiguousDifference) => {
+                        let (p, o) = match (piece.inner, o.inner) {
+                            (Some(p), Some(o)) => (p, o),
+                            _ => unreachable!("empty ranges are never discontiguous"),
+                        };
+                        let below = Range {
+                            inner: Some(RangeInner {
+                                lower: o.lower,
+                                upper: p.upper,
+                            }),
+                        };
+                        let above = Range {
+                            inner: Some(RangeInner {
+                                lower: p.lower,
+                                upper: o.upper,
+                            }),
+                        };
+                        next.push(piece.difference(&below)?);
+                        next.push(piece.difference(&above)?);
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            pieces = next;
+        }
+        canonicalize(pieces)
+    }
+
+    /// Reports whether any range in multirange `a` contains `elem`, which must
+    /// be a non-null datum of the multirange's element type.
+    pub fn contains_elem<'a>(a: &[Range<Datum<'a>>], elem: Datum<'a>) -> bool {
+        a.iter().any(|r| match r.inner {
+            None => false,
+            Some(inner) => {
+                let above_lower = match inner.lower.bound {
+                    None => true,
+                    Some(l) => l < elem || (l == elem && inner.lower.inclusive),
+                };
+                let below_upper = match inner.upper.bound {
+                    None => true,
+                    Some(u) => elem < u || (elem == u && inner.upper.inclusive),
+                };
+                above_lower && below_upper
+            }
+        })
+    }
+
+    /// Reports whether multirange `a` contains every value in `range`.
+    pub fn contains_range<'a>(a: &[Range<Datum<'a>>], range: &Range<Datum<'a>>) -> bool {
+        // Because the ranges of a canonical multirange are neither overlapping
+        // nor adjacent, a non-empty range is contained only if a single range
+        // contains it.
+        range.inner.is_none() || a.iter().any(|r| r.contains_range(range))
+    }
+
+    /// Reports whether multirange `a` contains every value in multirange `b`.
+    pub fn contains_multirange<'a>(a: &[Range<Datum<'a>>], b: &[Range<Datum<'a>>]) -> bool {
+        b.iter().all(|r| contains_range(a, r))
+    }
+
+    /// Reports whether multirange `a` shares any values with `range`.
+    pub fn overlaps_range<'a>(a: &[Range<Datum<'a>>], range: &Range<Datum<'a>>) -> bool {
+        a.iter().any(|r| r.overlaps(range))
+    }
+
+    /// Reports whether two multiranges share any values.
+    pub fn overlaps_multirange<'a>(a: &[Range<Datum<'a>>], b: &[Range<Datum<'a>>]) -> bool {
+        b.iter().any(|r| overlaps_range(a, r))
+    }
+
+    /// Returns the smallest range that contains every value in multirange `a`.
+    pub fn span<'a>(a: &[Range<Datum<'a>>]) -> Range<Datum<'a>> {
+        match (a.first().and_then(|r| r.inner), a.last().and_then(|r| r.inner)) {
+            (Some(first), Some(last)) => Range {
+                inner: Some(RangeInner {
+                    lower: first.lower,
+                    upper: last.upper,
+                }),
+            },
+            _ => Range { inner: None },
+        }
+    }
+}
+
 /// Holds the upper and lower bounds for non-empty ranges.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct RangeInner<B> {
@@ -798,6 +957,7 @@ pub enum InvalidRangeError {
     /// bounds of inconsistent types, or the wrong number of bounds). Only
     /// reachable by decoding untrusted/corrupted bytes.
     InvalidRangeData,
+    NullMultiRangeMember,
 }
 
 impl Display for InvalidRangeError {
@@ -820,6 +980,9 @@ impl Display for InvalidRangeError {
                 f.write_str("range constructor flags argument must not be null")
             }
             InvalidRangeError::InvalidRangeData => f.write_str("invalid range data"),
+            InvalidRangeError::NullMultiRangeMember => {
+                f.write_str("multirange values cannot contain null members")
+            }
         }
     }
 }
@@ -851,6 +1014,7 @@ impl RustType<ProtoInvalidRangeError> for InvalidRangeError {
             InvalidRangeError::DiscontiguousDifference => DiscontiguousDifference(()),
             InvalidRangeError::NullRangeBoundFlags => NullRangeBoundFlags(()),
             InvalidRangeError::InvalidRangeData => InvalidRangeData(()),
+            InvalidRangeError::NullMultiRangeMember => NullMultiRangeMember(()),
         };
         ProtoInvalidRangeError { kind: Some(kind) }
     }
@@ -868,6 +1032,7 @@ impl RustType<ProtoInvalidRangeError> for InvalidRangeError {
                 DiscontiguousDifference(()) => InvalidRangeError::DiscontiguousDifference,
                 NullRangeBoundFlags(()) => InvalidRangeError::NullRangeBoundFlags,
                 InvalidRangeData(()) => InvalidRangeError::InvalidRangeData,
+                NullMultiRangeMember(()) => InvalidRangeError::NullMultiRangeMember,
             }),
             None => Err(TryFromProtoError::missing_field(
                 "`ProtoInvalidRangeError::kind`",
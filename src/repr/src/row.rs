@@ -42,6 +42,7 @@ use crate::adt::array::{
     Array, ArrayDimension, ArrayDimensions, InvalidArrayError, MAX_ARRAY_DIMENSIONS,
 };
 use crate::adt::date::Date;
+use crate::adt::inet::{Inet, MacAddr};
 use crate::adt::interval::Interval;
 use crate::adt::mz_acl_item::{AclItem, MzAclItem};
 use crate::adt::numeric;
@@ -1190,6 +1191,10 @@ enum Tag {
     UInt64_48,
     UInt64_56,
     UInt64_64,
+
+    InetV4,
+    InetV6,
+    MacAddr,
 }
 
 impl Tag {
@@ -1689,6 +1694,20 @@ pub unsafe fn read_datum<'a>(data: &mut &'a [u8]) -> Datum<'a> {
                 AclItem::decode_binary(&read_byte_array::<N>(data)).expect("invalid aclitem");
             Datum::AclItem(acl_item)
         }
+        Tag::InetV4 => {
+            const N: usize = Inet::V4_BINARY_SIZE;
+            let inet = Inet::decode_binary(&read_byte_array::<N>(data)).expect("invalid inet");
+            Datum::Inet(inet)
+        }
+        Tag::InetV6 => {
+            const N: usize = Inet::V6_BINARY_SIZE;
+            let inet = Inet::decode_binary(&read_byte_array::<N>(data)).expect("invalid inet");
+            Datum::Inet(inet)
+        }
+        Tag::MacAddr => {
+            const N: usize = MacAddr::BINARY_SIZE;
+            Datum::MacAddr(MacAddr(read_byte_array::<N>(data)))
+        }
     }
 }
 
@@ -2067,6 +2086,18 @@ where
             data.push(Tag::AclItem.into());
             data.extend_from_slice(&acl_item.encode_binary());
         }
+        Datum::Inet(inet) => {
+            let tag = match inet.family() {
+                4 => Tag::InetV4,
+                _ => Tag::InetV6,
+            };
+            data.push(tag.into());
+            data.extend_from_slice(&inet.encode_binary());
+        }
+        Datum::MacAddr(mac_addr) => {
+            data.push(Tag::MacAddr.into());
+            data.extend_from_slice(&mac_addr.0);
+        }
     }
 }
 
@@ -2179,6 +2210,11 @@ pub fn datum_size(datum: &Datum) -> usize {
         }
         Datum::MzAclItem(_) => 1 + MzAclItem::binary_size(),
         Datum::AclItem(_) => 1 + AclItem::binary_size(),
+        Datum::Inet(inet) => match inet.family() {
+            4 => 1 + Inet::V4_BINARY_SIZE,
+            _ => 1 + Inet::V6_BINARY_SIZE,
+        },
+        Datum::MacAddr(_) => 1 + MacAddr::BINARY_SIZE,
     }
 }
 
@@ -2743,6 +2779,26 @@ impl RowPacker<'_> {
         }
     }
 
+    /// Pushes a multirange, represented as a `DatumList` of `Datum::Range`s,
+    /// built from `ranges`.
+    ///
+    /// # Notes
+    /// - This function brings the ranges into canonical multirange form (see
+    ///   [`range::multirange`]) before pushing them to the row, so `ranges`
+    ///   may be empty, unsorted, or overlapping.
+    pub fn push_multirange<'a>(
+        &mut self,
+        ranges: Vec<Range<Datum<'a>>>,
+    ) -> Result<(), InvalidRangeError> {
+        let ranges = range::multirange::canonicalize(ranges)?;
+        self.push_list_with(|packer| {
+            for range in ranges {
+                packer.push_range(range)?;
+            }
+            Ok(())
+        })
+    }
+
     /// Pushes a `DatumRange` built from the specified arguments.
     ///
     /// # Warning
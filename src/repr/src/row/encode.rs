@@ -47,6 +47,7 @@ use uuid::Uuid;
 use crate::adt::array::{ArrayDimension, PackedArrayDimension};
 use crate::adt::date::Date;
 use crate::adt::datetime::PackedNaiveTime;
+use crate::adt::inet::{Inet, MacAddr, PackedInet};
 use crate::adt::interval::PackedInterval;
 use crate::adt::jsonb::{JsonbPacker, JsonbRef};
 use crate::adt::mz_acl_item::{PackedAclItem, PackedMzAclItem};
@@ -74,6 +75,8 @@ mod fixed_binary_sizes {
     pub const INTERVAL_FIXED_BYTES: i32 = PackedInterval::SIZE as i32;
     pub const ACL_ITEM_FIXED_BYTES: i32 = PackedAclItem::SIZE as i32;
     pub const _MZ_ACL_ITEM_FIXED_BYTES: i32 = PackedMzAclItem::SIZE as i32;
+    pub const INET_FIXED_BYTES: i32 = PackedInet::SIZE as i32;
+    pub const MAC_ADDR_FIXED_BYTES: i32 = MacAddr::BINARY_SIZE as i32;
     pub const ARRAY_DIMENSION_FIXED_BYTES: i32 = PackedArrayDimension::SIZE as i32;
 
     pub const UUID_FIXED_BYTES: i32 = 16;
@@ -94,6 +97,7 @@ pub fn preserves_order(scalar_type: &SqlScalarType) -> bool {
         | SqlScalarType::UInt16
         | SqlScalarType::UInt32
         | SqlScalarType::UInt64
+        | SqlScalarType::Enum { .. }
         | SqlScalarType::Date
         | SqlScalarType::Time
         | SqlScalarType::Timestamp { .. }
@@ -104,7 +108,10 @@ pub fn preserves_order(scalar_type: &SqlScalarType) -> bool {
         | SqlScalarType::Uuid
         | SqlScalarType::MzTimestamp
         | SqlScalarType::MzAclItem
-        | SqlScalarType::AclItem => true,
+        | SqlScalarType::AclItem
+        | SqlScalarType::Inet
+        | SqlScalarType::Cidr
+        | SqlScalarType::MacAddr => true,
         // We sort records lexicographically; a record has a meaningful sort if all its fields do.
         SqlScalarType::Record { fields, .. } => fields
             .iter()
@@ -130,7 +137,8 @@ pub fn preserves_order(scalar_type: &SqlScalarType) -> bool {
         | SqlScalarType::RegType
         | SqlScalarType::RegClass
         | SqlScalarType::Int2Vector
-        | SqlScalarType::Range { .. } => false,
+        | SqlScalarType::Range { .. }
+        | SqlScalarType::MultiRange { .. } => false,
     }
 }
 
@@ -199,6 +207,8 @@ enum DatumColumnEncoder {
     Uuid(FixedSizeBinaryBuilder),
     AclItem(FixedSizeBinaryBuilder),
     MzAclItem(BinaryBuilder),
+    Inet(FixedSizeBinaryBuilder),
+    MacAddr(FixedSizeBinaryBuilder),
     Range(BinaryBuilder),
     /// Hand rolled "StringBuilder" that reduces the number of copies required
     /// to serialize JSON.
@@ -289,6 +299,8 @@ impl DatumColumnEncoder {
             DatumColumnEncoder::Uuid(a) => a.len() * size_of::<Uuid>(),
             DatumColumnEncoder::AclItem(a) => a.len() * PackedAclItem::SIZE,
             DatumColumnEncoder::MzAclItem(a) => a.values_slice().len(),
+            DatumColumnEncoder::Inet(a) => a.len() * PackedInet::SIZE,
+            DatumColumnEncoder::MacAddr(a) => a.len() * MacAddr::BINARY_SIZE,
             DatumColumnEncoder::Range(a) => a.values_slice().len(),
             DatumColumnEncoder::Jsonb { buf, .. } => buf.len(),
             DatumColumnEncoder::Array { dims, vals, .. } => {
@@ -386,6 +398,15 @@ impl DatumColumnEncoder {
                 let packed = PackedMzAclItem::from_value(val);
                 builder.append_value(packed.as_bytes());
             }
+            (DatumColumnEncoder::Inet(builder), Datum::Inet(val)) => {
+                let packed = PackedInet::from_value(val);
+                builder
+                    .append_value(packed.as_bytes())
+                    .expect("known correct size");
+            }
+            (DatumColumnEncoder::MacAddr(builder), Datum::MacAddr(val)) => {
+                builder.append_value(val.0).expect("known correct size")
+            }
             (DatumColumnEncoder::Range(builder), d @ Datum::Range(_)) => {
                 let proto = ProtoDatum::from(d);
                 let bytes = proto.encode_to_vec();
@@ -550,6 +571,8 @@ impl DatumColumnEncoder {
             DatumColumnEncoder::Uuid(builder) => builder.append_null(),
             DatumColumnEncoder::AclItem(builder) => builder.append_null(),
             DatumColumnEncoder::MzAclItem(builder) => builder.append_null(),
+            DatumColumnEncoder::Inet(builder) => builder.append_null(),
+            DatumColumnEncoder::MacAddr(builder) => builder.append_null(),
             DatumColumnEncoder::Range(builder) => builder.append_null(),
             DatumColumnEncoder::Jsonb {
                 offsets,
@@ -736,6 +759,8 @@ impl DatumColumnEncoder {
             }
             DatumColumnEncoder::AclItem(mut builder) => Arc::new(builder.finish()),
             DatumColumnEncoder::MzAclItem(mut builder) => Arc::new(builder.finish()),
+            DatumColumnEncoder::Inet(mut builder) => Arc::new(builder.finish()),
+            DatumColumnEncoder::MacAddr(mut builder) => Arc::new(builder.finish()),
             DatumColumnEncoder::Range(mut builder) => Arc::new(builder.finish()),
             DatumColumnEncoder::Jsonb {
                 offsets,
@@ -903,6 +928,8 @@ enum DatumColumnDecoder {
     Range(BinaryArray),
     MzAclItem(BinaryArray),
     AclItem(FixedSizeBinaryArray),
+    Inet(FixedSizeBinaryArray),
+    MacAddr(FixedSizeBinaryArray),
 }
 
 impl DatumColumnDecoder {
@@ -1025,6 +1052,18 @@ impl DatumColumnDecoder {
                     Datum::MzAclItem(packed.into_value())
                 })
             }
+            DatumColumnDecoder::Inet(array) => {
+                array.is_valid(idx).then(|| array.value(idx)).map(|x| {
+                    let packed = PackedInet::from_bytes(x).expect("failed to roundtrip Inet");
+                    Datum::Inet(packed.into_value())
+                })
+            }
+            DatumColumnDecoder::MacAddr(array) => {
+                array.is_valid(idx).then(|| array.value(idx)).map(|x| {
+                    let bytes = x.try_into().expect("failed to roundtrip MacAddr");
+                    Datum::MacAddr(MacAddr(bytes))
+                })
+            }
             DatumColumnDecoder::Range(array) => {
                 let Some(val) = array.is_valid(idx).then(|| array.value(idx)) else {
                     packer.push(Datum::Null);
@@ -1207,6 +1246,8 @@ impl DatumColumnDecoder {
             }
             DatumColumnDecoder::AclItem(_)
             | DatumColumnDecoder::MzAclItem(_)
+            | DatumColumnDecoder::Inet(_)
+            | DatumColumnDecoder::MacAddr(_)
             | DatumColumnDecoder::Range(_) => ColumnStatKinds::None,
             DatumColumnDecoder::Json(a) => stats_for_json(a.iter()).values,
             DatumColumnDecoder::Array { .. }
@@ -1241,6 +1282,8 @@ impl DatumColumnDecoder {
             DatumColumnDecoder::Uuid(a) => ArrayOrd::FixedSizeBinary(a.clone()).goodbytes(),
             DatumColumnDecoder::AclItem(a) => ArrayOrd::FixedSizeBinary(a.clone()).goodbytes(),
             DatumColumnDecoder::MzAclItem(a) => ArrayOrd::Binary(a.clone()).goodbytes(),
+            DatumColumnDecoder::Inet(a) => ArrayOrd::FixedSizeBinary(a.clone()).goodbytes(),
+            DatumColumnDecoder::MacAddr(a) => ArrayOrd::FixedSizeBinary(a.clone()).goodbytes(),
             DatumColumnDecoder::Range(a) => ArrayOrd::Binary(a.clone()).goodbytes(),
             DatumColumnDecoder::Json(a) => ArrayOrd::String(a.clone()).goodbytes(),
             DatumColumnDecoder::Array { dims, vals, .. } => {
@@ -1558,7 +1601,8 @@ fn array_to_decoder(
             | SqlScalarType::Oid
             | SqlScalarType::RegClass
             | SqlScalarType::RegProc
-            | SqlScalarType::RegType,
+            | SqlScalarType::RegType
+            | SqlScalarType::Enum { .. },
         ) => {
             let array = downcast_array::<UInt32Array>(array)?;
             DatumColumnDecoder::U32(array.clone())
@@ -1648,6 +1692,17 @@ fn array_to_decoder(
             let array = downcast_array::<BinaryArray>(array)?;
             DatumColumnDecoder::MzAclItem(array.clone())
         }
+        (
+            DataType::FixedSizeBinary(INET_FIXED_BYTES),
+            SqlScalarType::Inet | SqlScalarType::Cidr,
+        ) => {
+            let array = downcast_array::<FixedSizeBinaryArray>(array)?;
+            DatumColumnDecoder::Inet(array.clone())
+        }
+        (DataType::FixedSizeBinary(MAC_ADDR_FIXED_BYTES), SqlScalarType::MacAddr) => {
+            let array = downcast_array::<FixedSizeBinaryArray>(array)?;
+            DatumColumnDecoder::MacAddr(array.clone())
+        }
         (DataType::Binary, SqlScalarType::Range { .. }) => {
             let array = downcast_array::<BinaryArray>(array)?;
             DatumColumnDecoder::Range(array.clone())
@@ -1695,6 +1750,19 @@ fn array_to_decoder(
                 nulls: array.nulls().cloned(),
             }
         }
+        (DataType::List(_), SqlScalarType::MultiRange { element_type }) => {
+            // Multiranges are encoded as a list of their ranges.
+            let array = downcast_array::<ListArray>(array)?;
+            let range_type = SqlScalarType::Range {
+                element_type: element_type.clone(),
+            };
+            let inner_decoder = array_to_decoder(array.values(), &range_type)?;
+            DatumColumnDecoder::List {
+                offsets: array.offsets().clone(),
+                values: Box::new(inner_decoder),
+                nulls: array.nulls().cloned(),
+            }
+        }
         (DataType::Map(_, true), SqlScalarType::Map { value_type, .. }) => {
             let array = downcast_array::<MapArray>(array)?;
             let keys = downcast_array::<StringArray>(array.keys())?;
@@ -1762,7 +1830,8 @@ fn scalar_type_to_encoder(col_ty: &SqlScalarType) -> Result<DatumColumnEncoder,
         | SqlScalarType::Oid
         | SqlScalarType::RegClass
         | SqlScalarType::RegProc
-        | SqlScalarType::RegType => DatumColumnEncoder::U32(UInt32Builder::new()),
+        | SqlScalarType::RegType
+        | SqlScalarType::Enum { .. } => DatumColumnEncoder::U32(UInt32Builder::new()),
         SqlScalarType::UInt64 => DatumColumnEncoder::U64(UInt64Builder::new()),
         SqlScalarType::Int16 => DatumColumnEncoder::I16(Int16Builder::new()),
         SqlScalarType::Int32 => DatumColumnEncoder::I32(Int32Builder::new()),
@@ -1800,6 +1869,12 @@ fn scalar_type_to_encoder(col_ty: &SqlScalarType) -> Result<DatumColumnEncoder,
             DatumColumnEncoder::AclItem(FixedSizeBinaryBuilder::new(ACL_ITEM_FIXED_BYTES))
         }
         SqlScalarType::MzAclItem => DatumColumnEncoder::MzAclItem(BinaryBuilder::new()),
+        SqlScalarType::Inet | SqlScalarType::Cidr => {
+            DatumColumnEncoder::Inet(FixedSizeBinaryBuilder::new(INET_FIXED_BYTES))
+        }
+        SqlScalarType::MacAddr => {
+            DatumColumnEncoder::MacAddr(FixedSizeBinaryBuilder::new(MAC_ADDR_FIXED_BYTES))
+        }
         SqlScalarType::Range { .. } => DatumColumnEncoder::Range(BinaryBuilder::new()),
         SqlScalarType::Jsonb => DatumColumnEncoder::Jsonb {
             offsets: vec![0],
@@ -1828,6 +1903,16 @@ fn scalar_type_to_encoder(col_ty: &SqlScalarType) -> Result<DatumColumnEncoder,
                 nulls: None,
             }
         }
+        SqlScalarType::MultiRange { element_type } => {
+            let inner = scalar_type_to_encoder(&SqlScalarType::Range {
+                element_type: element_type.clone(),
+            })?;
+            DatumColumnEncoder::List {
+                lengths: Vec::new(),
+                values: Box::new(inner),
+                nulls: None,
+            }
+        }
         SqlScalarType::Map { value_type, .. } => {
             let inner = scalar_type_to_encoder(&*value_type)?;
             DatumColumnEncoder::Map {
@@ -2023,6 +2108,8 @@ impl<'a> From<Datum<'a>> for ProtoDatum {
             })),
             Datum::MzAclItem(x) => DatumType::MzAclItem(x.into_proto()),
             Datum::AclItem(x) => DatumType::AclItem(x.into_proto()),
+            Datum::Inet(x) => DatumType::Inet(x.encode_binary()),
+            Datum::MacAddr(x) => DatumType::MacAddr(x.0.to_vec()),
         };
         ProtoDatum {
             datum_type: Some(datum_type),
@@ -2210,6 +2297,16 @@ impl RowPacker<'_> {
             }
             Some(DatumType::MzAclItem(x)) => self.push(Datum::MzAclItem(x.clone().into_rust()?)),
             Some(DatumType::AclItem(x)) => self.push(Datum::AclItem(x.clone().into_rust()?)),
+            Some(DatumType::Inet(x)) => {
+                let inet = Inet::decode_binary(x).map_err(|err| err.to_string())?;
+                self.push(Datum::Inet(inet));
+            }
+            Some(DatumType::MacAddr(x)) => {
+                let bytes = x.as_slice().try_into().map_err(|_| {
+                    format!("invalid mac_addr length: expected 6 bytes, got {}", x.len())
+                })?;
+                self.push(Datum::MacAddr(MacAddr(bytes)));
+            }
             None => return Err("unknown datum type".into()),
         };
         Ok(())
@@ -2397,7 +2494,11 @@ mod tests {
                         // We don't collect stats for these types.
                         SqlScalarType::AclItem
                         | SqlScalarType::MzAclItem
+                        | SqlScalarType::Inet
+                        | SqlScalarType::Cidr
+                        | SqlScalarType::MacAddr
                         | SqlScalarType::Range { .. }
+                        | SqlScalarType::MultiRange { .. }
                         | SqlScalarType::Array(_)
                         | SqlScalarType::Map { .. }
                         | SqlScalarType::List { .. }
@@ -13,6 +13,7 @@ use std::collections::BTreeMap;
 use std::fmt::{self, Debug};
 use std::hash::Hash;
 use std::iter;
+use std::net::{Ipv4Addr, Ipv6Addr};
 #[cfg(any(test, feature = "proptest"))]
 use std::ops::Add;
 use std::sync::LazyLock;
@@ -40,6 +41,7 @@ use uuid::Uuid;
 use crate::adt::array::{Array, ArrayDimension};
 use crate::adt::char::{Char, CharLength};
 use crate::adt::date::Date;
+use crate::adt::inet::{Inet, MacAddr};
 use crate::adt::interval::Interval;
 use crate::adt::jsonb::{Jsonb, JsonbRef};
 use crate::adt::mz_acl_item::{AclItem, AclMode, MzAclItem};
@@ -147,6 +149,10 @@ pub enum Datum<'a> {
     /// A list of privileges granted to a user that uses [`Oid`]s for role references.
     /// This type is used primarily for compatibility with PostgreSQL.
     AclItem(AclItem),
+    /// An IPv4 or IPv6 address with a network prefix length.
+    Inet(Inet),
+    /// A MAC address.
+    MacAddr(MacAddr),
     /// A placeholder value.
     ///
     /// Dummy values are never meant to be observed. Many operations on `Datum`
@@ -217,6 +223,8 @@ impl Debug for Datum<'_> {
             Datum::Range(x) => f.debug_tuple("Range").field(&redact(x)).finish(),
             Datum::MzAclItem(x) => f.debug_tuple("MzAclItem").field(&redact(x)).finish(),
             Datum::AclItem(x) => f.debug_tuple("AclItem").field(&redact(x)).finish(),
+            Datum::Inet(x) => f.debug_tuple("Inet").field(&redact(x)).finish(),
+            Datum::MacAddr(x) => f.debug_tuple("MacAddr").field(&redact(x)).finish(),
             Datum::Dummy => f.debug_tuple("Dummy").finish(),
             Datum::Null => f.debug_tuple("Null").finish(),
         }
@@ -976,6 +984,16 @@ impl<'a> Datum<'a> {
         }
     }
 
+    /// Unwraps the ranges of the multirange value within this datum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the datum is not a [`Datum::List`] of [`Datum::Range`]s.
+    #[track_caller]
+    pub fn unwrap_multirange(&self) -> Vec<Range<Datum<'a>>> {
+        self.unwrap_list().iter().map(|r| r.unwrap_range()).collect()
+    }
+
     /// Unwraps the mz_acl_item value within this datum.
     ///
     /// # Panics
@@ -1002,6 +1020,32 @@ impl<'a> Datum<'a> {
         }
     }
 
+    /// Unwraps the inet value within this datum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the datum is not [`Datum::Inet`].
+    #[track_caller]
+    pub fn unwrap_inet(&self) -> Inet {
+        match self {
+            Datum::Inet(inet) => *inet,
+            _ => panic!("Datum::unwrap_inet called on {:?}", self),
+        }
+    }
+
+    /// Unwraps the macaddr value within this datum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the datum is not [`Datum::MacAddr`].
+    #[track_caller]
+    pub fn unwrap_mac_addr(&self) -> MacAddr {
+        match self {
+            Datum::MacAddr(mac_addr) => *mac_addr,
+            _ => panic!("Datum::unwrap_mac_addr called on {:?}", self),
+        }
+    }
+
     /// Reports whether this datum is an instance of the specified (representation) column type.
     ///
     /// See [`Datum<'a>::is_instance_of_sql`] for comparing `Datum`s to `SqlColumnType`s.
@@ -1122,6 +1166,10 @@ impl<'a> Datum<'a> {
                     (Datum::MzAclItem(_), _) => false,
                     (Datum::AclItem(_), ReprScalarType::AclItem) => true,
                     (Datum::AclItem(_), _) => false,
+                    (Datum::Inet(_), ReprScalarType::Inet) => true,
+                    (Datum::Inet(_), _) => false,
+                    (Datum::MacAddr(_), ReprScalarType::MacAddr) => true,
+                    (Datum::MacAddr(_), _) => false,
                 }
             }
         }
@@ -1179,6 +1227,9 @@ impl<'a> Datum<'a> {
                     (Datum::UInt32(_), SqlScalarType::RegProc) => true,
                     (Datum::UInt32(_), SqlScalarType::RegType) => true,
                     (Datum::UInt32(_), SqlScalarType::UInt32) => true,
+                    (Datum::UInt32(ordinal), SqlScalarType::Enum { labels, .. }) => {
+                        usize::cast_from(ordinal) < labels.len()
+                    }
                     (Datum::UInt32(_), _) => false,
                     (Datum::UInt64(_), SqlScalarType::UInt64) => true,
                     (Datum::UInt64(_), _) => false,
@@ -1231,6 +1282,14 @@ impl<'a> Datum<'a> {
                             (e.is_null() && t.nullable) || is_instance_of_scalar(e, &t.scalar_type)
                         })
                     }
+                    (Datum::List(list), SqlScalarType::MultiRange { element_type }) => {
+                        let range_type = SqlScalarType::Range {
+                            element_type: element_type.clone(),
+                        };
+                        list.iter().all(|e| {
+                            matches!(e, Datum::Range(_)) && is_instance_of_scalar(e, &range_type)
+                        })
+                    }
                     (Datum::List(_), _) => false,
                     (Datum::Map(map), SqlScalarType::Map { value_type, .. }) => map
                         .iter()
@@ -1260,6 +1319,12 @@ impl<'a> Datum<'a> {
                     (Datum::MzAclItem(_), _) => false,
                     (Datum::AclItem(_), SqlScalarType::AclItem) => true,
                     (Datum::AclItem(_), _) => false,
+                    (Datum::Inet(_), SqlScalarType::Inet) => true,
+                    // `cidr` values never have bits set outside of their prefix.
+                    (Datum::Inet(inet), SqlScalarType::Cidr) => inet.is_network(),
+                    (Datum::Inet(_), _) => false,
+                    (Datum::MacAddr(_), SqlScalarType::MacAddr) => true,
+                    (Datum::MacAddr(_), _) => false,
                 }
             }
         }
@@ -1500,6 +1565,20 @@ impl<'a> From<MzAclItem> for Datum<'a> {
     }
 }
 
+impl<'a> From<Inet> for Datum<'a> {
+    #[inline]
+    fn from(inet: Inet) -> Self {
+        Datum::Inet(inet)
+    }
+}
+
+impl<'a> From<MacAddr> for Datum<'a> {
+    #[inline]
+    fn from(mac_addr: MacAddr) -> Self {
+        Datum::MacAddr(mac_addr)
+    }
+}
+
 impl<'a, T> From<Option<T>> for Datum<'a>
 where
     Datum<'a>: From<T>,
@@ -1592,6 +1671,8 @@ impl fmt::Display for Datum<'_> {
             Datum::Range(i) => write!(f, "{}", i),
             Datum::MzAclItem(mz_acl_item) => write!(f, "{mz_acl_item}"),
             Datum::AclItem(acl_item) => write!(f, "{acl_item}"),
+            Datum::Inet(inet) => write!(f, "{inet}"),
+            Datum::MacAddr(mac_addr) => write!(f, "{mac_addr}"),
         }
     }
 }
@@ -1757,6 +1838,37 @@ pub enum SqlScalarType {
     MzAclItem,
     /// The type of [`Datum::AclItem`]
     AclItem,
+    /// An IPv4 or IPv6 host address with an optional network prefix, backed
+    /// by [`Datum::Inet`].
+    Inet,
+    /// An IPv4 or IPv6 network, backed by a [`Datum::Inet`] with no bits set
+    /// outside of its prefix.
+    Cidr,
+    /// The type of [`Datum::MacAddr`].
+    MacAddr,
+    /// A set of non-overlapping ranges, backed by a [`Datum::List`] of
+    /// [`Datum::Range`]s.
+    ///
+    /// The ranges are kept in canonical form: none of them are empty, and
+    /// they are sorted, non-overlapping and non-adjacent.
+    MultiRange {
+        element_type: Box<SqlScalarType>,
+    },
+    /// A user-defined enumerated type backed by a [`Datum::UInt32`].
+    ///
+    /// The datum is the zero-based position of the value's label in
+    /// `labels`, so comparing datums compares values in declaration order.
+    /// Labels are only ever appended to an enum type, which keeps the
+    /// positions of existing values stable.
+    Enum {
+        custom_id: CatalogItemId,
+        /// The OID of the enum type, reported to PostgreSQL clients.
+        custom_oid: u32,
+        /// The labels of the enum type, in declaration order.
+        ///
+        /// Boxed slice to reduce the size of the enum variant.
+        labels: Box<[String]>,
+    },
 }
 
 impl RustType<ProtoRecordField> for (ColumnName, SqlColumnType) {
@@ -1849,6 +1961,21 @@ impl RustType<ProtoScalarType> for SqlScalarType {
                 })),
                 SqlScalarType::MzAclItem => MzAclItem(()),
                 SqlScalarType::AclItem => AclItem(()),
+                SqlScalarType::Inet => Inet(()),
+                SqlScalarType::Cidr => Cidr(()),
+                SqlScalarType::MacAddr => MacAddr(()),
+                SqlScalarType::MultiRange { element_type } => MultiRange(Box::new(ProtoRange {
+                    element_type: Some(element_type.into_proto()),
+                })),
+                SqlScalarType::Enum {
+                    custom_id,
+                    custom_oid,
+                    labels,
+                } => Enum(ProtoEnum {
+                    custom_id: Some(custom_id.into_proto()),
+                    custom_oid: *custom_oid,
+                    labels: labels.to_vec(),
+                }),
             }),
         }
     }
@@ -1935,6 +2062,21 @@ impl RustType<ProtoScalarType> for SqlScalarType {
             }),
             MzAclItem(()) => Ok(SqlScalarType::MzAclItem),
             AclItem(()) => Ok(SqlScalarType::AclItem),
+            Inet(()) => Ok(SqlScalarType::Inet),
+            Cidr(()) => Ok(SqlScalarType::Cidr),
+            MacAddr(()) => Ok(SqlScalarType::MacAddr),
+            MultiRange(x) => Ok(SqlScalarType::MultiRange {
+                element_type: Box::new(
+                    x.element_type
+                        .map(|x| *x)
+                        .into_rust_if_some("ProtoRange::element_type")?,
+                ),
+            }),
+            Enum(x) => Ok(SqlScalarType::Enum {
+                custom_id: x.custom_id.into_rust_if_some("ProtoEnum::custom_id")?,
+                custom_oid: x.custom_oid,
+                labels: x.labels.into(),
+            }),
         }
     }
 }
@@ -2397,6 +2539,8 @@ impl_datum_type_copy!(Uuid, Uuid);
 impl_datum_type_copy!('a, &'a str, String);
 impl_datum_type_copy!('a, &'a [u8], Bytes);
 impl_datum_type_copy!(crate::Timestamp, MzTimestamp);
+impl_datum_type_copy!(Inet, Inet);
+impl_datum_type_copy!(MacAddr, MacAddr);
 
 impl<'a, E> InputDatumType<'a, E> for Datum<'a> {
     fn nullable() -> bool {
@@ -3569,6 +3713,9 @@ impl SqlScalarType {
             Range { element_type } => Range {
                 element_type: Box::new(element_type.without_modifiers()),
             },
+            MultiRange { element_type } => MultiRange {
+                element_type: Box::new(element_type.without_modifiers()),
+            },
             v => v.clone(),
         }
     }
@@ -3665,6 +3812,22 @@ impl SqlScalarType {
         }
     }
 
+    /// Returns the [`SqlScalarType`] of the elements of the ranges in a
+    /// [`SqlScalarType::MultiRange`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called on anything other than a [`SqlScalarType::MultiRange`].
+    pub fn unwrap_multirange_element_type(&self) -> &SqlScalarType {
+        match self {
+            SqlScalarType::MultiRange { element_type } => &**element_type,
+            _ => panic!(
+                "SqlScalarType::unwrap_multirange_element_type called on {:?}",
+                self
+            ),
+        }
+    }
+
     /// Returns a "near match" of `self`, which are types that are implicitly
     /// castable from `self` and offer a means to leverage Materialize's type
     /// system to achieve more reasonable approaches to unifying types.
@@ -3736,6 +3899,7 @@ impl SqlScalarType {
                         .map(|(_, t)| t)
                         .any(|t| t.scalar_type.is_custom_type())
             }
+            Enum { .. } => true,
             _ => false,
         }
     }
@@ -3813,6 +3977,32 @@ impl SqlScalarType {
             ) => Ok(Range {
                 element_type: Box::new(element_type.sql_union(other_element_type)?),
             }),
+            (
+                MultiRange { element_type },
+                MultiRange {
+                    element_type: other_element_type,
+                },
+            ) => Ok(MultiRange {
+                element_type: Box::new(element_type.sql_union(other_element_type)?),
+            }),
+            // Labels are only ever appended to an enum type, so the longer
+            // label list is the more recent description of the same type.
+            (
+                Enum {
+                    custom_id, labels, ..
+                },
+                Enum {
+                    custom_id: other_custom_id,
+                    labels: other_labels,
+                    ..
+                },
+            ) if custom_id == other_custom_id => {
+                if labels.len() >= other_labels.len() {
+                    Ok(self.clone())
+                } else {
+                    Ok(other.clone())
+                }
+            }
             (scalar_type, other_scalar_type) if scalar_type.base_eq(other_scalar_type) => {
                 Ok(scalar_type.without_modifiers())
             }
@@ -3865,7 +4055,9 @@ impl SqlScalarType {
                     custom_id: oid_r,
                 },
             ) => l.eq_inner(r, structure_only) && (oid_l == oid_r || structure_only),
-            (Array(a), Array(b)) | (Range { element_type: a }, Range { element_type: b }) => {
+            (Array(a), Array(b))
+            | (Range { element_type: a }, Range { element_type: b })
+            | (MultiRange { element_type: a }, MultiRange { element_type: b }) => {
                 a.eq_inner(b, structure_only)
             }
             (
@@ -3889,6 +4081,18 @@ impl SqlScalarType {
                                 && a.1.scalar_type.eq_inner(&b.1.scalar_type, structure_only)
                         })
             }
+            (
+                Enum {
+                    custom_id: oid_a,
+                    labels: labels_a,
+                    ..
+                },
+                Enum {
+                    custom_id: oid_b,
+                    labels: labels_b,
+                    ..
+                },
+            ) => oid_a == oid_b || (structure_only && labels_a == labels_b),
             (s, o) => SqlScalarBaseType::from(s) == SqlScalarBaseType::from(o),
         }
     }
@@ -4276,6 +4480,29 @@ impl SqlScalarType {
                 Datum::Uuid(Uuid::from_u128(u128::MAX)),
             ])
         });
+        static INET: LazyLock<Row> = LazyLock::new(|| {
+            Row::pack_slice(&[
+                Datum::Inet(Inet::host_only(Ipv4Addr::UNSPECIFIED.into())),
+                Datum::Inet(Inet::host_only(Ipv4Addr::BROADCAST.into())),
+                Datum::Inet(Inet::new(Ipv4Addr::new(10, 1, 2, 3).into(), 8).unwrap()),
+                Datum::Inet(Inet::host_only(Ipv6Addr::UNSPECIFIED.into())),
+                Datum::Inet(Inet::host_only(Ipv6Addr::from(u128::MAX).into())),
+            ])
+        });
+        static CIDR: LazyLock<Row> = LazyLock::new(|| {
+            Row::pack_slice(&[
+                Datum::Inet(Inet::new(Ipv4Addr::UNSPECIFIED.into(), 0).unwrap()),
+                Datum::Inet(Inet::host_only(Ipv4Addr::BROADCAST.into())),
+                Datum::Inet(Inet::new(Ipv6Addr::UNSPECIFIED.into(), 0).unwrap()),
+                Datum::Inet(Inet::host_only(Ipv6Addr::from(u128::MAX).into())),
+            ])
+        });
+        static MACADDR: LazyLock<Row> = LazyLock::new(|| {
+            Row::pack_slice(&[
+                Datum::MacAddr(MacAddr([u8::MIN; 6])),
+                Datum::MacAddr(MacAddr([u8::MAX; 6])),
+            ])
+        });
         static ARRAY: LazyLock<BTreeMap<&'static SqlScalarType, Row>> = LazyLock::new(|| {
             let generate_row = |inner_type: &SqlScalarType| {
                 let datums: Vec<_> = inner_type.interesting_datums().collect();
@@ -4396,6 +4623,9 @@ impl SqlScalarType {
             SqlScalarType::VarChar { .. } => Box::new((*STRING).iter().chain((*CHAR).iter())),
             SqlScalarType::Jsonb => Box::new((*JSONB).iter()),
             SqlScalarType::Uuid => Box::new((*UUID).iter()),
+            SqlScalarType::Inet => Box::new((*INET).iter()),
+            SqlScalarType::Cidr => Box::new((*CIDR).iter()),
+            SqlScalarType::MacAddr => Box::new((*MACADDR).iter()),
             SqlScalarType::Array(inner_type) => {
                 if matches!(inner_type.as_ref(), SqlScalarType::Array(_)) {
                     panic!("SqlScalarType::Array cannot have a nested Array");
@@ -4418,8 +4648,11 @@ impl SqlScalarType {
             SqlScalarType::Int2Vector => Box::new((*INT2VECTOR).iter()),
             SqlScalarType::MzTimestamp => Box::new((*MZTIMESTAMP).iter()),
             SqlScalarType::Range { .. } => Box::new((*RANGE).iter()),
+            SqlScalarType::MultiRange { .. } => Box::new((*LIST).iter()),
             SqlScalarType::MzAclItem { .. } => Box::new((*MZACLITEM).iter()),
             SqlScalarType::AclItem { .. } => Box::new((*ACLITEM).iter()),
+            // Valid datums depend on the number of labels in the enum type.
+            SqlScalarType::Enum { .. } => Box::new(iter::empty()),
         };
 
         iter
@@ -4478,6 +4711,9 @@ impl SqlScalarType {
             SqlScalarType::Int2Vector,
             SqlScalarType::MzTimestamp,
             SqlScalarType::MzAclItem,
+            SqlScalarType::Inet,
+            SqlScalarType::Cidr,
+            SqlScalarType::MacAddr,
             // TODO: Fill in some variants of these.
             /*
             SqlScalarType::AclItem,
@@ -4538,7 +4774,11 @@ impl SqlScalarType {
             | SqlScalarType::Int2Vector
             | SqlScalarType::MzTimestamp
             | SqlScalarType::Range { .. }
-            | SqlScalarType::MzAclItem { .. }) => Ok(t),
+            | SqlScalarType::MzAclItem { .. }
+            | SqlScalarType::Inet
+            | SqlScalarType::Cidr
+            | SqlScalarType::MacAddr
+            | SqlScalarType::MultiRange { .. }) => Ok(t),
 
             SqlScalarType::Array(elem) => Ok(elem.array_of_self_elem_type()?),
 
@@ -4546,7 +4786,9 @@ impl SqlScalarType {
             t @ (SqlScalarType::Char { .. }
             // not sensible to put in arrays
             | SqlScalarType::Map { .. }
-            | SqlScalarType::List { .. }) => Err(t),
+            | SqlScalarType::List { .. }
+            // enum types are not created with a corresponding array type
+            | SqlScalarType::Enum { .. }) => Err(t),
         }
     }
 }
@@ -4595,6 +4837,9 @@ impl Arbitrary for SqlScalarType {
             Just(SqlScalarType::PgLegacyName).boxed(),
             Just(SqlScalarType::Jsonb).boxed(),
             Just(SqlScalarType::Uuid).boxed(),
+            Just(SqlScalarType::Inet).boxed(),
+            Just(SqlScalarType::Cidr).boxed(),
+            Just(SqlScalarType::MacAddr).boxed(),
             Just(SqlScalarType::AclItem).boxed(),
             Just(SqlScalarType::MzAclItem).boxed(),
             Just(SqlScalarType::Oid).boxed(),
@@ -4711,6 +4956,8 @@ impl Arbitrary for ReprScalarType {
             Just(ReprScalarType::String).boxed(),
             Just(ReprScalarType::Jsonb).boxed(),
             Just(ReprScalarType::Uuid).boxed(),
+            Just(ReprScalarType::Inet).boxed(),
+            Just(ReprScalarType::MacAddr).boxed(),
             Just(ReprScalarType::AclItem).boxed(),
             Just(ReprScalarType::MzAclItem).boxed(),
             Just(ReprScalarType::Int2Vector).boxed(),
@@ -4830,6 +5077,8 @@ pub enum ReprScalarType {
     Range { element_type: Box<ReprScalarType> },
     MzAclItem,
     AclItem,
+    Inet, // also includes SqlScalarType::Cidr
+    MacAddr,
 }
 
 impl PartialEq for ReprScalarType {
@@ -4953,6 +5202,8 @@ impl std::fmt::Display for ReprScalarType {
             ReprScalarType::Range { element_type } => write!(f, "r_range({element_type})"),
             ReprScalarType::MzAclItem => write!(f, "r_mz_acl_item"),
             ReprScalarType::AclItem => write!(f, "r_acl_item"),
+            ReprScalarType::Inet => write!(f, "r_inet"),
+            ReprScalarType::MacAddr => write!(f, "r_mac_addr"),
         }
     }
 }
@@ -5000,6 +5251,8 @@ impl ReprScalarType {
             (ReprScalarType::Jsonb, ReprScalarType::Jsonb) => Ok(ReprScalarType::Jsonb),
             (ReprScalarType::String, ReprScalarType::String) => Ok(ReprScalarType::String),
             (ReprScalarType::Uuid, ReprScalarType::Uuid) => Ok(ReprScalarType::Uuid),
+            (ReprScalarType::Inet, ReprScalarType::Inet) => Ok(ReprScalarType::Inet),
+            (ReprScalarType::MacAddr, ReprScalarType::MacAddr) => Ok(ReprScalarType::MacAddr),
             (ReprScalarType::Array(element_type), ReprScalarType::Array(other_element_type)) => Ok(
                 ReprScalarType::Array(Box::new(element_type.union(other_element_type)?)),
             ),
@@ -5143,6 +5396,15 @@ impl From<&SqlScalarType> for ReprScalarType {
             },
             SqlScalarType::MzAclItem => ReprScalarType::MzAclItem,
             SqlScalarType::AclItem => ReprScalarType::AclItem,
+            SqlScalarType::Inet => ReprScalarType::Inet,
+            SqlScalarType::Cidr => ReprScalarType::Inet,
+            SqlScalarType::MacAddr => ReprScalarType::MacAddr,
+            SqlScalarType::MultiRange { element_type } => ReprScalarType::List {
+                element_type: Box::new(ReprScalarType::Range {
+                    element_type: Box::new(element_type.as_ref().into()),
+                }),
+            },
+            SqlScalarType::Enum { .. } => ReprScalarType::UInt32,
         }
     }
 }
@@ -5221,6 +5483,8 @@ impl SqlScalarType {
             },
             ReprScalarType::MzAclItem => SqlScalarType::MzAclItem,
             ReprScalarType::AclItem => SqlScalarType::AclItem,
+            ReprScalarType::Inet => SqlScalarType::Inet,
+            ReprScalarType::MacAddr => SqlScalarType::MacAddr,
         }
     }
 }
@@ -5316,6 +5580,9 @@ pub enum PropDatum {
     AclItem(AclItem),
     MzAclItem(MzAclItem),
 
+    Inet(Inet),
+    MacAddr(MacAddr),
+
     JsonNull,
     Uuid(Uuid),
     Dummy,
@@ -5470,6 +5737,11 @@ pub fn arb_datum_for_scalar(scalar_type: SqlScalarType) -> impl Strategy<Value =
             .boxed(),
         SqlScalarType::AclItem => any::<AclItem>().prop_map(PropDatum::AclItem).boxed(),
         SqlScalarType::MzAclItem => any::<MzAclItem>().prop_map(PropDatum::MzAclItem).boxed(),
+        SqlScalarType::Inet => any::<Inet>().prop_map(PropDatum::Inet).boxed(),
+        SqlScalarType::Cidr => any::<Inet>()
+            .prop_map(|inet| PropDatum::Inet(inet.network()))
+            .boxed(),
+        SqlScalarType::MacAddr => any::<MacAddr>().prop_map(PropDatum::MacAddr).boxed(),
         SqlScalarType::Range { element_type } => {
             let data_strat = (
                 arb_datum_for_scalar(*element_type.clone()),
@@ -5939,6 +6211,8 @@ impl<'a> From<&'a PropDatum> for Datum<'a> {
             }
             AclItem(i) => Datum::AclItem(*i),
             MzAclItem(i) => Datum::MzAclItem(*i),
+            Inet(i) => Datum::Inet(*i),
+            MacAddr(m) => Datum::MacAddr(*m),
             JsonNull => Datum::JsonNull,
             Uuid(u) => Datum::from(*u),
             Dummy => Datum::Dummy,
@@ -129,7 +129,8 @@ pub fn col_values<'a>(
             | SqlScalarType::Oid
             | SqlScalarType::RegClass
             | SqlScalarType::RegProc
-            | SqlScalarType::RegType,
+            | SqlScalarType::RegType
+            | SqlScalarType::Enum { .. },
             ColumnStatKinds::Primitive(U32(stats)),
         ) => map_stats(stats, Datum::UInt32),
         (SqlScalarType::UInt64, ColumnStatKinds::Primitive(U64(stats))) => {
@@ -231,7 +232,11 @@ pub fn col_values<'a>(
         (
             SqlScalarType::AclItem
             | SqlScalarType::MzAclItem
+            | SqlScalarType::Inet
+            | SqlScalarType::Cidr
+            | SqlScalarType::MacAddr
             | SqlScalarType::Range { .. }
+            | SqlScalarType::MultiRange { .. }
             | SqlScalarType::Array(_)
             | SqlScalarType::Map { .. }
             | SqlScalarType::List { .. }
@@ -27,6 +27,7 @@ use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::error::Error;
 use std::fmt;
+use std::net::IpAddr;
 use std::num::FpCategory;
 use std::str::FromStr;
 use std::sync::LazyLock;
@@ -52,6 +53,7 @@ use uuid::Uuid;
 use crate::adt::array::ArrayDimension;
 use crate::adt::date::Date;
 use crate::adt::datetime::{self, DateOrder, DateTimeField, ParsedDateTime};
+use crate::adt::inet::{Inet, MacAddr};
 use crate::adt::interval::Interval;
 use crate::adt::jsonb::{Jsonb, JsonbRef};
 use crate::adt::mz_acl_item::{AclItem, MzAclItem};
@@ -1560,15 +1562,33 @@ where
 
     buf.take_while(|ch| ch.is_ascii_whitespace());
 
-    if buf.consume_str("empty") {
-        buf.take_while(|ch| ch.is_ascii_whitespace());
-        if buf.next().is_none() {
-            return Ok(None);
-        } else {
-            bail!("Junk after \"empty\" key word.")
+    let range = lex_range(buf, &mut gen_elem)?;
+
+    buf.take_while(|ch| ch.is_ascii_whitespace());
+
+    if buf.next().is_some() {
+        match range {
+            None => bail!("Junk after \"empty\" key word."),
+            Some(_) => bail!("Junk after right parenthesis or bracket."),
         }
     }
 
+    Ok(range)
+}
+
+/// Lexes a single range literal, i.e. `empty` or a bracketed pair of bounds,
+/// from the front of `buf`.
+fn lex_range<'a, V, E>(
+    buf: &mut LexBuf<'a>,
+    gen_elem: &mut impl FnMut(Cow<'a, str>) -> Result<V, E>,
+) -> Result<Option<RangeInner<V>>, String>
+where
+    E: ToString,
+{
+    if buf.consume_str("empty") {
+        return Ok(None);
+    }
+
     let lower_inclusive = match buf.next() {
         Some('[') => true,
         Some('(') => false,
@@ -1607,13 +1627,7 @@ where
         _ => bail!("Missing left parenthesis or bracket."),
     };
 
-    buf.take_while(|ch| ch.is_ascii_whitespace());
-
-    if buf.next().is_some() {
-        bail!("Junk after right parenthesis or bracket.")
-    }
-
-    let range = Some(RangeInner {
+    Ok(Some(RangeInner {
         lower: RangeBound {
             inclusive: lower_inclusive,
             bound: lower_bound,
@@ -1622,9 +1636,65 @@ where
             inclusive: upper_inclusive,
             bound: upper_bound,
         },
-    });
+    }))
+}
 
-    Ok(range)
+/// Parses a multirange, e.g. `{[1,3), [5,7)}`, into its constituent ranges.
+///
+/// The ranges are returned as written; callers are responsible for bringing
+/// them into the canonical multirange form.
+pub fn parse_multirange<'a, V, E>(
+    s: &'a str,
+    gen_elem: impl FnMut(Cow<'a, str>) -> Result<V, E>,
+) -> Result<Vec<Range<V>>, ParseError>
+where
+    E: ToString,
+{
+    parse_multirange_inner(s, gen_elem)
+        .map_err(|details| ParseError::invalid_input_syntax("multirange", s).with_details(details))
+}
+
+fn parse_multirange_inner<'a, V, E>(
+    s: &'a str,
+    mut gen_elem: impl FnMut(Cow<'a, str>) -> Result<V, E>,
+) -> Result<Vec<Range<V>>, String>
+where
+    E: ToString,
+{
+    let buf = &mut LexBuf::new(s);
+
+    buf.take_while(|ch| ch.is_ascii_whitespace());
+
+    if !buf.consume('{') {
+        bail!("Missing left brace.")
+    }
+
+    buf.take_while(|ch| ch.is_ascii_whitespace());
+
+    let mut ranges = vec![];
+    if !buf.consume('}') {
+        loop {
+            buf.take_while(|ch| ch.is_ascii_whitespace());
+            ranges.push(Range {
+                inner: lex_range(buf, &mut gen_elem)?,
+            });
+            buf.take_while(|ch| ch.is_ascii_whitespace());
+            match buf.next() {
+                Some(',') => {}
+                Some('}') => break,
+                Some(_) => bail!("Expected comma or end of multirange."),
+                None => bail!("Unexpected end of input."),
+            }
+        }
+    }
+
+    buf.take_while(|ch| ch.is_ascii_whitespace());
+
+    if buf.next().is_some() {
+        bail!("Junk after closing right brace.")
+    }
+
+    Ok(ranges)
 }
 
 /// Writes a [`Range`] to `buf`.
@@ -1675,6 +1745,28 @@ where
     Ok(Nestable::MayNeedEscaping)
 }
 
+/// Writes a multirange, represented by its constituent ranges, to `buf`.
+pub fn format_multirange<'r, F, V, E>(
+    buf: &mut F,
+    ranges: impl IntoIterator<Item = &'r Range<V>>,
+    mut format_elem: impl FnMut(RangeElementWriter<F>, Option<&V>) -> Result<Nestable, E>,
+) -> Result<Nestable, E>
+where
+    F: FormatBuffer,
+    V: 'r,
+{
+    buf.write_char('{');
+    let mut ranges = ranges.into_iter().peekable();
+    while let Some(range) = ranges.next() {
+        format_range(buf, range, &mut format_elem)?;
+        if ranges.peek().is_some() {
+            buf.write_char(',');
+        }
+    }
+    buf.write_char('}');
+    Ok(Nestable::MayNeedEscaping)
+}
+
 /// A helper for `format_range` that formats a single record element.
 #[derive(Debug)]
 pub struct RangeElementWriter<'a, F>(&'a mut F);
@@ -1833,6 +1925,85 @@ pub fn parse_acl_item(s: &str) -> Result<AclItem, ParseError> {
         .map_err(|e| ParseError::invalid_input_syntax("aclitem", s).with_details(e))
 }
 
+/// Parses an [`Inet`] from `s`, which contains an IPv4 or IPv6 address
+/// optionally followed by `/` and a prefix length.
+pub fn parse_inet(s: &str) -> Result<Inet, ParseError> {
+    parse_inet_internal(s).map_err(|details| {
+        let e = ParseError::invalid_input_syntax("inet", s);
+        match details {
+            Some(details) => e.with_details(details),
+            None => e,
+        }
+    })
+}
+
+fn parse_inet_internal(s: &str) -> Result<Inet, Option<String>> {
+    let s = s.trim();
+    let (addr, netmask) = match s.split_once('/') {
+        Some((addr, netmask)) => (addr, Some(netmask)),
+        None => (s, None),
+    };
+    let addr: IpAddr = addr.parse().map_err(|_| None)?;
+    match netmask {
+        None => Ok(Inet::host_only(addr)),
+        Some(netmask) => {
+            let netmask: u8 = netmask.parse().map_err(|_| None)?;
+            Inet::new(addr, netmask).map_err(|e| Some(e.to_string()))
+        }
+    }
+}
+
+/// Parses an [`Inet`] from `s` as a `cidr` value, which must not have any
+/// bits set outside of its network prefix.
+pub fn parse_cidr(s: &str) -> Result<Inet, ParseError> {
+    let inet = parse_inet_internal(s).map_err(|details| {
+        let e = ParseError::invalid_input_syntax("cidr", s);
+        match details {
+            Some(details) => e.with_details(details),
+            None => e,
+        }
+    })?;
+    if !inet.is_network() {
+        return Err(ParseError::invalid_input_syntax("cidr", s)
+            .with_details("Value has bits set to right of mask."));
+    }
+    Ok(inet)
+}
+
+/// Writes an `inet` to `buf`.
+pub fn format_inet<F>(buf: &mut F, inet: Inet) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{inet}");
+    Nestable::Yes
+}
+
+/// Writes a `cidr` to `buf`.
+pub fn format_cidr<F>(buf: &mut F, cidr: Inet) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{}", cidr.display_cidr());
+    Nestable::Yes
+}
+
+/// Parses a [`MacAddr`] from `s`.
+pub fn parse_mac_addr(s: &str) -> Result<MacAddr, ParseError> {
+    s.trim()
+        .parse()
+        .map_err(|e| ParseError::invalid_input_syntax("macaddr", s).with_details(e))
+}
+
+/// Writes a `macaddr` to `buf`.
+pub fn format_mac_addr<F>(buf: &mut F, mac_addr: MacAddr) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{mac_addr}");
+    Nestable::Yes
+}
+
 pub trait ElementEscaper {
     fn needs_escaping(elem: &[u8]) -> bool;
     fn escape_char(c: u8) -> u8;
@@ -169,6 +169,7 @@ impl Keyword {
                 | EXISTS
                 | EXTRACT
                 | GREATEST
+                | GROUPING
                 | LEAST
                 | MAP
                 | NORMALIZE
@@ -1333,7 +1333,7 @@ impl<'a, 'u> Gen<'a, 'u> {
 
     /// An optional `INCLUDE <metadata>, …` clause (distinct, order-stable).
     fn include_metadata(&mut self) {
-        let opts = ["KEY", "PARTITION", "OFFSET", "TIMESTAMP", "HEADERS"];
+        let opts = ["KEY", "TOPIC", "PARTITION", "OFFSET", "TIMESTAMP", "HEADERS"];
         let included: Vec<usize> = (0..opts.len()).filter(|_| self.chance(1, 3)).collect();
         if included.is_empty() {
             return;
@@ -1344,8 +1344,8 @@ impl<'a, 'u> Gen<'a, 'u> {
                 self.out.push_str(", ");
             }
             self.out.push_str(opts[i]);
-            // KEY / PARTITION can carry an optional alias.
-            if (opts[i] == "KEY" || opts[i] == "PARTITION") && self.chance(1, 2) {
+            // KEY / TOPIC / PARTITION can carry an optional alias.
+            if matches!(opts[i], "KEY" | "TOPIC" | "PARTITION") && self.chance(1, 2) {
                 self.out.push_str(" AS ");
                 self.ident();
             }
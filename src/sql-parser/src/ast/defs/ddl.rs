@@ -639,6 +639,9 @@ pub enum SourceIncludeMetadata {
     Timestamp {
         alias: Option<Ident>,
     },
+    Topic {
+        alias: Option<Ident>,
+    },
     Partition {
         alias: Option<Ident>,
     },
@@ -673,6 +676,10 @@ impl AstDisplay for SourceIncludeMetadata {
                 f.write_str("TIMESTAMP");
                 print_alias(f, alias);
             }
+            SourceIncludeMetadata::Topic { alias } => {
+                f.write_str("TOPIC");
+                print_alias(f, alias);
+            }
             SourceIncludeMetadata::Partition { alias } => {
                 f.write_str("PARTITION");
                 print_alias(f, alias);
@@ -1167,6 +1174,8 @@ impl_display_t!(CreateConnectionOption);
 pub enum KafkaSourceConfigOptionName {
     GroupIdPrefix,
     Topic,
+    Topics,
+    TopicPattern,
     TopicMetadataRefreshInterval,
     StartTimestamp,
     StartOffset,
@@ -1177,6 +1186,8 @@ impl AstDisplay for KafkaSourceConfigOptionName {
         f.write_str(match self {
             KafkaSourceConfigOptionName::GroupIdPrefix => "GROUP ID PREFIX",
             KafkaSourceConfigOptionName::Topic => "TOPIC",
+            KafkaSourceConfigOptionName::Topics => "TOPICS",
+            KafkaSourceConfigOptionName::TopicPattern => "TOPIC PATTERN",
             KafkaSourceConfigOptionName::TopicMetadataRefreshInterval => {
                 "TOPIC METADATA REFRESH INTERVAL"
             }
@@ -1197,6 +1208,8 @@ impl WithOptionName for KafkaSourceConfigOptionName {
         match self {
             KafkaSourceConfigOptionName::GroupIdPrefix
             | KafkaSourceConfigOptionName::Topic
+            | KafkaSourceConfigOptionName::Topics
+            | KafkaSourceConfigOptionName::TopicPattern
             | KafkaSourceConfigOptionName::TopicMetadataRefreshInterval
             | KafkaSourceConfigOptionName::StartOffset
             | KafkaSourceConfigOptionName::StartTimestamp => false,
@@ -1334,6 +1347,9 @@ pub enum PgConfigOptionName {
     /// fully deprecating that feature and forcing users to use explicit
     /// `CREATE TABLE .. FROM SOURCE` statements
     ExcludeColumns,
+    /// How exports react to a `TRUNCATE` of their upstream table, unless
+    /// they specify their own behavior
+    TruncateBehavior,
 }
 
 impl AstDisplay for PgConfigOptionName {
@@ -1343,6 +1359,7 @@ impl AstDisplay for PgConfigOptionName {
             PgConfigOptionName::Publication => "PUBLICATION",
             PgConfigOptionName::TextColumns => "TEXT COLUMNS",
             PgConfigOptionName::ExcludeColumns => "EXCLUDE COLUMNS",
+            PgConfigOptionName::TruncateBehavior => "TRUNCATE BEHAVIOR",
         })
     }
 }
@@ -1359,7 +1376,8 @@ impl WithOptionName for PgConfigOptionName {
             PgConfigOptionName::Details
             | PgConfigOptionName::Publication
             | PgConfigOptionName::TextColumns
-            | PgConfigOptionName::ExcludeColumns => false,
+            | PgConfigOptionName::ExcludeColumns
+            | PgConfigOptionName::TruncateBehavior => false,
         }
     }
 }
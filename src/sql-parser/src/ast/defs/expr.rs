@@ -133,6 +133,13 @@ pub enum Expr<T: AstInfo> {
         l_expr: Box<Expr<T>>,
         r_expr: Box<Expr<T>>,
     },
+    /// `GROUPING(<expr>, ...)`
+    ///
+    /// Reports which of its arguments are aggregated away in the grouping set
+    /// that produced the current row. It has function call syntax, but its
+    /// arguments are matched against the `GROUP BY` clause rather than
+    /// evaluated.
+    Grouping(Vec<Expr<T>>),
     /// Nested expression e.g. `(foo > bar)` or `(1)`
     Nested(Box<Expr<T>>),
     /// A row constructor like `ROW(<expr>...)` or `(<expr>, <expr>...)`.
@@ -415,6 +422,11 @@ impl<T: AstInfo> AstDisplay for Expr<T> {
                 f.write_node(&display::comma_separated(&[l_expr, r_expr]));
                 f.write_str(")");
             }
+            Expr::Grouping(exprs) => {
+                f.write_str("GROUPING(");
+                f.write_node(&display::comma_separated(exprs));
+                f.write_str(")");
+            }
             Expr::Nested(ast) => {
                 f.write_str("(");
                 f.write_node(&ast);
@@ -594,6 +606,7 @@ fn write_dot_receiver<W: fmt::Write, T: AstInfo>(f: &mut AstFormatter<W>, expr:
             | Expr::Subscript { .. }
             | Expr::HomogenizingFunction { .. }
             | Expr::NullIf { .. }
+            | Expr::Grouping(_)
             | Expr::Value(
                 Value::String(_)
                     | Value::Boolean(_)
@@ -847,6 +860,7 @@ fn prints_self_delimiting<T: AstInfo>(expr: &Expr<T>) -> bool {
         | Expr::Function(_)
         | Expr::HomogenizingFunction { .. }
         | Expr::NullIf { .. }
+        | Expr::Grouping(_)
         | Expr::Subquery(_)
         | Expr::Exists(_)
         | Expr::Nested(_)
@@ -924,6 +938,7 @@ fn write_subscript_receiver<W: fmt::Write, T: AstInfo>(f: &mut AstFormatter<W>,
         | Expr::Function(_)
         | Expr::HomogenizingFunction { .. }
         | Expr::NullIf { .. }
+        | Expr::Grouping(_)
         | Expr::Nested(_)
         | Expr::Subquery(_)
         | Expr::Exists(_)
@@ -1321,7 +1336,8 @@ impl<T: AstInfo> Function<T> {
         let has_call_modifiers = self.distinct
             || self.filter.is_some()
             || self.over.is_some()
-            || matches!(&self.args, FunctionArgs::Args { order_by, .. } if !order_by.is_empty());
+            || matches!(&self.args, FunctionArgs::Args { order_by, .. } if !order_by.is_empty())
+            || matches!(&self.args, FunctionArgs::WithinGroup { .. });
         if allow_special_form && !f.stable() && !has_call_modifiers {
             let special: Option<(&str, &[Option<Keyword>])> =
                 match self.name.to_ast_string_stable().as_str() {
@@ -1400,6 +1416,11 @@ impl<T: AstInfo> Function<T> {
         }
         f.write_node(&self.args);
         f.write_str(")");
+        if let FunctionArgs::WithinGroup { order_by, .. } = &self.args {
+            f.write_str(" WITHIN GROUP (ORDER BY ");
+            f.write_node(&display::comma_separated(order_by));
+            f.write_str(")");
+        }
         if let Some(filter) = &self.filter {
             f.write_str(" FILTER (WHERE ");
             f.write_node(&filter);
@@ -1422,6 +1443,13 @@ pub enum FunctionArgs<T: AstInfo> {
         args: Vec<Expr<T>>,
         order_by: Vec<OrderByExpr<T>>,
     },
+    /// The direct arguments of an ordered-set aggregate, along with the
+    /// sort specification of its `WITHIN GROUP (ORDER BY ...)` clause, as in
+    /// `percentile_cont(0.5) WITHIN GROUP (ORDER BY x)`.
+    WithinGroup {
+        args: Vec<Expr<T>>,
+        order_by: Vec<OrderByExpr<T>>,
+    },
 }
 
 impl<T: AstInfo> FunctionArgs<T> {
@@ -1436,7 +1464,9 @@ impl<T: AstInfo> FunctionArgs<T> {
     pub fn first(&self) -> Option<&Expr<T>> {
         match self {
             FunctionArgs::Star => None,
-            FunctionArgs::Args { args, .. } => args.first(),
+            FunctionArgs::Args { args, .. } | FunctionArgs::WithinGroup { args, .. } => {
+                args.first()
+            }
         }
     }
 
@@ -1444,7 +1474,9 @@ impl<T: AstInfo> FunctionArgs<T> {
     pub fn len(&self) -> Option<usize> {
         match self {
             FunctionArgs::Star => None,
-            FunctionArgs::Args { args, .. } => Some(args.len()),
+            FunctionArgs::Args { args, .. } | FunctionArgs::WithinGroup { args, .. } => {
+                Some(args.len())
+            }
         }
     }
 
@@ -1455,7 +1487,7 @@ impl<T: AstInfo> FunctionArgs<T> {
         kws: &[Option<Keyword>],
     ) {
         let args = match self {
-            FunctionArgs::Star => unreachable!(),
+            FunctionArgs::Star | FunctionArgs::WithinGroup { .. } => unreachable!(),
             FunctionArgs::Args { args, .. } => args,
         };
         soft_assert_eq_or_log!(args.len(), kws.len());
@@ -1484,6 +1516,11 @@ impl<T: AstInfo> AstDisplay for FunctionArgs<T> {
                     f.write_node(&display::comma_separated(order_by));
                 }
             }
+            // The `WITHIN GROUP` clause follows the closing parenthesis, so
+            // `Function` is responsible for printing it.
+            FunctionArgs::WithinGroup { args, .. } => {
+                f.write_node(&display::comma_separated(args));
+            }
         }
     }
 }
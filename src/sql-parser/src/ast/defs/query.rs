@@ -242,6 +242,76 @@ pub struct SelectOption<T: AstInfo> {
 }
 impl_display_for_with_option!(SelectOption);
 
+/// An item of a `GROUP BY` clause.
+///
+/// The grouping sets of a `GROUP BY` clause are the cross product of the
+/// grouping sets of its items. A plain expression contributes a single set.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GroupByItem<T: AstInfo> {
+    /// A grouping expression.
+    Expr(Expr<T>),
+    /// `(<expr>, ...)`, a single grouping set of the listed expressions.
+    /// Only appears inside `GROUPING SETS`, except for the empty grouping
+    /// set `()`.
+    Set(Vec<Expr<T>>),
+    /// `ROLLUP (<element>, ...)`: the full list of elements and each of its
+    /// prefixes, down to the empty set. Each element is one or more
+    /// expressions grouped as a unit.
+    Rollup(Vec<Vec<Expr<T>>>),
+    /// `CUBE (<element>, ...)`: every subset of the elements.
+    Cube(Vec<Vec<Expr<T>>>),
+    /// `GROUPING SETS (<item>, ...)`: the union of the grouping sets of each
+    /// item.
+    GroupingSets(Vec<GroupByItem<T>>),
+}
+
+impl<T: AstInfo> AstDisplay for GroupByItem<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            GroupByItem::Expr(expr) => f.write_node(expr),
+            GroupByItem::Set(exprs) => {
+                f.write_str("(");
+                f.write_node(&display::comma_separated(exprs));
+                f.write_str(")");
+            }
+            GroupByItem::Rollup(elements) => {
+                f.write_str("ROLLUP (");
+                write_grouping_elements(f, elements);
+                f.write_str(")");
+            }
+            GroupByItem::Cube(elements) => {
+                f.write_str("CUBE (");
+                write_grouping_elements(f, elements);
+                f.write_str(")");
+            }
+            GroupByItem::GroupingSets(items) => {
+                f.write_str("GROUPING SETS (");
+                f.write_node(&display::comma_separated(items));
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display_t!(GroupByItem);
+
+fn write_grouping_elements<W: fmt::Write, T: AstInfo>(
+    f: &mut AstFormatter<W>,
+    elements: &[Vec<Expr<T>>],
+) {
+    for (i, element) in elements.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ");
+        }
+        if let [expr] = element.as_slice() {
+            f.write_node(expr);
+        } else {
+            f.write_str("(");
+            f.write_node(&display::comma_separated(element));
+            f.write_str(")");
+        }
+    }
+}
+
 /// A restricted variant of `SELECT` (without CTEs/`ORDER BY`), which may
 /// appear either as the only body item of an `SQLQuery`, or as an operand
 /// to a set operation like `UNION`.
@@ -255,7 +325,7 @@ pub struct Select<T: AstInfo> {
     /// WHERE
     pub selection: Option<Expr<T>>,
     /// GROUP BY
-    pub group_by: Vec<Expr<T>>,
+    pub group_by: Vec<GroupByItem<T>>,
     /// HAVING
     pub having: Option<Expr<T>>,
     /// QUALIFY
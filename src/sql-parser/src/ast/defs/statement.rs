@@ -32,7 +32,7 @@ use crate::ast::{
     CreateSourceOptionName, DeferredItemName, Expr, Format, FormatSpecifier, IcebergSinkMode,
     Ident, IntervalValue, KeyConstraint, MaterializedViewOption, Query, SelectItem, SinkEnvelope,
     SourceEnvelope, SourceIncludeMetadata, SubscribeOutput, TableAlias, TableConstraint,
-    TableWithJoins, UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName,
+    TableFactor, TableWithJoins, UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName,
     UnresolvedSchemaName, Value,
 };
 
@@ -46,6 +46,7 @@ pub enum Statement<T: AstInfo> {
     Copy(CopyStatement<T>),
     Update(UpdateStatement<T>),
     Delete(DeleteStatement<T>),
+    Merge(MergeStatement<T>),
     CreateConnection(CreateConnectionStatement<T>),
     CreateDatabase(CreateDatabaseStatement),
     CreateSchema(CreateSchemaStatement),
@@ -59,6 +60,7 @@ pub enum Statement<T: AstInfo> {
     CreateTableFromSource(CreateTableFromSourceStatement<T>),
     CreateIndex(CreateIndexStatement<T>),
     CreateType(CreateTypeStatement<T>),
+    CreateFunction(CreateFunctionStatement<T>),
     CreateRole(CreateRoleStatement),
     CreateCluster(CreateClusterStatement<T>),
     CreateClusterReplica(CreateClusterReplicaStatement<T>),
@@ -71,6 +73,7 @@ pub enum Statement<T: AstInfo> {
     AlterRetainHistory(AlterRetainHistoryStatement<T>),
     AlterIndex(AlterIndexStatement<T>),
     AlterSecret(AlterSecretStatement<T>),
+    AlterTypeAddValue(AlterTypeAddValueStatement),
     AlterSetCluster(AlterSetClusterStatement<T>),
     AlterSink(AlterSinkStatement<T>),
     AlterSource(AlterSourceStatement<T>),
@@ -125,6 +128,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Copy(stmt) => f.write_node(stmt),
             Statement::Update(stmt) => f.write_node(stmt),
             Statement::Delete(stmt) => f.write_node(stmt),
+            Statement::Merge(stmt) => f.write_node(stmt),
             Statement::CreateConnection(stmt) => f.write_node(stmt),
             Statement::CreateDatabase(stmt) => f.write_node(stmt),
             Statement::CreateSchema(stmt) => f.write_node(stmt),
@@ -140,6 +144,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateRole(stmt) => f.write_node(stmt),
             Statement::CreateSecret(stmt) => f.write_node(stmt),
             Statement::CreateType(stmt) => f.write_node(stmt),
+            Statement::CreateFunction(stmt) => f.write_node(stmt),
             Statement::CreateCluster(stmt) => f.write_node(stmt),
             Statement::CreateClusterReplica(stmt) => f.write_node(stmt),
             Statement::CreateNetworkPolicy(stmt) => f.write_node(stmt),
@@ -152,6 +157,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::AlterIndex(stmt) => f.write_node(stmt),
             Statement::AlterSetCluster(stmt) => f.write_node(stmt),
             Statement::AlterSecret(stmt) => f.write_node(stmt),
+            Statement::AlterTypeAddValue(stmt) => f.write_node(stmt),
             Statement::AlterSink(stmt) => f.write_node(stmt),
             Statement::AlterSource(stmt) => f.write_node(stmt),
             Statement::AlterSystemSet(stmt) => f.write_node(stmt),
@@ -213,13 +219,16 @@ impl StatementKind {
 
     /// Whether this kind of statement can carry sensitive material that we
     /// redact from logged SQL text (and error messages): secret values, or
-    /// bulk/PII user data in `INSERT`/`UPDATE`/`EXECUTE`. A superset of
-    /// [`Self::is_secret`].
+    /// bulk/PII user data in `INSERT`/`UPDATE`/`MERGE`/`EXECUTE`. A superset
+    /// of [`Self::is_secret`].
     pub fn is_sensitive(&self) -> bool {
         self.is_secret()
             || matches!(
                 self,
-                StatementKind::Insert | StatementKind::Update | StatementKind::Execute
+                StatementKind::Insert
+                    | StatementKind::Update
+                    | StatementKind::Merge
+                    | StatementKind::Execute
             )
     }
 }
@@ -232,6 +241,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Copy => "copy",
         StatementKind::Update => "update",
         StatementKind::Delete => "delete",
+        StatementKind::Merge => "merge",
         StatementKind::CreateConnection => "create_connection",
         StatementKind::CreateDatabase => "create_database",
         StatementKind::CreateSchema => "create_schema",
@@ -245,6 +255,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::CreateTableFromSource => "create_table_from_source",
         StatementKind::CreateIndex => "create_index",
         StatementKind::CreateType => "create_type",
+        StatementKind::CreateFunction => "create_function",
         StatementKind::CreateRole => "create_role",
         StatementKind::CreateCluster => "create_cluster",
         StatementKind::CreateClusterReplica => "create_cluster_replica",
@@ -258,6 +269,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::AlterNetworkPolicy => "alter_network_policy",
         StatementKind::AlterRole => "alter_role",
         StatementKind::AlterSecret => "alter_secret",
+        StatementKind::AlterTypeAddValue => "alter_type_add_value",
         StatementKind::AlterSetCluster => "alter_set_cluster",
         StatementKind::AlterSink => "alter_sink",
         StatementKind::AlterSource => "alter_source",
@@ -346,6 +358,8 @@ pub struct InsertStatement<T: AstInfo> {
     pub columns: Vec<Ident>,
     /// A SQL query that specifies what to insert.
     pub source: InsertSource<T>,
+    /// ON CONFLICT
+    pub on_conflict: Option<OnConflict<T>>,
     /// RETURNING
     pub returning: Vec<SelectItem<T>>,
 }
@@ -361,6 +375,10 @@ impl<T: AstInfo> AstDisplay for InsertStatement<T> {
         }
         f.write_str(" ");
         f.write_node(&self.source);
+        if let Some(on_conflict) = &self.on_conflict {
+            f.write_str(" ");
+            f.write_node(on_conflict);
+        }
         if !self.returning.is_empty() {
             f.write_str(" RETURNING ");
             f.write_node(&display::comma_separated(&self.returning));
@@ -369,6 +387,55 @@ impl<T: AstInfo> AstDisplay for InsertStatement<T> {
 }
 impl_display_t!(InsertStatement);
 
+/// `ON CONFLICT (columns) DO ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OnConflict<T: AstInfo> {
+    /// The columns of the unique constraint that determines conflicts.
+    pub columns: Vec<Ident>,
+    pub action: OnConflictAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for OnConflict<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ON CONFLICT (");
+        f.write_node(&display::comma_separated(&self.columns));
+        f.write_str(") ");
+        f.write_node(&self.action);
+    }
+}
+impl_display_t!(OnConflict);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OnConflictAction<T: AstInfo> {
+    /// `DO NOTHING`
+    DoNothing,
+    /// `DO UPDATE SET ... [WHERE ...]`
+    DoUpdate {
+        assignments: Vec<Assignment<T>>,
+        selection: Option<Expr<T>>,
+    },
+}
+
+impl<T: AstInfo> AstDisplay for OnConflictAction<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            OnConflictAction::DoNothing => f.write_str("DO NOTHING"),
+            OnConflictAction::DoUpdate {
+                assignments,
+                selection,
+            } => {
+                f.write_str("DO UPDATE SET ");
+                f.write_node(&display::comma_separated(assignments));
+                if let Some(selection) = selection {
+                    f.write_str(" WHERE ");
+                    f.write_node(selection);
+                }
+            }
+        }
+    }
+}
+impl_display_t!(OnConflictAction);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CopyRelation<T: AstInfo> {
     Named {
@@ -422,9 +489,11 @@ pub enum CopyOptionName {
     Quote,
     Header,
     AwsConnection,
+    GcpConnection,
     MaxFileSize,
     Files,
     Pattern,
+    SingleColumn,
 }
 
 impl AstDisplay for CopyOptionName {
@@ -437,9 +506,11 @@ impl AstDisplay for CopyOptionName {
             CopyOptionName::Quote => "QUOTE",
             CopyOptionName::Header => "HEADER",
             CopyOptionName::AwsConnection => "AWS CONNECTION",
+            CopyOptionName::GcpConnection => "GCP CONNECTION",
             CopyOptionName::MaxFileSize => "MAX FILE SIZE",
             CopyOptionName::Files => "FILES",
             CopyOptionName::Pattern => "PATTERN",
+            CopyOptionName::SingleColumn => "SINGLE COLUMN",
         })
     }
 }
@@ -459,7 +530,9 @@ impl WithOptionName for CopyOptionName {
             | CopyOptionName::Quote
             | CopyOptionName::Header
             | CopyOptionName::AwsConnection
-            | CopyOptionName::MaxFileSize => false,
+            | CopyOptionName::GcpConnection
+            | CopyOptionName::MaxFileSize
+            | CopyOptionName::SingleColumn => false,
             CopyOptionName::Files | CopyOptionName::Pattern => true,
         }
     }
@@ -586,6 +659,134 @@ impl<T: AstInfo> AstDisplay for DeleteStatement<T> {
 }
 impl_display_t!(DeleteStatement);
 
+/// `MERGE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MergeStatement<T: AstInfo> {
+    /// `INTO`
+    pub table_name: T::ItemName,
+    /// `AS`
+    pub alias: Option<TableAlias>,
+    /// `USING`
+    pub using: TableFactor<T>,
+    /// `ON`
+    pub on: Expr<T>,
+    /// `WHEN [NOT] MATCHED ...`
+    pub clauses: Vec<MergeClause<T>>,
+    /// `RETURNING`
+    pub returning: Vec<SelectItem<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for MergeStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("MERGE INTO ");
+        f.write_node(&self.table_name);
+        if let Some(alias) = &self.alias {
+            f.write_str(" AS ");
+            f.write_node(alias);
+        }
+        f.write_str(" USING ");
+        f.write_node(&self.using);
+        f.write_str(" ON ");
+        f.write_node(&self.on);
+        for clause in &self.clauses {
+            f.write_str(" ");
+            f.write_node(clause);
+        }
+        if !self.returning.is_empty() {
+            f.write_str(" RETURNING ");
+            f.write_node(&display::comma_separated(&self.returning));
+        }
+    }
+}
+impl_display_t!(MergeStatement);
+
+/// `WHEN [NOT] MATCHED [AND condition] THEN action`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MergeClause<T: AstInfo> {
+    /// Whether the clause applies to source rows that match a target row.
+    pub matched: bool,
+    /// `AND`
+    pub condition: Option<Expr<T>>,
+    pub action: MergeAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for MergeClause<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("WHEN ");
+        if !self.matched {
+            f.write_str("NOT ");
+        }
+        f.write_str("MATCHED");
+        if let Some(condition) = &self.condition {
+            f.write_str(" AND ");
+            f.write_node(condition);
+        }
+        f.write_str(" THEN ");
+        f.write_node(&self.action);
+    }
+}
+impl_display_t!(MergeClause);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MergeAction<T: AstInfo> {
+    /// `UPDATE SET ...`
+    Update { assignments: Vec<Assignment<T>> },
+    /// `DELETE`
+    Delete,
+    /// `INSERT [(columns)] { VALUES (...) | DEFAULT VALUES }`
+    Insert {
+        columns: Vec<Ident>,
+        source: MergeInsertSource<T>,
+    },
+    /// `DO NOTHING`
+    DoNothing,
+}
+
+impl<T: AstInfo> AstDisplay for MergeAction<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            MergeAction::Update { assignments } => {
+                f.write_str("UPDATE SET ");
+                f.write_node(&display::comma_separated(assignments));
+            }
+            MergeAction::Delete => f.write_str("DELETE"),
+            MergeAction::Insert { columns, source } => {
+                f.write_str("INSERT ");
+                if !columns.is_empty() {
+                    f.write_str("(");
+                    f.write_node(&display::comma_separated(columns));
+                    f.write_str(") ");
+                }
+                f.write_node(source);
+            }
+            MergeAction::DoNothing => f.write_str("DO NOTHING"),
+        }
+    }
+}
+impl_display_t!(MergeAction);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MergeInsertSource<T: AstInfo> {
+    /// `VALUES (...)`
+    Values(Vec<Expr<T>>),
+    /// `DEFAULT VALUES`
+    DefaultValues,
+}
+
+impl<T: AstInfo> AstDisplay for MergeInsertSource<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            MergeInsertSource::Values(values) => {
+                f.write_str("VALUES (");
+                f.write_node(&display::comma_separated(values));
+                f.write_str(")");
+            }
+            MergeInsertSource::DefaultValues => f.write_str("DEFAULT VALUES"),
+        }
+    }
+}
+impl_display_t!(MergeInsertSource);
+
 /// `CREATE DATABASE`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateDatabaseStatement {
@@ -1243,6 +1444,8 @@ pub enum CreateSubsourceOptionName {
     /// `DETAILS` for this subsource, hex-encoded protobuf type
     /// `mz_storage_types::sources::SourceExportStatementDetails`
     Details,
+    /// How to react to a `TRUNCATE` of the upstream table
+    TruncateBehavior,
 }
 
 impl AstDisplay for CreateSubsourceOptionName {
@@ -1254,6 +1457,7 @@ impl AstDisplay for CreateSubsourceOptionName {
             CreateSubsourceOptionName::TextColumns => "TEXT COLUMNS",
             CreateSubsourceOptionName::ExcludeColumns => "EXCLUDE COLUMNS",
             CreateSubsourceOptionName::Details => "DETAILS",
+            CreateSubsourceOptionName::TruncateBehavior => "TRUNCATE BEHAVIOR",
         })
     }
 }
@@ -1271,7 +1475,8 @@ impl WithOptionName for CreateSubsourceOptionName {
             | CreateSubsourceOptionName::RetainHistory
             | CreateSubsourceOptionName::Details
             | CreateSubsourceOptionName::TextColumns
-            | CreateSubsourceOptionName::ExcludeColumns => false,
+            | CreateSubsourceOptionName::ExcludeColumns
+            | CreateSubsourceOptionName::TruncateBehavior => false,
         }
     }
 }
@@ -1698,6 +1903,8 @@ pub enum TableFromSourceOptionName {
     TextColumns,
     /// Columns you want to exclude when ingesting data
     ExcludeColumns,
+    /// Columns whose upstream enum values you want to ingest as an enum type
+    EnumColumns,
     /// Hex-encoded protobuf of a `ProtoSourceExportStatementDetails`
     /// message, which includes details necessary for planning this
     /// table as a Source Export
@@ -1706,6 +1913,10 @@ pub enum TableFromSourceOptionName {
     PartitionBy,
     // The `RETAIN HISTORY` option
     RetainHistory,
+    /// How to react to a `TRUNCATE` of the upstream table
+    TruncateBehavior,
+    /// How to react to columns being added to the upstream table
+    SchemaChangeBehavior,
 }
 
 impl AstDisplay for TableFromSourceOptionName {
@@ -1713,9 +1924,12 @@ impl AstDisplay for TableFromSourceOptionName {
         f.write_str(match self {
             TableFromSourceOptionName::TextColumns => "TEXT COLUMNS",
             TableFromSourceOptionName::ExcludeColumns => "EXCLUDE COLUMNS",
+            TableFromSourceOptionName::EnumColumns => "ENUM COLUMNS",
             TableFromSourceOptionName::Details => "DETAILS",
             TableFromSourceOptionName::PartitionBy => "PARTITION BY",
             TableFromSourceOptionName::RetainHistory => "RETAIN HISTORY",
+            TableFromSourceOptionName::TruncateBehavior => "TRUNCATE BEHAVIOR",
+            TableFromSourceOptionName::SchemaChangeBehavior => "SCHEMA CHANGE BEHAVIOR",
         })
     }
 }
@@ -1732,7 +1946,10 @@ impl WithOptionName for TableFromSourceOptionName {
             TableFromSourceOptionName::Details
             | TableFromSourceOptionName::TextColumns
             | TableFromSourceOptionName::ExcludeColumns
-            | TableFromSourceOptionName::RetainHistory => false,
+            | TableFromSourceOptionName::EnumColumns
+            | TableFromSourceOptionName::RetainHistory
+            | TableFromSourceOptionName::TruncateBehavior
+            | TableFromSourceOptionName::SchemaChangeBehavior => false,
             // The value is an arbitrary user expression/literal that may embed
             // sensitive data, so redact it (mirrors `KafkaSinkConfigOptionName`).
             TableFromSourceOptionName::PartitionBy => true,
@@ -2240,11 +2457,95 @@ impl<T: AstInfo> AstDisplay for CreateTypeStatement<T> {
                 }
                 f.write_str(")");
             }
+            CreateTypeAs::Enum { labels } => {
+                f.write_str(&self.as_type);
+                f.write_str("(");
+                for (i, label) in labels.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ");
+                    }
+                    f.write_str("'");
+                    f.write_node(&display::escape_single_quote_string(label));
+                    f.write_str("'");
+                }
+                f.write_str(")");
+            }
         };
     }
 }
 impl_display_t!(CreateTypeStatement);
 
+/// `CREATE FUNCTION ..`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateFunctionStatement<T: AstInfo> {
+    /// Name of the created function.
+    pub name: UnresolvedItemName,
+    /// The function's parameters.
+    pub parameters: Vec<FunctionParameter<T>>,
+    /// The function's return type.
+    pub returns: FunctionReturnType<T>,
+    /// The `LANGUAGE SQL` query that computes the function's result.
+    pub body: Query<T>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateFunctionStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE FUNCTION ");
+        f.write_node(&self.name);
+        f.write_str("(");
+        f.write_node(&display::comma_separated(&self.parameters));
+        f.write_str(") RETURNS ");
+        f.write_node(&self.returns);
+        f.write_str(" LANGUAGE SQL AS ");
+        let body = self.body.to_ast_string(f.mode());
+        f.write_node(&display::escaped_string_literal(&body));
+    }
+}
+impl_display_t!(CreateFunctionStatement);
+
+/// A parameter of a function created by `CREATE FUNCTION`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionParameter<T: AstInfo> {
+    /// The name of the parameter, if any.
+    pub name: Option<Ident>,
+    /// The type of the parameter.
+    pub data_type: T::DataType,
+}
+
+impl<T: AstInfo> AstDisplay for FunctionParameter<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        if let Some(name) = &self.name {
+            f.write_node(name);
+            f.write_str(" ");
+        }
+        f.write_node(&self.data_type);
+    }
+}
+impl_display_t!(FunctionParameter);
+
+/// The return type of a function created by `CREATE FUNCTION`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FunctionReturnType<T: AstInfo> {
+    /// `RETURNS <type>`
+    Scalar(T::DataType),
+    /// `RETURNS TABLE (<column_defs>)`
+    Table(Vec<ColumnDef<T>>),
+}
+
+impl<T: AstInfo> AstDisplay for FunctionReturnType<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            FunctionReturnType::Scalar(data_type) => f.write_node(data_type),
+            FunctionReturnType::Table(column_defs) => {
+                f.write_str("TABLE (");
+                f.write_node(&display::comma_separated(column_defs));
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display_t!(FunctionReturnType);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ClusterOptionName {
     /// The `AUTO SCALING STRATEGY [[=] (...)]` option.
@@ -2686,6 +2987,9 @@ pub enum CreateTypeAs<T: AstInfo> {
     Record {
         column_defs: Vec<ColumnDef<T>>,
     },
+    Enum {
+        labels: Vec<String>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for CreateTypeAs<T> {
@@ -2694,6 +2998,7 @@ impl<T: AstInfo> AstDisplay for CreateTypeAs<T> {
             CreateTypeAs::List { .. } => f.write_str("LIST "),
             CreateTypeAs::Map { .. } => f.write_str("MAP "),
             CreateTypeAs::Record { .. } => f.write_str("RECORD "),
+            CreateTypeAs::Enum { .. } => f.write_str("ENUM "),
         }
     }
 }
@@ -3117,6 +3422,62 @@ impl<T: AstInfo> AstDisplay for AlterSecretStatement<T> {
 
 impl_display_t!(AlterSecretStatement);
 
+/// The position of a new label in `ALTER TYPE .. ADD VALUE`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterTypeAddValuePosition {
+    Before(String),
+    After(String),
+}
+
+impl AstDisplay for AlterTypeAddValuePosition {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        let (keyword, label) = match self {
+            AlterTypeAddValuePosition::Before(label) => ("BEFORE", label),
+            AlterTypeAddValuePosition::After(label) => ("AFTER", label),
+        };
+        f.write_str(keyword);
+        f.write_str(" '");
+        f.write_node(&display::escape_single_quote_string(label));
+        f.write_str("'");
+    }
+}
+impl_display!(AlterTypeAddValuePosition);
+
+/// `ALTER TYPE .. ADD VALUE ..`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTypeAddValueStatement {
+    /// Name of the altered enum type.
+    pub name: UnresolvedItemName,
+    pub if_exists: bool,
+    pub if_not_exists: bool,
+    /// The label to add.
+    pub value: String,
+    /// Where to place the new label, if not at the end.
+    pub position: Option<AlterTypeAddValuePosition>,
+}
+
+impl AstDisplay for AlterTypeAddValueStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER TYPE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" ADD VALUE ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_str("'");
+        f.write_node(&display::escape_single_quote_string(&self.value));
+        f.write_str("'");
+        if let Some(position) = &self.position {
+            f.write_str(" ");
+            f.write_node(position);
+        }
+    }
+}
+impl_display!(AlterTypeAddValueStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AlterConnectionAction<T: AstInfo> {
     RotateKeys,
@@ -3871,6 +4232,25 @@ impl<T: AstInfo> AstDisplay for ShowCreateTypeStatement<T> {
     }
 }
 
+/// `SHOW [REDACTED] CREATE FUNCTION <function>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ShowCreateFunctionStatement<T: AstInfo> {
+    pub function_name: T::ItemName,
+    pub redacted: bool,
+}
+
+impl<T: AstInfo> AstDisplay for ShowCreateFunctionStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("SHOW ");
+        if self.redacted {
+            f.write_str("REDACTED ");
+        }
+        f.write_str("CREATE FUNCTION ");
+        f.write_node(&self.function_name);
+    }
+}
+impl_display_t!(ShowCreateFunctionStatement);
+
 /// `SET TRANSACTION ...`
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct SetTransactionStatement {
@@ -5247,13 +5627,18 @@ impl<T: AstInfo> AstDisplay for ExecuteStatement<T> {
 impl_display_t!(ExecuteStatement);
 
 /// `EXECUTE UNIT TEST ...`
+///
+/// A test either asserts a single snapshot of the target (`EXPECTED`) or runs
+/// a sequence of timestamped `STEPS` that change mock contents and assert on
+/// the target along the way. Exactly one of `expected` and `steps` is set.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExecuteUnitTestStatement<T: AstInfo> {
     pub name: Ident,
     pub target: T::ItemName,
     pub at_time: Option<Expr<T>>,
     pub mocks: Vec<MockViewDef<T>>,
-    pub expected: ExpectedResultDef<T>,
+    pub expected: Option<ExpectedResultDef<T>>,
+    pub steps: Vec<UnitTestStep<T>>,
 }
 
 impl<T: AstInfo> AstDisplay for ExecuteUnitTestStatement<T> {
@@ -5270,18 +5655,28 @@ impl<T: AstInfo> AstDisplay for ExecuteUnitTestStatement<T> {
             f.write_str(if i == 0 { " MOCK " } else { ", MOCK " });
             f.write_node(mock);
         }
-        f.write_str(" EXPECTED ");
-        f.write_node(&self.expected);
+        if let Some(expected) = &self.expected {
+            f.write_str(" EXPECTED ");
+            f.write_node(expected);
+        }
+        if !self.steps.is_empty() {
+            f.write_str(" STEPS (");
+            f.write_node(&display::comma_separated(&self.steps));
+            f.write_str(")");
+        }
     }
 }
 impl_display_t!(ExecuteUnitTestStatement);
 
 /// Mock view definition for EXECUTE UNIT TEST
+///
+/// The query is optional: a mock without one starts out empty, which is only
+/// useful when `STEPS` insert rows into it.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MockViewDef<T: AstInfo> {
     pub name: T::ItemName,
     pub columns: Vec<ColumnDef<T>>,
-    pub query: Query<T>,
+    pub query: Option<Query<T>>,
 }
 
 impl<T: AstInfo> AstDisplay for MockViewDef<T> {
@@ -5289,9 +5684,12 @@ impl<T: AstInfo> AstDisplay for MockViewDef<T> {
         f.write_node(&self.name);
         f.write_str("(");
         f.write_node(&display::comma_separated(&self.columns));
-        f.write_str(") AS (");
-        f.write_node(&self.query);
         f.write_str(")");
+        if let Some(query) = &self.query {
+            f.write_str(" AS (");
+            f.write_node(query);
+            f.write_str(")");
+        }
     }
 }
 impl_display_t!(MockViewDef);
@@ -5314,6 +5712,84 @@ impl<T: AstInfo> AstDisplay for ExpectedResultDef<T> {
 }
 impl_display_t!(ExpectedResultDef);
 
+/// A single step of a multi-step EXECUTE UNIT TEST.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UnitTestStep<T: AstInfo> {
+    /// `AT TIME <time> INSERT INTO <mock> AS (<query>)`
+    Insert {
+        at_time: Expr<T>,
+        mock: T::ItemName,
+        query: Query<T>,
+    },
+    /// `AT TIME <time> RETRACT FROM <mock> AS (<query>)`
+    Retract {
+        at_time: Expr<T>,
+        mock: T::ItemName,
+        query: Query<T>,
+    },
+    /// `AT TIME <time> EXPECTED (<cols>) AS (<query>)`
+    ExpectSnapshot {
+        at_time: Expr<T>,
+        expected: ExpectedResultDef<T>,
+    },
+    /// `BETWEEN <from> AND <to> EXPECTED CHANGES (<cols>) AS (<query>)`
+    ///
+    /// Each row of the query carries one extra trailing column: the diff.
+    ExpectChanges {
+        from: Expr<T>,
+        to: Expr<T>,
+        expected: ExpectedResultDef<T>,
+    },
+}
+
+impl<T: AstInfo> AstDisplay for UnitTestStep<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            UnitTestStep::Insert {
+                at_time,
+                mock,
+                query,
+            } => {
+                f.write_str("AT TIME ");
+                f.write_node(at_time);
+                f.write_str(" INSERT INTO ");
+                f.write_node(mock);
+                f.write_str(" AS (");
+                f.write_node(query);
+                f.write_str(")");
+            }
+            UnitTestStep::Retract {
+                at_time,
+                mock,
+                query,
+            } => {
+                f.write_str("AT TIME ");
+                f.write_node(at_time);
+                f.write_str(" RETRACT FROM ");
+                f.write_node(mock);
+                f.write_str(" AS (");
+                f.write_node(query);
+                f.write_str(")");
+            }
+            UnitTestStep::ExpectSnapshot { at_time, expected } => {
+                f.write_str("AT TIME ");
+                f.write_node(at_time);
+                f.write_str(" EXPECTED ");
+                f.write_node(expected);
+            }
+            UnitTestStep::ExpectChanges { from, to, expected } => {
+                f.write_str("BETWEEN ");
+                f.write_node(from);
+                f.write_str(" AND ");
+                f.write_node(to);
+                f.write_str(" EXPECTED CHANGES ");
+                f.write_node(expected);
+            }
+        }
+    }
+}
+impl_display_t!(UnitTestStep);
+
 /// `DEALLOCATE ...`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DeallocateStatement {
@@ -5442,6 +5918,7 @@ pub enum ShowStatement<T: AstInfo> {
     ShowCreateConnection(ShowCreateConnectionStatement<T>),
     ShowCreateCluster(ShowCreateClusterStatement<T>),
     ShowCreateType(ShowCreateTypeStatement<T>),
+    ShowCreateFunction(ShowCreateFunctionStatement<T>),
     ShowVariable(ShowVariableStatement),
     InspectShard(InspectShardStatement),
 }
@@ -5460,6 +5937,7 @@ impl<T: AstInfo> AstDisplay for ShowStatement<T> {
             ShowStatement::ShowCreateConnection(stmt) => f.write_node(stmt),
             ShowStatement::ShowCreateCluster(stmt) => f.write_node(stmt),
             ShowStatement::ShowCreateType(stmt) => f.write_node(stmt),
+            ShowStatement::ShowCreateFunction(stmt) => f.write_node(stmt),
             ShowStatement::ShowVariable(stmt) => f.write_node(stmt),
             ShowStatement::InspectShard(stmt) => f.write_node(stmt),
         }
@@ -138,6 +138,11 @@ where
         }
     }
 
+    /// The mode in which the AST is being printed.
+    pub fn mode(&self) -> FormatMode {
+        self.mode
+    }
+
     pub fn set_mode(&mut self, mode: FormatMode) {
         self.mode = mode;
     }
@@ -460,6 +460,9 @@ impl<'a> Parser<'a> {
                 Token::Keyword(UPDATE) => {
                     Ok(self.parse_update().map_parser_err(StatementKind::Update)?)
                 }
+                Token::Keyword(MERGE) => {
+                    Ok(self.parse_merge().map_parser_err(StatementKind::Merge)?)
+                }
                 Token::Keyword(ALTER) => Ok(self.parse_alter()?),
                 Token::Keyword(COPY) => Ok(self.parse_copy()?),
                 Token::Keyword(SET) => Ok(self.parse_set()?),
@@ -690,6 +693,7 @@ impl<'a> Parser<'a> {
                 self.parse_homogenizing_function(HomogenizingFunction::Least)
             }
             (Token::Keyword(NULLIF), Some(Token::LParen)) => self.parse_nullif_expr(),
+            (Token::Keyword(GROUPING), Some(Token::LParen)) => self.parse_grouping_expr(),
             (Token::Keyword(EXISTS), Some(Token::LParen)) => self.parse_exists_expr(),
             (Token::Keyword(EXTRACT), Some(Token::LParen)) => self.parse_extract_expr(),
             (Token::Keyword(NOT), _) => Ok(Expr::Not {
@@ -860,6 +864,43 @@ impl<'a> Parser<'a> {
             ));
         }
 
+        // Require the opening parenthesis as well, so that `within` remains
+        // usable as a column alias ahead of a `GROUP BY` clause.
+        let within_group_pos = self.peek_pos();
+        let args = if self.peek_keywords(&[WITHIN, GROUP])
+            && self.peek_nth_token(2) == Some(Token::LParen)
+        {
+            self.expect_keywords(&[WITHIN, GROUP])?;
+            let args = match args {
+                FunctionArgs::Args { args, order_by } if order_by.is_empty() => args,
+                FunctionArgs::Args { .. } => {
+                    return parser_err!(
+                        self,
+                        within_group_pos,
+                        "cannot use multiple ORDER BY clauses with WITHIN GROUP"
+                    );
+                }
+                FunctionArgs::Star => {
+                    return parser_err!(self, within_group_pos, "cannot use * with WITHIN GROUP");
+                }
+                FunctionArgs::WithinGroup { .. } => unreachable!("parsed only below"),
+            };
+            if distinct {
+                return parser_err!(
+                    self,
+                    within_group_pos,
+                    "cannot use DISTINCT with WITHIN GROUP"
+                );
+            }
+            self.expect_token(&Token::LParen)?;
+            self.expect_keywords(&[ORDER, BY])?;
+            let order_by = self.parse_comma_separated(Parser::parse_order_by_expr)?;
+            self.expect_token(&Token::RParen)?;
+            FunctionArgs::WithinGroup { args, order_by }
+        } else {
+            args
+        };
+
         let filter = if self.parse_keyword(FILTER) {
             self.expect_token(&Token::LParen)?;
             self.expect_keyword(WHERE)?;
@@ -1008,6 +1049,7 @@ impl<'a> Parser<'a> {
                 | Expr::Identifier { .. }
                 | Expr::HomogenizingFunction { .. }
                 | Expr::NullIf { .. }
+                | Expr::Grouping(_)
                 | Expr::Subquery { .. }
                 | Expr::Parameter(..) => true,
                 Expr::Cast { expr, .. } | Expr::Collate { expr, .. } => safe_before_pg_cast(expr),
@@ -1071,6 +1113,13 @@ impl<'a> Parser<'a> {
         Ok(Expr::NullIf { l_expr, r_expr })
     }
 
+    fn parse_grouping_expr(&mut self) -> Result<Expr<Raw>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::Grouping(exprs))
+    }
+
     // Parse calls to extract(), which can take the form:
     // - extract(field from 'interval')
     fn parse_extract_expr(&mut self) -> Result<Expr<Raw>, ParserError> {
@@ -2066,6 +2115,9 @@ impl<'a> Parser<'a> {
         } else if self.peek_keyword(TYPE) {
             self.parse_create_type()
                 .map_parser_err(StatementKind::CreateType)
+        } else if self.peek_keyword(FUNCTION) {
+            self.parse_create_function()
+                .map_parser_err(StatementKind::CreateFunction)
         } else if self.peek_keyword(ROLE) {
             self.parse_create_role()
                 .map_parser_err(StatementKind::CreateRole)
@@ -2141,8 +2193,8 @@ impl<'a> Parser<'a> {
                     }
                     (false, true) => "TABLE, or VIEW after CREATE TEMPORARY",
                     (false, false) => {
-                        "DATABASE, SCHEMA, ROLE, TYPE, INDEX, SINK, SOURCE, [TEMPORARY] TABLE, \
-                        SECRET, [OR REPLACE] [TEMPORARY] VIEW, or [OR REPLACE] MATERIALIZED VIEW \
+                        "DATABASE, SCHEMA, ROLE, TYPE, FUNCTION, INDEX, SINK, SOURCE, \
+                        [TEMPORARY] TABLE, SECRET, [OR REPLACE] [TEMPORARY] VIEW, or [OR REPLACE] MATERIALIZED VIEW \
                         after CREATE"
                     }
                 };
@@ -2847,7 +2899,7 @@ impl<'a> Parser<'a> {
     fn parse_kafka_source_config_option(
         &mut self,
     ) -> Result<KafkaSourceConfigOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[GROUP, START, TOPIC])? {
+        let name = match self.expect_one_of_keywords(&[GROUP, START, TOPIC, TOPICS])? {
             GROUP => {
                 self.expect_keywords(&[ID, PREFIX])?;
                 KafkaSourceConfigOptionName::GroupIdPrefix
@@ -2861,10 +2913,13 @@ impl<'a> Parser<'a> {
                 if self.parse_keyword(METADATA) {
                     self.expect_keywords(&[REFRESH, INTERVAL])?;
                     KafkaSourceConfigOptionName::TopicMetadataRefreshInterval
+                } else if self.parse_keyword(PATTERN) {
+                    KafkaSourceConfigOptionName::TopicPattern
                 } else {
                     KafkaSourceConfigOptionName::Topic
                 }
             }
+            TOPICS => KafkaSourceConfigOptionName::Topics,
             _ => unreachable!(),
         };
         Ok(KafkaSourceConfigOption {
@@ -3152,9 +3207,9 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_create_subsource_option(&mut self) -> Result<CreateSubsourceOption<Raw>, ParserError> {
-        let option = match self
-            .expect_one_of_keywords(&[EXTERNAL, PROGRESS, TEXT, EXCLUDE, IGNORE, DETAILS, RETAIN])?
-        {
+        let option = match self.expect_one_of_keywords(&[
+            EXTERNAL, PROGRESS, TEXT, EXCLUDE, IGNORE, DETAILS, RETAIN, TRUNCATE,
+        ])? {
             EXTERNAL => {
                 self.expect_keyword(REFERENCE)?;
                 CreateSubsourceOption {
@@ -3200,6 +3255,13 @@ impl<'a> Parser<'a> {
                     value: self.parse_option_retain_history()?,
                 }
             }
+            TRUNCATE => {
+                self.expect_keyword(BEHAVIOR)?;
+                CreateSubsourceOption {
+                    name: CreateSubsourceOptionName::TruncateBehavior,
+                    value: self.parse_optional_option_value()?,
+                }
+            }
             _ => unreachable!(),
         };
         Ok(option)
@@ -3774,9 +3836,15 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_pg_connection_option(&mut self) -> Result<PgConfigOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[DETAILS, PUBLICATION, TEXT, EXCLUDE])? {
+        let keyword =
+            self.expect_one_of_keywords(&[DETAILS, PUBLICATION, TEXT, EXCLUDE, TRUNCATE])?;
+        let name = match keyword {
             DETAILS => PgConfigOptionName::Details,
             PUBLICATION => PgConfigOptionName::Publication,
+            TRUNCATE => {
+                self.expect_keyword(BEHAVIOR)?;
+                PgConfigOptionName::TruncateBehavior
+            }
             TEXT => {
                 self.expect_keyword(COLUMNS)?;
 
@@ -4505,7 +4573,7 @@ impl<'a> Parser<'a> {
         let name = self.parse_item_name()?;
         self.expect_keyword(AS)?;
 
-        match self.parse_one_of_keywords(&[LIST, MAP]) {
+        match self.parse_one_of_keywords(&[LIST, MAP, ENUM]) {
             Some(LIST) => {
                 self.expect_token(&Token::LParen)?;
                 let options = self.parse_comma_separated(Parser::parse_create_type_list_option)?;
@@ -4524,6 +4592,20 @@ impl<'a> Parser<'a> {
                     as_type: CreateTypeAs::Map { options },
                 }))
             }
+            Some(ENUM) => {
+                self.expect_token(&Token::LParen)?;
+                let labels = if self.consume_token(&Token::RParen) {
+                    vec![]
+                } else {
+                    let labels = self.parse_comma_separated(Parser::parse_literal_string)?;
+                    self.expect_token(&Token::RParen)?;
+                    labels
+                };
+                Ok(Statement::CreateType(CreateTypeStatement {
+                    name,
+                    as_type: CreateTypeAs::Enum { labels },
+                }))
+            }
             None => {
                 let column_defs = self.parse_composite_type_definition()?;
 
@@ -4563,6 +4645,102 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_create_function(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(FUNCTION)?;
+        let name = self.parse_item_name()?;
+        self.expect_token(&Token::LParen)?;
+        let parameters = if self.consume_token(&Token::RParen) {
+            vec![]
+        } else {
+            let parameters = self.parse_comma_separated(Parser::parse_function_parameter)?;
+            self.expect_token(&Token::RParen)?;
+            parameters
+        };
+        self.expect_keyword(RETURNS)?;
+        let returns = if self.parse_keyword(TABLE) {
+            FunctionReturnType::Table(self.parse_composite_type_definition()?)
+        } else {
+            FunctionReturnType::Scalar(self.parse_data_type()?)
+        };
+
+        // The `LANGUAGE` and `AS` clauses may appear in either order, and the
+        // language defaults to SQL, the only supported language.
+        let mut language = false;
+        let mut body = None;
+        loop {
+            match self.parse_one_of_keywords(&[LANGUAGE, AS]) {
+                Some(LANGUAGE) if !language => {
+                    self.expect_keyword(SQL)?;
+                    language = true;
+                }
+                Some(AS) if body.is_none() => body = Some(self.parse_function_body()?),
+                Some(kw) => {
+                    return parser_err!(
+                        self,
+                        self.peek_prev_pos(),
+                        "{} specified more than once",
+                        kw
+                    );
+                }
+                None if body.is_some() => break,
+                None => {
+                    return self.expected(self.peek_pos(), "LANGUAGE or AS", self.peek_token());
+                }
+            }
+        }
+
+        Ok(Statement::CreateFunction(CreateFunctionStatement {
+            name,
+            parameters,
+            returns,
+            body: body.expect("loop exits once body is parsed"),
+        }))
+    }
+
+    fn parse_function_parameter(&mut self) -> Result<FunctionParameter<Raw>, ParserError> {
+        // Both the optional name and the type may be identifiers, so first try
+        // to parse the parameter as a lone type.
+        let data_type = self.maybe_parse(|parser| {
+            let data_type = parser.parse_data_type()?;
+            match parser.peek_token() {
+                Some(Token::Comma | Token::RParen) => Ok(data_type),
+                other => parser.expected(parser.peek_pos(), "comma or right paren", other),
+            }
+        });
+        match data_type {
+            Some(data_type) => Ok(FunctionParameter {
+                name: None,
+                data_type,
+            }),
+            None => Ok(FunctionParameter {
+                name: Some(self.parse_identifier()?),
+                data_type: self.parse_data_type()?,
+            }),
+        }
+    }
+
+    /// Parses the string literal that holds a function's body as a query.
+    fn parse_function_body(&mut self) -> Result<Query<Raw>, ParserError> {
+        let pos = self.peek_pos();
+        let body = self.parse_literal_string()?;
+        let invalid_body =
+            |e: ParserError| ParserError::new(pos, format!("invalid function body: {}", e.message));
+        let tokens = lexer::lex(&body).map_err(|e| invalid_body(e.into()))?;
+        let mut parser = Parser::new(&body, tokens);
+        let query = parser.parse_query().map_err(invalid_body)?;
+        parser.consume_token(&Token::Semicolon);
+        if parser.peek_token().is_some() {
+            return parser
+                .expected(
+                    parser.peek_pos(),
+                    "end of function body",
+                    parser.peek_token(),
+                )
+                .map_err(invalid_body);
+        }
+        Ok(query)
+    }
+
     fn parse_create_cluster(&mut self) -> Result<Statement<Raw>, ParserError> {
         let if_not_exists = self.parse_if_not_exists()?;
         let name = self.parse_identifier()?;
@@ -4920,15 +5098,18 @@ impl<'a> Parser<'a> {
     fn parse_source_include_metadata(&mut self) -> Result<Vec<SourceIncludeMetadata>, ParserError> {
         if self.parse_keyword(INCLUDE) {
             self.parse_comma_separated(|parser| {
-                let metadata = match parser
-                    .expect_one_of_keywords(&[KEY, TIMESTAMP, PARTITION, OFFSET, HEADERS, HEADER])?
-                {
+                let metadata = match parser.expect_one_of_keywords(&[
+                    KEY, TIMESTAMP, TOPIC, PARTITION, OFFSET, HEADERS, HEADER,
+                ])? {
                     KEY => SourceIncludeMetadata::Key {
                         alias: parser.parse_alias()?,
                     },
                     TIMESTAMP => SourceIncludeMetadata::Timestamp {
                         alias: parser.parse_alias()?,
                     },
+                    TOPIC => SourceIncludeMetadata::Topic {
+                        alias: parser.parse_alias()?,
+                    },
                     PARTITION => SourceIncludeMetadata::Partition {
                         alias: parser.parse_alias()?,
                     },
@@ -5045,6 +5226,7 @@ impl<'a> Parser<'a> {
             | ObjectType::Sink
             | ObjectType::Index
             | ObjectType::Type
+            | ObjectType::Func
             | ObjectType::Secret
             | ObjectType::Connection => {
                 let names = self.parse_comma_separated(|parser| {
@@ -5061,7 +5243,7 @@ impl<'a> Parser<'a> {
                     cascade,
                 }))
             }
-            ObjectType::Func | ObjectType::Subsource => parser_err!(
+            ObjectType::Subsource => parser_err!(
                 self,
                 self.peek_prev_pos(),
                 format!("Unsupported DROP on {object_type}")
@@ -5308,10 +5490,10 @@ impl<'a> Parser<'a> {
     fn parse_table_from_source_option(
         &mut self,
     ) -> Result<TableFromSourceOption<Raw>, ParserError> {
-        let option = match self
-            .expect_one_of_keywords(&[TEXT, EXCLUDE, IGNORE, DETAILS, PARTITION, RETAIN])?
-        {
-            ref keyword @ (TEXT | EXCLUDE) => {
+        let option = match self.expect_one_of_keywords(&[
+            TEXT, EXCLUDE, ENUM, IGNORE, DETAILS, PARTITION, RETAIN, TRUNCATE, SCHEMA,
+        ])? {
+            ref keyword @ (TEXT | EXCLUDE | ENUM) => {
                 self.expect_keyword(COLUMNS)?;
 
                 let _ = self.consume_token(&Token::Eq);
@@ -5328,6 +5510,7 @@ impl<'a> Parser<'a> {
                     name: match *keyword {
                         TEXT => TableFromSourceOptionName::TextColumns,
                         EXCLUDE => TableFromSourceOptionName::ExcludeColumns,
+                        ENUM => TableFromSourceOptionName::EnumColumns,
                         _ => unreachable!(),
                     },
                     value,
@@ -5368,6 +5551,20 @@ impl<'a> Parser<'a> {
                     value: self.parse_option_retain_history()?,
                 }
             }
+            TRUNCATE => {
+                self.expect_keyword(BEHAVIOR)?;
+                TableFromSourceOption {
+                    name: TableFromSourceOptionName::TruncateBehavior,
+                    value: self.parse_optional_option_value()?,
+                }
+            }
+            SCHEMA => {
+                self.expect_keywords(&[CHANGE, BEHAVIOR])?;
+                TableFromSourceOption {
+                    name: TableFromSourceOptionName::SchemaChangeBehavior,
+                    value: self.parse_optional_option_value()?,
+                }
+            }
             _ => unreachable!(),
         };
         Ok(option)
@@ -5812,26 +6009,7 @@ impl<'a> Parser<'a> {
             ObjectType::View | ObjectType::MaterializedView | ObjectType::Table => {
                 self.parse_alter_views(object_type)
             }
-            ObjectType::Type => {
-                let if_exists = self
-                    .parse_if_exists()
-                    .map_parser_err(StatementKind::AlterOwner)?;
-                let name = UnresolvedObjectName::Item(
-                    self.parse_item_name()
-                        .map_parser_err(StatementKind::AlterOwner)?,
-                );
-                self.expect_keywords(&[OWNER, TO])
-                    .map_parser_err(StatementKind::AlterOwner)?;
-                let new_owner = self
-                    .parse_identifier()
-                    .map_parser_err(StatementKind::AlterOwner)?;
-                Ok(Statement::AlterOwner(AlterOwnerStatement {
-                    object_type,
-                    if_exists,
-                    name,
-                    new_owner,
-                }))
-            }
+            ObjectType::Type => self.parse_alter_type(),
             ObjectType::Cluster => self.parse_alter_cluster(object_type),
             ObjectType::ClusterReplica => {
                 let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
@@ -6252,6 +6430,63 @@ impl<'a> Parser<'a> {
         )
     }
 
+    fn parse_alter_type(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
+        let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
+        let name = self.parse_item_name().map_no_statement_parser_err()?;
+
+        Ok(
+            match self
+                .expect_one_of_keywords(&[OWNER, ADD])
+                .map_no_statement_parser_err()?
+            {
+                OWNER => {
+                    self.expect_keyword(TO)
+                        .map_parser_err(StatementKind::AlterOwner)?;
+                    let new_owner = self
+                        .parse_identifier()
+                        .map_parser_err(StatementKind::AlterOwner)?;
+                    Statement::AlterOwner(AlterOwnerStatement {
+                        object_type: ObjectType::Type,
+                        if_exists,
+                        name: UnresolvedObjectName::Item(name),
+                        new_owner,
+                    })
+                }
+                ADD => {
+                    self.expect_keyword(VALUE)
+                        .map_parser_err(StatementKind::AlterTypeAddValue)?;
+                    let if_not_exists = self
+                        .parse_if_not_exists()
+                        .map_parser_err(StatementKind::AlterTypeAddValue)?;
+                    let value = self
+                        .parse_literal_string()
+                        .map_parser_err(StatementKind::AlterTypeAddValue)?;
+                    let position = match self.parse_one_of_keywords(&[BEFORE, AFTER]) {
+                        Some(keyword) => {
+                            let label = self
+                                .parse_literal_string()
+                                .map_parser_err(StatementKind::AlterTypeAddValue)?;
+                            Some(match keyword {
+                                BEFORE => AlterTypeAddValuePosition::Before(label),
+                                AFTER => AlterTypeAddValuePosition::After(label),
+                                _ => unreachable!(),
+                            })
+                        }
+                        None => None,
+                    };
+                    Statement::AlterTypeAddValue(AlterTypeAddValueStatement {
+                        name,
+                        if_exists,
+                        if_not_exists,
+                        value,
+                        position,
+                    })
+                }
+                _ => unreachable!(),
+            },
+        )
+    }
+
     fn parse_alter_secret(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
         let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
         let name = self.parse_item_name().map_no_statement_parser_err()?;
@@ -6959,7 +7194,7 @@ impl<'a> Parser<'a> {
 
     fn parse_copy_option(&mut self) -> Result<CopyOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[
-            FORMAT, DELIMITER, NULL, ESCAPE, QUOTE, HEADER, AWS, MAX, FILES, PATTERN,
+            FORMAT, DELIMITER, NULL, ESCAPE, QUOTE, HEADER, AWS, GCP, MAX, FILES, PATTERN, SINGLE,
         ])? {
             FORMAT => CopyOptionName::Format,
             DELIMITER => CopyOptionName::Delimiter,
@@ -6974,12 +7209,23 @@ impl<'a> Parser<'a> {
                     value: Some(self.parse_object_option_value()?),
                 });
             }
+            GCP => {
+                self.expect_keyword(CONNECTION)?;
+                return Ok(CopyOption {
+                    name: CopyOptionName::GcpConnection,
+                    value: Some(self.parse_object_option_value()?),
+                });
+            }
             MAX => {
                 self.expect_keywords(&[FILE, SIZE])?;
                 CopyOptionName::MaxFileSize
             }
             FILES => CopyOptionName::Files,
             PATTERN => CopyOptionName::Pattern,
+            SINGLE => {
+                self.expect_keyword(COLUMN)?;
+                CopyOptionName::SingleColumn
+            }
             _ => unreachable!(),
         };
         Ok(CopyOption {
@@ -7650,6 +7896,96 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_merge(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(INTO)?;
+        let table_name = RawItemName::Name(self.parse_item_name()?);
+        // The alias here doesn't support columns, so don't use parse_optional_table_alias.
+        let alias = self.parse_optional_alias(Keyword::is_reserved_in_table_alias)?;
+        let alias = alias.map(|name| TableAlias {
+            name,
+            columns: Vec::new(),
+            strict: false,
+        });
+        self.expect_keyword(USING)?;
+        let using = self.parse_table_factor()?;
+        self.expect_keyword(ON)?;
+        let on = self.parse_expr()?;
+
+        let mut clauses = vec![];
+        while self.parse_keyword(WHEN) {
+            clauses.push(self.parse_merge_clause()?);
+        }
+        if clauses.is_empty() {
+            return self.expected(self.peek_pos(), "WHEN", self.peek_token());
+        }
+        let returning = self.parse_returning()?;
+
+        Ok(Statement::Merge(MergeStatement {
+            table_name,
+            alias,
+            using,
+            on,
+            clauses,
+            returning,
+        }))
+    }
+
+    /// Parses a `[NOT] MATCHED [AND condition] THEN action` clause of a MERGE
+    /// statement, assuming the leading `WHEN` has already been consumed.
+    fn parse_merge_clause(&mut self) -> Result<MergeClause<Raw>, ParserError> {
+        let matched = !self.parse_keyword(NOT);
+        self.expect_keyword(MATCHED)?;
+        let condition = if self.parse_keyword(AND) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        self.expect_keyword(THEN)?;
+
+        let keywords: &[Keyword] = if matched {
+            &[UPDATE, DELETE, DO]
+        } else {
+            &[INSERT, DO]
+        };
+        let action = match self.expect_one_of_keywords(keywords)? {
+            UPDATE => {
+                self.expect_keyword(SET)?;
+                let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+                MergeAction::Update { assignments }
+            }
+            DELETE => MergeAction::Delete,
+            INSERT => {
+                if self.parse_keywords(&[DEFAULT, VALUES]) {
+                    MergeAction::Insert {
+                        columns: vec![],
+                        source: MergeInsertSource::DefaultValues,
+                    }
+                } else {
+                    let columns = self.parse_parenthesized_column_list(Optional)?;
+                    self.expect_keyword(VALUES)?;
+                    self.expect_token(&Token::LParen)?;
+                    let values = self.parse_comma_separated(Parser::parse_expr)?;
+                    self.expect_token(&Token::RParen)?;
+                    MergeAction::Insert {
+                        columns,
+                        source: MergeInsertSource::Values(values),
+                    }
+                }
+            }
+            DO => {
+                self.expect_keyword(NOTHING)?;
+                MergeAction::DoNothing
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(MergeClause {
+            matched,
+            condition,
+            action,
+        })
+    }
+
     /// Parses a SELECT (or WITH, VALUES, TABLE) statement with optional AS OF.
     fn parse_select_statement(&mut self) -> Result<SelectStatement<Raw>, ParserError> {
         Ok(SelectStatement {
@@ -8038,7 +8374,7 @@ impl<'a> Parser<'a> {
         };
 
         let group_by = if self.parse_keywords(&[GROUP, BY]) {
-            self.parse_comma_separated(Parser::parse_expr)?
+            self.parse_comma_separated(Parser::parse_group_by_item)?
         } else {
             vec![]
         };
@@ -8076,6 +8412,75 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses an item of a `GROUP BY` clause: an expression, `()`, `ROLLUP
+    /// (...)`, `CUBE (...)`, or `GROUPING SETS (...)`.
+    fn parse_group_by_item(&mut self) -> Result<GroupByItem<Raw>, ParserError> {
+        match (self.peek_token(), self.peek_nth_token(1)) {
+            (Some(Token::LParen), Some(Token::RParen)) => {
+                self.next_token();
+                self.next_token();
+                Ok(GroupByItem::Set(vec![]))
+            }
+            (Some(Token::Keyword(ROLLUP)), Some(Token::LParen)) => {
+                self.next_token();
+                Ok(GroupByItem::Rollup(self.parse_grouping_elements()?))
+            }
+            (Some(Token::Keyword(CUBE)), Some(Token::LParen)) => {
+                self.next_token();
+                Ok(GroupByItem::Cube(self.parse_grouping_elements()?))
+            }
+            (Some(Token::Keyword(GROUPING)), Some(Token::Keyword(SETS))) => {
+                self.next_token();
+                self.next_token();
+                self.expect_token(&Token::LParen)?;
+                let items = self.parse_comma_separated(Parser::parse_grouping_set)?;
+                self.expect_token(&Token::RParen)?;
+                Ok(GroupByItem::GroupingSets(items))
+            }
+            _ => Ok(GroupByItem::Expr(self.parse_expr()?)),
+        }
+    }
+
+    /// Parses an item of a `GROUPING SETS` list. Unlike at the top level of a
+    /// `GROUP BY` clause, a parenthesized list of expressions is a single
+    /// grouping set rather than a row constructor.
+    fn parse_grouping_set(&mut self) -> Result<GroupByItem<Raw>, ParserError> {
+        let nested = matches!(
+            (self.peek_token(), self.peek_nth_token(1)),
+            (Some(Token::LParen), Some(Token::RParen))
+                | (Some(Token::Keyword(ROLLUP | CUBE)), Some(Token::LParen))
+                | (Some(Token::Keyword(GROUPING)), Some(Token::Keyword(SETS)))
+        );
+        if nested {
+            return self.checked_recur_mut(Parser::parse_group_by_item);
+        }
+        let exprs = self.parse_grouping_element()?;
+        if exprs.len() == 1 {
+            Ok(GroupByItem::Expr(exprs.into_element()))
+        } else {
+            Ok(GroupByItem::Set(exprs))
+        }
+    }
+
+    /// Parses the parenthesized element list of `ROLLUP` or `CUBE`.
+    fn parse_grouping_elements(&mut self) -> Result<Vec<Vec<Expr<Raw>>>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let elements = self.parse_comma_separated(Parser::parse_grouping_element)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(elements)
+    }
+
+    /// Parses an element of `ROLLUP`, `CUBE`, or `GROUPING SETS`: either an
+    /// expression, or a parenthesized list of expressions that are grouped as
+    /// a unit.
+    fn parse_grouping_element(&mut self) -> Result<Vec<Expr<Raw>>, ParserError> {
+        let parenthesized = self.peek_token() == Some(Token::LParen);
+        match self.parse_expr()? {
+            Expr::Row { exprs } if parenthesized => Ok(exprs),
+            expr => Ok(vec![expr]),
+        }
+    }
+
     fn parse_select_option(&mut self) -> Result<SelectOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[EXPECTED, AGGREGATE, DISTINCT, LIMIT])? {
             EXPECTED => {
@@ -8402,6 +8807,13 @@ impl<'a> Parser<'a> {
                 type_name: self.parse_data_type()?,
                 redacted,
             }))
+        } else if self.parse_keywords(&[CREATE, FUNCTION]) {
+            Ok(ShowStatement::ShowCreateFunction(
+                ShowCreateFunctionStatement {
+                    function_name: self.parse_raw_name()?,
+                    redacted,
+                },
+            ))
         } else {
             let variable = if self.parse_keywords(&[TRANSACTION, ISOLATION, LEVEL]) {
                 ident!("transaction_isolation")
@@ -8767,15 +9179,46 @@ impl<'a> Parser<'a> {
         } else {
             InsertSource::Query(self.parse_query()?)
         };
+        let on_conflict = if self.parse_keywords(&[ON, CONFLICT]) {
+            Some(self.parse_on_conflict()?)
+        } else {
+            None
+        };
         let returning = self.parse_returning()?;
         Ok(Statement::Insert(InsertStatement {
             table_name,
             columns,
             source,
+            on_conflict,
             returning,
         }))
     }
 
+    /// Parse the remainder of an `ON CONFLICT` clause, assuming the `ON
+    /// CONFLICT` keywords have already been consumed.
+    fn parse_on_conflict(&mut self) -> Result<OnConflict<Raw>, ParserError> {
+        let columns = self.parse_parenthesized_column_list(Mandatory)?;
+        self.expect_keyword(DO)?;
+        let action = match self.expect_one_of_keywords(&[NOTHING, UPDATE])? {
+            NOTHING => OnConflictAction::DoNothing,
+            UPDATE => {
+                self.expect_keyword(SET)?;
+                let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+                let selection = if self.parse_keyword(WHERE) {
+                    Some(self.parse_expr()?)
+                } else {
+                    None
+                };
+                OnConflictAction::DoUpdate {
+                    assignments,
+                    selection,
+                }
+            }
+            _ => unreachable!(),
+        };
+        Ok(OnConflict { columns, action })
+    }
+
     fn parse_returning(&mut self) -> Result<Vec<SelectItem<Raw>>, ParserError> {
         Ok(if self.parse_keyword(RETURNING) {
             self.parse_comma_separated(Parser::parse_select_item)?
@@ -9517,6 +9960,7 @@ impl<'a> Parser<'a> {
                 | Statement::Insert(_)
                 | Statement::Delete(_)
                 | Statement::Update(_)
+                | Statement::Merge(_)
                 | Statement::Fetch(_),
         ) {
             return parser_err!(self, pos, "unpreparable statement").map_no_statement_parser_err();
@@ -9569,7 +10013,7 @@ impl<'a> Parser<'a> {
 
         // Parse MOCK definitions (0 or more). Like CTEs, MOCK clauses are
         // comma-separated; no comma appears before the trailing `EXPECTED`
-        // clause.
+        // or `STEPS` clause.
         let mut mocks = Vec::new();
         if self.parse_keyword(MOCK) {
             mocks.push(self.parse_mock_view_def()?);
@@ -9579,10 +10023,92 @@ impl<'a> Parser<'a> {
             }
         }
 
-        self.expect_keyword(EXPECTED)?;
+        // A test body is either a single `EXPECTED` clause or a parenthesized,
+        // comma-separated list of `STEPS`.
+        let (expected, steps) = if self.parse_keyword(STEPS) {
+            if at_time.is_some() {
+                return parser_err!(
+                    self,
+                    self.peek_prev_pos(),
+                    "AT TIME cannot be combined with STEPS; give each step its own AT TIME"
+                );
+            }
+            self.expect_token(&Token::LParen)?;
+            let steps = self.parse_comma_separated(Parser::parse_unit_test_step)?;
+            self.expect_token(&Token::RParen)?;
+            (None, steps)
+        } else {
+            self.expect_keyword(EXPECTED)?;
+            (Some(self.parse_expected_result_def()?), vec![])
+        };
+
+        Ok(Statement::ExecuteUnitTest(ExecuteUnitTestStatement {
+            name,
+            target,
+            at_time,
+            mocks,
+            expected,
+            steps,
+        }))
+    }
 
+    /// Parse a single step of an `EXECUTE UNIT TEST ... STEPS (...)` list.
+    fn parse_unit_test_step(&mut self) -> Result<UnitTestStep<Raw>, ParserError> {
+        if self.parse_keyword(BETWEEN) {
+            // Stop parsing the bounds on `AND`, as in `parse_between`.
+            let from = self.parse_subexpr(Precedence::Like)?;
+            self.expect_keyword(AND)?;
+            let to = self.parse_subexpr(Precedence::Like)?;
+            self.expect_keywords(&[EXPECTED, CHANGES])?;
+            let expected = self.parse_expected_result_def()?;
+            return Ok(UnitTestStep::ExpectChanges { from, to, expected });
+        }
+
+        self.expect_keywords(&[AT, TIME])?;
+        let at_time = self.parse_expr()?;
+        match self.expect_one_of_keywords(&[INSERT, RETRACT, EXPECTED])? {
+            INSERT => {
+                self.expect_keyword(INTO)?;
+                let mock = self.parse_raw_name()?;
+                let query = self.parse_unit_test_step_query()?;
+                Ok(UnitTestStep::Insert {
+                    at_time,
+                    mock,
+                    query,
+                })
+            }
+            RETRACT => {
+                self.expect_keyword(FROM)?;
+                let mock = self.parse_raw_name()?;
+                let query = self.parse_unit_test_step_query()?;
+                Ok(UnitTestStep::Retract {
+                    at_time,
+                    mock,
+                    query,
+                })
+            }
+            EXPECTED => {
+                let expected = self.parse_expected_result_def()?;
+                Ok(UnitTestStep::ExpectSnapshot { at_time, expected })
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Parse the `AS (<query>)` body of an `INSERT` or `RETRACT` step.
+    fn parse_unit_test_step_query(&mut self) -> Result<Query<Raw>, ParserError> {
+        self.expect_keyword(AS)?;
+        self.expect_token(&Token::LParen)?;
+        let query = self.parse_query()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(query)
+    }
+
+    /// Parse the `(<cols>) AS (<query>)` body of an `EXPECTED` clause,
+    /// assuming the leading `EXPECTED` keyword(s) have already been consumed.
+    fn parse_expected_result_def(&mut self) -> Result<ExpectedResultDef<Raw>, ParserError> {
         self.expect_token(&Token::LParen)?;
-        let expected_columns = self.parse_comma_separated(|parser| {
+        let columns = self.parse_comma_separated(|parser| {
             Ok(ColumnDef {
                 name: parser.parse_identifier()?,
                 data_type: parser.parse_data_type()?,
@@ -9594,24 +10120,13 @@ impl<'a> Parser<'a> {
 
         self.expect_keyword(AS)?;
         self.expect_token(&Token::LParen)?;
-        let expected_query = self.parse_query()?;
+        let query = self.parse_query()?;
         self.expect_token(&Token::RParen)?;
 
-        let expected = ExpectedResultDef {
-            columns: expected_columns,
-            query: expected_query,
-        };
-
-        Ok(Statement::ExecuteUnitTest(ExecuteUnitTestStatement {
-            name,
-            target,
-            at_time,
-            mocks,
-            expected,
-        }))
+        Ok(ExpectedResultDef { columns, query })
     }
 
-    /// Parse a single `MOCK <name> (<cols>) AS (<query>)` definition,
+    /// Parse a single `MOCK <name> (<cols>) [AS (<query>)]` definition,
     /// assuming the leading `MOCK` keyword has already been consumed.
     fn parse_mock_view_def(&mut self) -> Result<MockViewDef<Raw>, ParserError> {
         let name = self.parse_raw_name()?;
@@ -9627,10 +10142,15 @@ impl<'a> Parser<'a> {
         })?;
         self.expect_token(&Token::RParen)?;
 
-        self.expect_keyword(AS)?;
-        self.expect_token(&Token::LParen)?;
-        let query = self.parse_query()?;
-        self.expect_token(&Token::RParen)?;
+        // Mocks without a body start out empty; `STEPS` may fill them in.
+        let query = if self.parse_keyword(AS) {
+            self.expect_token(&Token::LParen)?;
+            let query = self.parse_query()?;
+            self.expect_token(&Token::RParen)?;
+            Some(query)
+        } else {
+            None
+        };
 
         Ok(MockViewDef {
             name,
@@ -9892,7 +10412,6 @@ impl<'a> Parser<'a> {
             | ObjectType::Index
             | ObjectType::ClusterReplica
             | ObjectType::Role
-            | ObjectType::Func
             | ObjectType::Subsource => {
                 parser_err!(
                     self,
@@ -9902,6 +10421,7 @@ impl<'a> Parser<'a> {
             }
             ObjectType::Table
             | ObjectType::Type
+            | ObjectType::Func
             | ObjectType::Cluster
             | ObjectType::Secret
             | ObjectType::Connection
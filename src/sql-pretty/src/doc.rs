@@ -904,6 +904,9 @@ impl Pretty {
             InsertSource::DefaultValues => self.doc_display(&v.source, "insert source"),
         };
         let mut doc = intersperse_line_nest([intersperse_line_nest(first), sources]);
+        if let Some(on_conflict) = &v.on_conflict {
+            doc = nest(doc, self.doc_display_pass(on_conflict));
+        }
         if !v.returning.is_empty() {
             doc = nest(
                 doc,
@@ -1178,7 +1181,7 @@ impl Pretty {
         if !v.group_by.is_empty() {
             docs.push(title_comma_separate(
                 "GROUP BY",
-                |e| self.doc_expr(e),
+                |e| self.doc_group_by_item(e),
                 &v.group_by,
             ));
         }
@@ -1252,6 +1255,7 @@ impl Pretty {
                                 | Expr::Function(_)
                                 | Expr::HomogenizingFunction { .. }
                                 | Expr::NullIf { .. }
+                                | Expr::Grouping(_)
                                 | Expr::Subquery(_)
                                 | Expr::Exists(_)
                                 | Expr::Nested(_)
@@ -1393,6 +1397,11 @@ impl Pretty {
                 comma_separate(|e| self.doc_expr(e), exprs),
                 ")",
             ),
+            Expr::Grouping(exprs) => bracket(
+                "GROUPING(",
+                comma_separate(|e| self.doc_expr(e), exprs),
+                ")",
+            ),
             Expr::ArraySubquery(s) => bracket("ARRAY(", self.doc_query(s), ")"),
             Expr::ListSubquery(s) => bracket("LIST(", self.doc_query(s), ")"),
             Expr::Array(exprs) => {
@@ -1404,9 +1413,22 @@ impl Pretty {
         .group()
     }
 
+    fn doc_group_by_item<'a, T: AstInfo>(&'a self, v: &'a GroupByItem<T>) -> RcDoc<'a> {
+        match v {
+            GroupByItem::Expr(expr) => self.doc_expr(expr),
+            GroupByItem::GroupingSets(items) => bracket(
+                "GROUPING SETS (",
+                comma_separate(|i| self.doc_group_by_item(i), items),
+                ")",
+            ),
+            _ => self.doc_display_pass(v),
+        }
+    }
+
     fn doc_function<'a, T: AstInfo>(&'a self, v: &'a Function<T>) -> RcDoc<'a> {
         match &v.args {
             FunctionArgs::Star => self.doc_display_pass(v),
+            FunctionArgs::WithinGroup { .. } => self.doc_display(v, "function within group"),
             FunctionArgs::Args { args, order_by } => {
                 if args.is_empty() {
                     // Nullary, don't allow newline between parens, so just delegate.
@@ -119,6 +119,53 @@ impl SqlServerTableDesc {
         }
     }
 
+    /// Removes the length limit of the `varchar` columns of this table.
+    ///
+    /// Tables that evolve their schema ingest `varchar` columns without a length
+    /// limit, so that the limit may be changed upstream.
+    pub fn remove_varchar_limits(&mut self) {
+        for column in &mut self.columns {
+            column.remove_varchar_limit();
+        }
+    }
+
+    /// Returns the columns that were added to this table, given the `columns`
+    /// that its latest capture instance captures and the names of those columns
+    /// that have a default value.
+    ///
+    /// Only nullable columns without a default value whose type can be ingested
+    /// are returned, and `varchar` columns lose their length limit. Returns no
+    /// columns if the capture instance doesn't capture every column that is
+    /// ingested from this table.
+    pub fn added_columns(
+        &self,
+        columns: &[SqlServerColumnRaw],
+        columns_with_defaults: &BTreeSet<Arc<str>>,
+    ) -> Vec<SqlServerColumnDesc> {
+        let captures_ingested = self
+            .columns
+            .iter()
+            .filter(|c| !c.is_excluded())
+            .all(|c| columns.iter().any(|raw| raw.name == c.name));
+        if !captures_ingested {
+            return vec![];
+        }
+        columns
+            .iter()
+            .filter(|raw| {
+                raw.is_nullable
+                    && !columns_with_defaults.contains(&raw.name)
+                    && !self.columns.iter().any(|c| c.name == raw.name)
+            })
+            .map(SqlServerColumnDesc::new)
+            .filter(|c| !c.is_excluded())
+            .map(|mut c| {
+                c.remove_varchar_limit();
+                c
+            })
+            .collect()
+    }
+
     /// Returns a [`SqlServerRowDecoder`] which can be used to decode [`tiberius::Row`]s into
     /// [`mz_repr::Row`]s that match the shape of the provided [`RelationDesc`].
     pub fn decoder(&self, desc: &RelationDesc) -> Result<SqlServerRowDecoder, SqlServerError> {
@@ -348,6 +395,17 @@ impl SqlServerColumnDesc {
             .map(|ct| SqlScalarType::String.nullable(ct.nullable));
     }
 
+    /// Remove the length limit of this [`SqlServerColumnDesc`] if it is a `varchar`.
+    pub fn remove_varchar_limit(&mut self) {
+        if let Some(SqlColumnType {
+            scalar_type: SqlScalarType::VarChar { max_length },
+            ..
+        }) = &mut self.column_type
+        {
+            *max_length = None;
+        }
+    }
+
     /// Exclude this [`SqlServerColumnDesc`] from being replicated into Materialize.
     pub fn exclude(&mut self) {
         self.column_type = None;
@@ -1359,6 +1417,59 @@ mod tests {
         assert_contains!(context, "columns with unlimited size do not support CDC");
     }
 
+    #[mz_ore::test]
+    fn test_added_columns() {
+        let table = SqlServerTableRaw {
+            schema_name: "my_schema".into(),
+            name: "my_table".into(),
+            capture_instance: Arc::new(SqlServerCaptureInstanceRaw {
+                name: "my_table_CT".into(),
+                create_date: NaiveDateTime::parse_from_str(
+                    "2024-01-01 00:00:00",
+                    "%Y-%m-%d %H:%M:%S",
+                )
+                .unwrap()
+                .into(),
+            }),
+            columns: [
+                SqlServerColumnRaw::new("a", "int"),
+                SqlServerColumnRaw::new("b", "int").nullable(true),
+            ]
+            .into(),
+        };
+        let mut table = SqlServerTableDesc::new(table, vec![]).unwrap();
+        table.apply_excl_columns(&BTreeSet::from(["b"]));
+
+        let captured = [
+            SqlServerColumnRaw::new("a", "int"),
+            SqlServerColumnRaw::new("c", "varchar")
+                .max_length(16)
+                .nullable(true),
+            SqlServerColumnRaw::new("d", "int"),
+            SqlServerColumnRaw::new("e", "int").nullable(true),
+            SqlServerColumnRaw::new("f", "bad_data_type").nullable(true),
+        ];
+        let added = table.added_columns(&captured, &BTreeSet::from(["e".into()]));
+        let added = added
+            .into_iter()
+            .map(|c| (c.name, c.column_type))
+            .collect_vec();
+        assert_eq!(
+            added,
+            [(
+                "c".into(),
+                Some(SqlScalarType::VarChar { max_length: None }.nullable(true))
+            )]
+        );
+
+        // Nothing is added from a capture instance that misses an ingested column.
+        assert!(
+            table
+                .added_columns(&captured[1..], &BTreeSet::new())
+                .is_empty()
+        );
+    }
+
     #[mz_ore::test]
     fn smoketest_decoder() {
         let sql_server_columns = [
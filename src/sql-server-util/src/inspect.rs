@@ -16,7 +16,7 @@ use itertools::Itertools;
 use mz_ore::cast::CastFrom;
 use mz_ore::retry::RetryResult;
 use smallvec::SmallVec;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::sync::Arc;
 use std::time::Duration;
@@ -475,6 +475,88 @@ pub async fn get_cdc_table_columns(
     Ok(columns)
 }
 
+/// The latest capture instance of a table.
+#[derive(Debug)]
+pub struct LatestCaptureInstance {
+    /// Name of the capture instance.
+    pub name: Arc<str>,
+    /// The LSN from which on the capture instance captures changes.
+    pub start_lsn: Lsn,
+    /// The upstream columns that the capture instance captures.
+    pub columns: Vec<SqlServerColumnRaw>,
+    /// The names of the captured columns that have a default value.
+    pub columns_with_defaults: BTreeSet<Arc<str>>,
+}
+
+/// Returns the most recently created capture instance of the table that is
+/// tracked by `capture_instance`, which might be `capture_instance` itself.
+///
+/// A table can have two capture instances at a time, which is how columns that
+/// were added to a table are captured.
+pub async fn get_latest_capture_instance(
+    client: &mut Client,
+    capture_instance: &str,
+) -> Result<Option<LatestCaptureInstance>, SqlServerError> {
+    static LATEST_CAPTURE_INSTANCE_QUERY: &str = "SELECT TOP 1 \
+        l.start_lsn, \
+        l.capture_instance \
+    FROM cdc.change_tables AS c \
+    JOIN cdc.change_tables AS l ON l.source_object_id = c.source_object_id \
+    WHERE c.capture_instance = @P1 \
+    ORDER BY l.create_date DESC;";
+    static CAPTURED_COLUMNS_QUERY: &str = "SELECT \
+        c.name AS col_name, \
+        ty.name AS col_type, \
+        c.is_nullable AS col_nullable, \
+        c.max_length AS col_max_length, \
+        c.precision AS col_precision, \
+        c.scale AS col_scale, \
+        c.is_computed AS col_is_computed, \
+        CAST(CASE WHEN c.default_object_id <> 0 THEN 1 ELSE 0 END AS bit) AS col_has_default \
+    FROM cdc.captured_columns AS cc \
+    JOIN cdc.change_tables AS ch ON cc.object_id = ch.object_id \
+    JOIN sys.columns AS c ON c.object_id = ch.source_object_id AND c.column_id = cc.column_id \
+    JOIN sys.types AS ty ON c.user_type_id = ty.user_type_id \
+    WHERE ch.capture_instance = @P1 \
+    ORDER BY cc.column_ordinal;";
+
+    let result = client
+        .query(LATEST_CAPTURE_INSTANCE_QUERY, &[&capture_instance])
+        .await?;
+    let Some(row) = result.first() else {
+        // The capture instance was dropped.
+        return Ok(None);
+    };
+    let start_lsn = parse_lsn(&result[..1])?;
+    let name: Arc<str> = get_value::<&str>(row, "capture_instance")?.into();
+
+    let result = client.query(CAPTURED_COLUMNS_QUERY, &[&&*name]).await?;
+    let mut columns = Vec::with_capacity(result.len());
+    let mut columns_with_defaults = BTreeSet::new();
+    for row in result.iter() {
+        let column = SqlServerColumnRaw {
+            name: get_value::<&str>(row, "col_name")?.into(),
+            data_type: get_value::<&str>(row, "col_type")?.into(),
+            is_nullable: get_value(row, "col_nullable")?,
+            max_length: get_value(row, "col_max_length")?,
+            precision: get_value(row, "col_precision")?,
+            scale: get_value(row, "col_scale")?,
+            is_computed: get_value(row, "col_is_computed")?,
+        };
+        if get_value(row, "col_has_default")? {
+            columns_with_defaults.insert(Arc::clone(&column.name));
+        }
+        columns.push(column);
+    }
+
+    Ok(Some(LatestCaptureInstance {
+        name,
+        start_lsn,
+        columns,
+        columns_with_defaults,
+    }))
+}
+
 /// Retrieve primary key and unique constraints for the given tables.  Tables should be provided as
 /// an interator of tuples, where each tuple contains is `(schema_name, table_name)`.
 pub async fn get_constraints_for_tables(
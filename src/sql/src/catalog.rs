@@ -45,7 +45,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::func::Func;
+use crate::func::FuncRef;
 use crate::names::{
     Aug, CommentObjectId, DatabaseId, FullItemName, FullSchemaName, ObjectId, PartialItemName,
     QualifiedItemName, QualifiedSchemaName, ResolvedDatabaseSpecifier, ResolvedIds, SchemaId,
@@ -835,7 +835,7 @@ pub trait CatalogItem {
     ///
     /// If the catalog item is not of a type that produces functions (i.e.,
     /// anything other than a function), it returns an error.
-    fn func(&self) -> Result<&'static Func, CatalogError>;
+    fn func(&self) -> Result<FuncRef, CatalogError>;
 
     /// Returns the resolved source connection.
     ///
@@ -1117,8 +1117,13 @@ pub enum CatalogType<T: TypeReference> {
     Bytes,
     Char,
     Date,
+    Enum {
+        labels: Vec<String>,
+    },
     Float32,
     Float64,
+    Inet,
+    Cidr,
     Int16,
     Int32,
     Int64,
@@ -1132,6 +1137,7 @@ pub enum CatalogType<T: TypeReference> {
         element_reference: T::Reference,
         element_modifiers: Vec<i64>,
     },
+    MacAddr,
     Map {
         key_reference: T::Reference,
         key_modifiers: Vec<i64>,
@@ -1160,6 +1166,9 @@ pub enum CatalogType<T: TypeReference> {
     VarChar,
     Int2Vector,
     MzAclItem,
+    MultiRange {
+        element_reference: T::Reference,
+    },
 }
 
 impl CatalogType<IdReference> {
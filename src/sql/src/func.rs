@@ -13,7 +13,8 @@
 use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::fmt;
-use std::sync::LazyLock;
+use std::ops::Deref;
+use std::sync::{Arc, LazyLock};
 
 use itertools::Itertools;
 use mz_expr::func;
@@ -24,7 +25,7 @@ use mz_pgrepr::oid;
 use mz_repr::role_id::RoleId;
 use mz_repr::{ColumnName, Datum, SqlRelationType, SqlScalarBaseType, SqlScalarType};
 
-use crate::ast::{SelectStatement, Statement};
+use crate::ast::{Query, Raw, SelectStatement, Statement};
 use crate::catalog::{CatalogType, TypeCategory, TypeReference};
 use crate::names::{self, ResolvedItemName};
 use crate::plan::error::PlanError;
@@ -113,7 +114,10 @@ impl TypeCategory {
             }
             SqlScalarType::Map { .. } => Self::Pseudo,
             SqlScalarType::MzTimestamp => Self::Numeric,
-            SqlScalarType::Range { .. } => Self::Range,
+            SqlScalarType::Inet | SqlScalarType::Cidr => Self::NetworkAddress,
+            SqlScalarType::MacAddr => Self::UserDefined,
+            SqlScalarType::Range { .. } | SqlScalarType::MultiRange { .. } => Self::Range,
+            SqlScalarType::Enum { .. } => Self::Enum,
         }
     }
 
@@ -124,6 +128,7 @@ impl TypeCategory {
             | ParamType::ArrayAny
             | ParamType::ArrayAnyCompatible
             | ParamType::AnyCompatible
+            | ParamType::EnumAny
             | ParamType::ListAny
             | ParamType::ListAnyCompatible
             | ParamType::ListElementAnyCompatible
@@ -133,7 +138,9 @@ impl TypeCategory {
             | ParamType::MapAny
             | ParamType::MapAnyCompatible
             | ParamType::RecordAny => Self::Pseudo,
-            ParamType::RangeAnyCompatible | ParamType::RangeAny => Self::Range,
+            ParamType::RangeAnyCompatible | ParamType::RangeAny | ParamType::MultiRangeAny => {
+                Self::Range
+            }
             ParamType::Plain(t) => Self::from_type(t),
         }
     }
@@ -181,7 +188,10 @@ impl TypeCategory {
             CatalogType::Record { .. } => TypeCategory::Composite,
             CatalogType::Map { .. } | CatalogType::Pseudo => Self::Pseudo,
             CatalogType::MzTimestamp => Self::String,
-            CatalogType::Range { .. } => Self::Range,
+            CatalogType::Inet | CatalogType::Cidr => Self::NetworkAddress,
+            CatalogType::MacAddr => Self::UserDefined,
+            CatalogType::Range { .. } | CatalogType::MultiRange { .. } => Self::Range,
+            CatalogType::Enum { .. } => Self::Enum,
         }
     }
 
@@ -200,13 +210,13 @@ impl TypeCategory {
             | Self::Enum
             | Self::Geometric
             | Self::List
-            | Self::NetworkAddress
             | Self::Pseudo
             | Self::Range
             | Self::Unknown
             | Self::UserDefined => None,
             Self::Boolean => Some(SqlScalarType::Bool),
             Self::DateTime => Some(SqlScalarType::TimestampTz { precision: None }),
+            Self::NetworkAddress => Some(SqlScalarType::Inet),
             Self::Numeric => Some(SqlScalarType::Float64),
             Self::String => Some(SqlScalarType::String),
             Self::Timespan => Some(SqlScalarType::Interval),
@@ -704,6 +714,9 @@ pub enum ParamType {
     /// A pseudotype permitting any array type, permitting other "Compatibility"-type
     /// parameters to find the best common type.
     ArrayAnyCompatible,
+    /// An pseudotype permitting any enum type, requiring other "Any"-type
+    /// parameters to be of the same type.
+    EnumAny,
     /// An pseudotype permitting any list type, requiring other "Any"-type
     /// parameters to be of the same type.
     ListAny,
@@ -745,6 +758,9 @@ pub enum ParamType {
     /// this type into generating non-existent range types (e.g. ranges of
     /// floats) that will panic.
     RangeAnyCompatible,
+    /// An pseudotype permitting any multirange type, requiring other
+    /// "Any"-type parameters to be of the same type.
+    MultiRangeAny,
     /// A psuedotype indicating that the function is only meant to be called
     /// internally by the database system.
     Internal,
@@ -759,9 +775,11 @@ impl ParamType {
         match self {
             Any | AnyElement | AnyCompatible | ListElementAnyCompatible => true,
             ArrayAny | ArrayAnyCompatible => matches!(t, Array(..) | Int2Vector),
+            EnumAny => matches!(t, Enum { .. }),
             ListAny | ListAnyCompatible => matches!(t, List { .. }),
             MapAny | MapAnyCompatible => matches!(t, Map { .. }),
             RangeAny | RangeAnyCompatible => matches!(t, Range { .. }),
+            MultiRangeAny => matches!(t, MultiRange { .. }),
             NonVecAny | NonVecAnyCompatible => !t.is_vec(),
             Internal => false,
             Plain(to) => typeconv::can_cast(ecx, CastContext::Implicit, t, to),
@@ -805,6 +823,7 @@ impl ParamType {
             | ArrayAny
             | ArrayAnyCompatible
             | AnyCompatible
+            | EnumAny
             | ListAny
             | ListAnyCompatible
             | ListElementAnyCompatible
@@ -817,7 +836,8 @@ impl ParamType {
             // `PolymorphicCompatClass::StructuralEq`.
             | RecordAny
             | RangeAny
-            | RangeAnyCompatible => true,
+            | RangeAnyCompatible
+            | MultiRangeAny => true,
             Any | Internal | Plain(_)  => false,
         }
     }
@@ -839,6 +859,7 @@ impl ParamType {
             ParamType::AnyElement => "anyelement",
             ParamType::ArrayAny => "anyarray",
             ParamType::ArrayAnyCompatible => "anycompatiblearray",
+            ParamType::EnumAny => "anyenum",
             ParamType::Internal => "internal",
             ParamType::ListAny => "list",
             ParamType::ListAnyCompatible => "anycompatiblelist",
@@ -852,6 +873,7 @@ impl ParamType {
             ParamType::RecordAny => "record",
             ParamType::RangeAny => "anyrange",
             ParamType::RangeAnyCompatible => "anycompatiblerange",
+            ParamType::MultiRangeAny => "anymultirange",
         }
     }
 }
@@ -882,7 +904,7 @@ impl From<SqlScalarBaseType> for ParamType {
     fn from(s: SqlScalarBaseType) -> ParamType {
         use SqlScalarBaseType::*;
         let s = match s {
-            Array | List | Map | Record | Range => {
+            Array | List | Map | Record | Range | MultiRange | Enum => {
                 panic!("use polymorphic parameters rather than {:?}", s);
             }
             AclItem => SqlScalarType::AclItem,
@@ -909,6 +931,9 @@ impl From<SqlScalarBaseType> for ParamType {
             PgLegacyName => SqlScalarType::PgLegacyName,
             Jsonb => SqlScalarType::Jsonb,
             Uuid => SqlScalarType::Uuid,
+            Inet => SqlScalarType::Inet,
+            Cidr => SqlScalarType::Cidr,
+            MacAddr => SqlScalarType::MacAddr,
             Oid => SqlScalarType::Oid,
             RegClass => SqlScalarType::RegClass,
             RegProc => SqlScalarType::RegProc,
@@ -1348,9 +1373,8 @@ impl TryFrom<&ParamType> for PolymorphicCompatClass {
         use ParamType::*;
 
         Ok(match param {
-            AnyElement | ArrayAny | ListAny | MapAny | NonVecAny | RangeAny => {
-                PolymorphicCompatClass::Any
-            }
+            AnyElement | ArrayAny | EnumAny | ListAny | MapAny | NonVecAny | RangeAny
+            | MultiRangeAny => PolymorphicCompatClass::Any,
             ArrayAnyCompatible | AnyCompatible | RangeAnyCompatible | NonVecAnyCompatible => {
                 PolymorphicCompatClass::BestCommonAny
             }
@@ -1417,8 +1441,8 @@ impl PolymorphicSolution {
 
         self.seen.push(match param {
             // These represent the keys of their respective compatibility classes.
-            AnyElement | AnyCompatible | ListAnyCompatible | MapAnyCompatible | NonVecAny
-            | RecordAny => seen,
+            AnyElement | AnyCompatible | EnumAny | ListAnyCompatible | MapAnyCompatible
+            | NonVecAny | RecordAny => seen,
             MapAny => seen.map_coerced(|array| array.unwrap_map_value_type().clone()),
             ListAny => seen.map_coerced(|array| array.unwrap_list_element_type().clone()),
             ArrayAny | ArrayAnyCompatible => {
@@ -1427,6 +1451,9 @@ impl PolymorphicSolution {
             RangeAny | RangeAnyCompatible => {
                 seen.map_coerced(|range| range.unwrap_range_element_type().clone())
             }
+            MultiRangeAny => seen.map_coerced(|multirange| {
+                multirange.unwrap_multirange_element_type().clone()
+            }),
             ListElementAnyCompatible => seen.map_coerced(|el| SqlScalarType::List {
                 custom_id: None,
                 element_type: Box::new(el),
@@ -1543,9 +1570,8 @@ impl PolymorphicSolution {
         );
 
         match param {
-            AnyElement | AnyCompatible | ListAnyCompatible | MapAnyCompatible | NonVecAny => {
-                self.key.clone()
-            }
+            AnyElement | AnyCompatible | EnumAny | ListAnyCompatible | MapAnyCompatible
+            | NonVecAny => self.key.clone(),
             ArrayAny | ArrayAnyCompatible => self
                 .key
                 .as_ref()
@@ -1561,6 +1587,9 @@ impl PolymorphicSolution {
             RangeAny | RangeAnyCompatible => self.key.as_ref().map(|key| SqlScalarType::Range {
                 element_type: Box::new(key.clone()),
             }),
+            MultiRangeAny => self.key.as_ref().map(|key| SqlScalarType::MultiRange {
+                element_type: Box::new(key.clone()),
+            }),
             ListElementAnyCompatible => self
                 .key
                 .as_ref()
@@ -1786,6 +1815,111 @@ impl Func {
     }
 }
 
+/// A reference to the definition of a function in the catalog.
+#[derive(Debug, Clone)]
+pub enum FuncRef {
+    /// A built-in function.
+    Builtin(&'static Func),
+    /// A function created by `CREATE FUNCTION`.
+    User(Arc<Func>),
+}
+
+impl Deref for FuncRef {
+    type Target = Func;
+
+    fn deref(&self) -> &Func {
+        match self {
+            FuncRef::Builtin(func) => func,
+            FuncRef::User(func) => func,
+        }
+    }
+}
+
+/// The return type of a function created by `CREATE FUNCTION`.
+#[derive(Debug, Clone)]
+pub enum SqlFuncReturnType {
+    /// `RETURNS <type>`
+    Scalar(SqlScalarType),
+    /// `RETURNS TABLE (<columns>)`
+    Table(Vec<(ColumnName, SqlScalarType)>),
+}
+
+/// Builds the definition of a function created by `CREATE FUNCTION ...
+/// LANGUAGE SQL`.
+///
+/// Like the SQL-implemented built-in functions, the function's `body` is
+/// planned anew and inlined into the calling expression each time the function
+/// is called. A scalar function evaluates to the single value that its body
+/// returns, or NULL if the body returns no rows; it is an error for the body to
+/// return more than one row.
+///
+/// The OID of the function's sole implementation is left unset, as it is that
+/// of the function's catalog item.
+pub fn sql_user_func(
+    name: String,
+    params: Vec<(Option<String>, SqlScalarType)>,
+    returns: SqlFuncReturnType,
+    body: Query<Raw>,
+) -> Func {
+    let param_list = ParamList::Exact(
+        params
+            .iter()
+            .map(|(_name, ty)| ParamType::Plain(ty.clone()))
+            .collect(),
+    );
+    match returns {
+        SqlFuncReturnType::Scalar(return_type) => {
+            let return_types = vec![return_type.clone()];
+            Func::Scalar(vec![FuncImpl {
+                oid: 0,
+                params: param_list,
+                return_type: ReturnType::scalar(ParamType::Plain(return_type)),
+                op: Operation::variadic(move |ecx, args| {
+                    if !ecx.allow_subqueries {
+                        sql_bail!("{} does not allow calls to SQL functions", ecx.name)
+                    }
+                    let (expr, _scope) = query::plan_sql_function_body(
+                        ecx.qcx,
+                        &name,
+                        &params,
+                        &return_types,
+                        &body,
+                    )?;
+                    let mut out = expr.select();
+                    out.splice_parameters(&args, 0);
+                    Ok(out)
+                }),
+            }])
+        }
+        SqlFuncReturnType::Table(columns) => {
+            let (column_names, return_types): (Vec<_>, Vec<_>) = columns.into_iter().unzip();
+            let return_type = match return_types.as_slice() {
+                [ty] => ParamType::Plain(ty.clone()),
+                _ => ParamType::RecordAny,
+            };
+            Func::Table(vec![FuncImpl {
+                oid: 0,
+                params: param_list,
+                return_type: ReturnType::set_of(return_type),
+                op: Operation::variadic(move |ecx, args| {
+                    let (mut expr, _scope) = query::plan_sql_function_body(
+                        ecx.qcx,
+                        &name,
+                        &params,
+                        &return_types,
+                        &body,
+                    )?;
+                    expr.splice_parameters(&args, 0);
+                    Ok(TableFuncPlan {
+                        imp: TableFuncImpl::Expr(expr),
+                        column_names: column_names.clone(),
+                    })
+                }),
+            }])
+        }
+    }
+}
+
 /// Functions using this macro should be transformed/planned away before
 /// reaching function selection code, but still need to be present in the
 /// catalog during planning.
@@ -2198,6 +2332,19 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(String, Interval)
                 => BinaryFunc::from(func::DateTruncInterval) => Interval, 1218;
         },
+        "datemultirange" => Scalar {
+            params!() => multirange_constructor(SqlScalarType::Date) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Date),
+            }, 4292;
+            params!(SqlScalarType::Range {
+                element_type: Box::new(SqlScalarType::Date),
+            }) => multirange_constructor(SqlScalarType::Date) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Date),
+            }, 4293;
+            variadic_ranges(SqlScalarType::Date) => multirange_constructor(SqlScalarType::Date) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Date),
+            }, 4294;
+        },
         "daterange" => Scalar {
             params!(Date, Date) => Operation::variadic(|_ecx, mut exprs| {
                 exprs.push(HirScalarExpr::literal(
@@ -2232,6 +2379,9 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(Float64) => UnaryFunc::Exp(func::Exp) => Float64, 1347;
             params!(Numeric) => UnaryFunc::ExpNumeric(func::ExpNumeric) => Numeric, 1732;
         },
+        "family" => Scalar {
+            params!(Inet) => UnaryFunc::Family(func::Family) => Int32, 711;
+        },
         "floor" => Scalar {
             params!(Float32) => UnaryFunc::FloorFloat32(func::FloorFloat32)
                 => Float32, oid::FUNC_FLOOR_F32_OID;
@@ -2350,9 +2500,25 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(Bytes, Bytes, String) => VariadicFunc::from(variadic::HmacBytes)
                 => Bytes, oid::FUNC_PG_HMAC_BYTES;
         },
+        "host" => Scalar {
+            params!(Inet) => UnaryFunc::Host(func::Host) => String, 699;
+        },
         "initcap" => Scalar {
             params!(String) => UnaryFunc::Initcap(func::Initcap) => String, 872;
         },
+        "int4multirange" => Scalar {
+            params!() => multirange_constructor(SqlScalarType::Int32) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Int32),
+            }, 4280;
+            params!(SqlScalarType::Range {
+                element_type: Box::new(SqlScalarType::Int32),
+            }) => multirange_constructor(SqlScalarType::Int32) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Int32),
+            }, 4281;
+            variadic_ranges(SqlScalarType::Int32) => multirange_constructor(SqlScalarType::Int32) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Int32),
+            }, 4282;
+        },
         "int4range" => Scalar {
             params!(Int32, Int32) => Operation::variadic(|_ecx, mut exprs| {
                 exprs.push(HirScalarExpr::literal(
@@ -2374,6 +2540,19 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 element_type: Box::new(SqlScalarType::Int32),
             }, 3841;
         },
+        "int8multirange" => Scalar {
+            params!() => multirange_constructor(SqlScalarType::Int64) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Int64),
+            }, 4295;
+            params!(SqlScalarType::Range {
+                element_type: Box::new(SqlScalarType::Int64),
+            }) => multirange_constructor(SqlScalarType::Int64) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Int64),
+            }, 4296;
+            variadic_ranges(SqlScalarType::Int64) => multirange_constructor(SqlScalarType::Int64) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Int64),
+            }, 4297;
+        },
         "int8range" => Scalar {
             params!(Int64, Int64) => Operation::variadic(|_ecx, mut exprs| {
                 exprs.push(HirScalarExpr::literal(
@@ -2397,6 +2576,8 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         },
         "isempty" => Scalar {
             params!(RangeAny) => UnaryFunc::RangeEmpty(func::RangeEmpty) => Bool, 3850;
+            params!(MultiRangeAny) => multirange_span_then(UnaryFunc::RangeEmpty(func::RangeEmpty))
+                => Bool, 4237;
         },
         "jsonb_array_length" => Scalar {
             params!(Jsonb) => UnaryFunc::JsonbArrayLength(func::JsonbArrayLength) => Int32, 3207;
@@ -2474,12 +2655,18 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "lower" => Scalar {
             params!(String) => UnaryFunc::Lower(func::Lower) => String, 870;
             params!(RangeAny) => UnaryFunc::RangeLower(func::RangeLower) => AnyElement, 3848;
+            params!(MultiRangeAny) => multirange_span_then(UnaryFunc::RangeLower(func::RangeLower))
+                => AnyElement, 4235;
         },
         "lower_inc" => Scalar {
             params!(RangeAny) => UnaryFunc::RangeLowerInc(func::RangeLowerInc) => Bool, 3851;
+            params!(MultiRangeAny) => multirange_span_then(UnaryFunc::RangeLowerInc(func::RangeLowerInc))
+                => Bool, 4238;
         },
         "lower_inf" => Scalar {
             params!(RangeAny) => UnaryFunc::RangeLowerInf(func::RangeLowerInf) => Bool, 3853;
+            params!(MultiRangeAny) => multirange_span_then(UnaryFunc::RangeLowerInf(func::RangeLowerInf))
+                => Bool, 4240;
         },
         "lpad" => Scalar {
             params!(String, Int32) => VariadicFunc::from(variadic::PadLeading) => String, 879;
@@ -2500,6 +2687,9 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(Int64, Int64, Int64, Int64, Int64, Float64)
                 => VariadicFunc::from(variadic::MakeTimestamp) => Timestamp, 3461;
         },
+        "masklen" => Scalar {
+            params!(Inet) => UnaryFunc::Masklen(func::Masklen) => Int32, 697;
+        },
         "md5" => Scalar {
             params!(String) => Operation::unary(move |_ecx, input| {
                 let algorithm = HirScalarExpr::literal(Datum::String("md5"), SqlScalarType::String);
@@ -2543,6 +2733,15 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 Operation::nullary(|_ecx| catalog_name_only!("mod"))
                 => UInt64, oid::FUNC_MOD_UINT64_OID;
         },
+        "multirange" => Scalar {
+            params!(RangeAny) => Operation::unary(|ecx, range| {
+                let elem_type = ecx.scalar_type(&range).unwrap_range_element_type().clone();
+                Ok(multirange_create(elem_type, vec![range]))
+            }) => MultiRangeAny, 4298;
+        },
+        "network" => Scalar {
+            params!(Inet) => UnaryFunc::Network(func::Network) => Cidr, 683;
+        },
         "normalize" => Scalar {
             // Parser always provides two arguments (defaults second to "NFC" when omitted)
             params!(String, String) => BinaryFunc::Normalize(func::Normalize)
@@ -2551,6 +2750,19 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "now" => Scalar {
             params!() => UnmaterializableFunc::CurrentTimestamp => TimestampTz, 1299;
         },
+        "nummultirange" => Scalar {
+            params!() => multirange_constructor(SqlScalarType::Numeric { max_scale: None }) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Numeric { max_scale: None }),
+            }, 4283;
+            params!(SqlScalarType::Range {
+                element_type: Box::new(SqlScalarType::Numeric { max_scale: None }),
+            }) => multirange_constructor(SqlScalarType::Numeric { max_scale: None }) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Numeric { max_scale: None }),
+            }, 4284;
+            variadic_ranges(SqlScalarType::Numeric { max_scale: None }) => multirange_constructor(SqlScalarType::Numeric { max_scale: None }) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Numeric { max_scale: None }),
+            }, 4285;
+        },
         "numrange" => Scalar {
             params!(Numeric, Numeric) => Operation::variadic(|_ecx, mut exprs| {
                 exprs.push(HirScalarExpr::literal(
@@ -2886,6 +3098,10 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "radians" => Scalar {
             params!(Float64) => UnaryFunc::Radians(func::Radians) => Float64, 1609;
         },
+        "range_merge" => Scalar {
+            params!(MultiRangeAny) => UnaryFunc::MultiRangeSpan(func::MultiRangeSpan)
+                => RangeAny, 4228;
+        },
         "repeat" => Scalar {
             params!(String, Int32) => BinaryFunc::RepeatString(func::RepeatString) => String, 1622;
         },
@@ -2915,6 +3131,10 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             ) => String, 882;
             params!(String, String) => BinaryFunc::from(func::TrimTrailing) => String, 876;
         },
+        "set_masklen" => Scalar {
+            params!(Inet, Int32) => BinaryFunc::from(func::SetMasklenInet) => Inet, 605;
+            params!(Cidr, Int32) => BinaryFunc::from(func::SetMasklenCidr) => Cidr, 635;
+        },
         "sha224" => Scalar {
             params!(Bytes) => digest("sha224") => Bytes, 3419;
         },
@@ -3140,6 +3360,19 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(Float64) => UnaryFunc::TruncFloat64(func::TruncFloat64) => Float64, 1343;
             params!(Numeric) => UnaryFunc::TruncNumeric(func::TruncNumeric) => Numeric, 1710;
         },
+        "tsmultirange" => Scalar {
+            params!() => multirange_constructor(SqlScalarType::Timestamp { precision: None }) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Timestamp { precision: None }),
+            }, 4286;
+            params!(SqlScalarType::Range {
+                element_type: Box::new(SqlScalarType::Timestamp { precision: None }),
+            }) => multirange_constructor(SqlScalarType::Timestamp { precision: None }) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Timestamp { precision: None }),
+            }, 4287;
+            variadic_ranges(SqlScalarType::Timestamp { precision: None }) => multirange_constructor(SqlScalarType::Timestamp { precision: None }) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::Timestamp { precision: None }),
+            }, 4288;
+        },
         "tsrange" => Scalar {
             params!(Timestamp, Timestamp) => Operation::variadic(|_ecx, mut exprs| {
                 exprs.push(HirScalarExpr::literal(
@@ -3165,6 +3398,19 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 element_type: Box::new(SqlScalarType::Timestamp { precision: None }),
             }, 3934;
         },
+        "tstzmultirange" => Scalar {
+            params!() => multirange_constructor(SqlScalarType::TimestampTz { precision: None }) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::TimestampTz { precision: None }),
+            }, 4289;
+            params!(SqlScalarType::Range {
+                element_type: Box::new(SqlScalarType::TimestampTz { precision: None }),
+            }) => multirange_constructor(SqlScalarType::TimestampTz { precision: None }) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::TimestampTz { precision: None }),
+            }, 4290;
+            variadic_ranges(SqlScalarType::TimestampTz { precision: None }) => multirange_constructor(SqlScalarType::TimestampTz { precision: None }) => SqlScalarType::MultiRange {
+                element_type: Box::new(SqlScalarType::TimestampTz { precision: None }),
+            }, 4291;
+        },
         "tstzrange" => Scalar {
             params!(TimestampTz, TimestampTz) => Operation::variadic(|_ecx, mut exprs| {
                 exprs.push(HirScalarExpr::literal(
@@ -3193,12 +3439,18 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "upper" => Scalar {
             params!(String) => UnaryFunc::Upper(func::Upper) => String, 871;
             params!(RangeAny) => UnaryFunc::RangeUpper(func::RangeUpper) => AnyElement, 3849;
+            params!(MultiRangeAny) => multirange_span_then(UnaryFunc::RangeUpper(func::RangeUpper))
+                => AnyElement, 4236;
         },
         "upper_inc" => Scalar {
             params!(RangeAny) => UnaryFunc::RangeUpperInc(func::RangeUpperInc) => Bool, 3852;
+            params!(MultiRangeAny) => multirange_span_then(UnaryFunc::RangeUpperInc(func::RangeUpperInc))
+                => Bool, 4239;
         },
         "upper_inf" => Scalar {
             params!(RangeAny) => UnaryFunc::RangeUpperInf(func::RangeUpperInf) => Bool, 3854;
+            params!(MultiRangeAny) => multirange_span_then(UnaryFunc::RangeUpperInf(func::RangeUpperInf))
+                => Bool, 4241;
         },
         "uuid_generate_v5" => Scalar {
             params!(Uuid, String) => BinaryFunc::from(func::UuidGenerateV5)
@@ -3328,6 +3580,11 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 bail_unsupported!("anyelement_in")
             }) => AnyElement, 2312;
         },
+        "anyenum_in" => Scalar {
+            params!(String) => Operation::variadic(|_ecx, _exprs| {
+                bail_unsupported!("anyenum_in")
+            }) => EnumAny, 3504;
+        },
         "anynonarray_in" => Scalar {
             params!(String) => Operation::variadic(|_ecx, _exprs| {
                 bail_unsupported!("anynonarray_in")
@@ -3339,6 +3596,12 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                     bail_unsupported!("anyrange_in")
                 }) => RangeAny, 3832;
         },
+        "anymultirange_in" => Scalar {
+            params!(String, Oid, Int32) =>
+                Operation::variadic(|_ecx, _exprs| {
+                    bail_unsupported!("anymultirange_in")
+                }) => MultiRangeAny, 4229;
+        },
         "array_in" => Scalar {
             params!(String, Oid, Int32) =>
                 Operation::variadic(|_ecx, _exprs| {
@@ -3366,6 +3629,11 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 bail_unsupported!("charin")
             }) => PgLegacyChar, 1245;
         },
+        "cidr_in" => Scalar {
+            params!(String) => Operation::variadic(|_ecx, _exprs| {
+                bail_unsupported!("cidr_in")
+            }) => Cidr, 1267;
+        },
         "date_in" => Scalar {
             params!(String) => Operation::variadic(|_ecx, _exprs| {
                 bail_unsupported!("date_in")
@@ -3381,6 +3649,11 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 bail_unsupported!("float8in")
             }) => Float64, 214;
         },
+        "inet_in" => Scalar {
+            params!(String) => Operation::variadic(|_ecx, _exprs| {
+                bail_unsupported!("inet_in")
+            }) => Inet, 910;
+        },
         "int2in" => Scalar {
             params!(String) => Operation::variadic(|_ecx, _exprs| {
                 bail_unsupported!("int2in")
@@ -3417,6 +3690,11 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 bail_unsupported!("jsonb_in")
             }) => Jsonb, 3806;
         },
+        "macaddr_in" => Scalar {
+            params!(String) => Operation::variadic(|_ecx, _exprs| {
+                bail_unsupported!("macaddr_in")
+            }) => MacAddr, 436;
+        },
         "namein" => Scalar {
             params!(String) => Operation::variadic(|_ecx, _exprs| {
                 bail_unsupported!("namein")
@@ -3433,6 +3711,12 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 bail_unsupported!("oidin")
             }) => Oid, 1798;
         },
+        "multirange_in" => Scalar {
+            params!(String, Oid, Int32) =>
+                Operation::variadic(|_ecx, _exprs| {
+                    bail_unsupported!("multirange_in")
+                }) => MultiRangeAny, 4231;
+        },
         "range_in" => Scalar {
             params!(String, Oid, Int32) =>
                 Operation::variadic(|_ecx, _exprs| {
@@ -3526,6 +3810,11 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 Operation::nullary(|_ecx| catalog_name_only!("charrecv"))
                 => PgLegacyChar, 2434;
         },
+        "cidr_recv" => Scalar {
+            params!(Internal) =>
+                Operation::nullary(|_ecx| catalog_name_only!("cidr_recv"))
+                => Cidr, 2498;
+        },
         "date_recv" => Scalar {
             params!(Internal) =>
                 Operation::nullary(|_ecx| catalog_name_only!("date_recv"))
@@ -3543,6 +3832,11 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                     catalog_name_only!("float8recv")
                 }) => Float64, 2426;
         },
+        "inet_recv" => Scalar {
+            params!(Internal) =>
+                Operation::nullary(|_ecx| catalog_name_only!("inet_recv"))
+                => Inet, 2496;
+        },
         "int4recv" => Scalar {
             params!(Internal) =>
                 Operation::nullary(|_ecx| catalog_name_only!("int4recv"))
@@ -3565,6 +3859,11 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                     catalog_name_only!("jsonb_recv")
                 }) => Jsonb, 3805;
         },
+        "macaddr_recv" => Scalar {
+            params!(Internal) =>
+                Operation::nullary(|_ecx| catalog_name_only!("macaddr_recv"))
+                => MacAddr, 2494;
+        },
         "namerecv" => Scalar {
             params!(Internal) =>
                 Operation::nullary(|_ecx| catalog_name_only!("namerecv"))
@@ -3662,6 +3961,12 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                     catalog_name_only!("range_recv")
                 }) => RangeAny, 3836;
         },
+        "multirange_recv" => Scalar {
+            params!(Internal) =>
+                Operation::nullary(|_ecx| {
+                    catalog_name_only!("multirange_recv")
+                }) => MultiRangeAny, 4233;
+        },
 
 
         // Aggregates.
@@ -3749,6 +4054,57 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(Interval) => AggregateFunc::MinInterval => Interval, 2144;
             params!(Time) => AggregateFunc::MinTime => Time, 2139;
         },
+        "mode" => Aggregate {
+            params!(AnyElement) => Operation::unary_ordered(|_ecx, e, order_by| {
+                let desc = within_group_desc(&order_by);
+                Ok((e, AggregateFunc::Mode { desc }))
+            }) => AnyElement, 3994;
+        },
+        "percentile_cont" => Aggregate {
+            params!(Float64, Float64) => Operation::binary_ordered(|_ecx, fraction, e, order_by| {
+                let percentiles = percentiles(fraction, false)?;
+                let desc = within_group_desc(&order_by);
+                Ok((e, AggregateFunc::PercentileCont { percentiles, desc }))
+            }) => Float64, 3974;
+            params!(Float64, Interval) => Operation::binary_ordered(|_ecx, fraction, e, order_by| {
+                let percentiles = percentiles(fraction, false)?;
+                let desc = within_group_desc(&order_by);
+                Ok((e, AggregateFunc::PercentileCont { percentiles, desc }))
+            }) => Interval, 3976;
+            params!(SqlScalarType::Array(Box::new(SqlScalarType::Float64)), Float64) =>
+                Operation::binary_ordered(|_ecx, fractions, e, order_by| {
+                    let percentiles = percentiles(fractions, true)?;
+                    let desc = within_group_desc(&order_by);
+                    Ok((e, AggregateFunc::PercentileCont { percentiles, desc }))
+                }) => SqlScalarType::Array(Box::new(SqlScalarType::Float64)), 3975;
+            params!(SqlScalarType::Array(Box::new(SqlScalarType::Float64)), Interval) =>
+                Operation::binary_ordered(|_ecx, fractions, e, order_by| {
+                    let percentiles = percentiles(fractions, true)?;
+                    let desc = within_group_desc(&order_by);
+                    Ok((e, AggregateFunc::PercentileCont { percentiles, desc }))
+                }) => SqlScalarType::Array(Box::new(SqlScalarType::Interval)), 3977;
+        },
+        "percentile_disc" => Aggregate {
+            params!(Float64, AnyElement) =>
+                Operation::binary_ordered(|_ecx, fraction, e, order_by| {
+                    let percentiles = percentiles(fraction, false)?;
+                    let desc = within_group_desc(&order_by);
+                    Ok((e, AggregateFunc::PercentileDisc { percentiles, desc }))
+                }) => AnyElement, 3972;
+            params!(SqlScalarType::Array(Box::new(SqlScalarType::Float64)), NonVecAny) =>
+                Operation::binary_ordered(|ecx, fractions, e, order_by| {
+                    let elem_type = ecx.scalar_type(&e);
+                    if let Err(elem_type) = elem_type.array_of_self_elem_type() {
+                        bail_unsupported!(format!(
+                            "percentile_disc on {}",
+                            ecx.humanize_sql_scalar_type(&elem_type, false)
+                        ));
+                    }
+                    let percentiles = percentiles(fractions, true)?;
+                    let desc = within_group_desc(&order_by);
+                    Ok((e, AggregateFunc::PercentileDisc { percentiles, desc }))
+                }) => ArrayAny, 3973;
+        },
         "jsonb_agg" => Aggregate {
             params!(Any) => Operation::unary_ordered(|ecx, e, order_by| {
                 // TODO(see <materialize#7572>): remove this
@@ -3808,6 +4164,14 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 Ok((e, AggregateFunc::JsonbObjectAgg { order_by }))
             }) => Jsonb, 3270;
         },
+        "range_agg" => Aggregate {
+            params!(RangeAny) => AggregateFunc::RangeAgg => MultiRangeAny, 4301;
+            params!(MultiRangeAny) => AggregateFunc::RangeAgg => MultiRangeAny, 6227;
+        },
+        "range_intersect_agg" => Aggregate {
+            params!(RangeAny) => AggregateFunc::RangeIntersectAgg => RangeAny, 4450;
+            params!(MultiRangeAny) => AggregateFunc::RangeIntersectAgg => MultiRangeAny, 4389;
+        },
         "string_agg" => Aggregate {
             params!(String, String) => Operation::binary_ordered(|_ecx, value, sep, order_by| {
                 let e = HirScalarExpr::call_variadic(
@@ -3858,6 +4222,18 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "dense_rank" => ScalarWindow {
             params!() => ScalarWindowFunc::DenseRank => Int64, 3102;
         },
+        "percent_rank" => ScalarWindow {
+            params!() => ScalarWindowFunc::PercentRank => Float64, 3103;
+        },
+        "cume_dist" => ScalarWindow {
+            params!() => ScalarWindowFunc::CumeDist => Float64, 3104;
+        },
+        "ntile" => ValueWindow {
+            params!(Int32) => Operation::unary(|_ecx, num_buckets| {
+                check_positive_window_func_arg(&num_buckets, "ntile")?;
+                Ok((num_buckets, ValueWindowFunc::Ntile))
+            }) => Int32, 3105;
+        },
         "lag" => ValueWindow {
             // All args are encoded into a single record to be handled later
             params!(AnyElement) => Operation::unary(|ecx, e| {
@@ -3960,6 +4336,19 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "last_value" => ValueWindow {
             params!(AnyElement) => ValueWindowFunc::LastValue => AnyElement, 3113;
         },
+        "nth_value" => ValueWindow {
+            // Both args are encoded into a single record to be handled later
+            params!(AnyElement, Int32) => Operation::binary(|_ecx, e, n| {
+                check_positive_window_func_arg(&n, "nth_value")?;
+                let e = HirScalarExpr::call_variadic(
+                    variadic::RecordCreate {
+                        field_names: vec![ColumnName::from("expr"), ColumnName::from("n")],
+                    },
+                    vec![e, n],
+                );
+                Ok((e, ValueWindowFunc::NthValue))
+            }) => AnyElement, 3114;
+        },
 
         // Table functions.
         "generate_series" => Table {
@@ -4256,6 +4645,10 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(String, String) => BinaryFunc::from(func::ConstantTimeEqString)
                 => Bool, oid::FUNC_CONSTANT_TIME_EQ_STRING_OID;
         },
+        "approx_count_distinct" => Aggregate {
+            params!(Any) => AggregateFunc::ApproxCountDistinct
+                => Int64, oid::FUNC_APPROX_COUNT_DISTINCT_OID;
+        },
         // Note: this is the original version of the AVG(...) function, as it existed prior to
         // v0.66. We updated the internal type promotion used when summing values to increase
         // precision, but objects (e.g. materialized views) that already used the AVG(...) function
@@ -4555,6 +4948,16 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 "has_type_privilege(current_user, $1, $2)",
             ) => Bool, 3143;
         },
+        "hll_cardinality" => Scalar {
+            params!(Bytes) => UnaryFunc::HllCardinality(func::HllCardinality)
+                => Int64, oid::FUNC_HLL_CARDINALITY_OID;
+        },
+        "hll_sketch_agg" => Aggregate {
+            params!(Any) => AggregateFunc::HllSketchAgg => Bytes, oid::FUNC_HLL_SKETCH_AGG_OID;
+        },
+        "hll_union_agg" => Aggregate {
+            params!(Bytes) => AggregateFunc::HllUnionAgg => Bytes, oid::FUNC_HLL_UNION_AGG_OID;
+        },
         "kafka_murmur2" => Scalar {
             params!(String) => UnaryFunc::KafkaMurmur2String(func::KafkaMurmur2String)
                 => Int32, oid::FUNC_KAFKA_MURMUR2_STRING_OID;
@@ -4906,6 +5309,20 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 // This return type should be equivalent to
                 // "ListElementAny", but this would be its sole use.
                 ReturnType::set_of(Any), oid::FUNC_UNNEST_MAP_OID;
+            vec![MultiRangeAny] => Operation::unary(move |ecx, e| {
+                let range_type = SqlScalarType::Range {
+                    element_type: Box::new(
+                        ecx.scalar_type(&e).unwrap_multirange_element_type().clone(),
+                    ),
+                };
+                Ok(TableFuncPlan {
+                    imp: TableFuncImpl::CallTable {
+                        func: TableFunc::UnnestList { el_typ: range_type },
+                        exprs: vec![e],
+                    },
+                    column_names: vec!["unnest".into()],
+                })
+            }) => ReturnType::set_of(RangeAny), 1293;
         }
     }
 });
@@ -5525,6 +5942,109 @@ fn digest(algorithm: &'static str) -> Operation<HirScalarExpr> {
     })
 }
 
+/// The parameters of the variadic form of a multirange constructor, which takes
+/// two or more ranges of `elem_type`. A single range is handled by the unary
+/// form instead.
+fn variadic_ranges(elem_type: SqlScalarType) -> ParamList {
+    let range = SqlScalarType::Range {
+        element_type: Box::new(elem_type),
+    };
+    ParamList::Variadic {
+        leading: vec![range.clone().into()],
+        trailing: range.into(),
+    }
+}
+
+/// Builds a multirange of the ranges in `exprs`. As in PostgreSQL, the
+/// multirange of a single null range is null, while a null member of a longer
+/// list of ranges is an error.
+fn multirange_create(elem_type: SqlScalarType, exprs: Vec<HirScalarExpr>) -> HirScalarExpr {
+    let multirange_type = SqlScalarType::MultiRange {
+        element_type: Box::new(elem_type.clone()),
+    };
+    let multirange =
+        HirScalarExpr::call_variadic(variadic::MultiRangeCreate { elem_type }, exprs.clone());
+    match exprs.as_slice() {
+        [range] => HirScalarExpr::if_then_else(
+            range.clone().call_is_null(),
+            HirScalarExpr::literal_null(multirange_type),
+            multirange,
+        ),
+        _ => multirange,
+    }
+}
+
+fn multirange_constructor(elem_type: SqlScalarType) -> Operation<HirScalarExpr> {
+    Operation::variadic(move |_ecx, exprs| Ok(multirange_create(elem_type.clone(), exprs)))
+}
+
+/// Applies `range_func` to the smallest range containing a multirange, which
+/// agrees with PostgreSQL for the bound accessors of multiranges.
+fn multirange_span_then(range_func: UnaryFunc) -> Operation<HirScalarExpr> {
+    Operation::unary(move |_ecx, e| {
+        Ok(e.call_unary(UnaryFunc::MultiRangeSpan(func::MultiRangeSpan))
+            .call_unary(range_func.clone()))
+    })
+}
+
+/// Applies the binary range function `range_func` to the arguments of a
+/// positional operator, replacing each multirange argument with the smallest
+/// range containing it.
+fn multirange_positional_op(range_func: BinaryFunc) -> Operation<HirScalarExpr> {
+    Operation::binary(move |ecx, lhs, rhs| {
+        let span = |e: HirScalarExpr| match ecx.scalar_type(&e) {
+            SqlScalarType::MultiRange { .. } => {
+                e.call_unary(UnaryFunc::MultiRangeSpan(func::MultiRangeSpan))
+            }
+            _ => e,
+        };
+        Ok(span(lhs).call_binary(span(rhs), range_func.clone()))
+    })
+}
+
+/// Whether the `WITHIN GROUP` clause of an ordered-set aggregate sorts in
+/// descending order. A missing clause is reported once the aggregate is planned.
+fn within_group_desc(order_by: &[ColumnOrder]) -> bool {
+    order_by.first().is_some_and(|col| col.desc)
+}
+
+/// Evaluates the fraction arguments of `percentile_cont` and `percentile_disc`,
+/// which must be constant, and checks that each is between 0 and 1.
+fn percentiles(fractions: HirScalarExpr, array: bool) -> Result<mz_expr::Percentiles, PlanError> {
+    let row = fractions
+        .try_into_literal_row()
+        .map_err(|_| sql_err!("percentile fraction must be a constant"))?;
+    let datum = row.unpack_first();
+    let datums = match datum {
+        Datum::Null => sql_bail!("percentile fraction must not be null"),
+        Datum::Array(datums) => datums.elements().iter().collect(),
+        datum => vec![datum],
+    };
+    let mut fractions = Vec::with_capacity(datums.len());
+    for datum in datums {
+        if datum.is_null() {
+            sql_bail!("percentile fraction must not be null");
+        }
+        let fraction = datum.unwrap_float64();
+        if !(0.0..=1.0).contains(&fraction) {
+            sql_bail!("percentile value {} is not between 0 and 1", fraction);
+        }
+        fractions.push(fraction.into());
+    }
+    Ok(mz_expr::Percentiles { fractions, array })
+}
+
+/// Rejects a constant, non-positive `ntile` or `nth_value` argument. (Arguments that are not
+/// constant are checked only at runtime, where they make the result null.)
+fn check_positive_window_func_arg(arg: &HirScalarExpr, name: &str) -> Result<(), PlanError> {
+    if let Some(Datum::Int32(n)) = arg.as_literal()
+        && n <= 0
+    {
+        sql_bail!("argument of {} must be greater than zero", name);
+    }
+    Ok(())
+}
+
 fn array_to_string(
     ecx: &ExprContext,
     exprs: Vec<HirScalarExpr>,
@@ -5619,6 +6139,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             } => Time, 1849;
             params!(Numeric, Numeric) => BF::from(func::AddNumeric) => Numeric, 1758;
             params!(RangeAny, RangeAny) => BF::from(func::RangeUnion) => RangeAny, 3898;
+            params!(MultiRangeAny, MultiRangeAny) => BF::from(func::MultiRangeUnion)
+                => MultiRangeAny, 4392;
         },
         "-" => Scalar {
             params!(Int16) => UnaryFunc::NegInt16(func::NegInt16) => Int16, 559;
@@ -5649,6 +6171,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Jsonb, Int64) => BF::from(func::JsonbDeleteInt64) => Jsonb, 3286;
             params!(Jsonb, String) => BF::from(func::JsonbDeleteString) => Jsonb, 3285;
             params!(RangeAny, RangeAny) => BF::from(func::RangeDifference) => RangeAny, 3899;
+            params!(MultiRangeAny, MultiRangeAny) => BF::from(func::MultiRangeDifference)
+                => MultiRangeAny, 4393;
             // TODO(jamii) there should be corresponding overloads for
             // Array(Int64) and Array(String)
         },
@@ -5667,6 +6191,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             } => Interval, 1584;
             params!(Numeric, Numeric) => BF::from(func::MulNumeric) => Numeric, 1760;
             params!(RangeAny, RangeAny) => BF::from(func::RangeIntersection) => RangeAny, 3900;
+            params!(MultiRangeAny, MultiRangeAny) => BF::from(func::MultiRangeIntersection)
+                => MultiRangeAny, 4394;
         },
         "/" => Scalar {
             params!(Int16, Int16) => BF::from(func::DivInt16) => Int16, 527;
@@ -5726,6 +6252,16 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(UInt64, UInt32) => BF::from(func::BitShiftLeftUint64)
                 => UInt64, oid::FUNC_SHIFT_LEFT_UINT64;
             params!(RangeAny, RangeAny) => BF::from(func::RangeBefore) => Bool, 3893;
+            params!(RangeAny, MultiRangeAny)
+                => multirange_positional_op(BF::from(func::RangeBefore)) => Bool, 4395;
+            params!(MultiRangeAny, RangeAny)
+                => multirange_positional_op(BF::from(func::RangeBefore)) => Bool, 4396;
+            params!(MultiRangeAny, MultiRangeAny)
+                => multirange_positional_op(BF::from(func::RangeBefore)) => Bool, 4397;
+            params!(Inet, Inet) => BF::from(func::InetContainedBy) => Bool, 931;
+        },
+        "<<=" => Scalar {
+            params!(Inet, Inet) => BF::from(func::InetContainedByOrEquals) => Bool, 932;
         },
         ">>" => Scalar {
             params!(Int16, Int32) => BF::from(func::BitShiftRightInt16) => Int16, 1879;
@@ -5738,6 +6274,16 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(UInt64, UInt32) => BF::from(func::BitShiftRightUint64)
                 => UInt64, oid::FUNC_SHIFT_RIGHT_UINT64;
             params!(RangeAny, RangeAny) => BF::from(func::RangeAfter) => Bool, 3894;
+            params!(RangeAny, MultiRangeAny)
+                => multirange_positional_op(BF::from(func::RangeAfter)) => Bool, 4398;
+            params!(MultiRangeAny, RangeAny)
+                => multirange_positional_op(BF::from(func::RangeAfter)) => Bool, 4399;
+            params!(MultiRangeAny, MultiRangeAny)
+                => multirange_positional_op(BF::from(func::RangeAfter)) => Bool, 4400;
+            params!(Inet, Inet) => BF::from(func::InetContains) => Bool, 933;
+        },
+        ">>=" => Scalar {
+            params!(Inet, Inet) => BF::from(func::InetContainsOrEquals) => Bool, 934;
         },
 
         // ILIKE
@@ -5951,6 +6497,14 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RangeAny, RangeAny) => Operation::binary(|_ecx, lhs, rhs| {
                 Ok(lhs.call_binary(rhs, BF::from(func::RangeContainsRange)))
             }) => Bool, 3890;
+            params!(MultiRangeAny, AnyElement) => BF::from(func::MultiRangeContainsElem)
+                => Bool, 2869;
+            params!(MultiRangeAny, RangeAny) => BF::from(func::MultiRangeContainsRange)
+                => Bool, 2870;
+            params!(MultiRangeAny, MultiRangeAny) => BF::from(func::MultiRangeContainsMultiRange)
+                => Bool, 2871;
+            params!(RangeAny, MultiRangeAny) => BF::from(func::RangeContainsMultiRange)
+                => Bool, 4539;
             params!(ArrayAny, ArrayAny) => Operation::binary(|_ecx, lhs, rhs| {
                 Ok(lhs.call_binary(rhs, BF::from(func::ArrayContainsArray)))
             }) => Bool, 2751;
@@ -5996,6 +6550,18 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RangeAny, RangeAny) => Operation::binary(|_ecx, lhs, rhs| {
                 Ok(rhs.call_binary(lhs, BF::from(func::RangeContainsRangeRev)))
             }) => Bool, 3892;
+            params!(AnyElement, MultiRangeAny) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, BF::from(func::MultiRangeContainsElemRev)))
+            }) => Bool, 2872;
+            params!(RangeAny, MultiRangeAny) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, BF::from(func::MultiRangeContainsRangeRev)))
+            }) => Bool, 2873;
+            params!(MultiRangeAny, MultiRangeAny) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, BF::from(func::MultiRangeContainsMultiRangeRev)))
+            }) => Bool, 2874;
+            params!(MultiRangeAny, RangeAny) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, BF::from(func::RangeContainsMultiRangeRev)))
+            }) => Bool, 4540;
             params!(ArrayAny, ArrayAny) => Operation::binary(|_ecx, lhs, rhs| {
                 Ok(lhs.call_binary(rhs, BF::from(func::ArrayContainsArrayRev)))
             }) => Bool, 2752;
@@ -6020,15 +6586,41 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
         },
         "&&" => Scalar {
             params!(RangeAny, RangeAny) => BF::from(func::RangeOverlaps) => Bool, 3888;
+            params!(RangeAny, MultiRangeAny) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, BF::from(func::MultiRangeOverlapsRange)))
+            }) => Bool, 2866;
+            params!(MultiRangeAny, RangeAny) => BF::from(func::MultiRangeOverlapsRange)
+                => Bool, 2867;
+            params!(MultiRangeAny, MultiRangeAny) => BF::from(func::MultiRangeOverlapsMultiRange)
+                => Bool, 2868;
+            params!(Inet, Inet) => BF::from(func::InetOverlaps) => Bool, 3552;
         },
         "&<" => Scalar {
             params!(RangeAny, RangeAny) => BF::from(func::RangeOverleft) => Bool, 3895;
+            params!(RangeAny, MultiRangeAny)
+                => multirange_positional_op(BF::from(func::RangeOverleft)) => Bool, 2875;
+            params!(MultiRangeAny, RangeAny)
+                => multirange_positional_op(BF::from(func::RangeOverleft)) => Bool, 2876;
+            params!(MultiRangeAny, MultiRangeAny)
+                => multirange_positional_op(BF::from(func::RangeOverleft)) => Bool, 2877;
         },
         "&>" => Scalar {
             params!(RangeAny, RangeAny) => BF::from(func::RangeOverright) => Bool, 3896;
+            params!(RangeAny, MultiRangeAny)
+                => multirange_positional_op(BF::from(func::RangeOverright)) => Bool, 3585;
+            params!(MultiRangeAny, RangeAny)
+                => multirange_positional_op(BF::from(func::RangeOverright)) => Bool, 4035;
+            params!(MultiRangeAny, MultiRangeAny)
+                => multirange_positional_op(BF::from(func::RangeOverright)) => Bool, 4142;
         },
         "-|-" => Scalar {
             params!(RangeAny, RangeAny) => BF::from(func::RangeAdjacent) => Bool, 3897;
+            params!(RangeAny, MultiRangeAny)
+                => multirange_positional_op(BF::from(func::RangeAdjacent)) => Bool, 4179;
+            params!(MultiRangeAny, RangeAny)
+                => multirange_positional_op(BF::from(func::RangeAdjacent)) => Bool, 4180;
+            params!(MultiRangeAny, MultiRangeAny)
+                => multirange_positional_op(BF::from(func::RangeAdjacent)) => Bool, 4198;
         },
 
         // COMPARISON OPS
@@ -6049,6 +6641,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Timestamp, Timestamp) => BF::from(func::Lt) => Bool, 2062;
             params!(TimestampTz, TimestampTz) => BF::from(func::Lt) => Bool, 1322;
             params!(Uuid, Uuid) => BF::from(func::Lt) => Bool, 2974;
+            params!(Inet, Inet) => BF::from(func::Lt) => Bool, 1203;
+            params!(MacAddr, MacAddr) => BF::from(func::Lt) => Bool, 1222;
             params!(Interval, Interval) => BF::from(func::Lt) => Bool, 1332;
             params!(Bytes, Bytes) => BF::from(func::Lt) => Bool, 1957;
             params!(String, String) => BF::from(func::Lt) => Bool, 664;
@@ -6058,9 +6652,11 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Jsonb, Jsonb) => BF::from(func::Lt) => Bool, 3242;
             params!(ArrayAny, ArrayAny) => BF::from(func::Lt) => Bool, 1072;
             params!(RecordAny, RecordAny) => BF::from(func::Lt) => Bool, 2990;
+            params!(EnumAny, EnumAny) => BF::from(func::Lt) => Bool, 3518;
             params!(MzTimestamp, MzTimestamp) => BF::from(func::Lt)
                 => Bool, oid::FUNC_MZ_TIMESTAMP_LT_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BF::from(func::Lt) => Bool, 3884;
+            params!(MultiRangeAny, MultiRangeAny) => BF::from(func::Lt) => Bool, 2862;
         },
         "<=" => Scalar {
             params!(Numeric, Numeric) => BF::from(func::Lte) => Bool, 1755;
@@ -6079,6 +6675,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Timestamp, Timestamp) => BF::from(func::Lte) => Bool, 2063;
             params!(TimestampTz, TimestampTz) => BF::from(func::Lte) => Bool, 1323;
             params!(Uuid, Uuid) => BF::from(func::Lte) => Bool, 2976;
+            params!(Inet, Inet) => BF::from(func::Lte) => Bool, 1204;
+            params!(MacAddr, MacAddr) => BF::from(func::Lte) => Bool, 1223;
             params!(Interval, Interval) => BF::from(func::Lte) => Bool, 1333;
             params!(Bytes, Bytes) => BF::from(func::Lte) => Bool, 1958;
             params!(String, String) => BF::from(func::Lte) => Bool, 665;
@@ -6088,9 +6686,11 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Jsonb, Jsonb) => BF::from(func::Lte) => Bool, 3244;
             params!(ArrayAny, ArrayAny) => BF::from(func::Lte) => Bool, 1074;
             params!(RecordAny, RecordAny) => BF::from(func::Lte) => Bool, 2992;
+            params!(EnumAny, EnumAny) => BF::from(func::Lte) => Bool, 3520;
             params!(MzTimestamp, MzTimestamp) => BF::from(func::Lte)
                 => Bool, oid::FUNC_MZ_TIMESTAMP_LTE_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BF::from(func::Lte) => Bool, 3885;
+            params!(MultiRangeAny, MultiRangeAny) => BF::from(func::Lte) => Bool, 2863;
         },
         ">" => Scalar {
             params!(Numeric, Numeric) => BF::from(func::Gt) => Bool, 1756;
@@ -6109,6 +6709,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Timestamp, Timestamp) => BF::from(func::Gt) => Bool, 2064;
             params!(TimestampTz, TimestampTz) => BF::from(func::Gt) => Bool, 1324;
             params!(Uuid, Uuid) => BF::from(func::Gt) => Bool, 2975;
+            params!(Inet, Inet) => BF::from(func::Gt) => Bool, 1205;
+            params!(MacAddr, MacAddr) => BF::from(func::Gt) => Bool, 1224;
             params!(Interval, Interval) => BF::from(func::Gt) => Bool, 1334;
             params!(Bytes, Bytes) => BF::from(func::Gt) => Bool, 1959;
             params!(String, String) => BF::from(func::Gt) => Bool, 666;
@@ -6118,9 +6720,11 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Jsonb, Jsonb) => BF::from(func::Gt) => Bool, 3243;
             params!(ArrayAny, ArrayAny) => BF::from(func::Gt) => Bool, 1073;
             params!(RecordAny, RecordAny) => BF::from(func::Gt) => Bool, 2991;
+            params!(EnumAny, EnumAny) => BF::from(func::Gt) => Bool, 3519;
             params!(MzTimestamp, MzTimestamp) => BF::from(func::Gt)
                 => Bool, oid::FUNC_MZ_TIMESTAMP_GT_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BF::from(func::Gt) => Bool, 3887;
+            params!(MultiRangeAny, MultiRangeAny) => BF::from(func::Gt) => Bool, 2865;
         },
         ">=" => Scalar {
             params!(Numeric, Numeric) => BF::from(func::Gte) => Bool, 1757;
@@ -6139,6 +6743,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Timestamp, Timestamp) => BF::from(func::Gte) => Bool, 2065;
             params!(TimestampTz, TimestampTz) => BF::from(func::Gte) => Bool, 1325;
             params!(Uuid, Uuid) => BF::from(func::Gte) => Bool, 2977;
+            params!(Inet, Inet) => BF::from(func::Gte) => Bool, 1206;
+            params!(MacAddr, MacAddr) => BF::from(func::Gte) => Bool, 1225;
             params!(Interval, Interval) => BF::from(func::Gte) => Bool, 1335;
             params!(Bytes, Bytes) => BF::from(func::Gte) => Bool, 1960;
             params!(String, String) => BF::from(func::Gte) => Bool, 667;
@@ -6148,9 +6754,11 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Jsonb, Jsonb) => BF::from(func::Gte) => Bool, 3245;
             params!(ArrayAny, ArrayAny) => BF::from(func::Gte) => Bool, 1075;
             params!(RecordAny, RecordAny) => BF::from(func::Gte) => Bool, 2993;
+            params!(EnumAny, EnumAny) => BF::from(func::Gte) => Bool, 3521;
             params!(MzTimestamp, MzTimestamp) => BF::from(func::Gte)
                 => Bool, oid::FUNC_MZ_TIMESTAMP_GTE_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BF::from(func::Gte) => Bool, 3886;
+            params!(MultiRangeAny, MultiRangeAny) => BF::from(func::Gte) => Bool, 2864;
         },
         // Warning!
         // - If you are writing functions here that do not simply use
@@ -6179,6 +6787,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Timestamp, Timestamp) => BF::from(func::Eq) => Bool, 2060;
             params!(TimestampTz, TimestampTz) => BF::from(func::Eq) => Bool, 1320;
             params!(Uuid, Uuid) => BF::from(func::Eq) => Bool, 2972;
+            params!(Inet, Inet) => BF::from(func::Eq) => Bool, 1201;
+            params!(MacAddr, MacAddr) => BF::from(func::Eq) => Bool, 1220;
             params!(Interval, Interval) => BF::from(func::Eq) => Bool, 1330;
             params!(Bytes, Bytes) => BF::from(func::Eq) => Bool, 1955;
             params!(String, String) => BF::from(func::Eq) => Bool, 98;
@@ -6189,9 +6799,11 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(ListAny, ListAny) => BF::from(func::Eq) => Bool, oid::FUNC_LIST_EQ_OID;
             params!(ArrayAny, ArrayAny) => BF::from(func::Eq) => Bool, 1070;
             params!(RecordAny, RecordAny) => BF::from(func::Eq) => Bool, 2988;
+            params!(EnumAny, EnumAny) => BF::from(func::Eq) => Bool, 3516;
             params!(MzTimestamp, MzTimestamp) => BF::from(func::Eq)
                 => Bool, oid::FUNC_MZ_TIMESTAMP_EQ_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BF::from(func::Eq) => Bool, 3882;
+            params!(MultiRangeAny, MultiRangeAny) => BF::from(func::Eq) => Bool, 2860;
             params!(MzAclItem, MzAclItem) => BF::from(func::Eq)
                 => Bool, oid::FUNC_MZ_ACL_ITEM_EQ_MZ_ACL_ITEM_OID;
             params!(AclItem, AclItem) => BF::from(func::Eq) => Bool, 974;
@@ -6213,6 +6825,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Timestamp, Timestamp) => BF::from(func::NotEq) => Bool, 2061;
             params!(TimestampTz, TimestampTz) => BF::from(func::NotEq) => Bool, 1321;
             params!(Uuid, Uuid) => BF::from(func::NotEq) => Bool, 2973;
+            params!(Inet, Inet) => BF::from(func::NotEq) => Bool, 1202;
+            params!(MacAddr, MacAddr) => BF::from(func::NotEq) => Bool, 1221;
             params!(Interval, Interval) => BF::from(func::NotEq) => Bool, 1331;
             params!(Bytes, Bytes) => BF::from(func::NotEq) => Bool, 1956;
             params!(String, String) => BF::from(func::NotEq) => Bool, 531;
@@ -6222,9 +6836,11 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Jsonb, Jsonb) => BF::from(func::NotEq) => Bool, 3241;
             params!(ArrayAny, ArrayAny) => BF::from(func::NotEq) => Bool, 1071;
             params!(RecordAny, RecordAny) => BF::from(func::NotEq) => Bool, 2989;
+            params!(EnumAny, EnumAny) => BF::from(func::NotEq) => Bool, 3517;
             params!(MzTimestamp, MzTimestamp) => BF::from(func::NotEq)
                 => Bool, oid::FUNC_MZ_TIMESTAMP_NOT_EQ_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BF::from(func::NotEq) => Bool, 3883;
+            params!(MultiRangeAny, MultiRangeAny) => BF::from(func::NotEq) => Bool, 2861;
             params!(MzAclItem, MzAclItem) => BF::from(func::NotEq)
                 => Bool, oid::FUNC_MZ_ACL_ITEM_NOT_EQ_MZ_ACL_ITEM_OID;
         }
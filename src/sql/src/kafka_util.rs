@@ -9,10 +9,11 @@
 
 //! Provides parsing and convenience functions for working with Kafka from the `sql` package.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
 
 use mz_kafka_util::client::DEFAULT_TOPIC_METADATA_REFRESH_INTERVAL;
+use mz_ore::str::StrExt;
 use mz_ore::task;
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
@@ -20,6 +21,7 @@ use mz_sql_parser::ast::{
     KafkaSourceConfigOptionName,
 };
 use mz_storage_types::sinks::KafkaSinkCompressionType;
+use mz_storage_types::sources::kafka::{KafkaTopics, compile_topic_pattern};
 use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
 use rdkafka::{Offset, TopicPartitionList};
 use tokio::time::Duration;
@@ -35,6 +37,8 @@ generate_extracted_config!(
     KafkaSourceConfigOption,
     (GroupIdPrefix, String),
     (Topic, String),
+    (Topics, Vec<String>),
+    (TopicPattern, String),
     (
         TopicMetadataRefreshInterval,
         Duration,
@@ -44,6 +48,53 @@ generate_extracted_config!(
     (StartOffset, Vec<i64>)
 );
 
+impl KafkaSourceConfigOptionExtracted {
+    /// Returns the topics selected by the `TOPIC`, `TOPICS`, or `TOPIC PATTERN`
+    /// option, or `None` if none of them was specified.
+    pub fn topics(&self) -> Result<Option<KafkaTopics>, PlanError> {
+        let topics = match (&self.topic, &self.topics, &self.topic_pattern) {
+            (None, None, None) => return Ok(None),
+            (Some(topic), None, None) => KafkaTopics::Topic(topic.clone()),
+            (None, Some(topics), None) => {
+                if topics.is_empty() {
+                    sql_bail!("TOPICS must specify at least one topic");
+                }
+                let mut seen = BTreeSet::new();
+                for topic in topics {
+                    if !seen.insert(topic) {
+                        sql_bail!("TOPICS specifies topic {} more than once", topic.quoted());
+                    }
+                }
+                KafkaTopics::List(topics.clone())
+            }
+            (None, None, Some(pattern)) => {
+                if let Err(e) = compile_topic_pattern(pattern) {
+                    sql_bail!("invalid TOPIC PATTERN: {}", e);
+                }
+                KafkaTopics::Pattern(pattern.clone())
+            }
+            _ => sql_bail!("only one of TOPIC, TOPICS, or TOPIC PATTERN may be specified"),
+        };
+        if let KafkaTopics::List(names) = &topics {
+            if let Some((a, b)) = topics.find_topic_id_collision(names.iter().map(|n| n.as_str())) {
+                sql_bail!(
+                    "topics {} and {} cannot be read by the same source",
+                    a.quoted(),
+                    b.quoted()
+                );
+            }
+        }
+        if !matches!(topics, KafkaTopics::Topic(_))
+            && (self.start_offset.is_some() || self.start_timestamp.is_some())
+        {
+            sql_bail!(
+                "START OFFSET and START TIMESTAMP cannot be used with TOPICS or TOPIC PATTERN"
+            );
+        }
+        Ok(Some(topics))
+    }
+}
+
 generate_extracted_config!(
     KafkaSinkConfigOption,
     (
@@ -267,3 +318,23 @@ where
     })
     .await
 }
+
+/// Validates that we can connect to the broker and list its topics.
+pub async fn ensure_topics_listable<C>(
+    consumer: Arc<BaseConsumer<C>>,
+    fetch_metadata_timeout: Duration,
+) -> Result<(), PlanError>
+where
+    C: ConsumerContext + 'static,
+{
+    task::spawn_blocking(
+        || "kafka_ensure_topics_listable".to_string(),
+        move || {
+            consumer
+                .fetch_metadata(None, fetch_metadata_timeout)
+                .map_err(|e| sql_err!("{}", e))?;
+            Ok(())
+        },
+    )
+    .await
+}
@@ -37,7 +37,8 @@ use crate::ast::visit_mut::VisitMut;
 use crate::ast::{
     self, AstInfo, Cte, CteBlock, CteMutRec, DocOnIdentifier, GrantTargetSpecification,
     GrantTargetSpecificationInner, Ident, MutRecBlock, ObjectType, Query, Raw, RawClusterName,
-    RawDataType, RawItemName, Statement, UnresolvedItemName, UnresolvedObjectName,
+    RawDataType, RawItemName, ShowCreateFunctionStatement, Statement, UnresolvedItemName,
+    UnresolvedObjectName,
 };
 use crate::catalog::{
     CatalogError, CatalogItem, CatalogItemType, CatalogType, CatalogTypeDetails, SessionCatalog,
@@ -1518,10 +1519,13 @@ impl<'a> NameResolver<'a> {
                     .entry(item.id())
                     .or_default()
                     .insert(item.global_id());
-                let print_id = !matches!(
-                    item.item_type(),
-                    CatalogItemType::Func | CatalogItemType::Type
-                );
+                // User-defined functions are pinned by ID, like relations, so
+                // that dependents survive renames and sort after them.
+                let print_id = match item.item_type() {
+                    CatalogItemType::Func => item.id().is_user(),
+                    CatalogItemType::Type => false,
+                    _ => true,
+                };
                 let alter_table_enabled =
                     self.catalog.system_vars().enable_alter_table_add_column();
                 let version = match item.latest_version() {
@@ -2257,10 +2261,10 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
     ) -> GrantTargetSpecification<Aug> {
         match node {
             GrantTargetSpecification::Object {
-                object_type: ObjectType::Type,
+                object_type: object_type @ (ObjectType::Type | ObjectType::Func),
                 object_spec_inner: GrantTargetSpecificationInner::Objects { names },
             } => GrantTargetSpecification::Object {
-                object_type: ObjectType::Type,
+                object_type,
                 object_spec_inner: GrantTargetSpecificationInner::Objects {
                     names: names
                         .into_iter()
@@ -2268,11 +2272,12 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                             UnresolvedObjectName::Item(name) => {
                                 ResolvedObjectName::Item(self.resolve_item_name_name(
                                     name,
-                                    // `{GRANT|REVOKE} ... ON TYPE ...` can only
-                                    // refer to type names.
+                                    // `{GRANT|REVOKE} ... ON {TYPE|FUNCTION} ...`
+                                    // can only refer to type or function names,
+                                    // respectively.
                                     ItemResolutionConfig {
-                                        functions: false,
-                                        types: true,
+                                        functions: object_type == ObjectType::Func,
+                                        types: object_type == ObjectType::Type,
                                         relations: false,
                                     },
                                 ))
@@ -2286,6 +2291,24 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
         }
     }
 
+    fn fold_show_create_function_statement(
+        &mut self,
+        node: ShowCreateFunctionStatement<Raw>,
+    ) -> ShowCreateFunctionStatement<Aug> {
+        ShowCreateFunctionStatement {
+            function_name: self.resolve_item_name(
+                node.function_name,
+                // `SHOW CREATE FUNCTION` can only refer to function names.
+                ItemResolutionConfig {
+                    functions: true,
+                    types: false,
+                    relations: false,
+                },
+            ),
+            redacted: node.redacted,
+        }
+    }
+
     fn fold_doc_on_identifier(&mut self, node: DocOnIdentifier<Raw>) -> DocOnIdentifier<Aug> {
         match node {
             DocOnIdentifier::Column(name) => DocOnIdentifier::Column(self.fold_column_name(name)),
@@ -21,12 +21,13 @@ use mz_repr::{ColumnName, GlobalId};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
-    CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
-    CreateTableFromSourceStatement, CreateTableStatement, CreateTypeStatement, CreateViewStatement,
-    CreateWebhookSourceStatement, CteBlock, Function, FunctionArgs, Ident, IfExistsBehavior,
-    MutRecBlock, Op, Query, Statement, TableFactor, TableFromSourceColumns, UnresolvedItemName,
-    UnresolvedSchemaName, Value, ViewDefinition,
+    CreateConnectionStatement, CreateFunctionStatement, CreateIndexStatement,
+    CreateMaterializedViewStatement, CreateSecretStatement, CreateSinkStatement,
+    CreateSourceStatement, CreateSubsourceStatement, CreateTableFromSourceStatement,
+    CreateTableStatement, CreateTypeStatement, CreateViewStatement, CreateWebhookSourceStatement,
+    CteBlock, Function, FunctionArgs, Ident, IfExistsBehavior, MutRecBlock, Op, Query, Statement,
+    TableFactor, TableFromSourceColumns, UnresolvedItemName, UnresolvedSchemaName, Value,
+    ViewDefinition,
 };
 
 use crate::names::{Aug, FullItemName, PartialItemName, PartialSchemaName, RawDatabaseSpecifier};
@@ -220,7 +221,8 @@ pub fn create_statement(
         fn visit_function_mut(&mut self, func: &'ast mut Function<Aug>) {
             match &mut func.args {
                 FunctionArgs::Star => (),
-                FunctionArgs::Args { args, order_by } => {
+                FunctionArgs::Args { args, order_by }
+                | FunctionArgs::WithinGroup { args, order_by } => {
                     for arg in args {
                         self.visit_expr_mut(arg);
                     }
@@ -450,6 +452,23 @@ pub fn create_statement(
                 return Err(err);
             }
         }
+        Statement::CreateFunction(CreateFunctionStatement {
+            name,
+            parameters,
+            returns,
+            body,
+        }) => {
+            *name = allocate_name(name)?;
+            let mut normalizer = QueryNormalizer::new();
+            for parameter in parameters {
+                normalizer.visit_function_parameter_mut(parameter);
+            }
+            normalizer.visit_function_return_type_mut(returns);
+            normalizer.visit_query_mut(body);
+            if let Some(err) = normalizer.err {
+                return Err(err);
+            }
+        }
         Statement::CreateSecret(CreateSecretStatement {
             name,
             if_not_exists,
@@ -29,6 +29,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::num::NonZeroUsize;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
@@ -80,6 +81,7 @@ use crate::catalog::{
     CatalogType, DefaultPrivilegeAclItem, DefaultPrivilegeObject, IdReference, ObjectType,
     RoleAttributesRaw,
 };
+use crate::func;
 use crate::names::{
     Aug, CommentObjectId, DependencyIds, FullItemName, ObjectId, QualifiedItemName,
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaSpecifier, SystemObjectId,
@@ -148,6 +150,7 @@ pub enum Plan {
     CreateNetworkPolicy(CreateNetworkPolicyPlan),
     CreateIndex(CreateIndexPlan),
     CreateType(CreateTypePlan),
+    CreateFunction(CreateFunctionPlan),
     Comment(CommentPlan),
     DiscardTemp,
     DiscardAll,
@@ -186,6 +189,7 @@ pub enum Plan {
     AlterSchemaRename(AlterSchemaRenamePlan),
     AlterSchemaSwap(AlterSchemaSwapPlan),
     AlterSecret(AlterSecretPlan),
+    AlterTypeAddValue(AlterTypeAddValuePlan),
     AlterSink(AlterSinkPlan),
     AlterSystemSet(AlterSystemSetPlan),
     AlterSystemReset(AlterSystemResetPlan),
@@ -239,6 +243,7 @@ impl Plan {
             StatementKind::AlterRole => &[PlanKind::AlterRole],
             StatementKind::AlterNetworkPolicy => &[PlanKind::AlterNetworkPolicy],
             StatementKind::AlterSecret => &[PlanKind::AlterNoop, PlanKind::AlterSecret],
+            StatementKind::AlterTypeAddValue => &[PlanKind::AlterNoop, PlanKind::AlterTypeAddValue],
             StatementKind::AlterSetCluster => &[PlanKind::AlterNoop, PlanKind::AlterSetCluster],
             StatementKind::AlterSink => &[PlanKind::AlterNoop, PlanKind::AlterSink],
             StatementKind::AlterSource => &[
@@ -287,10 +292,12 @@ impl Plan {
             StatementKind::CreateTable => &[PlanKind::CreateTable],
             StatementKind::CreateTableFromSource => &[PlanKind::CreateTable],
             StatementKind::CreateType => &[PlanKind::CreateType],
+            StatementKind::CreateFunction => &[PlanKind::CreateFunction],
             StatementKind::CreateView => &[PlanKind::CreateView],
             StatementKind::Deallocate => &[PlanKind::Deallocate],
             StatementKind::Declare => &[PlanKind::Declare],
             StatementKind::Delete => &[PlanKind::ReadThenWrite],
+            StatementKind::Merge => &[PlanKind::ReadThenWrite],
             StatementKind::Discard => &[PlanKind::DiscardAll, PlanKind::DiscardTemp],
             StatementKind::DropObjects => &[PlanKind::DropObjects],
             StatementKind::DropOwned => &[PlanKind::DropOwned],
@@ -304,7 +311,7 @@ impl Plan {
             StatementKind::Fetch => &[PlanKind::Fetch],
             StatementKind::GrantPrivileges => &[PlanKind::GrantPrivileges],
             StatementKind::GrantRole => &[PlanKind::GrantRole],
-            StatementKind::Insert => &[PlanKind::Insert],
+            StatementKind::Insert => &[PlanKind::Insert, PlanKind::ReadThenWrite],
             StatementKind::Prepare => &[PlanKind::Prepare],
             StatementKind::Raise => &[PlanKind::Raise],
             StatementKind::ReassignOwned => &[PlanKind::ReassignOwned],
@@ -350,6 +357,7 @@ impl Plan {
             Plan::CreateMaterializedView(_) => "create materialized view",
             Plan::CreateIndex(_) => "create index",
             Plan::CreateType(_) => "create type",
+            Plan::CreateFunction(_) => "create function",
             Plan::CreateNetworkPolicy(_) => "create network policy",
             Plan::Comment(_) => "comment",
             Plan::DiscardTemp => "discard temp",
@@ -423,6 +431,7 @@ impl Plan {
             Plan::AlterSchemaRename(_) => "alter rename schema",
             Plan::AlterSchemaSwap(_) => "alter swap schema",
             Plan::AlterSecret(_) => "alter secret",
+            Plan::AlterTypeAddValue(_) => "alter type",
             Plan::AlterSink(_) => "alter sink",
             Plan::AlterSystemSet(_) => "alter system",
             Plan::AlterSystemReset(_) => "alter system",
@@ -458,6 +467,8 @@ impl Plan {
                 MutationKind::Insert => "insert into select",
                 MutationKind::Update => "update",
                 MutationKind::Delete => "delete",
+                MutationKind::Upsert { .. } => "insert on conflict",
+                MutationKind::Merge { .. } => "merge",
             },
             Plan::Prepare(_) => "prepare",
             Plan::Execute(_) => "execute",
@@ -816,6 +827,12 @@ pub struct CreateTypePlan {
     pub typ: Type,
 }
 
+#[derive(Debug)]
+pub struct CreateFunctionPlan {
+    pub name: QualifiedItemName,
+    pub function: Function,
+}
+
 #[derive(Debug)]
 pub struct DropObjectsPlan {
     /// The IDs of only the objects directly referenced in the `DROP` statement.
@@ -1022,6 +1039,15 @@ pub enum CopyFromSource {
         /// ID of the connection object.
         connection_id: CatalogItemId,
     },
+    /// Files in a Google Cloud Storage bucket.
+    Gcs {
+        /// Expression that evaluates to the files we want to copy.
+        uri: HirScalarExpr,
+        /// Details for how we connect to GCS.
+        connection: GcpConnection,
+        /// ID of the connection object.
+        connection_id: CatalogItemId,
+    },
 }
 
 #[derive(Debug)]
@@ -1195,6 +1221,9 @@ pub struct SendDiffsPlan {
     pub updates: Vec<(Row, Diff)>,
     pub kind: MutationKind,
     pub returning: Vec<(Row, NonZeroUsize)>,
+    /// The number of rows affected by the mutation, if it cannot be derived
+    /// from `updates`.
+    pub affected_rows: Option<usize>,
     pub max_result_size: u64,
 }
 
@@ -1375,6 +1404,13 @@ pub struct AlterSecretPlan {
     pub secret_as: MirScalarExpr,
 }
 
+#[derive(Debug)]
+pub struct AlterTypeAddValuePlan {
+    pub id: CatalogItemId,
+    /// The enum type with the new label appended.
+    pub typ: Type,
+}
+
 #[derive(Debug)]
 pub struct AlterSystemSetPlan {
     pub name: String,
@@ -1985,6 +2021,12 @@ pub struct Type {
     pub inner: CatalogType<IdReference>,
 }
 
+#[derive(Clone, Debug)]
+pub struct Function {
+    pub create_sql: String,
+    pub inner: Arc<func::Func>,
+}
+
 /// Specifies when a `Peek` or `Subscribe` should occur.
 #[derive(Deserialize, Clone, Debug, PartialEq)]
 pub enum QueryWhen {
@@ -2072,6 +2114,27 @@ pub enum MutationKind {
     Insert,
     Update,
     Delete,
+    /// An `INSERT ... ON CONFLICT`. Each row of the selection is followed by a
+    /// boolean column that indicates whether the row is retracted rather than
+    /// inserted.
+    Upsert {
+        /// Whether conflicting rows are updated (`DO UPDATE`) rather than left
+        /// alone (`DO NOTHING`).
+        update: bool,
+    },
+    /// A `MERGE`. Each row of the selection is followed by a nullable boolean
+    /// column: false for rows that are inserted, true for rows that are
+    /// deleted, and null for rows that are retracted because they are being
+    /// updated. Only rows with a non-null marker count as affected by the
+    /// statement.
+    Merge {
+        /// Whether any clause inserts rows.
+        insert: bool,
+        /// Whether any clause updates rows.
+        update: bool,
+        /// Whether any clause deletes rows.
+        delete: bool,
+    },
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -2080,6 +2143,8 @@ pub enum CopyFormat {
     Csv,
     Binary,
     Parquet,
+    Json,
+    Avro,
 }
 
 #[derive(Debug, Copy, Clone)]
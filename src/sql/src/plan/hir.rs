@@ -574,6 +574,8 @@ impl ScalarWindowExpr {
             ScalarWindowFunc::RowNumber => {}
             ScalarWindowFunc::Rank => {}
             ScalarWindowFunc::DenseRank => {}
+            ScalarWindowFunc::PercentRank => {}
+            ScalarWindowFunc::CumeDist => {}
         }
         Ok(())
     }
@@ -587,6 +589,8 @@ impl ScalarWindowExpr {
             ScalarWindowFunc::RowNumber => {}
             ScalarWindowFunc::Rank => {}
             ScalarWindowFunc::DenseRank => {}
+            ScalarWindowFunc::PercentRank => {}
+            ScalarWindowFunc::CumeDist => {}
         }
         Ok(())
     }
@@ -611,6 +615,12 @@ impl ScalarWindowExpr {
             ScalarWindowFunc::DenseRank => mz_expr::AggregateFunc::DenseRank {
                 order_by: self.order_by,
             },
+            ScalarWindowFunc::PercentRank => mz_expr::AggregateFunc::PercentRank {
+                order_by: self.order_by,
+            },
+            ScalarWindowFunc::CumeDist => mz_expr::AggregateFunc::CumeDist {
+                order_by: self.order_by,
+            },
         }
     }
 }
@@ -631,6 +641,8 @@ pub enum ScalarWindowFunc {
     RowNumber,
     Rank,
     DenseRank,
+    PercentRank,
+    CumeDist,
 }
 
 impl Display for ScalarWindowFunc {
@@ -639,6 +651,8 @@ impl Display for ScalarWindowFunc {
             ScalarWindowFunc::RowNumber => write!(f, "row_number"),
             ScalarWindowFunc::Rank => write!(f, "rank"),
             ScalarWindowFunc::DenseRank => write!(f, "dense_rank"),
+            ScalarWindowFunc::PercentRank => write!(f, "percent_rank"),
+            ScalarWindowFunc::CumeDist => write!(f, "cume_dist"),
         }
     }
 }
@@ -649,6 +663,8 @@ impl ScalarWindowFunc {
             ScalarWindowFunc::RowNumber => SqlScalarType::Int64.nullable(false),
             ScalarWindowFunc::Rank => SqlScalarType::Int64.nullable(false),
             ScalarWindowFunc::DenseRank => SqlScalarType::Int64.nullable(false),
+            ScalarWindowFunc::PercentRank => SqlScalarType::Float64.nullable(false),
+            ScalarWindowFunc::CumeDist => SqlScalarType::Float64.nullable(false),
         }
     }
 }
@@ -685,6 +701,8 @@ impl Display for ValueWindowFunc {
             ValueWindowFunc::Lead => write!(f, "lead"),
             ValueWindowFunc::FirstValue => write!(f, "first_value"),
             ValueWindowFunc::LastValue => write!(f, "last_value"),
+            ValueWindowFunc::Ntile => write!(f, "ntile"),
+            ValueWindowFunc::NthValue => write!(f, "nth_value"),
             ValueWindowFunc::Fused(funcs) => write!(f, "fused[{}]", separated(", ", funcs)),
         }
     }
@@ -769,14 +787,17 @@ pub enum ValueWindowFunc {
     Lead,
     FirstValue,
     LastValue,
+    Ntile,
+    NthValue,
     Fused(Vec<ValueWindowFunc>),
 }
 
 impl ValueWindowFunc {
     pub fn output_sql_type(&self, input_type: SqlColumnType) -> SqlColumnType {
         match self {
-            ValueWindowFunc::Lag | ValueWindowFunc::Lead => {
-                // The input is a (value, offset, default) record, so extract the type of the first arg
+            ValueWindowFunc::Lag | ValueWindowFunc::Lead | ValueWindowFunc::NthValue => {
+                // The input is a (value, offset, default) or (value, n) record, so extract the type
+                // of the first arg
                 input_type.scalar_type.unwrap_record_element_type()[0]
                     .clone()
                     .nullable(true)
@@ -784,6 +805,7 @@ impl ValueWindowFunc {
             ValueWindowFunc::FirstValue | ValueWindowFunc::LastValue => {
                 input_type.scalar_type.nullable(true)
             }
+            ValueWindowFunc::Ntile => SqlScalarType::Int32.nullable(true),
             ValueWindowFunc::Fused(funcs) => {
                 let input_types = input_type.scalar_type.unwrap_record_element_column_type();
                 SqlScalarType::Record {
@@ -825,6 +847,12 @@ impl ValueWindowFunc {
                 order_by,
                 window_frame,
             },
+            // The window frame doesn't affect ntile
+            ValueWindowFunc::Ntile => mz_expr::AggregateFunc::Ntile { order_by },
+            ValueWindowFunc::NthValue => mz_expr::AggregateFunc::NthValue {
+                order_by,
+                window_frame,
+            },
             ValueWindowFunc::Fused(funcs) => mz_expr::AggregateFunc::FusedValueWindowFunc {
                 funcs: funcs
                     .into_iter()
@@ -1380,6 +1408,30 @@ pub enum AggregateFunc {
     FusedWindowAgg {
         funcs: Vec<AggregateFunc>,
     },
+    /// The ordered-set aggregate `percentile_cont(..) WITHIN GROUP (ORDER BY ..)`.
+    PercentileCont {
+        percentiles: mz_expr::Percentiles,
+        desc: bool,
+    },
+    /// The ordered-set aggregate `percentile_disc(..) WITHIN GROUP (ORDER BY ..)`.
+    PercentileDisc {
+        percentiles: mz_expr::Percentiles,
+        desc: bool,
+    },
+    /// The ordered-set aggregate `mode() WITHIN GROUP (ORDER BY ..)`.
+    Mode {
+        desc: bool,
+    },
+    /// Approximates the number of distinct non-null inputs.
+    ApproxCountDistinct,
+    /// Summarizes the non-null inputs as a HyperLogLog sketch.
+    HllSketchAgg,
+    /// Unions HyperLogLog sketches.
+    HllUnionAgg,
+    /// Unions ranges or multiranges into a multirange.
+    RangeAgg,
+    /// Intersects ranges or multiranges.
+    RangeIntersectAgg,
     /// Accumulates any number of `Datum::Dummy`s into `Datum::Dummy`.
     ///
     /// Useful for removing an expensive aggregation while maintaining the shape
@@ -1460,6 +1512,18 @@ impl AggregateFunc {
             AggregateFunc::FusedWindowAgg { funcs: _ } => {
                 panic!("into_expr called on FusedWindowAgg")
             }
+            AggregateFunc::PercentileCont { percentiles, desc } => {
+                mz_expr::AggregateFunc::PercentileCont { percentiles, desc }
+            }
+            AggregateFunc::PercentileDisc { percentiles, desc } => {
+                mz_expr::AggregateFunc::PercentileDisc { percentiles, desc }
+            }
+            AggregateFunc::Mode { desc } => mz_expr::AggregateFunc::Mode { desc },
+            AggregateFunc::ApproxCountDistinct => mz_expr::AggregateFunc::ApproxCountDistinct,
+            AggregateFunc::HllSketchAgg => mz_expr::AggregateFunc::HllSketchAgg,
+            AggregateFunc::HllUnionAgg => mz_expr::AggregateFunc::HllUnionAgg,
+            AggregateFunc::RangeAgg => mz_expr::AggregateFunc::RangeAgg,
+            AggregateFunc::RangeIntersectAgg => mz_expr::AggregateFunc::RangeIntersectAgg,
             AggregateFunc::Dummy => mz_expr::AggregateFunc::Dummy,
         }
     }
@@ -1524,7 +1588,15 @@ impl AggregateFunc {
             | AggregateFunc::JsonbAgg { .. }
             | AggregateFunc::JsonbObjectAgg { .. }
             | AggregateFunc::MapAgg { .. }
-            | AggregateFunc::StringAgg { .. } => Datum::Null,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::ApproxCountDistinct
+            | AggregateFunc::HllSketchAgg
+            | AggregateFunc::HllUnionAgg
+            | AggregateFunc::RangeAgg
+            | AggregateFunc::RangeIntersectAgg => Datum::Null,
             AggregateFunc::FusedWindowAgg { funcs: _ } => {
                 // `identity_datum` is used only in HIR planning, and `FusedWindowAgg` can't occur
                 // in HIR planning, because it is introduced only during HIR transformation.
@@ -1547,12 +1619,26 @@ impl AggregateFunc {
     /// counts.
     pub fn output_sql_type(&self, input_type: SqlColumnType) -> SqlColumnType {
         let scalar_type = match self {
-            AggregateFunc::Count => SqlScalarType::Int64,
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct => SqlScalarType::Int64,
+            AggregateFunc::HllSketchAgg | AggregateFunc::HllUnionAgg => SqlScalarType::Bytes,
+            AggregateFunc::RangeAgg => match input_type.scalar_type {
+                SqlScalarType::Range { element_type }
+                | SqlScalarType::MultiRange { element_type } => {
+                    SqlScalarType::MultiRange { element_type }
+                }
+                _ => unreachable!("range_agg takes ranges or multiranges"),
+            },
             AggregateFunc::Any => SqlScalarType::Bool,
             AggregateFunc::All => SqlScalarType::Bool,
             AggregateFunc::JsonbAgg { .. } => SqlScalarType::Jsonb,
             AggregateFunc::JsonbObjectAgg { .. } => SqlScalarType::Jsonb,
             AggregateFunc::StringAgg { .. } => SqlScalarType::String,
+            AggregateFunc::PercentileCont { percentiles, .. }
+            | AggregateFunc::PercentileDisc { percentiles, .. }
+                if percentiles.array =>
+            {
+                SqlScalarType::Array(Box::new(input_type.scalar_type))
+            }
             AggregateFunc::SumInt16 | AggregateFunc::SumInt32 => SqlScalarType::Int64,
             AggregateFunc::SumInt64 => SqlScalarType::Numeric {
                 max_scale: Some(NumericMaxScale::ZERO),
@@ -1609,6 +1695,10 @@ impl AggregateFunc {
             | AggregateFunc::SumFloat32
             | AggregateFunc::SumFloat64
             | AggregateFunc::SumNumeric
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::RangeIntersectAgg
             | AggregateFunc::Dummy => input_type.scalar_type,
             AggregateFunc::FusedWindowAgg { funcs } => {
                 let input_types = input_type.scalar_type.unwrap_record_element_column_type();
@@ -1623,7 +1713,10 @@ impl AggregateFunc {
             }
         };
         // max/min/sum return null on empty sets
-        let nullable = !matches!(self, AggregateFunc::Count);
+        let nullable = !matches!(
+            self,
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct
+        );
         scalar_type.nullable(nullable)
     }
 
@@ -1639,6 +1732,16 @@ impl AggregateFunc {
                 | StringAgg { .. }
         )
     }
+
+    /// Whether this is an ordered-set aggregate, which must be called with a
+    /// `WITHIN GROUP (ORDER BY ..)` clause.
+    pub fn is_ordered_set(&self) -> bool {
+        use AggregateFunc::*;
+        matches!(
+            self,
+            PercentileCont { .. } | PercentileDisc { .. } | Mode { .. }
+        )
+    }
 }
 
 impl HirRelationExpr {
@@ -4232,6 +4335,20 @@ impl HirScalarExpr {
         })
     }
 
+    /// Simplifies this constant expression to a literal, returned as a single-datum [`Row`].
+    ///
+    /// Returns `PlanError::ConstantExpressionSimplificationFailed` if it's not a constant
+    /// expression (as determined by `is_constant`) or an EvalError occurs during evaluation.
+    pub fn try_into_literal_row(self) -> Result<Row, PlanError> {
+        if !self.is_constant() {
+            return Err(PlanError::ConstantExpressionSimplificationFailed(format!(
+                "Expected a constant expression, got {}",
+                self
+            )));
+        }
+        self.simplify_to_literal_with_result()
+    }
+
     pub fn contains_parameters(&self) -> bool {
         let mut contains_parameters = false;
         #[allow(deprecated)]
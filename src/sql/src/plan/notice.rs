@@ -37,6 +37,9 @@ pub enum PlanNotice {
         column_name: String,
         object_name: String,
     },
+    EnumLabelAlreadyExists {
+        label: String,
+    },
     UpsertSinkKeyNotEnforced {
         key: Vec<ColumnName>,
         name: String,
@@ -96,6 +99,9 @@ impl fmt::Display for PlanNotice {
                     object_name.quoted()
                 )
             }
+            PlanNotice::EnumLabelAlreadyExists { label } => {
+                write!(f, "enum label {} already exists, skipping", label.quoted())
+            }
             PlanNotice::UpsertSinkKeyNotEnforced { .. } => {
                 write!(f, "upsert key not validated to be unique")
             }
@@ -47,8 +47,8 @@ use std::{iter, mem};
 
 use itertools::Itertools;
 use mz_expr::func::variadic::{
-    ArrayCreate, ArrayIndex, Coalesce, Greatest, Least, ListCreate, ListIndex, ListSliceLinear,
-    MapBuild, RecordCreate,
+    ArrayCreate, ArrayIndex, Coalesce, ErrorIfNull, Greatest, Least, ListCreate, ListIndex,
+    ListSliceLinear, MapBuild, RecordCreate,
 };
 use mz_expr::virtual_syntax::AlgExcept;
 use mz_expr::{
@@ -77,19 +77,20 @@ use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     AsOf, Assignment, AstInfo, CreateWebhookSourceBody, CreateWebhookSourceCheck,
     CreateWebhookSourceHeader, CreateWebhookSourceSecret, CteBlock, DeleteStatement, Distinct,
-    Expr, Function, FunctionArgs, HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join,
-    JoinConstraint, JoinOperator, Limit, MapEntry, MutRecBlock, MutRecBlockOption,
-    MutRecBlockOptionName, OrderByExpr, Query, Select, SelectItem, SelectOption, SelectOptionName,
-    SetExpr, SetOperator, ShowStatement, SubscriptPosition, TableAlias, TableFactor,
-    TableWithJoins, UnresolvedItemName, UpdateStatement, Value, Values, WindowFrame,
+    Expr, Function, FunctionArgs, GroupByItem, HomogenizingFunction, Ident, InsertSource,
+    IsExprConstruct, Join, JoinConstraint, JoinOperator, Limit, MapEntry, MergeAction, MergeClause,
+    MergeInsertSource, MergeStatement, MutRecBlock, MutRecBlockOption, MutRecBlockOptionName,
+    OnConflict, OnConflictAction, OrderByExpr, Query, Raw, Select, SelectItem, SelectOption,
+    SelectOptionName, SetExpr, SetOperator, ShowStatement, SubscriptPosition, TableAlias,
+    TableFactor, TableWithJoins, UnresolvedItemName, UpdateStatement, Value, Values, WindowFrame,
     WindowFrameBound, WindowFrameUnits, WindowSpec, visit,
 };
 use mz_sql_parser::ident;
 
 use crate::catalog::{CatalogItemType, CatalogType, SessionCatalog};
-use crate::func::{self, Func, FuncSpec, TableFuncImpl};
+use crate::func::{self, Func, FuncRef, FuncSpec, TableFuncImpl};
 use crate::names::{
-    Aug, FullItemName, PartialItemName, ResolvedDataType, ResolvedItemName, SchemaSpecifier,
+    self, Aug, FullItemName, PartialItemName, ResolvedDataType, ResolvedItemName, SchemaSpecifier,
 };
 use crate::plan::PlanError::InvalidWmrRecursionLimit;
 use crate::plan::error::PlanError;
@@ -390,46 +391,565 @@ pub fn plan_insert_query(
         } else {
             (Scope::empty(), SqlRelationType::empty())
         };
+        plan_returning(&qcx, scope, typ, returning)?
+    };
+
+    Ok((
+        table.id(),
+        expr.map(map_exprs).project(project_key),
+        returning,
+    ))
+}
+
+/// Plans the `RETURNING` clause of a mutation whose written rows have the
+/// given `scope` and type.
+fn plan_returning(
+    qcx: &QueryContext,
+    scope: Scope,
+    typ: SqlRelationType,
+    returning: Vec<SelectItem<Aug>>,
+) -> Result<PlannedRootQuery<Vec<HirScalarExpr>>, PlanError> {
+    let ecx = &ExprContext {
+        qcx,
+        name: "RETURNING clause",
+        scope: &scope,
+        relation_type: &typ,
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: true,
+        allow_windows: false,
+    };
+    let table_func_names = BTreeMap::new();
+    let mut output_columns = vec![];
+    let mut new_exprs = vec![];
+    let mut new_type = SqlRelationType::empty();
+    for mut si in returning {
+        transform_ast::transform(qcx.scx, &mut si)?;
+        for (select_item, column_name) in expand_select_item(ecx, &si, &table_func_names)? {
+            let expr = match &select_item {
+                ExpandedSelectItem::InputOrdinal(i) => HirScalarExpr::column(*i),
+                ExpandedSelectItem::Expr(expr) => plan_expr(ecx, expr)?.type_as_any(ecx)?,
+            };
+            output_columns.push(column_name);
+            let typ = ecx.column_type(&expr);
+            new_type.column_types.push(typ);
+            new_exprs.push(expr);
+        }
+    }
+    let desc = RelationDesc::new(new_type, output_columns);
+    let desc_arity = desc.arity();
+    Ok(PlannedRootQuery {
+        expr: new_exprs,
+        desc,
+        finishing: HirRelationExpr::trivial_row_set_finishing_hir(desc_arity),
+        scope,
+    })
+}
+
+/// Plans the `ON CONFLICT` clause of an `INSERT` into `table_name`, where
+/// `values` produces the proposed rows in the table's column order.
+///
+/// The selection of the returned plan produces the rows to write, each
+/// followed by a boolean column that is true for the existing rows that
+/// `DO UPDATE` retracts.
+pub fn plan_on_conflict_query(
+    scx: &StatementContext,
+    table_name: ResolvedItemName,
+    values: HirRelationExpr,
+    mut on_conflict: OnConflict<Aug>,
+) -> Result<ReadThenWritePlan, PlanError> {
+    transform_ast::transform(scx, &mut on_conflict)?;
+    let OnConflict { columns, action } = on_conflict;
+
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    let table = scx.get_item_by_resolved_name(&table_name)?;
+    let id = table.id();
+    let desc = table.relation_desc().expect("table has desc");
+    let arity = desc.arity();
+
+    // The conflict target must match one of the table's keys, which are
+    // derived from its PRIMARY KEY and UNIQUE constraints.
+    let mut target = BTreeSet::new();
+    for column in columns {
+        let name = normalize::column_name(column);
+        match desc.get_by_name(&name) {
+            Some((idx, _)) => {
+                target.insert(idx);
+            }
+            None => sql_bail!(
+                "column {} of relation {} does not exist",
+                name.quoted(),
+                table_name.full_name_str().quoted()
+            ),
+        }
+    }
+    if !desc
+        .typ()
+        .keys
+        .iter()
+        .any(|key| key.iter().copied().collect::<BTreeSet<_>>() == target)
+    {
+        sql_bail!("there is no unique constraint matching the ON CONFLICT specification");
+    }
+
+    let (table, table_scope) = qcx.resolve_table_name(table_name)?;
+    // Identical proposed rows are only written once.
+    let values = values.distinct();
+    // Each existing row paired with the proposed rows that conflict with it.
+    let on = HirScalarExpr::variadic_and(
+        target
+            .iter()
+            .map(|i| {
+                HirScalarExpr::column(*i)
+                    .call_binary(HirScalarExpr::column(arity + i), expr_func::Eq)
+            })
+            .collect(),
+    );
+    let conflicts = table.join(values.clone(), on, JoinKind::Inner);
+    // The proposed rows that do not conflict with any existing row.
+    let inserts = values
+        .union(
+            conflicts
+                .clone()
+                .project((arity..2 * arity).collect())
+                .distinct()
+                .negate(),
+        )
+        .map(vec![HirScalarExpr::literal_false()]);
+
+    let selection = match action {
+        OnConflictAction::DoNothing => inserts,
+        OnConflictAction::DoUpdate {
+            assignments,
+            selection,
+        } => {
+            let excluded = PartialItemName {
+                database: None,
+                schema: None,
+                item: "excluded".into(),
+            };
+            let scope =
+                table_scope.product(Scope::from_source(Some(excluded), desc.iter_names()))?;
+            let relation_type = qcx.relation_type(&conflicts);
+
+            let mut conflicts = conflicts;
+            if let Some(expr) = selection {
+                let ecx = &ExprContext {
+                    qcx: &qcx,
+                    name: "WHERE clause",
+                    scope: &scope,
+                    relation_type: &relation_type,
+                    allow_aggregates: false,
+                    allow_subqueries: true,
+                    allow_parameters: true,
+                    allow_windows: false,
+                };
+                let expr = plan_expr(ecx, &expr)?.type_as(ecx, &SqlScalarType::Bool)?;
+                conflicts = conflicts.filter(vec![expr]);
+            }
+
+            let mut sets = BTreeMap::new();
+            for Assignment { id, value } in assignments {
+                let name = normalize::column_name(id);
+                match desc.get_by_name(&name) {
+                    Some((idx, _)) if target.contains(&idx) => {
+                        sql_bail!("cannot update conflict target column {}", name.quoted())
+                    }
+                    Some((idx, typ)) => {
+                        let ecx = &ExprContext {
+                            qcx: &qcx,
+                            name: "SET clause",
+                            scope: &scope,
+                            relation_type: &relation_type,
+                            allow_aggregates: false,
+                            allow_subqueries: false,
+                            allow_parameters: true,
+                            allow_windows: false,
+                        };
+                        let expr = plan_expr(ecx, &value)?.cast_to(
+                            ecx,
+                            CastContext::Assignment,
+                            &typ.scalar_type,
+                        )?;
+
+                        if sets.insert(idx, expr).is_some() {
+                            sql_bail!("column {} set twice", name)
+                        }
+                    }
+                    None => sql_bail!("unknown column {}", name),
+                };
+            }
+
+            // Retract each existing row that conflicts, and replace it with
+            // the existing row with the SET expressions applied.
+            let retractions = conflicts
+                .clone()
+                .project((0..arity).collect())
+                .map(vec![HirScalarExpr::literal_true()]);
+            let project = (0..arity)
+                .map(|i| match sets.keys().position(|idx| *idx == i) {
+                    Some(pos) => 2 * arity + pos,
+                    None => i,
+                })
+                .chain(iter::once(2 * arity + sets.len()))
+                .collect();
+            let updates = conflicts
+                .map(
+                    sets.into_values()
+                        .chain(iter::once(HirScalarExpr::literal_false()))
+                        .collect(),
+                )
+                .project(project);
+            inserts.union(retractions).union(updates)
+        }
+    };
+
+    let finishing = RowSetFinishing {
+        order_by: vec![],
+        limit: None,
+        offset: 0,
+        project: (0..=arity).collect(),
+    };
+
+    Ok(ReadThenWritePlan {
+        id,
+        selection,
+        finishing,
+        assignments: BTreeMap::new(),
+    })
+}
+
+/// Plans a `MERGE` statement.
+///
+/// The selection of the returned plan produces the rows to write, each
+/// followed by a nullable boolean column as described by
+/// [`MutationKind::Merge`](crate::plan::MutationKind::Merge). The returned
+/// `RETURNING` expressions refer to the written rows.
+pub fn plan_merge_query(
+    scx: &StatementContext,
+    mut merge_stmt: MergeStatement<Aug>,
+) -> Result<(ReadThenWritePlan, PlannedRootQuery<Vec<HirScalarExpr>>), PlanError> {
+    transform_ast::transform(scx, &mut merge_stmt)?;
+    let MergeStatement {
+        table_name,
+        alias,
+        using,
+        on,
+        clauses,
+        returning,
+    } = merge_stmt;
+
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+
+    // Get ID and version of the relation desc.
+    let (id, version) = match table_name {
+        ResolvedItemName::Item { id, version, .. } => (id, version),
+        _ => sql_bail!("cannot mutate non-user table"),
+    };
+
+    // Perform checks on item with given ID.
+    let item = qcx.scx.get_item(&id).at_version(version);
+    if item.item_type() != CatalogItemType::Table {
+        sql_bail!(
+            "cannot mutate {} '{}'",
+            item.item_type(),
+            table_name.full_name_str()
+        );
+    }
+    let mut defaults = item
+        .writable_table_details()
+        .ok_or_else(|| {
+            sql_err!(
+                "cannot mutate non-writeable table '{}'",
+                table_name.full_name_str()
+            )
+        })?
+        .to_vec();
+    for default in &mut defaults {
+        transform_ast::transform(scx, default)?;
+    }
+    if id.is_system() {
+        sql_bail!(
+            "cannot mutate system table '{}'",
+            table_name.full_name_str()
+        );
+    }
+    let desc = item.relation_desc().expect("table has desc");
+    let arity = desc.arity();
+    let full_name = table_name.full_name_str();
+
+    let (target, target_scope) = qcx.resolve_table_name(table_name)?;
+    let target_scope = plan_table_alias(target_scope, alias.as_ref())?;
+    let returning = plan_returning(
+        &qcx,
+        target_scope.clone(),
+        qcx.relation_type(&target),
+        returning,
+    )?;
+
+    // Pair each source row with the target rows it matches, or with nulls if
+    // it matches none. The target rows are followed by a column that is
+    // non-null exactly when the source row matched a target row.
+    let (source, source_scope) = plan_table_factor(&qcx, &using)?;
+    let mut matched_scope = target_scope.clone();
+    matched_scope.items.push(ScopeItem::empty());
+    let joined_scope = matched_scope.product(source_scope)?;
+    let matched = target.clone().map(vec![HirScalarExpr::literal_true()]);
+    let on = {
         let ecx = &ExprContext {
             qcx: &qcx,
-            name: "RETURNING clause",
-            scope: &scope,
-            relation_type: &typ,
+            name: "ON clause",
+            scope: &joined_scope,
+            relation_type: &SqlRelationType::new(
+                qcx.relation_type(&matched)
+                    .column_types
+                    .into_iter()
+                    .chain(qcx.relation_type(&source).column_types)
+                    .collect(),
+            ),
             allow_aggregates: false,
-            allow_subqueries: false,
+            allow_subqueries: true,
             allow_parameters: true,
             allow_windows: false,
         };
-        let table_func_names = BTreeMap::new();
-        let mut output_columns = vec![];
-        let mut new_exprs = vec![];
-        let mut new_type = SqlRelationType::empty();
-        for mut si in returning {
-            transform_ast::transform(scx, &mut si)?;
-            for (select_item, column_name) in expand_select_item(ecx, &si, &table_func_names)? {
-                let expr = match &select_item {
-                    ExpandedSelectItem::InputOrdinal(i) => HirScalarExpr::column(*i),
-                    ExpandedSelectItem::Expr(expr) => plan_expr(ecx, expr)?.type_as_any(ecx)?,
-                };
-                output_columns.push(column_name);
-                let typ = ecx.column_type(&expr);
-                new_type.column_types.push(typ);
-                new_exprs.push(expr);
-            }
-        }
-        let desc = RelationDesc::new(new_type, output_columns);
-        let desc_arity = desc.arity();
-        PlannedRootQuery {
-            expr: new_exprs,
-            desc,
-            finishing: HirRelationExpr::trivial_row_set_finishing_hir(desc_arity),
+        plan_expr(ecx, &on)?.type_as(ecx, &SqlScalarType::Bool)?
+    };
+    let joined = matched.join(source, on, JoinKind::RightOuter);
+    let relation_type = qcx.relation_type(&joined);
+    let joined_arity = relation_type.arity();
+
+    // Clauses for unmatched source rows can only refer to the source.
+    let mut unmatched_scope = joined_scope.clone();
+    for item in &mut unmatched_scope.items[..=arity] {
+        item.error_if_referenced = Some(|_table, column| {
+            sql_err!(
+                "column {} cannot be referenced in a WHEN NOT MATCHED clause",
+                column.quoted()
+            )
+        });
+    }
+
+    let is_matched = HirScalarExpr::column(arity).call_is_null().not();
+    let is_unmatched = HirScalarExpr::column(arity).call_is_null();
+    // Each row is acted on by the first clause whose condition it satisfies,
+    // so the guard of each clause excludes rows taken by earlier clauses.
+    let mut taken_matched = vec![];
+    let mut taken_unmatched = vec![];
+    // The matched rows that are updated or deleted.
+    let mut modified = vec![];
+    let mut selection = vec![];
+    for MergeClause {
+        matched,
+        condition,
+        action,
+    } in clauses
+    {
+        let (scope, taken, kind) = if matched {
+            (&joined_scope, &mut taken_matched, is_matched.clone())
+        } else {
+            (&unmatched_scope, &mut taken_unmatched, is_unmatched.clone())
+        };
+        let ecx = &ExprContext {
+            qcx: &qcx,
+            name: "WHEN clause",
             scope,
-        }
+            relation_type: &relation_type,
+            allow_aggregates: false,
+            allow_subqueries: true,
+            allow_parameters: true,
+            allow_windows: false,
+        };
+        let condition = match condition {
+            Some(condition) => HirScalarExpr::if_then_else(
+                plan_expr(ecx, &condition)?.type_as(ecx, &SqlScalarType::Bool)?,
+                HirScalarExpr::literal_true(),
+                HirScalarExpr::literal_false(),
+            ),
+            None => HirScalarExpr::literal_true(),
+        };
+        let guard = HirScalarExpr::variadic_and(
+            iter::once(kind)
+                .chain(taken.iter().map(|t: &HirScalarExpr| t.clone().not()))
+                .chain(iter::once(condition.clone()))
+                .collect(),
+        );
+        taken.push(condition);
+        let rows = joined.clone().filter(vec![guard.clone()]);
+
+        let ecx = &ExprContext {
+            name: match action {
+                MergeAction::Update { .. } => "SET clause",
+                _ => "VALUES clause",
+            },
+            allow_subqueries: false,
+            ..*ecx
+        };
+        match action {
+            MergeAction::Update { assignments } => {
+                let mut sets = BTreeMap::new();
+                for Assignment { id, value } in assignments {
+                    let name = normalize::column_name(id);
+                    match desc.get_by_name(&name) {
+                        Some((idx, typ)) => {
+                            let expr = plan_expr(ecx, &value)?.cast_to(
+                                ecx,
+                                CastContext::Assignment,
+                                &typ.scalar_type,
+                            )?;
+                            if sets.insert(idx, expr).is_some() {
+                                sql_bail!("column {} set twice", name)
+                            }
+                        }
+                        None => sql_bail!("unknown column {}", name),
+                    }
+                }
+                // Retract each matched target row, and replace it with the
+                // target row with the SET expressions applied.
+                selection.push(
+                    rows.clone()
+                        .project((0..arity).collect())
+                        .map(vec![HirScalarExpr::literal_null(SqlScalarType::Bool)]),
+                );
+                let project = (0..arity)
+                    .map(|i| match sets.keys().position(|idx| *idx == i) {
+                        Some(pos) => joined_arity + pos,
+                        None => i,
+                    })
+                    .chain(iter::once(joined_arity + sets.len()))
+                    .collect();
+                selection.push(
+                    rows.map(
+                        sets.into_values()
+                            .chain(iter::once(HirScalarExpr::literal_false()))
+                            .collect(),
+                    )
+                    .project(project),
+                );
+                modified.push(guard);
+            }
+            MergeAction::Delete => {
+                selection.push(
+                    rows.project((0..arity).collect())
+                        .map(vec![HirScalarExpr::literal_true()]),
+                );
+                modified.push(guard);
+            }
+            MergeAction::Insert { columns, source } => {
+                let mut values = BTreeMap::new();
+                if let MergeInsertSource::Values(exprs) = source {
+                    let columns: Vec<_> = if columns.is_empty() {
+                        desc.iter_names().take(exprs.len()).cloned().collect()
+                    } else {
+                        columns.into_iter().map(normalize::column_name).collect()
+                    };
+                    if exprs.len() > columns.len() {
+                        sql_bail!("INSERT has more expressions than target columns");
+                    }
+                    if exprs.len() < columns.len() {
+                        sql_bail!("INSERT has more target columns than expressions");
+                    }
+                    for (name, expr) in columns.into_iter().zip_eq(exprs) {
+                        let Some((idx, typ)) = desc.get_by_name(&name) else {
+                            sql_bail!(
+                                "column {} of relation {} does not exist",
+                                name.quoted(),
+                                full_name.quoted()
+                            );
+                        };
+                        let expr = plan_expr(ecx, &expr)?.cast_to(
+                            ecx,
+                            CastContext::Assignment,
+                            &typ.scalar_type,
+                        )?;
+                        if values.insert(idx, expr).is_some() {
+                            sql_bail!("column {} specified more than once", name.quoted());
+                        }
+                    }
+                }
+                // Fill in any omitted columns with their defaults.
+                let mut map_exprs = vec![];
+                for (idx, (typ, default)) in desc.iter_types().zip_eq(&defaults).enumerate() {
+                    map_exprs.push(match values.remove(&idx) {
+                        Some(expr) => expr,
+                        None => plan_default_expr(scx, default, &typ.scalar_type)?,
+                    });
+                }
+                map_exprs.push(HirScalarExpr::literal_false());
+                selection.push(
+                    rows.map(map_exprs)
+                        .project((joined_arity..=joined_arity + arity).collect()),
+                );
+            }
+            MergeAction::DoNothing => {}
+        }
+    }
+
+    // Like PostgreSQL, it is an error for more than one source row to update
+    // or delete the same target row. Count the modifications of each target
+    // row, and compare that count to the number of copies of the row.
+    let counts = joined
+        .filter(vec![HirScalarExpr::variadic_or(modified)])
+        .project((0..arity).collect())
+        .map(vec![
+            HirScalarExpr::literal_true(),
+            HirScalarExpr::literal_null(SqlScalarType::Bool),
+        ])
+        .union(target.map(vec![
+            HirScalarExpr::literal_null(SqlScalarType::Bool),
+            HirScalarExpr::literal_true(),
+        ]))
+        .reduce(
+            (0..arity).collect(),
+            vec![
+                AggregateExpr {
+                    func: AggregateFunc::Count,
+                    expr: Box::new(HirScalarExpr::column(arity)),
+                    distinct: false,
+                },
+                AggregateExpr {
+                    func: AggregateFunc::Count,
+                    expr: Box::new(HirScalarExpr::column(arity + 1)),
+                    distinct: false,
+                },
+            ],
+            None,
+        );
+    let check = counts
+        .filter(vec![HirScalarExpr::call_variadic(
+            ErrorIfNull,
+            vec![
+                HirScalarExpr::if_then_else(
+                    HirScalarExpr::column(arity)
+                        .call_binary(HirScalarExpr::column(arity + 1), expr_func::Gt),
+                    HirScalarExpr::literal_null(SqlScalarType::Bool),
+                    HirScalarExpr::literal_false(),
+                ),
+                HirScalarExpr::literal(
+                    Datum::String("MERGE command cannot affect row a second time"),
+                    SqlScalarType::String,
+                ),
+            ],
+        )])
+        .project((0..arity).collect())
+        .map(vec![HirScalarExpr::literal_null(SqlScalarType::Bool)]);
+    let selection = selection
+        .into_iter()
+        .fold(check, |selection, rows| selection.union(rows));
+
+    let finishing = RowSetFinishing {
+        order_by: vec![],
+        limit: None,
+        offset: 0,
+        project: (0..=arity).collect(),
     };
 
     Ok((
-        table.id(),
-        expr.map(map_exprs).project(project_key),
+        ReadThenWritePlan {
+            id,
+            selection,
+            finishing,
+            assignments: BTreeMap::new(),
+        },
         returning,
     ))
 }
@@ -684,7 +1204,8 @@ pub fn plan_copy_from_rows(
     Ok(expr.map(map_exprs).project(project_key))
 }
 
-/// Common information used for DELETE, UPDATE, and INSERT INTO ... SELECT plans.
+/// Common information used for DELETE, UPDATE, INSERT INTO ... SELECT, and
+/// INSERT ... ON CONFLICT plans.
 pub struct ReadThenWritePlan {
     pub id: CatalogItemId,
     /// Read portion of query.
@@ -1835,6 +2356,110 @@ pub fn plan_nested_query(
     Ok((expr.project(project), scope))
 }
 
+/// Plans the body of a function created by `CREATE FUNCTION ... LANGUAGE SQL`.
+///
+/// The body may refer to the function's parameters by name, optionally
+/// qualified by the function's name, or by position (`$1`, `$2`, ...). As in
+/// PostgreSQL, a column of a relation in the body takes precedence over a
+/// parameter of the same name. Either way, parameter references are planned as
+/// [`HirScalarExpr::Parameter`]s, which the caller splices with the arguments
+/// of the function call.
+///
+/// The body's columns are cast to `return_types` by assignment.
+pub fn plan_sql_function_body(
+    qcx: &QueryContext,
+    name: &str,
+    params: &[(Option<String>, SqlScalarType)],
+    return_types: &[SqlScalarType],
+    body: &Query<Raw>,
+) -> Result<(HirRelationExpr, Scope), PlanError> {
+    let mut scx = qcx.scx.clone();
+    scx.param_types = RefCell::new(
+        params
+            .iter()
+            .enumerate()
+            .map(|(i, (_name, ty))| (i + 1, ty.clone()))
+            .collect(),
+    );
+    let root_qcx = QueryContext::root(&scx, qcx.lifetime);
+
+    // Named parameters are made visible to the body as the columns of an outer
+    // relation named after the function, which gives the body's own columns
+    // precedence over them.
+    let function_name = PartialItemName {
+        database: None,
+        schema: None,
+        item: name.to_string(),
+    };
+    let mut params_scope = Scope::empty();
+    params_scope.items = params
+        .iter()
+        .map(|(param_name, _ty)| match param_name {
+            Some(param_name) => {
+                ScopeItem::from_name(Some(function_name.clone()), param_name.as_str())
+            }
+            None => {
+                let mut item = ScopeItem::empty();
+                item.allow_unqualified_references = false;
+                item
+            }
+        })
+        .collect();
+    let params_type = SqlRelationType::new(
+        params
+            .iter()
+            .map(|(_name, ty)| ty.clone().nullable(true))
+            .collect(),
+    );
+    let mut qcx = root_qcx.derived_context(params_scope, params_type);
+
+    let (mut body, ids) = names::resolve(scx.catalog, body.clone())?;
+    scx.record_sql_impl_ids(&ids);
+    transform_ast::transform(&scx, &mut body)?;
+    let (expr, scope) = plan_nested_query(&mut qcx, &body)?;
+
+    let return_type_name = match return_types {
+        [ty] => scx.humanize_sql_scalar_type(ty, false),
+        _ => "record".into(),
+    };
+    if scope.len() != return_types.len() {
+        sql_bail!(
+            "return type mismatch in function declared to return {}: \
+            function body returns {} columns instead of {}",
+            return_type_name,
+            scope.len(),
+            return_types.len(),
+        );
+    }
+    let mut expr =
+        cast_relation(&qcx, CastContext::Assignment, expr, return_types).map_err(|e| {
+            sql_err!(
+                "return type mismatch in function declared to return {}: \
+                function body returns {} instead of {} at column {}",
+                return_type_name,
+                scx.humanize_sql_scalar_type(&e.source_type, false),
+                scx.humanize_sql_scalar_type(&e.target_type, false),
+                e.column + 1,
+            )
+        })?;
+
+    // Replace references to the parameters' scope with references to the
+    // parameters themselves.
+    #[allow(deprecated)]
+    let _ = expr.visit_scalar_expressions_mut(0, &mut |e: &mut HirScalarExpr, depth: usize| {
+        e.visit_recursively_mut(depth, &mut |depth: usize, e: &mut HirScalarExpr| {
+            if let HirScalarExpr::Column(col, _name) = e {
+                if col.level == depth + 1 {
+                    *e = HirScalarExpr::parameter(col.column + 1);
+                }
+            }
+            Ok::<_, ()>(())
+        })
+    });
+
+    Ok((expr, scope))
+}
+
 fn plan_set_expr(
     qcx: &mut QueryContext,
     q: &SetExpr<Aug>,
@@ -2086,6 +2711,10 @@ fn plan_set_expr(
                     show::plan_show_create_type(qcx.scx, stmt.clone())?,
                     show::describe_show_create_type(qcx.scx, stmt)?,
                 ),
+                ShowStatement::ShowCreateFunction(stmt) => to_hirscope(
+                    show::plan_show_create_function(qcx.scx, stmt.clone())?,
+                    show::describe_show_create_function(qcx.scx, stmt)?,
+                ),
                 ShowStatement::ShowObjects(stmt) => {
                     show::show_objects(qcx.scx, stmt)?.plan_hir(qcx)
                 }
@@ -2346,6 +2975,14 @@ fn plan_select_from_where(
         }
         visitor.into_result()?
     };
+    let groupings = {
+        let mut visitor = GroupingFuncCollector::default();
+        visitor.visit_select(&s);
+        for o in order_by_exprs.iter() {
+            visitor.visit_order_by_expr(o);
+        }
+        visitor.into_result()
+    };
     let mut table_func_names: BTreeMap<String, Ident> = BTreeMap::new();
     if !table_funcs.is_empty() {
         let (expr, scope) = plan_scalar_table_funcs(
@@ -2396,13 +3033,16 @@ fn plan_select_from_where(
             allow_parameters: true,
             allow_windows: false,
         };
+        let (group_by_exprs, grouping_sets) = expand_group_by(&s.group_by)?;
         let mut group_key = vec![];
         let mut group_exprs: BTreeMap<HirScalarExpr, ScopeItem> = BTreeMap::new();
         let mut group_hir_exprs = vec![];
+        // The group key column that each of `group_by_exprs` maps to.
+        let mut group_columns = vec![];
         let mut group_scope = Scope::empty();
         let mut select_all_mapping = BTreeMap::new();
 
-        for group_expr in &s.group_by {
+        for group_expr in group_by_exprs {
             let (group_expr, expr) = plan_group_by_expr(ecx, group_expr, &projection)?;
             let new_column = group_key.len();
 
@@ -2423,6 +3063,11 @@ fn plan_select_from_where(
                 if let Some(group_expr) = group_expr {
                     existing_scope_item.exprs.insert(group_expr.clone());
                 }
+                let column = group_hir_exprs
+                    .iter()
+                    .position(|e| *e == expr)
+                    .expect("deduplicated group expression has a column");
+                group_columns.push(column);
                 continue;
             }
 
@@ -2450,11 +3095,32 @@ fn plan_select_from_where(
                 scope_item.exprs.insert(group_expr);
             }
 
+            group_columns.push(new_column);
             group_key.push(from_scope.len() + group_exprs.len());
             group_hir_exprs.push(expr.clone());
             group_exprs.insert(expr, scope_item);
         }
 
+        // Resolve the arguments of the GROUPING calls gathered in Step 3 to
+        // group key columns.
+        let mut grouping_args = vec![];
+        for args in &groupings {
+            if args.len() > 31 {
+                sql_bail!("GROUPING must have fewer than 32 arguments");
+            }
+            let mut columns = vec![];
+            for arg in args {
+                let expr = plan_expr(ecx, arg)?.type_as_any(ecx)?;
+                match group_hir_exprs.iter().position(|e| *e == expr) {
+                    Some(column) => columns.push(column),
+                    None => sql_bail!(
+                        "arguments to GROUPING must be grouping expressions of the associated query level"
+                    ),
+                }
+            }
+            grouping_args.push(columns);
+        }
+
         assert_eq!(group_hir_exprs.len(), group_exprs.len());
         for expr in &group_hir_exprs {
             if let Some(scope_item) = group_exprs.remove(expr) {
@@ -2485,13 +3151,59 @@ fn plan_select_from_where(
                 .items
                 .push(ScopeItem::from_expr(Expr::Function(sql_function.clone())));
         }
-        if !agg_exprs.is_empty() || !group_key.is_empty() || s.having.is_some() {
+        for args in groupings {
+            group_scope
+                .items
+                .push(ScopeItem::from_expr(Expr::Grouping(args)));
+        }
+        if !agg_exprs.is_empty()
+            || !s.group_by.is_empty()
+            || s.having.is_some()
+            || !grouping_args.is_empty()
+        {
             // apply GROUP BY / aggregates
-            relation_expr = relation_expr.map(group_hir_exprs).reduce(
-                group_key,
-                agg_exprs,
-                group_size_hints.aggregate_input_group_size,
-            );
+            let key_arity = group_hir_exprs.len();
+            let input = relation_expr.map(group_hir_exprs);
+            relation_expr = match grouping_sets {
+                Some(sets) if sets.len() > 1 => {
+                    let key_offset = from_scope.len();
+                    let input_type = qcx.relation_type(&input);
+                    let group_types = &input_type.column_types[key_offset..];
+                    let sets: Vec<BTreeSet<usize>> = sets
+                        .iter()
+                        .map(|set| set.iter().map(|i| group_columns[*i]).collect())
+                        .collect();
+                    plan_grouping_sets(
+                        input,
+                        key_offset,
+                        group_types,
+                        &sets,
+                        agg_exprs,
+                        &grouping_args,
+                        group_size_hints.aggregate_input_group_size,
+                    )
+                }
+                // A single grouping set groups by every key column, so every
+                // GROUPING call is zero.
+                _ => {
+                    let reduce = input.reduce(
+                        group_key,
+                        agg_exprs,
+                        group_size_hints.aggregate_input_group_size,
+                    );
+                    if grouping_args.is_empty() {
+                        reduce
+                    } else {
+                        let all_columns: BTreeSet<usize> = (0..key_arity).collect();
+                        reduce.map(
+                            grouping_args
+                                .iter()
+                                .map(|args| grouping_literal(args, &all_columns))
+                                .collect(),
+                        )
+                    }
+                }
+            };
 
             // For every old column that wasn't a group key, add a scope item
             // that errors when referenced. We can't simply drop these items
@@ -2858,6 +3570,293 @@ fn plan_scalar_table_funcs(
     Ok((expr, scope))
 }
 
+/// The most grouping sets that a `GROUP BY` clause may expand to, matching
+/// PostgreSQL's limit.
+const MAX_GROUPING_SETS: usize = 4096;
+
+/// Flattens the items of a `GROUP BY` clause into the expressions they
+/// mention, in order, and the grouping sets the clause expands to, as indexes
+/// into those expressions.
+///
+/// The grouping sets of the clause are the cross product of the grouping sets
+/// of its items. A clause of plain expressions has a single set containing
+/// all of them and is reported as `None`, so that it is planned exactly as
+/// before grouping sets existed.
+fn expand_group_by(
+    items: &[GroupByItem<Aug>],
+) -> Result<(Vec<&Expr<Aug>>, Option<Vec<Vec<usize>>>), PlanError> {
+    let mut exprs = vec![];
+    let mut sets: Vec<Vec<usize>> = vec![vec![]];
+    for item in items {
+        let item_sets = expand_group_by_item(item, &mut exprs)?;
+        sets = sets
+            .iter()
+            .cartesian_product(&item_sets)
+            .map(|(l, r)| l.iter().chain(r).copied().collect())
+            .collect();
+        if sets.len() > MAX_GROUPING_SETS {
+            sql_bail!("too many grouping sets present (maximum {MAX_GROUPING_SETS})");
+        }
+    }
+    if items
+        .iter()
+        .all(|item| matches!(item, GroupByItem::Expr(_)))
+    {
+        Ok((exprs, None))
+    } else {
+        Ok((exprs, Some(sets)))
+    }
+}
+
+/// Expands a single `GROUP BY` item into its grouping sets, appending the
+/// expressions it mentions to `exprs`.
+fn expand_group_by_item<'a>(
+    item: &'a GroupByItem<Aug>,
+    exprs: &mut Vec<&'a Expr<Aug>>,
+) -> Result<Vec<Vec<usize>>, PlanError> {
+    fn push<'a>(exprs: &mut Vec<&'a Expr<Aug>>, list: &'a [Expr<Aug>]) -> Vec<usize> {
+        list.iter()
+            .map(|expr| {
+                exprs.push(expr);
+                exprs.len() - 1
+            })
+            .collect()
+    }
+
+    let sets = match item {
+        GroupByItem::Expr(expr) => vec![push(exprs, std::slice::from_ref(expr))],
+        GroupByItem::Set(list) => vec![push(exprs, list)],
+        GroupByItem::Rollup(elements) => {
+            let elements: Vec<_> = elements.iter().map(|e| push(exprs, e)).collect();
+            // `ROLLUP (a, b)` is `GROUPING SETS ((a, b), (a), ())`.
+            (0..=elements.len())
+                .rev()
+                .map(|n| elements[..n].concat())
+                .collect()
+        }
+        GroupByItem::Cube(elements) => {
+            if elements.len() > 12 {
+                sql_bail!("CUBE is limited to 12 elements");
+            }
+            let elements: Vec<_> = elements.iter().map(|e| push(exprs, e)).collect();
+            // `CUBE (a, b)` is `GROUPING SETS ((a, b), (a), (b), ())`. Counting
+            // down through the bitmasks of included elements, with the first
+            // element as the high bit, produces the sets in that order.
+            let n = elements.len();
+            (0..1u32 << n)
+                .rev()
+                .map(|mask| {
+                    elements
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| mask & (1 << (n - 1 - i)) != 0)
+                        .flat_map(|(_, element)| element.iter().copied())
+                        .collect()
+                })
+                .collect()
+        }
+        GroupByItem::GroupingSets(items) => {
+            let mut sets = vec![];
+            for item in items {
+                sets.extend(expand_group_by_item(item, exprs)?);
+                if sets.len() > MAX_GROUPING_SETS {
+                    sql_bail!("too many grouping sets present (maximum {MAX_GROUPING_SETS})");
+                }
+            }
+            sets
+        }
+    };
+    Ok(sets)
+}
+
+/// Plans a `GROUP BY` with several grouping sets as the union of one reduction
+/// per set.
+///
+/// `input` carries the group key in the columns starting at `key_offset`, with
+/// types `group_types`. Each branch reduces by the key columns in its set and
+/// then restores the full key, with `NULL` in place of the columns the set
+/// aggregates away, so that the union has the layout of a plain `GROUP BY`:
+/// the group key, the aggregates, then one column per `GROUPING` call.
+///
+/// When every aggregate can be computed from partial results of itself (see
+/// [`reaggregate`]), `input` is reduced once by every key column that any set
+/// uses, and each set is reduced from that reduction rather than from `input`.
+/// For `ROLLUP (a, b)`, the `(a)` and `()` sets then aggregate the groups of
+/// `(a, b)` instead of the input rows, and all three sets share the arrangement
+/// of that one reduction of `input`. Otherwise every set reduces `input` on its
+/// own.
+fn plan_grouping_sets(
+    input: HirRelationExpr,
+    key_offset: usize,
+    group_types: &[SqlColumnType],
+    sets: &[BTreeSet<usize>],
+    aggregates: Vec<AggregateExpr>,
+    groupings: &[Vec<usize>],
+    expected_group_size: Option<u64>,
+) -> HirRelationExpr {
+    let key_arity = group_types.len();
+    let finest: BTreeSet<usize> = sets.iter().flatten().copied().collect();
+    let shared = aggregates
+        .iter()
+        .map(reaggregate)
+        .collect::<Option<Vec<_>>>()
+        .map(|reaggregates| {
+            let key = finest.iter().map(|c| key_offset + c).collect();
+            let reduced = input
+                .clone()
+                .reduce(key, aggregates.clone(), expected_group_size);
+            (reduced, reaggregates)
+        });
+
+    sets.iter()
+        .map(|set| {
+            let reduced = match &shared {
+                Some((finest_reduced, _)) if *set == finest => finest_reduced.clone(),
+                Some((finest_reduced, reaggregates)) => reduce_partials(
+                    finest_reduced.clone(),
+                    &finest,
+                    set,
+                    reaggregates,
+                    expected_group_size,
+                ),
+                None => {
+                    let key = set.iter().map(|c| key_offset + c).collect();
+                    input
+                        .clone()
+                        .reduce(key, aggregates.clone(), expected_group_size)
+                }
+            };
+            let reduced_arity = set.len() + aggregates.len();
+            let mut scalars: Vec<_> = group_types
+                .iter()
+                .enumerate()
+                .map(|(c, typ)| match set.iter().position(|s| *s == c) {
+                    Some(i) => HirScalarExpr::column(i),
+                    None => HirScalarExpr::literal_null(typ.scalar_type.clone()),
+                })
+                .collect();
+            scalars.extend(groupings.iter().map(|args| grouping_literal(args, set)));
+            let outputs = (reduced_arity..reduced_arity + key_arity)
+                .chain(set.len()..reduced_arity)
+                .chain(reduced_arity + key_arity..reduced_arity + key_arity + groupings.len())
+                .collect();
+            reduced.map(scalars).project(outputs)
+        })
+        .reduce(|union, branch| union.union(branch))
+        .expect("GROUP BY expands to at least one grouping set")
+}
+
+/// How to compute an aggregate from partial results of itself.
+struct Reaggregate {
+    /// The aggregate to apply to the partial results.
+    func: AggregateFunc,
+    /// Whether the result must be cast back from `numeric` to `int8`.
+    cast_to_int8: bool,
+    /// Whether the aggregate of no rows is zero rather than `NULL`.
+    zero_if_empty: bool,
+}
+
+/// Returns how to compute `aggregate` from its results over a partition of its
+/// input, or `None` if that is not possible.
+///
+/// Sums of floats are left out, as summing partial sums rounds differently
+/// than summing the input rows.
+fn reaggregate(aggregate: &AggregateExpr) -> Option<Reaggregate> {
+    use AggregateFunc::*;
+    if aggregate.distinct {
+        return None;
+    }
+    let (func, cast_to_int8) = match &aggregate.func {
+        MaxNumeric | MaxInt16 | MaxInt32 | MaxInt64 | MaxUInt16 | MaxUInt32 | MaxUInt64
+        | MaxMzTimestamp | MaxFloat32 | MaxFloat64 | MaxBool | MaxString | MaxDate
+        | MaxTimestamp | MaxTimestampTz | MaxInterval | MaxTime | MinNumeric | MinInt16
+        | MinInt32 | MinInt64 | MinUInt16 | MinUInt32 | MinUInt64 | MinMzTimestamp | MinFloat32
+        | MinFloat64 | MinBool | MinString | MinDate | MinTimestamp | MinTimestampTz
+        | MinInterval | MinTime | Any | All | SumNumeric => (aggregate.func.clone(), false),
+        // `int8` sums and counts are `numeric` sums of the partial results.
+        SumInt16 | SumInt32 | Count => (SumInt64, true),
+        SumInt64 | SumUInt64 => (SumNumeric, false),
+        _ => return None,
+    };
+    Some(Reaggregate {
+        func,
+        cast_to_int8,
+        zero_if_empty: aggregate.func == Count,
+    })
+}
+
+/// Reduces `partials`, the result of reducing by the key columns `finest`, to
+/// the grouping set `set`, a subset of `finest`, with the layout of reducing
+/// the input by `set`.
+fn reduce_partials(
+    partials: HirRelationExpr,
+    finest: &BTreeSet<usize>,
+    set: &BTreeSet<usize>,
+    reaggregates: &[Reaggregate],
+    expected_group_size: Option<u64>,
+) -> HirRelationExpr {
+    let key = set
+        .iter()
+        .map(|c| {
+            finest
+                .iter()
+                .position(|f| f == c)
+                .expect("grouping sets are subsets of the finest set")
+        })
+        .collect();
+    let aggregates = reaggregates
+        .iter()
+        .enumerate()
+        .map(|(i, reaggregate)| AggregateExpr {
+            func: reaggregate.func.clone(),
+            expr: Box::new(HirScalarExpr::column(finest.len() + i)),
+            distinct: false,
+        })
+        .collect();
+    let reduced = partials.reduce(key, aggregates, expected_group_size);
+
+    let reduced_arity = set.len() + reaggregates.len();
+    let mut casts = vec![];
+    let mut outputs: Vec<_> = (0..set.len()).collect();
+    for (i, reaggregate) in reaggregates.iter().enumerate() {
+        if !reaggregate.cast_to_int8 {
+            outputs.push(set.len() + i);
+            continue;
+        }
+        let mut cast = HirScalarExpr::column(set.len() + i)
+            .call_unary(UnaryFunc::CastNumericToInt64(expr_func::CastNumericToInt64));
+        // The default row of a reduction without a key holds a `NULL` sum,
+        // where `count` must produce zero.
+        if set.is_empty() && reaggregate.zero_if_empty {
+            cast = HirScalarExpr::call_variadic(
+                Coalesce,
+                vec![
+                    cast,
+                    HirScalarExpr::literal(Datum::Int64(0), SqlScalarType::Int64),
+                ],
+            );
+        }
+        outputs.push(reduced_arity + casts.len());
+        casts.push(cast);
+    }
+    if casts.is_empty() {
+        reduced
+    } else {
+        reduced.map(casts).project(outputs)
+    }
+}
+
+/// The value of a `GROUPING` call with arguments at the group key columns
+/// `args` for rows produced by the grouping set `set`: a bitmask with one bit
+/// per argument, the first argument the most significant, that is set when
+/// the argument is not in the grouping set.
+fn grouping_literal(args: &[usize], set: &BTreeSet<usize>) -> HirScalarExpr {
+    let value = args
+        .iter()
+        .fold(0, |acc, c| (acc << 1) | i32::from(!set.contains(c)));
+    HirScalarExpr::literal(Datum::Int32(value), SqlScalarType::Int32)
+}
+
 /// Plans an expression in a `GROUP BY` clause.
 ///
 /// For historical reasons, PostgreSQL allows `GROUP BY` expressions to refer to
@@ -3349,6 +4348,10 @@ fn plan_table_function_internal(
             }
             plan_exprs(ecx, args)?
         }
+        FunctionArgs::WithinGroup { .. } => sql_bail!(
+            "WITHIN GROUP specified, but {} is not an aggregate function",
+            name
+        ),
     };
 
     let table_name = match table_name {
@@ -3362,7 +4365,7 @@ fn plan_table_function_internal(
         item: table_name,
     });
 
-    let (expr, mut scope) = match resolve_func(ecx, name, args)? {
+    let (expr, mut scope) = match &*resolve_func(ecx, name, args)? {
         Func::Table(impls) => {
             let tf = func::select_impl(ecx, FuncSpec::Func(name), impls, scalar_args, vec![])?;
             let scope = Scope::from_source(scope_name.clone(), tf.column_names);
@@ -3604,6 +4607,7 @@ fn invent_column_name(
                 NameQuality::High,
             )),
             Expr::NullIf { .. } => Some(("nullif".into(), NameQuality::High)),
+            Expr::Grouping(_) => Some(("grouping".into(), NameQuality::High)),
             Expr::Array { .. } => Some(("array".into(), NameQuality::High)),
             Expr::List { .. } => Some(("list".into(), NameQuality::High)),
             Expr::Map { .. } | Expr::MapSubquery(_) => Some(("map".into(), NameQuality::High)),
@@ -4135,6 +5139,9 @@ fn plan_expr_inner<'a>(
             &Some(Box::new(*l_expr.clone())),
         )?
         .into()),
+        // GROUPING calls in a grouped query are planned along with its
+        // aggregates and resolved from the scope above.
+        Expr::Grouping(_) => sql_bail!("grouping operations are not allowed in {}", ecx.name),
         Expr::FieldAccess { expr, field } => plan_field_access(ecx, expr, field),
         Expr::WildcardAccess(expr) => plan_expr(ecx, expr),
         Expr::Subscript { expr, positions } => plan_subscript(ecx, expr, positions),
@@ -5233,7 +6240,7 @@ fn plan_aggregate_common(
         name,
         args,
         filter,
-        over: _,
+        over,
         distinct,
     }: &Function<Aug>,
 ) -> Result<AggregateExpr, PlanError> {
@@ -5251,7 +6258,8 @@ fn plan_aggregate_common(
     // prevents the projection into Record below from triggering on unsupported
     // functions.
 
-    let impls = match resolve_func(ecx, name, args)? {
+    let func = resolve_func(ecx, name, args)?;
+    let impls = match &*func {
         Func::Aggregate(impls) => impls,
         _ => bail_internal!("plan_aggregate_common called on non-aggregate function"),
     };
@@ -5264,6 +6272,7 @@ fn plan_aggregate_common(
     // rules to all aggregates, not just `count`, since we may one day support
     // user-defined aggregates, including user-defined aggregates that take no
     // parameters.
+    let within_group = matches!(args, FunctionArgs::WithinGroup { .. });
     let (args, order_by) = match &args {
         FunctionArgs::Star => (vec![], vec![]),
         FunctionArgs::Args { args, order_by } => {
@@ -5276,11 +6285,44 @@ fn plan_aggregate_common(
             let args = plan_exprs(ecx, args)?;
             (args, order_by.clone())
         }
+        // The sort expressions of an ordered-set aggregate are its aggregated
+        // arguments, which follow the direct arguments in its signature. Only
+        // their sort order is passed on as `col_orders`.
+        FunctionArgs::WithinGroup { args, order_by } => {
+            if over.is_some() {
+                sql_bail!(
+                    "OVER is not supported for ordered-set aggregate {}",
+                    humanize_or_debug(ecx.qcx.scx, name)
+                );
+            }
+            let mut args = plan_exprs(ecx, args)?;
+            for obe in order_by {
+                args.push(plan_expr(ecx, &obe.expr)?);
+            }
+            (args, order_by.clone())
+        }
     };
 
-    let (order_by_exprs, col_orders) = plan_function_order_by(ecx, &order_by)?;
+    let (order_by_exprs, col_orders) = if within_group {
+        let col_orders = order_by
+            .iter()
+            .enumerate()
+            .map(|(i, obe)| resolve_desc_and_nulls_last(obe, i))
+            .collect();
+        (vec![], col_orders)
+    } else {
+        plan_function_order_by(ecx, &order_by)?
+    };
 
     let (mut expr, func) = func::select_impl(ecx, FuncSpec::Func(name), impls, args, col_orders)?;
+    if func.is_ordered_set() != within_group {
+        let name = humanize_or_debug(ecx.qcx.scx, name);
+        if within_group {
+            sql_bail!("{name} is not an ordered-set aggregate, so it cannot have WITHIN GROUP");
+        } else {
+            sql_bail!("WITHIN GROUP is required for ordered-set aggregate {name}");
+        }
+    }
     if let Some(filter) = &filter {
         // If a filter is present, as in
         //
@@ -5460,7 +6502,8 @@ fn plan_function<'a>(
         distinct,
     }: &'a Function<Aug>,
 ) -> Result<HirScalarExpr, PlanError> {
-    let impls = match resolve_func(ecx, name, args)? {
+    let func = resolve_func(ecx, name, args)?;
+    let impls = match &*func {
         Func::Table(_) => {
             sql_bail!(
                 "table functions are not allowed in {} (function {})",
@@ -5655,6 +6698,10 @@ fn plan_function<'a>(
             }
             plan_exprs(ecx, args)?
         }
+        FunctionArgs::WithinGroup { .. } => sql_bail!(
+            "WITHIN GROUP specified, but {} is not an aggregate function",
+            humanize_or_debug(ecx.qcx.scx, name)
+        ),
     };
 
     func::select_impl(ecx, FuncSpec::Func(name), impls, scalar_args, vec![])
@@ -5670,7 +6717,7 @@ pub fn resolve_func(
     ecx: &ExprContext,
     name: &ResolvedItemName,
     args: &mz_sql_parser::ast::FunctionArgs<Aug>,
-) -> Result<&'static Func, PlanError> {
+) -> Result<FuncRef, PlanError> {
     if let Ok(i) = ecx.qcx.scx.get_item_by_resolved_name(name) {
         if let Ok(f) = i.func() {
             return Ok(f);
@@ -5690,6 +6737,13 @@ pub fn resolve_func(
             }
             plan_exprs(ecx, args)?
         }
+        mz_sql_parser::ast::FunctionArgs::WithinGroup { args, order_by } => {
+            let mut exprs = plan_exprs(ecx, args)?;
+            for obe in order_by {
+                exprs.push(plan_expr(ecx, &obe.expr)?);
+            }
+            exprs
+        }
     };
 
     let arg_types: Vec<_> = cexprs
@@ -5892,6 +6946,10 @@ fn plan_window_function_non_aggr<'a>(
             }
             plan_exprs(ecx, args)?
         }
+        FunctionArgs::WithinGroup { .. } => sql_bail!(
+            "WITHIN GROUP specified, but {} is not an aggregate function",
+            name
+        ),
     };
 
     Ok((
@@ -6365,6 +7423,18 @@ fn scalar_type_from_catalog_inner(
                         budget,
                     )?),
                 }),
+                CatalogType::MultiRange {
+                    element_reference: element_id,
+                } => Ok(SqlScalarType::MultiRange {
+                    element_type: Box::new(scalar_type_from_catalog_inner(
+                        catalog,
+                        *element_id,
+                        &[],
+                        depth + 1,
+                        depth_limit,
+                        budget,
+                    )?),
+                }),
                 CatalogType::Record { fields } => {
                     let scalars: Box<[(ColumnName, SqlColumnType)]> = fields
                         .iter()
@@ -6395,6 +7465,11 @@ fn scalar_type_from_catalog_inner(
                 CatalogType::Bool => Ok(SqlScalarType::Bool),
                 CatalogType::Bytes => Ok(SqlScalarType::Bytes),
                 CatalogType::Date => Ok(SqlScalarType::Date),
+                CatalogType::Enum { labels } => Ok(SqlScalarType::Enum {
+                    custom_id: id,
+                    custom_oid: entry.oid(),
+                    labels: labels.clone().into(),
+                }),
                 CatalogType::Float32 => Ok(SqlScalarType::Float32),
                 CatalogType::Float64 => Ok(SqlScalarType::Float64),
                 CatalogType::Int16 => Ok(SqlScalarType::Int16),
@@ -6421,6 +7496,9 @@ fn scalar_type_from_catalog_inner(
                 CatalogType::String => Ok(SqlScalarType::String),
                 CatalogType::Time => Ok(SqlScalarType::Time),
                 CatalogType::Uuid => Ok(SqlScalarType::Uuid),
+                CatalogType::Inet => Ok(SqlScalarType::Inet),
+                CatalogType::Cidr => Ok(SqlScalarType::Cidr),
+                CatalogType::MacAddr => Ok(SqlScalarType::MacAddr),
                 CatalogType::Int2Vector => Ok(SqlScalarType::Int2Vector),
                 CatalogType::MzAclItem => Ok(SqlScalarType::MzAclItem),
                 CatalogType::Numeric => unreachable!("handled above"),
@@ -6488,7 +7566,7 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
             Err(_) => return,
         };
 
-        match item.func() {
+        match item.func().as_deref() {
             // We don't want to collect window aggregations, because these will be handled not by
             // plan_aggregate, but by plan_function.
             Ok(Func::Aggregate { .. }) if func.over.is_none() => {
@@ -6542,7 +7620,7 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
                 // and save the table func so it can be planned elsewhere.
                 let mut table_func = None;
                 if let Ok(item) = self.scx.get_item_by_resolved_name(&func.name) {
-                    if let Ok(Func::Table { .. }) = item.func() {
+                    if let Ok(Func::Table { .. }) = item.func().as_deref() {
                         if let Some(context) = self.table_disallowed_context.last() {
                             self.err = Some(sql_err!(
                                 "table functions are not allowed in {} (function {})",
@@ -6602,6 +7680,37 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
     }
 }
 
+/// Collects the arguments of `GROUPING` calls, which are planned along with
+/// the aggregates of the enclosing `GROUP BY`.
+#[derive(Default)]
+struct GroupingFuncCollector {
+    groupings: Vec<Vec<Expr<Aug>>>,
+}
+
+impl GroupingFuncCollector {
+    fn into_result(self) -> Vec<Vec<Expr<Aug>>> {
+        // Dedup while preserving the order.
+        let mut seen = BTreeSet::new();
+        self.groupings
+            .into_iter()
+            .filter(move |args| seen.insert(args.clone()))
+            .collect()
+    }
+}
+
+impl Visit<'_, Aug> for GroupingFuncCollector {
+    fn visit_expr(&mut self, expr: &Expr<Aug>) {
+        if let Expr::Grouping(args) = expr {
+            self.groupings.push(args.clone());
+        }
+        visit::visit_expr(self, expr);
+    }
+
+    fn visit_query(&mut self, _query: &Query<Aug>) {
+        // Don't go into subqueries. Those will be handled by their own `plan_query`.
+    }
+}
+
 #[derive(Default)]
 struct WindowFuncCollector {
     window_funcs: Vec<Expr<Aug>>,
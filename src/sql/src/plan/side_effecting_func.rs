@@ -189,7 +189,7 @@ fn extract_sef_call(
     else {
         return Ok(None);
     };
-    let func_impl = match func {
+    let func_impl = match &*func {
         Func::Scalar(impls) if impls.len() == 1 => impls.into_element(),
         _ => return Ok(None),
     };
@@ -16,6 +16,7 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 use std::iter;
 use std::num::NonZeroU32;
+use std::sync::Arc;
 use std::time::Duration;
 
 use chrono::DateTime;
@@ -52,37 +53,38 @@ use mz_sql_parser::ast::{
     AlterRoleOption, AlterRoleStatement, AlterSecretStatement, AlterSetClusterStatement,
     AlterSinkAction, AlterSinkStatement, AlterSourceAction, AlterSourceAddSubsourceOption,
     AlterSourceAddSubsourceOptionName, AlterSourceStatement, AlterSystemResetAllStatement,
-    AlterSystemResetStatement, AlterSystemSetStatement, AlterTableAddColumnStatement, AvroSchema,
-    AvroSchemaOption, AvroSchemaOptionName, ClusterAlterOption, ClusterAlterOptionName,
-    ClusterAlterOptionValue, ClusterAlterUntilReadyOption, ClusterAlterUntilReadyOptionName,
+    AlterSystemResetStatement, AlterSystemSetStatement, AlterTableAddColumnStatement,
+    AlterTypeAddValuePosition, AlterTypeAddValueStatement, AvroSchema, AvroSchemaOption,
+    AvroSchemaOptionName, ClusterAlterOption, ClusterAlterOptionName, ClusterAlterOptionValue,
+    ClusterAlterUntilReadyOption, ClusterAlterUntilReadyOptionName,
     ClusterAutoScalingStrategyOptionValue, ClusterFeature, ClusterFeatureName, ClusterOption,
     ClusterOptionName, ClusterScheduleOptionValue, ColumnDef, ColumnOption, CommentObjectType,
     CommentStatement, ConnectionOption, ConnectionOptionName, CreateClusterReplicaStatement,
     CreateClusterStatement, CreateConnectionOption, CreateConnectionOptionName,
-    CreateConnectionStatement, CreateConnectionType, CreateDatabaseStatement, CreateIndexStatement,
-    CreateMaterializedViewStatement, CreateNetworkPolicyStatement, CreateRoleStatement,
-    CreateSchemaStatement, CreateSecretStatement, CreateSinkConnection, CreateSinkOption,
-    CreateSinkOptionName, CreateSinkStatement, CreateSourceConnection, CreateSourceOption,
-    CreateSourceOptionName, CreateSourceStatement, CreateSubsourceOption,
-    CreateSubsourceOptionName, CreateSubsourceStatement, CreateTableFromSourceStatement,
-    CreateTableStatement, CreateTypeAs, CreateTypeListOption, CreateTypeListOptionName,
-    CreateTypeMapOption, CreateTypeMapOptionName, CreateTypeStatement, CreateViewStatement,
-    CreateWebhookSourceStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
-    CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DeferredItemName,
-    DocOnIdentifier, DocOnSchema, DropObjectsStatement, DropOwnedStatement, Expr, Format,
-    FormatSpecifier, GlueAvroOption, GlueAvroOptionName, IcebergSinkConfigOption, Ident,
-    IfExistsBehavior, IndexOption, IndexOptionName, KafkaSinkConfigOption, KeyConstraint,
-    LoadGeneratorOption, LoadGeneratorOptionName, MaterializedViewOption,
-    MaterializedViewOptionName, MySqlConfigOption, MySqlConfigOptionName, NetworkPolicyOption,
-    NetworkPolicyOptionName, NetworkPolicyRuleDefinition, NetworkPolicyRuleOption,
-    NetworkPolicyRuleOptionName, OnHydrationOptionValue, PgConfigOption, PgConfigOptionName,
-    ProtobufSchema, QualifiedReplica, RefreshAtOptionValue, RefreshEveryOptionValue,
-    RefreshOptionValue, ReplicaDefinition, ReplicaOption, ReplicaOptionName, RoleAttribute,
-    SetRoleVar, SourceErrorPolicy, SourceIncludeMetadata, SqlServerConfigOption,
-    SqlServerConfigOptionName, Statement, TableConstraint, TableFromSourceColumns,
-    TableFromSourceOption, TableFromSourceOptionName, TableOption, TableOptionName,
-    UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName, UnresolvedSchemaName, Value,
-    ViewDefinition, WithOptionValue,
+    CreateConnectionStatement, CreateConnectionType, CreateDatabaseStatement,
+    CreateFunctionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
+    CreateNetworkPolicyStatement, CreateRoleStatement, CreateSchemaStatement,
+    CreateSecretStatement, CreateSinkConnection, CreateSinkOption, CreateSinkOptionName,
+    CreateSinkStatement, CreateSourceConnection, CreateSourceOption, CreateSourceOptionName,
+    CreateSourceStatement, CreateSubsourceOption, CreateSubsourceOptionName,
+    CreateSubsourceStatement, CreateTableFromSourceStatement, CreateTableStatement, CreateTypeAs,
+    CreateTypeListOption, CreateTypeListOptionName, CreateTypeMapOption, CreateTypeMapOptionName,
+    CreateTypeStatement, CreateViewStatement, CreateWebhookSourceStatement, CsrConfigOption,
+    CsrConfigOptionName, CsrConnection, CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf,
+    CsvColumns, DeferredItemName, DocOnIdentifier, DocOnSchema, DropObjectsStatement,
+    DropOwnedStatement, Expr, Format, FormatSpecifier, FunctionReturnType, GlueAvroOption,
+    GlueAvroOptionName, IcebergSinkConfigOption, Ident, IfExistsBehavior, IndexOption,
+    IndexOptionName, KafkaSinkConfigOption, KeyConstraint, LoadGeneratorOption,
+    LoadGeneratorOptionName, MaterializedViewOption, MaterializedViewOptionName, MySqlConfigOption,
+    MySqlConfigOptionName, NetworkPolicyOption, NetworkPolicyOptionName,
+    NetworkPolicyRuleDefinition, NetworkPolicyRuleOption, NetworkPolicyRuleOptionName,
+    OnHydrationOptionValue, PgConfigOption, PgConfigOptionName, ProtobufSchema, QualifiedReplica,
+    RefreshAtOptionValue, RefreshEveryOptionValue, RefreshOptionValue, ReplicaDefinition,
+    ReplicaOption, ReplicaOptionName, RoleAttribute, SetRoleVar, SourceErrorPolicy,
+    SourceIncludeMetadata, SqlServerConfigOption, SqlServerConfigOptionName, Statement,
+    TableConstraint, TableFromSourceColumns, TableFromSourceOption, TableFromSourceOptionName,
+    TableOption, TableOptionName, UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName,
+    UnresolvedSchemaName, Value, ViewDefinition, WithOptionValue,
 };
 use mz_sql_parser::ident;
 use mz_sql_parser::parser::StatementParseResult;
@@ -110,7 +112,7 @@ use mz_storage_types::sources::mysql::{
     MySqlSourceConnection, MySqlSourceDetails, ProtoMySqlSourceDetails,
 };
 use mz_storage_types::sources::postgres::{
-    PostgresSourceConnection, PostgresSourcePublicationDetails,
+    PostgresSourceConnection, PostgresSourcePublicationDetails, PostgresTruncateBehavior,
     ProtoPostgresSourcePublicationDetails,
 };
 use mz_storage_types::sources::sql_server::{
@@ -118,9 +120,9 @@ use mz_storage_types::sources::sql_server::{
 };
 use mz_storage_types::sources::{
     GenericSourceConnection, MySqlSourceExportDetails, PostgresSourceExportDetails,
-    ProtoSourceExportStatementDetails, SourceConnection, SourceDesc, SourceExportDataConfig,
-    SourceExportDetails, SourceExportStatementDetails, SqlServerSourceConnection,
-    SqlServerSourceExtras, Timeline,
+    ProtoSourceExportStatementDetails, SchemaChangeBehavior, SourceConnection, SourceDesc,
+    SourceExportDataConfig, SourceExportDetails, SourceExportStatementDetails,
+    SqlServerSourceConnection, SqlServerSourceExtras, Timeline,
 };
 use mz_storage_types::wire_format::WireFormat;
 use prost::Message;
@@ -130,6 +132,7 @@ use crate::catalog::{
     CatalogCluster, CatalogDatabase, CatalogError, CatalogItem, CatalogItemType,
     CatalogRecordField, CatalogType, CatalogTypeDetails, ObjectType, SystemObjectType,
 };
+use crate::func;
 use crate::iceberg::IcebergSinkConfigOptionExtracted;
 use crate::kafka_util::{KafkaSinkConfigOptionExtracted, KafkaSourceConfigOptionExtracted};
 use crate::names::{
@@ -154,18 +157,18 @@ use crate::plan::{
     AlterOptionParameter, AlterRetainHistoryPlan, AlterRolePlan, AlterSchemaRenamePlan,
     AlterSchemaSwapPlan, AlterSecretPlan, AlterSetClusterPlan, AlterSinkPlan,
     AlterSourceTimestampIntervalPlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
-    AlterSystemSetPlan, AlterTablePlan, AutoScalingStrategy, ClusterSchedule, CommentPlan,
-    ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, ConnectionDetails,
-    CreateClusterManagedPlan, CreateClusterPlan, CreateClusterReplicaPlan,
+    AlterSystemSetPlan, AlterTablePlan, AlterTypeAddValuePlan, AutoScalingStrategy,
+    ClusterSchedule, CommentPlan, ComputeReplicaConfig, ComputeReplicaIntrospectionConfig,
+    ConnectionDetails, CreateClusterManagedPlan, CreateClusterPlan, CreateClusterReplicaPlan,
     CreateClusterUnmanagedPlan, CreateClusterVariant, CreateConnectionPlan, CreateDatabasePlan,
-    CreateIndexPlan, CreateMaterializedViewPlan, CreateNetworkPolicyPlan, CreateRolePlan,
-    CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan,
-    CreateTypePlan, CreateViewPlan, DataSourceDesc, DropObjectsPlan, DropOwnedPlan,
-    HirRelationExpr, Index, MaterializedView, NetworkPolicyRule, NetworkPolicyRuleAction,
-    NetworkPolicyRuleDirection, OnHydration, Plan, PlanClusterOption, PlanNotice, PolicyAddress,
-    QueryContext, ReplicaConfig, Secret, Sink, Source, Table, TableDataSource, Type, VariableValue,
-    View, WebhookBodyFormat, WebhookHeaderFilters, WebhookHeaders, WebhookValidation, literal,
-    plan_utils, query, transform_ast,
+    CreateFunctionPlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateNetworkPolicyPlan,
+    CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan,
+    CreateTablePlan, CreateTypePlan, CreateViewPlan, DataSourceDesc, DropObjectsPlan,
+    DropOwnedPlan, Function, HirRelationExpr, Index, MaterializedView, NetworkPolicyRule,
+    NetworkPolicyRuleAction, NetworkPolicyRuleDirection, OnHydration, Plan, PlanClusterOption,
+    PlanNotice, PolicyAddress, QueryContext, ReplicaConfig, Secret, Sink, Source, Table,
+    TableDataSource, Type, VariableValue, View, WebhookBodyFormat, WebhookHeaderFilters,
+    WebhookHeaders, WebhookValidation, literal, plan_utils, query, transform_ast,
 };
 use crate::session::vars::{
     self, ENABLE_AUTO_SCALING_STRATEGY, ENABLE_CLUSTER_SCHEDULE_REFRESH,
@@ -543,7 +546,12 @@ generate_extracted_config!(
     (Details, String),
     (Publication, String),
     (TextColumns, Vec::<UnresolvedItemName>, Default(vec![])),
-    (ExcludeColumns, Vec::<UnresolvedItemName>, Default(vec![]))
+    (ExcludeColumns, Vec::<UnresolvedItemName>, Default(vec![])),
+    (
+        TruncateBehavior,
+        PostgresTruncateBehavior,
+        Default(PostgresTruncateBehavior::Error)
+    )
 );
 
 generate_extracted_config!(
@@ -1173,6 +1181,7 @@ fn plan_postgres_source_connection(
         // in these options for round-tripping of a `CREATE SOURCE` statement. This should
         // be removed once we drop support for implicitly created subsources.
         exclude_columns: _,
+        truncate_behavior,
         seen: _,
     } = options.clone().try_into()?;
     let details = details
@@ -1189,6 +1198,7 @@ fn plan_postgres_source_connection(
         // Validated during purification.
         publication: publication.ok_or_else(|| internal_err!("PUBLICATION option is required"))?,
         publication_details,
+        truncate_behavior,
     })
 }
 
@@ -1205,16 +1215,21 @@ fn plan_kafka_source_connection(
             scx.catalog.resolve_full_name(connection_item.name())
         )
     }
+    let extracted: KafkaSourceConfigOptionExtracted = options.clone().try_into()?;
+    // Validated during purification.
+    let topics = extracted
+        .topics()?
+        .ok_or_else(|| internal_err!("TOPIC option is required"))?;
     let KafkaSourceConfigOptionExtracted {
         group_id_prefix,
-        topic,
+        topic: _,
+        topics: _,
+        topic_pattern: _,
         topic_metadata_refresh_interval,
         start_timestamp: _, // purified into `start_offset`
         start_offset,
         seen: _,
-    }: KafkaSourceConfigOptionExtracted = options.clone().try_into()?;
-    // Validated during purification.
-    let topic = topic.ok_or_else(|| internal_err!("TOPIC option is required"))?;
+    } = extracted;
     let mut start_offsets = BTreeMap::new();
     if let Some(offsets) = start_offset {
         for (part, offset) in offsets.iter().enumerate() {
@@ -1244,6 +1259,13 @@ fn plan_kafka_source_connection(
                 };
                 Some((name, KafkaMetadataKind::Timestamp))
             }
+            SourceIncludeMetadata::Topic { alias } => {
+                let name = match alias {
+                    Some(name) => name.to_string(),
+                    None => "topic".to_owned(),
+                };
+                Some((name, KafkaMetadataKind::Topic))
+            }
             SourceIncludeMetadata::Partition { alias } => {
                 let name = match alias {
                     Some(name) => name.to_string(),
@@ -1285,7 +1307,7 @@ fn plan_kafka_source_connection(
     Ok(KafkaSourceConnection {
         connection: connection_item.id(),
         connection_id: connection_item.id(),
-        topic,
+        topics,
         start_offsets,
         group_id_prefix,
         topic_metadata_refresh_interval,
@@ -1587,7 +1609,8 @@ generate_extracted_config!(
     (RetainHistory, OptionalDuration),
     (TextColumns, Vec::<Ident>, Default(vec![])),
     (ExcludeColumns, Vec::<Ident>, Default(vec![])),
-    (Details, String)
+    (Details, String),
+    (TruncateBehavior, PostgresTruncateBehavior)
 );
 
 pub fn plan_create_subsource(
@@ -1610,6 +1633,7 @@ pub fn plan_create_subsource(
         text_columns,
         exclude_columns,
         details,
+        truncate_behavior,
         seen: _,
     } = with_options.clone().try_into()?;
 
@@ -1653,6 +1677,11 @@ pub fn plan_create_subsource(
             ProtoSourceExportStatementDetails::decode(&*details).map_err(|e| sql_err!("{}", e))?;
         let details =
             SourceExportStatementDetails::from_proto(details).map_err(|e| sql_err!("{}", e))?;
+        if truncate_behavior.is_some()
+            && !matches!(details, SourceExportStatementDetails::Postgres { .. })
+        {
+            sql_bail!("TRUNCATE BEHAVIOR is only supported for PostgreSQL sources");
+        }
         let details = match details {
             SourceExportStatementDetails::Postgres {
                 table,
@@ -1662,9 +1691,19 @@ pub fn plan_create_subsource(
                     scx,
                     &table,
                     &text_columns,
+                    &BTreeMap::new(),
+                    false,
                     cast_oid_full_range,
                 )?,
                 table,
+                // Subsources created alongside their source have the source's
+                // behavior propagated to them during planning.
+                truncate_behavior: truncate_behavior.unwrap_or_default(),
+                schema_change_behavior: SchemaChangeBehavior::default(),
+                exclude_columns: exclude_columns
+                    .into_iter()
+                    .map(|c| c.into_string())
+                    .collect(),
             }),
             SourceExportStatementDetails::MySql {
                 table,
@@ -1679,6 +1718,7 @@ pub fn plan_create_subsource(
                     .map(|c| c.into_string())
                     .collect(),
                 binlog_full_metadata,
+                schema_change_behavior: SchemaChangeBehavior::default(),
             }),
             SourceExportStatementDetails::SqlServer {
                 table,
@@ -1693,6 +1733,7 @@ pub fn plan_create_subsource(
                     .into_iter()
                     .map(|c| c.into_string())
                     .collect(),
+                schema_change_behavior: SchemaChangeBehavior::default(),
             }),
             SourceExportStatementDetails::LoadGenerator { output } => {
                 SourceExportDetails::LoadGenerator(LoadGeneratorSourceExportDetails { output })
@@ -1746,9 +1787,12 @@ generate_extracted_config!(
     TableFromSourceOption,
     (TextColumns, Vec::<Ident>, Default(vec![])),
     (ExcludeColumns, Vec::<Ident>, Default(vec![])),
+    (EnumColumns, Vec::<Ident>, Default(vec![])),
     (PartitionBy, Vec<Ident>),
     (RetainHistory, OptionalDuration),
-    (Details, String)
+    (Details, String),
+    (TruncateBehavior, PostgresTruncateBehavior),
+    (SchemaChangeBehavior, SchemaChangeBehavior)
 );
 
 pub fn plan_create_table_from_source(
@@ -1777,12 +1821,43 @@ pub fn plan_create_table_from_source(
     let TableFromSourceOptionExtracted {
         text_columns,
         exclude_columns,
+        enum_columns,
         retain_history,
         partition_by,
         details,
+        truncate_behavior,
+        schema_change_behavior,
         seen: _,
     } = with_options.clone().try_into()?;
 
+    // Purification records the enum type of each column named in `ENUM
+    // COLUMNS` in the column definitions.
+    let enum_column_types = match columns {
+        TableFromSourceColumns::Defined(defs) => defs
+            .iter()
+            .filter(|def| enum_columns.contains(&def.name))
+            .map(|def| {
+                let ty = scalar_type_from_sql(scx, &def.data_type)?;
+                if !matches!(ty, SqlScalarType::Enum { .. }) {
+                    sql_bail!(
+                        "ENUM COLUMNS refers to column {}, which does not have an enum type",
+                        def.name
+                    );
+                }
+                Ok((def.name.to_string(), ty))
+            })
+            .collect::<Result<BTreeMap<_, _>, PlanError>>()?,
+        _ => BTreeMap::new(),
+    };
+    if enum_column_types.len() != enum_columns.len() {
+        sql_bail!("ENUM COLUMNS refers to columns not in the table");
+    }
+    // The labels of enum columns are fixed when the table is created, so they
+    // could not follow values that are added to the enum type upstream.
+    if !enum_columns.is_empty() && schema_change_behavior == Some(SchemaChangeBehavior::Evolve) {
+        sql_bail!("ENUM COLUMNS cannot be used with SCHEMA CHANGE BEHAVIOR = EVOLVE");
+    }
+
     let source_item = scx.get_item_by_resolved_name(source)?;
     let ingestion_id = source_item.id();
 
@@ -1813,6 +1888,28 @@ pub fn plan_create_table_from_source(
     {
         bail_unsupported!("INCLUDE metadata with non-Kafka source table");
     }
+    if truncate_behavior.is_some()
+        && !matches!(details, SourceExportStatementDetails::Postgres { .. })
+    {
+        sql_bail!("TRUNCATE BEHAVIOR is only supported for PostgreSQL sources");
+    }
+    if schema_change_behavior.is_some()
+        && !matches!(
+            details,
+            SourceExportStatementDetails::Postgres { .. }
+                | SourceExportStatementDetails::MySql { .. }
+                | SourceExportStatementDetails::SqlServer { .. }
+        )
+    {
+        sql_bail!(
+            "SCHEMA CHANGE BEHAVIOR is only supported for PostgreSQL, MySQL and SQL Server sources"
+        );
+    }
+
+    let source_connection = &source_item
+        .source_desc()?
+        .ok_or_else(|| sql_err!("item is not a source"))?
+        .connection;
 
     let details = match details {
         SourceExportStatementDetails::Postgres {
@@ -1823,9 +1920,24 @@ pub fn plan_create_table_from_source(
                 scx,
                 &table,
                 &text_columns,
+                &enum_column_types,
+                schema_change_behavior == Some(SchemaChangeBehavior::Evolve),
                 cast_oid_full_range,
             )?,
             table,
+            // Tables without their own behavior inherit the source's.
+            truncate_behavior: match (truncate_behavior, source_connection) {
+                (Some(behavior), _) => behavior,
+                (None, GenericSourceConnection::Postgres(connection)) => {
+                    connection.truncate_behavior
+                }
+                (None, _) => PostgresTruncateBehavior::default(),
+            },
+            schema_change_behavior: schema_change_behavior.unwrap_or_default(),
+            exclude_columns: exclude_columns
+                .into_iter()
+                .map(|c| c.into_string())
+                .collect(),
         }),
         SourceExportStatementDetails::MySql {
             table,
@@ -1840,6 +1952,7 @@ pub fn plan_create_table_from_source(
                 .map(|c| c.into_string())
                 .collect(),
             binlog_full_metadata,
+            schema_change_behavior: schema_change_behavior.unwrap_or_default(),
         }),
         SourceExportStatementDetails::SqlServer {
             table,
@@ -1854,6 +1967,7 @@ pub fn plan_create_table_from_source(
                 .into_iter()
                 .map(|c| c.into_string())
                 .collect(),
+            schema_change_behavior: schema_change_behavior.unwrap_or_default(),
         }),
         SourceExportStatementDetails::LoadGenerator { output } => {
             SourceExportDetails::LoadGenerator(LoadGeneratorSourceExportDetails { output })
@@ -1881,6 +1995,13 @@ pub fn plan_create_table_from_source(
                         };
                         Some((name, KafkaMetadataKind::Timestamp))
                     }
+                    SourceIncludeMetadata::Topic { alias } => {
+                        let name = match alias {
+                            Some(name) => name.to_string(),
+                            None => "topic".to_owned(),
+                        };
+                        Some((name, KafkaMetadataKind::Topic))
+                    }
                     SourceIncludeMetadata::Partition { alias } => {
                         let name = match alias {
                             Some(name) => name.to_string(),
@@ -1924,28 +2045,45 @@ pub fn plan_create_table_from_source(
         }
     };
 
-    let source_connection = &source_item
-        .source_desc()?
-        .ok_or_else(|| sql_err!("item is not a source"))?
-        .connection;
-
     // Some source-types (e.g. postgres, mysql, multi-output load-gen sources) define a value_schema
     // during purification and define the `columns` and `constraints` fields for the statement,
     // whereas other source-types (e.g. kafka, single-output load-gen sources) do not, so instead
     // we use the source connection's default schema.
+    //
+    // Columns that were added by evolving the schema of the table are planned
+    // as later versions of its description further below.
+    let mut added_columns = vec![];
     let (key_desc, value_desc) =
         if matches!(columns, TableFromSourceColumns::Defined(_)) || !constraints.is_empty() {
             let columns = match columns {
                 TableFromSourceColumns::Defined(columns) => columns,
                 _ => bail_internal!("expected column definitions to be present"),
             };
-            let desc = plan_source_export_desc(scx, name, columns, constraints)?;
+            let (versioned, columns): (Vec<_>, Vec<_>) = columns.iter().cloned().partition(|c| {
+                c.options
+                    .iter()
+                    .any(|o| matches!(o.option, ColumnOption::Versioned { .. }))
+            });
+            added_columns = versioned;
+            let desc = plan_source_export_desc(scx, name, &columns, constraints)?;
             (None, desc)
         } else {
             let key_desc = source_connection.default_key_desc();
             let value_desc = source_connection.default_value_desc();
             (Some(key_desc), value_desc)
         };
+    if !added_columns.is_empty()
+        && !matches!(
+            details,
+            SourceExportDetails::Postgres(_)
+                | SourceExportDetails::MySql(_)
+                | SourceExportDetails::SqlServer(_)
+        )
+    {
+        bail_internal!(
+            "only tables from PostgreSQL, MySQL and SQL Server sources can have added columns"
+        );
+    }
 
     let metadata_columns_desc = match &details {
         SourceExportDetails::Kafka(KafkaSourceExportDetails {
@@ -1968,13 +2106,46 @@ pub fn plan_create_table_from_source(
         plan_utils::maybe_rename_columns(format!("source table {}", name), &mut desc, col_names)?;
     }
 
-    let names: Vec<_> = desc.iter_names().cloned().collect();
+    let names: Vec<_> = desc
+        .iter_names()
+        .cloned()
+        .chain(
+            added_columns
+                .iter()
+                .map(|c| normalize::column_name(c.name.clone())),
+        )
+        .collect();
     if let Some(dup) = names.iter().duplicates().next() {
         sql_bail!("column {} specified more than once", dup.quoted());
     }
 
     let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name.clone())?)?;
 
+    // Table options should only consider the original columns, since those
+    // were the only ones in scope when the table was created.
+    let root_desc = desc.clone();
+    let mut desc = VersionedRelationDesc::new(desc);
+    for column in added_columns {
+        let version = column
+            .options
+            .iter()
+            .find_map(|o| match &o.option {
+                ColumnOption::Versioned { action: _, version } => Some(*version),
+                _ => None,
+            })
+            .expect("partitioned on versioned columns");
+        if column.options.len() != 1 {
+            bail_unsupported!("Source export with constraints on added columns");
+        }
+        let typ = scalar_type_from_sql(scx, &column.data_type)?;
+        let new_version = desc.add_column(normalize::column_name(column.name), typ.nullable(true));
+        if RelationVersion::from(version) != new_version {
+            return Err(PlanError::InvalidTable {
+                name: scx.catalog.resolve_full_name(&name).item,
+            });
+        }
+    }
+
     // Allow users to specify a timeline. If they do not, determine a default
     // timeline for the source.
     let timeline = match envelope {
@@ -1986,7 +2157,7 @@ pub fn plan_create_table_from_source(
 
     if let Some(partition_by) = partition_by {
         scx.require_feature_flag(&ENABLE_COLLECTION_PARTITION_BY)?;
-        check_partition_by(&desc, partition_by)?;
+        check_partition_by(&root_desc, partition_by)?;
     }
 
     let data_source = DataSourceDesc::IngestionExport {
@@ -2007,7 +2178,7 @@ pub fn plan_create_table_from_source(
     let compaction_window = plan_retain_history_option(scx, retain_history)?;
     let table = Table {
         create_sql,
-        desc: VersionedRelationDesc::new(desc),
+        desc,
         temporary: false,
         compaction_window,
         data_source: TableDataSource::DataSource {
@@ -4519,6 +4690,16 @@ pub fn plan_create_type(
             }
             CatalogType::Record { fields }
         }
+        CreateTypeAs::Enum { labels } => {
+            let mut seen = BTreeSet::new();
+            for label in &labels {
+                validate_enum_label(label)?;
+                if !seen.insert(label) {
+                    sql_bail!("enum label {} used more than once", label.quoted());
+                }
+            }
+            CatalogType::Enum { labels }
+        }
     };
 
     let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
@@ -4543,6 +4724,134 @@ pub fn plan_create_type(
     }))
 }
 
+/// Enum labels share PostgreSQL's identifier length limit.
+fn validate_enum_label(label: &str) -> Result<(), PlanError> {
+    const MAX_ENUM_LABEL_BYTES: usize = 63;
+    if label.len() > MAX_ENUM_LABEL_BYTES {
+        sql_bail!(
+            "invalid enum label {}: labels must be {} bytes or less",
+            label.quoted(),
+            MAX_ENUM_LABEL_BYTES
+        );
+    }
+    Ok(())
+}
+
+pub fn describe_create_function(
+    _: &StatementContext,
+    _: CreateFunctionStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_function(
+    scx: &StatementContext,
+    stmt: CreateFunctionStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let create_sql = normalize::create_statement(scx, Statement::CreateFunction(stmt.clone()))?;
+    let CreateFunctionStatement {
+        name,
+        parameters,
+        returns,
+        body: _,
+    } = stmt;
+
+    // Function signatures are exposed through `mz_functions` and `pg_proc`,
+    // which only describe builtin types.
+    fn function_type_from_sql(
+        scx: &StatementContext,
+        data_type: &ResolvedDataType,
+    ) -> Result<SqlScalarType, PlanError> {
+        fn is_custom(data_type: &ResolvedDataType) -> bool {
+            match data_type {
+                ResolvedDataType::AnonymousList(element_type) => is_custom(element_type),
+                ResolvedDataType::AnonymousMap {
+                    key_type,
+                    value_type,
+                } => is_custom(key_type) || is_custom(value_type),
+                ResolvedDataType::Named { id, .. } => id.is_user(),
+                ResolvedDataType::Error => false,
+            }
+        }
+        if is_custom(data_type) {
+            bail_unsupported!("custom types in function signatures");
+        }
+        scalar_type_from_sql(scx, data_type)
+    }
+
+    let mut params = vec![];
+    for param in &parameters {
+        let name = param.name.clone().map(normalize::ident);
+        if let Some(name) = &name {
+            if params.iter().any(|(n, _)| n.as_ref() == Some(name)) {
+                sql_bail!("parameter name {} used more than once", name.quoted());
+            }
+        }
+        params.push((name, function_type_from_sql(scx, &param.data_type)?));
+    }
+
+    let returns = match &returns {
+        FunctionReturnType::Scalar(data_type) => {
+            func::SqlFuncReturnType::Scalar(function_type_from_sql(scx, data_type)?)
+        }
+        FunctionReturnType::Table(columns) => {
+            let mut out = vec![];
+            for column in columns {
+                let name = normalize::column_name(column.name.clone());
+                if out.iter().any(|(n, _)| n == &name) {
+                    sql_bail!("column {} specified more than once", name.quoted());
+                }
+                out.push((name, function_type_from_sql(scx, &column.data_type)?));
+            }
+            func::SqlFuncReturnType::Table(out)
+        }
+    };
+    let return_types: Vec<_> = match &returns {
+        func::SqlFuncReturnType::Scalar(ty) => vec![ty.clone()],
+        func::SqlFuncReturnType::Table(columns) => {
+            columns.iter().map(|(_name, ty)| ty.clone()).collect()
+        }
+    };
+
+    // The body is stored unresolved, as it appears in the normalized
+    // `create_sql`, and is re-planned at each call site. Its references are
+    // pinned by ID, so this is robust to renames of the objects it depends on.
+    let body = match parse::parse(&create_sql)
+        .expect("valid create_sql")
+        .into_element()
+        .ast
+    {
+        Statement::CreateFunction(stmt) => stmt.body,
+        _ => unreachable!("create_sql is a CREATE FUNCTION statement"),
+    };
+
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
+    let full_name = scx.catalog.resolve_full_name(&name);
+
+    // Plan the body once up front, so that errors are reported at creation
+    // time rather than when the function is first called.
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    query::plan_sql_function_body(&qcx, &full_name.item, &params, &return_types, &body)?;
+
+    // Check for a function in the catalog with this same name.
+    let partial_name = PartialItemName::from(full_name.clone());
+    if let Ok(item) = scx.catalog.resolve_function(&partial_name) {
+        return Err(PlanError::ItemAlreadyExists {
+            name: full_name.to_string(),
+            item_type: item.item_type(),
+        });
+    }
+
+    let inner = func::sql_user_func(full_name.item, params, returns, body);
+    Ok(Plan::CreateFunction(CreateFunctionPlan {
+        name,
+        function: Function {
+            create_sql,
+            inner: Arc::new(inner),
+        },
+    }))
+}
+
 generate_extracted_config!(CreateTypeListOption, (ElementType, ResolvedDataType));
 
 generate_extracted_config!(
@@ -5743,9 +6052,6 @@ pub fn plan_drop_objects(
         cascade,
     }: DropObjectsStatement,
 ) -> Result<Plan, PlanError> {
-    if object_type == mz_sql_parser::ast::ObjectType::Func {
-        bail_unsupported!("DROP FUNCTION");
-    }
     let object_type = object_type.into();
 
     let mut referenced_ids = Vec::new();
@@ -7491,6 +7797,129 @@ pub fn plan_alter_secret(
     Ok(Plan::AlterSecret(AlterSecretPlan { id, secret_as }))
 }
 
+pub fn describe_alter_type_add_value(
+    _: &StatementContext,
+    _: AlterTypeAddValueStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_type_add_value(
+    scx: &StatementContext,
+    stmt: AlterTypeAddValueStatement,
+) -> Result<Plan, PlanError> {
+    let AlterTypeAddValueStatement {
+        name,
+        if_exists,
+        if_not_exists,
+        value,
+        position,
+    } = stmt;
+    let object_type = ObjectType::Type;
+    let Some(item) = resolve_item_or_type(scx, object_type, name.clone(), if_exists)? else {
+        scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+            name: name.to_ast_string_simple(),
+            object_type,
+        });
+        return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+    };
+    let id = item.id();
+    let full_name = scx.catalog.resolve_full_name(item.name());
+
+    let labels = match item.type_details() {
+        Some(CatalogTypeDetails {
+            typ: CatalogType::Enum { labels },
+            ..
+        }) => labels,
+        _ => sql_bail!("{} is not an enum", full_name.to_string().quoted()),
+    };
+
+    validate_enum_label(&value)?;
+    if labels.contains(&value) {
+        if if_not_exists {
+            scx.catalog
+                .add_notice(PlanNotice::EnumLabelAlreadyExists { label: value });
+            return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+        }
+        sql_bail!("enum label {} already exists", value.quoted());
+    }
+
+    // Labels are stored by ordinal, so new labels can only be appended without
+    // rewriting existing data.
+    if let Some(position) = position {
+        let (keyword, neighbor) = match &position {
+            AlterTypeAddValuePosition::Before(label) => ("BEFORE", label),
+            AlterTypeAddValuePosition::After(label) => ("AFTER", label),
+        };
+        if !labels.contains(neighbor) {
+            sql_bail!("{} is not an existing enum label", neighbor.quoted());
+        }
+        bail_unsupported!(format!("ALTER TYPE ... ADD VALUE ... {keyword}"));
+    }
+
+    // Dependents are re-planned when the type changes, but running dataflows
+    // and source ingestions keep the labels they were rendered with. Only
+    // permit dependents that either have no dataflow or merely arrange the
+    // enum values of a table.
+    let mut seen = BTreeSet::new();
+    let mut dependents = item.used_by().to_vec();
+    while let Some(dependent_id) = dependents.pop() {
+        if !seen.insert(dependent_id) {
+            continue;
+        }
+        let dependent = scx.catalog.get_item(&dependent_id);
+        let permitted = match dependent.item_type() {
+            CatalogItemType::View | CatalogItemType::Type | CatalogItemType::Func => true,
+            CatalogItemType::Table => dependent.writable_table_details().is_some(),
+            CatalogItemType::Index => {
+                let (keys, _) = dependent.index_details().expect("item type is index");
+                keys.iter()
+                    .all(|key| matches!(key, mz_expr::MirScalarExpr::Column(..)))
+                    && dependent
+                        .uses()
+                        .iter()
+                        .all(|id| scx.catalog.get_item(id).writable_table_details().is_some())
+            }
+            _ => false,
+        };
+        if !permitted {
+            sql_bail!(
+                "cannot add a value to enum type {} because {} {} depends on it",
+                full_name.to_string().quoted(),
+                dependent.item_type(),
+                scx.catalog
+                    .resolve_full_name(dependent.name())
+                    .to_string()
+                    .quoted()
+            );
+        }
+        dependents.extend_from_slice(dependent.used_by());
+    }
+
+    // Reconstruct the original CREATE TYPE statement and append the label.
+    let stmts = mz_sql_parser::parser::parse_statements(item.create_sql())?;
+    let [stmt]: [StatementParseResult; 1] = stmts
+        .try_into()
+        .map_err(|_| internal_err!("create SQL of type was not exactly one statement"))?;
+    let Statement::CreateType(stmt) = stmt.ast else {
+        bail_internal!("create SQL of type is not a CREATE TYPE statement");
+    };
+    let (mut stmt, _) = crate::names::resolve(scx.catalog, stmt)?;
+    let CreateTypeAs::Enum { labels } = &mut stmt.as_type else {
+        bail_internal!("create SQL of enum type is not a CREATE TYPE ... AS ENUM statement");
+    };
+    labels.push(value);
+    let inner = CatalogType::Enum {
+        labels: labels.clone(),
+    };
+    let create_sql = normalize::create_statement(scx, Statement::CreateType(stmt))?;
+
+    Ok(Plan::AlterTypeAddValue(AlterTypeAddValuePlan {
+        id,
+        typ: Type { create_sql, inner },
+    }))
+}
+
 pub fn describe_alter_connection(
     _: &StatementContext,
     _: AlterConnectionStatement<Aug>,
@@ -8364,6 +8793,7 @@ pub(crate) fn resolve_item_or_type<'a>(
     let name = normalize::unresolved_item_name(name)?;
     let catalog_item = match object_type {
         ObjectType::Type => scx.catalog.resolve_type(&name),
+        ObjectType::Func => scx.catalog.resolve_function(&name),
         ObjectType::Table
         | ObjectType::View
         | ObjectType::MaterializedView
@@ -8377,7 +8807,6 @@ pub(crate) fn resolve_item_or_type<'a>(
         | ObjectType::Connection
         | ObjectType::Database
         | ObjectType::Schema
-        | ObjectType::Func
         | ObjectType::NetworkPolicy => scx.catalog.resolve_item(&name),
     };
 
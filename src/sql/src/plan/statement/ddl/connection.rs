@@ -121,7 +121,7 @@ pub(super) fn validate_options_per_connection_type(
         .as_slice(),
         CreateConnectionType::AwsPrivatelink => &[AvailabilityZones, Port, ServiceName],
         CreateConnectionType::GlueSchemaRegistry => &[AwsConnection, Registry],
-        CreateConnectionType::Gcp => &[ServiceAccountKey],
+        CreateConnectionType::Gcp => &[Endpoint, ServiceAccountKey],
         CreateConnectionType::Csr => &[
             AwsPrivatelink,
             Password,
@@ -325,7 +325,10 @@ impl ConnectionOptionExtracted {
                     .service_account_key
                     .ok_or_else(|| sql_err!("SERVICE ACCOUNT KEY option is required"))?
                     .into();
-                ConnectionDetails::Gcp(GcpConnection { credentials_json })
+                ConnectionDetails::Gcp(GcpConnection {
+                    credentials_json,
+                    endpoint: self.endpoint.filter(|endpoint| !endpoint.is_empty()),
+                })
             }
             CreateConnectionType::Kafka => {
                 let (tls, sasl) = plan_kafka_security(scx, &self)?;
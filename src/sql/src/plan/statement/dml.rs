@@ -22,7 +22,9 @@ use mz_ore::error::ErrorExt;
 use mz_ore::num::NonNeg;
 use mz_ore::soft_panic_or_log;
 use mz_ore::str::separated;
-use mz_pgcopy::{CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams};
+use mz_pgcopy::{
+    CopyCsvFormatParams, CopyFormatParams, CopyJsonFormatParams, CopyTextFormatParams,
+};
 use mz_repr::adt::numeric::NumericMaxScale;
 use mz_repr::bytes::ByteSize;
 use mz_repr::explain::{ExplainConfig, ExplainFormat};
@@ -44,9 +46,9 @@ use mz_storage_types::sinks::{
 use crate::ast::display::{AstDisplay, escaped_string_literal};
 use crate::ast::{
     AstInfo, CopyDirection, CopyOption, CopyOptionName, CopyRelation, CopyStatement, CopyTarget,
-    DeleteStatement, ExplainPlanStatement, ExplainStage, Explainee, Ident, InsertStatement, Query,
-    SelectStatement, SubscribeOption, SubscribeOptionName, SubscribeRelation, SubscribeStatement,
-    UpdateStatement,
+    DeleteStatement, ExplainPlanStatement, ExplainStage, Explainee, Ident, InsertStatement,
+    MergeAction, MergeStatement, OnConflictAction, Query, SelectStatement, SubscribeOption,
+    SubscribeOptionName, SubscribeRelation, SubscribeStatement, UpdateStatement,
 };
 use crate::catalog::CatalogItemType;
 use crate::names::{Aug, ResolvedItemName};
@@ -81,10 +83,15 @@ pub fn describe_insert(
         table_name,
         columns,
         source,
+        on_conflict,
         returning,
     }: InsertStatement<Aug>,
 ) -> Result<StatementDesc, PlanError> {
-    let (_, _, returning) = query::plan_insert_query(scx, table_name, columns, source, returning)?;
+    let (_, values, returning) =
+        query::plan_insert_query(scx, table_name.clone(), columns, source, returning)?;
+    if let Some(on_conflict) = on_conflict {
+        query::plan_on_conflict_query(scx, table_name, values, on_conflict)?;
+    }
     let desc = if returning.expr.is_empty() {
         None
     } else {
@@ -99,13 +106,13 @@ pub fn plan_insert(
         table_name,
         columns,
         source,
+        on_conflict,
         returning,
     }: InsertStatement<Aug>,
     params: &Params,
 ) -> Result<Plan, PlanError> {
     let (id, mut expr, returning) =
-        query::plan_insert_query(scx, table_name, columns, source, returning)?;
-    expr.bind_parameters_and_simplify_offset(scx, QueryLifetime::OneShot, params)?;
+        query::plan_insert_query(scx, table_name.clone(), columns, source, returning)?;
     let returning = returning
         .expr
         .into_iter()
@@ -115,6 +122,17 @@ pub fn plan_insert(
         })
         .collect::<Result<Vec<_>, _>>()?;
 
+    // Inserts that may conflict with existing rows must read the table, so
+    // they are sequenced as read-then-writes.
+    if let Some(on_conflict) = on_conflict {
+        let kind = MutationKind::Upsert {
+            update: matches!(on_conflict.action, OnConflictAction::DoUpdate { .. }),
+        };
+        let rtw_plan = query::plan_on_conflict_query(scx, table_name, expr, on_conflict)?;
+        return plan_read_then_write(scx, kind, params, rtw_plan, returning);
+    }
+
+    expr.bind_parameters_and_simplify_offset(scx, QueryLifetime::OneShot, params)?;
     Ok(Plan::Insert(InsertPlan {
         id,
         values: expr,
@@ -136,7 +154,7 @@ pub fn plan_delete(
     params: &Params,
 ) -> Result<Plan, PlanError> {
     let rtw_plan = query::plan_delete_query(scx, stmt)?;
-    plan_read_then_write(scx, MutationKind::Delete, params, rtw_plan)
+    plan_read_then_write(scx, MutationKind::Delete, params, rtw_plan, Vec::new())
 }
 
 pub fn describe_update(
@@ -153,7 +171,44 @@ pub fn plan_update(
     params: &Params,
 ) -> Result<Plan, PlanError> {
     let rtw_plan = query::plan_update_query(scx, stmt)?;
-    plan_read_then_write(scx, MutationKind::Update, params, rtw_plan)
+    plan_read_then_write(scx, MutationKind::Update, params, rtw_plan, Vec::new())
+}
+
+pub fn describe_merge(
+    scx: &StatementContext,
+    stmt: MergeStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    let (_, returning) = query::plan_merge_query(scx, stmt)?;
+    let desc = if returning.expr.is_empty() {
+        None
+    } else {
+        Some(returning.desc)
+    };
+    Ok(StatementDesc::new(desc))
+}
+
+pub fn plan_merge(
+    scx: &StatementContext,
+    stmt: MergeStatement<Aug>,
+    params: &Params,
+) -> Result<Plan, PlanError> {
+    let has_action =
+        |f: fn(&MergeAction<Aug>) -> bool| stmt.clauses.iter().any(|clause| f(&clause.action));
+    let kind = MutationKind::Merge {
+        insert: has_action(|action| matches!(action, MergeAction::Insert { .. })),
+        update: has_action(|action| matches!(action, MergeAction::Update { .. })),
+        delete: has_action(|action| matches!(action, MergeAction::Delete)),
+    };
+    let (rtw_plan, returning) = query::plan_merge_query(scx, stmt)?;
+    let returning = returning
+        .expr
+        .into_iter()
+        .map(|mut expr| {
+            expr.bind_parameters_and_simplify_offset(scx, QueryLifetime::OneShot, params)?;
+            expr.lower_uncorrelated(scx.catalog.system_vars())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    plan_read_then_write(scx, kind, params, rtw_plan, returning)
 }
 
 pub fn plan_read_then_write(
@@ -166,6 +221,7 @@ pub fn plan_read_then_write(
         finishing,
         assignments,
     }: query::ReadThenWritePlan,
+    returning: Vec<mz_expr::MirScalarExpr>,
 ) -> Result<Plan, PlanError> {
     selection.bind_parameters_and_simplify_offset(scx, QueryLifetime::OneShot, params)?;
     let mut assignments_outer = BTreeMap::new();
@@ -181,7 +237,7 @@ pub fn plan_read_then_write(
         finishing,
         assignments: assignments_outer,
         kind,
-        returning: Vec::new(),
+        returning,
     }))
 }
 
@@ -1879,15 +1935,22 @@ fn plan_copy_to_expr(
     format: CopyFormat,
     options: CopyOptionExtracted,
 ) -> Result<Plan, PlanError> {
-    let conn_id = match options.aws_connection {
-        Some(conn_id) => CatalogItemId::from(conn_id),
-        None => sql_bail!("AWS CONNECTION is required for COPY ... TO <expr>"),
+    let conn_id = match (options.aws_connection, options.gcp_connection) {
+        (Some(_), Some(_)) => {
+            sql_bail!("cannot specify both AWS CONNECTION and GCP CONNECTION")
+        }
+        (Some(conn_id), None) | (None, Some(conn_id)) => CatalogItemId::from(conn_id),
+        (None, None) => {
+            sql_bail!("AWS CONNECTION or GCP CONNECTION is required for COPY ... TO <expr>")
+        }
     };
     let connection = scx.get_item(&conn_id).connection()?;
 
-    match connection {
-        mz_storage_types::connections::Connection::Aws(_) => {}
-        _ => sql_bail!("only AWS CONNECTION is supported for COPY ... TO <expr>"),
+    match (connection, options.gcp_connection.is_some()) {
+        (mz_storage_types::connections::Connection::Aws(_), false) => {}
+        (mz_storage_types::connections::Connection::Gcp(_), true) => {}
+        (_, false) => sql_bail!("AWS CONNECTION must reference an AWS connection"),
+        (_, true) => sql_bail!("GCP CONNECTION must reference a GCP connection"),
     }
 
     let format = match format {
@@ -1915,6 +1978,8 @@ fn plan_copy_to_expr(
         }
         CopyFormat::Binary => bail_unsupported!("FORMAT BINARY"),
         CopyFormat::Text => bail_unsupported!("FORMAT TEXT"),
+        CopyFormat::Json => bail_unsupported!("COPY TO with FORMAT JSON"),
+        CopyFormat::Avro => bail_unsupported!("COPY TO with FORMAT AVRO"),
     };
 
     // Converting the to expr to a HirScalarExpr
@@ -1973,6 +2038,13 @@ fn plan_copy_from(
         }
     }
 
+    fn only_available_with_text_or_csv<T>(option: Option<T>, param: &str) -> Result<(), PlanError> {
+        match option {
+            Some(_) => sql_bail!("COPY {} available only in TEXT and CSV mode", param),
+            None => Ok(()),
+        }
+    }
+
     let source = match target {
         CopyTarget::Stdin => CopyFromSource::Stdin,
         CopyTarget::Expr(from) => {
@@ -1992,8 +2064,11 @@ fn plan_copy_from(
             };
             let from = plan_expr(ecx, &from_expr)?.type_as(ecx, &SqlScalarType::String)?;
 
-            match options.aws_connection {
-                Some(conn_id) => {
+            match (options.aws_connection, options.gcp_connection) {
+                (Some(_), Some(_)) => {
+                    sql_bail!("cannot specify both AWS CONNECTION and GCP CONNECTION")
+                }
+                (Some(conn_id), None) => {
                     let conn_id = CatalogItemId::from(conn_id);
 
                     // Validate the connection type is one we expect.
@@ -2008,24 +2083,45 @@ fn plan_copy_from(
                         connection_id: conn_id,
                     }
                 }
-                None => CopyFromSource::Url(from),
+                (None, Some(conn_id)) => {
+                    let conn_id = CatalogItemId::from(conn_id);
+
+                    let connection = match scx.get_item(&conn_id).connection()? {
+                        mz_storage_types::connections::Connection::Gcp(conn) => conn,
+                        _ => sql_bail!("GCP CONNECTION must reference a GCP connection"),
+                    };
+
+                    CopyFromSource::Gcs {
+                        uri: from,
+                        connection,
+                        connection_id: conn_id,
+                    }
+                }
+                (None, None) => CopyFromSource::Url(from),
             }
         }
         CopyTarget::Stdout => bail_never_supported!("COPY FROM {} not supported", target),
     };
 
-    // COPY FROM a URL or S3 bucket only supports CSV and Parquet. Unlike COPY
-    // FROM STDIN there's no sensible default format, so one must be specified
-    // explicitly. Reject unsupported formats here in planning; the coordinator
+    // COPY FROM a URL or S3 bucket only supports CSV, Parquet, JSON, and Avro.
+    // Unlike COPY FROM STDIN there's no sensible default format, so one must be
+    // specified explicitly. Reject unsupported formats here in planning; the coordinator
     // relies on this and would otherwise soft-panic.
     let format = match &source {
         CopyFromSource::Stdin => format.unwrap_or(CopyFormat::Text),
-        CopyFromSource::Url(_) | CopyFromSource::AwsS3 { .. } => match format {
-            None => sql_bail!("COPY FROM <expr> requires a FORMAT option"),
-            Some(CopyFormat::Text) => bail_unsupported!("FORMAT TEXT"),
-            Some(CopyFormat::Binary) => bail_unsupported!("FORMAT BINARY"),
-            Some(format @ (CopyFormat::Csv | CopyFormat::Parquet)) => format,
-        },
+        CopyFromSource::Url(_) | CopyFromSource::AwsS3 { .. } | CopyFromSource::Gcs { .. } => {
+            match format {
+                None => sql_bail!("COPY FROM <expr> requires a FORMAT option"),
+                Some(CopyFormat::Text) => bail_unsupported!("FORMAT TEXT"),
+                Some(CopyFormat::Binary) => bail_unsupported!("FORMAT BINARY"),
+                Some(
+                    format @ (CopyFormat::Csv
+                    | CopyFormat::Parquet
+                    | CopyFormat::Json
+                    | CopyFormat::Avro),
+                ) => format,
+            }
+        }
     };
 
     let params = match format {
@@ -2058,6 +2154,19 @@ fn plan_copy_from(
         }
         CopyFormat::Binary => bail_unsupported!("FORMAT BINARY"),
         CopyFormat::Parquet => CopyFormatParams::Parquet,
+        CopyFormat::Json | CopyFormat::Avro => {
+            only_available_with_csv(options.quote, "quote")?;
+            only_available_with_csv(options.escape, "escape")?;
+            only_available_with_csv(options.header, "HEADER")?;
+            only_available_with_text_or_csv(options.delimiter, "delimiter")?;
+            only_available_with_text_or_csv(options.null, "NULL")?;
+            match format {
+                CopyFormat::Json => CopyFormatParams::Json(CopyJsonFormatParams {
+                    single_column: options.single_column.unwrap_or(false),
+                }),
+                _ => CopyFormatParams::Avro,
+            }
+        }
     };
 
     let filter = match (options.files, options.pattern) {
@@ -2079,6 +2188,19 @@ fn plan_copy_from(
         sql_bail!("[internal error] COPY FROM ... expects an MFP to be produced");
     };
 
+    // Each JSON value is loaded as-is, so there must be exactly one column for
+    // it to go into.
+    if let CopyFormatParams::Json(CopyJsonFormatParams {
+        single_column: true,
+    }) = &params
+    {
+        let mut types = source_desc.iter_types();
+        match (types.next(), types.next()) {
+            (Some(typ), None) if typ.scalar_type == SqlScalarType::Jsonb => {}
+            _ => sql_bail!("COPY SINGLE COLUMN requires exactly one target column of type jsonb"),
+        }
+    }
+
     Ok(Plan::CopyFrom(CopyFromPlan {
         target_id: id,
         target_name: table_name_string,
@@ -2108,9 +2230,11 @@ generate_extracted_config!(
     (Quote, String),
     (Header, bool),
     (AwsConnection, with_options::Object),
+    (GcpConnection, with_options::Object),
     (MaxFileSize, ByteSize, Default(ByteSize::mb(256))),
     (Files, Vec<String>),
-    (Pattern, String)
+    (Pattern, String),
+    (SingleColumn, bool)
 );
 
 pub fn plan_copy(
@@ -2133,9 +2257,14 @@ pub fn plan_copy(
             "csv" => Ok(CopyFormat::Csv),
             "binary" => Ok(CopyFormat::Binary),
             "parquet" => Ok(CopyFormat::Parquet),
+            "json" => Ok(CopyFormat::Json),
+            "avro" => Ok(CopyFormat::Avro),
             _ => sql_bail!("unknown FORMAT: {}", format),
         })
         .transpose()?;
+    if options.single_column.is_some() && format != Some(CopyFormat::Json) {
+        sql_bail!("COPY SINGLE COLUMN available only with FORMAT JSON");
+    }
 
     match (&direction, &target) {
         (CopyDirection::To, CopyTarget::Stdout) => {
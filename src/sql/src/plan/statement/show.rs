@@ -22,8 +22,9 @@ use mz_sql_parser::ast::display::{AstDisplay, FormatMode};
 use mz_sql_parser::ast::{
     CreateSinkOptionName, CreateSubsourceOptionName, ExternalReferenceExport, ExternalReferences,
     ObjectType, ShowCreateClusterStatement, ShowCreateConnectionStatement,
-    ShowCreateMaterializedViewStatement, ShowCreateTypeStatement, ShowObjectType,
-    SqlServerConfigOptionName, SystemObjectType, UnresolvedItemName, WithOptionValue,
+    ShowCreateFunctionStatement, ShowCreateMaterializedViewStatement, ShowCreateTypeStatement,
+    ShowObjectType, SqlServerConfigOptionName, SystemObjectType, UnresolvedItemName,
+    WithOptionValue,
 };
 use mz_sql_pretty::PrettyConfig;
 use query::QueryContext;
@@ -120,7 +121,7 @@ fn plan_show_create_item(
     if item.id().is_system()
         && matches!(
             expect_type,
-            CatalogItemType::Table | CatalogItemType::Source
+            CatalogItemType::Table | CatalogItemType::Source | CatalogItemType::Func
         )
     {
         sql_bail!("cannot show create for system object {name}");
@@ -299,6 +300,28 @@ pub fn describe_show_create_type(
     )))
 }
 
+pub fn plan_show_create_function(
+    scx: &StatementContext,
+    ShowCreateFunctionStatement {
+        function_name,
+        redacted,
+    }: ShowCreateFunctionStatement<Aug>,
+) -> Result<ShowCreatePlan, PlanError> {
+    plan_show_create_item(scx, &function_name, CatalogItemType::Func, redacted)
+}
+
+pub fn describe_show_create_function(
+    _: &StatementContext,
+    _: ShowCreateFunctionStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(Some(
+        RelationDesc::builder()
+            .with_column("name", SqlScalarType::String.nullable(false))
+            .with_column("create_sql", SqlScalarType::String.nullable(false))
+            .finish(),
+    )))
+}
+
 pub fn plan_show_create_connection(
     scx: &StatementContext,
     ShowCreateConnectionStatement {
@@ -1091,10 +1114,13 @@ fn humanize_sql_for_show_create(
             stmt.with_options.retain_mut(|o| match o.name {
                 TableFromSourceOptionName::TextColumns => true,
                 TableFromSourceOptionName::ExcludeColumns => true,
+                TableFromSourceOptionName::EnumColumns => true,
                 // Drop details, which does not roundtrip.
                 TableFromSourceOptionName::Details => false,
                 TableFromSourceOptionName::PartitionBy => true,
                 TableFromSourceOptionName::RetainHistory => true,
+                TableFromSourceOptionName::TruncateBehavior => true,
+                TableFromSourceOptionName::SchemaChangeBehavior => true,
             });
             // The `Defined` column list and constraints are populated during
             // purification (from the upstream schema), and `CREATE TABLE ... FROM
@@ -1293,6 +1319,7 @@ fn humanize_sql_for_show_create(
                     CreateSubsourceOptionName::Details => false,
                     CreateSubsourceOptionName::ExternalReference => true,
                     CreateSubsourceOptionName::Progress => true,
+                    CreateSubsourceOptionName::TruncateBehavior => true,
                 }
             });
         }
@@ -17,7 +17,7 @@ use std::sync::LazyLock;
 use dynfmt::{Format, SimpleCurlyFormat};
 use itertools::Itertools;
 use mz_expr::func;
-use mz_expr::func::variadic::{JsonbBuildObject, RecordCreate};
+use mz_expr::func::variadic::{JsonbBuildObject, MultiRangeCreate, RecordCreate};
 use mz_expr::func::{CastArrayToJsonb, CastListToJsonb};
 use mz_repr::{
     ColumnName, Datum, SqlColumnType, SqlRelationType, SqlScalarBaseType, SqlScalarType,
@@ -655,6 +655,9 @@ static VALID_CASTS: LazyLock<BTreeMap<(SqlScalarBaseType, SqlScalarBaseType), Ca
             (String, Bytes) => Explicit: CastStringToBytes(func::CastStringToBytes),
             (String, Jsonb) => Explicit: CastStringToJsonb(func::CastStringToJsonb),
             (String, Uuid) => Explicit: CastStringToUuid(func::CastStringToUuid),
+            (String, Inet) => Explicit: CastStringToInet(func::CastStringToInet),
+            (String, Cidr) => Explicit: CastStringToCidr(func::CastStringToCidr),
+            (String, MacAddr) => Explicit: CastStringToMacAddr(func::CastStringToMacAddr),
             (String, Array) => Explicit: CastTemplate::new(|ecx, ccx, from_type, to_type| {
                 let return_ty = to_type.clone();
                 let to_el_type = to_type.unwrap_array_element_type();
@@ -707,7 +710,28 @@ static VALID_CASTS: LazyLock<BTreeMap<(SqlScalarBaseType, SqlScalarBaseType), Ca
                     ))
                 })
             }),
+            (String, MultiRange) => Explicit: CastTemplate::new(|ecx, ccx, from_type, to_type| {
+                let return_ty = to_type.clone();
+                let to_el_type = to_type.unwrap_multirange_element_type();
+                let cast_expr = plan_hypothetical_cast(ecx, ccx, from_type, to_el_type)?;
+                Some(|e: HirScalarExpr| {
+                    e.call_unary(UnaryFunc::CastStringToMultiRange(
+                        func::CastStringToMultiRange {
+                            return_ty,
+                            cast_expr: Box::new(cast_expr),
+                        },
+                    ))
+                })
+            }),
             (String, Int2Vector) => Explicit: CastStringToInt2Vector(func::CastStringToInt2Vector),
+            (String, Enum) => Explicit: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
+                let return_ty = to_type.clone();
+                Some(|e: HirScalarExpr| {
+                    e.call_unary(UnaryFunc::CastStringToEnum(
+                        func::CastStringToEnum { return_ty },
+                    ))
+                })
+            }),
             (String, Char) => Implicit: CastTemplate::new(|_ecx, ccx, _from_type, to_type| {
                 let length = to_type.unwrap_char_length();
                 let fail_on_len = ccx != CastContext::Explicit;
@@ -854,6 +878,22 @@ static VALID_CASTS: LazyLock<BTreeMap<(SqlScalarBaseType, SqlScalarBaseType), Ca
                 })
             }),
 
+            // ENUM
+            (Enum, String) => Assignment: CastTemplate::new(|_ecx, _ccx, from_type, _to_type| {
+                let ty = from_type.clone();
+                Some(|e: HirScalarExpr| {
+                    e.call_unary(CastEnumToString(func::CastEnumToString { ty }))
+                })
+            }),
+            (Enum, Enum) => Implicit: CastTemplate::new(|_ecx, _ccx, from_type, to_type| {
+                // Values of the same enum type share ordinals, even if one of
+                // the types was resolved before labels were appended to it.
+                if !from_type.base_eq(to_type) {
+                    return None;
+                }
+                Some(|e: HirScalarExpr| e)
+            }),
+
             // ARRAY
             (Array, String) => Assignment: CastTemplate::new(|_ecx, _ccx, from_type, _to_type| {
                 let ty = from_type.clone();
@@ -974,6 +1014,17 @@ static VALID_CASTS: LazyLock<BTreeMap<(SqlScalarBaseType, SqlScalarBaseType), Ca
             // UUID
             (Uuid, String) => Assignment: CastUuidToString(func::CastUuidToString),
 
+            // INET
+            (Inet, String) => Assignment: CastInetToString(func::CastInetToString),
+            (Inet, Cidr) => Assignment: CastInetToCidr(func::CastInetToCidr),
+
+            // CIDR
+            (Cidr, String) => Assignment: CastCidrToString(func::CastCidrToString),
+            (Cidr, Inet) => Implicit: CastCidrToInet(func::CastCidrToInet),
+
+            // MACADDR
+            (MacAddr, String) => Assignment: CastMacAddrToString(func::CastMacAddrToString),
+
             // Numeric
             (Numeric, Numeric) => Assignment: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
                 let scale = to_type.unwrap_numeric_max_scale();
@@ -1005,6 +1056,35 @@ static VALID_CASTS: LazyLock<BTreeMap<(SqlScalarBaseType, SqlScalarBaseType), Ca
                     ))
                 })
             }),
+            (Range, MultiRange) => Explicit: CastTemplate::new(|_ecx, _ccx, from_type, to_type| {
+                let elem_type = from_type.unwrap_range_element_type().clone();
+                if !elem_type.base_eq(to_type.unwrap_multirange_element_type()) {
+                    return None;
+                }
+                let return_ty = to_type.clone();
+                Some(|e: HirScalarExpr| {
+                    // The multirange of a null range is null, rather than an
+                    // error about a null member.
+                    HirScalarExpr::if_then_else(
+                        e.clone().call_is_null(),
+                        HirScalarExpr::literal_null(return_ty),
+                        HirScalarExpr::call_variadic(
+                            MultiRangeCreate { elem_type },
+                            vec![e],
+                        ),
+                    )
+                })
+            }),
+
+            // MultiRange
+            (MultiRange, String) => Assignment: CastTemplate::new(|_ecx, _ccx, from_type, _to_type| {
+                let ty = from_type.clone();
+                Some(|e: HirScalarExpr| {
+                    e.call_unary(CastMultiRangeToString(
+                        func::CastMultiRangeToString { ty },
+                    ))
+                })
+            }),
 
             // MzAclItem
             (MzAclItem, String) => Explicit: sql_impl_cast("(
@@ -1125,7 +1205,8 @@ fn get_cast(
     }
 
     // Reject casts to range types with unsupported element types at plan time.
-    if let SqlScalarType::Range { element_type } = to {
+    if let SqlScalarType::Range { element_type } | SqlScalarType::MultiRange { element_type } = to
+    {
         validate_range_element_type(ecx, element_type)?;
     }
 
@@ -1258,6 +1339,9 @@ pub fn to_jsonb(ecx: &ExprContext, expr: HirScalarExpr) -> Result<HirScalarExpr,
         | Char { .. }
         | VarChar { .. }
         | Uuid
+        | Inet
+        | Cidr
+        | MacAddr
         | Oid
         | Map { .. }
         | RegProc
@@ -1266,8 +1350,10 @@ pub fn to_jsonb(ecx: &ExprContext, expr: HirScalarExpr) -> Result<HirScalarExpr,
         | Int2Vector
         | MzTimestamp
         | Range { .. }
+        | MultiRange { .. }
         | MzAclItem
-        | AclItem => to_string(ecx, expr)?
+        | AclItem
+        | Enum { .. } => to_string(ecx, expr)?
             .call_unary(UnaryFunc::CastJsonbableToJsonb(func::CastJsonbableToJsonb)),
     })
 }
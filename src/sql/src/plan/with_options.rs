@@ -22,6 +22,8 @@ use mz_sql_parser::ast::{
 };
 use mz_storage_types::connections::IcebergCatalogType;
 use mz_storage_types::connections::string_or_secret::StringOrSecret;
+use mz_storage_types::sources::SchemaChangeBehavior;
+use mz_storage_types::sources::postgres::PostgresTruncateBehavior;
 use serde::{Deserialize, Serialize};
 
 use crate::ast::{AstInfo, UnresolvedItemName, Value, WithOptionValue};
@@ -68,6 +70,70 @@ impl ImpliedValue for IcebergCatalogType {
     }
 }
 
+impl TryFromValue<Value> for PostgresTruncateBehavior {
+    fn try_from_value(v: Value) -> Result<Self, PlanError> {
+        match v {
+            Value::String(v) => match v.to_lowercase().as_str() {
+                "error" => Ok(PostgresTruncateBehavior::Error),
+                "retract" => Ok(PostgresTruncateBehavior::Retract),
+                // The caller will add context, resulting in an error like
+                // "invalid TRUNCATE BEHAVIOR: <bad-behavior>".
+                _ => sql_bail!("{}", v),
+            },
+            _ => sql_bail!("truncate behavior must be ERROR or RETRACT"),
+        }
+    }
+
+    fn try_into_value(self, _catalog: &dyn SessionCatalog) -> Option<Value> {
+        Some(Value::String(match self {
+            PostgresTruncateBehavior::Error => "error".to_string(),
+            PostgresTruncateBehavior::Retract => "retract".to_string(),
+        }))
+    }
+
+    fn name() -> String {
+        "truncate behavior".to_string()
+    }
+}
+
+impl ImpliedValue for PostgresTruncateBehavior {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a truncate behavior")
+    }
+}
+
+impl TryFromValue<Value> for SchemaChangeBehavior {
+    fn try_from_value(v: Value) -> Result<Self, PlanError> {
+        match v {
+            Value::String(v) => match v.to_lowercase().as_str() {
+                "ignore" => Ok(SchemaChangeBehavior::Ignore),
+                "evolve" => Ok(SchemaChangeBehavior::Evolve),
+                // The caller will add context, resulting in an error like
+                // "invalid SCHEMA CHANGE BEHAVIOR: <bad-behavior>".
+                _ => sql_bail!("{}", v),
+            },
+            _ => sql_bail!("schema change behavior must be IGNORE or EVOLVE"),
+        }
+    }
+
+    fn try_into_value(self, _catalog: &dyn SessionCatalog) -> Option<Value> {
+        Some(Value::String(match self {
+            SchemaChangeBehavior::Ignore => "ignore".to_string(),
+            SchemaChangeBehavior::Evolve => "evolve".to_string(),
+        }))
+    }
+
+    fn name() -> String {
+        "schema change behavior".to_string()
+    }
+}
+
+impl ImpliedValue for SchemaChangeBehavior {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a schema change behavior")
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct Secret(CatalogItemId);
 
@@ -32,7 +32,9 @@ use mz_ore::iter::IteratorExt;
 use mz_ore::str::StrExt;
 use mz_postgres_util::desc::PostgresTableDesc;
 use mz_proto::RustType;
-use mz_repr::{CatalogItemId, RelationDesc, RelationVersionSelector, Timestamp, strconv};
+use mz_repr::{
+    CatalogItemId, RelationDesc, RelationVersionSelector, SqlColumnType, Timestamp, strconv,
+};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::visit::{Visit, visit_function};
 use mz_sql_parser::ast::visit_mut::{VisitMut, visit_expr_mut};
@@ -45,7 +47,7 @@ use mz_sql_parser::ast::{
     DocOnSchema, Expr, Function, FunctionArgs, GlueAvroOption, GlueAvroSeed, Ident,
     KafkaSourceConfigOption, KafkaSourceConfigOptionName, LoadGenerator, LoadGeneratorOption,
     LoadGeneratorOptionName, MaterializedViewOption, MaterializedViewOptionName, MySqlConfigOption,
-    MySqlConfigOptionName, PgConfigOption, PgConfigOptionName, RawItemName,
+    MySqlConfigOptionName, PgConfigOption, PgConfigOptionName, RawDataType, RawItemName,
     ReaderSchemaSelectionStrategy, RefreshAtOptionValue, RefreshEveryOptionValue,
     RefreshOptionValue, SourceEnvelope, SqlServerConfigOption, SqlServerConfigOptionName,
     Statement, TableFromSourceColumns, TableFromSourceOption, TableFromSourceOptionName,
@@ -56,11 +58,13 @@ use mz_storage_types::configuration::StorageConfiguration;
 use mz_storage_types::connections::Connection;
 use mz_storage_types::connections::inline::IntoInlineConnection;
 use mz_storage_types::errors::ContextCreationError;
+use mz_storage_types::sources::kafka::KafkaTopics;
 use mz_storage_types::sources::load_generator::LoadGeneratorOutput;
 use mz_storage_types::sources::mysql::MySqlSourceDetails;
 use mz_storage_types::sources::postgres::PostgresSourcePublicationDetails;
 use mz_storage_types::sources::{
-    GenericSourceConnection, SourceDesc, SourceExportStatementDetails, SqlServerSourceExtras,
+    GenericSourceConnection, SchemaChangeBehavior, SourceDesc, SourceExportStatementDetails,
+    SqlServerSourceExtras,
 };
 use prost::Message;
 use protobuf_native::MessageLite;
@@ -273,6 +277,38 @@ pub enum PurifiedExportDetails {
     },
 }
 
+/// The type with which a column that was added to an upstream table is added to
+/// a table that is fed by a source.
+#[derive(Debug, Clone)]
+pub struct AddedColumnType {
+    /// The type of the column.
+    pub typ: SqlColumnType,
+    /// The type of the column as it appears in the table's definition.
+    pub data_type: RawDataType,
+    /// Whether the column is ingested as text because its upstream type can't
+    /// be ingested.
+    pub text: bool,
+}
+
+/// Determines the type with which a column that was added to an upstream table
+/// and is ingested as `ty` is added to a table. Added columns are always
+/// nullable.
+fn added_column_type(
+    scx: &StatementContext,
+    ty: mz_pgrepr::Type,
+    text: bool,
+) -> Result<AddedColumnType, PlanError> {
+    let data_type = scx.resolve_type(ty)?;
+    let typ = crate::plan::query::scalar_type_from_sql(scx, &data_type)?.nullable(true);
+    // "unresolve" the data type so it can be written into the table's definition.
+    let data_type = mz_sql_parser::parser::parse_data_type(&data_type.to_ast_string_stable())?;
+    Ok(AddedColumnType {
+        typ,
+        data_type,
+        text,
+    })
+}
+
 /// Purifies a statement, removing any dependencies on external state.
 ///
 /// See the section on [purification](crate#purification) in the crate
@@ -814,8 +850,8 @@ async fn purify_create_source(
             let extracted_options: KafkaSourceConfigOptionExtracted =
                 base_with_options.clone().try_into()?;
 
-            let topic = extracted_options
-                .topic
+            let topics = extracted_options
+                .topics()?
                 .ok_or(KafkaSourcePurificationError::ConnectionMissingTopic)?;
 
             let consumer = connection
@@ -834,73 +870,102 @@ async fn purify_create_source(
                 })?;
             let consumer = Arc::new(consumer);
 
-            match (
-                extracted_options.start_offset,
-                extracted_options.start_timestamp,
-            ) {
-                (None, None) => {
-                    // Validate that the topic at least exists.
-                    kafka_util::ensure_topic_exists(
-                        Arc::clone(&consumer),
-                        &topic,
-                        storage_configuration
-                            .parameters
-                            .kafka_timeout_config
-                            .fetch_metadata_timeout,
-                    )
-                    .await?;
-                }
-                (Some(_), Some(_)) => {
-                    sql_bail!("cannot specify START TIMESTAMP and START OFFSET at same time")
+            let fetch_metadata_timeout = storage_configuration
+                .parameters
+                .kafka_timeout_config
+                .fetch_metadata_timeout;
+            match &topics {
+                KafkaTopics::Topic(topic) => {
+                    match (
+                        extracted_options.start_offset,
+                        extracted_options.start_timestamp,
+                    ) {
+                        (None, None) => {
+                            // Validate that the topic at least exists.
+                            kafka_util::ensure_topic_exists(
+                                Arc::clone(&consumer),
+                                topic,
+                                fetch_metadata_timeout,
+                            )
+                            .await?;
+                        }
+                        (Some(_), Some(_)) => {
+                            sql_bail!(
+                                "cannot specify START TIMESTAMP and START OFFSET at same time"
+                            )
+                        }
+                        (Some(start_offsets), None) => {
+                            // Validate the start offsets.
+                            kafka_util::validate_start_offsets(
+                                Arc::clone(&consumer),
+                                topic,
+                                start_offsets,
+                                fetch_metadata_timeout,
+                            )
+                            .await?;
+                        }
+                        (None, Some(time_offset)) => {
+                            // Translate `START TIMESTAMP` to a start offset.
+                            let start_offsets = kafka_util::lookup_start_offsets(
+                                Arc::clone(&consumer),
+                                topic,
+                                time_offset,
+                                now,
+                                fetch_metadata_timeout,
+                            )
+                            .await?;
+
+                            base_with_options.retain(|val| {
+                                !matches!(val.name, KafkaSourceConfigOptionName::StartTimestamp)
+                            });
+                            base_with_options.push(KafkaSourceConfigOption {
+                                name: KafkaSourceConfigOptionName::StartOffset,
+                                value: Some(WithOptionValue::Sequence(
+                                    start_offsets
+                                        .iter()
+                                        .map(|offset| {
+                                            WithOptionValue::Value(Value::Number(
+                                                offset.to_string(),
+                                            ))
+                                        })
+                                        .collect(),
+                                )),
+                            });
+                        }
+                    }
                 }
-                (Some(start_offsets), None) => {
-                    // Validate the start offsets.
-                    kafka_util::validate_start_offsets(
-                        Arc::clone(&consumer),
-                        &topic,
-                        start_offsets,
-                        storage_configuration
-                            .parameters
-                            .kafka_timeout_config
-                            .fetch_metadata_timeout,
-                    )
-                    .await?;
+                KafkaTopics::List(names) => {
+                    // Validate that the topics at least exist.
+                    for name in names {
+                        kafka_util::ensure_topic_exists(
+                            Arc::clone(&consumer),
+                            name,
+                            fetch_metadata_timeout,
+                        )
+                        .await?;
+                    }
                 }
-                (None, Some(time_offset)) => {
-                    // Translate `START TIMESTAMP` to a start offset.
-                    let start_offsets = kafka_util::lookup_start_offsets(
+                KafkaTopics::Pattern(_) => {
+                    // Topics matching the pattern may be created later, so only validate
+                    // that we can list the existing ones.
+                    kafka_util::ensure_topics_listable(
                         Arc::clone(&consumer),
-                        &topic,
-                        time_offset,
-                        now,
-                        storage_configuration
-                            .parameters
-                            .kafka_timeout_config
-                            .fetch_metadata_timeout,
+                        fetch_metadata_timeout,
                     )
                     .await?;
-
-                    base_with_options.retain(|val| {
-                        !matches!(val.name, KafkaSourceConfigOptionName::StartTimestamp)
-                    });
-                    base_with_options.push(KafkaSourceConfigOption {
-                        name: KafkaSourceConfigOptionName::StartOffset,
-                        value: Some(WithOptionValue::Sequence(
-                            start_offsets
-                                .iter()
-                                .map(|offset| {
-                                    WithOptionValue::Value(Value::Number(offset.to_string()))
-                                })
-                                .collect(),
-                        )),
-                    });
                 }
             }
 
-            let reference_client = SourceReferenceClient::Kafka { topic: &topic };
+            let reference = topics.to_string();
+            let reference_client = SourceReferenceClient::Kafka { topic: &reference };
             retrieved_source_references = reference_client.get_source_references().await?;
 
-            format_options = SourceFormatOptions::Kafka { topic };
+            format_options = SourceFormatOptions::Kafka {
+                topic: match topics {
+                    KafkaTopics::Topic(topic) => Some(topic),
+                    KafkaTopics::List(_) | KafkaTopics::Pattern(_) => None,
+                },
+            };
         }
         CreateSourceConnection::Postgres {
             connection,
@@ -1744,9 +1809,8 @@ async fn purify_alter_source_refresh_references(
             reference_client.get_source_references().await?
         }
         GenericSourceConnection::Kafka(kafka_conn) => {
-            let reference_client = SourceReferenceClient::Kafka {
-                topic: &kafka_conn.topic,
-            };
+            let reference = kafka_conn.topics.to_string();
+            let reference_client = SourceReferenceClient::Kafka { topic: &reference };
             reference_client.get_source_references().await?
         }
     };
@@ -1803,9 +1867,12 @@ async fn purify_create_table_from_source(
     let crate::plan::statement::ddl::TableFromSourceOptionExtracted {
         text_columns,
         exclude_columns,
+        enum_columns,
         retain_history: _,
         details,
         partition_by: _,
+        truncate_behavior: _,
+        schema_change_behavior,
         seen: _,
     } = with_options.clone().try_into()?;
     if details.is_some() {
@@ -1852,6 +1919,14 @@ async fn purify_create_table_from_source(
 
     // Run purification work specific to each source type: resolve the external reference to
     // a fully qualified name and obtain the appropriate details for the source-export statement
+    // Enum types resolved for the columns named in `ENUM COLUMNS`, keyed by
+    // column name.
+    let mut enum_column_types = BTreeMap::new();
+    if !enum_columns.is_empty() && !matches!(desc.connection, GenericSourceConnection::Postgres(_))
+    {
+        sql_bail!("ENUM COLUMNS is only supported for PostgreSQL sources");
+    }
+
     let purified_export = match desc.connection {
         GenericSourceConnection::Postgres(pg_source_connection) => {
             // Get PostgresConnection for generating subsources.
@@ -1886,6 +1961,9 @@ async fn purify_create_table_from_source(
             .await?;
             // There should be exactly one source_export returned for this statement
             let (_, purified_export) = source_exports.into_element();
+            enum_column_types =
+                postgres::purify_enum_columns(&scx, &client, &purified_export, &enum_columns)
+                    .await?;
             purified_export
         }
         GenericSourceConnection::MySql(mysql_source_connection) => {
@@ -2008,9 +2086,8 @@ async fn purify_create_table_from_source(
             }
         }
         GenericSourceConnection::Kafka(kafka_conn) => {
-            let reference_client = SourceReferenceClient::Kafka {
-                topic: &kafka_conn.topic,
-            };
+            let reference = kafka_conn.topics.to_string();
+            let reference_client = SourceReferenceClient::Kafka { topic: &reference };
             retrieved_source_references = reference_client.get_source_references().await?;
             let requested_exports = retrieved_source_references
                 .requested_source_exports(requested_references.as_ref(), &unresolved_source_name)?;
@@ -2018,7 +2095,10 @@ async fn purify_create_table_from_source(
             let export = requested_exports.into_element();
 
             format_options = SourceFormatOptions::Kafka {
-                topic: kafka_conn.topic.clone(),
+                topic: match &kafka_conn.topics {
+                    KafkaTopics::Topic(topic) => Some(topic.clone()),
+                    KafkaTopics::List(_) | KafkaTopics::Pattern(_) => None,
+                },
             };
             PurifiedSourceExport {
                 external_reference: export.external_reference,
@@ -2054,6 +2134,8 @@ async fn purify_create_table_from_source(
             } = postgres::generate_source_export_statement_values(
                 &scx,
                 purified_export,
+                &enum_column_types,
+                schema_change_behavior == Some(SchemaChangeBehavior::Evolve),
                 &mut unsupported_cols,
             )?;
             if !unsupported_cols.is_empty() {
@@ -2080,6 +2162,17 @@ async fn purify_create_table_from_source(
                         Some(WithOptionValue::Sequence(gen_exclude_columns));
                 }
             }
+            if let Some(enum_cols_option) = with_options
+                .iter_mut()
+                .find(|option| option.name == TableFromSourceOptionName::EnumColumns)
+            {
+                enum_cols_option.value = Some(WithOptionValue::Sequence(
+                    enum_column_types
+                        .keys()
+                        .map(|name| Ok(WithOptionValue::Ident(Ident::new(name.clone())?)))
+                        .collect::<Result<_, PlanError>>()?,
+                ));
+            }
             match columns {
                 TableFromSourceColumns::Defined(_) => {
                     // Purification is what populates the `Defined` variant;
@@ -2111,7 +2204,11 @@ async fn purify_create_table_from_source(
                 exclude_columns: gen_exclude_columns,
                 details: gen_details,
                 external_reference: _,
-            } = mysql::generate_source_export_statement_values(&scx, purified_export)?;
+            } = mysql::generate_source_export_statement_values(
+                &scx,
+                purified_export,
+                schema_change_behavior == Some(SchemaChangeBehavior::Evolve),
+            )?;
 
             if let Some(text_cols_option) = with_options
                 .iter_mut()
@@ -2161,7 +2258,11 @@ async fn purify_create_table_from_source(
                 excl_columns: gen_excl_columns,
                 details: gen_details,
                 external_reference: _,
-            } = sql_server::generate_source_export_statement_values(&scx, purified_export)?;
+            } = sql_server::generate_source_export_statement_values(
+                &scx,
+                purified_export,
+                schema_change_behavior == Some(SchemaChangeBehavior::Evolve),
+            )?;
 
             if let Some(text_cols_option) = with_options
                 .iter_mut()
@@ -2260,7 +2361,11 @@ async fn purify_create_table_from_source(
 
 enum SourceFormatOptions {
     Default,
-    Kafka { topic: String },
+    /// The topic whose subjects are looked up in a schema registry, if the
+    /// source reads from exactly one topic.
+    Kafka {
+        topic: Option<String>,
+    },
 }
 
 async fn purify_source_format(
@@ -2464,6 +2569,11 @@ async fn purify_csr_connection_proto(
     } = csr_connection;
     match seed {
         None => {
+            let Some(topic) = topic else {
+                sql_bail!(
+                    "Confluent Schema Registry requires SEED or a source that reads exactly one topic"
+                )
+            };
             let scx = StatementContext::new(None, &*catalog);
 
             let ccsr_connection = match scx.get_item_by_resolved_name(connection)?.connection()? {
@@ -2511,6 +2621,11 @@ async fn purify_csr_connection_avro(
         value_strategy,
     } = csr_connection;
     if seed.is_none() {
+        let Some(topic) = topic else {
+            sql_bail!(
+                "Confluent Schema Registry requires SEED or a source that reads exactly one topic"
+            )
+        };
         let scx = StatementContext::new(None, &*catalog);
         let csr_connection = match scx.get_item_by_resolved_name(connection)?.connection()? {
             Connection::Csr(connection) => connection.clone().into_inline_connection(catalog),
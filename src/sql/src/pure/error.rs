@@ -52,6 +52,17 @@ pub enum PgSourcePurificationError {
     DuplicatedColumnNames(Vec<String>),
     #[error("referenced tables use unsupported types")]
     UnrecognizedTypes { cols: Vec<(String, Oid)> },
+    #[error("ENUM COLUMNS refers to column {column}, whose upstream type is not an enum")]
+    EnumColumnNotEnum { column: String },
+    #[error("enum type {type_name} for column {column} does not exist")]
+    EnumTypeNotFound { column: String, type_name: String },
+    #[error("type {type_name} for column {column} does not match the upstream enum type")]
+    EnumLabelMismatch {
+        column: String,
+        type_name: String,
+        upstream_labels: Vec<String>,
+        labels: Vec<String>,
+    },
     #[error("{0} is not a POSTGRES CONNECTION")]
     NotPgConnection(FullItemName),
     #[error("CONNECTION must specify PUBLICATION")]
@@ -97,6 +108,15 @@ impl PgSourcePurificationError {
                     "\n"
                 )
             )),
+            Self::EnumLabelMismatch {
+                upstream_labels,
+                labels,
+                ..
+            } => Some(format!(
+                "upstream labels: ({}); Materialize labels: ({})",
+                upstream_labels.join(", "),
+                labels.join(", ")
+            )),
             Self::InvalidConnection(e) => e.detail(),
             _ => None,
         }
@@ -123,6 +143,15 @@ impl PgSourcePurificationError {
                 option
             )),
             Self::BypassRLSRequired { .. } => Some("Add the BYPASSRLS attribute to the Materialize user".into()),
+            Self::EnumTypeNotFound { type_name, .. } => Some(format!(
+                "Create an enum type named {type_name} with the same labels as the upstream type \
+                using CREATE TYPE ... AS ENUM."
+            )),
+            Self::EnumLabelMismatch { .. } => Some(
+                "The upstream labels must match the leading labels of the Materialize type, in \
+                the same order."
+                    .into(),
+            ),
             Self::InvalidConnection(e) => e.hint(),
             _ => None,
         }
@@ -134,7 +163,7 @@ impl PgSourcePurificationError {
 pub enum KafkaSourcePurificationError {
     #[error("{} is only valid for multi-output sources", .0.to_ast_string_simple())]
     ReferencedSubsources(ExternalReferences),
-    #[error("KAFKA CONNECTION without TOPIC")]
+    #[error("KAFKA CONNECTION without TOPIC, TOPICS, or TOPIC PATTERN")]
     ConnectionMissingTopic,
     #[error("{0} is not a KAFKA CONNECTION")]
     NotKafkaConnection(FullItemName),
@@ -12,7 +12,8 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use mz_mysql_util::{
-    MySqlError, MySqlTableDesc, QualifiedTableRef, SYSTEM_SCHEMAS, validate_source_privileges,
+    MySqlColumnDesc, MySqlError, MySqlTableDesc, QualifiedTableRef, SYSTEM_SCHEMAS,
+    validate_source_privileges,
 };
 use mz_proto::RustType;
 use mz_sql_parser::ast::display::AstDisplay;
@@ -30,7 +31,8 @@ use crate::pure::{MySqlSourcePurificationError, ResolvedItemName};
 
 use super::references::RetrievedSourceReferences;
 use super::{
-    PurifiedExportDetails, PurifiedSourceExport, RequestedSourceExport, SourceReferencePolicy,
+    AddedColumnType, PurifiedExportDetails, PurifiedSourceExport, RequestedSourceExport,
+    SourceReferencePolicy,
 };
 
 /// The name of the fake database that we use for MySQL sources
@@ -68,7 +70,7 @@ pub fn generate_create_subsource_statements(
             exclude_columns,
             details,
             external_reference,
-        } = generate_source_export_statement_values(scx, purified_export)?;
+        } = generate_source_export_statement_values(scx, purified_export, false)?;
 
         let mut with_options = vec![
             CreateSubsourceOption {
@@ -121,12 +123,16 @@ pub(super) struct MySqlExportStatementValues {
     pub(super) external_reference: UnresolvedItemName,
 }
 
+/// Generate the columns, constraints and options of the statement for a
+/// purified source export. `evolve` indicates whether the export evolves its
+/// schema with its upstream table.
 pub(super) fn generate_source_export_statement_values(
     scx: &StatementContext,
     purified_export: PurifiedSourceExport,
+    evolve: bool,
 ) -> Result<MySqlExportStatementValues, PlanError> {
     let PurifiedExportDetails::MySql {
-        table,
+        mut table,
         text_columns,
         exclude_columns,
         initial_gtid_set,
@@ -135,6 +141,9 @@ pub(super) fn generate_source_export_statement_values(
     else {
         bail_internal!("purified export details must be mysql");
     };
+    if evolve {
+        table.remove_varchar_limits();
+    }
 
     // Figure out the schema of the subsource
     let mut columns = vec![];
@@ -564,3 +573,17 @@ pub async fn is_binlog_full_metadata(
         }
     }
 }
+
+/// Determines the type with which `column`, which was added to an upstream
+/// table, is added to a table that is fed by a MySQL source. `text` indicates
+/// whether the column is ingested as text.
+pub fn added_column_type(
+    scx: &StatementContext,
+    column: &MySqlColumnDesc,
+    text: bool,
+) -> Result<AddedColumnType, PlanError> {
+    let Some(column_type) = &column.column_type else {
+        bail_internal!("added column {} must not be excluded", column.name);
+    };
+    super::added_column_type(scx, mz_pgrepr::Type::from(&column_type.scalar_type), text)
+}
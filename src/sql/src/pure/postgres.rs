@@ -11,14 +11,15 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 
-use mz_postgres_util::desc::PostgresTableDesc;
+use mz_postgres_util::PostgresError;
+use mz_postgres_util::desc::{PostgresColumnDesc, PostgresTableDesc};
 use mz_proto::RustType;
 use mz_repr::{Datum, ReprColumnType, ReprScalarType, Row, SqlScalarType};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
     ColumnDef, CreateSubsourceOption, CreateSubsourceOptionName, CreateSubsourceStatement,
-    ExternalReferences, Ident, PgConfigOptionName, TableConstraint, UnresolvedItemName, Value,
-    WithOptionValue,
+    ExternalReferences, Ident, PgConfigOptionName, RawDataType, RawItemName, TableConstraint,
+    TableFromSourceOptionName, UnresolvedItemName, Value, WithOptionValue,
 };
 use mz_storage_types::sources::SourceExportStatementDetails;
 use mz_storage_types::sources::casts::{CastFunc, StorageScalarExpr};
@@ -27,13 +28,14 @@ use prost::Message;
 use tokio_postgres::Client;
 use tokio_postgres::types::Oid;
 
-use crate::names::{Aug, ResolvedItemName};
+use crate::catalog::CatalogType;
+use crate::names::{self, Aug, PartialItemName, ResolvedDataType, ResolvedItemName};
 use crate::normalize;
 use crate::plan::{PlanError, StatementContext};
 
 use super::error::PgSourcePurificationError;
 use super::references::RetrievedSourceReferences;
-use super::{PartialItemName, PurifiedExportDetails, PurifiedSourceExport, SourceReferencePolicy};
+use super::{AddedColumnType, PurifiedExportDetails, PurifiedSourceExport, SourceReferencePolicy};
 
 /// Ensure that we have select permissions on all tables; we have to do this before we
 /// start snapshotting because if we discover we cannot `COPY` from a table while
@@ -149,7 +151,13 @@ pub fn generate_create_subsource_statements(
             exclude_columns,
             details,
             external_reference,
-        } = generate_source_export_statement_values(scx, purified_export, &mut unsupported_cols)?;
+        } = generate_source_export_statement_values(
+            scx,
+            purified_export,
+            &BTreeMap::new(),
+            false,
+            &mut unsupported_cols,
+        )?;
 
         let mut with_options = vec![
             CreateSubsourceOption {
@@ -219,9 +227,15 @@ pub(super) struct PostgresExportStatementValues {
     pub(super) external_reference: UnresolvedItemName,
 }
 
+/// Generate the columns, constraints and options of the statement for a
+/// purified source export. Columns in `enum_columns` take the given enum type
+/// rather than the type derived from their upstream type. `evolve` indicates
+/// whether the export evolves its schema with its upstream table.
 pub(super) fn generate_source_export_statement_values(
     scx: &StatementContext,
     purified_export: PurifiedSourceExport,
+    enum_columns: &BTreeMap<String, ResolvedDataType>,
+    evolve: bool,
     unsupported_cols: &mut Vec<(String, mz_repr::adt::system::Oid)>,
 ) -> Result<PostgresExportStatementValues, PlanError> {
     let PurifiedExportDetails::Postgres {
@@ -246,10 +260,20 @@ pub(super) fn generate_source_export_statement_values(
             continue;
         }
 
+        if let Some(data_type) = enum_columns.get(&c.name) {
+            columns.push(ColumnDef {
+                name,
+                data_type: data_type.clone(),
+                collation: None,
+                options: not_null_options(c.nullable),
+            });
+            continue;
+        }
+
         let ty = if text_column_set.contains(c.name.as_str()) {
             mz_pgrepr::Type::Text
         } else {
-            match mz_pgrepr::Type::from_oid_and_typmod(c.type_oid, c.type_mod) {
+            match upstream_column_type(c, evolve) {
                 Ok(t) => t,
                 Err(_) => {
                     let mut full_name = purified_export.external_reference.0.clone();
@@ -264,20 +288,12 @@ pub(super) fn generate_source_export_statement_values(
         };
 
         let data_type = scx.resolve_type(ty)?;
-        let mut options = vec![];
-
-        if !c.nullable {
-            options.push(mz_sql_parser::ast::ColumnOptionDef {
-                name: None,
-                option: mz_sql_parser::ast::ColumnOption::NotNull,
-            });
-        }
 
         columns.push(ColumnDef {
             name,
             data_type,
             collation: None,
-            options,
+            options: not_null_options(c.nullable),
         });
     }
 
@@ -351,6 +367,17 @@ pub(super) fn generate_source_export_statement_values(
     })
 }
 
+fn not_null_options(nullable: bool) -> Vec<mz_sql_parser::ast::ColumnOptionDef<Aug>> {
+    if nullable {
+        vec![]
+    } else {
+        vec![mz_sql_parser::ast::ColumnOptionDef {
+            name: None,
+            option: mz_sql_parser::ast::ColumnOption::NotNull,
+        }]
+    }
+}
+
 pub(super) struct PurifiedSourceExports {
     pub(super) source_exports: BTreeMap<UnresolvedItemName, PurifiedSourceExport>,
     // NOTE(roshan): The text columns are already part of their
@@ -570,10 +597,118 @@ pub(super) async fn purify_source_exports(
     })
 }
 
+/// Resolve the Materialize enum types of the columns named in `ENUM COLUMNS`.
+///
+/// Each column must have an enum type upstream, and Materialize must contain an
+/// enum type of the same name whose leading labels match the upstream labels
+/// in order, so that values compare the same way in both systems.
+pub(super) async fn purify_enum_columns(
+    scx: &StatementContext<'_>,
+    client: &Client,
+    purified_export: &PurifiedSourceExport,
+    enum_columns: &[Ident],
+) -> Result<BTreeMap<String, ResolvedDataType>, PlanError> {
+    let PurifiedExportDetails::Postgres {
+        table,
+        text_columns,
+        exclude_columns,
+    } = &purified_export.details
+    else {
+        bail_internal!("purified export details must be postgres");
+    };
+
+    let mut resolved = BTreeMap::new();
+    for column in enum_columns {
+        let name = column.as_str();
+        if text_columns
+            .iter()
+            .chain(exclude_columns.iter())
+            .flatten()
+            .any(|c| c.as_str() == name)
+        {
+            Err(PgSourcePurificationError::DuplicatedColumnNames(vec![
+                name.to_string(),
+            ]))?
+        }
+        let Some(desc) = table.columns.iter().find(|c| c.name == name) else {
+            return Err(PlanError::InvalidOptionValue {
+                option_name: TableFromSourceOptionName::EnumColumns.to_ast_string_simple(),
+                err: Box::new(PlanError::UnknownColumn {
+                    table: Some(normalize::unresolved_item_name(
+                        purified_export.external_reference.clone(),
+                    )?),
+                    column: mz_repr::ColumnName::from(name),
+                    similar: Box::new([]),
+                }),
+            });
+        };
+
+        let rows = client
+            .query(
+                "SELECT t.typname, e.enumlabel
+                FROM pg_type t
+                LEFT JOIN pg_enum e ON e.enumtypid = t.oid
+                WHERE t.oid = $1 AND t.typtype = 'e'
+                ORDER BY e.enumsortorder",
+                &[&desc.type_oid],
+            )
+            .await
+            .map_err(PostgresError::from)?;
+        let Some(first) = rows.first() else {
+            return Err(PgSourcePurificationError::EnumColumnNotEnum {
+                column: name.to_string(),
+            }
+            .into());
+        };
+        let type_name: String = first.get("typname");
+        // An enum without labels produces a single row with a NULL label.
+        let upstream_labels: Vec<String> = rows
+            .iter()
+            .filter_map(|row| row.get::<_, Option<String>>("enumlabel"))
+            .collect();
+
+        let not_found = || PgSourcePurificationError::EnumTypeNotFound {
+            column: name.to_string(),
+            type_name: type_name.clone(),
+        };
+        let item = scx
+            .catalog
+            .resolve_type(&PartialItemName {
+                database: None,
+                schema: None,
+                item: type_name.clone(),
+            })
+            .map_err(|_| not_found())?;
+        let labels = match item.type_details().map(|details| &details.typ) {
+            Some(CatalogType::Enum { labels }) => labels,
+            _ => Err(not_found())?,
+        };
+        if !labels.starts_with(&upstream_labels) {
+            Err(PgSourcePurificationError::EnumLabelMismatch {
+                column: name.to_string(),
+                type_name: type_name.clone(),
+                upstream_labels,
+                labels: labels.clone(),
+            })?
+        }
+
+        let full_name = scx.catalog.resolve_full_name(item.name());
+        let data_type = RawDataType::Other {
+            name: RawItemName::Name(full_name.into()),
+            typ_mod: vec![],
+        };
+        let (data_type, _) = names::resolve(scx.catalog, data_type)?;
+        resolved.insert(name.to_string(), data_type);
+    }
+    Ok(resolved)
+}
+
 pub(crate) fn generate_column_casts(
     scx: &StatementContext,
     table: &PostgresTableDesc,
     text_columns: &Vec<Ident>,
+    enum_columns: &BTreeMap<String, SqlScalarType>,
+    evolve: bool,
     cast_oid_full_range: bool,
 ) -> Result<Vec<(CastType, StorageScalarExpr)>, PlanError> {
     // Generate the cast expressions required to convert the text encoded columns into
@@ -584,6 +719,22 @@ pub(crate) fn generate_column_casts(
 
     let mut table_cast = vec![];
     for (i, column) in table.columns.iter().enumerate() {
+        if let Some(return_ty) = enum_columns.get(&column.name) {
+            // Enum columns named in `ENUM COLUMNS` map each upstream label
+            // onto the label of the same name in the Materialize enum type.
+            let cast_expr = StorageScalarExpr::CallUnary(
+                CastFunc::CastStringToEnum {
+                    return_ty: return_ty.clone(),
+                },
+                Box::new(StorageScalarExpr::Column(i)),
+            );
+            table_cast.push((
+                CastType::Natural,
+                enforce_not_null(table, column, cast_expr),
+            ));
+            continue;
+        }
+
         let (cast_type, ty) = if text_columns.contains(column.name.as_str()) {
             // Treat the column as text if it was referenced in
             // `TEXT COLUMNS`. This is the only place we need to
@@ -592,7 +743,7 @@ pub(crate) fn generate_column_casts(
             // storage.
             (CastType::Text, mz_pgrepr::Type::Text)
         } else {
-            match mz_pgrepr::Type::from_oid_and_typmod(column.type_oid, column.type_mod) {
+            match upstream_column_type(column, evolve) {
                 Ok(t) => (CastType::Natural, t),
                 // If this reference survived purification, we
                 // do not expect it to be from a table that the
@@ -640,26 +791,47 @@ pub(crate) fn generate_column_casts(
             Err(e) => return Err(e),
         };
 
-        let cast = if column.nullable {
-            cast_expr
-        } else {
-            // We must enforce nullability constraint on cast
-            // because PG replication stream does not propagate
-            // constraint changes and we want to error subsource if
-            // e.g. the constraint is dropped and we don't notice
-            // it.
-            let message = format!(
-                "PG column {}.{}.{} contained NULL data, despite having NOT NULL constraint",
-                table.namespace, table.name, column.name
-            );
-            StorageScalarExpr::ErrorIfNull(Box::new(cast_expr), message)
-        };
-
-        table_cast.push((cast_type, cast));
+        table_cast.push((cast_type, enforce_not_null(table, column, cast_expr)));
     }
     Ok(table_cast)
 }
 
+/// Returns the type with which the upstream `column` is ingested. Exports that
+/// evolve their schema ingest `varchar` columns without a length limit, so that
+/// the limit can be widened upstream.
+fn upstream_column_type(
+    column: &PostgresColumnDesc,
+    evolve: bool,
+) -> Result<mz_pgrepr::Type, mz_pgrepr::TypeFromOidError> {
+    let type_mod = if evolve && column.is_varchar() {
+        -1
+    } else {
+        column.type_mod
+    };
+    mz_pgrepr::Type::from_oid_and_typmod(column.type_oid, type_mod)
+}
+
+fn enforce_not_null(
+    table: &PostgresTableDesc,
+    column: &mz_postgres_util::desc::PostgresColumnDesc,
+    cast_expr: StorageScalarExpr,
+) -> StorageScalarExpr {
+    if column.nullable {
+        cast_expr
+    } else {
+        // We must enforce nullability constraint on cast
+        // because PG replication stream does not propagate
+        // constraint changes and we want to error subsource if
+        // e.g. the constraint is dropped and we don't notice
+        // it.
+        let message = format!(
+            "PG column {}.{}.{} contained NULL data, despite having NOT NULL constraint",
+            table.namespace, table.name, column.name
+        );
+        StorageScalarExpr::ErrorIfNull(Box::new(cast_expr), message)
+    }
+}
+
 /// Resolve a PG type to its corresponding `SqlScalarType` via the catalog.
 fn resolve_pg_type_to_scalar_type(
     scx: &StatementContext,
@@ -669,6 +841,22 @@ fn resolve_pg_type_to_scalar_type(
     crate::plan::query::scalar_type_from_sql(scx, &data_type)
 }
 
+/// Determines the type with which `column`, which was added to an upstream
+/// table, is added to a table that is fed by a PostgreSQL source. Columns whose
+/// upstream type can't be ingested are added as text, as if they had been
+/// named in `TEXT COLUMNS`.
+pub fn added_column_type(
+    scx: &StatementContext,
+    column: &PostgresColumnDesc,
+    cast_oid_full_range: bool,
+) -> Result<AddedColumnType, PlanError> {
+    let natural = upstream_column_type(column, true)
+        .ok()
+        .filter(|ty| pg_type_to_cast_func(scx, ty, cast_oid_full_range).is_ok());
+    let text = natural.is_none();
+    super::added_column_type(scx, natural.unwrap_or(mz_pgrepr::Type::Text), text)
+}
+
 /// Map a PG type to the corresponding `CastFunc` variant. Returns:
 /// - `Ok(Some(func))` for types that need a cast
 /// - `Ok(None)` for types that need no cast (Text → String identity)
@@ -762,6 +950,9 @@ fn pg_type_to_cast_func(
             }
         }
         Type::Uuid => CastFunc::CastStringToUuid,
+        Type::Inet => CastFunc::CastStringToInet,
+        Type::Cidr => CastFunc::CastStringToCidr,
+        Type::MacAddr => CastFunc::CastStringToMacAddr,
         Type::Int2Vector => CastFunc::CastStringToInt2Vector,
         Type::MzTimestamp => CastFunc::CastStringToMzTimestamp,
         // JSON is ingested as JSONB (same as the old plan_cast path).
@@ -798,6 +989,14 @@ fn pg_type_to_cast_func(
                 cast_expr: Box::new(elem_cast),
             }
         }
+        Type::MultiRange { element_type } => {
+            let return_ty = resolve_pg_type_to_scalar_type(scx, ty)?;
+            let elem_cast = build_element_cast_expr(scx, element_type, cast_oid_full_range)?;
+            CastFunc::CastStringToMultiRange {
+                return_ty,
+                cast_expr: Box::new(elem_cast),
+            }
+        }
         // reg* types require subquery-based casts that storage cannot
         // evaluate. Users can ingest them as text via TEXT COLUMNS.
         Type::RegType | Type::RegClass | Type::RegProc => {
@@ -819,7 +1018,7 @@ fn pg_type_to_cast_func(
 }
 
 /// Build the element cast expression for container types (Array, List, Map,
-/// Range). The element expression operates on a single-column input row
+/// Range, MultiRange). The element expression operates on a single-column input row
 /// containing the text-encoded element at column 0.
 fn build_element_cast_expr(
     scx: &StatementContext,
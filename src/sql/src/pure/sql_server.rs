@@ -28,8 +28,8 @@ use prost::Message;
 use crate::names::{Aug, ResolvedItemName};
 use crate::plan::{PlanError, StatementContext};
 use crate::pure::{
-    PurifiedExportDetails, PurifiedSourceExport, RequestedSourceExport, RetrievedSourceReferences,
-    SourceReferencePolicy, SqlServerSourcePurificationError,
+    AddedColumnType, PurifiedExportDetails, PurifiedSourceExport, RequestedSourceExport,
+    RetrievedSourceReferences, SourceReferencePolicy, SqlServerSourcePurificationError,
 };
 
 pub(super) struct PurifiedSourceExports {
@@ -346,7 +346,7 @@ pub fn generate_create_subsource_statements(
             excl_columns,
             details,
             external_reference,
-        } = generate_source_export_statement_values(scx, purified_export)?;
+        } = generate_source_export_statement_values(scx, purified_export, false)?;
 
         let mut with_options = vec![
             CreateSubsourceOption {
@@ -398,12 +398,16 @@ pub(super) struct SqlServerExportStatementValues {
     pub(super) external_reference: UnresolvedItemName,
 }
 
+/// Generate the columns, constraints and options of the statement for a
+/// purified source export. `evolve` indicates whether the export evolves its
+/// schema with its upstream table.
 pub(super) fn generate_source_export_statement_values(
     scx: &StatementContext,
     purified_export: PurifiedSourceExport,
+    evolve: bool,
 ) -> Result<SqlServerExportStatementValues, PlanError> {
     let PurifiedExportDetails::SqlServer {
-        table,
+        mut table,
         text_columns,
         excl_columns,
         capture_instance,
@@ -412,6 +416,9 @@ pub(super) fn generate_source_export_statement_values(
     else {
         bail_internal!("purified export details must be SQL Server");
     };
+    if evolve {
+        table.remove_varchar_limits();
+    }
 
     // Filter out columns that the user wanted to exclude.
     let included_columns = table
@@ -599,3 +606,15 @@ fn normalize_column_refs(
     seq.dedup();
     Ok(seq)
 }
+
+/// Determines the type with which `column`, which was added to an upstream
+/// table, is added to a table that is fed by a SQL Server source.
+pub fn added_column_type(
+    scx: &StatementContext,
+    column: &SqlServerColumnDesc,
+) -> Result<AddedColumnType, PlanError> {
+    let Some(column_type) = &column.column_type else {
+        bail_internal!("added column {} must not be excluded", column.name);
+    };
+    super::added_column_type(scx, mz_pgrepr::Type::from(&column_type.scalar_type), false)
+}
@@ -730,6 +730,15 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::CreateFunction(plan::CreateFunctionPlan { name, function: _ }) => RbacRequirements {
+            privileges: vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::Comment(plan::CommentPlan {
             object_id,
             sub_component: _,
@@ -1249,6 +1258,11 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::AlterTypeAddValue(plan::AlterTypeAddValuePlan { id, typ: _ }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*id)],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::AlterRole(plan::AlterRolePlan {
             id,
             name: _,
@@ -1381,6 +1395,22 @@ fn generate_rbac_requirements(
                 MutationKind::Insert => AclMode::INSERT,
                 MutationKind::Update => AclMode::UPDATE,
                 MutationKind::Delete => AclMode::DELETE,
+                MutationKind::Upsert { update: false } => AclMode::INSERT,
+                MutationKind::Upsert { update: true } => AclMode::INSERT.union(AclMode::UPDATE),
+                MutationKind::Merge {
+                    insert,
+                    update,
+                    delete,
+                } => [
+                    (*insert, AclMode::INSERT),
+                    (*update, AclMode::UPDATE),
+                    (*delete, AclMode::DELETE),
+                ]
+                .into_iter()
+                .filter(|(needed, _)| *needed)
+                .fold(AclMode::empty(), |accum, (_, acl_mode)| {
+                    accum.union(acl_mode)
+                }),
             };
             let schema_id: ObjectId = catalog.get_item(id).name().qualifiers.clone().into();
             let mut privileges = vec![
@@ -1774,6 +1804,12 @@ fn generate_read_privileges_inner(
                 CatalogItemType::Type | CatalogItemType::Secret | CatalogItemType::Connection => {
                     privileges.push((SystemObjectId::Object(id.into()), AclMode::USAGE, role_id));
                 }
+                // User-defined functions are inlined into the calling query, so
+                // the objects they reference are read with the caller's role.
+                CatalogItemType::Func if id.is_user() => {
+                    privileges.push((SystemObjectId::Object(id.into()), AclMode::USAGE, role_id));
+                    queue.extend(item.references().items().map(|id| (*id, role_id)));
+                }
                 CatalogItemType::Sink | CatalogItemType::Index | CatalogItemType::Func => {}
             }
         }
@@ -1898,7 +1934,7 @@ pub const fn all_object_privileges(object_type: SystemObjectType) -> AclMode {
         SystemObjectType::Object(ObjectType::Connection) => AclMode::USAGE,
         SystemObjectType::Object(ObjectType::Database) => USAGE_CREATE_ACL_MODE,
         SystemObjectType::Object(ObjectType::Schema) => USAGE_CREATE_ACL_MODE,
-        SystemObjectType::Object(ObjectType::Func) => EMPTY_ACL_MODE,
+        SystemObjectType::Object(ObjectType::Func) => AclMode::USAGE,
         SystemObjectType::System => ALL_SYSTEM_PRIVILEGES,
     }
 }
@@ -73,7 +73,7 @@ use mz_persist_client::cfg::PersistConfig;
 use mz_persist_client::rpc::{
     MetricsSameProcessPubSubSender, PersistGrpcPubSubServer, PubSubClientConnection, PubSubSender,
 };
-use mz_pgrepr::{Interval, Jsonb, Numeric, UInt2, UInt4, UInt8, Value, oid};
+use mz_pgrepr::{Inet, Interval, Jsonb, MacAddr, Numeric, UInt2, UInt4, UInt8, Value, oid};
 use mz_repr::ColumnName;
 use mz_repr::adt::date::Date;
 use mz_repr::adt::mz_acl_item::{AclItem, MzAclItem};
@@ -530,6 +530,9 @@ impl<'a> FromSql<'a> for Slt {
                 DateTime::<Utc>::from_sql(ty, raw)?.try_into()?,
             )),
             PgType::UUID => Self(Value::Uuid(Uuid::from_sql(ty, raw)?)),
+            PgType::INET => Self(Value::Inet(Inet::from_sql(ty, raw)?)),
+            PgType::CIDR => Self(Value::Cidr(Inet::from_sql(ty, raw)?)),
+            PgType::MACADDR => Self(Value::MacAddr(MacAddr::from_sql(ty, raw)?)),
             PgType::RECORD => {
                 let num_fields = read_be_i32(&mut raw)?;
                 let mut tuple = vec![];
@@ -554,6 +557,24 @@ impl<'a> FromSql<'a> for Slt {
                 let range: Range<Slt> = Range::from_sql(ty, raw)?;
                 Self(Value::Range(range.into_bounds(|b| Box::new(b.0))))
             }
+            PgType::INT4MULTI_RANGE
+            | PgType::INT8MULTI_RANGE
+            | PgType::DATEMULTI_RANGE
+            | PgType::NUMMULTI_RANGE
+            | PgType::TSMULTI_RANGE
+            | PgType::TSTZMULTI_RANGE => {
+                use mz_repr::adt::range::Range;
+                let PgKind::Multirange(range_type) = ty.kind() else {
+                    unreachable!()
+                };
+                let num_ranges = read_be_i32(&mut raw)?;
+                let mut ranges = vec![];
+                for _ in 0..num_ranges {
+                    let range: Range<Slt> = read_value(range_type, &mut raw)?;
+                    ranges.push(range.into_bounds(|b| Box::new(b.0)));
+                }
+                Self(Value::MultiRange(ranges))
+            }
 
             _ => match ty.kind() {
                 PgKind::Array(arr_type) => {
@@ -642,6 +663,9 @@ impl<'a> FromSql<'a> for Slt {
                 | PgType::TIMESTAMP
                 | PgType::TIMESTAMPTZ
                 | PgType::UUID
+                | PgType::INET
+                | PgType::CIDR
+                | PgType::MACADDR
                 | PgType::INT4_RANGE
                 | PgType::INT4_RANGE_ARRAY
                 | PgType::INT8_RANGE
@@ -654,6 +678,12 @@ impl<'a> FromSql<'a> for Slt {
                 | PgType::TS_RANGE_ARRAY
                 | PgType::TSTZ_RANGE
                 | PgType::TSTZ_RANGE_ARRAY
+                | PgType::INT4MULTI_RANGE
+                | PgType::INT8MULTI_RANGE
+                | PgType::DATEMULTI_RANGE
+                | PgType::NUMMULTI_RANGE
+                | PgType::TSMULTI_RANGE
+                | PgType::TSTZMULTI_RANGE
         )
     }
 }
@@ -220,6 +220,10 @@ impl Status {
     }
 }
 
+/// The key in [`StatusUpdate::namespaced_errors`] under which a source export reports that it
+/// waits for columns that were added upstream to be added to it.
+pub const SCHEMA_EVOLUTION_STATUS_NAMESPACE: &str = "schema-evolution";
+
 /// A source or sink status update.
 ///
 /// Represents a status update for a given object type. The inner value for each
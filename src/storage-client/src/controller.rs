@@ -198,6 +198,9 @@ pub struct ExportDescription<T = mz_repr::Timestamp> {
 #[derive(Debug)]
 pub enum Response {
     FrontierUpdates(Vec<(GlobalId, Antichain<Timestamp>)>),
+    /// Source exports that wait for columns that were added to their upstream table to be added
+    /// to them.
+    SchemaEvolutionPending(Vec<GlobalId>),
 }
 
 /// Metadata that the storage controller must know to properly handle the life
@@ -565,6 +568,19 @@ pub trait StorageController: Debug {
         expected_version: RelationVersion,
     ) -> Result<(), StorageError>;
 
+    /// Evolves the schema of a source export, moving the export of its
+    /// ingestion to `new_collection` with the given `details`.
+    ///
+    /// The existing collection remains readable at its previous schema.
+    async fn alter_ingestion_export_desc(
+        &mut self,
+        existing_collection: GlobalId,
+        new_collection: GlobalId,
+        new_desc: RelationDesc,
+        expected_version: RelationVersion,
+        details: SourceExportDetails,
+    ) -> Result<(), StorageError>;
+
     /// Registers the `DataSource::Table` collections among `ids` during bootstrap.
     ///
     /// Runtime registration must go through the adapter's group committer. In read-only mode, only
@@ -1899,12 +1899,18 @@ impl StorageCollections for StorageCollectionsImpl {
             Table(Reverse<GlobalId>),
             /// For most collections the id order is the correct one.
             Collection(GlobalId),
+            /// Earlier versions of tables fed by a source depend on their later
+            /// versions, so they are registered after them.
+            Superseded(Reverse<GlobalId>),
             /// Sinks should always be registered last.
             Sink(GlobalId),
         }
         to_register.sort_by_key(|(id, desc, ..)| match &desc.data_source {
             DataSource::Table => DependencyOrder::Table(Reverse(*id)),
             DataSource::Sink { .. } => DependencyOrder::Sink(*id),
+            _ if desc.primary.is_some_and(|primary| primary > *id) => {
+                DependencyOrder::Superseded(Reverse(*id))
+            }
             _ => DependencyOrder::Collection(*id),
         });
 
@@ -2100,13 +2106,17 @@ impl StorageCollections for StorageCollectionsImpl {
         new_desc: RelationDesc,
         expected_version: RelationVersion,
     ) -> Result<(), StorageError> {
-        let data_shard = {
+        let (data_shard, txns_shard) = {
             let self_collections = self.collections.lock().expect("lock poisoned");
             let existing = self_collections
                 .get(&existing_collection)
                 .ok_or_else(|| StorageError::IdentifierMissing(existing_collection))?;
 
-            existing.collection_metadata.data_shard
+            // Tables that are fed by a source are not part of the txns shard.
+            (
+                existing.collection_metadata.data_shard,
+                existing.collection_metadata.txns_shard,
+            )
         };
 
         let persist_client = self
@@ -2215,7 +2225,7 @@ impl StorageCollections for StorageCollectionsImpl {
                 persist_location: self.persist_location.clone(),
                 relation_desc: new_desc.clone(),
                 data_shard,
-                txns_shard: Some(self.txns_read.txns_id().clone()),
+                txns_shard,
             };
             let collection_state = CollectionState::new(
                 None,
@@ -2238,8 +2248,12 @@ impl StorageCollections for StorageCollectionsImpl {
             );
         };
 
-        // TODO(alter_table): Support changes to sources.
-        self.register_handles(new_collection, true, since_handle, write_handle);
+        self.register_handles(
+            new_collection,
+            txns_shard.is_some(),
+            since_handle,
+            write_handle,
+        );
 
         info!(%existing_collection, %new_collection, ?new_desc, "altered table");
 
@@ -52,8 +52,9 @@ use mz_persist_types::codec_impls::UnitSchema;
 use mz_repr::adt::timestamp::CheckedTimestamp;
 use mz_repr::{Datum, Diff, GlobalId, RelationDesc, RelationVersion, Row, Timestamp};
 use mz_storage_client::client::{
-    AppendOnlyUpdate, RunIngestionCommand, RunOneshotIngestion, RunSinkCommand, Status,
-    StatusUpdate, StorageCommand, StorageResponse, TableData,
+    AppendOnlyUpdate, RunIngestionCommand, RunOneshotIngestion, RunSinkCommand,
+    SCHEMA_EVOLUTION_STATUS_NAMESPACE, Status, StatusUpdate, StorageCommand, StorageResponse,
+    TableData,
 };
 use mz_storage_client::controller::{
     BoxFuture, CollectionDescription, DataSource, ExportDescription, ExportState,
@@ -83,7 +84,7 @@ use mz_storage_types::read_policy::ReadPolicy;
 use mz_storage_types::sinks::{StorageSinkConnection, StorageSinkDesc};
 use mz_storage_types::sources::{
     GenericSourceConnection, IngestionDescription, SourceConnection, SourceData, SourceDesc,
-    SourceExport, SourceExportDataConfig,
+    SourceExport, SourceExportDataConfig, SourceExportDetails,
 };
 use mz_storage_types::{AlterCompatible, StorageDiff, dyncfgs};
 use mz_txn_wal::metrics::Metrics as TxnMetrics;
@@ -171,6 +172,9 @@ pub struct Controller {
     /// Closures that can be used to send responses from oneshot ingestions.
     #[derivative(Debug = "ignore")]
     pending_oneshot_ingestions: BTreeMap<uuid::Uuid, PendingOneshotIngestion>,
+    /// Source exports that reported that they wait for columns that were added upstream to be
+    /// added to them, and that have not been reported to our client yet.
+    pending_schema_evolutions: BTreeSet<GlobalId>,
 
     /// Interface for managed collections
     pub(crate) collection_manager: collection_mgmt::CollectionManager,
@@ -1468,6 +1472,136 @@ impl StorageController for Controller {
         Ok(())
     }
 
+    async fn alter_ingestion_export_desc(
+        &mut self,
+        existing_collection: GlobalId,
+        new_collection: GlobalId,
+        new_desc: RelationDesc,
+        expected_version: RelationVersion,
+        details: SourceExportDetails,
+    ) -> Result<(), StorageError> {
+        let existing = self
+            .collections
+            .get(&existing_collection)
+            .ok_or(StorageError::IdentifierMissing(existing_collection))?;
+        let (ingestion_id, data_config) = match &existing.data_source {
+            DataSource::IngestionExport {
+                ingestion_id,
+                details: _,
+                data_config,
+            } => (*ingestion_id, data_config.clone()),
+            _ => return Err(StorageError::IdentifierInvalid(existing_collection)),
+        };
+        let CollectionStateExtra::Ingestion(existing_state) = &existing.extra_state else {
+            return Err(StorageError::IdentifierInvalid(existing_collection));
+        };
+        let write_frontier = existing_state.write_frontier.clone();
+        let instance_id = existing_state.instance_id;
+
+        // Let StorageCollections know!
+        self.storage_collections
+            .alter_table_desc(
+                existing_collection,
+                new_collection,
+                new_desc.clone(),
+                expected_version,
+            )
+            .await?;
+
+        let data_source = DataSource::IngestionExport {
+            ingestion_id,
+            details: details.clone(),
+            data_config: data_config.clone(),
+        };
+        let description = CollectionDescription {
+            desc: new_desc.clone(),
+            data_source: data_source.clone(),
+            since: None,
+            timeline: None,
+            primary: None,
+        };
+        let storage_dependencies =
+            self.determine_collection_dependencies(new_collection, &description)?;
+        let dependency_read_holds = self
+            .storage_collections
+            .acquire_read_holds(storage_dependencies)
+            .expect("can acquire read holds");
+        let mut dependency_since = Antichain::from_elem(Timestamp::MIN);
+        for read_hold in dependency_read_holds.iter() {
+            dependency_since.join_assign(read_hold.since());
+        }
+
+        // The new collection takes over the export from the existing one, which
+        // is no longer written by the ingestion.
+        let existing = self
+            .collections
+            .remove(&existing_collection)
+            .expect("known to exist");
+        let existing_state = CollectionState::new(
+            DataSource::Other,
+            existing.collection_metadata.clone(),
+            CollectionStateExtra::None,
+            existing.wallclock_lag_metrics,
+        );
+        self.collections.insert(existing_collection, existing_state);
+
+        let collection_meta = CollectionMetadata {
+            relation_desc: new_desc,
+            ..existing.collection_metadata
+        };
+        let ingestion_state = IngestionState {
+            read_capabilities: MutableAntichain::from(dependency_since.clone()),
+            dependency_read_holds,
+            derived_since: dependency_since,
+            write_frontier,
+            hold_policy: ReadPolicy::step_back(),
+            instance_id,
+            hydrated_on: BTreeSet::new(),
+        };
+        let wallclock_lag_metrics = self
+            .metrics
+            .wallclock_lag_metrics(new_collection, Some(instance_id));
+        let collection_state = CollectionState::new(
+            data_source,
+            collection_meta,
+            CollectionStateExtra::Ingestion(ingestion_state),
+            wallclock_lag_metrics,
+        );
+        self.collections.insert(new_collection, collection_state);
+
+        let ingestion_collection = self
+            .collections
+            .get_mut(&ingestion_id)
+            .ok_or(StorageError::IdentifierMissing(ingestion_id))?;
+        match &mut ingestion_collection.data_source {
+            DataSource::Ingestion(ingestion_desc) => {
+                ingestion_desc.source_exports.remove(&existing_collection);
+                ingestion_desc.source_exports.insert(
+                    new_collection,
+                    SourceExport {
+                        storage_metadata: (),
+                        details,
+                        data_config,
+                    },
+                );
+            }
+            o => {
+                tracing::warn!("alter_ingestion_export_desc called on {:?}", o);
+                Err(StorageError::IdentifierInvalid(ingestion_id))?
+            }
+        }
+
+        self.append_shard_mappings([new_collection].into_iter(), Diff::ONE);
+
+        if !self.read_only {
+            self.run_ingestion(ingestion_id)?;
+        }
+
+        info!(%existing_collection, %new_collection, "altered source export");
+
+        Ok(())
+    }
+
     async fn register_table_collections(
         &mut self,
         register_ts: Timestamp,
@@ -2192,6 +2326,10 @@ impl StorageController for Controller {
             return;
         }
 
+        if !self.pending_schema_evolutions.is_empty() {
+            return;
+        }
+
         tokio::select! {
             Some(m) = self.instance_response_rx.recv() => {
                 self.stashed_responses.push(m);
@@ -2413,6 +2551,18 @@ impl StorageController for Controller {
                                             // here
                             }
                         }
+                        Status::Stalled => {
+                            // Source exports report under a dedicated namespace that they wait
+                            // for columns that were added upstream. Our client evolves their
+                            // schema, after which the source can resume.
+                            if status_update
+                                .namespaced_errors
+                                .contains_key(SCHEMA_EVOLUTION_STATUS_NAMESPACE)
+                                && self.collections.contains_key(&status_update.id)
+                            {
+                                self.pending_schema_evolutions.insert(status_update.id);
+                            }
+                        }
                         _ => (),
                     }
 
@@ -2457,12 +2607,17 @@ impl StorageController for Controller {
             self.drop_sources(storage_metadata, dropped_table_ids)?;
         }
 
-        if updated_frontiers.is_empty() {
-            Ok(None)
-        } else {
+        if !updated_frontiers.is_empty() {
             Ok(Some(Response::FrontierUpdates(
                 updated_frontiers.into_iter().collect(),
             )))
+        } else if !self.pending_schema_evolutions.is_empty() {
+            let ids = std::mem::take(&mut self.pending_schema_evolutions);
+            Ok(Some(Response::SchemaEvolutionPending(
+                ids.into_iter().collect(),
+            )))
+        } else {
+            Ok(None)
         }
     }
 
@@ -2659,6 +2814,7 @@ impl StorageController for Controller {
             pending_table_handle_drops_tx: _,
             pending_table_handle_drops_rx: _,
             pending_oneshot_ingestions,
+            pending_schema_evolutions,
             collection_manager: _,
             introspection_ids,
             introspection_tokens: _,
@@ -2698,6 +2854,10 @@ impl StorageController for Controller {
             .iter()
             .map(|(uuid, i)| (uuid.to_string(), format!("{i:?}")))
             .collect();
+        let pending_schema_evolutions: Vec<_> = pending_schema_evolutions
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
         let introspection_ids: BTreeMap<_, _> = introspection_ids
             .iter()
             .map(|(typ, id)| (format!("{typ:?}"), id.to_string()))
@@ -2725,6 +2885,7 @@ impl StorageController for Controller {
             "dropped_objects": dropped_objects,
             "stashed_responses": stashed_responses,
             "pending_oneshot_ingestions": pending_oneshot_ingestions,
+            "pending_schema_evolutions": pending_schema_evolutions,
             "introspection_ids": introspection_ids,
             "instances": instances,
             "initialized": initialized,
@@ -2860,6 +3021,7 @@ where
             pending_table_handle_drops_tx,
             pending_table_handle_drops_rx,
             pending_oneshot_ingestions: BTreeMap::default(),
+            pending_schema_evolutions: BTreeSet::new(),
             collection_manager,
             introspection_ids,
             introspection_tokens,
@@ -2950,6 +3112,9 @@ where
                 CollectionStateExtra::None => {
                     if matches!(collection.data_source, DataSource::Progress) {
                         // We do get these, but can't do anything with it!
+                    } else if matches!(collection.data_source, DataSource::Other) {
+                        // Source exports whose schema was evolved might still
+                        // report frontiers before their ingestion is restarted.
                     } else {
                         tracing::error!(
                             ?collection,
@@ -81,6 +81,7 @@ use mz_persist_types::codec_impls::UnitSchema;
 use mz_repr::{DatumVec, GlobalId, Row, RowArena, Timestamp};
 use mz_storage_types::StorageDiff;
 use mz_storage_types::connections::ConnectionContext;
+use mz_storage_types::connections::http::build_http_client;
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::oneshot_sources::{
     ContentFilter, ContentFormat, ContentSource, OneshotIngestionRequest,
@@ -101,17 +102,21 @@ use timely::dataflow::{Scope, StreamVec};
 use timely::progress::Antichain;
 use tracing::info;
 
+use crate::oneshot_source::avro::{AvroBlock, AvroDecoder, AvroWorkRequest};
 use crate::oneshot_source::aws_source::{AwsS3Source, S3Checksum, S3Object};
 use crate::oneshot_source::csv::{CsvDecoder, CsvRecord, CsvWorkRequest};
-use crate::oneshot_source::http_source::{
-    HttpChecksum, HttpObject, HttpOneshotSource, build_http_client,
-};
+use crate::oneshot_source::gcs_source::{GcsChecksum, GcsObject, GcsSource};
+use crate::oneshot_source::http_source::{HttpChecksum, HttpObject, HttpOneshotSource};
+use crate::oneshot_source::json::{JsonDecoder, JsonLines, JsonWorkRequest};
 use crate::oneshot_source::parquet::{ParquetFormat, ParquetRowGroup, ParquetWorkRequest};
 
+pub mod avro;
 pub mod csv;
+pub mod json;
 pub mod parquet;
 
 pub mod aws_source;
+pub mod gcs_source;
 pub mod http_source;
 
 mod util;
@@ -193,6 +198,26 @@ where
             );
             SourceKind::AwsS3(source)
         }
+        ContentSource::Gcs {
+            connection,
+            connection_id,
+            uri,
+        } => {
+            let source = GcsSource::new(
+                connection,
+                connection_id,
+                connection_context,
+                uri,
+                enforce_external_addresses,
+            );
+            match source {
+                Ok(source) => SourceKind::Gcs(source),
+                Err(err) => {
+                    worker_callback(Err(format!("failed to create GCS source: {err}")));
+                    return Vec::new();
+                }
+            }
+        }
     };
     tracing::info!(?source, "created oneshot source");
 
@@ -205,6 +230,14 @@ where
             let format = ParquetFormat::new(shape.source_desc);
             FormatKind::Parquet(format)
         }
+        ContentFormat::Json(params) => {
+            let format = JsonDecoder::new(params, &shape.source_desc);
+            FormatKind::Json(format)
+        }
+        ContentFormat::Avro => {
+            let format = AvroDecoder::new(&shape.source_desc);
+            FormatKind::Avro(format)
+        }
     };
 
     // Discover what objects are available to copy.
@@ -766,6 +799,7 @@ pub trait OneshotSource: Clone + Send + Unpin {
 pub(crate) enum SourceKind {
     Http(HttpOneshotSource),
     AwsS3(AwsS3Source),
+    Gcs(GcsSource),
 }
 
 impl OneshotSource for SourceKind {
@@ -794,6 +828,16 @@ impl OneshotSource for SourceKind {
                     .collect();
                 Ok(objects)
             }
+            SourceKind::Gcs(gcs) => {
+                let objects = gcs.list().await.context("gcs")?;
+                let objects = objects
+                    .into_iter()
+                    .map(|(object, checksum)| {
+                        (ObjectKind::Gcs(object), ChecksumKind::Gcs(checksum))
+                    })
+                    .collect();
+                Ok(objects)
+            }
         }
     }
 
@@ -813,7 +857,11 @@ impl OneshotSource for SourceKind {
                 .get(object, checksum, range)
                 .map(|result| result.context("aws_s3"))
                 .boxed(),
-            (SourceKind::AwsS3(_) | SourceKind::Http(_), _, _) => {
+            (SourceKind::Gcs(gcs), ObjectKind::Gcs(object), ChecksumKind::Gcs(checksum)) => gcs
+                .get(object, checksum, range)
+                .map(|result| result.context("gcs"))
+                .boxed(),
+            (SourceKind::AwsS3(_) | SourceKind::Http(_) | SourceKind::Gcs(_), _, _) => {
                 unreachable!("programming error! wrong source, object, and checksum kind");
             }
         }
@@ -825,6 +873,7 @@ impl OneshotSource for SourceKind {
 pub(crate) enum ObjectKind {
     Http(HttpObject),
     AwsS3(S3Object),
+    Gcs(GcsObject),
 }
 
 impl OneshotObject for ObjectKind {
@@ -832,6 +881,7 @@ impl OneshotObject for ObjectKind {
         match self {
             ObjectKind::Http(object) => object.name(),
             ObjectKind::AwsS3(object) => object.name(),
+            ObjectKind::Gcs(object) => object.name(),
         }
     }
 
@@ -839,6 +889,7 @@ impl OneshotObject for ObjectKind {
         match self {
             ObjectKind::Http(object) => object.path(),
             ObjectKind::AwsS3(object) => object.path(),
+            ObjectKind::Gcs(object) => object.path(),
         }
     }
 
@@ -846,6 +897,7 @@ impl OneshotObject for ObjectKind {
         match self {
             ObjectKind::Http(object) => object.size(),
             ObjectKind::AwsS3(object) => object.size(),
+            ObjectKind::Gcs(object) => object.size(),
         }
     }
 
@@ -853,6 +905,7 @@ impl OneshotObject for ObjectKind {
         match self {
             ObjectKind::Http(object) => object.encodings(),
             ObjectKind::AwsS3(object) => object.encodings(),
+            ObjectKind::Gcs(object) => object.encodings(),
         }
     }
 }
@@ -862,6 +915,7 @@ impl OneshotObject for ObjectKind {
 pub(crate) enum ChecksumKind {
     Http(HttpChecksum),
     AwsS3(S3Checksum),
+    Gcs(GcsChecksum),
 }
 
 /// Defines a format that we fetch for a "one time" ingestion.
@@ -910,6 +964,8 @@ pub trait OneshotFormat: Clone {
 pub(crate) enum FormatKind {
     Csv(CsvDecoder),
     Parquet(ParquetFormat),
+    Json(JsonDecoder),
+    Avro(AvroDecoder),
 }
 
 impl OneshotFormat for FormatKind {
@@ -946,6 +1002,26 @@ impl OneshotFormat for FormatKind {
                     .collect();
                 Ok(work)
             }
+            FormatKind::Json(json) => {
+                let work = json
+                    .split_work(source, object, checksum)
+                    .await
+                    .context("json")?
+                    .into_iter()
+                    .map(RequestKind::Json)
+                    .collect();
+                Ok(work)
+            }
+            FormatKind::Avro(avro) => {
+                let work = avro
+                    .split_work(source, object, checksum)
+                    .await
+                    .context("avro")?
+                    .into_iter()
+                    .map(RequestKind::Avro)
+                    .collect();
+                Ok(work)
+            }
         }
     }
 
@@ -965,8 +1041,23 @@ impl OneshotFormat for FormatKind {
                 .map_ok(RecordChunkKind::Parquet)
                 .map(|result| result.context("parquet"))
                 .boxed(),
-            (FormatKind::Parquet(_), RequestKind::Csv(_))
-            | (FormatKind::Csv(_), RequestKind::Parquet(_)) => {
+            (FormatKind::Json(json), RequestKind::Json(request)) => json
+                .fetch_work(source, request)
+                .map_ok(RecordChunkKind::Json)
+                .map(|result| result.context("json"))
+                .boxed(),
+            (FormatKind::Avro(avro), RequestKind::Avro(request)) => avro
+                .fetch_work(source, request)
+                .map_ok(RecordChunkKind::Avro)
+                .map(|result| result.context("avro"))
+                .boxed(),
+            (
+                FormatKind::Csv(_)
+                | FormatKind::Parquet(_)
+                | FormatKind::Json(_)
+                | FormatKind::Avro(_),
+                _,
+            ) => {
                 unreachable!("programming error, {self:?}")
             }
         }
@@ -984,8 +1075,19 @@ impl OneshotFormat for FormatKind {
             (FormatKind::Parquet(parquet), RecordChunkKind::Parquet(chunk)) => {
                 parquet.decode_chunk(chunk, rows).context("parquet")
             }
-            (FormatKind::Parquet(_), RecordChunkKind::Csv(_))
-            | (FormatKind::Csv(_), RecordChunkKind::Parquet(_)) => {
+            (FormatKind::Json(json), RecordChunkKind::Json(chunk)) => {
+                json.decode_chunk(chunk, rows).context("json")
+            }
+            (FormatKind::Avro(avro), RecordChunkKind::Avro(chunk)) => {
+                avro.decode_chunk(chunk, rows).context("avro")
+            }
+            (
+                FormatKind::Csv(_)
+                | FormatKind::Parquet(_)
+                | FormatKind::Json(_)
+                | FormatKind::Avro(_),
+                _,
+            ) => {
                 unreachable!("programming error, {self:?}")
             }
         }
@@ -996,12 +1098,16 @@ impl OneshotFormat for FormatKind {
 pub(crate) enum RequestKind<O, C> {
     Csv(CsvWorkRequest<O, C>),
     Parquet(ParquetWorkRequest<O, C>),
+    Json(JsonWorkRequest<O, C>),
+    Avro(AvroWorkRequest<O, C>),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub(crate) enum RecordChunkKind {
     Csv(CsvRecord),
     Parquet(ParquetRowGroup),
+    Json(JsonLines),
+    Avro(AvroBlock),
 }
 
 pub(crate) enum ObjectFilter {
@@ -1062,6 +1168,10 @@ pub enum StorageErrorXKind {
     CsvDecoding(Arc<str>),
     #[error("parquet error: {0}")]
     ParquetError(Arc<str>),
+    #[error("json decoding error: {0}")]
+    JsonDecoding(Arc<str>),
+    #[error("avro decoding error: {0}")]
+    AvroDecoding(Arc<str>),
     #[error("reqwest error: {0}")]
     Reqwest(Arc<str>),
     #[error("aws s3 request error: {0}")]
@@ -1122,6 +1232,18 @@ impl From<::parquet::errors::ParquetError> for StorageErrorXKind {
     }
 }
 
+impl From<serde_json::Error> for StorageErrorXKind {
+    fn from(err: serde_json::Error) -> Self {
+        StorageErrorXKind::JsonDecoding(err.to_string().into())
+    }
+}
+
+impl From<mz_avro::error::Error> for StorageErrorXKind {
+    fn from(err: mz_avro::error::Error) -> Self {
+        StorageErrorXKind::AvroDecoding(err.to_string().into())
+    }
+}
+
 impl StorageErrorXKind {
     pub fn with_context<C: Display>(self, context: C) -> StorageErrorX {
         StorageErrorX {
@@ -0,0 +1,379 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Avro Object Container File to Row Decoder.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::io;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use bytes::{Buf, BytesMut};
+use futures::stream::{BoxStream, StreamExt};
+use mz_avro::error::Error as AvroError;
+use mz_avro::schema::Schema;
+use mz_avro::types::{DecimalValue, Value};
+use mz_avro::{Block, Header, from_avro_datum};
+use mz_repr::adt::date::Date;
+use mz_repr::adt::jsonb::JsonbPacker;
+use mz_repr::adt::numeric::twos_complement_be_to_numeric;
+use mz_repr::{Datum, RelationDesc, Row, RowArena, RowPacker};
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::oneshot_source::util::{decompressed_reader, encodings_from_name};
+use crate::oneshot_source::{
+    Encoding, OneshotFormat, OneshotObject, OneshotSource, StorageErrorX, StorageErrorXKind,
+};
+
+#[derive(Debug, Clone)]
+pub struct AvroDecoder {
+    /// Names of the columns of the table we're copying into.
+    column_names: Arc<[String]>,
+    /// Types of the table we're copying into.
+    column_types: Arc<[mz_pgrepr::Type]>,
+    /// Schemas of the blocks we've decoded, keyed by their JSON.
+    schemas: Arc<Mutex<BTreeMap<Arc<str>, Arc<Schema>>>>,
+}
+
+impl AvroDecoder {
+    pub fn new(desc: &RelationDesc) -> Self {
+        let column_names = desc.iter_names().map(|name| name.to_string()).collect();
+        let column_types = desc
+            .iter_types()
+            .map(|x| &x.scalar_type)
+            .map(mz_pgrepr::Type::from)
+            .collect();
+        AvroDecoder {
+            column_names,
+            column_types,
+            schemas: Arc::default(),
+        }
+    }
+
+    /// Returns the parsed form of `schema`, which is the same for all blocks
+    /// of a file, so it is only parsed once.
+    fn schema(&self, schema: &Arc<str>) -> Result<Arc<Schema>, StorageErrorXKind> {
+        let mut schemas = self.schemas.lock().expect("lock poisoned");
+        if let Some(parsed) = schemas.get(schema) {
+            return Ok(Arc::clone(parsed));
+        }
+        let parsed = Arc::new(Schema::from_str(schema)?);
+        schemas.insert(Arc::clone(schema), Arc::clone(&parsed));
+        Ok(parsed)
+    }
+
+    /// Packs a single Avro record into `packer`.
+    fn pack_record(
+        &self,
+        value: Value,
+        packer: &mut RowPacker,
+        arena: &RowArena,
+    ) -> Result<(), StorageErrorXKind> {
+        let Value::Record(mut fields) = value else {
+            let msg = "expected the Avro schema to be a record";
+            return Err(StorageErrorXKind::invalid_record_batch(msg));
+        };
+
+        // Fields without a matching column are ignored, and columns without a
+        // matching field are NULL.
+        for (name, typ) in self.column_names.iter().zip(self.column_types.iter()) {
+            let value = fields
+                .iter()
+                .position(|(field, _)| field == name)
+                .map(|idx| fields.swap_remove(idx).1);
+            match value {
+                None => packer.push(Datum::Null),
+                Some(value) => pack_value(value, typ, packer, arena).map_err(|err| {
+                    StorageErrorXKind::invalid_record_batch(format!("column \"{name}\": {err}"))
+                })?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Packs an Avro value into `packer` as a datum of type `typ`.
+fn pack_value(
+    value: Value,
+    typ: &mz_pgrepr::Type,
+    packer: &mut RowPacker,
+    arena: &RowArena,
+) -> Result<(), String> {
+    let text = match (value, typ) {
+        (Value::Union { inner, .. }, _) => return pack_value(*inner, typ, packer, arena),
+        (Value::Null, _) => {
+            packer.push(Datum::Null);
+            return Ok(());
+        }
+        (value, mz_pgrepr::Type::Jsonb) => {
+            return JsonbPacker::new(packer)
+                .pack_serde_json(avro_to_json(value)?)
+                .map_err(|err| err.to_string());
+        }
+        (Value::Bytes(bytes) | Value::Fixed(_, bytes), mz_pgrepr::Type::Bytea) => {
+            packer.push(Datum::Bytes(&bytes));
+            return Ok(());
+        }
+        (value, _) => avro_to_text(value)?,
+    };
+
+    // Decode everything else from its text representation.
+    let value = mz_pgrepr::Value::decode_text(typ, text.as_bytes()).map_err(|e| e.to_string())?;
+    let datum = value.into_datum_decode_error(arena, typ, "record field")?;
+    packer.push(datum);
+    Ok(())
+}
+
+/// Returns the text representation of a scalar Avro value.
+fn avro_to_text(value: Value) -> Result<String, String> {
+    let text = match value {
+        Value::Boolean(b) => b.to_string(),
+        Value::Int(i) => i.to_string(),
+        Value::Long(i) => i.to_string(),
+        Value::Float(f) => f.to_string(),
+        Value::Double(f) => f.to_string(),
+        Value::Date(days) => Date::from_unix_epoch(days)
+            .map_err(|e| e.to_string())?
+            .to_string(),
+        Value::Timestamp(ts) => ts.to_string(),
+        Value::Decimal(DecimalValue {
+            mut unscaled,
+            scale,
+            ..
+        }) => {
+            let scale =
+                u8::try_from(scale).map_err(|_| format!("decimal scale {scale} too large"))?;
+            twos_complement_be_to_numeric(&mut unscaled, scale)
+                .map_err(|e| e.to_string())?
+                .to_string()
+        }
+        Value::String(s) | Value::Enum(_, s) => s,
+        Value::Uuid(uuid) => uuid.to_string(),
+        Value::Json(json) => json.to_string(),
+        Value::Bytes(_) | Value::Fixed(..) => {
+            return Err("Avro bytes can only be loaded into bytea or jsonb columns".into());
+        }
+        Value::Array(_) | Value::Map(_) | Value::Record(_) => {
+            return Err(
+                "Avro arrays, maps, and records can only be loaded into jsonb columns".into(),
+            );
+        }
+        Value::Null | Value::Union { .. } => unreachable!("handled by pack_value"),
+    };
+    Ok(text)
+}
+
+/// Converts an Avro value into JSON.
+///
+/// Values without a JSON equivalent, e.g. decimals and timestamps, are
+/// represented as strings.
+fn avro_to_json(value: Value) -> Result<serde_json::Value, String> {
+    let json = match value {
+        Value::Null => serde_json::Value::Null,
+        Value::Boolean(b) => serde_json::Value::Bool(b),
+        Value::Int(i) => serde_json::Value::from(i),
+        Value::Long(i) => serde_json::Value::from(i),
+        Value::Float(f) => serde_json::Value::from(f),
+        Value::Double(f) => serde_json::Value::from(f),
+        Value::Union { inner, .. } => avro_to_json(*inner)?,
+        Value::Array(values) => serde_json::Value::Array(
+            values
+                .into_iter()
+                .map(avro_to_json)
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::Map(entries) => serde_json::Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| Ok((key, avro_to_json(value)?)))
+                .collect::<Result<_, String>>()?,
+        ),
+        Value::Record(fields) => serde_json::Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| Ok((key, avro_to_json(value)?)))
+                .collect::<Result<_, String>>()?,
+        ),
+        Value::Json(json) => json,
+        Value::Bytes(bytes) | Value::Fixed(_, bytes) => {
+            let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+            serde_json::Value::String(format!("\\x{hex}"))
+        }
+        value => serde_json::Value::String(avro_to_text(value)?),
+    };
+    Ok(json)
+}
+
+/// Instructions on how to parse a single Avro file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AvroWorkRequest<O, C> {
+    object: O,
+    checksum: C,
+    encodings: SmallVec<[Encoding; 1]>,
+}
+
+/// A block of values from an Avro file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AvroBlock {
+    /// Schema the values were written with, as JSON.
+    schema: Arc<str>,
+    /// Decompressed bytes of the block.
+    bytes: Vec<u8>,
+    /// Number of values in the block.
+    len: usize,
+}
+
+impl OneshotFormat for AvroDecoder {
+    type WorkRequest<S>
+        = AvroWorkRequest<S::Object, S::Checksum>
+    where
+        S: OneshotSource;
+    type RecordChunk = AvroBlock;
+
+    async fn split_work<S: OneshotSource + Send>(
+        &self,
+        _source: S,
+        object: S::Object,
+        checksum: S::Checksum,
+    ) -> Result<Vec<Self::WorkRequest<S>>, StorageErrorX> {
+        // A single worker reads each file, and the blocks it reads are
+        // distributed across all workers for decoding.
+        //
+        // TODO: Blocks are delimited by a sync marker, so uncompressed
+        // files could be split into byte ranges like Parquet row groups.
+        let encodings = encodings_from_name(object.name());
+
+        let request = AvroWorkRequest {
+            object,
+            checksum,
+            encodings,
+        };
+        Ok(vec![request])
+    }
+
+    fn fetch_work<'a, S: OneshotSource + Sync + 'static>(
+        &'a self,
+        source: &'a S,
+        request: Self::WorkRequest<S>,
+    ) -> BoxStream<'a, Result<Self::RecordChunk, StorageErrorX>> {
+        let AvroWorkRequest {
+            object,
+            checksum,
+            encodings,
+        } = request;
+
+        let mut reader = decompressed_reader(source, object, checksum, encodings);
+
+        async_stream::try_stream!({
+            // Only the bytes of the block that is being read are buffered.
+            let mut buf = BytesMut::new();
+            let header = read_next(&mut reader, &mut buf, |bytes| Header::from_reader(bytes))
+                .await
+                .map_err(|err| err.with_context("avro header"))?
+                .ok_or_else(|| {
+                    let msg = "expected an Avro Object Container File, found an empty file";
+                    StorageErrorXKind::invalid_record_batch(msg).with_context("avro header")
+                })?;
+            let schema: Arc<str> = serde_json::to_string(header.writer_schema())
+                .map_err(|err| StorageErrorXKind::from(err).with_context("avro schema"))?
+                .into();
+
+            while let Some(block) =
+                read_next(&mut reader, &mut buf, |bytes| header.read_block(bytes))
+                    .await
+                    .map_err(|err| err.with_context("avro block"))?
+            {
+                let Block { bytes, len } = block;
+                yield AvroBlock {
+                    schema: Arc::clone(&schema),
+                    bytes,
+                    len,
+                };
+            }
+        })
+        .boxed()
+    }
+
+    fn decode_chunk(
+        &self,
+        chunk: Self::RecordChunk,
+        rows: &mut Vec<Row>,
+    ) -> Result<usize, StorageErrorX> {
+        let AvroBlock { schema, bytes, len } = chunk;
+        let schema = self
+            .schema(&schema)
+            .map_err(|err| err.with_context("avro schema"))?;
+
+        let arena = RowArena::new();
+        let mut bytes = &bytes[..];
+        for _ in 0..len {
+            let value = from_avro_datum(&schema, &mut bytes)
+                .map_err(|err| StorageErrorXKind::from(err).with_context("avro decoding"))?;
+
+            // Decode a Row from the Avro record.
+            let mut row = Row::default();
+            self.pack_record(value, &mut row.packer(), &arena)
+                .map_err(|err| err.with_context("avro decoding"))?;
+            rows.push(row);
+        }
+
+        Ok(len)
+    }
+}
+
+/// Reads the next item of an Avro file, i.e. its header or one of its blocks,
+/// with `parse`, buffering bytes from `reader` in `buf` until `parse` no longer
+/// runs out of them.
+///
+/// Returns `None` if `reader` ended before the item started.
+async fn read_next<R, T>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+    parse: impl Fn(&mut &[u8]) -> Result<T, AvroError>,
+) -> Result<Option<T>, StorageErrorXKind>
+where
+    R: AsyncRead + Unpin,
+{
+    /// The least number of bytes we read at a time.
+    const READ_SIZE: usize = 64 * 1024;
+
+    let mut eof = false;
+    loop {
+        let mut bytes = &buf[..];
+        match parse(&mut bytes) {
+            Ok(item) => {
+                let consumed = buf.len() - bytes.len();
+                buf.advance(consumed);
+                return Ok(Some(item));
+            }
+            Err(AvroError::IO(io::ErrorKind::UnexpectedEof)) if !eof => {
+                // Read until the buffer at least doubles, so that a large
+                // block isn't parsed again for every chunk of the byte stream.
+                let target = buf.len().max(READ_SIZE) * 2;
+                buf.reserve(target - buf.len());
+                while buf.len() < target {
+                    let read = reader
+                        .read_buf(buf)
+                        .await
+                        .map_err(StorageErrorXKind::generic)?;
+                    if read == 0 {
+                        eof = true;
+                        break;
+                    }
+                }
+            }
+            Err(AvroError::IO(io::ErrorKind::UnexpectedEof)) if buf.is_empty() => return Ok(None),
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Google Cloud Storage [`OneshotSource`].
+
+use std::path::Path;
+use std::sync::Arc;
+
+use derivative::Derivative;
+use futures::StreamExt;
+use futures::stream::{BoxStream, TryStreamExt};
+use mz_ore::error::ErrorExt;
+use mz_repr::CatalogItemId;
+use mz_storage_types::connections::ConnectionContext;
+use mz_storage_types::connections::gcp::GcpConnection;
+use mz_storage_types::connections::gcp::gcs::GcsClient;
+use serde::{Deserialize, Serialize};
+use tokio::sync::OnceCell;
+
+use crate::oneshot_source::{
+    OneshotObject, OneshotSource, StorageErrorX, StorageErrorXContext, StorageErrorXKind,
+};
+
+/// Characters that start a glob in a GCS URI.
+const GLOB_CHARS: &[char] = &['*', '?', '['];
+
+#[derive(Clone, Derivative)]
+#[derivative(Debug)]
+pub struct GcsSource {
+    // Only used for initialization.
+    #[derivative(Debug = "ignore")]
+    connection: Arc<GcpConnection>,
+    connection_id: CatalogItemId,
+    #[derivative(Debug = "ignore")]
+    context: Arc<ConnectionContext>,
+
+    /// Name of the GCS bucket we'll list from.
+    bucket: String,
+    /// Optional prefix that can be specified via a GCS URI.
+    prefix: Option<String>,
+    /// Glob that object names must match, if the GCS URI contains one.
+    pattern: Option<glob::Pattern>,
+    /// GCS client that is lazily initialized.
+    #[derivative(Debug = "ignore")]
+    client: Arc<OnceCell<GcsClient>>,
+    enforce_external_addresses: bool,
+}
+
+impl GcsSource {
+    pub fn new(
+        connection: GcpConnection,
+        connection_id: CatalogItemId,
+        context: ConnectionContext,
+        uri: String,
+        enforce_external_addresses: bool,
+    ) -> Result<Self, StorageErrorXKind> {
+        let path = uri
+            .strip_prefix("gs://")
+            .expect("validated URI in sequencing");
+        let (bucket, path) = path.split_once('/').unwrap_or((path, ""));
+
+        // A URI like `gs://bucket/events/part-*.csv` lists everything below the
+        // literal prefix and filters with the glob, while a URI without a glob
+        // refers to a "directory", like the S3 source.
+        let (prefix, pattern) = match path.find(GLOB_CHARS) {
+            Some(idx) => {
+                let pattern = glob::Pattern::new(path).map_err(|err| {
+                    StorageErrorXKind::generic(format!("invalid glob in GCS uri: {err}"))
+                })?;
+                (Some(path[..idx].to_string()), Some(pattern))
+            }
+            None if path.is_empty() => (None, None),
+            None if path.ends_with('/') => (Some(path.to_string()), None),
+            None => (Some(format!("{path}/")), None),
+        };
+        let prefix = prefix.filter(|prefix| !prefix.is_empty());
+
+        Ok(GcsSource {
+            connection: Arc::new(connection),
+            context: Arc::new(context),
+            connection_id,
+            bucket: bucket.to_string(),
+            prefix,
+            pattern,
+            client: Arc::new(OnceCell::new()),
+            enforce_external_addresses,
+        })
+    }
+
+    pub async fn client(&self) -> Result<&GcsClient, StorageErrorXKind> {
+        self.client
+            .get_or_try_init(|| async {
+                self.connection
+                    .gcs_client(&self.context, self.enforce_external_addresses)
+                    .await
+                    .map_err(|err| StorageErrorXKind::generic(err.display_with_causes()))
+            })
+            .await
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GcsObject {
+    /// Key of the object in the bucket.
+    key: String,
+    /// Name of the object, generally the last component of the key.
+    name: String,
+    /// Size of the object in bytes.
+    size: usize,
+}
+
+impl OneshotObject for GcsObject {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn path(&self) -> &str {
+        &self.key
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn encodings(&self) -> &[super::Encoding] {
+        &[]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GcsChecksum {
+    /// Generation of the object when we listed it, which lets us detect that
+    /// the object was overwritten while we were reading it.
+    generation: String,
+}
+
+impl OneshotSource for GcsSource {
+    type Object = GcsObject;
+    type Checksum = GcsChecksum;
+
+    async fn list<'a>(
+        &'a self,
+    ) -> Result<Vec<(Self::Object, Self::Checksum)>, super::StorageErrorX> {
+        let client = self.client().await?;
+        let objects = client
+            .list_objects(&self.bucket, self.prefix.as_deref())
+            .await
+            .map_err(|err| StorageErrorXKind::generic(err.display_with_causes()))
+            .context("list_objects")?;
+
+        let objects: Vec<_> = objects
+            .into_iter()
+            // GCS consoles create zero-sized "folder" objects ending with a `/`.
+            .filter(|o| !o.name.ends_with('/'))
+            .filter(|o| match &self.pattern {
+                Some(pattern) => pattern.matches(&o.name),
+                None => true,
+            })
+            .map(|o| {
+                let name = Path::new(&o.name)
+                    .file_name()
+                    .and_then(|os_name| os_name.to_str())
+                    .ok_or_else(|| {
+                        StorageErrorXKind::Generic(format!("malformed key: {}", o.name))
+                    })?
+                    .to_string();
+                let size: usize = o.size.try_into().map_err(StorageErrorXKind::generic)?;
+
+                let object = GcsObject {
+                    key: o.name,
+                    name,
+                    size,
+                };
+                let checksum = GcsChecksum {
+                    generation: o.generation,
+                };
+
+                Ok::<_, StorageErrorXKind>((object, checksum))
+            })
+            .collect::<Result<_, _>>()
+            .context("list")?;
+
+        Ok(objects)
+    }
+
+    fn get<'s>(
+        &'s self,
+        object: Self::Object,
+        checksum: Self::Checksum,
+        range: Option<std::ops::RangeInclusive<usize>>,
+    ) -> BoxStream<'s, Result<bytes::Bytes, StorageErrorX>> {
+        let initial_response = async move {
+            tracing::info!(name = %object.name(), ?range, "fetching object");
+
+            let client = self.client().await?;
+            let stream = client
+                .get_object(&self.bucket, &object.key, Some(&checksum.generation), range)
+                .await
+                .map_err(|err| StorageErrorXKind::generic(err.display_with_causes()))?
+                .err_into()
+                .boxed();
+
+            Ok::<_, StorageErrorX>(stream)
+        };
+
+        futures::stream::once(initial_response)
+            .try_flatten()
+            .boxed()
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Newline-delimited JSON to Row Decoder.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use futures::stream::{BoxStream, StreamExt};
+use mz_pgcopy::CopyJsonFormatParams;
+use mz_repr::adt::jsonb::JsonbPacker;
+use mz_repr::{Datum, RelationDesc, Row, RowArena, RowPacker};
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+use crate::oneshot_source::util::{decompressed_reader, encodings_from_name};
+use crate::oneshot_source::{
+    Encoding, OneshotFormat, OneshotObject, OneshotSource, StorageErrorX, StorageErrorXKind,
+};
+
+/// Maximum number of lines that get decoded together.
+const LINES_PER_CHUNK: usize = 1024;
+
+#[derive(Debug, Clone)]
+pub struct JsonDecoder {
+    /// Properties of the JSON Reader.
+    params: CopyJsonFormatParams,
+    /// Names of the columns of the table we're copying into.
+    column_names: Arc<[String]>,
+    /// Types of the table we're copying into.
+    column_types: Arc<[mz_pgrepr::Type]>,
+}
+
+impl JsonDecoder {
+    pub fn new(params: CopyJsonFormatParams, desc: &RelationDesc) -> Self {
+        let column_names = desc.iter_names().map(|name| name.to_string()).collect();
+        let column_types = desc
+            .iter_types()
+            .map(|x| &x.scalar_type)
+            .map(mz_pgrepr::Type::from)
+            .collect();
+        JsonDecoder {
+            params,
+            column_names,
+            column_types,
+        }
+    }
+
+    /// Packs the JSON value of a single line into `packer`.
+    fn pack_line(
+        &self,
+        line: &[u8],
+        packer: &mut RowPacker,
+        arena: &RowArena,
+    ) -> Result<(), StorageErrorXKind> {
+        if self.params.single_column {
+            // We check in planning that the table has a single `jsonb` column.
+            return JsonbPacker::new(packer)
+                .pack_slice(line)
+                .map_err(StorageErrorXKind::from);
+        }
+
+        let value: serde_json::Value = serde_json::from_slice(line)?;
+        let serde_json::Value::Object(mut object) = value else {
+            let msg = "expected a JSON object, use SINGLE COLUMN to load other values";
+            return Err(StorageErrorXKind::invalid_record_batch(msg));
+        };
+
+        // Keys without a matching column are ignored, and columns without a
+        // matching key are NULL.
+        for (name, typ) in self.column_names.iter().zip(self.column_types.iter()) {
+            let value = match object.remove(name) {
+                None | Some(serde_json::Value::Null) => {
+                    packer.push(Datum::Null);
+                    continue;
+                }
+                Some(value) => value,
+            };
+            if *typ == mz_pgrepr::Type::Jsonb {
+                JsonbPacker::new(packer).pack_serde_json(value)?;
+                continue;
+            }
+
+            // Decode everything else from its text representation, which for
+            // strings is their contents rather than the quoted JSON string.
+            let text = match value {
+                serde_json::Value::String(s) => s,
+                other => other.to_string(),
+            };
+            let value = mz_pgrepr::Value::decode_text(typ, text.as_bytes()).map_err(|err| {
+                StorageErrorXKind::invalid_record_batch(format!("column \"{name}\": {err}"))
+            })?;
+            let datum = value
+                .into_datum_decode_error(arena, typ, "record field")
+                .map_err(StorageErrorXKind::invalid_record_batch)?;
+            packer.push(datum);
+        }
+
+        Ok(())
+    }
+}
+
+/// Instructions on how to parse a single JSON file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonWorkRequest<O, C> {
+    object: O,
+    checksum: C,
+    encodings: SmallVec<[Encoding; 1]>,
+}
+
+/// A batch of lines from a JSON file.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct JsonLines {
+    bytes: Vec<u8>,
+    ranges: Vec<std::ops::Range<usize>>,
+}
+
+impl JsonLines {
+    fn push(&mut self, line: &[u8]) {
+        let start = self.bytes.len();
+        self.bytes.extend_from_slice(line);
+        self.ranges.push(start..self.bytes.len());
+    }
+
+    fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+impl OneshotFormat for JsonDecoder {
+    type WorkRequest<S>
+        = JsonWorkRequest<S::Object, S::Checksum>
+    where
+        S: OneshotSource;
+    type RecordChunk = JsonLines;
+
+    async fn split_work<S: OneshotSource + Send>(
+        &self,
+        _source: S,
+        object: S::Object,
+        checksum: S::Checksum,
+    ) -> Result<Vec<Self::WorkRequest<S>>, StorageErrorX> {
+        // A single worker reads each file, and the batches of lines it reads
+        // are distributed across all workers for decoding.
+        //
+        // TODO: Uncompressed files could be split into byte ranges, with
+        // each request decoding the lines that start within its range.
+        let encodings = encodings_from_name(object.name());
+
+        let request = JsonWorkRequest {
+            object,
+            checksum,
+            encodings,
+        };
+        Ok(vec![request])
+    }
+
+    fn fetch_work<'a, S: OneshotSource + Sync + 'static>(
+        &'a self,
+        source: &'a S,
+        request: Self::WorkRequest<S>,
+    ) -> BoxStream<'a, Result<Self::RecordChunk, StorageErrorX>> {
+        let JsonWorkRequest {
+            object,
+            checksum,
+            encodings,
+        } = request;
+
+        let reader = decompressed_reader(source, object, checksum, encodings);
+        let mut reader = BufReader::new(reader);
+
+        async_stream::try_stream!({
+            let mut lines = JsonLines::default();
+            let mut line = Vec::new();
+            loop {
+                line.clear();
+                let read = reader
+                    .read_until(b'\n', &mut line)
+                    .await
+                    .map_err(|err| StorageErrorXKind::generic(err).with_context("json reading"))?;
+                if read == 0 {
+                    break;
+                }
+
+                // Skip blank lines, e.g. a trailing newline at the end of the file.
+                let trimmed = line.trim_ascii();
+                if trimmed.is_empty() {
+                    continue;
+                }
+                lines.push(trimmed);
+
+                if lines.len() >= LINES_PER_CHUNK {
+                    yield std::mem::take(&mut lines);
+                }
+            }
+            if !lines.is_empty() {
+                yield lines;
+            }
+        })
+        .boxed()
+    }
+
+    fn decode_chunk(
+        &self,
+        chunk: Self::RecordChunk,
+        rows: &mut Vec<Row>,
+    ) -> Result<usize, StorageErrorX> {
+        let JsonLines { bytes, ranges } = chunk;
+
+        let arena = RowArena::new();
+        let count = ranges.len();
+        for range in ranges {
+            let line = bytes
+                .get(range)
+                .ok_or_else(|| StorageErrorXKind::programming_error("invalid byte range"))?;
+
+            // Decode a Row from the JSON line.
+            let mut row = Row::default();
+            self.pack_line(line, &mut row.packer(), &arena)
+                .map_err(|err| err.with_context("json decoding"))?;
+            rows.push(row);
+        }
+
+        Ok(count)
+    }
+}
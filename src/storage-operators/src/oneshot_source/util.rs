@@ -9,6 +9,77 @@
 
 //! Utility functions for Oneshot sources.
 
+use std::io;
+use std::pin::Pin;
+
+use futures::TryStreamExt;
+use smallvec::{SmallVec, smallvec};
+use tokio::io::AsyncRead;
+use tokio_util::io::StreamReader;
+
+use crate::oneshot_source::{Encoding, OneshotSource};
+
+/// Returns the [`Encoding`]s of an object based on the extension of its name.
+///
+/// TODO(cf1): Check the encodings from the object to determine what
+/// decompression to apply. Also support the user manually specifying certain
+/// encodings.
+pub fn encodings_from_name(name: &str) -> SmallVec<[Encoding; 1]> {
+    if name.ends_with(".gz") {
+        smallvec![Encoding::Gzip]
+    } else if name.ends_with(".bz2") {
+        smallvec![Encoding::Bzip2]
+    } else if name.ends_with(".xz") {
+        smallvec![Encoding::Xz]
+    } else if name.ends_with(".zst") {
+        smallvec![Encoding::Zstd]
+    } else {
+        smallvec![]
+    }
+}
+
+/// Returns a reader over the entire contents of `object`, decompressed
+/// according to `encodings`.
+pub fn decompressed_reader<'a, S: OneshotSource>(
+    source: &'a S,
+    object: S::Object,
+    checksum: S::Checksum,
+    encodings: SmallVec<[Encoding; 1]>,
+) -> Pin<Box<dyn AsyncRead + Send + 'a>> {
+    // Wrap our `Stream<Bytes>` into a type that implements `tokio::io::AsyncRead`.
+    let raw_byte_stream = source
+        .get(object, checksum, None)
+        .map_err(|e| io::Error::new(io::ErrorKind::Interrupted, format!("{e:?}")));
+    let stream_reader = StreamReader::new(raw_byte_stream);
+
+    // TODO(cf3): Support multiple encodings.
+    assert!(encodings.len() <= 1, "TODO support multiple encodings");
+
+    // Decompress the byte stream, if necessary.
+    let Some(encoding) = encodings.into_iter().next() else {
+        return Box::pin(stream_reader);
+    };
+    tracing::info!(?encoding, "decompressing byte stream");
+    match encoding {
+        Encoding::Bzip2 => {
+            let decoder = async_compression::tokio::bufread::BzDecoder::new(stream_reader);
+            Box::pin(decoder)
+        }
+        Encoding::Gzip => {
+            let decoder = async_compression::tokio::bufread::GzipDecoder::new(stream_reader);
+            Box::pin(decoder)
+        }
+        Encoding::Xz => {
+            let decoder = async_compression::tokio::bufread::XzDecoder::new(stream_reader);
+            Box::pin(decoder)
+        }
+        Encoding::Zstd => {
+            let decoder = async_compression::tokio::bufread::ZstdDecoder::new(stream_reader);
+            Box::pin(decoder)
+        }
+    }
+}
+
 /// Utility trait for converting various Rust Range types into a header value.
 /// according to the MDN Web Docs.
 ///
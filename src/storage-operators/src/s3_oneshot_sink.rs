@@ -7,7 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-//! Uploads a consolidated collection to S3
+//! Uploads a consolidated collection to S3 or GCS
 
 use std::any::Any;
 use std::collections::BTreeMap;
@@ -18,15 +18,20 @@ use anyhow::anyhow;
 use aws_types::sdk_config::SdkConfig;
 use differential_dataflow::Hashable;
 use futures::StreamExt;
+use mz_aws_util::s3_uploader::{
+    CompletedUpload, S3MultiPartUploadError, S3MultiPartUploader, S3MultiPartUploaderConfig,
+};
 use mz_ore::cast::CastFrom;
 use mz_ore::error::ErrorExt;
 use mz_ore::future::InTask;
 use mz_repr::{CatalogItemId, Diff, GlobalId, Row, Timestamp};
 use mz_storage_types::connections::ConnectionContext;
-use mz_storage_types::connections::aws::AwsConnection;
+use mz_storage_types::connections::gcp::gcs::{
+    GcsClient, GcsMultiPartUploadError, GcsMultiPartUploader, GcsMultiPartUploaderConfig,
+};
 use mz_storage_types::errors::DataflowError;
 use mz_storage_types::sinks::s3_oneshot_sink::S3KeyManager;
-use mz_storage_types::sinks::{S3SinkFormat, S3UploadInfo};
+use mz_storage_types::sinks::{CopyToConnection, S3SinkFormat, S3UploadInfo};
 use mz_timely_util::builder_async::{
     Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
 };
@@ -68,7 +73,7 @@ pub fn copy_to<'scope, F>(
     up_to: Antichain<Timestamp>,
     connection_details: S3UploadInfo,
     connection_context: ConnectionContext,
-    aws_connection: AwsConnection,
+    connection: CopyToConnection,
     sink_id: GlobalId,
     connection_id: CatalogItemId,
     params: CopyToParameters,
@@ -90,7 +95,7 @@ where
         S3SinkFormat::PgCopy(_) => render_upload_operator::<pgcopy::PgCopyUploader>(
             scope.clone(),
             connection_context.clone(),
-            aws_connection.clone(),
+            connection.clone(),
             connection_id,
             connection_details,
             sink_id,
@@ -104,7 +109,7 @@ where
         S3SinkFormat::Parquet => render_upload_operator::<parquet::ParquetUploader>(
             scope.clone(),
             connection_context.clone(),
-            aws_connection.clone(),
+            connection.clone(),
             connection_id,
             connection_details,
             sink_id,
@@ -120,7 +125,7 @@ where
     let completion_token = render_completion_operator(
         scope,
         connection_context,
-        aws_connection,
+        connection,
         connection_id,
         sink_id,
         s3_key_manager,
@@ -216,7 +221,7 @@ fn render_initialization_operator<'scope>(
 fn render_completion_operator<'scope, F>(
     scope: Scope<'scope, Timestamp>,
     connection_context: ConnectionContext,
-    aws_connection: AwsConnection,
+    connection: CopyToConnection,
     connection_id: CatalogItemId,
     sink_id: GlobalId,
     s3_key_manager: S3KeyManager,
@@ -255,16 +260,14 @@ where
 
             if is_leader {
                 debug!(%sink_id, %worker_id, "s3 leader worker completion");
-                let sdk_config = aws_connection
-                    .load_sdk_config(
-                        &connection_context,
-                        connection_id,
-                        InTask::Yes,
-                        enforce_external_addresses,
-                    )
-                    .await?;
+                let client = CopyToClient::new(
+                    &connection_context,
+                    &connection,
+                    connection_id,
+                    enforce_external_addresses,
+                )
+                .await?;
 
-                let client = mz_aws_util::s3::new_client(&sdk_config);
                 let bucket = s3_key_manager.bucket.clone();
                 let incomplete_sentinel_key = s3_key_manager.incomplete_sentinel_key();
 
@@ -275,13 +278,7 @@ where
                 // TODO: Should we also write a manifest of all the files uploaded?
                 mz_ore::task::spawn(|| "copytos3:completion", async move {
                     debug!(%sink_id, %worker_id, "removing INCOMPLETE sentinel file");
-                    client
-                        .delete_object()
-                        .bucket(bucket)
-                        .key(incomplete_sentinel_key)
-                        .send()
-                        .await?;
-                    Ok::<(), anyhow::Error>(())
+                    client.delete_object(bucket, incomplete_sentinel_key).await
                 })
                 .await?;
             }
@@ -305,7 +302,7 @@ where
 fn render_upload_operator<'scope, T>(
     scope: Scope<'scope, Timestamp>,
     connection_context: ConnectionContext,
-    aws_connection: AwsConnection,
+    connection: CopyToConnection,
     connection_id: CatalogItemId,
     connection_details: S3UploadInfo,
     sink_id: GlobalId,
@@ -356,14 +353,13 @@ where
 
         // fallible async block to use the `?` operator for convenience
         let res = async move {
-            let sdk_config = aws_connection
-                .load_sdk_config(
-                    &connection_context,
-                    connection_id,
-                    InTask::Yes,
-                    enforce_external_addresses,
-                )
-                .await?;
+            let client = CopyToClient::new(
+                &connection_context,
+                &connection,
+                connection_id,
+                enforce_external_addresses,
+            )
+            .await?;
 
             // Map of an uploader per batch.
             let mut s3_uploaders: BTreeMap<u64, T> = BTreeMap::new();
@@ -374,7 +370,7 @@ where
             // if the input is empty. See database-issue#8599.
             if worker_id == 0 {
                 let mut uploader = T::new(
-                    sdk_config.clone(),
+                    client.clone(),
                     connection_details.clone(),
                     &sink_id,
                     0,
@@ -413,7 +409,7 @@ where
                                     Entry::Vacant(entry) => {
                                         debug!(%sink_id, %worker_id, "handling batch: {}", batch);
                                         entry.insert(T::new(
-                                            sdk_config.clone(),
+                                            client.clone(),
                                             connection_details.clone(),
                                             &sink_id,
                                             batch,
@@ -469,7 +465,7 @@ pub struct CopyToParameters {
 /// format that requires buffering a batch of rows before writing to S3.
 trait CopyToS3Uploader: Sized {
     fn new(
-        sdk_config: SdkConfig,
+        client: CopyToClient,
         connection_details: S3UploadInfo,
         sink_id: &GlobalId,
         batch: u64,
@@ -484,3 +480,138 @@ trait CopyToS3Uploader: Sized {
     /// This will be called when the input stream is finished.
     async fn finish(&mut self) -> Result<(), anyhow::Error>;
 }
+
+/// Client for the object store that a COPY TO uploads to.
+#[derive(Clone, Debug)]
+enum CopyToClient {
+    S3(SdkConfig),
+    Gcs(GcsClient),
+}
+
+impl CopyToClient {
+    async fn new(
+        connection_context: &ConnectionContext,
+        connection: &CopyToConnection,
+        connection_id: CatalogItemId,
+        enforce_external_addresses: bool,
+    ) -> Result<Self, anyhow::Error> {
+        match connection {
+            CopyToConnection::Aws(aws_connection) => {
+                let sdk_config = aws_connection
+                    .load_sdk_config(
+                        connection_context,
+                        connection_id,
+                        InTask::Yes,
+                        enforce_external_addresses,
+                    )
+                    .await?;
+                Ok(CopyToClient::S3(sdk_config))
+            }
+            CopyToConnection::Gcp(gcp_connection) => {
+                let client = gcp_connection
+                    .gcs_client(connection_context, enforce_external_addresses)
+                    .await?;
+                Ok(CopyToClient::Gcs(client))
+            }
+        }
+    }
+
+    /// Starts a multi-part upload of the object `key` in `bucket`.
+    async fn start_upload(
+        &self,
+        bucket: String,
+        key: String,
+        part_size_limit: u64,
+        file_size_limit: u64,
+    ) -> Result<MultiPartUploader, anyhow::Error> {
+        let uploader = match self {
+            CopyToClient::S3(sdk_config) => {
+                let config = S3MultiPartUploaderConfig {
+                    part_size_limit,
+                    file_size_limit,
+                };
+                let uploader =
+                    S3MultiPartUploader::try_new(sdk_config, bucket, key, config).await?;
+                MultiPartUploader::S3(uploader)
+            }
+            CopyToClient::Gcs(client) => {
+                let config = GcsMultiPartUploaderConfig {
+                    part_size_limit,
+                    file_size_limit,
+                };
+                let uploader = GcsMultiPartUploader::try_new(client, bucket, key, config).await?;
+                MultiPartUploader::Gcs(uploader)
+            }
+        };
+        Ok(uploader)
+    }
+
+    /// Deletes the object `key` in `bucket`, if it exists.
+    async fn delete_object(&self, bucket: String, key: String) -> Result<(), anyhow::Error> {
+        match self {
+            CopyToClient::S3(sdk_config) => {
+                let client = mz_aws_util::s3::new_client(sdk_config);
+                client
+                    .delete_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .send()
+                    .await?;
+            }
+            CopyToClient::Gcs(client) => {
+                client.delete_object(&bucket, &key).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Uploads a single file across multiple parts to either object store.
+enum MultiPartUploader {
+    S3(S3MultiPartUploader),
+    Gcs(GcsMultiPartUploader),
+}
+
+impl MultiPartUploader {
+    /// See [`S3MultiPartUploader::buffer_chunk`].
+    fn buffer_chunk(&mut self, data: &[u8]) -> Result<(), S3MultiPartUploadError> {
+        match self {
+            MultiPartUploader::S3(uploader) => uploader.buffer_chunk(data),
+            MultiPartUploader::Gcs(uploader) => uploader.buffer_chunk(data).map_err(into_s3_error),
+        }
+    }
+
+    /// See [`S3MultiPartUploader::finish`].
+    async fn finish(self) -> Result<CompletedUpload, S3MultiPartUploadError> {
+        match self {
+            MultiPartUploader::S3(uploader) => uploader.finish().await,
+            MultiPartUploader::Gcs(uploader) => {
+                let completed = uploader.finish().await.map_err(into_s3_error)?;
+                Ok(CompletedUpload {
+                    part_count: completed.part_count,
+                    total_bytes_uploaded: completed.total_bytes_uploaded,
+                    bucket: completed.bucket,
+                    key: completed.key,
+                })
+            }
+        }
+    }
+
+    /// See [`S3MultiPartUploader::added_bytes`].
+    fn added_bytes(&self) -> u64 {
+        match self {
+            MultiPartUploader::S3(uploader) => uploader.added_bytes(),
+            MultiPartUploader::Gcs(uploader) => uploader.added_bytes(),
+        }
+    }
+}
+
+/// Maps a GCS upload error to its S3 equivalent, which the uploaders handle.
+fn into_s3_error(err: GcsMultiPartUploadError) -> S3MultiPartUploadError {
+    match err {
+        GcsMultiPartUploadError::UploadExceedsMaxFileLimit(limit) => {
+            S3MultiPartUploadError::UploadExceedsMaxFileLimit(limit)
+        }
+        GcsMultiPartUploadError::Other(err) => S3MultiPartUploadError::Other(err),
+    }
+}
@@ -8,10 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use anyhow::anyhow;
-use aws_types::sdk_config::SdkConfig;
-use mz_aws_util::s3_uploader::{
-    CompletedUpload, S3MultiPartUploadError, S3MultiPartUploader, S3MultiPartUploaderConfig,
-};
+use mz_aws_util::s3_uploader::{CompletedUpload, S3MultiPartUploadError};
 use mz_ore::assert_none;
 use mz_ore::cast::CastFrom;
 use mz_pgcopy::{CopyFormatParams, encode_copy_format, encode_copy_format_header};
@@ -21,7 +18,7 @@ use mz_storage_types::sinks::s3_oneshot_sink::S3KeyManager;
 use mz_storage_types::sinks::{S3SinkFormat, S3UploadInfo};
 use tracing::info;
 
-use super::{CopyToParameters, CopyToS3Uploader};
+use super::{CopyToClient, CopyToParameters, CopyToS3Uploader, MultiPartUploader};
 
 /// Required state to upload batches to S3
 pub(super) struct PgCopyUploader {
@@ -38,20 +35,20 @@ pub(super) struct PgCopyUploader {
     /// The desired file size. A new file upload will be started
     /// when the size exceeds this amount.
     max_file_size: u64,
-    /// The aws sdk config.
+    /// The client for the object store.
     /// This is an option so that we can get an owned value later to move to a
     /// spawned tokio task.
-    sdk_config: Option<SdkConfig>,
+    client: Option<CopyToClient>,
     /// Multi-part uploader for the current file.
     /// Keeping the uploader in an `Option` to later take owned value.
-    current_file_uploader: Option<S3MultiPartUploader>,
+    current_file_uploader: Option<MultiPartUploader>,
     /// Upload parameters.
     params: CopyToParameters,
 }
 
 impl CopyToS3Uploader for PgCopyUploader {
     fn new(
-        sdk_config: SdkConfig,
+        client: CopyToClient,
         connection_details: S3UploadInfo,
         sink_id: &GlobalId,
         batch: u64,
@@ -60,7 +57,7 @@ impl CopyToS3Uploader for PgCopyUploader {
         match connection_details.format {
             S3SinkFormat::PgCopy(format_params) => Ok(PgCopyUploader {
                 desc: connection_details.desc,
-                sdk_config: Some(sdk_config),
+                client: Some(client),
                 format: format_params,
                 key_manager: S3KeyManager::new(sink_id, &connection_details.uri),
                 batch,
@@ -94,8 +91,8 @@ impl CopyToS3Uploader for PgCopyUploader {
     }
 
     /// Appends the row to the in-progress upload where it is buffered till it reaches the configured
-    /// `part_size_limit` after which the `S3MultiPartUploader` will upload that part. In case it will
-    /// exceed the max file size of the ongoing upload, then a new `S3MultiPartUploader` for a new file will
+    /// `part_size_limit` after which the `MultiPartUploader` will upload that part. In case it will
+    /// exceed the max file size of the ongoing upload, then a new `MultiPartUploader` for a new file will
     /// be created and the row data will be appended there.
     async fn append_row(&mut self, row: &Row) -> Result<(), anyhow::Error> {
         let mut buf: Vec<u8> = vec![];
@@ -144,28 +141,18 @@ impl PgCopyUploader {
                 .data_key(self.batch, self.file_index, self.format.file_extension());
         let bucket = self.key_manager.bucket.clone();
         info!("starting upload: bucket {}, key {}", &bucket, &object_key);
-        let sdk_config = self
-            .sdk_config
-            .take()
-            .expect("sdk_config should always be present");
+        let client = self.client.take().expect("client should always be present");
         let max_file_size = self.max_file_size;
         // Moving the aws s3 calls onto tokio tasks instead of using timely runtime.
         let part_size_limit = u64::cast_from(self.params.s3_multipart_part_size_bytes);
         let handle = mz_ore::task::spawn(|| "s3_uploader::try_new", async move {
-            let uploader = S3MultiPartUploader::try_new(
-                &sdk_config,
-                bucket,
-                object_key,
-                S3MultiPartUploaderConfig {
-                    part_size_limit,
-                    file_size_limit: max_file_size,
-                },
-            )
-            .await;
-            (uploader, sdk_config)
+            let uploader = client
+                .start_upload(bucket, object_key, part_size_limit, max_file_size)
+                .await;
+            (uploader, client)
         });
-        let (uploader, sdk_config) = handle.await;
-        self.sdk_config = Some(sdk_config);
+        let (uploader, client) = handle.await;
+        self.client = Some(client);
         let mut uploader = uploader?;
         if self.format.requires_header() {
             let mut buf: Vec<u8> = vec![];
@@ -236,7 +223,7 @@ mod tests {
         let column_names = vec![ColumnName::from("col1")];
         let desc = RelationDesc::new(typ, column_names.into_iter());
         let mut uploader = PgCopyUploader::new(
-            sdk_config.clone(),
+            CopyToClient::S3(sdk_config.clone()),
             S3UploadInfo {
                 uri: format!("s3://{}/{}", bucket, path),
                 // this is only for testing, users will not be able to set value smaller than 16MB.
@@ -86,6 +86,7 @@ use crate::errors::{ContextCreationError, CsrConnectError};
 
 pub mod aws;
 pub mod gcp;
+pub mod http;
 pub mod inline;
 pub mod string_or_secret;
 
@@ -307,6 +308,9 @@ pub struct ConnectionContext {
     /// The ARN for a Materialize-controlled role to assume before assuming
     /// a customer's requested role for an AWS connection.
     pub aws_connection_role_arn: Option<String>,
+    /// The URI at which GCP service accounts mint access tokens instead of the
+    /// one in their key, e.g. to test against a GCS emulator.
+    pub gcp_oauth_token_uri: Option<String>,
     /// A secrets reader.
     pub secrets_reader: Arc<dyn SecretsReader>,
     /// A cloud resource reader, if supported in this configuration.
@@ -328,6 +332,7 @@ impl ConnectionContext {
         startup_log_level: &CloneableEnvFilter,
         aws_external_id_prefix: Option<AwsExternalIdPrefix>,
         aws_connection_role_arn: Option<String>,
+        gcp_oauth_token_uri: Option<String>,
         secrets_reader: Arc<dyn SecretsReader>,
         cloud_resource_reader: Option<Arc<dyn CloudResourceReader>>,
     ) -> ConnectionContext {
@@ -339,6 +344,7 @@ impl ConnectionContext {
             ),
             aws_external_id_prefix,
             aws_connection_role_arn,
+            gcp_oauth_token_uri,
             secrets_reader,
             cloud_resource_reader,
             ssh_tunnel_manager: SshTunnelManager::default(),
@@ -359,6 +365,7 @@ impl ConnectionContext {
             aws_connection_role_arn: Some(
                 "arn:aws:iam::123456789000:role/MaterializeConnection".into(),
             ),
+            gcp_oauth_token_uri: None,
             secrets_reader,
             cloud_resource_reader: None,
             ssh_tunnel_manager: SshTunnelManager::default(),
@@ -940,7 +947,7 @@ impl IcebergCatalogConnection<InlinedConnection> {
             IcebergCatalogAuth::Gcp(gcp_connection_reference) => {
                 let (creds_json, service_account) = gcp_connection_reference
                     .connection
-                    .read_credentials(storage_configuration)
+                    .read_credentials(&storage_configuration.connection_context)
                     .await
                     .map_err(|e| anyhow!("failed to parse GCP service account JSON: {e}"))?;
 
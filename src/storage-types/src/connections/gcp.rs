@@ -18,15 +18,18 @@ use serde::{Deserialize, Serialize};
 
 use crate::AlterCompatible;
 use crate::configuration::StorageConfiguration;
+use crate::connections::ConnectionContext;
 use crate::connections::inline::{
     ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
     ReferencedConnection,
 };
 use crate::controller::AlterError;
 
+pub mod gcs;
+
 /// Every service-account key is allowed to mint tokens for this scope,
 /// and it's a blanket scope that covers both BigLake (Iceberg catalog) and GCS.
-const GCP_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+pub(crate) const GCP_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
 
 /// Modern GCP Service Account keys always use the same token URI.
 const OAUTH_TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
@@ -37,6 +40,13 @@ pub struct GcpConnection {
     /// Secret containing a GCP service-account key in JSON format,
     /// as produced by `gcloud iam service-accounts keys create`.
     pub credentials_json: CatalogItemId,
+    /// Endpoint to use for Google Cloud Storage instead of the default, e.g.
+    /// to connect through Private Service Connect or to a GCS emulator.
+    ///
+    /// Requests to a custom endpoint are authenticated like requests to
+    /// Google's.
+    #[serde(default)]
+    pub endpoint: Option<String>,
 }
 
 impl AlterCompatible for GcpConnection {
@@ -68,20 +78,35 @@ impl GcpServiceAccountKeyTokenUri {
     }
 }
 
+/// Returns the service-account key `json` with its token URI replaced by
+/// `token_uri`.
+fn with_token_uri(json: &str, token_uri: &str) -> Result<String, serde_json::Error> {
+    let mut key: serde_json::Map<String, serde_json::Value> = serde_json::from_str(json)?;
+    key.insert("token_uri".into(), token_uri.into());
+    serde_json::to_string(&key)
+}
+
 impl GcpConnection {
     /// Returns (credentials JSON, parsed service account) because both forms are useful.
+    ///
+    /// If the connection context overrides the OAuth token URI, both forms
+    /// mint their tokens there.
     pub(crate) async fn read_credentials(
         &self,
-        storage_configuration: &StorageConfiguration,
+        connection_context: &ConnectionContext,
     ) -> Result<(String, CustomServiceAccount), GcpConnectionValidationError> {
-        let json = storage_configuration
-            .connection_context
+        let json = connection_context
             .secrets_reader
             .read_string(self.credentials_json)
             .await
             .map_err(GcpConnectionValidationError::SecretRead)?;
         GcpServiceAccountKeyTokenUri::validate_json(&json)
             .map_err(GcpConnectionValidationError::ParseKey)?;
+        let json = match &connection_context.gcp_oauth_token_uri {
+            Some(token_uri) => with_token_uri(&json, token_uri)
+                .map_err(|e| GcpConnectionValidationError::ParseKey(anyhow::Error::from(e)))?,
+            None => json,
+        };
         let account = CustomServiceAccount::from_json(&json)
             .map_err(|e| GcpConnectionValidationError::ParseKey(anyhow::Error::from(e)))?;
         Ok((json, account))
@@ -93,7 +118,9 @@ impl GcpConnection {
         _id: CatalogItemId,
         storage_configuration: &StorageConfiguration,
     ) -> Result<(), GcpConnectionValidationError> {
-        let (_, service_account) = self.read_credentials(storage_configuration).await?;
+        let (_, service_account) = self
+            .read_credentials(&storage_configuration.connection_context)
+            .await?;
         service_account
             .token(&[GCP_SCOPE])
             .await
@@ -240,6 +240,16 @@ pub const PG_SCHEMA_VALIDATION_INTERVAL: Config<Duration> = Config::new(
     "Interval to re-validate the schemas of ingested tables.",
 );
 
+/// The maximum number of bytes of a single transaction that a PG source buffers per table with
+/// `TRUNCATE BEHAVIOR = RETRACT`. The rows a transaction writes before a `TRUNCATE` of the same
+/// table have to be retracted along with it, so they are kept in memory until the transaction
+/// ends. A `TRUNCATE` after more rows than this is treated as with `TRUNCATE BEHAVIOR = ERROR`.
+pub const PG_SOURCE_TRUNCATE_RETRACT_MAX_TX_BYTES: Config<usize> = Config::new(
+    "pg_source_truncate_retract_max_tx_bytes",
+    64 * 1024 * 1024,
+    "Maximum bytes of a transaction buffered per table to retract them on TRUNCATE.",
+);
+
 /// Controls behavior of PG Source when the upstream DB timeline changes. The default behavior
 /// is to emit a definite error forcing source recreation. In cases of HA, the upstream DB may
 /// provide guarantees of failover without loss of data (e.g. CloudSQL maintenance). Changing this
@@ -441,6 +451,7 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&ORE_OVERFLOWING_BEHAVIOR)
         .add(&PG_FETCH_SLOT_RESUME_LSN_INTERVAL)
         .add(&PG_SCHEMA_VALIDATION_INTERVAL)
+        .add(&PG_SOURCE_TRUNCATE_RETRACT_MAX_TX_BYTES)
         .add(&PG_SOURCE_VALIDATE_TIMELINE)
         .add(&REPLICA_METRICS_HISTORY_RETENTION_INTERVAL)
         .add(&SINK_ENSURE_TOPIC_CONFIG)
@@ -463,5 +474,6 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&crate::sources::sql_server::CDC_CLEANUP_CHANGE_TABLE_MAX_DELETES)
         .add(&crate::sources::sql_server::MAX_LSN_WAIT)
         .add(&crate::sources::sql_server::SNAPSHOT_PROGRESS_REPORT_INTERVAL)
+        .add(&crate::sources::sql_server::SCHEMA_EVOLUTION_CHECK_INTERVAL)
         .add(&STATISTICS_RETENTION_DURATION)
 }
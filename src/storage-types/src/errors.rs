@@ -818,6 +818,9 @@ mod columnation {
                         EvalError::TypeFromOid(x) => {
                             EvalError::TypeFromOid(self.string_region.copy(x))
                         }
+                        EvalError::InvalidEnumLabel(x) => {
+                            EvalError::InvalidEnumLabel(self.string_region.copy(x))
+                        }
                         EvalError::InvalidRange(x) => {
                             let err = match x {
                                 e @ InvalidRangeError::MisorderedRangeBounds
@@ -825,7 +828,8 @@ mod columnation {
                                 | e @ InvalidRangeError::DiscontiguousUnion
                                 | e @ InvalidRangeError::DiscontiguousDifference
                                 | e @ InvalidRangeError::NullRangeBoundFlags
-                                | e @ InvalidRangeError::InvalidRangeData => e.clone(),
+                                | e @ InvalidRangeError::InvalidRangeData
+                                | e @ InvalidRangeError::NullMultiRangeMember => e.clone(),
                                 InvalidRangeError::CanonicalizationOverflow(string) => {
                                     InvalidRangeError::CanonicalizationOverflow(
                                         self.string_region.copy(string),
@@ -12,7 +12,7 @@
 use derivative::Derivative;
 use mz_expr::SafeMfpPlan;
 use mz_ore::url::SensitiveUrl;
-use mz_pgcopy::CopyCsvFormatParams;
+use mz_pgcopy::{CopyCsvFormatParams, CopyJsonFormatParams};
 use mz_repr::{CatalogItemId, RelationDesc};
 use mz_timely_util::builder_async::PressOnDropButton;
 
@@ -20,6 +20,7 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc::UnboundedReceiver;
 
 use crate::connections::aws::AwsConnection;
+use crate::connections::gcp::GcpConnection;
 
 /// Callback type used to send the result of a oneshot source.
 pub type OneshotResultCallback<Batch> =
@@ -52,12 +53,22 @@ pub enum ContentSource {
         connection_id: CatalogItemId,
         uri: String,
     },
+    Gcs {
+        #[derivative(Debug = "ignore")]
+        connection: GcpConnection,
+        connection_id: CatalogItemId,
+        uri: String,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub enum ContentFormat {
     Csv(CopyCsvFormatParams<'static>),
     Parquet,
+    /// Newline-delimited JSON.
+    Json(CopyJsonFormatParams),
+    /// Avro Object Container Files.
+    Avro,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
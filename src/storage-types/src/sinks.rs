@@ -25,6 +25,8 @@ use timely::PartialOrder;
 use timely::progress::frontier::Antichain;
 
 use crate::AlterCompatible;
+use crate::connections::aws::AwsConnection;
+use crate::connections::gcp::GcpConnection;
 use crate::connections::inline::{
     ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
     ReferencedConnection,
@@ -650,6 +652,15 @@ pub struct S3UploadInfo {
     pub format: S3SinkFormat,
 }
 
+/// Connection to the object store that a oneshot COPY TO uploads to.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum CopyToConnection {
+    /// Amazon S3, or an S3-compatible object store.
+    Aws(AwsConnection),
+    /// Google Cloud Storage.
+    Gcp(GcpConnection),
+}
+
 pub const MIN_S3_SINK_FILE_SIZE: ByteSize = ByteSize::mb(16);
 pub const MAX_S3_SINK_FILE_SIZE: ByteSize = ByteSize::gb(4);
 
@@ -9,7 +9,8 @@
 
 use std::str::FromStr;
 
-use anyhow::anyhow;
+use anyhow::{Context, anyhow};
+use bytes::Bytes;
 use http::Uri;
 use mz_ore::future::InTask;
 use mz_repr::{CatalogItemId, GlobalId};
@@ -17,7 +18,8 @@ use tracing::{debug, info};
 
 use crate::connections::ConnectionContext;
 use crate::connections::aws::AwsConnection;
-use crate::sinks::S3UploadInfo;
+use crate::connections::gcp::GcpConnection;
+use crate::sinks::{CopyToConnection, S3UploadInfo};
 
 /// Performs preflight checks for a copy to operation.
 ///
@@ -35,7 +37,7 @@ use crate::sinks::S3UploadInfo;
 /// for each replica).
 pub async fn preflight(
     connection_context: ConnectionContext,
-    aws_connection: &AwsConnection,
+    connection: &CopyToConnection,
     connection_details: &S3UploadInfo,
     connection_id: CatalogItemId,
     sink_id: GlobalId,
@@ -45,6 +47,39 @@ pub async fn preflight(
 
     let s3_key_manager = S3KeyManager::new(&sink_id, &connection_details.uri);
 
+    match connection {
+        CopyToConnection::Aws(aws_connection) => {
+            preflight_s3(
+                connection_context,
+                aws_connection,
+                s3_key_manager,
+                connection_id,
+                sink_id,
+                enforce_external_addresses,
+            )
+            .await
+        }
+        CopyToConnection::Gcp(gcp_connection) => {
+            preflight_gcs(
+                connection_context,
+                gcp_connection,
+                s3_key_manager,
+                sink_id,
+                enforce_external_addresses,
+            )
+            .await
+        }
+    }
+}
+
+async fn preflight_s3(
+    connection_context: ConnectionContext,
+    aws_connection: &AwsConnection,
+    s3_key_manager: S3KeyManager,
+    connection_id: CatalogItemId,
+    sink_id: GlobalId,
+    enforce_external_addresses: bool,
+) -> Result<(), anyhow::Error> {
     let sdk_config = aws_connection
         .load_sdk_config(
             &connection_context,
@@ -109,6 +144,47 @@ pub async fn preflight(
     Ok::<(), anyhow::Error>(())
 }
 
+async fn preflight_gcs(
+    connection_context: ConnectionContext,
+    gcp_connection: &GcpConnection,
+    s3_key_manager: S3KeyManager,
+    sink_id: GlobalId,
+    enforce_external_addresses: bool,
+) -> Result<(), anyhow::Error> {
+    let client = gcp_connection
+        .gcs_client(&connection_context, enforce_external_addresses)
+        .await?;
+    let bucket = &s3_key_manager.bucket;
+
+    let files = client
+        .list_objects(bucket, Some(s3_key_manager.path_prefix()))
+        .await?;
+    if !files.is_empty() {
+        Err(anyhow!(
+            "GCS bucket path is not empty, contains {} objects",
+            files.len()
+        ))?;
+    }
+
+    // Confirm we have permission to delete objects before proceeding by trying
+    // to delete a known non-existent file, which GCS answers with a 404 if we do.
+    client
+        .delete_object(bucket, &s3_key_manager.data_key(0, 0, "delete_object_test"))
+        .await
+        .context("error when deleting objects")?;
+
+    debug!(%sink_id, "uploading INCOMPLETE sentinel file");
+    client
+        .insert_object(
+            bucket,
+            &s3_key_manager.incomplete_sentinel_key(),
+            Bytes::new(),
+        )
+        .await?;
+
+    Ok(())
+}
+
 /// Helper to manage object keys created by this sink based on the S3 URI provided
 /// by the user and the GlobalId that identifies this copy-to-s3 sink.
 /// Since there may be multiple compute replicas running their own copy of this sink
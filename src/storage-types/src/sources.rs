@@ -903,6 +903,18 @@ impl crate::AlterCompatible for SourceExportDetails {
     }
 }
 
+/// How a source export reacts to additive changes to the schema of its
+/// upstream table, e.g. added columns.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SchemaChangeBehavior {
+    /// Ignore the new columns.
+    #[default]
+    Ignore,
+    /// Wait for the new columns to be added to the export before ingesting
+    /// further changes.
+    Evolve,
+}
+
 /// Details necessary to store in the `Details` option of a source export
 /// statement (`CREATE SUBSOURCE` and `CREATE TABLE .. FROM SOURCE` statements),
 /// to generate the appropriate `SourceExportDetails` struct during planning.
@@ -88,6 +88,9 @@ pub enum CastFunc {
     CastStringToTime,
     CastStringToInterval,
     CastStringToUuid,
+    CastStringToInet,
+    CastStringToCidr,
+    CastStringToMacAddr,
     CastStringToJsonb,
     CastStringToMzTimestamp,
     CastStringToInt2Vector,
@@ -118,6 +121,15 @@ pub enum CastFunc {
         return_ty: SqlScalarType,
         cast_expr: Box<StorageScalarExpr>,
     },
+    CastStringToMultiRange {
+        return_ty: SqlScalarType,
+        cast_expr: Box<StorageScalarExpr>,
+    },
+    /// The text-to-enum cast, used for upstream enum columns named in
+    /// `ENUM COLUMNS`. Produces the ordinal of the label in `return_ty`.
+    CastStringToEnum {
+        return_ty: SqlScalarType,
+    },
 }
 
 impl StorageScalarExpr {
@@ -232,6 +244,15 @@ impl CastFunc {
             CastFunc::CastStringToUuid => {
                 Ok(Datum::Uuid(strconv::parse_uuid(a).map_err(parse_err)?))
             }
+            CastFunc::CastStringToInet => {
+                Ok(Datum::Inet(strconv::parse_inet(a).map_err(parse_err)?))
+            }
+            CastFunc::CastStringToCidr => {
+                Ok(Datum::Inet(strconv::parse_cidr(a).map_err(parse_err)?))
+            }
+            CastFunc::CastStringToMacAddr => Ok(Datum::MacAddr(
+                strconv::parse_mac_addr(a).map_err(parse_err)?,
+            )),
             // TODO(jamii): it would be much more efficient to skip the
             // intermediate repr::jsonb::Jsonb.
             CastFunc::CastStringToJsonb => {
@@ -376,6 +397,31 @@ impl CastFunc {
                         .expect("must have already handled errors")
                 }))
             }
+            CastFunc::CastStringToMultiRange {
+                return_ty: _,
+                cast_expr,
+            } => {
+                let ranges = strconv::parse_multirange(a, |elem_text| {
+                    let elem_text = match elem_text {
+                        Cow::Owned(s) => arena.push_string(s),
+                        Cow::Borrowed(s) => s,
+                    };
+                    cast_expr.eval(&[Datum::String(elem_text)], arena)
+                })?;
+                Ok(arena.try_make_datum(|packer| packer.push_multirange(ranges))?)
+            }
+            CastFunc::CastStringToEnum { return_ty } => {
+                let labels = match return_ty {
+                    SqlScalarType::Enum { labels, .. } => labels,
+                    ty => unreachable!("CastStringToEnum called with non-enum type {ty:?}"),
+                };
+                match labels.iter().position(|l| l == a) {
+                    Some(ordinal) => Ok(Datum::UInt32(
+                        u32::try_from(ordinal).expect("enum types have fewer than 2^32 labels"),
+                    )),
+                    None => Err(EvalError::InvalidEnumLabel(a.into())),
+                }
+            }
             CastFunc::CastStringToInt2Vector => {
                 let datums =
                     strconv::parse_legacy_vector(a, |elem_text| -> Result<Datum, EvalError> {
@@ -1003,6 +1049,19 @@ mod tests {
             );
         }
 
+        #[mz_ore::test]
+        fn error_enum() {
+            let return_ty = SqlScalarType::Enum {
+                custom_id: mz_repr::CatalogItemId::User(1),
+                custom_oid: 20_000,
+                labels: vec!["sad".to_string(), "ok".to_string()].into(),
+            };
+            assert_eq!(
+                eval_cast_err(CastFunc::CastStringToEnum { return_ty }, "happy"),
+                EvalError::InvalidEnumLabel("happy".into()),
+            );
+        }
+
         #[mz_ore::test]
         fn error_if_null() {
             let arena = RowArena::new();
@@ -1180,6 +1239,29 @@ mod tests {
             );
         }
 
+        #[mz_ore::test]
+        fn parity_inet() {
+            use mz_expr::func::{CastStringToCidr, CastStringToInet, CastStringToMacAddr};
+            assert_parity(
+                "Inet",
+                CastFunc::CastStringToInet,
+                UnaryFunc::CastStringToInet(CastStringToInet),
+                &["192.168.1.5/24", "::1", "10.0.0.0/33", "bad", ""],
+            );
+            assert_parity(
+                "Cidr",
+                CastFunc::CastStringToCidr,
+                UnaryFunc::CastStringToCidr(CastStringToCidr),
+                &["192.168.1.0/24", "192.168.1.5/24", "bad", ""],
+            );
+            assert_parity(
+                "MacAddr",
+                CastFunc::CastStringToMacAddr,
+                UnaryFunc::CastStringToMacAddr(CastStringToMacAddr),
+                &["08:00:2b:01:02:03", "08-00-2b-01-02-03", "bad", ""],
+            );
+        }
+
         #[mz_ore::test]
         #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `decContextDefault` on OS `linux`
         fn parity_jsonb() {
@@ -1315,6 +1397,24 @@ mod tests {
             );
         }
 
+        #[mz_ore::test]
+        fn parity_enum() {
+            use mz_expr::func::CastStringToEnum;
+            let return_ty = SqlScalarType::Enum {
+                custom_id: mz_repr::CatalogItemId::User(1),
+                custom_oid: 20_000,
+                labels: vec!["sad".to_string(), "ok".to_string(), "happy".to_string()].into(),
+            };
+            assert_parity(
+                "Enum",
+                CastFunc::CastStringToEnum {
+                    return_ty: return_ty.clone(),
+                },
+                UnaryFunc::CastStringToEnum(CastStringToEnum { return_ty }),
+                &["sad", "happy", "Happy", ""],
+            );
+        }
+
         #[mz_ore::test]
         fn parity_mz_timestamp() {
             use mz_expr::func::CastStringToMzTimestamp;
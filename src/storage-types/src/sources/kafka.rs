@@ -10,6 +10,7 @@
 //! Types related kafka sources
 
 use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
 use std::fmt;
 use std::sync::LazyLock;
 use std::time::Duration;
@@ -23,6 +24,7 @@ use mz_repr::adt::numeric::Numeric;
 use mz_repr::{CatalogItemId, Datum, GlobalId, RelationDesc, Row, SqlColumnType, SqlScalarType};
 use mz_timely_util::order::{Extrema, Partitioned};
 use rdkafka::admin::AdminClient;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use timely::progress::Antichain;
 
@@ -39,16 +41,153 @@ include!(concat!(
     "/mz_storage_types.sources.kafka.rs"
 ));
 
-/// A "moment in time" perceivable in Kafka––for each partition, the greatest
+/// A "moment in time" perceivable in Kafka––for each topic partition, the greatest
 /// visible offset.
-pub type KafkaTimestamp = Partitioned<RangeBound<i32>, MzOffset>;
+pub type KafkaTimestamp = Partitioned<RangeBound<PartitionKey>, MzOffset>;
+
+/// Identifies a topic partition in a [`KafkaTimestamp`].
+///
+/// The upper 32 bits hold the id of the topic (see [`KafkaTopics::topic_id`]) and the lower 32
+/// bits hold the Kafka partition id. Keys are never negative.
+pub type PartitionKey = i64;
+
+/// Splits a [`PartitionKey`] into its topic id and Kafka partition id.
+pub fn split_partition_key(key: PartitionKey) -> (u32, i32) {
+    let topic_id = u32::try_from(key >> 32).expect("partition keys are non-negative");
+    let pid = i32::try_from(key & 0xffff_ffff).expect("partition ids are non-negative");
+    (topic_id, pid)
+}
+
+/// The topics a Kafka source reads from.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum KafkaTopics {
+    /// A single topic, as specified by `TOPIC`.
+    Topic(String),
+    /// A fixed set of topics, as specified by `TOPICS`.
+    List(Vec<String>),
+    /// All topics whose names match a regular expression, as specified by `TOPIC PATTERN`. Topics
+    /// created after the source are picked up on the next metadata refresh.
+    Pattern(String),
+}
+
+impl KafkaTopics {
+    /// Returns the id that `topic` is known by in the [`PartitionKey`]s of this source.
+    ///
+    /// Single-topic sources predate multi-topic sources and always use id `0`, which keeps their
+    /// partition keys equal to the bare partition ids already recorded in their remap shards.
+    /// Otherwise the id is a stable hash of the topic name.
+    pub fn topic_id(&self, topic: &str) -> u32 {
+        match self {
+            KafkaTopics::Topic(_) => 0,
+            KafkaTopics::List(_) | KafkaTopics::Pattern(_) => {
+                // Clear the top bit so that partition keys stay non-negative.
+                let hash = seahash::hash(topic.as_bytes()) & 0x7fff_ffff;
+                u32::try_from(hash).expect("masked to 31 bits")
+            }
+        }
+    }
+
+    /// Returns the [`PartitionKey`] of partition `pid` of `topic`.
+    pub fn partition_key(&self, topic: &str, pid: i32) -> PartitionKey {
+        assert!(pid >= 0, "invalid partition id {pid}");
+        (i64::from(self.topic_id(topic)) << 32) | i64::from(pid)
+    }
+
+    /// Returns the first pair of distinct topics in `topics` that share a topic id, if any.
+    ///
+    /// Such topics cannot be told apart in the source's timestamps, so a source must never read
+    /// from both.
+    pub fn find_topic_id_collision<'a>(
+        &self,
+        topics: impl IntoIterator<Item = &'a str>,
+    ) -> Option<(&'a str, &'a str)> {
+        let mut ids = BTreeMap::new();
+        for topic in topics {
+            match ids.entry(self.topic_id(topic)) {
+                Entry::Vacant(entry) => {
+                    entry.insert(topic);
+                }
+                Entry::Occupied(entry) if *entry.get() != topic => {
+                    return Some((*entry.get(), topic));
+                }
+                Entry::Occupied(_) => {}
+            }
+        }
+        None
+    }
+}
+
+/// Compiles a `TOPIC PATTERN`. The pattern must match the entire topic name.
+pub fn compile_topic_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    Regex::new(&format!("^(?:{pattern})$"))
+}
+
+impl fmt::Display for KafkaTopics {
+    /// Displays the topics the way they appear in `mz_kafka_sources` and in the external
+    /// reference of the source: the topic name for single-topic sources, the comma-separated
+    /// topic names for `TOPICS`, and the regular expression for `TOPIC PATTERN`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KafkaTopics::Topic(topic) => f.write_str(topic),
+            KafkaTopics::List(topics) => f.write_str(&topics.join(",")),
+            KafkaTopics::Pattern(pattern) => f.write_str(pattern),
+        }
+    }
+}
+
+/// Returns the upstream frontier of a Kafka source given the upper offset of every known
+/// partition key.
+///
+/// Each key is present as a singleton at its offset. The keys before, between, and after them are
+/// covered by ranges at offset zero, so that partitions and topics discovered later start out
+/// beyond the frontier.
+pub fn partitions_frontier(
+    offsets: &BTreeMap<PartitionKey, MzOffset>,
+) -> Antichain<KafkaTimestamp> {
+    let mut frontier = Antichain::new();
+    for (lower, upper) in unknown_partition_ranges(offsets.keys().copied()) {
+        frontier.insert(Partitioned::new_range(lower, upper, MzOffset::from(0)));
+    }
+    for (key, offset) in offsets {
+        frontier.insert(Partitioned::new_singleton(RangeBound::exact(*key), *offset));
+    }
+    frontier
+}
+
+/// Returns the ranges of partition keys that lie before, between, and after the given keys, which
+/// must be sorted and distinct.
+///
+/// Ranges that contain no valid partition key are omitted, except for the last range, which is
+/// always present. For keys `0..n` this yields a single range `(n-1, +inf)`, which matches the
+/// frontiers recorded by single-topic sources.
+pub fn unknown_partition_ranges(
+    keys: impl IntoIterator<Item = PartitionKey>,
+) -> Vec<(RangeBound<PartitionKey>, RangeBound<PartitionKey>)> {
+    let mut ranges = vec![];
+    let mut prev: Option<PartitionKey> = None;
+    for key in keys {
+        match prev {
+            None if key > 0 => ranges.push((RangeBound::NegInfinity, RangeBound::before(key))),
+            Some(prev) if key - prev > 1 => {
+                ranges.push((RangeBound::after(prev), RangeBound::before(key)))
+            }
+            _ => {}
+        }
+        prev = Some(key);
+    }
+    let lower = prev
+        .map(RangeBound::after)
+        .unwrap_or(RangeBound::NegInfinity);
+    ranges.push((lower, RangeBound::PosInfinity));
+    ranges
+}
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct KafkaSourceConnection<C: ConnectionAccess = InlinedConnection> {
     pub connection: C::Kafka,
     pub connection_id: CatalogItemId,
-    pub topic: String,
-    // Map from partition -> starting offset
+    pub topics: KafkaTopics,
+    // Map from partition -> starting offset. Only supported for `KafkaTopics::Topic`.
     pub start_offsets: BTreeMap<i32, i64>,
     pub group_id_prefix: Option<String>,
     // The metadata_columns for the primary source export from this kafka source
@@ -65,7 +204,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSourceConnection, R>
         let KafkaSourceConnection {
             connection,
             connection_id,
-            topic,
+            topics,
             start_offsets,
             group_id_prefix,
             metadata_columns,
@@ -74,7 +213,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSourceConnection, R>
         KafkaSourceConnection {
             connection: r.resolve_connection(connection).unwrap_kafka(),
             connection_id,
-            topic,
+            topics,
             start_offsets,
             group_id_prefix,
             metadata_columns,
@@ -151,37 +290,47 @@ impl KafkaSourceConnection {
 
         mz_ore::task::spawn_blocking(|| "kafka_fetch_write_frontier_fetch_metadata", {
             move || {
-                let meta = client
-                    .inner()
-                    .fetch_metadata(Some(&self.topic), metadata_timeout)?;
-
-                let pids = meta
-                    .topics()
-                    .into_element()
-                    .partitions()
-                    .iter()
-                    .map(|p| p.id());
-
-                let mut current_upper = Antichain::new();
-                let mut max_pid = 0;
-                for pid in pids {
-                    let (_, high) =
-                        client
-                            .inner()
-                            .fetch_watermarks(&self.topic, pid, metadata_timeout)?;
-                    max_pid = std::cmp::max(pid, max_pid);
-                    current_upper.insert(Partitioned::new_singleton(
-                        RangeBound::Elem(pid, BoundKind::At),
-                        MzOffset::from(u64::try_from(high).unwrap()),
-                    ));
+                let topics = match &self.topics {
+                    KafkaTopics::Topic(topic) => vec![topic.clone()],
+                    KafkaTopics::List(topics) => topics.clone(),
+                    KafkaTopics::Pattern(pattern) => {
+                        let pattern = compile_topic_pattern(pattern)?;
+                        let meta = client.inner().fetch_metadata(None, metadata_timeout)?;
+                        meta.topics()
+                            .iter()
+                            .map(|t| t.name())
+                            .filter(|name| pattern.is_match(name))
+                            .map(String::from)
+                            .collect()
+                    }
+                };
+
+                let mut offsets = BTreeMap::new();
+                for topic in topics {
+                    let meta = client
+                        .inner()
+                        .fetch_metadata(Some(&topic), metadata_timeout)?;
+
+                    let pids = meta
+                        .topics()
+                        .into_element()
+                        .partitions()
+                        .iter()
+                        .map(|p| p.id());
+
+                    for pid in pids {
+                        let (_, high) =
+                            client
+                                .inner()
+                                .fetch_watermarks(&topic, pid, metadata_timeout)?;
+                        offsets.insert(
+                            self.topics.partition_key(&topic, pid),
+                            MzOffset::from(u64::try_from(high).unwrap()),
+                        );
+                    }
                 }
-                current_upper.insert(Partitioned::new_range(
-                    RangeBound::Elem(max_pid, BoundKind::After),
-                    RangeBound::PosInfinity,
-                    MzOffset::from(0),
-                ));
 
-                Ok(current_upper)
+                Ok(partitions_frontier(&offsets))
             }
         })
         .await
@@ -194,7 +343,10 @@ impl<C: ConnectionAccess> SourceConnection for KafkaSourceConnection<C> {
     }
 
     fn external_reference(&self) -> Option<&str> {
-        Some(self.topic.as_str())
+        match &self.topics {
+            KafkaTopics::Topic(topic) => Some(topic.as_str()),
+            KafkaTopics::List(_) | KafkaTopics::Pattern(_) => None,
+        }
     }
 
     fn default_key_desc(&self) -> RelationDesc {
@@ -235,7 +387,7 @@ impl<C: ConnectionAccess> crate::AlterCompatible for KafkaSourceConnection<C> {
         let KafkaSourceConnection {
             connection,
             connection_id,
-            topic,
+            topics,
             start_offsets,
             group_id_prefix,
             metadata_columns,
@@ -248,7 +400,7 @@ impl<C: ConnectionAccess> crate::AlterCompatible for KafkaSourceConnection<C> {
                 "connection",
             ),
             (connection_id == &other.connection_id, "connection_id"),
-            (topic == &other.topic, "topic"),
+            (topics == &other.topics, "topics"),
             (start_offsets == &other.start_offsets, "start_offsets"),
             (group_id_prefix == &other.group_id_prefix, "group_id_prefix"),
             (
@@ -285,6 +437,7 @@ pub fn kafka_metadata_columns_desc(
         .iter()
         .map(|(name, kind)| {
             let typ = match kind {
+                KafkaMetadataKind::Topic => SqlScalarType::String.nullable(false),
                 KafkaMetadataKind::Partition => SqlScalarType::Int32.nullable(false),
                 KafkaMetadataKind::Offset => SqlScalarType::UInt64.nullable(false),
                 KafkaMetadataKind::Timestamp => {
@@ -456,7 +609,7 @@ impl SourceTimestamp for KafkaTimestamp {
         let mut row = Row::with_capacity(2);
         let mut packer = row.packer();
 
-        let to_numeric = |p: i32| Datum::from(OrderedDecimal(Numeric::from(p)));
+        let to_numeric = |p: PartitionKey| Datum::from(OrderedDecimal(Numeric::from(p)));
 
         let (lower, lower_inclusive) = match self.interval().lower {
             RangeBound::NegInfinity => (Datum::Null, false),
@@ -494,12 +647,12 @@ impl SourceTimestamp for KafkaTimestamp {
                 let range = range.inner.expect("empty range");
 
                 let lower = range.lower.bound.map(|row| {
-                    i32::try_from(row.unwrap_numeric().0)
-                        .expect("only i32 values converted to ranges")
+                    i64::try_from(row.unwrap_numeric().0)
+                        .expect("only i64 values converted to ranges")
                 });
                 let upper = range.upper.bound.map(|row| {
-                    i32::try_from(row.unwrap_numeric().0)
-                        .expect("only i32 values converted to ranges")
+                    i64::try_from(row.unwrap_numeric().0)
+                        .expect("only i64 values converted to ranges")
                 });
 
                 match (range.lower.inclusive, range.upper.inclusive) {
@@ -537,4 +690,5 @@ pub enum KafkaMetadataKind {
     Timestamp,
     Headers,
     Header { key: String, use_bytes: bool },
+    Topic,
 }
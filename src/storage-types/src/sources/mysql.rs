@@ -32,7 +32,7 @@ use crate::connections::inline::{
     ReferencedConnection,
 };
 use crate::controller::AlterError;
-use crate::sources::{SourceConnection, SourceTimestamp};
+use crate::sources::{SchemaChangeBehavior, SourceConnection, SourceTimestamp};
 
 include!(concat!(
     env!("OUT_DIR"),
@@ -216,6 +216,9 @@ pub struct MySqlSourceExportDetails {
     pub text_columns: Vec<String>,
     pub exclude_columns: Vec<String>,
     pub binlog_full_metadata: bool,
+    /// How to handle columns added to the upstream table.
+    #[serde(default)]
+    pub schema_change_behavior: SchemaChangeBehavior,
 }
 
 impl AlterCompatible for MySqlSourceExportDetails {
@@ -232,6 +235,7 @@ impl AlterCompatible for MySqlSourceExportDetails {
             text_columns: _,
             exclude_columns: _,
             binlog_full_metadata: _,
+            schema_change_behavior: _,
         } = self;
         Ok(())
     }
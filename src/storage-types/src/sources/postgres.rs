@@ -21,7 +21,7 @@ use crate::connections::inline::{
     ReferencedConnection,
 };
 use crate::controller::AlterError;
-use crate::sources::{MzOffset, SourceConnection};
+use crate::sources::{MzOffset, SchemaChangeBehavior, SourceConnection};
 
 include!(concat!(
     env!("OUT_DIR"),
@@ -34,6 +34,9 @@ pub struct PostgresSourceConnection<C: ConnectionAccess = InlinedConnection> {
     pub connection: C::Pg,
     pub publication: String,
     pub publication_details: PostgresSourcePublicationDetails,
+    /// The truncate behavior of exports that do not specify their own.
+    #[serde(default)]
+    pub truncate_behavior: PostgresTruncateBehavior,
 }
 
 impl<R: ConnectionResolver> IntoInlineConnection<PostgresSourceConnection, R>
@@ -45,6 +48,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<PostgresSourceConnection, R>
             connection,
             publication,
             publication_details,
+            truncate_behavior,
         } = self;
 
         PostgresSourceConnection {
@@ -52,6 +56,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<PostgresSourceConnection, R>
             connection: r.resolve_connection(connection).unwrap_pg(),
             publication,
             publication_details,
+            truncate_behavior,
         }
     }
 }
@@ -142,6 +147,7 @@ impl<C: ConnectionAccess> AlterCompatible for PostgresSourceConnection<C> {
             connection,
             publication,
             publication_details,
+            truncate_behavior,
         } = self;
 
         let compatibility_checks = [
@@ -157,6 +163,10 @@ impl<C: ConnectionAccess> AlterCompatible for PostgresSourceConnection<C> {
                     .is_ok(),
                 "publication_details",
             ),
+            (
+                truncate_behavior == &other.truncate_behavior,
+                "truncate_behavior",
+            ),
         ];
 
         for (compatible, field) in compatibility_checks {
@@ -190,6 +200,15 @@ pub struct PostgresSourceExportDetails {
     /// their target types
     pub column_casts: Vec<(CastType, crate::sources::casts::StorageScalarExpr)>,
     pub table: PostgresTableDesc,
+    /// How to handle a `TRUNCATE` of the upstream table.
+    #[serde(default)]
+    pub truncate_behavior: PostgresTruncateBehavior,
+    /// How to handle columns added to the upstream table.
+    #[serde(default)]
+    pub schema_change_behavior: SchemaChangeBehavior,
+    /// The upstream columns that were excluded from the export.
+    #[serde(default)]
+    pub exclude_columns: Vec<String>,
 }
 
 impl AlterCompatible for PostgresSourceExportDetails {
@@ -199,11 +218,24 @@ impl AlterCompatible for PostgresSourceExportDetails {
         let Self {
             column_casts: _,
             table: _,
+            truncate_behavior: _,
+            schema_change_behavior: _,
+            exclude_columns: _,
         } = self;
         Ok(())
     }
 }
 
+/// How a postgres source export reacts to a `TRUNCATE` of its upstream table.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PostgresTruncateBehavior {
+    /// Put the export into a definite error state.
+    #[default]
+    Error,
+    /// Retract the contents of the export as of the truncation.
+    Retract,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PostgresSourcePublicationDetails {
     pub slot: String,
@@ -26,7 +26,7 @@ use crate::connections::inline::{
     ReferencedConnection,
 };
 use crate::controller::AlterError;
-use crate::sources::{SourceConnection, SourceTimestamp};
+use crate::sources::{SchemaChangeBehavior, SourceConnection, SourceTimestamp};
 
 include!(concat!(
     env!("OUT_DIR"),
@@ -46,6 +46,13 @@ pub const SNAPSHOT_PROGRESS_REPORT_INTERVAL: Config<Duration> = Config::new(
     "Interval at which we'll report progress for currently running snapshots.",
 );
 
+pub const SCHEMA_EVOLUTION_CHECK_INTERVAL: Config<Duration> = Config::new(
+    "sql_server_schema_evolution_check_interval",
+    Duration::from_secs(30),
+    "Interval at which we'll check whether tables that evolve their schema have a newer \
+    capture instance that captures added columns.",
+);
+
 pub const CDC_CLEANUP_CHANGE_TABLE: Config<bool> = Config::new(
     "sql_server_cdc_cleanup_change_table",
     false,
@@ -250,6 +257,9 @@ pub struct SqlServerSourceExportDetails {
     /// This is used as a consistent snapshot point for this export to ensure
     /// correctness in the case of multiple replicas.
     pub initial_lsn: mz_sql_server_util::cdc::Lsn,
+    /// How to handle columns added to the upstream table.
+    #[serde(default)]
+    pub schema_change_behavior: SchemaChangeBehavior,
 }
 
 impl AlterCompatible for SqlServerSourceExportDetails {
@@ -266,6 +276,7 @@ impl AlterCompatible for SqlServerSourceExportDetails {
             text_columns: _,
             exclude_columns: _,
             initial_lsn: _,
+            schema_change_behavior: _,
         } = self;
         Ok(())
     }
@@ -23,7 +23,7 @@ use futures::StreamExt;
 use mz_ore::cast::CastFrom;
 use mz_ore::now::NowFn;
 use mz_repr::GlobalId;
-use mz_storage_client::client::{Status, StatusUpdate};
+use mz_storage_client::client::{SCHEMA_EVOLUTION_STATUS_NAMESPACE, Status, StatusUpdate};
 use mz_timely_util::builder_async::{
     Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
 };
@@ -65,6 +65,8 @@ pub enum StatusNamespace {
     Upsert,
     Decode,
     Iceberg,
+    /// Exports that wait for the controller to evolve their schema.
+    SchemaEvolution,
     Internal,
 }
 
@@ -88,6 +90,7 @@ impl fmt::Display for StatusNamespace {
             Decode => write!(f, "decode"),
             Internal => write!(f, "internal"),
             Iceberg => write!(f, "iceberg"),
+            SchemaEvolution => write!(f, "{}", SCHEMA_EVOLUTION_STATUS_NAMESPACE),
         }
     }
 }
@@ -175,16 +175,10 @@ impl StorageMetrics {
     /// Get an `KafkaSourceMetrics` for the given configuration.
     pub(crate) fn get_kafka_source_metrics(
         &self,
-        ids: Vec<i32>,
-        topic: String,
+        ids: Vec<(String, i32)>,
         source_id: GlobalId,
     ) -> source::kafka::KafkaSourceMetrics {
-        source::kafka::KafkaSourceMetrics::new(
-            &self.source_defs.kafka_source_defs,
-            ids,
-            topic,
-            source_id,
-        )
+        source::kafka::KafkaSourceMetrics::new(&self.source_defs.kafka_source_defs, ids, source_id)
     }
 
     /// Get an `KafkaSinkMetrics` for the given configuration.
@@ -11,7 +11,6 @@
 
 use std::collections::BTreeMap;
 
-use mz_ore::iter::IteratorExt;
 use mz_ore::metric;
 use mz_ore::metrics::{DeleteOnDropGauge, IntGaugeVec, MetricsRegistry};
 use mz_repr::GlobalId;
@@ -38,34 +37,32 @@ impl KafkaSourceMetricDefs {
 
 /// Kafka-specific per-partition metrics.
 pub(crate) struct KafkaSourceMetrics {
-    labels: Vec<String>,
+    source_id: GlobalId,
     defs: KafkaSourceMetricDefs,
-    partition_offset_map: BTreeMap<i32, DeleteOnDropGauge<AtomicI64, Vec<String>>>,
+    partition_offset_map: BTreeMap<(String, i32), DeleteOnDropGauge<AtomicI64, Vec<String>>>,
 }
 
 impl KafkaSourceMetrics {
     /// Create a `KafkaSourceMetrics` from the `KafkaSourceMetricDefs`.
     pub(crate) fn new(
         defs: &KafkaSourceMetricDefs,
-        ids: Vec<i32>,
-        topic: String,
+        ids: Vec<(String, i32)>,
         source_id: GlobalId,
     ) -> Self {
         Self {
-            partition_offset_map: BTreeMap::from_iter(ids.iter().map(|id| {
-                let labels = &[topic.clone(), source_id.to_string(), format!("{}", id)];
+            partition_offset_map: BTreeMap::from_iter(ids.into_iter().map(|(topic, id)| {
+                let labels = vec![topic.clone(), source_id.to_string(), format!("{}", id)];
                 (
-                    *id,
-                    defs.partition_offset_max
-                        .get_delete_on_drop_metric(labels.to_vec()),
+                    (topic, id),
+                    defs.partition_offset_max.get_delete_on_drop_metric(labels),
                 )
             })),
-            labels: vec![topic.clone(), source_id.to_string()],
+            source_id,
             defs: defs.clone(),
         }
     }
 
-    pub(crate) fn set_offset_max(&mut self, id: i32, offset: i64) {
+    pub(crate) fn set_offset_max(&mut self, topic: &str, id: i32, offset: i64) {
         // Valid partition ids start at 0, librdkafka uses -1 as a sentinel for unassigned partitions
         if id < 0 {
             return;
@@ -77,15 +74,15 @@ impl KafkaSourceMetrics {
             return;
         }
         self.partition_offset_map
-            .entry(id)
-            .or_insert_with_key(|id| {
-                self.defs.partition_offset_max.get_delete_on_drop_metric(
-                    self.labels
-                        .iter()
-                        .cloned()
-                        .chain_one(format!("{}", id))
-                        .collect(),
-                )
+            .entry((topic.to_string(), id))
+            .or_insert_with_key(|(topic, id)| {
+                self.defs
+                    .partition_offset_max
+                    .get_delete_on_drop_metric(vec![
+                        topic.clone(),
+                        self.source_id.to_string(),
+                        format!("{}", id),
+                    ])
             })
             .set(offset);
     }
@@ -28,6 +28,7 @@ use mz_storage_types::errors::{
 };
 use mz_storage_types::parameters::StorageMaxInflightBytesConfig;
 use mz_storage_types::sources::envelope::{KeyEnvelope, NoneEnvelope, UpsertEnvelope, UpsertStyle};
+use mz_storage_types::sources::postgres::PostgresTruncateBehavior;
 use mz_storage_types::sources::*;
 use mz_timely_util::builder_async::PressOnDropButton;
 use mz_timely_util::operator::CollectionExt;
@@ -121,7 +122,7 @@ where
     let mut outputs = BTreeMap::new();
     for (export_id, export) in exports {
         type CB<C> = CapacityContainerBuilder<C>;
-        let (ok_stream, err_stream) =
+        let (mut ok_stream, err_stream) =
             export.map_fallible::<CB<_>, CB<_>, _, _, _>("export-demux-ok-err", |r| r);
 
         // All sources should push their various error streams into this vector,
@@ -129,6 +130,23 @@ where
         // All subsources include the non-definite errors of the ingestion
         let mut error_collections = Vec::new();
 
+        // PostgreSQL exports that retract their contents on truncation emit truncation markers
+        // that must be replaced before any further processing.
+        if let SourceExportDetails::Postgres(details) =
+            &base_source_config.source_exports[&export_id].details
+            && details.truncate_behavior == PostgresTruncateBehavior::Retract
+        {
+            let (ok, err, token) = source::render_truncate_retractions(
+                scope,
+                &base_source_config,
+                export_id,
+                ok_stream,
+            );
+            ok_stream = ok;
+            error_collections.push(err);
+            needed_tokens.push(token);
+        }
+
         let data_config = base_source_config.source_exports[&export_id]
             .data_config
             .clone();
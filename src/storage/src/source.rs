@@ -39,6 +39,7 @@ mod postgres;
 mod sql_server;
 
 pub use kafka::KafkaSourceReader;
+pub(crate) use postgres::render_truncate_retractions;
 pub use source_reader_pipeline::{
     RawSourceCreationConfig, SourceExportCreationConfig, create_raw_source,
 };
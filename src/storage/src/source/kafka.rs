@@ -7,10 +7,10 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::collections::BTreeMap;
 use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
 use std::str::{self};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
 
@@ -35,7 +35,9 @@ use mz_storage_types::errors::{
     ContextCreationError, DataflowError, SourceError, SourceErrorDetails,
 };
 use mz_storage_types::sources::kafka::{
-    KafkaMetadataKind, KafkaSourceConnection, KafkaTimestamp, RangeBound,
+    KafkaMetadataKind, KafkaSourceConnection, KafkaTimestamp, KafkaTopics, PartitionKey,
+    RangeBound, compile_topic_pattern, partitions_frontier, split_partition_key,
+    unknown_partition_ranges,
 };
 use mz_storage_types::sources::{MzOffset, SourceExport, SourceExportDetails, SourceTimestamp};
 use mz_timely_util::antichain::AntichainExt;
@@ -102,10 +104,18 @@ impl HealthStatus {
     }
 }
 
+/// The names of the topics a source has seen so far, by topic id.
+///
+/// The reader learns about topics from the metadata fetcher and shares them with the offset
+/// committer, which needs them to commit the offsets of a [`PartitionKey`].
+type TopicNames = Arc<Mutex<BTreeMap<u32, String>>>;
+
 /// Contains all information necessary to ingest data from Kafka
 pub struct KafkaSourceReader {
-    /// Name of the topic on which this source is backed on
-    topic_name: String,
+    /// The topics on which this source is backed on
+    topics: KafkaTopics,
+    /// The names of the topics seen so far
+    topic_names: TopicNames,
     /// Name of the source (will have format kafka-source-id)
     source_name: String,
     /// Source global ID
@@ -121,15 +131,15 @@ pub struct KafkaSourceReader {
     /// The most recently read offset for each partition known to this source
     /// reader by output-index. An offset of -1 indicates that no prior message
     /// has been read for the given partition.
-    last_offsets: BTreeMap<usize, BTreeMap<PartitionId, i64>>,
+    last_offsets: BTreeMap<usize, BTreeMap<PartitionKey, i64>>,
     /// The offset to start reading from for each partition.
-    start_offsets: BTreeMap<PartitionId, i64>,
+    start_offsets: BTreeMap<PartitionKey, i64>,
     /// Channel to receive Kafka statistics JSON blobs from the stats callback.
     stats_rx: crossbeam_channel::Receiver<Jsonb>,
     /// A handle to the partition specific metrics
     partition_metrics: KafkaSourceMetrics,
     /// Per partition capabilities used to produce messages
-    partition_capabilities: BTreeMap<PartitionId, PartitionCapability>,
+    partition_capabilities: BTreeMap<PartitionKey, PartitionCapability>,
 }
 
 struct PartitionCapability {
@@ -147,7 +157,7 @@ type PartitionWatermark = u64;
 /// storing them in the `progress_statistics` to be emitted later.
 pub struct KafkaResumeUpperProcessor {
     config: RawSourceCreationConfig,
-    topic_name: String,
+    topic_names: TopicNames,
     consumer: Arc<BaseConsumer<TunnelingClientContext<GlueConsumerContext>>>,
     statistics: Vec<SourceStatistics>,
 }
@@ -155,9 +165,45 @@ pub struct KafkaResumeUpperProcessor {
 /// Computes whether this worker is responsible for consuming a partition. It assigns partitions to
 /// workers in a round-robin fashion, starting at an arbitrary worker based on the hash of the
 /// source id.
-fn responsible_for_pid(config: &RawSourceCreationConfig, pid: i32) -> bool {
-    let pid = usize::try_from(pid).expect("positive pid");
-    ((config.responsible_worker(config.id) + pid) % config.worker_count) == config.worker_id
+fn responsible_for_pid(config: &RawSourceCreationConfig, key: PartitionKey) -> bool {
+    let key = usize::try_from(key).expect("positive partition key");
+    let offset = key % config.worker_count;
+    ((config.responsible_worker(config.id) + offset) % config.worker_count) == config.worker_id
+}
+
+/// Returns a capability for `ts`, delayed from whichever of `caps` is not beyond it.
+fn delayed_from<'a>(
+    caps: impl IntoIterator<Item = &'a Capability<KafkaTimestamp>>,
+    ts: &KafkaTimestamp,
+) -> Capability<KafkaTimestamp> {
+    caps.into_iter()
+        .find(|cap| PartialOrder::less_equal(cap.time(), ts))
+        .unwrap_or_else(|| panic!("no capability covers {ts}"))
+        .delayed(ts)
+}
+
+/// Downgrades the capabilities held for partitions that are not known yet to the ranges left over
+/// by the known partition `keys`.
+///
+/// `data_cap` always covers the keys past the largest known key, and `gap_caps` cover the keys
+/// before and in between known keys. Partitions are only ever added, so each new range lies within
+/// a range we already hold a capability for.
+fn downgrade_unknown_partitions(
+    data_cap: &mut Capability<KafkaTimestamp>,
+    gap_caps: &mut Vec<Capability<KafkaTimestamp>>,
+    keys: impl IntoIterator<Item = PartitionKey>,
+) {
+    let mut ranges = unknown_partition_ranges(keys);
+    let (lower, upper) = ranges.pop().expect("last range always present");
+    let new_gap_caps = ranges
+        .into_iter()
+        .map(|(lower, upper)| {
+            let ts = Partitioned::new_range(lower, upper, MzOffset::from(0));
+            delayed_from(std::iter::once(&*data_cap).chain(gap_caps.iter()), &ts)
+        })
+        .collect();
+    *gap_caps = new_gap_caps;
+    data_cap.downgrade(&Partitioned::new_range(lower, upper, MzOffset::from(0)));
 }
 
 struct SourceOutputInfo {
@@ -168,8 +214,8 @@ struct SourceOutputInfo {
 }
 
 impl SourceRender for KafkaSourceConnection {
-    // TODO(petrosagg): The type used for the partition (RangeBound<PartitionId>) doesn't need to
-    // be so complicated and we could instead use `Partitioned<PartitionId, Option<u64>>` where all
+    // TODO(petrosagg): The type used for the partition (RangeBound<PartitionKey>) doesn't need to
+    // be so complicated and we could instead use `Partitioned<PartitionKey, Option<u64>>` where all
     // ranges are inclusive and a time of `None` signifies that a particular partition is not
     // present. This requires an shard migration of the remap shard.
     type Time = KafkaTimestamp;
@@ -265,7 +311,7 @@ fn render_reader<'scope>(
                 .get(id)
                 .expect("all source exports must be present in source resume uppers")
                 .iter()
-                .map(Partitioned::<RangeBound<PartitionId>, MzOffset>::decode_row),
+                .map(KafkaTimestamp::decode_row),
         );
 
         let metadata_columns = match details {
@@ -310,7 +356,7 @@ fn render_reader<'scope>(
             let group_id = connection.group_id(&config.config.connection_context, config.id);
             let KafkaSourceConnection {
                 connection,
-                topic,
+                topics,
                 topic_metadata_refresh_interval,
                 start_offsets,
                 metadata_columns: _,
@@ -413,16 +459,33 @@ fn render_reader<'scope>(
                 }
             };
 
-            // Start offsets is a map from partition to the next offset to read from.
+            // Start offsets is a map from partition to the next offset to read from. They can only
+            // be specified for single-topic sources, whose partition keys are the partition ids.
             let mut start_offsets: BTreeMap<_, u64> = start_offsets
                 .clone()
                 .into_iter()
-                .filter(|(pid, _offset)| responsible_for_pid(&config, *pid))
-                .map(|(pid, offset)| (pid, u64::try_from(offset).expect("start offsets must be non-negative and fit into u64")))
+                .map(|(pid, offset)| (PartitionKey::from(pid), offset))
+                .filter(|(key, _offset)| responsible_for_pid(&config, *key))
+                .map(|(key, offset)| (key, u64::try_from(offset).expect("start offsets must be non-negative and fit into u64")))
                 .collect();
 
+            let topic_names: TopicNames = Arc::new(Mutex::new(BTreeMap::new()));
+            // The names of pattern-matched topics are only known once we fetch metadata.
+            let static_topic_names = match &topics {
+                KafkaTopics::Topic(topic) => vec![topic.clone()],
+                KafkaTopics::List(names) => names.clone(),
+                KafkaTopics::Pattern(_) => vec![],
+            };
+            for name in static_topic_names {
+                let topic_id = topics.topic_id(&name);
+                topic_names.lock().expect("lock poisoned").insert(topic_id, name);
+            }
+
             let mut partition_capabilities = BTreeMap::new();
-            let mut max_pid = None;
+            // Capabilities for the ranges of partitions before and in between the known
+            // partitions. See `downgrade_unknown_partitions`.
+            let mut gap_capabilities = vec![];
+            let mut known_keys = BTreeSet::new();
             let resume_upper = Antichain::from_iter(
                 outputs
                     .iter()
@@ -438,58 +501,68 @@ fn render_reader<'scope>(
             );
 
             for ts in resume_upper.elements() {
-                if let Some(pid) = ts.interval().singleton() {
-                    let pid = pid.unwrap_exact();
-                    max_pid = std::cmp::max(max_pid, Some(*pid));
+                if let Some(key) = ts.interval().singleton() {
+                    let key = key.unwrap_exact();
+                    known_keys.insert(*key);
 
-                    if responsible_for_pid(&config, *pid) {
+                    if responsible_for_pid(&config, *key) {
                         let restored_offset = ts.timestamp().offset;
-                        if let Some(start_offset) = start_offsets.get_mut(pid) {
+                        if let Some(start_offset) = start_offsets.get_mut(key) {
                             *start_offset = std::cmp::max(restored_offset, *start_offset);
                         } else {
-                            start_offsets.insert(*pid, restored_offset);
+                            start_offsets.insert(*key, restored_offset);
                         }
 
                         let part_ts = Partitioned::new_singleton(
-                            RangeBound::exact(*pid),
+                            RangeBound::exact(*key),
                             ts.timestamp().clone(),
                         );
                         let part_cap = PartitionCapability {
                             data: data_cap.delayed(&part_ts),
                         };
-                        partition_capabilities.insert(*pid, part_cap);
+                        partition_capabilities.insert(*key, part_cap);
                     }
                 }
             }
-            let lower = max_pid
-                .map(RangeBound::after)
-                .unwrap_or(RangeBound::NegInfinity);
-            let future_ts =
-                Partitioned::new_range(lower, RangeBound::PosInfinity, MzOffset::from(0));
-            data_cap.downgrade(&future_ts);
+            downgrade_unknown_partitions(&mut data_cap, &mut gap_capabilities, known_keys);
 
             if mz_storage_types::dyncfgs::KAFKA_LOW_WATERMARK_CHECK
                 .get(config.config.config_set())
             {
-                let low_watermarks = fetch_partition_info(
-                    &consumer,
-                    topic.as_str(),
-                    config
-                        .config
-                        .parameters
-                        .kafka_timeout_config
-                        .fetch_metadata_timeout,
-                    Offset::Beginning, // fetch the low watermark
-                )
+                let fetch_timeout = config
+                    .config
+                    .parameters
+                    .kafka_timeout_config
+                    .fetch_metadata_timeout;
+                let low_watermarks = fetch_topic_names(&consumer, &topics, fetch_timeout)
+                    .and_then(|names| {
+                        let mut low_watermarks = BTreeMap::new();
+                        for name in names {
+                            let partitions = fetch_partition_info(
+                                &consumer,
+                                &name,
+                                fetch_timeout,
+                                Offset::Beginning, // fetch the low watermark
+                            )?;
+                            for (pid, lwm) in partitions {
+                                low_watermarks.insert(topics.partition_key(&name, pid), lwm);
+                            }
+                            topic_names
+                                .lock()
+                                .expect("lock poisoned")
+                                .insert(topics.topic_id(&name), name);
+                        }
+                        Ok(low_watermarks)
+                    })
                 .unwrap_or_else(|e| {
                     tracing::warn!(
                         source_id = config.id.to_string(),
                         worker_id = config.worker_id,
                         num_workers = config.worker_count,
-                        "Failed to fetch watermarks for topic {topic}: {e}"
+                        "Failed to fetch watermarks for topic {topics}: {e}"
                     );
                     let update = HealthStatusUpdate::stalled(
-                        format!("Failed to fetch watermarks for topic {topic}: {e}"),
+                        format!("Failed to fetch watermarks for topic {topics}: {e}"),
                         None,
                     );
                     health_output.give(
@@ -548,15 +621,22 @@ fn render_reader<'scope>(
                     }
                     BTreeMap::new()
                 });
-                for (pid, lwm) in &low_watermarks {
-                    if responsible_for_pid(&config, *pid) {
+                for (key, lwm) in &low_watermarks {
+                    let (_, pid) = split_partition_key(*key);
+                    if responsible_for_pid(&config, *key) {
                         // If a start offset exists for this partition, then either the user specified it
                         // or we restored it from the resume upper. In either case, if the low watermark is
                         // greater than the start offset, we know for certain that the offset we need to
                         // start at has been compacted away or dropped from retention by Kafka. If there
                         // is no start offset, then we set it to the low watermark and start consuming from
                         // there, assuming the user doesn't care about the messages that have been compacted away.
-                        if let Some(start_offset) = start_offsets.get_mut(pid) {
+                        if let Some(start_offset) = start_offsets.get_mut(key) {
+                            let topic = topic_names
+                                .lock()
+                                .expect("lock poisoned")
+                                .get(&split_partition_key(*key).0)
+                                .cloned()
+                                .unwrap_or_default();
                             tracing::info!(
                                 source_id = config.id.to_string(),
                                 worker_id = config.worker_id,
@@ -617,7 +697,7 @@ fn render_reader<'scope>(
                                 "partition {pid} has a non-zero low watermark {lwm}, but no start offset or \
                                 resume upper was found for this partition. Setting start offset to low watermark"
                             );
-                            start_offsets.insert(*pid, *lwm);
+                            start_offsets.insert(*key, *lwm);
                         }
                     }
                 }
@@ -634,12 +714,22 @@ fn render_reader<'scope>(
                 "kafka worker noticed rehydration is finished, starting partition queues..."
             );
 
-            let partition_ids = start_offsets.keys().copied().collect();
+            let partition_ids = {
+                let topic_names = topic_names.lock().expect("lock poisoned");
+                start_offsets
+                    .keys()
+                    .filter_map(|key| {
+                        let (topic_id, pid) = split_partition_key(*key);
+                        topic_names.get(&topic_id).map(|topic| (topic.clone(), pid))
+                    })
+                    .collect()
+            };
             let offset_commit_metrics = config.metrics.get_offset_commit_metrics(config.id);
-            let start_offsets = start_offsets.iter().map(|(pid, offset)| (*pid, i64::try_from(*offset).expect("start offsets must fit into i64"))).collect();
+            let start_offsets = start_offsets.iter().map(|(key, offset)| (*key, i64::try_from(*offset).expect("start offsets must fit into i64"))).collect();
 
             let mut reader = KafkaSourceReader {
-                topic_name: topic.clone(),
+                topics: topics.clone(),
+                topic_names: Arc::clone(&topic_names),
                 source_name: config.name.clone(),
                 id: config.id,
                 partition_consumers: Vec::new(),
@@ -654,7 +744,6 @@ fn render_reader<'scope>(
                 stats_rx,
                 partition_metrics: config.metrics.get_kafka_source_metrics(
                     partition_ids,
-                    topic.clone(),
                     config.id,
                 ),
                 partition_capabilities,
@@ -662,7 +751,7 @@ fn render_reader<'scope>(
 
             let offset_committer = KafkaResumeUpperProcessor {
                 config: config.clone(),
-                topic_name: topic.clone(),
+                topic_names,
                 consumer,
                 statistics: all_export_stats.clone(),
             };
@@ -747,36 +836,38 @@ fn render_reader<'scope>(
                 }
 
                 match metadata_update.take() {
-                    Some(MetadataUpdate::Partitions(partitions)) => {
-                        let max_pid = partitions.keys().last().cloned();
-                        let lower = max_pid
-                            .map(RangeBound::after)
-                            .unwrap_or(RangeBound::NegInfinity);
-                        let future_ts = Partitioned::new_range(
-                            lower,
-                            RangeBound::PosInfinity,
-                            MzOffset::from(0),
-                        );
+                    Some(MetadataUpdate::Partitions {
+                        topics: topic_names,
+                        partitions,
+                    }) => {
+                        reader
+                            .topic_names
+                            .lock()
+                            .expect("lock poisoned")
+                            .extend(topic_names.clone());
 
                         let mut offset_known = 0;
-                        for (&pid, &high_watermark) in &partitions {
-                            if responsible_for_pid(&config, pid) {
+                        for (&key, &high_watermark) in &partitions {
+                            if responsible_for_pid(&config, key) {
                                 offset_known += high_watermark;
-                                reader.ensure_partition(pid);
+                                let (topic_id, pid) = split_partition_key(key);
+                                reader.ensure_partition(&topic_names[&topic_id], pid);
                                 if let Entry::Vacant(entry) =
-                                    reader.partition_capabilities.entry(pid)
+                                    reader.partition_capabilities.entry(key)
                                 {
-                                    let start_offset = match reader.start_offsets.get(&pid) {
+                                    let start_offset = match reader.start_offsets.get(&key) {
                                         Some(&offset) => offset.try_into().unwrap(),
                                         None => 0u64,
                                     };
                                     let part_since_ts = Partitioned::new_singleton(
-                                        RangeBound::exact(pid),
+                                        RangeBound::exact(key),
                                         MzOffset::from(start_offset),
                                     );
 
+                                    let caps =
+                                        std::iter::once(&data_cap).chain(gap_capabilities.iter());
                                     entry.insert(PartitionCapability {
-                                        data: data_cap.delayed(&part_since_ts),
+                                        data: delayed_from(caps, &part_since_ts),
                                     });
                                 }
                             }
@@ -821,7 +912,11 @@ fn render_reader<'scope>(
                             export_stat.set_offset_known(offset_known);
                         }
 
-                        data_cap.downgrade(&future_ts);
+                        downgrade_unknown_partitions(
+                            &mut data_cap,
+                            &mut gap_capabilities,
+                            partitions.keys().copied(),
+                        );
                     }
                     Some(MetadataUpdate::TransientError(status)) => {
                         if let Some(update) = status.kafka {
@@ -906,7 +1001,7 @@ fn render_reader<'scope>(
                         Err(e) => {
                             let error = format!(
                                 "kafka error when polling consumer for source: {} topic: {} : {}",
-                                reader.source_name, reader.topic_name, e
+                                reader.source_name, reader.topics, e
                             );
                             let status = HealthStatusUpdate::stalled(error, None);
                             health_output.give(
@@ -929,11 +1024,15 @@ fn render_reader<'scope>(
                             }
                         }
                         Ok(message) => {
+                            let key = reader
+                                .topics
+                                .partition_key(message.topic(), message.partition());
                             let output_messages = outputs
                                 .iter()
                                 .map(|output| {
                                     let (message, ts) = construct_source_message(
                                         &message,
+                                        key,
                                         &output.metadata_columns,
                                     );
                                     (output.output_index, message, ts)
@@ -946,8 +1045,8 @@ fn render_reader<'scope>(
                                 if let Some((msg, time, diff)) =
                                     reader.handle_message(message, ts, &output_index)
                                 {
-                                    let pid = time.interval().singleton().unwrap().unwrap_exact();
-                                    let part_cap = &reader.partition_capabilities[pid].data;
+                                    let key = time.interval().singleton().unwrap().unwrap_exact();
+                                    let part_cap = &reader.partition_capabilities[key].data;
                                     let msg = msg.map_err(|e| {
                                         DataflowError::SourceError(Box::new(SourceError {
                                             error: SourceErrorDetails::Other(e.to_string().into()),
@@ -969,7 +1068,9 @@ fn render_reader<'scope>(
                 // Take the consumers temporarily to get around borrow checker errors
                 let mut consumers = std::mem::take(&mut reader.partition_consumers);
                 for consumer in consumers.iter_mut() {
-                    let pid = consumer.pid();
+                    let key = consumer.key();
+                    let (_, pid) = split_partition_key(key);
+                    let topic = consumer.topic();
                     // We want to make sure the rest of the actions in the outer loops get
                     // a chance to run. If rdkafka keeps pumping data at us we might find
                     // ourselves in a situation where we keep dumping data into the
@@ -984,18 +1085,20 @@ fn render_reader<'scope>(
 
                         for output in outputs.iter() {
                             let message = match &message {
-                                Ok((msg, pid)) => {
-                                    let (msg, ts) =
-                                        construct_source_message(msg, &output.metadata_columns);
-                                    assert_eq!(*pid, ts.0);
+                                Ok((msg, key)) => {
+                                    let (msg, ts) = construct_source_message(
+                                        msg,
+                                        *key,
+                                        &output.metadata_columns,
+                                    );
                                     Ok(reader.handle_message(msg, ts, &output.output_index))
                                 }
                                 Err(err) => Err(err),
                             };
                             match message {
                                 Ok(Some((msg, time, diff))) => {
-                                    let pid = time.interval().singleton().unwrap().unwrap_exact();
-                                    let part_cap = &reader.partition_capabilities[pid].data;
+                                    let key = time.interval().singleton().unwrap().unwrap_exact();
+                                    let part_cap = &reader.partition_capabilities[key].data;
                                     let msg = msg.map_err(|e| {
                                         DataflowError::SourceError(Box::new(SourceError {
                                             error: SourceErrorDetails::Other(e.to_string().into()),
@@ -1015,7 +1118,7 @@ fn render_reader<'scope>(
                                         .last_offsets
                                         .get(&output.output_index)
                                         .expect("output known to be installed")
-                                        .get(&pid)
+                                        .get(&key)
                                         .expect("partition known to be installed");
 
                                     let status = HealthStatusUpdate::stalled(
@@ -1056,19 +1159,20 @@ fn render_reader<'scope>(
                 reader.partition_consumers = consumers;
 
                 let positions = reader.consumer.position().unwrap();
-                let topic_positions = positions.elements_for_topic(&reader.topic_name);
                 let mut snapshot_staged = 0;
 
-                for position in topic_positions {
+                for position in positions.elements() {
                     // The offset begins in the `Offset::Invalid` state in which case we simply
                     // skip this partition.
                     if let Offset::Offset(offset) = position.offset() {
-                        let pid = position.partition();
+                        let key = reader
+                            .topics
+                            .partition_key(position.topic(), position.partition());
                         let upper_offset = MzOffset::from(u64::try_from(offset).unwrap());
                         let upper =
-                            Partitioned::new_singleton(RangeBound::exact(pid), upper_offset);
+                            Partitioned::new_singleton(RangeBound::exact(key), upper_offset);
 
-                        let part_cap = reader.partition_capabilities.get_mut(&pid).unwrap();
+                        let part_cap = reader.partition_capabilities.get_mut(&key).unwrap();
                         match part_cap.data.try_downgrade(&upper) {
                             Ok(()) => {
                                 if !snapshot_export_stats.is_empty() {
@@ -1133,11 +1237,17 @@ impl KafkaResumeUpperProcessor {
         // Generate a list of partitions that this worker is responsible for
         let mut offsets = vec![];
         let mut offset_committed = 0;
+        let topic_names = self.topic_names.lock().expect("lock poisoned").clone();
         for ts in frontier.iter() {
-            if let Some(pid) = ts.interval().singleton() {
-                let pid = pid.unwrap_exact();
-                if responsible_for_pid(&self.config, *pid) {
-                    offsets.push((pid.clone(), *ts.timestamp()));
+            if let Some(key) = ts.interval().singleton() {
+                let key = key.unwrap_exact();
+                if responsible_for_pid(&self.config, *key) {
+                    // The names of pattern-matched topics are not known until the first metadata
+                    // refresh. Their offsets get committed on the next frontier.
+                    let (topic_id, pid) = split_partition_key(*key);
+                    if let Some(topic) = topic_names.get(&topic_id) {
+                        offsets.push((topic.clone(), pid, *ts.timestamp()));
+                    }
 
                     // Note that we do not subtract 1 from the frontier. Imagine
                     // that frontier is 2 for this pid. That means we have
@@ -1154,10 +1264,10 @@ impl KafkaResumeUpperProcessor {
 
         if !offsets.is_empty() {
             let mut tpl = TopicPartitionList::new();
-            for (pid, offset) in offsets {
+            for (topic, pid, offset) in offsets {
                 let offset_to_commit =
                     Offset::Offset(offset.offset.try_into().expect("offset to be vald i64"));
-                tpl.add_partition_offset(&self.topic_name, pid, offset_to_commit)
+                tpl.add_partition_offset(&topic, pid, offset_to_commit)
                     .expect("offset known to be valid");
             }
             let consumer = Arc::clone(&self.consumer);
@@ -1172,8 +1282,8 @@ impl KafkaResumeUpperProcessor {
 }
 
 impl KafkaSourceReader {
-    /// Ensures that a partition queue for `pid` exists.
-    fn ensure_partition(&mut self, pid: PartitionId) {
+    /// Ensures that a partition queue for partition `pid` of `topic` exists.
+    fn ensure_partition(&mut self, topic: &str, pid: PartitionId) {
         if self.last_offsets.is_empty() {
             tracing::info!(
                 source_id = %self.id,
@@ -1182,30 +1292,36 @@ impl KafkaSourceReader {
 
             return;
         }
+        let key = self.topics.partition_key(topic, pid);
         for last_offsets in self.last_offsets.values() {
             // early exit if we've already inserted this partition
-            if last_offsets.contains_key(&pid) {
+            if last_offsets.contains_key(&key) {
                 return;
             }
         }
 
-        let start_offset = self.start_offsets.get(&pid).copied().unwrap_or(0);
-        self.create_partition_queue(pid, Offset::Offset(start_offset));
+        let start_offset = self.start_offsets.get(&key).copied().unwrap_or(0);
+        self.create_partition_queue(topic, pid, Offset::Offset(start_offset));
 
         for last_offsets in self.last_offsets.values_mut() {
-            let prev = last_offsets.insert(pid, start_offset - 1);
+            let prev = last_offsets.insert(key, start_offset - 1);
             assert_none!(prev);
         }
     }
 
-    /// Creates a new partition queue for `partition_id`.
-    fn create_partition_queue(&mut self, partition_id: PartitionId, initial_offset: Offset) {
+    /// Creates a new partition queue for partition `partition_id` of `topic`.
+    fn create_partition_queue(
+        &mut self,
+        topic: &str,
+        partition_id: PartitionId,
+        initial_offset: Offset,
+    ) {
         info!(
             source_id = self.id.to_string(),
             worker_id = self.worker_id,
             num_workers = self.worker_count,
             "activating Kafka queue for topic {}, partition {}",
-            self.topic_name,
+            topic,
             partition_id,
         );
 
@@ -1213,14 +1329,14 @@ impl KafkaSourceReader {
         let tpl = self.consumer.assignment().unwrap();
         // Create list from assignments
         let mut partition_list = TopicPartitionList::new();
-        for partition in tpl.elements_for_topic(&self.topic_name) {
+        for partition in tpl.elements() {
             partition_list
                 .add_partition_offset(partition.topic(), partition.partition(), partition.offset())
                 .expect("offset known to be valid");
         }
         // Add new partition
         partition_list
-            .add_partition_offset(&self.topic_name, partition_id, initial_offset)
+            .add_partition_offset(topic, partition_id, initial_offset)
             .expect("offset known to be valid");
         self.consumer
             .assign(&partition_list)
@@ -1232,7 +1348,7 @@ impl KafkaSourceReader {
         for pc in &mut self.partition_consumers {
             pc.partition_queue = self
                 .consumer
-                .split_partition_queue(&self.topic_name, pc.pid)
+                .split_partition_queue(&pc.topic, pc.pid)
                 .expect("partition known to be valid");
             pc.partition_queue.set_nonempty_callback({
                 let context = Arc::clone(&context);
@@ -1242,17 +1358,18 @@ impl KafkaSourceReader {
 
         let mut partition_queue = self
             .consumer
-            .split_partition_queue(&self.topic_name, partition_id)
+            .split_partition_queue(topic, partition_id)
             .expect("partition known to be valid");
         partition_queue.set_nonempty_callback(move || context.inner().activate());
-        self.partition_consumers
-            .push(PartitionConsumer::new(partition_id, partition_queue));
+        let key = self.topics.partition_key(topic, partition_id);
+        self.partition_consumers.push(PartitionConsumer::new(
+            topic.to_string(),
+            partition_id,
+            key,
+            partition_queue,
+        ));
         assert_eq!(
-            self.consumer
-                .assignment()
-                .unwrap()
-                .elements_for_topic(&self.topic_name)
-                .len(),
+            self.consumer.assignment().unwrap().count(),
             self.partition_consumers.len()
         );
     }
@@ -1262,15 +1379,20 @@ impl KafkaSourceReader {
         while let Ok(stats) = self.stats_rx.try_recv() {
             match serde_json::from_str::<Statistics>(&stats.to_string()) {
                 Ok(statistics) => {
-                    let topic = statistics.topics.get(&self.topic_name);
-                    match topic {
-                        Some(topic) => {
-                            for (id, partition) in &topic.partitions {
-                                self.partition_metrics
-                                    .set_offset_max(*id, partition.hi_offset);
+                    let topic_names = self.topic_names.lock().expect("lock poisoned");
+                    for name in topic_names.values() {
+                        match statistics.topics.get(name) {
+                            Some(topic) => {
+                                for (id, partition) in &topic.partitions {
+                                    self.partition_metrics.set_offset_max(
+                                        name,
+                                        *id,
+                                        partition.hi_offset,
+                                    );
+                                }
                             }
+                            None => error!("No stats found for topic: {}", name),
                         }
-                        None => error!("No stats found for topic: {}", &self.topic_name),
                     }
                 }
                 Err(e) => {
@@ -1285,7 +1407,7 @@ impl KafkaSourceReader {
     fn handle_message(
         &mut self,
         message: Result<SourceMessage, KafkaHeaderParseError>,
-        (partition, offset): (PartitionId, MzOffset),
+        (key, offset): (PartitionKey, MzOffset),
         output_index: &usize,
     ) -> Option<(
         Result<SourceMessage, KafkaHeaderParseError>,
@@ -1307,19 +1429,27 @@ impl KafkaSourceReader {
             self.last_offsets
                 .get(output_index)
                 .unwrap()
-                .contains_key(&partition)
+                .contains_key(&key)
         );
 
         let last_offset_ref = self
             .last_offsets
             .get_mut(output_index)
             .expect("output known to be installed")
-            .get_mut(&partition)
+            .get_mut(&key)
             .expect("partition known to be installed");
 
         let last_offset = *last_offset_ref;
         let offset_as_i64: i64 = offset.offset.try_into().expect("offset to be < i64::MAX");
         if offset_as_i64 <= last_offset {
+            let (topic_id, partition) = split_partition_key(key);
+            let topic = self
+                .topic_names
+                .lock()
+                .expect("lock poisoned")
+                .get(&topic_id)
+                .cloned()
+                .unwrap_or_default();
             info!(
                 source_id = self.id.to_string(),
                 worker_id = self.worker_id,
@@ -1328,7 +1458,7 @@ impl KafkaSourceReader {
                  source {} (reading topic {}, partition {}, output {}) \
                  received offset {} expected offset {:?}",
                 self.source_name,
-                self.topic_name,
+                topic,
                 partition,
                 output_index,
                 offset.offset,
@@ -1339,18 +1469,21 @@ impl KafkaSourceReader {
         } else {
             *last_offset_ref = offset_as_i64;
 
-            let ts = Partitioned::new_singleton(RangeBound::exact(partition), offset);
+            let ts = Partitioned::new_singleton(RangeBound::exact(key), offset);
             Some((message, ts, Diff::ONE))
         }
     }
 }
 
+/// Constructs a [`SourceMessage`] from `msg`, which was read from the partition identified by
+/// `partition_key`.
 fn construct_source_message(
     msg: &BorrowedMessage<'_>,
+    partition_key: PartitionKey,
     metadata_columns: &[KafkaMetadataKind],
 ) -> (
     Result<SourceMessage, KafkaHeaderParseError>,
-    (PartitionId, MzOffset),
+    (PartitionKey, MzOffset),
 ) {
     let pid = msg.partition();
     let Ok(offset) = u64::try_from(msg.offset()) else {
@@ -1364,6 +1497,7 @@ fn construct_source_message(
     let mut packer = metadata.packer();
     for kind in metadata_columns {
         match kind {
+            KafkaMetadataKind::Topic => packer.push(Datum::String(msg.topic())),
             KafkaMetadataKind::Partition => packer.push(Datum::from(pid)),
             KafkaMetadataKind::Offset => packer.push(Datum::UInt64(offset)),
             KafkaMetadataKind::Timestamp => {
@@ -1410,7 +1544,7 @@ fn construct_source_message(
                         match d {
                             Ok(d) => packer.push(d),
                             //abort with a definite error when the header is not found or cannot be parsed correctly
-                            Err(err) => return (Err(err), (pid, offset.into())),
+                            Err(err) => return (Err(err), (partition_key, offset.into())),
                         }
                     }
                     None => packer.push(Datum::Null),
@@ -1451,14 +1585,18 @@ fn construct_source_message(
             value,
             metadata,
         }),
-        (pid, offset.into()),
+        (partition_key, offset.into()),
     )
 }
 
 /// Wrapper around a partition containing the underlying consumer
 struct PartitionConsumer {
+    /// the topic with which this consumer is associated
+    topic: String,
     /// the partition id with which this consumer is associated
     pid: PartitionId,
+    /// the partition key of `topic` and `pid`
+    key: PartitionKey,
     /// The underlying Kafka partition queue
     partition_queue: PartitionQueue<TunnelingClientContext<GlueConsumerContext>>,
 }
@@ -1466,11 +1604,15 @@ struct PartitionConsumer {
 impl PartitionConsumer {
     /// Creates a new partition consumer from underlying Kafka consumer
     fn new(
+        topic: String,
         pid: PartitionId,
+        key: PartitionKey,
         partition_queue: PartitionQueue<TunnelingClientContext<GlueConsumerContext>>,
     ) -> Self {
         PartitionConsumer {
+            topic,
             pid,
+            key,
             partition_queue,
         }
     }
@@ -1481,17 +1623,22 @@ impl PartitionConsumer {
     /// be transformed into empty values.
     ///
     /// The inner `Option` represents if there is a message to process.
-    fn get_next_message(&self) -> Result<Option<(BorrowedMessage<'_>, PartitionId)>, KafkaError> {
+    fn get_next_message(&self) -> Result<Option<(BorrowedMessage<'_>, PartitionKey)>, KafkaError> {
         match self.partition_queue.poll(Duration::from_millis(0)) {
-            Some(Ok(msg)) => Ok(Some((msg, self.pid))),
+            Some(Ok(msg)) => Ok(Some((msg, self.key))),
             Some(Err(err)) => Err(err),
             _ => Ok(None),
         }
     }
 
-    /// Return the partition id for this PartitionConsumer
-    fn pid(&self) -> PartitionId {
-        self.pid
+    /// Return the topic for this PartitionConsumer
+    fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Return the partition key for this PartitionConsumer
+    fn key(&self) -> PartitionKey {
+        self.key
     }
 }
 
@@ -1661,11 +1808,41 @@ fn fetch_partition_info<C: ConsumerContext>(
     Ok(result)
 }
 
+/// Fetches the names of the topics that `topics` selects.
+///
+/// Named topics are returned as is. Their existence is checked when fetching their partitions.
+fn fetch_topic_names<C: ConsumerContext>(
+    consumer: &BaseConsumer<C>,
+    topics: &KafkaTopics,
+    fetch_timeout: Duration,
+) -> Result<Vec<String>, GetPartitionsError> {
+    match topics {
+        KafkaTopics::Topic(topic) => Ok(vec![topic.clone()]),
+        KafkaTopics::List(topics) => Ok(topics.clone()),
+        KafkaTopics::Pattern(pattern) => {
+            let pattern = compile_topic_pattern(pattern).map_err(|e| anyhow!(e))?;
+            let meta = consumer.client().fetch_metadata(None, fetch_timeout)?;
+            Ok(meta
+                .topics()
+                .iter()
+                .map(|topic| topic.name())
+                .filter(|name| pattern.is_match(name))
+                .map(String::from)
+                .collect())
+        }
+    }
+}
+
 /// An update produced by the metadata fetcher.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 enum MetadataUpdate {
     /// The current IDs and high watermarks of all topic partitions.
-    Partitions(BTreeMap<PartitionId, PartitionWatermark>),
+    Partitions {
+        /// The names of the topics by topic id.
+        topics: BTreeMap<u32, String>,
+        /// The high watermarks by partition key.
+        partitions: BTreeMap<PartitionKey, PartitionWatermark>,
+    },
     /// A transient error.
     ///
     /// Transient errors stall the source until their cause has been resolved.
@@ -1680,23 +1857,12 @@ impl MetadataUpdate {
     /// Return the upstream frontier resulting from the metadata update, if any.
     fn upstream_frontier(&self) -> Option<Antichain<KafkaTimestamp>> {
         match self {
-            Self::Partitions(partitions) => {
-                let max_pid = partitions.keys().last().copied();
-                let lower = max_pid
-                    .map(RangeBound::after)
-                    .unwrap_or(RangeBound::NegInfinity);
-                let future_ts =
-                    Partitioned::new_range(lower, RangeBound::PosInfinity, MzOffset::from(0));
-
-                let mut frontier = Antichain::from_elem(future_ts);
-                for (pid, high_watermark) in partitions {
-                    frontier.insert(Partitioned::new_singleton(
-                        RangeBound::exact(*pid),
-                        MzOffset::from(*high_watermark),
-                    ));
-                }
-
-                Some(frontier)
+            Self::Partitions { partitions, .. } => {
+                let offsets = partitions
+                    .iter()
+                    .map(|(key, high_watermark)| (*key, MzOffset::from(*high_watermark)))
+                    .collect();
+                Some(partitions_frontier(&offsets))
             }
             Self::DefiniteError(_) => Some(Antichain::new()),
             Self::TransientError(_) => None,
@@ -1760,7 +1926,7 @@ fn render_metadata_fetcher<'scope>(
         );
         let KafkaSourceConnection {
             connection,
-            topic,
+            topics,
             topic_metadata_refresh_interval,
             ..
         } = connection;
@@ -1809,7 +1975,7 @@ fn render_metadata_fetcher<'scope>(
         };
 
         let (tx, mut rx) = mpsc::unbounded_channel();
-        spawn_metadata_thread(config, consumer, topic, tx);
+        spawn_metadata_thread(config, consumer, topics, tx);
 
         let mut prev_upstream_frontier = resume_upper;
 
@@ -1853,10 +2019,92 @@ fn render_metadata_fetcher<'scope>(
     (metadata_stream, probe_stream, button.press_on_drop())
 }
 
+/// Fetches the partitions and high watermarks of all topics the source reads from.
+///
+/// `known_topics` holds the topics seen by previous calls. A topic that stops matching a
+/// `TOPIC PATTERN` must have been deleted, which is a definite error just like the deletion of a
+/// named topic.
+fn fetch_metadata_update<C: ConsumerContext>(
+    consumer: &BaseConsumer<TunnelingClientContext<C>>,
+    topics: &KafkaTopics,
+    known_topics: &mut BTreeSet<String>,
+    fetch_timeout: Duration,
+) -> MetadataUpdate {
+    let transient_error = |e: GetPartitionsError| {
+        let kafka_status = Some(HealthStatusUpdate::stalled(
+            format!("{}", e.display_with_causes()),
+            None,
+        ));
+
+        let ssh_status = consumer.client().context().tunnel_status();
+        let ssh_status = match ssh_status {
+            SshTunnelStatus::Running => Some(HealthStatusUpdate::running()),
+            SshTunnelStatus::Errored(e) => Some(HealthStatusUpdate::stalled(e, None)),
+        };
+
+        MetadataUpdate::TransientError(HealthStatus {
+            kafka: kafka_status,
+            ssh: ssh_status,
+        })
+    };
+    let topic_deleted = |topic: &str| {
+        let error = match topics {
+            KafkaTopics::Topic(_) => "topic was deleted".to_string(),
+            KafkaTopics::List(_) | KafkaTopics::Pattern(_) => format!("topic {topic} was deleted"),
+        };
+        MetadataUpdate::DefiniteError(SourceError {
+            error: SourceErrorDetails::Other(error.into()),
+        })
+    };
+
+    let names = match fetch_topic_names(consumer, topics, fetch_timeout) {
+        Ok(names) => names,
+        Err(e) => return transient_error(e),
+    };
+    if let Some(deleted) = known_topics.iter().find(|topic| !names.contains(*topic)) {
+        return topic_deleted(deleted);
+    }
+    if let Some((a, b)) = topics.find_topic_id_collision(names.iter().map(String::as_str)) {
+        let error = SourceError {
+            error: SourceErrorDetails::Other(
+                format!("topics {a} and {b} cannot be told apart by the source").into(),
+            ),
+        };
+        return MetadataUpdate::DefiniteError(error);
+    }
+
+    let mut topic_names = BTreeMap::new();
+    let mut partitions = BTreeMap::new();
+    for name in names {
+        match fetch_partition_info(consumer, &name, fetch_timeout, Offset::End) {
+            Ok(watermarks) => {
+                for (pid, high_watermark) in watermarks {
+                    partitions.insert(topics.partition_key(&name, pid), high_watermark);
+                }
+            }
+            // A pattern-matched topic that was deleted before we ever read from it.
+            Err(GetPartitionsError::TopicDoesNotExist)
+                if matches!(topics, KafkaTopics::Pattern(_)) && !known_topics.contains(&name) =>
+            {
+                continue;
+            }
+            Err(GetPartitionsError::TopicDoesNotExist) => return topic_deleted(&name),
+            Err(e) => return transient_error(e),
+        }
+        topic_names.insert(topics.topic_id(&name), name);
+    }
+    known_topics.extend(topic_names.values().cloned());
+
+    MetadataUpdate::Partitions {
+        topics: topic_names,
+        partitions,
+    }
+}
+
 fn spawn_metadata_thread<C: ConsumerContext>(
     config: RawSourceCreationConfig,
     consumer: BaseConsumer<TunnelingClientContext<C>>,
-    topic: String,
+    topics: KafkaTopics,
     tx: mpsc::UnboundedSender<(mz_repr::Timestamp, MetadataUpdate)>,
 ) {
     // Linux thread names are limited to 15 characters. Use a truncated ID to fit the name.
@@ -1872,63 +2120,27 @@ fn spawn_metadata_thread<C: ConsumerContext>(
 
             let timestamp_interval = config.timestamp_interval;
             let mut ticker = probe::Ticker::new(move || timestamp_interval, config.now_fn);
+            let mut known_topics = BTreeSet::new();
 
             loop {
                 let probe_ts = ticker.tick_blocking();
-                let result = fetch_partition_info(
+                let update = fetch_metadata_update(
                     &consumer,
-                    &topic,
+                    &topics,
+                    &mut known_topics,
                     config
                         .config
                         .parameters
                         .kafka_timeout_config
                         .fetch_metadata_timeout,
-                    Offset::End,
                 );
                 trace!(
                     source_id = config.id.to_string(),
                     worker_id = config.worker_id,
                     num_workers = config.worker_count,
                     "kafka metadata thread: metadata fetch result: {:?}",
-                    result
+                    update
                 );
-                let update = match result {
-                    Ok(partitions) => {
-                        trace!(
-                            source_id = config.id.to_string(),
-                            worker_id = config.worker_id,
-                            num_workers = config.worker_count,
-                            "kafka metadata thread: fetched partition metadata info",
-                        );
-
-                        MetadataUpdate::Partitions(partitions)
-                    }
-                    Err(GetPartitionsError::TopicDoesNotExist) => {
-                        let error = SourceError {
-                            error: SourceErrorDetails::Other("topic was deleted".into()),
-                        };
-                        MetadataUpdate::DefiniteError(error)
-                    }
-                    Err(e) => {
-                        let kafka_status = Some(HealthStatusUpdate::stalled(
-                            format!("{}", e.display_with_causes()),
-                            None,
-                        ));
-
-                        let ssh_status = consumer.client().context().tunnel_status();
-                        let ssh_status = match ssh_status {
-                            SshTunnelStatus::Running => Some(HealthStatusUpdate::running()),
-                            SshTunnelStatus::Errored(e) => {
-                                Some(HealthStatusUpdate::stalled(e, None))
-                            }
-                        };
-
-                        MetadataUpdate::TransientError(HealthStatus {
-                            kafka: kafka_status,
-                            ssh: ssh_status,
-                        })
-                    }
-                };
 
                 if tx.send((probe_ts, update)).is_err() {
                     break;
@@ -80,7 +80,9 @@ use mz_mysql_util::{
 use mz_ore::error::ErrorExt;
 use mz_storage_types::errors::SourceErrorDetails;
 use mz_storage_types::sources::mysql::{GtidPartition, GtidState, gtid_set_frontier};
-use mz_storage_types::sources::{MySqlSourceConnection, SourceExportDetails, SourceTimestamp};
+use mz_storage_types::sources::{
+    MySqlSourceConnection, SchemaChangeBehavior, SourceExportDetails, SourceTimestamp,
+};
 use mz_timely_util::builder_async::{AsyncOutputHandle, PressOnDropButton};
 use mz_timely_util::order::Extrema;
 
@@ -152,6 +154,7 @@ impl SourceRender for MySqlSourceConnection {
                 resume_upper,
                 export_id: id.clone(),
                 binlog_full_metadata: details.binlog_full_metadata,
+                schema_change_behavior: details.schema_change_behavior,
             });
         }
 
@@ -217,11 +220,32 @@ impl SourceRender for MySqlSourceConnection {
         let health_errs = snapshot_err
             .concat(repl_err)
             .concat(stats_err)
-            .map(move |err| {
+            .flat_map(move |err| {
                 // This update will cause the dataflow to restart
                 let err_string = err.display_with_causes().to_string();
                 let update = HealthStatusUpdate::halting(err_string.clone(), None);
 
+                // Exports that wait for columns to be added to them are stalled until the
+                // controller has evolved their schema. Only the source as a whole may halt.
+                let pending_exports = match &err {
+                    ReplicationError::Transient(err) => match &**err {
+                        TransientError::PendingSchemaEvolution { exports, .. } => exports.clone(),
+                        _ => vec![],
+                    },
+                    ReplicationError::Definite(_) => vec![],
+                };
+                let mut updates: Vec<_> = pending_exports
+                    .into_iter()
+                    .map(|id| HealthStatusMessage {
+                        id: Some(id),
+                        namespace: StatusNamespace::SchemaEvolution,
+                        update: HealthStatusUpdate::stalled(
+                            err_string.clone(),
+                            Some("the columns are added to this table automatically".into()),
+                        ),
+                    })
+                    .collect();
+
                 let namespace = match err {
                     ReplicationError::Transient(err)
                         if matches!(&*err, TransientError::MySqlError(MySqlError::Ssh(_))) =>
@@ -231,11 +255,12 @@ impl SourceRender for MySqlSourceConnection {
                     _ => Self::STATUS_NAMESPACE,
                 };
 
-                HealthStatusMessage {
+                updates.push(HealthStatusMessage {
                     id: None,
                     namespace: namespace.clone(),
                     update,
-                }
+                });
+                updates
             });
         let health = health_init.concat(health_errs);
 
@@ -259,6 +284,7 @@ struct SourceOutputInfo {
     resume_upper: Antichain<GtidPartition>,
     export_id: GlobalId,
     binlog_full_metadata: bool,
+    schema_change_behavior: SchemaChangeBehavior,
 }
 
 #[derive(Clone, Debug, thiserror::Error)]
@@ -282,6 +308,16 @@ pub enum TransientError {
     SQLClient(#[from] mysql_async::Error),
     #[error("ident decode error")]
     IdentError(#[from] mz_sql_parser::ast::IdentError),
+    #[error(
+        "source table {table} added columns {}; waiting for the columns to be added to its export",
+        .columns.join(", ")
+    )]
+    PendingSchemaEvolution {
+        table: String,
+        columns: Vec<String>,
+        /// The exports that are waiting for the columns to be added to them.
+        exports: Vec<GlobalId>,
+    },
     #[error(transparent)]
     MySqlError(#[from] MySqlError),
     #[error(transparent)]
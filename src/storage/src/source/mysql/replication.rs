@@ -78,6 +78,7 @@ use crate::metrics::source::mysql::MySqlSourceMetrics;
 use crate::source::RawSourceCreationConfig;
 use crate::source::types::{SignaledFuture, SourceMessage, StackedCollection};
 
+use super::schemas::refresh_enum_values;
 use super::{
     DefiniteError, ReplicationError, RewindRequest, SourceOutputInfo, TransientError,
     return_definite_error, validate_mysql_repl_settings,
@@ -283,6 +284,10 @@ pub(crate) fn render<'scope>(
             }
             trace!(%id, "timely-{worker_id} pending rewinds {rewinds:?}");
 
+            // Values may have been appended to enum columns while we weren't running, which
+            // the binlog only refers to by their index.
+            refresh_enum_values(&mut conn, &mut table_info).await?;
+
             // We don't use _conn_tunnel_handle here, but need to keep it around to ensure that the
             // SSH tunnel is not dropped until the replication stream is dropped.
             let (binlog_stream, _conn_tunnel_handle) =
@@ -315,7 +320,7 @@ pub(crate) fn render<'scope>(
                 &config,
                 &connection_config,
                 stream.as_mut(),
-                &table_info,
+                &mut table_info,
                 &metrics,
                 &mut data_output,
                 data_cap_set,
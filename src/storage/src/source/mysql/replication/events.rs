@@ -20,6 +20,7 @@ use mz_mysql_util::{MySqlError, pack_mysql_row};
 use mz_ore::iter::IteratorExt;
 use mz_repr::{Diff, Row};
 use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sources::SchemaChangeBehavior;
 use mz_storage_types::sources::mysql::GtidPartition;
 use timely::progress::Timestamp;
 use tracing::trace;
@@ -27,7 +28,7 @@ use tracing::trace;
 use crate::source::mysql::SourceOutputInfo;
 use crate::source::types::{FuelSize, SourceMessage};
 
-use super::super::schemas::verify_schemas;
+use super::super::schemas::{evolve_schemas, verify_schemas};
 use super::super::{DefiniteError, MySqlTableName, TransientError};
 use super::context::ReplContext;
 
@@ -155,6 +156,19 @@ pub(super) async fn handle_query_event(
                     ctx.data_output.give_fueled(&gtid_cap, update, size).await;
                     ctx.errored_outputs.insert(err_output.output_index);
                 }
+
+                // Outputs that evolve their schema wait for the columns that were added to
+                // the table to be added to their export before replicating past this event.
+                let outputs = ctx.table_info.get_mut(&table).expect("known to exist");
+                let (columns, exports) =
+                    evolve_schemas(&mut *conn, &table, outputs, &ctx.errored_outputs).await?;
+                if !exports.is_empty() {
+                    return Err(TransientError::PendingSchemaEvolution {
+                        table: table.to_string(),
+                        columns: columns.into_iter().collect(),
+                        exports,
+                    });
+                }
             }
         }
         // Detect `DROP TABLE [IF EXISTS] <tbl>, <tbl>` statements.
@@ -401,6 +415,7 @@ pub(super) async fn handle_rows_event(
                         &output.desc,
                         Some(&gtid_str),
                         output.binlog_full_metadata,
+                        output.schema_change_behavior == SchemaChangeBehavior::Evolve,
                     ) {
                         Ok(row) => Ok(SourceMessage {
                             key: Row::default(),
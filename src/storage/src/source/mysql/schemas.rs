@@ -10,7 +10,9 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use mysql_async::prelude::Queryable;
-use mz_mysql_util::{MySqlError, SchemaRequest, schema_info};
+use mz_mysql_util::{MySqlError, MySqlTableDesc, MySqlTableSchema, SchemaRequest, schema_info};
+use mz_repr::GlobalId;
+use mz_storage_types::sources::SchemaChangeBehavior;
 
 use super::{DefiniteError, MySqlTableName, SourceOutputInfo};
 
@@ -26,24 +28,7 @@ where
     Q: Queryable,
     I: IntoIterator<Item = &'a SourceOutputInfo>,
 {
-    let cur_schemas: BTreeMap<_, _> = schema_info(
-        conn,
-        &SchemaRequest::Tables(
-            expected
-                .iter()
-                .map(|(f, _)| (f.0.as_str(), f.1.as_str()))
-                .collect(),
-        ),
-    )
-    .await?
-    .into_iter()
-    .map(|schema| {
-        (
-            MySqlTableName::new(&schema.schema_name, &schema.name),
-            schema,
-        )
-    })
-    .collect();
+    let cur_schemas = current_schemas(conn, expected.keys().copied()).await?;
 
     Ok(expected
         .into_iter()
@@ -54,34 +39,163 @@ where
                 .into_iter()
                 .filter_map(|output| match &cur_schemas.get(table) {
                     None => Some((output, DefiniteError::TableDropped(table.to_string()))),
-                    Some(schema) => {
-                        let new_desc = (*schema).clone().to_desc(
-                            Some(&BTreeSet::from_iter(
-                                output.text_columns.iter().map(|s| s.as_str()),
-                            )),
-                            Some(&BTreeSet::from_iter(
-                                output.exclude_columns.iter().map(|s| s.as_str()),
-                            )),
-                        );
-                        match new_desc {
-                            Ok(desc) => {
-                                match output
-                                    .desc
-                                    .determine_compatibility(&desc, output.binlog_full_metadata)
-                                {
-                                    Ok(()) => None,
-                                    Err(err) => Some((
-                                        output,
-                                        DefiniteError::IncompatibleSchema(err.to_string()),
-                                    )),
-                                }
-                            }
-                            Err(err) => {
-                                Some((output, DefiniteError::IncompatibleSchema(err.to_string())))
+                    Some(schema) => match upstream_desc(schema, output) {
+                        Ok(desc) => {
+                            match output
+                                .desc
+                                .determine_compatibility(&desc, output.binlog_full_metadata)
+                            {
+                                Ok(()) => None,
+                                Err(err) => Some((
+                                    output,
+                                    DefiniteError::IncompatibleSchema(err.to_string()),
+                                )),
                             }
                         }
-                    }
+                        Err(err) => {
+                            Some((output, DefiniteError::IncompatibleSchema(err.to_string())))
+                        }
+                    },
                 })
         })
         .collect())
 }
+
+/// Evolves the schemas of the `outputs` of `table` that evolve their schema along with
+/// the current schema of the table, skipping the outputs in `errored`.
+///
+/// The values that were appended to the enum columns of the table are taken over right
+/// away. Returns the names of the columns that were added to the table and the outputs
+/// that wait for them to be added to their export.
+pub(super) async fn evolve_schemas<Q>(
+    conn: &mut Q,
+    table: &MySqlTableName,
+    outputs: &mut [SourceOutputInfo],
+    errored: &BTreeSet<usize>,
+) -> Result<(BTreeSet<String>, Vec<GlobalId>), MySqlError>
+where
+    Q: Queryable,
+{
+    let cur_schemas = current_schemas(conn, [table]).await?;
+    let Some(schema) = cur_schemas.get(table) else {
+        // Dropped tables are reported by `verify_schemas`.
+        return Ok((BTreeSet::new(), vec![]));
+    };
+
+    let mut added_columns = BTreeSet::new();
+    let mut pending = vec![];
+    for output in outputs {
+        if output.schema_change_behavior != SchemaChangeBehavior::Evolve
+            || errored.contains(&output.output_index)
+        {
+            continue;
+        }
+        if let Ok(desc) = upstream_desc(schema, output) {
+            output.desc.update_enum_values(&desc);
+        }
+        let added = schema.added_columns(&output.desc);
+        if !added.is_empty() {
+            added_columns.extend(added.into_iter().map(|c| c.desc.name));
+            pending.push(output.export_id);
+        }
+    }
+    Ok((added_columns, pending))
+}
+
+/// Takes over the values that were appended to the enum columns of the upstream tables of
+/// the outputs that evolve their schema.
+///
+/// Values that were appended while the source was not running would otherwise fail to
+/// decode when the source resumes replication.
+pub(super) async fn refresh_enum_values<Q>(
+    conn: &mut Q,
+    table_info: &mut BTreeMap<MySqlTableName, Vec<SourceOutputInfo>>,
+) -> Result<(), MySqlError>
+where
+    Q: Queryable,
+{
+    let evolving =
+        |output: &SourceOutputInfo| output.schema_change_behavior == SchemaChangeBehavior::Evolve;
+    let tables = table_info
+        .iter()
+        .filter(|(_, outputs)| outputs.iter().any(evolving))
+        .map(|(table, _)| table);
+    let cur_schemas = current_schemas(conn, tables).await?;
+
+    for (table, schema) in cur_schemas {
+        let Some(outputs) = table_info.get_mut(&table) else {
+            continue;
+        };
+        for output in outputs.iter_mut().filter(|output| evolving(output)) {
+            if let Ok(desc) = upstream_desc(&schema, output) {
+                output.desc.update_enum_values(&desc);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Retrieves the current schemas of `tables`.
+async fn current_schemas<'a, Q>(
+    conn: &mut Q,
+    tables: impl IntoIterator<Item = &'a MySqlTableName>,
+) -> Result<BTreeMap<MySqlTableName, MySqlTableSchema>, MySqlError>
+where
+    Q: Queryable,
+{
+    Ok(schema_info(
+        conn,
+        &SchemaRequest::Tables(
+            tables
+                .into_iter()
+                .map(|f| (f.0.as_str(), f.1.as_str()))
+                .collect(),
+        ),
+    )
+    .await?
+    .into_iter()
+    .map(|schema| {
+        (
+            MySqlTableName::new(&schema.schema_name, &schema.name),
+            schema,
+        )
+    })
+    .collect())
+}
+
+/// Converts the current `schema` of the upstream table of `output` into the description
+/// that `output` expects.
+///
+/// Outputs that evolve their schema ingest the columns that were added upstream as text if
+/// their type can't be ingested natively, and ingest `varchar` columns without a length
+/// limit.
+fn upstream_desc(
+    schema: &MySqlTableSchema,
+    output: &SourceOutputInfo,
+) -> Result<MySqlTableDesc, MySqlError> {
+    let evolve = output.schema_change_behavior == SchemaChangeBehavior::Evolve;
+    let added_columns = if evolve {
+        schema.added_columns(&output.desc)
+    } else {
+        vec![]
+    };
+    let text_columns = output
+        .text_columns
+        .iter()
+        .map(|s| s.as_str())
+        .chain(
+            added_columns
+                .iter()
+                .filter(|c| c.text)
+                .map(|c| c.desc.name.as_str()),
+        )
+        .collect();
+    let exclude_columns = output.exclude_columns.iter().map(|s| s.as_str()).collect();
+    let mut desc = schema
+        .clone()
+        .to_desc(Some(&text_columns), Some(&exclude_columns))?;
+    if evolve {
+        desc.remove_varchar_limits();
+    }
+    Ok(desc)
+}
@@ -1033,6 +1033,7 @@ pub(crate) fn render<'scope>(
                                 &output.desc,
                                 None,
                                 output.binlog_full_metadata,
+                                false,
                             ) {
                                 Ok(row) => Ok(SourceMessage {
                                     key: Row::default(),
@@ -1275,6 +1276,7 @@ where
 mod tests {
     use super::*;
     use mz_mysql_util::{MySqlColumnDesc, MySqlTableDesc};
+    use mz_storage_types::sources::SchemaChangeBehavior;
     use timely::progress::Antichain;
 
     #[mz_ore::test]
@@ -1306,6 +1308,7 @@ mod tests {
             resume_upper: Antichain::default(),
             export_id: mz_repr::GlobalId::User(1),
             binlog_full_metadata: false,
+            schema_change_behavior: SchemaChangeBehavior::Ignore,
         };
         let query = build_snapshot_query(&[info.clone(), info], None);
         assert_eq!(
@@ -1346,6 +1349,7 @@ mod tests {
             resume_upper: Antichain::default(),
             export_id: mz_repr::GlobalId::User(1),
             binlog_full_metadata: false,
+            schema_change_behavior: SchemaChangeBehavior::Ignore,
         };
 
         // Middle worker: both bounds.
@@ -31,6 +31,15 @@
 //!
 //! See the [replication] module for more information on the replication strategy.
 //!
+//! # Truncation
+//!
+//! A `TRUNCATE` of an upstream table normally puts the corresponding source exports in a definite
+//! error state. Exports with [`PostgresTruncateBehavior::Retract`] instead receive a truncation
+//! marker at the LSN of the `TRUNCATE`, which is replaced by the retraction of the export's
+//! contents after reclocking.
+//!
+//! See the [truncate] module for more information on how the retractions are produced.
+//!
 //! # Error handling
 //!
 //! There are two kinds of errors that can happen during ingestion that are represented as two
@@ -82,6 +91,7 @@
 
 use std::collections::BTreeMap;
 use std::rc::Rc;
+use std::sync::LazyLock;
 use std::time::Duration;
 
 use differential_dataflow::AsCollection;
@@ -94,9 +104,10 @@ use mz_postgres_util::{Client, PostgresError, Sql, query_opt, simple_query_opt,
 use mz_repr::{Datum, Diff, GlobalId, Row};
 use mz_storage_types::errors::{DataflowError, SourceError, SourceErrorDetails};
 use mz_storage_types::sources::casts::StorageScalarExpr;
-use mz_storage_types::sources::postgres::CastType;
+use mz_storage_types::sources::postgres::{CastType, PostgresTruncateBehavior};
 use mz_storage_types::sources::{
-    MzOffset, PostgresSourceConnection, SourceExport, SourceExportDetails, SourceTimestamp,
+    MzOffset, PostgresSourceConnection, SchemaChangeBehavior, SourceExport, SourceExportDetails,
+    SourceTimestamp,
 };
 use mz_timely_util::builder_async::PressOnDropButton;
 use serde::{Deserialize, Serialize};
@@ -115,6 +126,9 @@ use crate::source::{RawSourceCreationConfig, SourceMessage};
 
 mod replication;
 mod snapshot;
+mod truncate;
+
+pub(crate) use truncate::render as render_truncate_retractions;
 
 impl SourceRender for PostgresSourceConnection {
     type Time = MzOffset;
@@ -168,6 +182,9 @@ impl SourceRender for PostgresSourceConnection {
                 casts,
                 resume_upper,
                 export_id: id.clone(),
+                truncate_behavior: details.truncate_behavior,
+                schema_change_behavior: details.schema_change_behavior,
+                exclude_columns: details.exclude_columns.clone(),
             };
             table_info
                 .entry(output.desc.oid)
@@ -232,11 +249,32 @@ impl SourceRender for PostgresSourceConnection {
         // N.B. Note that we don't check ssh tunnel statuses here. We could, but immediately on
         // restart we are going to set the status to an ssh error correctly, so we don't do this
         // extra work.
-        let errs = snapshot_err.concat(repl_err).map(move |err| {
+        let errs = snapshot_err.concat(repl_err).flat_map(move |err| {
             // This update will cause the dataflow to restart
             let err_string = err.display_with_causes().to_string();
             let update = HealthStatusUpdate::halting(err_string.clone(), None);
 
+            // Exports that wait for columns to be added to them are stalled until the
+            // controller has evolved their schema. Only the source as a whole may halt.
+            let pending_exports = match &err {
+                ReplicationError::Transient(err) => match &**err {
+                    TransientError::PendingSchemaEvolution { exports, .. } => exports.clone(),
+                    _ => vec![],
+                },
+                ReplicationError::Definite(_) => vec![],
+            };
+            let mut updates: Vec<_> = pending_exports
+                .into_iter()
+                .map(|id| HealthStatusMessage {
+                    id: Some(id),
+                    namespace: StatusNamespace::SchemaEvolution,
+                    update: HealthStatusUpdate::stalled(
+                        err_string.clone(),
+                        Some("the columns are added to this table automatically".into()),
+                    ),
+                })
+                .collect();
+
             let namespace = match err {
                 ReplicationError::Transient(err)
                     if matches!(
@@ -250,11 +288,12 @@ impl SourceRender for PostgresSourceConnection {
                 _ => Self::STATUS_NAMESPACE,
             };
 
-            HealthStatusMessage {
+            updates.push(HealthStatusMessage {
                 id: None,
                 namespace: namespace.clone(),
                 update,
-            }
+            });
+            updates
         });
 
         let health = health_init.concat(errs);
@@ -274,11 +313,37 @@ struct SourceOutputInfo {
     desc: PostgresTableDesc,
     /// A projection of the upstream columns into the columns expected by this output. This field
     /// is recalculated every time we observe an upstream schema change. On dataflow initialization
-    /// this field is None since we haven't yet observed any schemas.
-    projection: Option<Vec<usize>>,
+    /// this field is None since we haven't yet observed any schemas. Columns that the upstream
+    /// schema does not have yet, because they were added to the table later, project to `None`.
+    projection: Option<Vec<Option<usize>>>,
     casts: Vec<(CastType, StorageScalarExpr)>,
     resume_upper: Antichain<MzOffset>,
     export_id: GlobalId,
+    /// How to handle a `TRUNCATE` of the upstream table.
+    truncate_behavior: PostgresTruncateBehavior,
+    /// How to handle columns added to the upstream table.
+    schema_change_behavior: SchemaChangeBehavior,
+    /// The upstream columns that are excluded from this output.
+    exclude_columns: Vec<String>,
+}
+
+/// The key of truncation markers. The messages of PostgreSQL sources otherwise always have an
+/// empty key.
+static TRUNCATE_MARKER_KEY: LazyLock<Row> =
+    LazyLock::new(|| Row::pack_slice(&[Datum::String("truncate")]));
+
+/// Returns a message that marks the truncation of the upstream table at its LSN.
+fn truncate_marker() -> SourceMessage {
+    SourceMessage {
+        key: TRUNCATE_MARKER_KEY.clone(),
+        value: Row::default(),
+        metadata: Row::default(),
+    }
+}
+
+/// Reports whether a message with the given key is a truncation marker.
+pub(crate) fn is_truncate_marker(key: &Row) -> bool {
+    *key == *TRUNCATE_MARKER_KEY
 }
 
 #[derive(Clone, Debug, thiserror::Error)]
@@ -317,6 +382,16 @@ pub enum TransientError {
     NestedTransaction,
     #[error("recoverable errors should crash the process during snapshots")]
     SyntheticError,
+    #[error(
+        "source table {table} added columns {}; waiting for the columns to be added to its export",
+        .columns.join(", ")
+    )]
+    PendingSchemaEvolution {
+        table: String,
+        columns: Vec<String>,
+        /// The exports that are waiting for the columns to be added to them.
+        exports: Vec<GlobalId>,
+    },
     #[error("sql client error")]
     SQLClient(#[from] tokio_postgres::Error),
     #[error(transparent)]
@@ -332,6 +407,10 @@ pub enum DefiniteError {
     SlotCompactedPastResumePoint(MzOffset, MzOffset),
     #[error("table was truncated")]
     TableTruncated,
+    #[error(
+        "table was truncated in a transaction that wrote more than {0} bytes to it before the TRUNCATE"
+    )]
+    TruncatedLargeTransaction(usize),
     #[error("table was dropped")]
     TableDropped,
     #[error("publication {0:?} does not exist")]
@@ -376,6 +455,7 @@ impl From<DefiniteError> for DataflowError {
             error: match &err {
                 DefiniteError::SlotCompactedPastResumePoint(_, _) => SourceErrorDetails::Other(m),
                 DefiniteError::TableTruncated => SourceErrorDetails::Other(m),
+                DefiniteError::TruncatedLargeTransaction(_) => SourceErrorDetails::Other(m),
                 DefiniteError::TableDropped => SourceErrorDetails::Other(m),
                 DefiniteError::PublicationDropped(_) => SourceErrorDetails::Initialization(m),
                 DefiniteError::InvalidReplicationSlot => SourceErrorDetails::Initialization(m),
@@ -481,10 +561,23 @@ fn verify_schema(
         })
         .collect();
 
-    match info
+    // Tables that evolve their schema ingest `varchar` columns without a length limit, so the
+    // limit may change upstream.
+    let allow_type_mods_to_change_by_col_num = info
         .desc
-        .determine_compatibility(current_desc, &allow_oids_to_change_by_col_num)
-    {
+        .columns
+        .iter()
+        .filter(|col| {
+            info.schema_change_behavior == SchemaChangeBehavior::Evolve && col.is_varchar()
+        })
+        .map(|col| col.col_num)
+        .collect();
+
+    match info.desc.determine_compatibility(
+        current_desc,
+        &allow_oids_to_change_by_col_num,
+        &allow_type_mods_to_change_by_col_num,
+    ) {
         Ok(()) => Ok(()),
         Err(err) => Err(DefiniteError::IncompatibleSchema(err.to_string())),
     }
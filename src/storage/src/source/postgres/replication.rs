@@ -69,7 +69,7 @@
 //! [1]: https://www.postgresql.org/docs/15/protocol-replication.html#PROTOCOL-REPLICATION-START-REPLICATION
 //! [2]: https://www.postgresql.org/message-id/CAFPTHDZS9O9WG02EfayBd6oONzK%2BqfUxS6AbVLJ7W%2BKECza2gg%40mail.gmail.com
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::convert::Infallible;
 use std::pin::pin;
 use std::rc::Rc;
@@ -88,9 +88,11 @@ use mz_postgres_util::PostgresError;
 use mz_postgres_util::{Client, Sql, execute, query_opt, simple_query_opt, sql};
 use mz_repr::{Datum, DatumVec, Diff, Row};
 use mz_storage_types::dyncfgs::PG_SCHEMA_VALIDATION_INTERVAL;
+use mz_storage_types::dyncfgs::PG_SOURCE_TRUNCATE_RETRACT_MAX_TX_BYTES;
 use mz_storage_types::dyncfgs::PG_SOURCE_VALIDATE_TIMELINE;
 use mz_storage_types::errors::DataflowError;
-use mz_storage_types::sources::{MzOffset, PostgresSourceConnection};
+use mz_storage_types::sources::postgres::PostgresTruncateBehavior;
+use mz_storage_types::sources::{MzOffset, PostgresSourceConnection, SchemaChangeBehavior};
 use mz_timely_util::builder_async::{
     AsyncOutputHandle, Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder,
     PressOnDropButton,
@@ -442,6 +444,13 @@ pub(crate) fn render<'scope>(
             // creating excessive progress tracking traffic when there are multiple small
             // transactions ready to go.
             let mut data_upper = resume_lsn;
+            // The outputs that retract their contents when the upstream table is truncated.
+            let retract_outputs: BTreeSet<usize> = table_info
+                .values()
+                .flatten()
+                .filter(|(_, info)| info.truncate_behavior == PostgresTruncateBehavior::Retract)
+                .map(|(output_index, _)| *output_index)
+                .collect();
             while let Some(event) = stream.as_mut().next().await {
                 use LogicalReplicationMessage::*;
                 use ReplicationMessage::*;
@@ -469,24 +478,71 @@ pub(crate) fn render<'scope>(
                                 "new_upper={data_upper} tx_lsn={commit_lsn}",
                             );
                             data_upper = commit_lsn + 1;
+                            // The rows that the transaction emitted so far for outputs that
+                            // retract their contents on truncation, along with their size. The
+                            // rows of an output are no longer buffered once they exceed
+                            // `max_tx_bytes`, and a later truncation of the output is an error.
+                            let max_tx_bytes = PG_SOURCE_TRUNCATE_RETRACT_MAX_TX_BYTES
+                                .get(config.config.config_set());
+                            let mut tx_rows: BTreeMap<usize, (usize, Vec<_>)> = BTreeMap::new();
+                            let mut overflowed: BTreeSet<usize> = BTreeSet::new();
                             while let Some((oid, output_index, event, diff)) = tx.try_next().await?
                             {
+                                let event = match event {
+                                    Err(DefiniteError::TableTruncated)
+                                        if overflowed.remove(&output_index) =>
+                                    {
+                                        Err(DefiniteError::TruncatedLargeTransaction(max_tx_bytes))
+                                    }
+                                    event => event,
+                                };
+                                let truncated = matches!(event, Err(DefiniteError::TableTruncated))
+                                    && retract_outputs.contains(&output_index);
                                 let event = event.map_err(Into::into);
                                 let data = (oid, output_index, event);
-                                if let Some(req) = rewinds.get(&output_index) {
-                                    if commit_lsn <= req.snapshot_lsn {
-                                        let update = (data.clone(), MzOffset::from(0), -diff);
-                                        let size = update.fuel_size();
-                                        data_output
-                                            .give_fueled(&data_cap_set[0], update, size)
-                                            .await;
+
+                                let mut updates = vec![];
+                                if truncated {
+                                    // Rows of the transaction end up at the same LSN as the
+                                    // truncation marker so they must be retracted here. Everything
+                                    // before is retracted after reclocking.
+                                    let rows = tx_rows.remove(&output_index).into_iter();
+                                    let rows = rows.flat_map(|(_, rows)| rows);
+                                    updates.extend(rows.map(|(data, diff)| (data, -diff)));
+                                } else if retract_outputs.contains(&output_index)
+                                    && data.2.is_ok()
+                                    && !overflowed.contains(&output_index)
+                                {
+                                    let (bytes, rows) = tx_rows.entry(output_index).or_default();
+                                    *bytes += data.fuel_size();
+                                    if *bytes <= max_tx_bytes {
+                                        rows.push((data.clone(), diff));
+                                    } else {
+                                        tx_rows.remove(&output_index);
+                                        overflowed.insert(output_index);
                                     }
                                 }
-                                let update = (data, commit_lsn, diff);
-                                let size = update.fuel_size();
-                                data_output
-                                    .give_fueled(&data_cap_set[0], update, size)
-                                    .await;
+                                updates.push((data, diff));
+
+                                for (data, diff) in updates {
+                                    // The truncation marker is not part of the data so it is
+                                    // never rewound.
+                                    let is_marker = truncated && data.2.is_err();
+                                    if let Some(req) = rewinds.get(&output_index) {
+                                        if commit_lsn <= req.snapshot_lsn && !is_marker {
+                                            let update = (data.clone(), MzOffset::from(0), -diff);
+                                            let size = update.fuel_size();
+                                            data_output
+                                                .give_fueled(&data_cap_set[0], update, size)
+                                                .await;
+                                        }
+                                    }
+                                    let update = (data, commit_lsn, diff);
+                                    let size = update.fuel_size();
+                                    data_output
+                                        .give_fueled(&data_cap_set[0], update, size)
+                                        .await;
+                                }
                             }
                         }
                         _ => return Err(TransientError::BareTransactionEvent),
@@ -624,15 +680,24 @@ pub(crate) fn render<'scope>(
                             .get(&oid)
                             .and_then(|outputs| outputs.get(&output_index))
                             .expect("table_info contains all outputs");
-                        let event = event.and_then(|row| {
-                            let datums = datum_vec.borrow_with(&row);
-                            super::cast_row(&output.casts, &datums, &mut final_row)?;
-                            Ok(SourceMessage {
-                                key: Row::default(),
-                                value: final_row.clone(),
-                                metadata: Row::default(),
+                        let is_marker = output.truncate_behavior
+                            == PostgresTruncateBehavior::Retract
+                            && event.as_ref().is_err_and(|err| {
+                                *err == DataflowError::from(DefiniteError::TableTruncated)
+                            });
+                        let event = if is_marker {
+                            Ok(super::truncate_marker())
+                        } else {
+                            event.and_then(|row| {
+                                let datums = datum_vec.borrow_with(&row);
+                                super::cast_row(&output.casts, &datums, &mut final_row)?;
+                                Ok(SourceMessage {
+                                    key: Row::default(),
+                                    value: final_row.clone(),
+                                    metadata: Row::default(),
+                                })
                             })
-                        });
+                        };
 
                         session.give(((output_index, event), time, diff));
                     }
@@ -925,7 +990,7 @@ fn extract_transaction<'a>(
                         let Some(ref projection) = info.projection else {
                             panic!("missing projection for {rel}");
                         };
-                        let datums = projection.iter().map(|idx| &tuple_data[*idx]);
+                        let datums = project_tuple(tuple_data, projection);
                         let row = unpack_tuple(datums, &mut row);
                         yield (rel, *output, row, Diff::ONE);
                     }
@@ -939,11 +1004,10 @@ fn extract_transaction<'a>(
                             let Some(ref projection) = info.projection else {
                                 panic!("missing projection for {rel}");
                             };
-                            let old_tuple =
-                                projection.iter().map(|idx| &old_tuple.tuple_data()[*idx]);
+                            let old_tuple = project_tuple(old_tuple.tuple_data(), projection);
                             // If the new tuple contains unchanged toast values we reference the old ones
                             let new_tuple = std::iter::zip(
-                                projection.iter().map(|idx| &new_tuple.tuple_data()[*idx]),
+                                project_tuple(new_tuple.tuple_data(), projection),
                                 old_tuple.clone(),
                             )
                             .map(|(new, old)| match new {
@@ -977,7 +1041,7 @@ fn extract_transaction<'a>(
                             let Some(ref projection) = info.projection else {
                                 panic!("missing projection for {rel}");
                             };
-                            let datums = projection.iter().map(|idx| &old_tuple.tuple_data()[*idx]);
+                            let datums = project_tuple(old_tuple.tuple_data(), projection);
                             let row = unpack_tuple(datums, &mut row);
                             yield (rel, *output, row, Diff::MINUS_ONE);
                         }
@@ -1024,11 +1088,51 @@ fn extract_transaction<'a>(
                                 }
                             }
                         });
+
+                        // Outputs that evolve their schema can only ingest the table once the
+                        // columns that were added upstream have also been added to them. Until
+                        // then the source stalls, which reports the pending exports to the
+                        // controller, and retries.
+                        let evolving = outputs.values().any(|info| {
+                            info.schema_change_behavior == SchemaChangeBehavior::Evolve
+                        });
+                        if let (true, Some(upstream)) = (evolving, upstream_info.get(&rel_id)) {
+                            let mut columns_with_defaults =
+                                mz_postgres_util::columns_with_defaults(metadata_client, &[rel_id])
+                                    .await?;
+                            let columns_with_defaults =
+                                columns_with_defaults.remove(&rel_id).unwrap_or_default();
+                            let mut columns = BTreeSet::new();
+                            let mut exports = vec![];
+                            for info in outputs.values() {
+                                if info.schema_change_behavior != SchemaChangeBehavior::Evolve {
+                                    continue;
+                                }
+                                let added = info.desc.added_columns(
+                                    upstream,
+                                    &columns_with_defaults,
+                                    &info.exclude_columns,
+                                );
+                                if !added.is_empty() {
+                                    columns.extend(added.iter().map(|c| c.name.clone()));
+                                    exports.push(info.export_id);
+                                }
+                            }
+                            if !exports.is_empty() {
+                                Err(TransientError::PendingSchemaEvolution {
+                                    table: format!("{}.{}", upstream.namespace, upstream.name),
+                                    columns: columns.into_iter().collect(),
+                                    exports,
+                                })?;
+                            }
+                        }
+
                         // Recalculate projection vector for the retained valid outputs. Here we
                         // must use the column names in the RelationBody message and not the
                         // upstream_info obtained above, since that one represents the current
                         // schema upstream which may be many versions head of the one we're about
-                        // to receive after this Relation message.
+                        // to receive after this Relation message. Columns that were added to
+                        // an output after this version of the schema project to NULL.
                         let column_positions: BTreeMap<_, _> = body
                             .columns()
                             .iter()
@@ -1038,7 +1142,7 @@ fn extract_transaction<'a>(
                         for info in outputs.values_mut() {
                             let mut projection = vec![];
                             for col in info.desc.columns.iter() {
-                                projection.push(column_positions[&*col.name]);
+                                projection.push(column_positions.get(&*col.name).copied());
                             }
                             info.projection = Some(projection);
                         }
@@ -1050,7 +1154,13 @@ fn extract_transaction<'a>(
                 Truncate(body) => {
                     for &rel_id in body.rel_ids() {
                         if let Some(outputs) = table_info.get_mut(&rel_id) {
-                            for (output, _) in std::mem::take(outputs) {
+                            // Outputs that retract their contents keep ingesting the table.
+                            let mut truncated = vec![];
+                            outputs.retain(|output, info| {
+                                truncated.push(*output);
+                                info.truncate_behavior == PostgresTruncateBehavior::Retract
+                            });
+                            for output in truncated {
                                 yield (
                                     rel_id,
                                     output,
@@ -1080,6 +1190,20 @@ fn extract_transaction<'a>(
     })
 }
 
+/// Projects the columns of a tuple according to `projection`, substituting NULL for the columns
+/// that the tuple does not have.
+#[inline]
+fn project_tuple<'a>(
+    tuple_data: &'a [TupleData],
+    projection: &'a [Option<usize>],
+) -> impl ExactSizeIterator<Item = &'a TupleData> + Clone {
+    static MISSING_COLUMN: TupleData = TupleData::Null;
+    projection.iter().map(move |idx| match idx {
+        Some(idx) => &tuple_data[*idx],
+        None => &MISSING_COLUMN,
+    })
+}
+
 /// Unpacks an iterator of TupleData into a list of nullable bytes or an error if this can't be
 /// done.
 #[inline]
@@ -0,0 +1,251 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Renders the retractions of source exports whose upstream table was truncated.
+//!
+//! Exports with [`PostgresTruncateBehavior::Retract`] don't enter an error state when their
+//! upstream table is truncated. Instead the replication reader emits a truncation marker at the
+//! LSN of the `TRUNCATE` and this operator, which runs after reclocking, replaces it with the
+//! retraction of the contents of the export at that point:
+//!
+//! * The contents that were committed to the export's shard before the reclocked time `t` of the
+//!   truncation are read back from persist as of `t - 1`. Only one worker does this.
+//! * The updates at `t` itself are not in persist yet, so every worker remembers the ones that
+//!   flowed through it and retracts the ones whose LSN is before the truncation. Updates of the
+//!   truncating transaction itself share its LSN; the replication reader has already retracted
+//!   those that came before the `TRUNCATE` message.
+//!
+//! The initial snapshot of a table is emitted at LSN 0 and can be arbitrarily large, so it is not
+//! remembered. A truncation that is reclocked to the same time as (part of) the snapshot falls
+//! back to the [`PostgresTruncateBehavior::Error`] behavior.
+//!
+//! [`PostgresTruncateBehavior::Retract`]: mz_storage_types::sources::postgres::PostgresTruncateBehavior::Retract
+//! [`PostgresTruncateBehavior::Error`]: mz_storage_types::sources::postgres::PostgresTruncateBehavior::Error
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use differential_dataflow::{AsCollection, VecCollection};
+use mz_persist_client::Diagnostics;
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_repr::{Diff, GlobalId, Row};
+use mz_storage_types::StorageDiff;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sources::SourceData;
+use mz_timely_util::builder_async::{
+    Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::Scope;
+use timely::dataflow::channels::pact::Pipeline;
+use timely::dataflow::operators::CapabilitySet;
+use timely::dataflow::operators::vec::{Broadcast, Filter};
+use timely::progress::{Antichain, Timestamp};
+
+use crate::source::RawSourceCreationConfig;
+use crate::source::postgres::{DefiniteError, is_truncate_marker};
+use crate::source::types::SourceOutput;
+
+/// Replaces the truncation markers in the `ok` collection of the given export with the
+/// retraction of the export's contents. Returns the resulting collection along with a collection
+/// of the errors of truncations that could not be retracted.
+pub(crate) fn render<'scope, FromTime: Timestamp>(
+    scope: Scope<'scope, mz_repr::Timestamp>,
+    config: &RawSourceCreationConfig,
+    export_id: GlobalId,
+    ok: VecCollection<'scope, mz_repr::Timestamp, SourceOutput<FromTime>, Diff>,
+) -> (
+    VecCollection<'scope, mz_repr::Timestamp, SourceOutput<FromTime>, Diff>,
+    VecCollection<'scope, mz_repr::Timestamp, DataflowError, Diff>,
+    PressOnDropButton,
+) {
+    let op_name = format!("PgTruncateRetractions({export_id})");
+    let mut builder = AsyncOperatorBuilder::new(op_name, scope.clone());
+
+    // Every worker needs to know about every truncation to retract the updates it has seen.
+    let markers = ok
+        .inner
+        .filter(|(output, _, _)| is_truncate_marker(&output.key))
+        .broadcast();
+
+    let (ok_output, ok_stream) = builder.new_output::<CapacityContainerBuilder<_>>();
+    let (err_output, err_stream) = builder.new_output::<CapacityContainerBuilder<_>>();
+    let mut data_input = builder.new_disconnected_input(ok.inner, Pipeline);
+    let mut marker_input = builder.new_disconnected_input(markers, Pipeline);
+
+    let reads_persist = config.responsible_for(("truncate", export_id));
+    let persist_clients = Arc::clone(&config.persist_clients);
+    let metadata = config.source_exports[&export_id].storage_metadata.clone();
+    let resume_upper = config.resume_uppers[&export_id].clone();
+
+    let button = builder.build(move |caps| async move {
+        let [ok_cap, err_cap]: [_; 2] = caps.try_into().unwrap();
+        let mut ok_caps = CapabilitySet::from_elem(ok_cap);
+        let mut err_caps = CapabilitySet::from_elem(err_cap);
+
+        let mut data_upper = Antichain::from_elem(mz_repr::Timestamp::minimum());
+        let mut marker_upper = Antichain::from_elem(mz_repr::Timestamp::minimum());
+        // The LSN of the last truncation at each time.
+        let mut truncations: BTreeMap<mz_repr::Timestamp, FromTime> = BTreeMap::new();
+        // The updates that were passed through at each time that is not yet complete.
+        let mut stash: BTreeMap<mz_repr::Timestamp, Vec<(SourceOutput<FromTime>, Diff)>> =
+            BTreeMap::new();
+        // The times at which snapshot updates were passed through.
+        let mut snapshot_times = BTreeSet::new();
+
+        loop {
+            tokio::select! {
+                Some(event) = data_input.next() => match event {
+                    AsyncEvent::Data(_, data) => {
+                        for (output, time, diff) in data {
+                            if is_truncate_marker(&output.key) {
+                                continue;
+                            }
+                            if output.from_time == FromTime::minimum() {
+                                snapshot_times.insert(time);
+                            } else {
+                                stash.entry(time).or_default().push((output.clone(), diff));
+                            }
+                            ok_output.give(&ok_caps.delayed(&time), (output, time, diff));
+                        }
+                    }
+                    AsyncEvent::Progress(upper) => data_upper = upper,
+                },
+                Some(event) = marker_input.next() => match event {
+                    AsyncEvent::Data(_, data) => {
+                        for (output, time, _) in data {
+                            let lsn = truncations.entry(time).or_insert(output.from_time.clone());
+                            if *lsn < output.from_time {
+                                *lsn = output.from_time;
+                            }
+                        }
+                    }
+                    AsyncEvent::Progress(upper) => marker_upper = upper,
+                },
+                else => break,
+            }
+
+            let mut frontier = data_upper.clone();
+            frontier.extend(marker_upper.iter().cloned());
+
+            while let Some((&time, _)) = truncations.first_key_value() {
+                if frontier.less_equal(&time) {
+                    break;
+                }
+                let (time, lsn) = truncations.pop_first().expect("known to exist");
+                let updates = stash.remove(&time).unwrap_or_default();
+
+                // Truncations before the resume upper are already reflected in the shard.
+                if !resume_upper.less_equal(&time) {
+                    continue;
+                }
+
+                if snapshot_times.contains(&time) {
+                    let err = DataflowError::from(DefiniteError::TableTruncated);
+                    err_output.give(&err_caps.delayed(&time), (err, time, Diff::ONE));
+                    continue;
+                }
+
+                let mut retractions: Vec<_> = updates
+                    .into_iter()
+                    .filter(|(output, _)| output.from_time < lsn)
+                    .map(|(output, diff)| {
+                        let output = SourceOutput {
+                            from_time: lsn.clone(),
+                            ..output
+                        };
+                        (output, time, -diff)
+                    })
+                    .collect();
+
+                if reads_persist {
+                    match time.step_back() {
+                        Some(as_of) => {
+                            let client = persist_clients
+                                .open(metadata.persist_location.clone())
+                                .await
+                                .expect("error creating persist client");
+                            let (mut write_handle, mut read_handle) = client
+                                .open::<SourceData, (), mz_repr::Timestamp, StorageDiff>(
+                                    metadata.data_shard,
+                                    Arc::new(metadata.relation_desc.clone()),
+                                    Arc::new(UnitSchema),
+                                    Diagnostics {
+                                        shard_name: export_id.to_string(),
+                                        handle_purpose: format!(
+                                            "truncate retractions for {export_id}"
+                                        ),
+                                    },
+                                    false,
+                                )
+                                .await
+                                .expect("invalid usage");
+                            let as_of = Antichain::from_elem(as_of);
+                            // The shard can only catch up to `time` once this operator has
+                            // released its capabilities for earlier times. Everything it emits
+                            // from now on is at or after `time`.
+                            ok_caps.downgrade(&[time]);
+                            err_caps.downgrade(&[time]);
+                            write_handle.wait_for_upper_past(&as_of).await;
+                            match read_handle.snapshot_and_fetch(as_of).await {
+                                Ok(contents) => {
+                                    for ((SourceData(row), ()), _, diff) in contents {
+                                        // Errors are never retracted.
+                                        let Ok(row) = row else { continue };
+                                        let output = SourceOutput {
+                                            key: Row::default(),
+                                            value: row,
+                                            metadata: Row::default(),
+                                            from_time: lsn.clone(),
+                                        };
+                                        retractions.push((output, time, -Diff::from(diff)));
+                                    }
+                                }
+                                Err(since) => {
+                                    tracing::warn!(
+                                        %export_id,
+                                        ?since,
+                                        "contents of truncated table compacted past {time}"
+                                    );
+                                    let err = DataflowError::from(DefiniteError::TableTruncated);
+                                    err_output
+                                        .give(&err_caps.delayed(&time), (err, time, Diff::ONE));
+                                }
+                            }
+                            read_handle.expire().await;
+                            write_handle.expire().await;
+                        }
+                        // Nothing can have been committed before the minimum time.
+                        None => {}
+                    }
+                }
+
+                differential_dataflow::consolidation::consolidate_updates(&mut retractions);
+                let cap = ok_caps.delayed(&time);
+                for retraction in retractions {
+                    ok_output.give(&cap, retraction);
+                }
+            }
+
+            stash.retain(|time, _| frontier.less_equal(time));
+            snapshot_times.retain(|time| frontier.less_equal(time));
+            ok_caps.downgrade(frontier.iter());
+            err_caps.downgrade(frontier.iter());
+            if frontier.is_empty() {
+                break;
+            }
+        }
+    });
+
+    (
+        ok_stream.as_collection(),
+        err_stream.as_collection(),
+        button.press_on_drop(),
+    )
+}
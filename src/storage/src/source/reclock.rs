@@ -313,7 +313,7 @@ mod tests {
     /// timestamps at offset zero.
     fn partitioned_frontier<I>(items: I) -> Antichain<kafka::KafkaTimestamp>
     where
-        I: IntoIterator<Item = (i32, MzOffset)>,
+        I: IntoIterator<Item = (i64, MzOffset)>,
     {
         let mut frontier = Antichain::new();
         let mut prev = RB::NegInfinity;
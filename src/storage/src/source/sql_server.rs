@@ -24,7 +24,8 @@ use mz_sql_server_util::cdc::Lsn;
 use mz_sql_server_util::desc::{SqlServerRowDecoder, SqlServerTableDesc};
 use mz_storage_types::errors::{DataflowError, SourceError, SourceErrorDetails};
 use mz_storage_types::sources::{
-    SourceExport, SourceExportDetails, SourceTimestamp, SqlServerSourceConnection,
+    SchemaChangeBehavior, SourceExport, SourceExportDetails, SourceTimestamp,
+    SqlServerSourceConnection,
 };
 use mz_timely_util::builder_async::PressOnDropButton;
 use timely::container::CapacityContainerBuilder;
@@ -46,7 +47,6 @@ struct SourceOutputInfo {
     /// Name of the capture instance in the upstream SQL Server DB.
     capture_instance: Arc<str>,
     /// Description of the upstream table.
-    #[allow(dead_code)]
     upstream_desc: Arc<SqlServerTableDesc>,
     /// Type that can decode (and map) SQL Server rows into Materialize rows.
     decoder: Arc<SqlServerRowDecoder>,
@@ -56,6 +56,8 @@ struct SourceOutputInfo {
     partition_index: u64,
     /// The basis for the resumption LSN when snapshotting.
     initial_lsn: Lsn,
+    /// How to handle columns added to the upstream table.
+    schema_change_behavior: SchemaChangeBehavior,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -70,6 +72,16 @@ pub enum ReplicationError {
 pub enum TransientError {
     #[error("stream ended prematurely")]
     ReplicationEOF,
+    #[error(
+        "source table {table} added columns {}; waiting for the columns to be added to its export",
+        .columns.join(", ")
+    )]
+    PendingSchemaEvolution {
+        table: String,
+        columns: Vec<String>,
+        /// The exports that are waiting for the columns to be added to them.
+        exports: Vec<GlobalId>,
+    },
     #[error(transparent)]
     SqlServer(#[from] SqlServerError),
     #[error(transparent)]
@@ -152,6 +164,7 @@ impl SourceRender for SqlServerSourceConnection {
                 resume_upper: Antichain::from_iter(resume_upper),
                 partition_index: u64::cast_from(idx),
                 initial_lsn: details.initial_lsn,
+                schema_change_behavior: details.schema_change_behavior,
             };
             source_outputs.insert(*id, output_info);
         }
@@ -205,19 +218,42 @@ impl SourceRender for SqlServerSourceConnection {
             .collect::<Vec<_>>()
             .to_stream(scope);
 
-        let health_errs = repl_errs.concat(progress_errs).map(move |err| {
+        let health_errs = repl_errs.concat(progress_errs).flat_map(move |err| {
             // This update will cause the dataflow to restart
             let err_string = err.display_with_causes().to_string();
-            let update = HealthStatusUpdate::halting(err_string, None);
+            let update = HealthStatusUpdate::halting(err_string.clone(), None);
+
+            // Exports that wait for columns to be added to them are stalled until the
+            // controller has evolved their schema. Only the source as a whole may halt.
+            let pending_exports = match &err {
+                ReplicationError::Transient(err) => match &**err {
+                    TransientError::PendingSchemaEvolution { exports, .. } => exports.clone(),
+                    _ => vec![],
+                },
+                ReplicationError::DefiniteError(_) => vec![],
+            };
+            let mut updates: Vec<_> = pending_exports
+                .into_iter()
+                .map(|id| HealthStatusMessage {
+                    id: Some(id),
+                    namespace: StatusNamespace::SchemaEvolution,
+                    update: HealthStatusUpdate::stalled(
+                        err_string.clone(),
+                        Some("the columns are added to this table automatically".into()),
+                    ),
+                })
+                .collect();
+
             // TODO(sql_server2): If the error has anything to do with SSH
             // connections we should use the SSH status namespace.
             let namespace = Self::STATUS_NAMESPACE;
 
-            HealthStatusMessage {
+            updates.push(HealthStatusMessage {
                 id: None,
                 namespace: namespace.clone(),
                 update,
-            }
+            });
+            updates
         });
         let health = health_init.concat(health_errs);
 
@@ -15,28 +15,35 @@
 //!   [`Lsn`], emit the upstream known offset, and update `SourceStatistics`.
 //! * Listen to a provided [`futures::Stream`] of resume uppers, which represents
 //!   the durably committed upper for _all_ of the subsources/exports associated
-//!   with this source. As the source makes progress this operator does two
+//!   with this source. As the source makes progress this operator does three
 //!   things:
 //!     1. If [`CDC_CLEANUP_CHANGE_TABLE`] is enabled, will delete entries from
 //!        the upstream change table that we've already ingested.
 //!     2. Update `SourceStatistics` to notify listeners of a new
 //!        "committed LSN".
+//!     3. At some cadence [`SCHEMA_EVOLUTION_CHECK_INTERVAL`], check whether
+//!        exports that evolve their schema wait for columns that are captured
+//!        by a newer capture instance of their upstream table.
 //!
 //! [`SqlServerSourceConnection`]: mz_storage_types::sources::SqlServerSourceConnection
 
 use std::collections::{BTreeMap, BTreeSet};
+use std::time::Instant;
 
 use futures::StreamExt;
 use mz_ore::future::InTask;
 use mz_repr::GlobalId;
+use mz_sql_server_util::Client;
 use mz_sql_server_util::cdc::Lsn;
-use mz_sql_server_util::inspect::{get_latest_restore_history_id, get_max_lsn};
+use mz_sql_server_util::inspect::{
+    get_latest_capture_instance, get_latest_restore_history_id, get_max_lsn,
+};
 use mz_storage_types::connections::SqlServerConnectionDetails;
 use mz_storage_types::dyncfgs::SQL_SERVER_SOURCE_VALIDATE_RESTORE_HISTORY;
-use mz_storage_types::sources::SqlServerSourceExtras;
 use mz_storage_types::sources::sql_server::{
-    CDC_CLEANUP_CHANGE_TABLE, CDC_CLEANUP_CHANGE_TABLE_MAX_DELETES,
+    CDC_CLEANUP_CHANGE_TABLE, CDC_CLEANUP_CHANGE_TABLE_MAX_DELETES, SCHEMA_EVOLUTION_CHECK_INTERVAL,
 };
+use mz_storage_types::sources::{SchemaChangeBehavior, SqlServerSourceExtras};
 use mz_timely_util::builder_async::{OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton};
 use timely::container::CapacityContainerBuilder;
 use timely::dataflow::operators::vec::Map;
@@ -123,6 +130,14 @@ pub(crate) fn render<'scope>(
             let cleanup_max_deletes =
                 CDC_CLEANUP_CHANGE_TABLE_MAX_DELETES
                     .handle(config.config.config_set());
+            let schema_evolution_check_interval =
+                SCHEMA_EVOLUTION_CHECK_INTERVAL.handle(config.config.config_set());
+            let mut last_schema_evolution_check: Option<Instant> = None;
+            let evolving_outputs: Vec<_> = outputs
+                .iter()
+                .filter(|(_, info)| info.schema_change_behavior == SchemaChangeBehavior::Evolve)
+                .map(|(id, info)| (*id, info.clone()))
+                .collect();
             let capture_instances: BTreeSet<_> = outputs
                 .into_values()
                 .map(|info| info.capture_instance)
@@ -197,6 +212,19 @@ pub(crate) fn render<'scope>(
                         for stat in config.statistics.values() {
                             stat.set_offset_committed(committed_upper.abbreviate());
                         }
+
+                        if !evolving_outputs.is_empty()
+                            && last_schema_evolution_check.is_none_or(|last| {
+                                last.elapsed() >= schema_evolution_check_interval.get()
+                            })
+                        {
+                            last_schema_evolution_check = Some(Instant::now());
+                            check_schema_evolution(
+                                &mut client,
+                                &evolving_outputs,
+                                *committed_upper,
+                            ).await?;
+                        }
                     }
                 };
             }
@@ -207,3 +235,56 @@ pub(crate) fn render<'scope>(
 
     (error_stream, probe_stream, button.press_on_drop())
 }
+
+/// Checks whether any of the `outputs`, which evolve their schema, wait for
+/// columns that were added to their upstream table, given that all changes
+/// before `committed_lsn` have been committed.
+///
+/// Columns that are added to a table are only captured by a capture instance
+/// that is created after them. Outputs switch to such a capture instance once
+/// the changes before its start LSN have been committed, because it doesn't
+/// capture any earlier changes. The added columns read as NULL in the changes
+/// between their addition and the switch.
+async fn check_schema_evolution(
+    client: &mut Client,
+    outputs: &[(GlobalId, SourceOutputInfo)],
+    committed_lsn: Lsn,
+) -> Result<(), TransientError> {
+    let mut outputs_by_instance: BTreeMap<_, Vec<_>> = BTreeMap::new();
+    for (id, info) in outputs {
+        outputs_by_instance
+            .entry(&*info.capture_instance)
+            .or_default()
+            .push((*id, info));
+    }
+
+    for (capture_instance, outputs) in outputs_by_instance {
+        let Some(latest) = get_latest_capture_instance(client, capture_instance).await? else {
+            // Dropped capture instances are reported by the replication operator.
+            continue;
+        };
+        if &*latest.name != capture_instance && committed_lsn < latest.start_lsn {
+            continue;
+        }
+
+        let mut columns = BTreeSet::new();
+        let mut exports = vec![];
+        for (id, info) in &outputs {
+            let added = info
+                .upstream_desc
+                .added_columns(&latest.columns, &latest.columns_with_defaults);
+            if !added.is_empty() {
+                columns.extend(added.into_iter().map(|c| c.name.to_string()));
+                exports.push(*id);
+            }
+        }
+        if !exports.is_empty() {
+            return Err(TransientError::PendingSchemaEvolution {
+                table: outputs[0].1.upstream_desc.qualified_name().to_string(),
+                columns: columns.into_iter().collect(),
+                exports,
+            });
+        }
+    }
+    Ok(())
+}
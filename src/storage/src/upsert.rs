@@ -248,14 +248,15 @@ where
 impl UpsertSourceTime for KafkaTimestamp {
     /// Per-record Kafka source times are exact singletons (a single partition
     /// at a single offset; see the source reader), and `KafkaTimestamp`'s
-    /// derived `Ord` is lexicographic on `(partition, offset)`, so this flat
-    /// projection is order-preserving. `RangeBound`'s infinities map to the
-    /// `i64` extrema to remain order-consistent for any non-singleton bound.
+    /// derived `Ord` is lexicographic on `(partition key, offset)`, so this
+    /// flat projection is order-preserving. `RangeBound`'s infinities map to
+    /// the `i64` extrema to remain order-consistent for any non-singleton
+    /// bound.
     type Order = (i64, u64);
     fn upsert_order(&self) -> (i64, u64) {
         let partition = match self.interval().lower {
             RangeBound::NegInfinity => i64::MIN,
-            RangeBound::Elem(p, _) => i64::from(p),
+            RangeBound::Elem(p, _) => p,
             RangeBound::PosInfinity => i64::MAX,
         };
         (partition, self.timestamp().offset)
@@ -1006,6 +1006,7 @@ impl Run for PosCommand {
                     "s3-verify-data" => s3::run_verify_data(builtin, state).await,
                     "s3-verify-keys" => s3::run_verify_keys(builtin, state).await,
                     "s3-file-upload" => s3::run_upload(builtin, state).await,
+                    "s3-avro-ocf-upload" => s3::run_upload_avro_ocf(builtin, state).await,
                     "s3-set-presigned-url" => s3::run_set_presigned_url(builtin, state).await,
                     "s3-upload-parquet-types" => s3::run_upload_parquet_types(builtin, state).await,
                     "s3-upload-parquet-unsorted-map" => {
@@ -31,6 +31,7 @@ use arrow::util::display::ArrayFormatter;
 use arrow::util::display::FormatOptions;
 use async_compression::tokio::bufread::{BzEncoder, GzipEncoder, XzEncoder, ZstdEncoder};
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use mz_avro::{Codec, Writer};
 use parquet::arrow::ArrowWriter;
 use parquet::basic::{BrotliLevel, Compression as ParquetCompression, GzipLevel, ZstdLevel};
 use parquet::file::properties::WriterProperties;
@@ -41,6 +42,7 @@ use crate::action::file::Compression;
 use crate::action::file::Contents;
 use crate::action::file::build_compression;
 use crate::action::{ControlFlow, State};
+use crate::format::avro;
 use crate::parser::BuiltinCommand;
 
 pub async fn run_verify_data(
@@ -235,18 +237,7 @@ pub async fn run_upload(
 
     // TODO(parkmycar): Stream data to S3. The ByteStream type from the AWS config is a bit
     // cumbersome to work with, so for now just stick with this.
-    let mut reader: Pin<Box<dyn AsyncRead + Send + Sync>> = match compression {
-        Compression::None => Box::pin(&body[..]),
-        Compression::Gzip => Box::pin(GzipEncoder::new(&body[..])),
-        Compression::Bzip2 => Box::pin(BzEncoder::new(&body[..])),
-        Compression::Xz => Box::pin(XzEncoder::new(&body[..])),
-        Compression::Zstd => Box::pin(ZstdEncoder::new(&body[..])),
-    };
-    let mut content = vec![];
-    reader
-        .read_to_end(&mut content)
-        .await
-        .context("compressing")?;
+    let content = compress(&body, compression).await?;
 
     // Upload the file(s) to S3.
     println!(
@@ -268,6 +259,62 @@ pub async fn run_upload(
     Ok(ControlFlow::Continue)
 }
 
+/// Uploads an Avro Object Container File to an S3 bucket. Each line of the body of the directive
+/// is a JSON value that is encoded with the Avro schema in `schema`.
+pub async fn run_upload_avro_ocf(
+    mut cmd: BuiltinCommand,
+    state: &State,
+) -> Result<ControlFlow, anyhow::Error> {
+    let bucket = cmd.args.string("bucket")?;
+    let key = cmd.args.string("key")?;
+    let schema = cmd.args.string("schema")?;
+    let codec = match cmd.args.opt_string("codec") {
+        Some(codec) => Some(codec.parse::<Codec>().context("parsing codec")?),
+        None => None,
+    };
+    let compression = build_compression(&mut cmd)?;
+    cmd.args.done()?;
+
+    let schema = avro::parse_schema(&schema, &[]).context("parsing avro schema")?;
+    let mut writer = Writer::with_codec_opt(schema.clone(), Vec::new(), codec);
+    for line in &cmd.input {
+        let value: serde_json::Value = serde_json::from_str(line).context("parsing json")?;
+        let value = avro::from_json(&value, schema.top_node())?;
+        writer.append(value).context("writing avro value")?;
+    }
+    writer.flush().context("flushing avro writer")?;
+    let content = compress(&writer.into_inner(), compression).await?;
+
+    println!("Uploading Avro file to S3 bucket '{bucket}/{key}'");
+    mz_aws_util::s3::new_client(&state.aws_config)
+        .put_object()
+        .bucket(&bucket)
+        .key(&key)
+        .body(content.into())
+        .send()
+        .await
+        .context("s3 put")?;
+
+    Ok(ControlFlow::Continue)
+}
+
+/// Compresses `body` with `compression`.
+async fn compress(body: &[u8], compression: Compression) -> Result<Vec<u8>, anyhow::Error> {
+    let mut reader: Pin<Box<dyn AsyncRead + Send + Sync>> = match compression {
+        Compression::None => Box::pin(body),
+        Compression::Gzip => Box::pin(GzipEncoder::new(body)),
+        Compression::Bzip2 => Box::pin(BzEncoder::new(body)),
+        Compression::Xz => Box::pin(XzEncoder::new(body)),
+        Compression::Zstd => Box::pin(ZstdEncoder::new(body)),
+    };
+    let mut content = vec![];
+    reader
+        .read_to_end(&mut content)
+        .await
+        .context("compressing")?;
+    Ok(content)
+}
+
 pub async fn run_set_presigned_url(
     mut cmd: BuiltinCommand,
     state: &mut State,
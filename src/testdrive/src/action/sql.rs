@@ -18,7 +18,7 @@ use md5::{Digest, Md5};
 use mz_ore::collections::CollectionExt;
 use mz_ore::retry::Retry;
 use mz_ore::str::StrExt;
-use mz_pgrepr::{Interval, Jsonb, Numeric, UInt2, UInt4, UInt8};
+use mz_pgrepr::{Inet, Interval, Jsonb, MacAddr, Numeric, UInt2, UInt4, UInt8};
 use mz_postgres_util::query_prepared;
 use mz_repr::adt::range::Range;
 use mz_sql_parser::ast::{Raw, Statement};
@@ -70,6 +70,7 @@ pub async fn run_sql(mut cmd: SqlCommand, state: &mut State) -> Result<ControlFl
         | CreateTableFromSource(_)
         | CreateIndex(_)
         | CreateType(_)
+        | CreateFunction(_)
         | CreateRole(_)
         | AlterObjectRename(_)
         | AlterIndex(_)
@@ -737,6 +738,11 @@ pub fn decode_row(
             Type::INTERVAL => row.get::<_, Option<Interval>>(i).map(|x| x.to_string()),
             Type::JSONB => row.get::<_, Option<Jsonb>>(i).map(|v| v.0.to_string()),
             Type::UUID => row.get::<_, Option<uuid::Uuid>>(i).map(|v| v.to_string()),
+            Type::INET => row.get::<_, Option<Inet>>(i).map(|v| v.to_string()),
+            Type::CIDR => row
+                .get::<_, Option<Inet>>(i)
+                .map(|v| v.0.display_cidr().to_string()),
+            Type::MACADDR => row.get::<_, Option<MacAddr>>(i).map(|v| v.to_string()),
             Type::BOOL_ARRAY => row
                 .get::<_, Option<Array<ArrayElement<bool>>>>(i)
                 .map(|a| a.to_string()),
@@ -497,12 +497,14 @@ impl FoldConstants {
                 let mut row_buf = Row::default();
                 move |(key, vals)| {
                     let temp_storage = RowArena::new();
-                    row_buf.packer().extend(key.into_iter().chain(
-                        aggregates.iter().enumerate().map(|(i, agg)| {
+                    let aggs = aggregates
+                        .iter()
+                        .enumerate()
+                        .map(|(i, agg)| {
                             if agg.distinct {
                                 // Distinct collapses to one copy per value, so
                                 // each distinct datum gets a unit multiplicity.
-                                agg.func.eval(
+                                agg.func.try_eval(
                                     vals.iter()
                                         .map(|(val, _diff)| val[i].unpack_first())
                                         .collect::<BTreeSet<_>>()
@@ -511,19 +513,20 @@ impl FoldConstants {
                                     &temp_storage,
                                 )
                             } else {
-                                agg.func.eval(
+                                agg.func.try_eval(
                                     vals.iter()
                                         .map(|(val, diff)| (val[i].unpack_first(), *diff)),
                                     &temp_storage,
                                 )
                             }
-                        }),
-                    ));
-                    (row_buf.clone(), Diff::ONE)
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    row_buf.packer().extend(key.into_iter().chain(aggs));
+                    Ok::<_, EvalError>((row_buf.clone(), Diff::ONE))
                 }
             })
             .collect();
-        Some(Ok(new_rows))
+        Some(new_rows)
     }
 
     fn fold_topk_constant<'a>(
@@ -23,6 +23,7 @@ use std::collections::{BTreeMap, BTreeSet};
 use itertools::Itertools;
 use mz_expr::JoinImplementation::IndexedFilter;
 use mz_expr::canonicalize::canonicalize_predicates;
+use mz_expr::func::Eq;
 use mz_expr::func::variadic::{And, Or};
 use mz_expr::visit::{Visit, VisitChildren};
 use mz_expr::{BinaryFunc, Id, MapFilterProject, MirRelationExpr, MirScalarExpr, VariadicFunc};
@@ -30,7 +31,8 @@ use mz_ore::collections::CollectionExt;
 use mz_ore::iter::IteratorExt;
 use mz_ore::stack::RecursionLimitError;
 use mz_ore::vec::swap_remove_multiple;
-use mz_repr::{Diff, GlobalId, ReprRelationType, Row};
+use mz_repr::adt::date::Date;
+use mz_repr::{Datum, Diff, GlobalId, ReprRelationType, ReprScalarType, Row};
 
 use crate::TransformCtx;
 use crate::canonicalize_mfp::CanonicalizeMfp;
@@ -80,6 +82,7 @@ impl LiteralConstraints {
 
             // Preparation for the literal constraints detection.
             Self::inline_literal_constraints(&mut mfp);
+            Self::expand_containment_constraints(&mut mfp);
             Self::list_of_predicates_to_and_of_predicates(&mut mfp);
             Self::distribute_and_over_or(&mut mfp)?;
             Self::unary_and(&mut mfp);
@@ -589,6 +592,48 @@ impl LiteralConstraints {
         mfp.perform_inlining(should_inline);
     }
 
+    /// Rewrites containment predicates of the form `<lit> @> <expr>` (or `<expr> <@ <lit>`), where
+    /// `<lit>` is a literal range or multirange, into ORs of literal constraints
+    /// `<expr> = <v1> OR <expr> = <v2> OR ...`, so that they can be used for index lookups.
+    ///
+    /// This is only possible for discrete element types (`int4`, `int8`, and `date`), and only
+    /// done when `<lit>` is bounded and contains at most [`MAX_CONTAINMENT_VALUES`] values.
+    /// The rewrite preserves null semantics, because both forms are null exactly when `<expr>`
+    /// is null. Literals that contain no values are left alone, since an empty OR is `false`
+    /// rather than null.
+    fn expand_containment_constraints(mfp: &mut MapFilterProject) {
+        for (_before, p) in mfp.predicates.iter_mut() {
+            p.visit_mut_post(&mut |e| {
+                if let MirScalarExpr::CallBinary {
+                    func:
+                        BinaryFunc::RangeContainsI32(_)
+                        | BinaryFunc::RangeContainsI32Rev(_)
+                        | BinaryFunc::RangeContainsI64(_)
+                        | BinaryFunc::RangeContainsI64Rev(_)
+                        | BinaryFunc::RangeContainsDate(_)
+                        | BinaryFunc::RangeContainsDateRev(_)
+                        | BinaryFunc::MultiRangeContainsElem(_)
+                        | BinaryFunc::MultiRangeContainsElemRev(_),
+                    expr1,
+                    expr2,
+                } = e
+                {
+                    let values = expr1.as_literal_non_error().and_then(contained_values);
+                    if let Some((values, typ)) = values {
+                        let equalities = values
+                            .into_iter()
+                            .map(|v| {
+                                let literal = MirScalarExpr::literal_ok(v, typ.clone());
+                                (**expr2).clone().call_binary(literal, Eq)
+                            })
+                            .collect();
+                        *e = MirScalarExpr::call_variadic(Or, equalities);
+                    }
+                }
+            });
+        }
+    }
+
     /// MFPs have a Vec of predicates `[p1, p2, ...]`, which logically represents `p1 AND p2 AND ...`.
     /// This function performs this conversion. Note that it might create a variadic AND with
     /// 0 or 1 args, so the resulting predicate Vec always has exactly 1 element.
@@ -769,3 +814,62 @@ enum IndexMatch {
     /// to speed up this filter.
     UnusableNoSubset,
 }
+
+/// The largest number of values that [`LiteralConstraints::expand_containment_constraints`]
+/// expands a containment predicate into.
+const MAX_CONTAINMENT_VALUES: i64 = 100;
+
+/// Enumerates the values contained in a literal range or multirange of a discrete element type,
+/// along with their type. Returns `None` if the values cannot be enumerated, if there are more
+/// than [`MAX_CONTAINMENT_VALUES`] of them, or if there are none.
+fn contained_values(container: Datum) -> Option<(Vec<Datum<'static>>, ReprScalarType)> {
+    let ranges = match container {
+        Datum::Range(_) => vec![container.unwrap_range()],
+        Datum::List(_) => container.unwrap_multirange(),
+        _ => return None,
+    };
+    let mut typ = None;
+    let mut values = Vec::new();
+    for range in ranges {
+        let Some(inner) = range.inner else {
+            continue;
+        };
+        let (lower, upper) = (inner.lower.bound?, inner.upper.bound?);
+        let (elem_typ, lower, upper) = match (lower, upper) {
+            (Datum::Int32(l), Datum::Int32(u)) => (ReprScalarType::Int32, l.into(), u.into()),
+            (Datum::Int64(l), Datum::Int64(u)) => (ReprScalarType::Int64, l, u),
+            (Datum::Date(l), Datum::Date(u)) => (
+                ReprScalarType::Date,
+                l.pg_epoch_days().into(),
+                u.pg_epoch_days().into(),
+            ),
+            _ => return None,
+        };
+        let first = if inner.lower.inclusive {
+            lower
+        } else {
+            lower.checked_add(1)?
+        };
+        let end = if inner.upper.inclusive {
+            upper.checked_add(1)?
+        } else {
+            upper
+        };
+        let count = i64::try_from(values.len()).ok()? + end.checked_sub(first)?.max(0);
+        if count > MAX_CONTAINMENT_VALUES {
+            return None;
+        }
+        for v in first..end {
+            values.push(match elem_typ {
+                ReprScalarType::Int32 => Datum::Int32(i32::try_from(v).ok()?),
+                ReprScalarType::Int64 => Datum::Int64(v),
+                _ => Datum::Date(Date::from_pg_epoch(i32::try_from(v).ok()?).ok()?),
+            });
+        }
+        typ = Some(elem_typ);
+    }
+    if values.is_empty() {
+        return None;
+    }
+    Some((values, typ?))
+}
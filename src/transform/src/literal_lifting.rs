@@ -594,10 +594,9 @@ impl LiteralLifting {
 
                     let eval_constant_aggr = |aggr: &mz_expr::AggregateExpr| {
                         let temp = mz_repr::RowArena::new();
-                        let mut eval = aggr.expr.eval(&[], &temp);
-                        if let Ok(param) = eval {
-                            eval = Ok(aggr.func.eval(Some((param, mz_repr::Diff::ONE)), &temp));
-                        }
+                        let eval = aggr.expr.eval(&[], &temp).and_then(|param| {
+                            aggr.func.try_eval(Some((param, mz_repr::Diff::ONE)), &temp)
+                        });
                         MirScalarExpr::literal(
                             eval,
                             // This type information should be available in the `a.expr` literal,
@@ -667,6 +667,10 @@ fn datum_difference_with_column_type(
                 (Datum::String(_), _) => mismatch(datum, scalar_type),
                 (Datum::Uuid(_), ReprScalarType::Uuid) => Ok(()),
                 (Datum::Uuid(_), _) => mismatch(datum, scalar_type),
+                (Datum::Inet(_), ReprScalarType::Inet) => Ok(()),
+                (Datum::Inet(_), _) => mismatch(datum, scalar_type),
+                (Datum::MacAddr(_), ReprScalarType::MacAddr) => Ok(()),
+                (Datum::MacAddr(_), _) => mismatch(datum, scalar_type),
                 (Datum::Array(array), ReprScalarType::Array(t)) => {
                     for e in array.elements().iter() {
                         if let Datum::Null = e {
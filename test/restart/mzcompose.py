@@ -207,6 +207,60 @@ def workflow_github_5108(c: Composition) -> None:
     c.kill("materialized")
 
 
+# Test that SQL functions, and the views that depend on them, are reloaded from
+# the catalog.
+def workflow_sql_functions(c: Composition) -> None:
+    c.down(destroy_volumes=True)
+    c.up("materialized", Service("testdrive_no_reset", idle=True))
+
+    c.testdrive(
+        service="testdrive_no_reset",
+        input=dedent("""
+            > CREATE TABLE t (a int)
+            > INSERT INTO t VALUES (1), (2)
+            > CREATE FUNCTION add_one(a int) RETURNS int AS 'SELECT a + 1'
+            > CREATE FUNCTION above(n int) RETURNS TABLE (a int) AS 'SELECT a FROM t WHERE a > n'
+            > CREATE VIEW v AS SELECT a, add_one(a) AS b FROM t
+            > CREATE VIEW w AS SELECT * FROM above(1)
+
+            > SELECT * FROM v
+            1 2
+            2 3
+            """),
+    )
+
+    # Restart mz
+    c.kill("materialized")
+    c.up("materialized")
+
+    c.testdrive(
+        service="testdrive_no_reset",
+        input=dedent("""
+            > SELECT * FROM v
+            1 2
+            2 3
+
+            > SELECT * FROM w
+            2
+
+            > SELECT add_one(41)
+            42
+
+            > SHOW CREATE FUNCTION add_one
+            materialize.public.add_one "CREATE FUNCTION materialize.public.add_one(a pg_catalog.int4) RETURNS pg_catalog.int4 LANGUAGE SQL AS 'SELECT a + 1';"
+
+            ! DROP FUNCTION add_one
+            contains:cannot drop function "add_one": still depended upon by view "v"
+
+            > DROP FUNCTION add_one CASCADE
+            > DROP FUNCTION above CASCADE
+            > DROP TABLE t
+            """),
+    )
+
+    c.kill("materialized")
+
+
 def workflow_audit_log(c: Composition) -> None:
     c.up("materialized")
 
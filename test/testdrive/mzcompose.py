@@ -22,6 +22,7 @@ from materialize.mzcompose.composition import (
     WorkflowArgumentParser,
 )
 from materialize.mzcompose.services.azurite import Azurite
+from materialize.mzcompose.services.fake_gcs import FakeGcpOauth, FakeGcs
 from materialize.mzcompose.services.fivetran_destination import FivetranDestination
 from materialize.mzcompose.services.kafka import Kafka
 from materialize.mzcompose.services.materialized import Materialized
@@ -33,6 +34,10 @@ from materialize.mzcompose.services.redpanda import Redpanda
 from materialize.mzcompose.services.schema_registry import SchemaRegistry
 from materialize.mzcompose.services.testdrive import Testdrive
 
+# Service accounts mint the tokens for the requests to fake-gcs here.
+FAKE_GCP_OAUTH = FakeGcpOauth()
+FAKE_GCP_OAUTH_ENV = [f"MZ_GCP_OAUTH_TOKEN_URI={FAKE_GCP_OAUTH.token_uri}"]
+
 SERVICES = [
     Kafka(
         environment_extra=[
@@ -56,8 +61,11 @@ SERVICES = [
     Azurite(),
     Mz(app_password=""),
     Minio(setup_materialize=True, additional_directories=["copytos3", "copyfroms3"]),
+    FakeGcs(buckets=["copytogcs"]),
+    FAKE_GCP_OAUTH,
     Materialized(
         external_blob_store=True,
+        environment_extra=FAKE_GCP_OAUTH_ENV,
         sanity_restart=False,
     ),
     FivetranDestination(volumes_extra=["tmp:/share/tmp"]),
@@ -139,6 +147,8 @@ def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
         "postgres",
         "mysql",
         "minio",
+        "fake-gcs",
+        "fake-gcp-oauth",
     ]
     if args.redpanda:
         dependencies += ["redpanda"]
@@ -155,6 +165,7 @@ def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
         default_size=args.default_size,
         external_blob_store=True,
         blob_store_is_azure=args.azurite,
+        environment_extra=FAKE_GCP_OAUTH_ENV,
         additional_system_parameter_defaults=additional_system_parameter_defaults,
         default_replication_factor=1,
         sanity_restart=False,